@@ -0,0 +1,154 @@
+//! 基准测试：批量格式化到同一缓冲区
+//! * 🎯对比`format_task`（每次返回新字符串）与`format_task_to`（写入调用方的缓冲区）
+//!   * 📌场景：将数百万条任务逐行写入文件
+//! * 🚩使用「计数分配器」统计堆分配次数，并计时
+//!   * 📌不引入外部依赖：直接包装[`System`]分配器
+//! * ✅断言：缓冲区预留足够容量后，`format_task_to`不再进行任何堆分配
+
+use narsese::{
+    conversion::string::{impl_enum, impl_lexical},
+    enum_narsese::Narsese as EnumNarsese,
+    lexical::Narsese as LexicalNarsese,
+};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+/// 计数分配器：统计分配次数
+struct CountingAllocator;
+
+/// 已进行的分配次数
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// 每次采样格式化的任务数
+const TASKS_PER_SAMPLE: usize = 20_000;
+/// 采样次数
+const SAMPLES: usize = 10;
+
+/// 样本语料：典型的任务
+const CORPUS: &[&str] = &[
+    "$0.5;0.75;0.4$ <(&/, <ball --> [left]>, <(*, {SELF}) --> ^go-to>) ==> <SELF --> [good]>>. :!-1: %1.0;0.9%",
+    "$0.8;0.8;0.8$ <{tim} --> (/,livingIn,_,{graz})>. %0%",
+    "$0.9$ <<(*,$1,sunglasses) --> own> ==> <$1 --> [aggressive]>>.",
+    "$$ <(&/,<(*,$1,plastic) --> made_of>,<(*,{SELF},$1) --> ^lighter>) =/> <$1 --> [heated]>>.",
+    "$0.5;0.5$ <{SELF} --> [hurt]>. :|: %0%",
+    "$0.7$ (&&,<#1 --> object>,<#1 --> [unscrewing]>)!",
+    "$0.6;0.4$ <(*,{SELF},?what) --> afraid_of>?",
+];
+
+/// 统计一次运行的「耗时」与「分配次数」
+fn measure(mut run: impl FnMut()) -> (Duration, usize) {
+    let mut min = Duration::MAX;
+    let mut allocations = usize::MAX;
+    for _ in 0..SAMPLES {
+        let before = ALLOCATIONS.load(Ordering::Relaxed);
+        let start = Instant::now();
+        run();
+        min = min.min(start.elapsed());
+        allocations = allocations.min(ALLOCATIONS.load(Ordering::Relaxed) - before);
+    }
+    (min, allocations)
+}
+
+/// 输出一行对比结果
+fn report(name: &str, (time, allocations): (Duration, usize)) {
+    println!(
+        "{name:<28} time: [min {time:>10.3?}] allocations: {allocations:>8} ({:.2} per task)",
+        allocations as f64 / TASKS_PER_SAMPLE as f64
+    );
+}
+
+/// 对同一批任务对比两种格式化方式
+/// * 📌缓冲区预留足够容量，排除「缓冲区扩容」的分配
+fn bench<T>(
+    name: &str,
+    tasks: &[T],
+    format: impl Fn(&T) -> String,
+    format_to: impl Fn(&T, &mut String) -> std::fmt::Result,
+) {
+    let capacity = tasks.iter().map(|task| format(task).len() + 1).sum();
+    let by_string = measure(|| {
+        let mut out = String::with_capacity(capacity);
+        for task in tasks {
+            out.push_str(&format(black_box(task)));
+            out.push('\n');
+        }
+        black_box(out);
+    });
+    let mut out = String::with_capacity(capacity);
+    let by_writer = measure(|| {
+        out.clear();
+        for task in tasks {
+            format_to(black_box(task), &mut out).unwrap();
+            out.push('\n');
+        }
+        black_box(&out);
+    });
+    println!("[{name}] {TASKS_PER_SAMPLE} tasks × {SAMPLES} samples");
+    report("format_task (String)", by_string);
+    report("format_task_to (buffer)", by_writer);
+    assert_eq!(by_writer.1, 0, "写入预留缓冲区时不应有堆分配");
+}
+
+fn main() {
+    // 枚举Narsese
+    let format = &impl_enum::format_instances::FORMAT_ASCII;
+    let tasks = CORPUS
+        .iter()
+        .cycle()
+        .take(TASKS_PER_SAMPLE)
+        .map(|input| {
+            format
+                .parse::<EnumNarsese>(input)
+                .unwrap()
+                .try_into_task()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    bench(
+        "enum",
+        &tasks,
+        |task| format.format_task(task),
+        |task, out| format.format_task_to(task, out),
+    );
+
+    // 词法Narsese
+    let format: &impl_lexical::NarseseFormat = &impl_lexical::format_instances::FORMAT_ASCII;
+    let tasks = CORPUS
+        .iter()
+        .cycle()
+        .take(TASKS_PER_SAMPLE)
+        .map(|input| match format.parse(input).unwrap() {
+            LexicalNarsese::Task(task) => task,
+            other => panic!("不是任务：{other:?}"),
+        })
+        .collect::<Vec<_>>();
+    bench(
+        "lexical",
+        &tasks,
+        |task| format.format_task(task),
+        |task, out| format.format_task_to(task, out),
+    );
+}
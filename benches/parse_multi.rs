@@ -0,0 +1,201 @@
+//! 基准测试：枚举Narsese的「批量解析」
+//! * 🎯测量[`NarseseFormat::parse_multi`]在各格式下的性能
+//!   * 📌场景：复用同一解析状态，批量解析大量短任务
+//!   * 📌覆盖ASCII、LaTeX、漢文：多字节字符的系词、连接符对前缀匹配影响较大
+//! * 🎯给出「语法元素」前缀匹配的前后对照（基线）
+//!   * 📌旧：逐次解码格式字串（原`starts_with_at`）
+//!   * 📌新：预计算首字符、字符切片（现`starts_with_token_at`）
+//! * 🚩流程同`parse_str_fast`：预热⇒多次采样⇒统计均值、最小值
+
+use narsese::{
+    conversion::string::impl_enum::{
+        format_instances::{FORMAT_ASCII, FORMAT_HAN, FORMAT_LATEX},
+        NarseseFormat,
+    },
+    enum_narsese::Narsese,
+};
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// 每次采样解析的行数
+const LINES_PER_SAMPLE: usize = 20_000;
+/// 预热次数
+const WARMUP_SAMPLES: usize = 3;
+/// 采样次数
+const SAMPLES: usize = 20;
+
+/// 样本语料（ASCII）：典型的短任务、语句
+/// * 🚩其它格式的输入由此转换而来
+const CORPUS: &[&str] = &[
+    "$0.5;0.75;0.4$ <(&/, <ball --> [left]>, <(*, {SELF}) --> ^go-to>) ==> <SELF --> [good]>>. :!-1: %1.0;0.9%",
+    "<{tim} --> (/,livingIn,_,{graz})>. %0%",
+    "<<(*,$1,sunglasses) --> own> ==> <$1 --> [aggressive]>>.",
+    "<(&/,<(*,$1,plastic) --> made_of>,<(*,{SELF},$1) --> ^lighter>) =/> <$1 --> [heated]>>.",
+    "<{SELF} --> [hurt]>. :|: %0%",
+    "(&&,<#1 --> object>,<#1 --> [unscrewing]>)!",
+    "<(*,{SELF},?what) --> afraid_of>?",
+    "<a --> A>. :|: %1.00;0.90%",
+];
+
+/// 生成输入：将语料转换到指定格式，并循环直到指定行数
+fn inputs(format: &NarseseFormat<&str>) -> Vec<String> {
+    let corpus = CORPUS
+        .iter()
+        .map(|input| format.format_narsese(&FORMAT_ASCII.parse::<Narsese>(input).unwrap()))
+        .collect::<Vec<_>>();
+    corpus.into_iter().cycle().take(LINES_PER_SAMPLE).collect()
+}
+
+/// 对一种格式进行基准测试
+fn bench(name: &str, format: &NarseseFormat<&'static str>) {
+    let inputs = inputs(format);
+    // 先确认全部解析成功
+    assert!(format
+        .parse_multi(inputs.iter().map(String::as_str))
+        .iter()
+        .all(Result::is_ok));
+    measure(name, inputs.len(), || {
+        black_box(format.parse_multi(inputs.iter().map(|input| black_box(input.as_str()))));
+    });
+}
+
+/// 预热、采样，并打印统计结果
+/// * 📌`lines`：每次采样处理的行数
+fn measure(name: &str, lines: usize, mut run: impl FnMut()) {
+    // 预热
+    for _ in 0..WARMUP_SAMPLES {
+        run();
+    }
+    // 采样
+    let samples = (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+    let mean = samples.iter().sum::<Duration>() / SAMPLES as u32;
+    let min = *samples.iter().min().unwrap();
+    let per_line = |time: Duration| time / lines as u32;
+    println!(
+        "{name:<24} time: [mean {:>10.3?} min {:>10.3?}] per line: [mean {:>8.3?} min {:>8.3?}]",
+        mean,
+        min,
+        per_line(mean),
+        per_line(min),
+    );
+}
+
+/// 格式中需要前缀匹配的「语法元素」：原子词项前缀、复合词项连接符、陈述系词
+fn tokens<'a>(format: &'a NarseseFormat<&'static str>) -> Vec<&'a str> {
+    let atom = &format.atom;
+    let compound = &format.compound;
+    let mut tokens = vec![
+        atom.prefix_word,
+        atom.prefix_variable_independent,
+        atom.prefix_variable_dependent,
+        atom.prefix_variable_query,
+        atom.prefix_interval,
+        atom.prefix_operator,
+        atom.prefix_placeholder,
+        compound.connecter_intersection_extension,
+        compound.connecter_intersection_intension,
+        compound.connecter_difference_extension,
+        compound.connecter_difference_intension,
+        compound.connecter_product,
+        compound.connecter_image_extension,
+        compound.connecter_image_intension,
+        compound.connecter_conjunction,
+        compound.connecter_disjunction,
+        compound.connecter_negation,
+        compound.connecter_conjunction_sequential,
+        compound.connecter_conjunction_parallel,
+    ];
+    tokens.extend(format.copulas());
+    tokens
+}
+
+/// 旧：逐次解码格式字串
+/// * 📌同`ParseEnvironment::starts_with_at`：每次匹配都重新计数、解码
+fn starts_with_at(env: &[char], index: usize, to_compare: &str) -> bool {
+    // 长度检验
+    if env.len() < index + to_compare.chars().count() {
+        return false;
+    }
+    // 逐个字符比较
+    for (i, c) in to_compare.chars().enumerate() {
+        if env[index + i] != c {
+            return false;
+        }
+    }
+    true
+}
+
+/// 新：预计算的字符切片
+/// * 📌同`ParseEnvironment::starts_with_token_at`：先比较首字符，再比较字符切片
+fn starts_with_token_at(env: &[char], index: usize, token: &[char]) -> bool {
+    if let Some(first) = token.first() {
+        if env.get(index) != Some(first) {
+            return false;
+        }
+    }
+    env.get(index..index + token.len())
+        .is_some_and(|chars| chars == token)
+}
+
+/// 「语法元素」前缀匹配的前后对照
+/// * 🚩在每行的每个位置，尝试匹配格式中的全部「语法元素」
+/// * 📌两种方式的匹配结果须完全一致
+fn bench_token_matching(name: &str, format: &NarseseFormat<&'static str>) {
+    let inputs = inputs(format)
+        .iter()
+        .map(|input| input.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let tokens = tokens(format);
+    let token_chars = tokens
+        .iter()
+        .map(|token| token.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    // 先确认两种方式结果一致
+    for env in &inputs {
+        for index in 0..env.len() {
+            for (token, chars) in tokens.iter().zip(&token_chars) {
+                assert_eq!(
+                    starts_with_at(env, index, token),
+                    starts_with_token_at(env, index, chars),
+                );
+            }
+        }
+    }
+    measure(&format!("{name} before"), inputs.len(), || {
+        for env in &inputs {
+            for index in 0..env.len() {
+                for token in &tokens {
+                    black_box(starts_with_at(black_box(env), index, token));
+                }
+            }
+        }
+    });
+    measure(&format!("{name} after"), inputs.len(), || {
+        for env in &inputs {
+            for index in 0..env.len() {
+                for chars in &token_chars {
+                    black_box(starts_with_token_at(black_box(env), index, chars));
+                }
+            }
+        }
+    });
+}
+
+fn main() {
+    println!("parse_multi {LINES_PER_SAMPLE} lines × {SAMPLES} samples");
+    bench("parse_multi (ASCII)", &FORMAT_ASCII);
+    bench("parse_multi (LaTeX)", &FORMAT_LATEX);
+    bench("parse_multi (HAN)", &FORMAT_HAN);
+    println!("token matching {LINES_PER_SAMPLE} lines × {SAMPLES} samples");
+    bench_token_matching("tokens (ASCII)", &FORMAT_ASCII);
+    bench_token_matching("tokens (LaTeX)", &FORMAT_LATEX);
+    bench_token_matching("tokens (HAN)", &FORMAT_HAN);
+}
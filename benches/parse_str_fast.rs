@@ -0,0 +1,109 @@
+//! 基准测试：枚举Narsese的「零拷贝解析」
+//! * 🎯对比[`NarseseFormat::parse`]与[`NarseseFormat::parse_str_fast`]
+//!   * 📌场景：批量解析大量短任务（如从日志中摄入数百万行）
+//! * 🚩仿照criterion的流程：预热⇒多次采样⇒统计均值、最小值
+//!   * 📌不引入外部依赖：直接使用[`Instant`]计时
+
+use narsese::{
+    conversion::string::impl_enum::{format_instances::FORMAT_ASCII, NarseseFormat},
+    enum_narsese::Narsese,
+};
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// 每次采样解析的行数
+const LINES_PER_SAMPLE: usize = 20_000;
+/// 预热次数
+const WARMUP_SAMPLES: usize = 3;
+/// 采样次数
+const SAMPLES: usize = 20;
+
+/// 样本语料：典型的短任务、语句
+const CORPUS: &[&str] = &[
+    "$0.5;0.75;0.4$ <(&/, <ball --> [left]>, <(*, {SELF}) --> ^go-to>) ==> <SELF --> [good]>>. :!-1: %1.0;0.9%",
+    "<{tim} --> (/,livingIn,_,{graz})>. %0%",
+    "<<(*,$1,sunglasses) --> own> ==> <$1 --> [aggressive]>>.",
+    "<(&/,<(*,$1,plastic) --> made_of>,<(*,{SELF},$1) --> ^lighter>) =/> <$1 --> [heated]>>.",
+    "<{SELF} --> [hurt]>. :|: %0%",
+    "(&&,<#1 --> object>,<#1 --> [unscrewing]>)!",
+    "<(*,{SELF},?what) --> afraid_of>?",
+    "<a --> A>. :|: %1.00;0.90%",
+];
+
+/// 生成输入：循环语料直到指定行数
+fn inputs() -> Vec<&'static str> {
+    CORPUS
+        .iter()
+        .copied()
+        .cycle()
+        .take(LINES_PER_SAMPLE)
+        .collect()
+}
+
+/// 采样统计
+struct Stats {
+    mean: Duration,
+    min: Duration,
+}
+
+/// 对一个解析函数进行基准测试
+fn bench(
+    name: &str,
+    inputs: &[&str],
+    parse: impl Fn(&NarseseFormat<&'static str>, &str) -> Option<Narsese>,
+) -> Stats {
+    let format = &FORMAT_ASCII;
+    let run = || {
+        for input in inputs {
+            black_box(parse(format, black_box(input)));
+        }
+    };
+    // 预热
+    for _ in 0..WARMUP_SAMPLES {
+        run();
+    }
+    // 采样
+    let samples = (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+    let stats = Stats {
+        mean: samples.iter().sum::<Duration>() / SAMPLES as u32,
+        min: *samples.iter().min().unwrap(),
+    };
+    let per_line = |time: Duration| time / inputs.len() as u32;
+    println!(
+        "{name:<24} time: [mean {:>10.3?} min {:>10.3?}] per line: [mean {:>8.3?} min {:>8.3?}]",
+        stats.mean,
+        stats.min,
+        per_line(stats.mean),
+        per_line(stats.min),
+    );
+    stats
+}
+
+fn main() {
+    let inputs = inputs();
+    // 先确认两者结果一致
+    for input in CORPUS {
+        let expected = FORMAT_ASCII.parse::<Narsese>(input).unwrap();
+        assert_eq!(FORMAT_ASCII.parse_str_fast(input).unwrap(), expected);
+    }
+    println!("parse {LINES_PER_SAMPLE} lines × {SAMPLES} samples");
+    let chars = bench("parse (Vec<char>)", &inputs, |format, input| {
+        format.parse(input).ok()
+    });
+    let fast = bench("parse_str_fast (&str)", &inputs, |format, input| {
+        format.parse_str_fast(input).ok()
+    });
+    println!(
+        "speedup: mean ×{:.2} min ×{:.2}",
+        chars.mean.as_secs_f64() / fast.mean.as_secs_f64(),
+        chars.min.as_secs_f64() / fast.min.as_secs_f64(),
+    );
+}
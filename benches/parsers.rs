@@ -0,0 +1,305 @@
+//! 基准测试：对比「枚举Narsese解析器」与「词法Narsese解析器+折叠」
+//! * 🎯为「热路径上选用哪种解析器」提供数据
+//!   * 📌语料：`long_term_stability.nal`风格的短任务
+//!   * 📌枚举解析、词法解析、词法解析+折叠、格式化回字符串
+//!   * 📌微基准：深层嵌套的词项、超长的合取（1000个组分）
+//! * 🚩流程同`parse_str_fast`：预热⇒多次采样⇒统计均值、最小值
+//!   * 📌不引入外部依赖：直接使用[`Instant`]计时
+//!   * ⚠️有意不用`criterion`：构建环境离线，且本crate的开发依赖只有`nar_dev_utils`
+//!     * 📌各基准函数只依赖「名称+闭包」，日后改用`criterion`时可原样迁移
+//! * 📄设置环境变量`NARSESE_BENCH_REPORT`⇒将结果以Markdown表格写入该路径
+//!   * 📌`PERFORMANCE.md`即由此生成
+//!   * 📌运行：`NARSESE_BENCH_REPORT=PERFORMANCE.md cargo bench --bench parsers`
+
+use narsese::{
+    api::CastToTask,
+    conversion::{
+        inter_type::lexical_fold::{FoldError, TryFoldInto},
+        string::{impl_enum, impl_lexical},
+    },
+    enum_narsese::{Narsese as EnumNarsese, Term as EnumTerm},
+    lexical::{Narsese as LexicalNarsese, Term as LexicalTerm},
+};
+use std::{
+    fmt::Write,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// 每次采样处理的行数
+const LINES_PER_SAMPLE: usize = 10_000;
+/// 预热次数
+const WARMUP_SAMPLES: usize = 3;
+/// 采样次数
+const SAMPLES: usize = 10;
+
+/// 深层嵌套的层数
+/// * 📌须小于默认的最大嵌套深度
+const NESTED_DEPTHS: &[usize] = &[16, 64, 128];
+/// 超长合取的组分数
+/// * 🎯按倍数增长：若耗时增长远快于组分数，则有平方级行为
+const CONJUNCTION_SIZES: &[usize] = &[250, 500, 1000];
+
+/// 样本语料：`long_term_stability.nal`
+const CORPUS: &[&str] = &[
+    "<{tim} --> (/,livingIn,_,{graz})>. %0%",
+    "<<(*,$1,sunglasses) --> own> ==> <$1 --> [aggressive]>>.",
+    "<(*,{tom},sunglasses) --> own>.",
+    "<<$1 --> [aggressive]> ==> <$1 --> murder>>.",
+    "<<$1 --> (/,livingIn,_,{graz})> ==> <$1 --> murder>>.",
+    "<{?who} --> murder>?",
+    "<(*,{tom},(&,[black],glasses)) --> own>.",
+    "<sunglasses --> (&,[black],glasses)>.",
+    "<(*,toothbrush,plastic) --> made_of>.",
+    "<(&/,<(*,$1,plastic) --> made_of>,<(*,{SELF},$1) --> ^lighter>) =/> <$1 --> [heated]>>.",
+    "<<$1 --> [heated]> =/> <$1 --> [melted]>>.",
+    "<<$1 --> [melted]> <|> <$1 --> [pliable]>>.",
+    "<(&/,<$1 --> [pliable]>,<(*,{SELF},$1) --> ^reshape>) =/> <$1 --> [hardened]>>.",
+    "<<$1 --> [hardened]> =|> <$1 --> [unscrewing]>>.",
+    "<toothbrush --> object>.",
+    "(&&,<#1 --> object>,<#1 --> [unscrewing]>)!",
+    "<{SELF} --> [hurt]>! %0%",
+    "<{SELF} --> [hurt]>. :|: %0%",
+    "<(&/,<(*,{SELF},wolf) --> close_to>,+1000) =/> <{SELF} --> [hurt]>>.",
+    "<(*,{SELF},wolf) --> close_to>. :|:",
+    "<(&|,<(*,{SELF},$1,FALSE) --> ^want>,<(*,{SELF},$1) --> ^anticipate>) =|> <(*,{SELF},$1) --> afraid_of>>.",
+    "<(*,{SELF},?what) --> afraid_of>?",
+    "<a --> A>. :|: %1.00;0.90%",
+    "<?1 =/> <c --> C>>?",
+    "<cup --> [bendable]>.",
+];
+
+/// 一项基准的结果
+struct Record {
+    /// 名称
+    name: String,
+    /// 每次采样处理的项数
+    items: usize,
+    /// 均值
+    mean: Duration,
+    /// 最小值
+    min: Duration,
+}
+
+/// 对一个过程进行基准测试
+/// * 🚩预热⇒采样⇒打印一行结果
+fn bench(name: impl Into<String>, items: usize, mut run: impl FnMut()) -> Record {
+    let name = name.into();
+    // 预热
+    for _ in 0..WARMUP_SAMPLES {
+        run();
+    }
+    // 采样
+    let samples = (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+    let record = Record {
+        name,
+        items,
+        mean: samples.iter().sum::<Duration>() / SAMPLES as u32,
+        min: *samples.iter().min().unwrap(),
+    };
+    let per_item = |time: Duration| time / items as u32;
+    println!(
+        "{:<40} time: [mean {:>10.3?} min {:>10.3?}] per item: [mean {:>9.3?} min {:>9.3?}]",
+        record.name,
+        record.mean,
+        record.min,
+        per_item(record.mean),
+        per_item(record.min),
+    );
+    record
+}
+
+/// 生成输入：循环语料直到指定行数
+fn inputs() -> Vec<&'static str> {
+    CORPUS
+        .iter()
+        .copied()
+        .cycle()
+        .take(LINES_PER_SAMPLE)
+        .collect()
+}
+
+/// 深层嵌套的词项：`(--, (--, … A))`
+fn nested_input(depth: usize) -> String {
+    let mut input = "(--,".repeat(depth);
+    input.push('A');
+    input.push_str(&")".repeat(depth));
+    input
+}
+
+/// 超长合取：`(&&, <a0 --> b>, <a1 --> b>, …)`
+fn conjunction_input(size: usize) -> String {
+    let mut input = String::from("(&&");
+    for i in 0..size {
+        write!(input, ", <a{i} --> b>").unwrap();
+    }
+    input.push(')');
+    input
+}
+
+/// 语料：解析、折叠、格式化
+fn bench_corpus(records: &mut Vec<Record>) {
+    let enum_format = &impl_enum::format_instances::FORMAT_ASCII;
+    let lexical_format = &*impl_lexical::format_instances::FORMAT_ASCII;
+    let inputs = inputs();
+    let n = inputs.len();
+    // 先确认：两条路径结果一致
+    for input in CORPUS {
+        let expected = enum_format.parse::<EnumNarsese>(input).unwrap();
+        let folded: EnumNarsese = lexical_format
+            .parse(input)
+            .unwrap()
+            .try_fold_into(enum_format)
+            .unwrap();
+        assert_eq!(folded, expected, "{input}");
+    }
+
+    records.push(bench("enum parse (ASCII)", n, || {
+        for input in &inputs {
+            black_box(enum_format.parse::<EnumNarsese>(black_box(input)).unwrap());
+        }
+    }));
+    records.push(bench("lexical parse (ASCII)", n, || {
+        for input in &inputs {
+            black_box(lexical_format.parse(black_box(input)).unwrap());
+        }
+    }));
+    records.push(bench("lexical parse + fold (ASCII)", n, || {
+        for input in &inputs {
+            let parsed = lexical_format.parse(black_box(input)).unwrap();
+            let folded: Result<EnumNarsese, FoldError> = parsed.try_fold_into(enum_format);
+            black_box(folded.unwrap());
+        }
+    }));
+
+    // 格式化
+    let enum_tasks = inputs
+        .iter()
+        .map(
+            |input| match enum_format.parse::<EnumNarsese>(input).unwrap() {
+                EnumNarsese::Sentence(sentence) => sentence.cast_to_task(),
+                EnumNarsese::Task(task) => task,
+                EnumNarsese::Term(term) => panic!("不是语句：{term:?}"),
+            },
+        )
+        .collect::<Vec<_>>();
+    let lexical_values = inputs
+        .iter()
+        .map(|input| lexical_format.parse(input).unwrap())
+        .collect::<Vec<LexicalNarsese>>();
+    records.push(bench("enum format (ASCII)", n, || {
+        for task in &enum_tasks {
+            black_box(enum_format.format_task(black_box(task)));
+        }
+    }));
+    records.push(bench("lexical format (ASCII)", n, || {
+        for value in &lexical_values {
+            black_box(lexical_format.format_narsese(black_box(value)));
+        }
+    }));
+}
+
+/// 微基准：深层嵌套、超长合取
+fn bench_micro(records: &mut Vec<Record>) {
+    let enum_format = &impl_enum::format_instances::FORMAT_ASCII;
+    let lexical_format = &*impl_lexical::format_instances::FORMAT_ASCII;
+    let cases = NESTED_DEPTHS
+        .iter()
+        .map(|&depth| (format!("nested depth {depth}"), nested_input(depth)))
+        .chain(
+            CONJUNCTION_SIZES
+                .iter()
+                .map(|&size| (format!("conjunction {size}"), conjunction_input(size))),
+        );
+    for (name, input) in cases {
+        // 先确认：两条路径结果一致
+        let parse_enum = |input: &str| {
+            enum_format
+                .parse::<EnumNarsese>(input)
+                .unwrap()
+                .try_into_term()
+                .unwrap()
+        };
+        let expected = parse_enum(&input);
+        let lexical = lexical_format.parse_term(&input).unwrap();
+        let folded: EnumTerm = lexical.clone().try_fold_into(enum_format).unwrap();
+        assert_eq!(folded, expected, "{name}");
+        records.push(bench(format!("{name}: enum parse"), 1, || {
+            black_box(parse_enum(black_box(&input)));
+        }));
+        records.push(bench(format!("{name}: lexical parse"), 1, || {
+            black_box(lexical_format.parse_term(black_box(&input)).unwrap());
+        }));
+        records.push(bench(format!("{name}: lexical fold"), 1, || {
+            let term: LexicalTerm = black_box(lexical.clone());
+            let folded: Result<EnumTerm, FoldError> = term.try_fold_into(enum_format);
+            black_box(folded.unwrap());
+        }));
+    }
+}
+
+/// 生成Markdown报告
+fn report(records: &[Record]) -> String {
+    let mut out = String::new();
+    writeln!(out, "# 性能特征\n").unwrap();
+    writeln!(out, "> 本文件由基准测试生成，请勿手动编辑：\n>").unwrap();
+    writeln!(
+        out,
+        "> `NARSESE_BENCH_REPORT=PERFORMANCE.md cargo bench --bench parsers`\n"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "- 语料：`long_term_stability.nal`风格的 {} 条短任务（ASCII）",
+        CORPUS.len()
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "- 每次采样 {LINES_PER_SAMPLE} 行，预热 {WARMUP_SAMPLES} 次，采样 {SAMPLES} 次"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "- 「词法解析 + 折叠」即「词法Narsese ⇒ 枚举Narsese」的完整路径"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "- 超长合取的组分数按倍数增长：耗时应大致按同样倍数增长（线性）\n"
+    )
+    .unwrap();
+    writeln!(out, "| 基准 | 每次采样项数 | 均值 | 最小值 | 单项均值 |").unwrap();
+    writeln!(out, "| :--- | ---: | ---: | ---: | ---: |").unwrap();
+    for record in records {
+        writeln!(
+            out,
+            "| {} | {} | {:.3?} | {:.3?} | {:.3?} |",
+            record.name,
+            record.items,
+            record.mean,
+            record.min,
+            record.mean / record.items as u32,
+        )
+        .unwrap();
+    }
+    out
+}
+
+fn main() {
+    println!("parsers: {LINES_PER_SAMPLE} lines × {SAMPLES} samples");
+    let mut records = vec![];
+    bench_corpus(&mut records);
+    bench_micro(&mut records);
+    // 写入报告
+    if let Ok(path) = std::env::var("NARSESE_BENCH_REPORT") {
+        std::fs::write(&path, report(&records)).unwrap();
+        println!("report written to {path}");
+    }
+}
@@ -0,0 +1,86 @@
+//! 「从字符串解析」的错误
+//! * 🎯为各Narsese实现的[`std::str::FromStr`]提供统一的错误类型
+//!   * 📄`let t: Term = "<A --> B>".parse()?;`
+//! * 📌区分「语法错误」与「类型不匹配」
+//!   * 📄将`A.`解析为「词项」：语法正确，但解析出的是「语句」
+
+use crate::api::NarseseCastError;
+use std::{error::Error, fmt::Display};
+
+/// 从字符串解析的错误
+/// * 📌泛型参数`E`为各解析器自身的「解析错误」
+#[derive(Debug, Clone)]
+pub enum FromStrError<E> {
+    /// 解析失败：语法错误
+    Parse(E),
+    /// 类型不匹配：解析成功，但不是所需的「词项/语句/任务」
+    WrongVariant {
+        /// 所需的类型名称
+        expected: &'static str,
+        /// 实际解析出的类型名称
+        found: String,
+    },
+}
+
+/// 「Narsese值」转换失败⇒类型不匹配
+/// * 🎯配合[`TryFrom`]：解析出的「Narsese值」不是所需的「词项/语句/任务」
+impl<E> From<NarseseCastError> for FromStrError<E> {
+    fn from(error: NarseseCastError) -> Self {
+        Self::WrongVariant {
+            expected: error.target,
+            found: error.found.to_string(),
+        }
+    }
+}
+
+/// 为具体的「Narsese值/词项/语句/任务」类型批量实现[`FromStr`](std::str::FromStr)
+/// * 🎯枚举Narsese、词法Narsese共用同一套实现
+///   * 📄枚举Narsese：`crate::conversion::string::impl_enum`
+///   * 📄词法Narsese：`crate::conversion::string::impl_lexical`
+/// * 🚩Narsese值：调用传入的解析函数
+/// * 🚩词项/语句/任务：统一先解析为「Narsese值」，再经[`TryFrom`]转换
+///   * 📄将`A.`解析为「词项」⇒[`FromStrError::WrongVariant`]
+#[cfg(any(feature = "enum_narsese", feature = "lexical_narsese"))]
+macro_rules! impl_from_str {
+    ($error:ty, $parse:expr; $narsese:ty => $($t:ty),*) => {
+        impl std::str::FromStr for $narsese {
+            type Err = $crate::api::FromStrError<$error>;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                ($parse)(s).map_err($crate::api::FromStrError::Parse)
+            }
+        }
+        $(
+            impl std::str::FromStr for $t {
+                type Err = $crate::api::FromStrError<$error>;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    Ok(s.parse::<$narsese>()?.try_into()?)
+                }
+            }
+        )*
+    };
+}
+#[cfg(any(feature = "enum_narsese", feature = "lexical_narsese"))]
+pub(crate) use impl_from_str;
+
+/// 呈现报错文本
+impl<E: Display> Display for FromStrError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse(error) => write!(f, "{error}"),
+            Self::WrongVariant { expected, found } => {
+                write!(f, "类型「{found}」不匹配，无法解析为{expected}")
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for FromStrError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Parse(error) => Some(error),
+            Self::WrongVariant { .. } => None,
+        }
+    }
+}
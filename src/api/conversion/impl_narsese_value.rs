@@ -1,15 +1,16 @@
 //! 为「Narsese值」实现所有有关「转换」的API
 //! * 🎯将「数据结构定义」和「具体方法实现」分离
 //!   * 🎯避免「循环依赖」发生
-use crate::api::{CastToTask, FormatTo, GetTerm, NarseseValue, TryCastToSentence};
-use std::io::{Error as IoError, ErrorKind};
+use crate::api::{
+    CastToTask, FormatTo, GetTerm, NarseseCastError, NarseseValue, TryCastToSentence,
+};
 
 /// 继续实现有关「转换」的API函数
 /// * 🎯数据结构与功能实现分离
 impl<Term, Sentence, Task> NarseseValue<Term, Sentence, Task> {
     /// 尝试转换到任务（兼容语句）
     /// * 🚩类似`try_into_task`，但若语句类型实现了[`CastToTask`]，则可进行自动转换
-    pub fn try_into_task_compatible(self) -> Result<Task, IoError>
+    pub fn try_into_task_compatible(self) -> Result<Task, NarseseCastError>
     where
         Sentence: CastToTask<Task>,
     {
@@ -19,12 +20,20 @@ impl<Term, Sentence, Task> NarseseValue<Term, Sentence, Task> {
             // 语句：自动转换成任务
             Self::Sentence(sentence) => Ok(sentence.cast_to_task()),
             // 其他类型：报错
-            _ => Err(IoError::new(
-                ErrorKind::InvalidData,
-                format!("类型「{}」不匹配，无法转换为任务", self.type_name()),
-            )),
+            _ => Err(NarseseCastError::new(self.type_name(), "任务")),
         }
     }
+
+    /// 获取内部词项
+    /// * 🎯不论是「词项」「语句」还是「任务」，都能直接取出其中的词项
+    /// * 🚩委托给[`GetTerm`]：语句、任务⇒取其中的词项
+    pub fn term(&self) -> &Term
+    where
+        Sentence: GetTerm<Term>,
+        Task: GetTerm<Term>,
+    {
+        self.get_term()
+    }
 }
 
 /// 对所有「其中的『任务』类型实现了『尝试转换到语句』特征」的「Narsese值」实现「尝试转换（其中的）任务到语句」
@@ -98,3 +107,105 @@ where
         }
     }
 }
+
+/// 单元测试
+/// * 🎯同时覆盖「枚举Narsese」与「词法Narsese」两种实例
+#[cfg(test)]
+mod tests {
+    use nar_dev_utils::asserts;
+
+    /// 测试/枚举Narsese
+    #[test]
+    #[cfg(feature = "enum_narsese")]
+    fn test_enum_narsese_value() {
+        use crate::{
+            conversion::string::impl_enum::format_instances::FORMAT_ASCII,
+            enum_narsese::{Narsese, Term},
+        };
+        let parse = |input| FORMAT_ASCII.parse::<Narsese>(input).unwrap();
+        let term = parse("<A --> B>");
+        let sentence = parse("<A --> B>.");
+        let task = parse("$0.5;0.5;0.5$ <A --> B>.");
+        let expected = Term::new_inheritance(Term::new_word("A"), Term::new_word("B"));
+        asserts! {
+            // 判断
+            term.is_term() && !term.is_sentence() && !term.is_task(),
+            sentence.is_sentence() && !sentence.is_term() && !sentence.is_task(),
+            task.is_task() && !task.is_term() && !task.is_sentence(),
+            // 引用形式
+            term.as_term() => Some(&expected),
+            term.as_sentence() => None,
+            term.as_task() => None,
+            sentence.as_sentence().is_some(),
+            sentence.as_term() => None,
+            task.as_task().is_some(),
+            task.as_sentence() => None,
+            term.try_as_term() => Ok(&expected),
+            sentence.try_as_sentence().is_ok(),
+            task.try_as_task().is_ok(),
+            // 统一取词项
+            term.term() => &expected,
+            sentence.term() => &expected,
+            task.term() => &expected,
+            // 所有权形式
+            term.clone().try_into_term() => Ok(expected.clone()),
+            sentence.clone().try_into_sentence().is_ok(),
+            task.clone().try_into_task().is_ok(),
+        }
+        // 失败路径：错误中带有「实际类型」与「目标类型」
+        let error = term.try_as_task().unwrap_err();
+        asserts! {
+            error.found => "词项",
+            error.target => "任务",
+            sentence.try_as_term().unwrap_err().found => "语句",
+            task.try_as_sentence().unwrap_err().target => "语句",
+            term.clone().try_into_sentence().unwrap_err().found => "词项",
+            sentence.clone().try_into_task().unwrap_err().target => "任务",
+            task.clone().try_into_term().unwrap_err().found => "任务",
+        }
+    }
+
+    /// 测试/词法Narsese
+    #[test]
+    #[cfg(feature = "lexical_narsese")]
+    fn test_lexical_narsese_value() {
+        use crate::{
+            conversion::string::impl_lexical::format_instances::FORMAT_ASCII,
+            lexical::Narsese,
+        };
+        let parse = |input| FORMAT_ASCII.parse(input).unwrap();
+        let term: Narsese = parse("<A --> B>");
+        let sentence = parse("<A --> B>.");
+        let task = parse("$0.5;0.5;0.5$ <A --> B>.");
+        let expected = FORMAT_ASCII.parse_term("<A --> B>").unwrap();
+        asserts! {
+            // 判断
+            term.is_term() && !term.is_sentence() && !term.is_task(),
+            sentence.is_sentence() && !sentence.is_term() && !sentence.is_task(),
+            task.is_task() && !task.is_term() && !task.is_sentence(),
+            // 引用形式
+            term.as_term() => Some(&expected),
+            term.as_task() => None,
+            sentence.as_sentence().map(|s| &s.punctuation) => Some(&".".to_string()),
+            task.as_task().map(|t| t.budget.len()) => Some(3),
+            term.try_as_term() => Ok(&expected),
+            // 统一取词项
+            term.term() => &expected,
+            sentence.term() => &expected,
+            task.term() => &expected,
+            // 所有权形式
+            term.clone().try_into_term() => Ok(expected.clone()),
+            sentence.clone().try_into_sentence().is_ok(),
+            task.clone().try_into_task().is_ok(),
+        }
+        // 失败路径
+        asserts! {
+            term.try_as_sentence().unwrap_err().to_string() => "类型「词项」不匹配，无法转换为语句",
+            sentence.try_as_task().unwrap_err().found => "语句",
+            task.try_as_term().unwrap_err().target => "词项",
+            term.clone().try_into_task().unwrap_err().found => "词项",
+            sentence.clone().try_into_term().unwrap_err().found => "语句",
+            task.clone().try_into_sentence().unwrap_err().found => "任务",
+        }
+    }
+}
@@ -16,4 +16,8 @@ nar_dev_utils::pub_mod_and_pub_use! {
     sentence_cast
     // 对「Narsese值」的实现
     impl_narsese_value
+    // 批量解析结果
+    parsed_batch
+    // 从字符串解析的错误
+    from_str_error
 }
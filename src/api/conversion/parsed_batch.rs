@@ -0,0 +1,156 @@
+//! 批量解析的结果集
+//! * 🎯将「多条输入的解析结果」分为「成功」与「失败」两部分
+//!   * 📄`parse_multi_collect`的返回值
+//! * 📌与具体的Narsese实现无关：值类型、错误类型均为泛型
+
+use std::{error::Error, fmt::Display};
+
+/// 批量解析结果
+/// * 🚩成功的值按输入顺序存放
+/// * 🚩失败的错误连同其「输入索引」按输入顺序存放
+/// * 📌`Value`一般为「Narsese值」，`E`一般为各解析器的「解析错误」
+#[derive(Debug, Clone)]
+pub struct ParsedBatch<Value, E> {
+    /// 成功解析的值
+    values: Vec<Value>,
+    /// 解析失败的错误，及其输入索引（从0开始）
+    errors: Vec<(usize, E)>,
+}
+
+impl<Value, E> ParsedBatch<Value, E> {
+    /// 从一系列「解析结果」中收集
+    /// * 🚩输入索引从`0`开始，按迭代顺序计数
+    pub fn from_results(results: impl IntoIterator<Item = Result<Value, E>>) -> Self {
+        let mut values = vec![];
+        let mut errors = vec![];
+        for (i, result) in results.into_iter().enumerate() {
+            match result {
+                Ok(value) => values.push(value),
+                Err(error) => errors.push((i, error)),
+            }
+        }
+        Self { values, errors }
+    }
+
+    /// 获取所有成功解析的值
+    pub fn values(&self) -> &[Value] {
+        &self.values
+    }
+
+    /// 获取所有解析错误，及其输入索引
+    pub fn errors(&self) -> &[(usize, E)] {
+        &self.errors
+    }
+
+    /// 判断是否全部解析成功
+    pub fn is_all_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// 遍历所有成功解析的值
+    pub fn iter(&self) -> std::slice::Iter<'_, Value> {
+        self.values.iter()
+    }
+
+    /// 拿出所有成功解析的值，丢弃错误
+    pub fn into_values(self) -> Vec<Value> {
+        self.values
+    }
+
+    /// 要求全部解析成功
+    /// * 🚩有任何错误⇒返回包含所有错误的[`BatchError`]
+    pub fn require_all(self) -> Result<Vec<Value>, BatchError<E>> {
+        match self.errors.is_empty() {
+            true => Ok(self.values),
+            false => Err(BatchError {
+                errors: self.errors,
+            }),
+        }
+    }
+}
+
+/// 遍历成功解析的值
+impl<Value, E> IntoIterator for ParsedBatch<Value, E> {
+    type Item = Value;
+    type IntoIter = std::vec::IntoIter<Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+/// 遍历成功解析的值（引用）
+impl<'a, Value, E> IntoIterator for &'a ParsedBatch<Value, E> {
+    type Item = &'a Value;
+    type IntoIter = std::slice::Iter<'a, Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// 批量解析错误
+/// * 🎯在[`ParsedBatch::require_all`]失败时，汇报所有出错的输入索引
+#[derive(Debug, Clone)]
+pub struct BatchError<E> {
+    /// 所有解析错误，及其输入索引
+    errors: Vec<(usize, E)>,
+}
+
+impl<E> BatchError<E> {
+    /// 获取所有解析错误，及其输入索引
+    pub fn errors(&self) -> &[(usize, E)] {
+        &self.errors
+    }
+
+    /// 获取所有出错的输入索引
+    pub fn indices(&self) -> Vec<usize> {
+        self.errors.iter().map(|(i, _)| *i).collect()
+    }
+}
+
+/// 呈现报错文本
+/// * 📄`批量解析失败：2条输入出错 @ [1, 3]`，随后逐行列出各错误
+impl<E: Display> Display for BatchError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "批量解析失败：{}条输入出错 @ {:?}",
+            self.errors.len(),
+            self.indices()
+        )?;
+        for (i, error) in &self.errors {
+            write!(f, "\n[{i}] {error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: Error> Error for BatchError<E> {}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nar_dev_utils::asserts;
+
+    #[test]
+    fn test_parsed_batch() {
+        let batch = ParsedBatch::from_results(["1", "x", "3", "y"].map(str::parse::<i32>));
+        asserts! {
+            batch.values() => [1, 3],
+            batch.errors().iter().map(|(i, _)| *i).collect::<Vec<_>>() => [1, 3],
+            batch.is_all_ok() => false,
+            batch.iter().sum::<i32>() => 4,
+            (&batch).into_iter().count() => 2,
+            batch.clone().into_iter().collect::<Vec<_>>() => [1, 3],
+            batch.clone().into_values() => [1, 3],
+        }
+        let error = batch.require_all().unwrap_err();
+        let message = error.to_string();
+        asserts! {
+            error.indices() => [1, 3],
+            message.contains("[1, 3]"),
+        }
+    }
+}
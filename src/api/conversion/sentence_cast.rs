@@ -22,3 +22,49 @@ where
     /// 将任务转换为语句
     fn try_cast_to_sentence(self) -> Result<Sentence, Self>;
 }
+
+/// 特征：将任务拆分为「语句」与「预算值」
+/// * 🎯「给出语句，并告知舍去了什么」：不论预算值是否为空，总能转换
+/// * 📌[`TryCastToSentence`]的实现基于此特征：空预算⇒成功，否则⇒原样返回
+pub trait SplitTask<Sentence, Budget>
+where
+    Self: Sized,
+{
+    /// 拆分为语句与预算值
+    fn split_task(self) -> (Sentence, Budget);
+
+    /// 从语句与预算值重新组合
+    /// * 📌[`Self::split_task`]的逆操作
+    fn join_task(sentence: Sentence, budget: Budget) -> Self;
+
+    /// 有损转换为语句
+    /// * 🚩空预算⇒[`None`]（无损失）；否则⇒舍去的预算值
+    fn to_sentence_lossy(self) -> (Sentence, Option<Budget>);
+}
+
+/// 语句转换为词项时舍去的部分
+/// * 📌与词项一起，可重新组合为原语句
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DroppedSentenceParts<Punctuation, Stamp, Truth> {
+    /// 标点
+    pub punctuation: Punctuation,
+    /// 时间戳
+    pub stamp: Stamp,
+    /// 真值
+    /// * 📌「问题」「请求」等无真值的语句⇒空真值
+    pub truth: Truth,
+}
+
+/// 特征：将语句拆分为「词项」与「舍去的部分」
+pub trait SplitSentence<Term, Punctuation, Stamp, Truth>
+where
+    Self: Sized,
+{
+    /// 有损转换为词项
+    /// * 🚩总能成功：标点、时间戳、真值记录在[`DroppedSentenceParts`]中
+    fn to_term_lossy(self) -> (Term, DroppedSentenceParts<Punctuation, Stamp, Truth>);
+
+    /// 从词项与舍去的部分重新组合
+    /// * 📌[`Self::to_term_lossy`]的逆操作
+    fn join_sentence(term: Term, parts: DroppedSentenceParts<Punctuation, Stamp, Truth>) -> Self;
+}
@@ -0,0 +1,107 @@
+//! 抽象API「预算值」
+//! * 🎯与「证据值」类似，统一「预算值」的读写接口
+//!   * 📄优先级、耐久度、质量
+//! * 🚩数值类型同样限定为[「证据数值」](EvidentNumber)
+//!   * 📌三者均处在 0≤x≤1 的范围
+
+use super::EvidentNumber;
+
+/// 抽象API「预算值」
+/// * 🚩【2024-04-17 10:33:56】与「证据值」一致：**不强制要求返回引用**
+pub trait BudgetValue<V: EvidentNumber> {
+    /// 获取「优先级」
+    fn get_priority(&self) -> V;
+
+    /// 获取「耐久度」
+    fn get_duality(&self) -> V;
+
+    /// 获取「质量」
+    fn get_quality(&self) -> V;
+
+    /// 获取「(优先级, 耐久度, 质量)」
+    /// * 🎯一次性获取三者
+    #[inline(always)]
+    fn get_pdq(&self) -> (V, V, V) {
+        (self.get_priority(), self.get_duality(), self.get_quality())
+    }
+}
+
+/// 抽象API「可变预算值」
+/// * 🎯供调度层直接修改预算值
+pub trait BudgetValueMut<V>: BudgetValue<V>
+where
+    V: EvidentNumber,
+{
+    /// 设置「优先级」
+    /// * 🚩与「可变证据值」一致，使用不可变引用传参
+    fn set_priority(&mut self, new_p: &V);
+
+    /// 设置「耐久度」
+    fn set_duality(&mut self, new_d: &V);
+
+    /// 设置「质量」
+    fn set_quality(&mut self, new_q: &V);
+
+    /// 同时设置「优先级」「耐久度」与「质量」
+    /// * 📜默认实现：依次设置三者
+    #[inline(always)]
+    fn set_pdq(&mut self, new_p: &V, new_d: &V, new_q: &V) {
+        self.set_priority(new_p);
+        self.set_duality(new_d);
+        self.set_quality(new_q);
+    }
+}
+
+/// 为三元组`(p, d, q)`自动实现「预算值」与「可变预算值」
+/// * 🚩与「证据值」对二元组的实现一致
+impl<V: EvidentNumber> BudgetValue<V> for (V, V, V) {
+    fn get_priority(&self) -> V {
+        self.0
+    }
+
+    fn get_duality(&self) -> V {
+        self.1
+    }
+
+    fn get_quality(&self) -> V {
+        self.2
+    }
+}
+
+impl<V: EvidentNumber> BudgetValueMut<V> for (V, V, V) {
+    fn set_priority(&mut self, new_p: &V) {
+        self.0 = *new_p;
+    }
+
+    fn set_duality(&mut self, new_d: &V) {
+        self.1 = *new_d;
+    }
+
+    fn set_quality(&mut self, new_q: &V) {
+        self.2 = *new_q;
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nar_dev_utils::asserts;
+
+    /// 测试/三元组预算值
+    #[test]
+    fn test_tuple() {
+        let mut budget = (0.5, 0.5, 0.5);
+        budget.set_priority(&0.9);
+        budget.set_quality(&0.1);
+        asserts! {
+            budget.get_pdq() => (0.9, 0.5, 0.1),
+        }
+        budget.set_pdq(&0.1, &0.2, &0.3);
+        asserts! {
+            budget.get_priority() => 0.1,
+            budget.get_duality() => 0.2,
+            budget.get_quality() => 0.3,
+        }
+    }
+}
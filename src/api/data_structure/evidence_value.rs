@@ -329,6 +329,7 @@ mod test {
     ///     * 💭特征要求问题：需要都实现「可变证据值」
     /// * 🚩【2024-04-17 12:49:38】目前方案：基于「可变证据值」辅以「快捷辅助函数」兼顾「高性能」与「简洁性」
     /// * 🎯【2024-04-17 12:50:24】亦用作NAL真值函数的有关示范
+    #[allow(dead_code)]
     trait TruthWithFunctions<V>
     where
         Self: EvidentValueMut<V>,
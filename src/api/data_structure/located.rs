@@ -0,0 +1,128 @@
+//! 定义「带源码位置的值」
+//! * 🎯为「批量解析/折叠」提供「行号/列号/来源名」等元信息
+//!   * 📄如：解析一万行的`.nal`文件后，在折叠出错时仍能报告`file.nal:123`
+//! * 📌只是一个「附加元信息」的包装，不影响原有的「纯值」API
+
+use std::{fmt::Display, sync::Arc};
+
+/// 带源码位置的值
+/// * 🎯在「解析→折叠→校验」的流程中传递「原始位置」
+/// * 📌行号、列号均从`1`开始计数
+///   * 🚩列号按「字符」而非「字节」计数
+/// * 📌来源名可选：如文件名`file.nal`
+///   * 🚩使用[`Arc<str>`]以便在大量条目间廉价共享
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Located<T> {
+    /// 被包装的值
+    pub value: T,
+    /// 所在行号（从1开始）
+    pub line: usize,
+    /// 所在列号（从1开始）
+    pub col: usize,
+    /// 来源名称（可选）
+    pub source_name: Option<Arc<str>>,
+}
+
+impl<T> Located<T> {
+    /// 构造函数
+    pub fn new(value: T, line: usize, col: usize, source_name: Option<Arc<str>>) -> Self {
+        Self {
+            value,
+            line,
+            col,
+            source_name,
+        }
+    }
+
+    /// 对内部值进行映射，保留位置信息
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Located<U> {
+        Located {
+            value: f(self.value),
+            line: self.line,
+            col: self.col,
+            source_name: self.source_name,
+        }
+    }
+
+    /// 以新值替换内部值，保留位置信息
+    pub fn with_value<U>(&self, value: U) -> Located<U> {
+        Located {
+            value,
+            line: self.line,
+            col: self.col,
+            source_name: self.source_name.clone(),
+        }
+    }
+
+    /// 获取内部值的引用（连同位置信息）
+    pub fn as_ref(&self) -> Located<&T> {
+        self.with_value(&self.value)
+    }
+
+    /// 拆出内部值，丢弃位置信息
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// 生成位置字符串
+    /// * 📄有来源名：`file.nal:123:4`
+    /// * 📄无来源名：`123:4`
+    pub fn location(&self) -> String {
+        match &self.source_name {
+            Some(name) => format!("{name}:{}:{}", self.line, self.col),
+            None => format!("{}:{}", self.line, self.col),
+        }
+    }
+}
+
+/// 对「结果」的特别支持
+/// * 🎯将「带位置的结果」拆分为「带位置的值」或「带位置的错误」
+impl<T, E> Located<Result<T, E>> {
+    /// 将位置信息分派到[`Ok`]与[`Err`]中
+    pub fn transpose(self) -> Result<Located<T>, Located<E>> {
+        let Located {
+            value,
+            line,
+            col,
+            source_name,
+        } = self;
+        match value {
+            Ok(value) => Ok(Located::new(value, line, col, source_name)),
+            Err(error) => Err(Located::new(error, line, col, source_name)),
+        }
+    }
+}
+
+/// 呈现「位置: 值」
+/// * 🎯用于错误报告，如`file.nal:123:1: 非法原子词项词缀「^^」`
+impl<T: Display> Display for Located<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.location(), self.value)
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nar_dev_utils::asserts;
+
+    #[test]
+    fn test_located() {
+        let name: Arc<str> = Arc::from("file.nal");
+        let located = Located::new("A", 123, 4, Some(name));
+        asserts! {
+            located.location() => "file.nal:123:4",
+            located.to_string() => "file.nal:123:4: A",
+            located.clone().map(str::len).value => 1,
+            Located::new(0, 1, 2, None).location() => "1:2",
+        }
+        // 结果分派
+        let ok: Located<Result<i32, &str>> = located.with_value(Ok(1));
+        let err: Located<Result<i32, &str>> = located.with_value(Err("e"));
+        asserts! {
+            ok.transpose().unwrap().value => 1,
+            err.transpose().unwrap_err().location() => "file.nal:123:4",
+        }
+    }
+}
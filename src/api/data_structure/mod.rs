@@ -4,6 +4,7 @@
 //! * 🎯与「真值/预算值」有关的共用API
 //!   * 📄其通用的「证据值」特征
 //!     * 🚩【2024-04-16 18:46:06】后续可以用来泛化「真值函数」
+//!   * 📄「预算值」的读写接口
 
 nar_dev_utils::pub_mod_and_pub_use! {
     // Narsese值
@@ -14,4 +15,8 @@ nar_dev_utils::pub_mod_and_pub_use! {
     term
     // 证据值
     evidence_value
+    // 预算值
+    budget_value
+    // 带源码位置的值
+    located
 }
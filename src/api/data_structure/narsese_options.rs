@@ -21,6 +21,35 @@ pub struct NarseseOptions<Budget, Term, Punctuation, Stamp, Truth> {
     pub truth: Option<Truth>,
 }
 
+/// 「可选Narsese」中的条目种类
+/// * 🎯标识[`NarseseOptions`]中的各个字段
+///   * 📄补全提示：「已有词项和`%`，期待真值」
+/// * 📌顺序遵循ASCII Narsese格式，同[`NarseseOptions`]的字段顺序
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemKind {
+    /// 预算值
+    Budget,
+    /// 词项
+    Term,
+    /// 标点
+    Punctuation,
+    /// 时间戳
+    Stamp,
+    /// 真值
+    Truth,
+}
+
+impl ItemKind {
+    /// 所有条目种类 | 按ASCII Narsese格式中的顺序
+    pub const ALL: [ItemKind; 5] = [
+        ItemKind::Budget,
+        ItemKind::Term,
+        ItemKind::Punctuation,
+        ItemKind::Stamp,
+        ItemKind::Truth,
+    ];
+}
+
 // 基础功能实现
 impl<Budget, Term, Punctuation, Stamp, Truth>
     NarseseOptions<Budget, Term, Punctuation, Stamp, Truth>
@@ -74,6 +103,27 @@ impl<Budget, Term, Punctuation, Stamp, Truth>
         self.truth.take()
     }
 
+    /// 判断其中是否具有某种条目
+    pub fn has_item(&self, kind: ItemKind) -> bool {
+        match kind {
+            ItemKind::Budget => self.budget.is_some(),
+            ItemKind::Term => self.term.is_some(),
+            ItemKind::Punctuation => self.punctuation.is_some(),
+            ItemKind::Stamp => self.stamp.is_some(),
+            ItemKind::Truth => self.truth.is_some(),
+        }
+    }
+
+    /// 列出其中缺失的条目
+    /// * 🚩按[`ItemKind::ALL`]的顺序
+    /// * 📄仅有预算值⇒缺失「词项」「标点」「时间戳」「真值」
+    pub fn missing_items(&self) -> Vec<ItemKind> {
+        ItemKind::ALL
+            .into_iter()
+            .filter(|&kind| !self.has_item(kind))
+            .collect()
+    }
+
     /// 判断其中是否具有「语句」
     /// * 🚩条件：同时具有「词项」「标点」
     /// * 💭【2024-06-13 20:33:03】可能「时间戳」「真值」不一定有
@@ -1,7 +1,7 @@
 //! 定义集成「词项/语句/任务」的通用Narsese枚举
 //! * 🎯提供「与具体实现无关」的Narsese数据结构表征
 
-use std::io::ErrorKind;
+use std::fmt::{Display, Formatter, Result as FmtResult};
 
 /// 定义「CommonNarsese值」类型
 /// * 🎯用于存储「词项/语句/任务」三者其一
@@ -20,12 +20,47 @@ pub enum NarseseValue<Term, Sentence, Task> {
     Task(Task),
 }
 
+/// 「Narsese值」类型不匹配的错误
+/// * 🎯在「词项/语句/任务」间向下转换失败时返回
+/// * 📌不再借用[`std::io::Error`]：可直接取得实际类型与目标类型
+/// * 🚩兼容原先的错误类型：可经[`From`]转换为[`std::io::Error`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NarseseCastError {
+    /// 实际的类型名
+    pub found: &'static str,
+    /// 期望转换到的类型名
+    pub target: &'static str,
+}
+
+impl NarseseCastError {
+    /// 构造函数
+    pub fn new(found: &'static str, target: &'static str) -> Self {
+        Self { found, target }
+    }
+}
+
+impl Display for NarseseCastError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "类型「{}」不匹配，无法转换为{}", self.found, self.target)
+    }
+}
+
+impl std::error::Error for NarseseCastError {}
+
+/// 兼容原先基于[`std::io::Error`]的错误
+/// * 📌错误种类同原先：[`std::io::ErrorKind::InvalidData`]
+impl From<NarseseCastError> for std::io::Error {
+    fn from(error: NarseseCastError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, error)
+    }
+}
+
 /// ! 无法自动实现[`TryFrom`]和[`TryInto`]：违反「孤儿规则」
 /// ! ⚠️亦即：禁止在泛型枚举中实现类似`impl<Term, Sentence, Task> TryFrom<NarseseValue<Term, Sentence, Task>> for Term`的代码
 /// * 📝经验：尽可能不要使用「没有经过约束就应用到所有类型」的实现
 impl<Term, Sentence, Task> NarseseValue<Term, Sentence, Task> {
     /// 获取名称（简体中文）
-    pub(crate) fn type_name(&self) -> &str {
+    pub(crate) fn type_name(&self) -> &'static str {
         match self {
             Self::Term(..) => "词项",
             Self::Sentence(..) => "语句",
@@ -51,50 +86,93 @@ impl<Term, Sentence, Task> NarseseValue<Term, Sentence, Task> {
     /// 尝试转换到词项
     /// * 🚩判断是否为其中的「词项」变体，然后向下转换
     ///   * 若否，则返回错误
-    pub fn try_into_term(self) -> Result<Term, std::io::Error> {
+    pub fn try_into_term(self) -> Result<Term, NarseseCastError> {
         match self {
             Self::Term(term) => Ok(term),
-            _ => Err(std::io::Error::new(
-                ErrorKind::InvalidData,
-                format!("类型「{}」不匹配，无法转换为词项", self.type_name()),
-            )),
+            _ => Err(NarseseCastError::new(self.type_name(), "词项")),
         }
     }
 
     /// 尝试转换到语句
     /// * 🚩判断是否为其中的「语句」变体，然后向下转换
     ///   * 若否，则返回错误
-    pub fn try_into_sentence(self) -> Result<Sentence, std::io::Error> {
+    pub fn try_into_sentence(self) -> Result<Sentence, NarseseCastError> {
         match self {
             Self::Sentence(sentence) => Ok(sentence),
-            _ => Err(std::io::Error::new(
-                ErrorKind::InvalidData,
-                format!("类型「{}」不匹配，无法转换为语句", self.type_name()),
-            )),
+            _ => Err(NarseseCastError::new(self.type_name(), "语句")),
         }
     }
 
     /// 尝试转换到任务
     /// * 🚩判断是否为其中的「任务」变体，然后向下转换
     ///   * 若否，则返回错误
-    pub fn try_into_task(self) -> Result<Task, std::io::Error> {
+    pub fn try_into_task(self) -> Result<Task, NarseseCastError> {
         match self {
             Self::Task(task) => Ok(task),
-            _ => Err(std::io::Error::new(
-                ErrorKind::InvalidData,
-                format!("类型「{}」不匹配，无法转换为任务", self.type_name()),
-            )),
+            _ => Err(NarseseCastError::new(self.type_name(), "任务")),
+        }
+    }
+
+    /// 以引用形式获取词项
+    /// * 🚩是「词项」变体⇒[`Some`]；否则⇒[`None`]
+    pub fn as_term(&self) -> Option<&Term> {
+        match self {
+            Self::Term(term) => Some(term),
+            _ => None,
+        }
+    }
+
+    /// 以引用形式获取语句
+    /// * 🚩是「语句」变体⇒[`Some`]；否则⇒[`None`]
+    pub fn as_sentence(&self) -> Option<&Sentence> {
+        match self {
+            Self::Sentence(sentence) => Some(sentence),
+            _ => None,
         }
     }
 
+    /// 以引用形式获取任务
+    /// * 🚩是「任务」变体⇒[`Some`]；否则⇒[`None`]
+    pub fn as_task(&self) -> Option<&Task> {
+        match self {
+            Self::Task(task) => Some(task),
+            _ => None,
+        }
+    }
+
+    /// 尝试以引用形式获取词项
+    /// * 🚩同[`Self::as_term`]，但失败时返回[`NarseseCastError`]
+    /// * 📌与[`Self::try_into_term`]相对：不消耗自身
+    pub fn try_as_term(&self) -> Result<&Term, NarseseCastError> {
+        self.as_term()
+            .ok_or_else(|| NarseseCastError::new(self.type_name(), "词项"))
+    }
+
+    /// 尝试以引用形式获取语句
+    /// * 🚩同[`Self::as_sentence`]，但失败时返回[`NarseseCastError`]
+    /// * 📌与[`Self::try_into_sentence`]相对：不消耗自身
+    pub fn try_as_sentence(&self) -> Result<&Sentence, NarseseCastError> {
+        self.as_sentence()
+            .ok_or_else(|| NarseseCastError::new(self.type_name(), "语句"))
+    }
+
+    /// 尝试以引用形式获取任务
+    /// * 🚩同[`Self::as_task`]，但失败时返回[`NarseseCastError`]
+    /// * 📌与[`Self::try_into_task`]相对：不消耗自身
+    pub fn try_as_task(&self) -> Result<&Task, NarseseCastError> {
+        self.as_task()
+            .ok_or_else(|| NarseseCastError::new(self.type_name(), "任务"))
+    }
+
     /// 从词项到Narsese值
     /// * 🚩直接打包
     ///
     /// * 📝虽说通过[`From`]实现不违反「孤儿规则」：「实现者」[`NarseseValue`]是在此定义的
+    ///
     /// ! ⚠️但若继续通过[`From`]实现（代码：`impl<Term, Sentence, Task> From<Sentence> for NarseseValue<Term, Sentence, Task>`）的话，
-    /// * 则「词项→Narsese值」「语句→Narsese值」「任务→Narsese值」会相互冲突
-    ///   * 📌编译器无法断定「词项」「语句」「任务」三者**一定不相同**
-    ///   * ❌因此可能会有「重复实现」⇒报错「冲突的实现」
+    ///   * 则「词项→Narsese值」「语句→Narsese值」「任务→Narsese值」会相互冲突
+    ///     * 📌编译器无法断定「词项」「语句」「任务」三者**一定不相同**
+    ///     * ❌因此可能会有「重复实现」⇒报错「冲突的实现」
     pub fn from_term(value: Term) -> Self {
         Self::Term(value)
     }
@@ -111,3 +189,40 @@ impl<Term, Sentence, Task> NarseseValue<Term, Sentence, Task> {
         Self::Task(value)
     }
 }
+
+/// 为具体的「词项/语句/任务」类型实现与[`NarseseValue`]间的相互转换
+/// * 🎯绕开「孤儿规则」：泛型实现不可行，但对具体类型的实现可行
+///   * 📄枚举Narsese：`crate::enum_narsese`
+///   * 📄词法Narsese：`crate::lexical`
+/// * 🚩[`From`]：具体类型→Narsese值
+///   * 📌三者为互不相同的具体类型，不会「冲突的实现」
+/// * 🚩[`TryFrom`]：Narsese值→具体类型
+///   * 📌错误类型统一为[`NarseseCastError`]
+#[cfg(any(feature = "enum_narsese", feature = "lexical_narsese"))]
+macro_rules! impl_narsese_value_conversions {
+    ($term:ty, $sentence:ty, $task:ty) => {
+        $crate::api::impl_narsese_value_conversions! {
+            @SINGLE $crate::api::NarseseValue<$term, $sentence, $task>;
+            $term => from_term, try_into_term;
+            $sentence => from_sentence, try_into_sentence;
+            $task => from_task, try_into_task;
+        }
+    };
+    (@SINGLE $value:ty; $($t:ty => $from:ident, $try_into:ident;)*) => {
+        $(
+            impl From<$t> for $value {
+                fn from(value: $t) -> Self {
+                    Self::$from(value)
+                }
+            }
+            impl TryFrom<$value> for $t {
+                type Error = $crate::api::NarseseCastError;
+                fn try_from(value: $value) -> Result<Self, Self::Error> {
+                    value.$try_into()
+                }
+            }
+        )*
+    };
+}
+#[cfg(any(feature = "enum_narsese", feature = "lexical_narsese"))]
+pub(crate) use impl_narsese_value_conversions;
@@ -0,0 +1,108 @@
+//! 定义抽象的「复合词项连接符」「陈述系词」API
+//! * 🎯以「种类」而非「字符串」指代连接符、系词
+//!   * 📄词项构造器：`enum_narsese::TermBuilder`
+//!   * 📌后续亦可供各格式共用：格式只需给出「种类→字符串」的映射
+//! * 📌不含词项集：其以括弧而非连接符表示
+
+use super::TermCapacity;
+
+/// 复合词项连接符
+/// * 🚩与「枚举Narsese」中的复合词项一一对应（词项集除外）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Connecter {
+    /// 外延交 | `&`
+    IntersectionExtension,
+    /// 内涵交 | `|`
+    IntersectionIntension,
+    /// 外延差 | `-`
+    DifferenceExtension,
+    /// 内涵差 | `~`
+    DifferenceIntension,
+    /// 乘积 | `*`
+    Product,
+    /// 外延像 | `/`
+    ImageExtension,
+    /// 内涵像 | `\`
+    ImageIntension,
+    /// 合取 | `&&`
+    Conjunction,
+    /// 析取 | `||`
+    Disjunction,
+    /// 否定 | `--`
+    Negation,
+    /// 顺序合取 | `&/`
+    ConjunctionSequential,
+    /// 平行合取 | `&|`
+    ConjunctionParallel,
+}
+
+impl Connecter {
+    /// 获取所构造复合词项的「容量」
+    /// * 📄同[`GetCapacity`](super::GetCapacity)
+    pub fn capacity(&self) -> TermCapacity {
+        use Connecter::*;
+        match self {
+            Negation => TermCapacity::Unary,
+            DifferenceExtension | DifferenceIntension => TermCapacity::BinaryVec,
+            Product | ImageExtension | ImageIntension | ConjunctionSequential => TermCapacity::Vec,
+            IntersectionExtension | IntersectionIntension | Conjunction | Disjunction
+            | ConjunctionParallel => TermCapacity::Set,
+        }
+    }
+
+    /// 是否为「像」
+    /// * 🎯像的组分中须有且仅有一个占位符
+    pub fn is_image(&self) -> bool {
+        matches!(self, Connecter::ImageExtension | Connecter::ImageIntension)
+    }
+}
+
+/// 陈述系词
+/// * 🚩与「枚举Narsese」中的陈述一一对应，另含三个派生系词
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Copula {
+    /// 继承 | `-->`
+    Inheritance,
+    /// 相似 | `<->`
+    Similarity,
+    /// 蕴含 | `==>`
+    Implication,
+    /// 等价 | `<=>`
+    Equivalence,
+    /// 实例（派生） | `{--`
+    Instance,
+    /// 属性（派生） | `--]`
+    Property,
+    /// 实例属性（派生） | `{-]`
+    InstanceProperty,
+    /// 预测性蕴含 | `=/>`
+    ImplicationPredictive,
+    /// 并发性蕴含 | `=|>`
+    ImplicationConcurrent,
+    /// 回顾性蕴含 | `=\>`
+    ImplicationRetrospective,
+    /// 预测性等价 | `</>`
+    EquivalencePredictive,
+    /// 并发性等价 | `<|>`
+    EquivalenceConcurrent,
+    /// 回顾性等价 | `<\>`
+    EquivalenceRetrospective,
+}
+
+impl Copula {
+    /// 获取所构造陈述的「容量」
+    /// * 📌对称的系词⇒二元集合，其余⇒二元序列
+    pub fn capacity(&self) -> TermCapacity {
+        use Copula::*;
+        match self {
+            Similarity | Equivalence | EquivalenceConcurrent => TermCapacity::BinarySet,
+            _ => TermCapacity::BinaryVec,
+        }
+    }
+
+    /// 是否为派生系词
+    /// * 📄`{--`、`--]`、`{-]`：构造后实为「继承」
+    pub fn is_derived(&self) -> bool {
+        matches!(self, Copula::Instance | Copula::Property | Copula::InstanceProperty)
+    }
+}
@@ -13,4 +13,10 @@ nar_dev_utils::pub_mod_and_pub_use! {
     term_category
     // API「词项容量」
     term_capacity
+    // API「词项复杂度」
+    term_complexity
+    // API「词项路径」
+    term_path
+    // API「连接符与系词」
+    connecter_copula
 }
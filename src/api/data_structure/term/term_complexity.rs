@@ -0,0 +1,88 @@
+//! 定义抽象的「词项复杂度」API
+//! * 🎯用于NARS中的预算分配：语法上越复杂的词项，优先级越低
+//! * 📌复杂度：递归统计「原子词项」的总数
+//!   * 📌像占位符亦计为1
+//!   * 📌没有组分的复合词项（词法Narsese不做检验）计为1：相当于只计「连接符」本身
+//! * 📌深度：最大嵌套层数
+//!   * 📌原子词项为1
+//!   * 📌没有组分的复合词项亦为1
+
+/// 特征「获取词项复杂度」
+pub trait GetComplexity {
+    /// 获取词项的「复杂度」
+    /// * 🚩递归统计原子词项的总数
+    fn complexity(&self) -> usize;
+
+    /// 获取词项的「深度」
+    /// * 🚩原子词项为1，复合词项为「组分最大深度+1」
+    fn depth(&self) -> usize;
+}
+
+/// 工具函数：从组分计算复杂度
+/// * 🎯统一「没有组分⇒计为1」的规则
+pub fn complexity_of_components<'a, T: GetComplexity + 'a>(
+    components: impl IntoIterator<Item = &'a T>,
+) -> usize {
+    components
+        .into_iter()
+        .map(GetComplexity::complexity)
+        .sum::<usize>()
+        .max(1)
+}
+
+/// 工具函数：从组分计算深度
+/// * 🎯统一「没有组分⇒计为1」的规则
+pub fn depth_of_components<'a, T: GetComplexity + 'a>(
+    components: impl IntoIterator<Item = &'a T>,
+) -> usize {
+    components
+        .into_iter()
+        .map(GetComplexity::depth)
+        .max()
+        .map_or(1, |depth| depth + 1)
+}
+
+/// 单元测试
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nar_dev_utils::asserts;
+
+    /// 用于测试的最简词项：原子或一串组分
+    enum Tree {
+        Leaf,
+        Node(Vec<Tree>),
+    }
+    use Tree::*;
+
+    impl GetComplexity for Tree {
+        fn complexity(&self) -> usize {
+            match self {
+                Leaf => 1,
+                Node(children) => complexity_of_components(children),
+            }
+        }
+
+        fn depth(&self) -> usize {
+            match self {
+                Leaf => 1,
+                Node(children) => depth_of_components(children),
+            }
+        }
+    }
+
+    #[test]
+    fn test_components() {
+        let tree = Node(vec![Leaf, Node(vec![Leaf, Node(vec![Leaf, Leaf])]), Leaf]);
+        asserts! {
+            Leaf.complexity() => 1,
+            Leaf.depth() => 1,
+            tree.complexity() => 5,
+            tree.depth() => 4,
+            // 没有组分
+            Node(vec![]).complexity() => 1,
+            Node(vec![]).depth() => 1,
+            Node(vec![Node(vec![])]).depth() => 2,
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! 定义抽象的「词项路径」API
+//! * 🎯为词项树中的子词项提供「地址」
+//!   * 📄词项改写界面：选中、替换某个子词项
+//! * 📌路径中的每个索引，对应「当前词项的组分」中的位置
+//!   * 📌空路径⇒词项自身
+//!   * ⚠️组分的顺序由各Narsese实现自行规定
+
+use crate::api::UIntPrecision;
+use std::{error::Error, fmt::Display};
+
+/// 词项路径
+/// * 🚩实际上是「组分索引数组」的别名
+/// * 📄`<(*, A, B) --> C>`中，`[0, 1]`指向`B`
+pub type TermPath = Vec<UIntPrecision>;
+
+/// 路径错误
+/// * 🎯按路径访问、替换子词项失败时返回
+/// * 📌`depth`：出错时已走过的路径长度
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathError {
+    /// 索引超出组分范围
+    OutOfRange {
+        depth: UIntPrecision,
+        index: UIntPrecision,
+        len: UIntPrecision,
+    },
+    /// 路径未走完，却已到达原子词项
+    AtomReached { depth: UIntPrecision },
+    /// 替换会破坏「像」的占位符，或使占位符出现在像与乘积之外
+    /// * 📄将占位符替换为其它词项；将其它组分替换为占位符
+    /// * 📄将陈述、集合等的组分替换为占位符
+    Placeholder { depth: UIntPrecision },
+}
+
+impl Display for PathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathError::OutOfRange { depth, index, len } => {
+                write!(f, "路径第{depth}层的索引{index}超出组分范围（共{len}个组分）")
+            }
+            PathError::AtomReached { depth } => {
+                write!(f, "路径第{depth}层已到达原子词项，无法继续深入")
+            }
+            PathError::Placeholder { depth } => {
+                write!(f, "路径第{depth}层的替换会错置占位符")
+            }
+        }
+    }
+}
+
+impl Error for PathError {}
@@ -0,0 +1,108 @@
+//! 「扁平Narsese」：与具体实现无关的自有数据结构
+//! * 🎯供FFI（C ABI、PyO3等）使用：外部只需接触此模块中的类型
+//!   * 📌不依赖任何特性：无论启用「枚举Narsese」还是「词法Narsese」均可使用
+//! * 🚩结构与「词法Narsese」一致，但数值已解析
+//!   * 📌词项：字符串形式的前缀、连接符、括弧、系词
+//!   * 📌语句、任务：时间戳为枚举，真值、预算值为[`f64`]数组
+//! * 🚩与各Narsese实现之间的转换见[`crate::conversion::inter_type::flat`]
+
+use super::NarseseValue;
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// 扁平词项
+/// * 📌与「词法词项」同构：仅含字符串与子词项
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FlatTerm {
+    /// 原子词项
+    Atom { prefix: String, name: String },
+    /// 复合词项
+    Compound {
+        connecter: String,
+        children: Vec<FlatTerm>,
+    },
+    /// 词项集
+    Set {
+        left_bracket: String,
+        children: Vec<FlatTerm>,
+        right_bracket: String,
+    },
+    /// 陈述
+    Statement {
+        copula: String,
+        subject: Box<FlatTerm>,
+        predicate: Box<FlatTerm>,
+    },
+}
+
+/// 扁平时间戳
+/// * 📌固定时间统一使用[`i64`]：不随平台变化
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FlatStamp {
+    /// 永恒
+    #[default]
+    Eternal,
+    /// 过去
+    Past,
+    /// 现在
+    Present,
+    /// 将来
+    Future,
+    /// 固定
+    Fixed(i64),
+}
+
+/// 扁平语句
+/// * 📌标点仍为字符串：其含义由格式决定
+/// * 📌无真值⇒空数组
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlatSentence {
+    /// 词项
+    pub term: FlatTerm,
+    /// 标点
+    pub punctuation: String,
+    /// 时间戳
+    pub stamp: FlatStamp,
+    /// 真值
+    pub truth: Vec<f64>,
+}
+
+/// 扁平任务
+/// * 📌无预算值⇒空数组
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlatTask {
+    /// 预算值
+    pub budget: Vec<f64>,
+    /// 语句
+    pub sentence: FlatSentence,
+}
+
+/// 扁平Narsese值
+pub type FlatNarsese = NarseseValue<FlatTerm, FlatSentence, FlatTask>;
+
+/// 转换为「扁平Narsese」时的错误
+/// * 📄非法的时间戳、无法解析的真值/预算值
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FlatError(String);
+
+impl FlatError {
+    /// 构造函数
+    pub fn new(message: impl Into<String>) -> Self {
+        Self(message.into())
+    }
+
+    /// 错误信息
+    pub fn message(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for FlatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for FlatError {}
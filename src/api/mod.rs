@@ -13,4 +13,6 @@ nar_dev_utils::pub_mod_and_pub_use! {
     data_structure
     // 转换
     conversion
+    // 扁平Narsese | 供FFI使用
+    flat
 }
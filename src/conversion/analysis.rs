@@ -0,0 +1,321 @@
+//! Narsese语料统计
+//! * 🎯分析`.nal`等语料：判断与问题各有多少、系词分布如何、词项平均有多复杂……
+//! * 🚩逐行解析，出错的行不会中止统计，而是收集其解析错误
+//!   * 📌空行（仅含空白符）跳过，不计入行数
+//! * 🚩统一在「词法Narsese」上统计
+//!   * 📌词法格式：直接解析
+//!   * 📌枚举格式：解析后[展开](crate::enum_narsese::Narsese::to_lexical)为词法Narsese
+//!   * ✨两类格式对同一语料的统计结果一致
+//! * 📌系词、连接符、标点均以「格式中的字符串」作键
+//!   * 📄词项集以「左右括弧拼接」作键：`{}`、`[]`
+
+use crate::{
+    api::{GetComplexity, GetTerm},
+    conversion::string::impl_lexical::{NarseseFormat, ParseError},
+    lexical::{Narsese, Sentence, StampKind, Term},
+};
+use std::collections::BTreeMap;
+
+/// 各类时间戳的计数
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StampCounts {
+    /// 永恒
+    pub eternal: usize,
+    /// 过去
+    pub past: usize,
+    /// 现在
+    pub present: usize,
+    /// 将来
+    pub future: usize,
+    /// 固定 | 不区分具体时间
+    pub fixed: usize,
+}
+
+impl StampCounts {
+    /// 计入一个时间戳
+    pub fn tally(&mut self, kind: StampKind) {
+        let count = match kind {
+            StampKind::Eternal => &mut self.eternal,
+            StampKind::Past => &mut self.past,
+            StampKind::Present => &mut self.present,
+            StampKind::Future => &mut self.future,
+            StampKind::Fixed(..) => &mut self.fixed,
+        };
+        *count += 1;
+    }
+}
+
+/// 语料统计结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CorpusStats {
+    /// 参与统计的行数 | 含解析失败的行，不含空行
+    pub lines: usize,
+    /// 解析出的词项个数
+    pub terms: usize,
+    /// 解析出的语句个数
+    pub sentences: usize,
+    /// 解析出的任务个数
+    pub tasks: usize,
+    /// 各标点的计数 | 语句与任务
+    pub punctuations: BTreeMap<String, usize>,
+    /// 各类时间戳的计数 | 语句与任务
+    pub stamps: StampCounts,
+    /// 带真值的语句/任务个数
+    pub with_truth: usize,
+    /// 带预算值的任务个数
+    pub with_budget: usize,
+    /// 各系词的计数 | 含嵌套的陈述
+    pub copulas: BTreeMap<String, usize>,
+    /// 各连接符的计数 | 含嵌套的复合词项、词项集
+    pub connecters: BTreeMap<String, usize>,
+    /// 所有词项的复杂度之和
+    pub total_complexity: usize,
+}
+
+/// 工具函数：计数加一
+fn increment(map: &mut BTreeMap<String, usize>, key: impl Into<String>) {
+    *map.entry(key.into()).or_default() += 1;
+}
+
+impl CorpusStats {
+    /// 成功解析的个数
+    pub fn parsed(&self) -> usize {
+        self.terms + self.sentences + self.tasks
+    }
+
+    /// 词项的平均复杂度
+    /// * 📌没有成功解析的条目⇒[`None`]
+    pub fn average_complexity(&self) -> Option<f64> {
+        match self.parsed() {
+            0 => None,
+            n => Some(self.total_complexity as f64 / n as f64),
+        }
+    }
+
+    /// 计入一个词项（及其所有子词项）
+    fn tally_term(&mut self, term: &Term) {
+        self.total_complexity += term.complexity();
+        for term in term.iter_dfs() {
+            match term {
+                Term::Atom { .. } => {}
+                Term::Compound { connecter, .. } => increment(&mut self.connecters, connecter),
+                Term::Set {
+                    left_bracket,
+                    right_bracket,
+                    ..
+                } => increment(
+                    &mut self.connecters,
+                    format!("{left_bracket}{right_bracket}"),
+                ),
+                Term::Statement { copula, .. } => increment(&mut self.copulas, copula),
+            }
+        }
+    }
+
+    /// 计入一个语句
+    fn tally_sentence(&mut self, sentence: &Sentence, stamp: StampKind) {
+        increment(&mut self.punctuations, &sentence.punctuation);
+        self.stamps.tally(stamp);
+        if !sentence.truth.is_empty() {
+            self.with_truth += 1;
+        }
+    }
+
+    /// 计入一个解析结果
+    /// * 📌时间戳的语义由调用方提供：词法Narsese中只有字符串
+    fn tally(&mut self, narsese: &Narsese, stamp: StampKind) {
+        self.tally_term(narsese.get_term());
+        match narsese {
+            Narsese::Term(..) => self.terms += 1,
+            Narsese::Sentence(sentence) => {
+                self.sentences += 1;
+                self.tally_sentence(sentence, stamp);
+            }
+            Narsese::Task(task) => {
+                self.tasks += 1;
+                self.tally_sentence(&task.sentence, stamp);
+                if !task.budget.is_empty() {
+                    self.with_budget += 1;
+                }
+            }
+        }
+    }
+}
+
+/// 可用于语料统计的格式
+/// * 🎯同时支持词法格式与枚举格式
+pub trait AnalyzeFormat {
+    /// 解析错误的类型
+    type Error;
+
+    /// 解析一行，得到词法Narsese及其时间戳语义
+    /// * 📌词项⇒时间戳为永恒（不会被计入）
+    fn parse_for_analysis(&self, line: &str) -> Result<(Narsese, StampKind), Self::Error>;
+}
+
+/// 词法格式：直接解析
+impl AnalyzeFormat for NarseseFormat {
+    type Error = ParseError;
+
+    fn parse_for_analysis(&self, line: &str) -> Result<(Narsese, StampKind), ParseError> {
+        let narsese = self.parse(line)?;
+        let stamp = match &narsese {
+            Narsese::Term(..) => StampKind::Eternal,
+            Narsese::Sentence(Sentence { stamp, .. }) => self.parse_stamp_kind(stamp)?,
+            Narsese::Task(task) => self.parse_stamp_kind(&task.sentence.stamp)?,
+        };
+        Ok((narsese, stamp))
+    }
+}
+
+/// 枚举格式：解析后展开为词法Narsese
+#[cfg(feature = "enum_narsese")]
+impl AnalyzeFormat for crate::conversion::string::impl_enum::NarseseFormat<&str> {
+    type Error = crate::conversion::string::impl_enum::ParseError;
+
+    fn parse_for_analysis(&self, line: &str) -> Result<(Narsese, StampKind), Self::Error> {
+        use crate::{
+            api::GetStamp,
+            enum_narsese::{Narsese as EnumNarsese, Stamp},
+        };
+        let narsese = self.parse::<EnumNarsese>(line)?;
+        let stamp = match &narsese {
+            EnumNarsese::Term(..) => &Stamp::Eternal,
+            EnumNarsese::Sentence(sentence) => sentence.get_stamp(),
+            EnumNarsese::Task(task) => task.get_sentence().get_stamp(),
+        };
+        let stamp = match *stamp {
+            Stamp::Eternal => StampKind::Eternal,
+            Stamp::Past => StampKind::Past,
+            Stamp::Present => StampKind::Present,
+            Stamp::Future => StampKind::Future,
+            Stamp::Fixed(time) => StampKind::Fixed(time as i64),
+        };
+        Ok((narsese.to_lexical(self), stamp))
+    }
+}
+
+/// 统计一份语料
+/// * 🚩逐行解析并计数；解析失败⇒收集错误，继续下一行
+/// * 📌空行跳过
+pub fn analyze<'a, Format: AnalyzeFormat + ?Sized>(
+    format: &Format,
+    lines: impl IntoIterator<Item = &'a str>,
+) -> (CorpusStats, Vec<Format::Error>) {
+    let mut stats = CorpusStats::default();
+    let mut errors = vec![];
+    for line in lines.into_iter().filter(|line| !line.trim().is_empty()) {
+        stats.lines += 1;
+        match format.parse_for_analysis(line) {
+            Ok((narsese, stamp)) => stats.tally(&narsese, stamp),
+            Err(error) => errors.push(error),
+        }
+    }
+    (stats, errors)
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversion::string::impl_lexical::format_instances::FORMAT_ASCII;
+    use nar_dev_utils::{asserts, show};
+
+    /// 测试用语料
+    /// * 📌20行，其中2行格式错误，另有空行
+    const CORPUS: &str = r"
+<robin --> bird>.
+<bird --> animal>. %1.0;0.9%
+<robin --> animal>?
+<(*, {SELF}, door) --> ^open>! :|: %1.0;0.9%
+$0.8;0.5;0.5$ <(&&, <$x --> bird>, <$x --> [flying]>) ==> <$x --> animal>>. %0.9;0.8%
+<robin <-> swan>. :\: %1.0;0.9%
+(&&, (--, <robin --> fish>), <robin --> bird>). %0.9;0.73%
+<?x --> bird>?
+
+$0.5;0.5$ <SELF --> [happy]>! :/:
+<{tweety} --> [yellow]>. :!10: %1.0;0.9%
+<(&/, <A --> B>, ^go) =/> <C --> D>>.
+<robin -- bird>.
+<A <=> B>@
+(*, A, B)
+<(/, REPRESENT, _, CAT) --> cat>.
+$0.9$ <A --> B>.
+<A ==> B>. %1.0%
+<A --> >.
+<#y --> animal>? :|:
+swan
+";
+
+    #[test]
+    fn test_analyze() {
+        let (stats, errors) = analyze(&*FORMAT_ASCII, CORPUS.lines());
+        show!(&stats, &errors);
+        let counts = |pairs: &[(&str, usize)]| {
+            pairs
+                .iter()
+                .map(|&(key, count)| (key.to_string(), count))
+                .collect::<BTreeMap<_, _>>()
+        };
+        asserts! {
+            errors.len() => 2,
+            stats.lines => 20,
+            stats.parsed() => 18,
+            stats.terms => 2,
+            stats.sentences => 13,
+            stats.tasks => 3,
+            stats.punctuations => counts(&[(".", 10), ("?", 3), ("!", 2), ("@", 1)]),
+            stats.stamps => StampCounts {
+                eternal: 11,
+                past: 1,
+                present: 2,
+                future: 1,
+                fixed: 1,
+            },
+            stats.with_truth => 7,
+            stats.with_budget => 3,
+            stats.copulas => counts(&[
+                ("-->", 17),
+                ("<->", 1),
+                ("==>", 2),
+                ("<=>", 1),
+                ("=/>", 1),
+            ]),
+            stats.connecters => counts(&[
+                ("*", 2),
+                ("{}", 2),
+                ("[]", 3),
+                ("&&", 2),
+                ("--", 1),
+                ("&/", 1),
+                ("/", 1),
+            ]),
+        }
+        assert!(stats.average_complexity().unwrap() > 1.0);
+    }
+
+    /// 空语料
+    #[test]
+    fn test_analyze_empty() {
+        let (stats, errors) = analyze(&*FORMAT_ASCII, ["", "  "]);
+        asserts! {
+            stats => CorpusStats::default(),
+            errors.is_empty(),
+            stats.average_complexity() => None,
+        }
+    }
+
+    /// 枚举格式与词法格式的统计结果一致
+    #[test]
+    #[cfg(feature = "enum_narsese")]
+    fn test_analyze_enum() {
+        use crate::conversion::string::impl_enum::format_instances::FORMAT_ASCII as ENUM_FORMAT_ASCII;
+        let (stats, errors) = analyze(&ENUM_FORMAT_ASCII, CORPUS.lines());
+        show!(&errors);
+        let (expected, expected_errors) = analyze(&*FORMAT_ASCII, CORPUS.lines());
+        asserts! {
+            errors.len() => expected_errors.len(),
+            stats => expected,
+        }
+    }
+}
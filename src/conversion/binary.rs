@@ -0,0 +1,794 @@
+//! 紧凑的二进制编码
+//! * 🎯缓存解析好的语料：启动时直接解码，无需重新解析文本
+//! * 📌基于「枚举Narsese」，手写实现，不依赖serde
+//! * 🚩「标签-长度-值」方案
+//!   * 📌每个值以「格式版本」字节开头，之后为「词项/语句/任务」标签
+//!     * 🎯多个值可直接首尾相接：[`decode`]返回所消耗的字节数，[`decode_all`]逐个解码
+//!   * 📌变种：一字节标签
+//!   * 📌长度、间隔、像的占位符索引：无符号LEB128变长整数
+//!   * 📌固定时间戳：先经ZigZag映射为无符号数，再按变长整数编码
+//!   * 📌字符串：变长整数长度 + UTF-8字节
+//!   * 📌真值、预算值：元数字节 + 各分量（`f64`小端序）
+//! * 📌无序容器（集合、合取等）：组分按[全序](Ord)排列
+//!   * 🎯同一值总是得到逐字节相同的编码，不依赖集合的遍历顺序
+//! * ⚠️解码不信任输入：损坏的输入⇒返回[`DecodeError`]而非panic
+//!   * 📌嵌套深度上限同解析器：[`DEFAULT_MAX_DEPTH`]
+
+use crate::{
+    api::{FloatPrecision, IntPrecision, UIntPrecision},
+    conversion::string::DEFAULT_MAX_DEPTH,
+    enum_narsese::{Budget, Narsese, Sentence, Stamp, Task, Term, TermBuildError, Truth},
+};
+use std::fmt::{self, Display, Formatter};
+use Term::*;
+
+/// 当前的格式版本
+/// * 📌编码格式变动时递增：旧版本的缓存⇒[`DecodeError::UnsupportedVersion`]
+pub const FORMAT_VERSION: u8 = 1;
+
+/// 各类标签
+mod tag {
+    // Narsese值 //
+    pub const NARSESE_TERM: u8 = 0;
+    pub const NARSESE_SENTENCE: u8 = 1;
+    pub const NARSESE_TASK: u8 = 2;
+
+    // 原子词项 //
+    pub const WORD: u8 = 0;
+    pub const PLACEHOLDER: u8 = 1;
+    pub const VARIABLE_INDEPENDENT: u8 = 2;
+    pub const VARIABLE_DEPENDENT: u8 = 3;
+    pub const VARIABLE_QUERY: u8 = 4;
+    pub const INTERVAL: u8 = 5;
+    pub const OPERATOR: u8 = 6;
+
+    // 复合词项 //
+    pub const SET_EXTENSION: u8 = 7;
+    pub const SET_INTENSION: u8 = 8;
+    pub const INTERSECTION_EXTENSION: u8 = 9;
+    pub const INTERSECTION_INTENSION: u8 = 10;
+    pub const DIFFERENCE_EXTENSION: u8 = 11;
+    pub const DIFFERENCE_INTENSION: u8 = 12;
+    pub const PRODUCT: u8 = 13;
+    pub const IMAGE_EXTENSION: u8 = 14;
+    pub const IMAGE_INTENSION: u8 = 15;
+    pub const CONJUNCTION: u8 = 16;
+    pub const DISJUNCTION: u8 = 17;
+    pub const NEGATION: u8 = 18;
+    pub const CONJUNCTION_SEQUENTIAL: u8 = 19;
+    pub const CONJUNCTION_PARALLEL: u8 = 20;
+
+    // 陈述 //
+    pub const INHERITANCE: u8 = 21;
+    pub const SIMILARITY: u8 = 22;
+    pub const IMPLICATION: u8 = 23;
+    pub const EQUIVALENCE: u8 = 24;
+    pub const IMPLICATION_PREDICTIVE: u8 = 25;
+    pub const IMPLICATION_CONCURRENT: u8 = 26;
+    pub const IMPLICATION_RETROSPECTIVE: u8 = 27;
+    pub const EQUIVALENCE_PREDICTIVE: u8 = 28;
+    pub const EQUIVALENCE_CONCURRENT: u8 = 29;
+    pub const EQUIVALENCE_RETROSPECTIVE: u8 = 30;
+
+    // 标点 //
+    pub const JUDGEMENT: u8 = 0;
+    pub const GOAL: u8 = 1;
+    pub const QUESTION: u8 = 2;
+    pub const QUEST: u8 = 3;
+
+    // 时间戳 //
+    pub const ETERNAL: u8 = 0;
+    pub const PAST: u8 = 1;
+    pub const PRESENT: u8 = 2;
+    pub const FUTURE: u8 = 3;
+    pub const FIXED: u8 = 4;
+}
+
+/// 解码错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// 输入意外结束
+    UnexpectedEof,
+    /// 不支持的格式版本
+    UnsupportedVersion(u8),
+    /// 未知的标签
+    /// * 📌`kind`为标签所属的类别，如「词项」「时间戳」
+    InvalidTag { kind: &'static str, tag: u8 },
+    /// 变长整数超出范围
+    VarintOverflow,
+    /// 字符串不是合法的UTF-8
+    InvalidUtf8,
+    /// 无法构造词项
+    /// * 📄不合法的原子词项名、越界的像占位符索引
+    InvalidTerm(TermBuildError),
+    /// 超出最大嵌套深度
+    MaxDepthExceeded,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEof => write!(f, "输入意外结束"),
+            Self::UnsupportedVersion(version) => write!(f, "不支持的格式版本：{version}"),
+            Self::InvalidTag { kind, tag } => write!(f, "未知的{kind}标签：{tag}"),
+            Self::VarintOverflow => write!(f, "变长整数超出范围"),
+            Self::InvalidUtf8 => write!(f, "字符串不是合法的UTF-8"),
+            Self::InvalidTerm(e) => write!(f, "无法构造词项：{e}"),
+            Self::MaxDepthExceeded => write!(f, "超出最大嵌套深度"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// 解码结果
+pub type DecodeResult<T> = Result<T, DecodeError>;
+
+// 编码 //
+
+/// 编码Narsese值，追加到`out`之后
+/// * 🚩格式版本 + Narsese值标签 + 内容
+pub fn encode(narsese: &Narsese, out: &mut Vec<u8>) {
+    out.push(FORMAT_VERSION);
+    match narsese {
+        Narsese::Term(term) => {
+            out.push(tag::NARSESE_TERM);
+            encode_term(term, out);
+        }
+        Narsese::Sentence(sentence) => {
+            out.push(tag::NARSESE_SENTENCE);
+            encode_sentence(sentence, out);
+        }
+        Narsese::Task(Task(sentence, budget)) => {
+            out.push(tag::NARSESE_TASK);
+            encode_sentence(sentence, out);
+            encode_budget(budget, out);
+        }
+    }
+}
+
+/// 编码无符号变长整数 | LEB128
+fn encode_varint(mut value: u64, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// 编码字符串
+fn encode_str(s: &str, out: &mut Vec<u8>) {
+    encode_varint(s.len() as u64, out);
+    out.extend_from_slice(s.as_bytes());
+}
+
+/// 编码浮点数序列 | 元数 + 各分量
+fn encode_floats(values: &[FloatPrecision], out: &mut Vec<u8>) {
+    out.push(values.len() as u8);
+    for value in values {
+        out.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+/// 编码词项序列 | 数目 + 各词项
+fn encode_terms<'t>(terms: impl ExactSizeIterator<Item = &'t Term>, out: &mut Vec<u8>) {
+    encode_varint(terms.len() as u64, out);
+    for term in terms {
+        encode_term(term, out);
+    }
+}
+
+/// 词项的标签
+fn term_tag(term: &Term) -> u8 {
+    match term {
+        Word(..) => tag::WORD,
+        Placeholder => tag::PLACEHOLDER,
+        VariableIndependent(..) => tag::VARIABLE_INDEPENDENT,
+        VariableDependent(..) => tag::VARIABLE_DEPENDENT,
+        VariableQuery(..) => tag::VARIABLE_QUERY,
+        Interval(..) => tag::INTERVAL,
+        Operator(..) => tag::OPERATOR,
+        SetExtension(..) => tag::SET_EXTENSION,
+        SetIntension(..) => tag::SET_INTENSION,
+        IntersectionExtension(..) => tag::INTERSECTION_EXTENSION,
+        IntersectionIntension(..) => tag::INTERSECTION_INTENSION,
+        DifferenceExtension(..) => tag::DIFFERENCE_EXTENSION,
+        DifferenceIntension(..) => tag::DIFFERENCE_INTENSION,
+        Product(..) => tag::PRODUCT,
+        ImageExtension(..) => tag::IMAGE_EXTENSION,
+        ImageIntension(..) => tag::IMAGE_INTENSION,
+        Conjunction(..) => tag::CONJUNCTION,
+        Disjunction(..) => tag::DISJUNCTION,
+        Negation(..) => tag::NEGATION,
+        ConjunctionSequential(..) => tag::CONJUNCTION_SEQUENTIAL,
+        ConjunctionParallel(..) => tag::CONJUNCTION_PARALLEL,
+        Inheritance(..) => tag::INHERITANCE,
+        Similarity(..) => tag::SIMILARITY,
+        Implication(..) => tag::IMPLICATION,
+        Equivalence(..) => tag::EQUIVALENCE,
+        ImplicationPredictive(..) => tag::IMPLICATION_PREDICTIVE,
+        ImplicationConcurrent(..) => tag::IMPLICATION_CONCURRENT,
+        ImplicationRetrospective(..) => tag::IMPLICATION_RETROSPECTIVE,
+        EquivalencePredictive(..) => tag::EQUIVALENCE_PREDICTIVE,
+        EquivalenceConcurrent(..) => tag::EQUIVALENCE_CONCURRENT,
+        EquivalenceRetrospective(..) => tag::EQUIVALENCE_RETROSPECTIVE,
+    }
+}
+
+/// 编码词项
+fn encode_term(term: &Term, out: &mut Vec<u8>) {
+    out.push(term_tag(term));
+    match term {
+        // 原子词项
+        Word(name)
+        | VariableIndependent(name)
+        | VariableDependent(name)
+        | VariableQuery(name)
+        | Operator(name) => encode_str(name, out),
+        Placeholder => {}
+        Interval(interval) => encode_varint(*interval as u64, out),
+        // 无序容器 | 按全序排列
+        SetExtension(terms)
+        | SetIntension(terms)
+        | IntersectionExtension(terms)
+        | IntersectionIntension(terms)
+        | Conjunction(terms)
+        | Disjunction(terms)
+        | ConjunctionParallel(terms) => {
+            let mut terms = terms.iter().collect::<Vec<_>>();
+            terms.sort();
+            encode_terms(terms.into_iter(), out)
+        }
+        // 有序容器
+        Product(terms) | ConjunctionSequential(terms) => encode_terms(terms.iter(), out),
+        ImageExtension(index, terms) | ImageIntension(index, terms) => {
+            encode_varint(*index as u64, out);
+            encode_terms(terms.iter(), out)
+        }
+        // 一元
+        Negation(term) => encode_term(term, out),
+        // 二元
+        DifferenceExtension(left, right)
+        | DifferenceIntension(left, right)
+        | Inheritance(left, right)
+        | Similarity(left, right)
+        | Implication(left, right)
+        | Equivalence(left, right)
+        | ImplicationPredictive(left, right)
+        | ImplicationConcurrent(left, right)
+        | ImplicationRetrospective(left, right)
+        | EquivalencePredictive(left, right)
+        | EquivalenceConcurrent(left, right)
+        | EquivalenceRetrospective(left, right) => {
+            encode_term(left, out);
+            encode_term(right, out);
+        }
+    }
+}
+
+/// 编码时间戳
+/// * 🚩固定时间戳：ZigZag映射，使绝对值小的负数亦编码得短
+fn encode_stamp(stamp: &Stamp, out: &mut Vec<u8>) {
+    match stamp {
+        Stamp::Eternal => out.push(tag::ETERNAL),
+        Stamp::Past => out.push(tag::PAST),
+        Stamp::Present => out.push(tag::PRESENT),
+        Stamp::Future => out.push(tag::FUTURE),
+        Stamp::Fixed(time) => {
+            out.push(tag::FIXED);
+            let time = *time as i64;
+            encode_varint(((time << 1) ^ (time >> 63)) as u64, out);
+        }
+    }
+}
+
+/// 编码真值
+fn encode_truth(truth: &Truth, out: &mut Vec<u8>) {
+    match *truth {
+        Truth::Empty => encode_floats(&[], out),
+        Truth::Single(f) => encode_floats(&[f], out),
+        Truth::Double(f, c) => encode_floats(&[f, c], out),
+        Truth::Triple(f, c, k) => encode_floats(&[f, c, k], out),
+    }
+}
+
+/// 编码预算值
+fn encode_budget(budget: &Budget, out: &mut Vec<u8>) {
+    match *budget {
+        Budget::Empty => encode_floats(&[], out),
+        Budget::Single(p) => encode_floats(&[p], out),
+        Budget::Double(p, d) => encode_floats(&[p, d], out),
+        Budget::Triple(p, d, q) => encode_floats(&[p, d, q], out),
+    }
+}
+
+/// 编码语句
+/// * 🚩标点 + 词项 + 时间戳 (+ 真值)
+fn encode_sentence(sentence: &Sentence, out: &mut Vec<u8>) {
+    let (punctuation, term, truth, stamp) = match sentence {
+        Sentence::Judgement(term, truth, stamp) => (tag::JUDGEMENT, term, Some(truth), stamp),
+        Sentence::Goal(term, truth, stamp) => (tag::GOAL, term, Some(truth), stamp),
+        Sentence::Question(term, stamp) => (tag::QUESTION, term, None, stamp),
+        Sentence::Quest(term, stamp) => (tag::QUEST, term, None, stamp),
+    };
+    out.push(punctuation);
+    encode_term(term, out);
+    encode_stamp(stamp, out);
+    if let Some(truth) = truth {
+        encode_truth(truth, out);
+    }
+}
+
+// 解码 //
+
+/// 从字节序列开头解码一个Narsese值
+/// * 📌返回「Narsese值」与「所消耗的字节数」：其后可继续解码下一个值
+pub fn decode(input: &[u8]) -> DecodeResult<(Narsese, usize)> {
+    let mut decoder = Decoder::new(input);
+    let narsese = decoder.narsese()?;
+    Ok((narsese, decoder.position))
+}
+
+/// 解码首尾相接的所有Narsese值
+/// * 🎯读取由多次[`encode`]追加而成的缓存
+/// * 🚩遇到错误即返回
+pub fn decode_all(mut input: &[u8]) -> DecodeResult<Vec<Narsese>> {
+    let mut result = vec![];
+    while !input.is_empty() {
+        let (narsese, len) = decode(input)?;
+        result.push(narsese);
+        input = &input[len..];
+    }
+    Ok(result)
+}
+
+/// 多元复合词项（像除外）的构造函数
+fn compound_constructor(tag: u8) -> Option<fn(Vec<Term>) -> Term> {
+    Some(match tag {
+        tag::SET_EXTENSION => Term::new_set_extension,
+        tag::SET_INTENSION => Term::new_set_intension,
+        tag::INTERSECTION_EXTENSION => Term::new_intersection_extension,
+        tag::INTERSECTION_INTENSION => Term::new_intersection_intension,
+        tag::PRODUCT => Term::new_product,
+        tag::CONJUNCTION => Term::new_conjunction,
+        tag::DISJUNCTION => Term::new_disjunction,
+        tag::CONJUNCTION_SEQUENTIAL => Term::new_conjunction_sequential,
+        tag::CONJUNCTION_PARALLEL => Term::new_conjunction_parallel,
+        _ => return None,
+    })
+}
+
+/// 二元复合词项、陈述的构造函数
+fn binary_constructor(tag: u8) -> Option<fn(Term, Term) -> Term> {
+    Some(match tag {
+        tag::DIFFERENCE_EXTENSION => Term::new_difference_extension,
+        tag::DIFFERENCE_INTENSION => Term::new_difference_intension,
+        tag::INHERITANCE => Term::new_inheritance,
+        tag::SIMILARITY => Term::new_similarity,
+        tag::IMPLICATION => Term::new_implication,
+        tag::EQUIVALENCE => Term::new_equivalence,
+        tag::IMPLICATION_PREDICTIVE => Term::new_implication_predictive,
+        tag::IMPLICATION_CONCURRENT => Term::new_implication_concurrent,
+        tag::IMPLICATION_RETROSPECTIVE => Term::new_implication_retrospective,
+        tag::EQUIVALENCE_PREDICTIVE => Term::new_equivalence_predictive,
+        tag::EQUIVALENCE_CONCURRENT => Term::new_equivalence_concurrent,
+        tag::EQUIVALENCE_RETROSPECTIVE => Term::new_equivalence_retrospective,
+        _ => return None,
+    })
+}
+
+/// 解码器
+/// * 📌记录当前位置与嵌套深度
+struct Decoder<'a> {
+    input: &'a [u8],
+    position: usize,
+    depth: usize,
+}
+
+impl<'a> Decoder<'a> {
+    /// 构造函数
+    fn new(input: &'a [u8]) -> Self {
+        Self {
+            input,
+            position: 0,
+            depth: 0,
+        }
+    }
+
+    /// 剩余的字节数
+    fn remaining(&self) -> usize {
+        self.input.len() - self.position
+    }
+
+    /// 读取若干字节
+    fn bytes(&mut self, len: usize) -> DecodeResult<&'a [u8]> {
+        if len > self.remaining() {
+            return Err(DecodeError::UnexpectedEof);
+        }
+        let bytes = &self.input[self.position..self.position + len];
+        self.position += len;
+        Ok(bytes)
+    }
+
+    /// 读取一个字节
+    fn byte(&mut self) -> DecodeResult<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    /// 读取无符号变长整数 | LEB128
+    fn varint(&mut self) -> DecodeResult<u64> {
+        let mut value = 0u64;
+        for i in 0..10 {
+            let byte = self.byte()?;
+            // 第十个字节只能容纳最高的一位
+            if i == 9 && byte > 1 {
+                return Err(DecodeError::VarintOverflow);
+            }
+            value |= ((byte & 0x7f) as u64) << (7 * i);
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(DecodeError::VarintOverflow)
+    }
+
+    /// 读取变长整数，作为长度、索引
+    fn usize(&mut self) -> DecodeResult<usize> {
+        usize::try_from(self.varint()?).map_err(|_| DecodeError::VarintOverflow)
+    }
+
+    /// 读取字符串
+    fn string(&mut self) -> DecodeResult<String> {
+        let len = self.usize()?;
+        let bytes = self.bytes(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| DecodeError::InvalidUtf8)
+    }
+
+    /// 读取浮点数序列
+    fn floats(&mut self, kind: &'static str) -> DecodeResult<Vec<FloatPrecision>> {
+        let len = self.byte()?;
+        if len > 3 {
+            return Err(DecodeError::InvalidTag { kind, tag: len });
+        }
+        (0..len)
+            .map(|_| {
+                let mut bytes = [0; 8];
+                bytes.copy_from_slice(self.bytes(8)?);
+                Ok(FloatPrecision::from_le_bytes(bytes))
+            })
+            .collect()
+    }
+
+    /// 解码Narsese值
+    fn narsese(&mut self) -> DecodeResult<Narsese> {
+        let version = self.byte()?;
+        if version != FORMAT_VERSION {
+            return Err(DecodeError::UnsupportedVersion(version));
+        }
+        match self.byte()? {
+            tag::NARSESE_TERM => Ok(Narsese::Term(self.term()?)),
+            tag::NARSESE_SENTENCE => Ok(Narsese::Sentence(self.sentence()?)),
+            tag::NARSESE_TASK => {
+                let sentence = self.sentence()?;
+                let budget = self.budget()?;
+                Ok(Narsese::Task(Task(sentence, budget)))
+            }
+            tag => Err(DecodeError::InvalidTag {
+                kind: "Narsese值",
+                tag,
+            }),
+        }
+    }
+
+    /// 解码词项序列
+    /// * ⚠️数目来自输入：不据此预分配，以免损坏的输入耗尽内存
+    ///   * 📌每个词项至少占一个字节：数目超出剩余字节数⇒输入必然不完整
+    fn terms(&mut self) -> DecodeResult<Vec<Term>> {
+        let len = self.usize()?;
+        if len > self.remaining() {
+            return Err(DecodeError::UnexpectedEof);
+        }
+        (0..len).map(|_| self.term()).collect()
+    }
+
+    /// 解码词项
+    /// * 🚩进入时检查嵌套深度
+    fn term(&mut self) -> DecodeResult<Term> {
+        if self.depth >= DEFAULT_MAX_DEPTH {
+            return Err(DecodeError::MaxDepthExceeded);
+        }
+        self.depth += 1;
+        let term = self.term_inner();
+        self.depth -= 1;
+        term
+    }
+
+    /// 解码词项（内部）
+    /// * ⚠️递归路径上的栈帧应尽可能小：原子词项、各构造函数的分派均放在别处
+    fn term_inner(&mut self) -> DecodeResult<Term> {
+        let tag = self.byte()?;
+        if let Some(new_compound) = compound_constructor(tag) {
+            return Ok(new_compound(self.terms()?));
+        }
+        if let Some(new_binary) = binary_constructor(tag) {
+            let (left, right) = self.binary()?;
+            return Ok(new_binary(left, right));
+        }
+        match tag {
+            tag::NEGATION => Ok(Term::new_negation(self.term()?)),
+            tag::IMAGE_EXTENSION | tag::IMAGE_INTENSION => {
+                let index = self.usize()?;
+                let terms = self.terms()?;
+                match tag {
+                    tag::IMAGE_EXTENSION => Term::try_new_image_extension(index, terms),
+                    _ => Term::try_new_image_intension(index, terms),
+                }
+                .map_err(DecodeError::InvalidTerm)
+            }
+            tag => self.atom(tag),
+        }
+    }
+
+    /// 解码原子词项
+    /// * 🚩检验名称：不合法⇒[`DecodeError::InvalidTerm`]
+    fn atom(&mut self, tag: u8) -> DecodeResult<Term> {
+        let term = match tag {
+            tag::PLACEHOLDER => return Ok(Term::new_placeholder()),
+            tag::INTERVAL => return Ok(Term::new_interval(self.usize()? as UIntPrecision)),
+            tag::WORD => Term::try_new_word(self.string()?),
+            tag::VARIABLE_INDEPENDENT => Term::try_new_variable_independent(self.string()?),
+            tag::VARIABLE_DEPENDENT => Term::try_new_variable_dependent(self.string()?),
+            tag::VARIABLE_QUERY => Term::try_new_variable_query(self.string()?),
+            tag::OPERATOR => Term::try_new_operator(self.string()?),
+            tag => {
+                return Err(DecodeError::InvalidTag {
+                    kind: "词项", tag
+                })
+            }
+        };
+        term.map_err(DecodeError::InvalidTerm)
+    }
+
+    /// 解码两个词项
+    fn binary(&mut self) -> DecodeResult<(Term, Term)> {
+        Ok((self.term()?, self.term()?))
+    }
+
+    /// 解码时间戳
+    fn stamp(&mut self) -> DecodeResult<Stamp> {
+        Ok(match self.byte()? {
+            tag::ETERNAL => Stamp::Eternal,
+            tag::PAST => Stamp::Past,
+            tag::PRESENT => Stamp::Present,
+            tag::FUTURE => Stamp::Future,
+            tag::FIXED => {
+                let zigzag = self.varint()?;
+                let time = ((zigzag >> 1) as i64) ^ -((zigzag & 1) as i64);
+                Stamp::Fixed(IntPrecision::try_from(time).map_err(|_| DecodeError::VarintOverflow)?)
+            }
+            tag => {
+                return Err(DecodeError::InvalidTag {
+                    kind: "时间戳",
+                    tag,
+                })
+            }
+        })
+    }
+
+    /// 解码真值
+    fn truth(&mut self) -> DecodeResult<Truth> {
+        Ok(match self.floats("真值")?[..] {
+            [] => Truth::Empty,
+            [f] => Truth::Single(f),
+            [f, c] => Truth::Double(f, c),
+            [f, c, k, ..] => Truth::Triple(f, c, k),
+        })
+    }
+
+    /// 解码预算值
+    fn budget(&mut self) -> DecodeResult<Budget> {
+        Ok(match self.floats("预算值")?[..] {
+            [] => Budget::Empty,
+            [p] => Budget::Single(p),
+            [p, d] => Budget::Double(p, d),
+            [p, d, q, ..] => Budget::Triple(p, d, q),
+        })
+    }
+
+    /// 解码语句
+    fn sentence(&mut self) -> DecodeResult<Sentence> {
+        let punctuation = self.byte()?;
+        if punctuation > tag::QUEST {
+            return Err(DecodeError::InvalidTag {
+                kind: "标点",
+                tag: punctuation,
+            });
+        }
+        let term = self.term()?;
+        let stamp = self.stamp()?;
+        Ok(match punctuation {
+            tag::JUDGEMENT => Sentence::Judgement(term, self.truth()?, stamp),
+            tag::GOAL => Sentence::Goal(term, self.truth()?, stamp),
+            tag::QUESTION => Sentence::Question(term, stamp),
+            _ => Sentence::Quest(term, stamp),
+        })
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enum_narsese::tests::generate_term_testset;
+    use nar_dev_utils::{asserts, show};
+
+    /// 工具函数：编码单个值
+    fn encoded(narsese: &Narsese) -> Vec<u8> {
+        let mut out = vec![];
+        encode(narsese, &mut out);
+        out
+    }
+
+    /// 生成样本：各词项分别作为词项、语句、任务
+    /// * 🚩覆盖所有标点、时间戳、真值元数、预算值元数
+    fn samples() -> Vec<Narsese> {
+        let stamps = [
+            Stamp::Eternal,
+            Stamp::Past,
+            Stamp::Present,
+            Stamp::Future,
+            Stamp::Fixed(0),
+            Stamp::Fixed(-1),
+            Stamp::Fixed(IntPrecision::MAX),
+            Stamp::Fixed(IntPrecision::MIN),
+        ];
+        let truths = [
+            Truth::Empty,
+            Truth::Single(1.0),
+            Truth::Double(1.0, 0.9),
+            Truth::Triple(0.5, 0.25, 1.0),
+        ];
+        let budgets = [
+            Budget::Empty,
+            Budget::Single(0.5),
+            Budget::Double(0.5, 0.75),
+            Budget::Triple(0.5, 0.75, 0.4),
+        ];
+        let mut samples = vec![];
+        for term in generate_term_testset() {
+            samples.push(Narsese::Term(term.clone()));
+            for stamp in &stamps {
+                let mut sentences = vec![
+                    Sentence::Question(term.clone(), stamp.clone()),
+                    Sentence::Quest(term.clone(), stamp.clone()),
+                ];
+                for truth in &truths {
+                    sentences.push(Sentence::Judgement(
+                        term.clone(),
+                        truth.clone(),
+                        stamp.clone(),
+                    ));
+                    sentences.push(Sentence::Goal(term.clone(), truth.clone(), stamp.clone()));
+                }
+                for sentence in sentences {
+                    for budget in &budgets {
+                        samples.push(Narsese::Task(Task(sentence.clone(), budget.clone())));
+                    }
+                    samples.push(Narsese::Sentence(sentence));
+                }
+            }
+        }
+        samples
+    }
+
+    /// 测试/往返
+    #[test]
+    fn test_round_trip() {
+        for narsese in samples() {
+            let bytes = encoded(&narsese);
+            let (decoded, len) = decode(&bytes).unwrap();
+            assert_eq!(decoded, narsese, "{bytes:?}");
+            assert_eq!(len, bytes.len());
+        }
+    }
+
+    /// 测试/首尾相接
+    #[test]
+    fn test_concatenated() {
+        let samples = samples();
+        let mut out = vec![];
+        for narsese in &samples {
+            encode(narsese, &mut out);
+        }
+        show!(samples.len(), out.len());
+        asserts! {
+            decode_all(&out) => Ok(samples),
+            decode_all(&[]) => Ok(vec![]),
+        }
+    }
+
+    /// 测试/编码确定性
+    /// * 🎯无序容器的编码不依赖遍历顺序
+    #[test]
+    fn test_deterministic() {
+        let words = |names: &[&str]| {
+            names
+                .iter()
+                .map(|&name| Term::new_word(name))
+                .collect::<Vec<_>>()
+        };
+        let a = Term::new_conjunction(words(&["A", "B", "C", "D"]));
+        let b = Term::new_conjunction(words(&["D", "C", "B", "A"]));
+        asserts! {
+            encoded(&Narsese::Term(a)) => encoded(&Narsese::Term(b)),
+            // 版本 + 标签 + 词语标签 + 长度 + 名称
+            encoded(&Narsese::Term(Term::new_word("A"))) => [FORMAT_VERSION, 0, 0, 1, b'A'],
+        }
+    }
+
+    /// 测试/损坏的输入
+    /// * 🎯干净地返回错误，而非panic
+    #[test]
+    fn test_corrupted() {
+        let task = Narsese::Task(Task(
+            Sentence::Judgement(
+                Term::new_implication(
+                    Term::new_image_extension(1, vec![Term::new_word("A"), Term::new_word("B")]),
+                    Term::new_conjunction(vec![Term::new_word("C"), Term::new_interval(300)]),
+                ),
+                Truth::Double(1.0, 0.9),
+                Stamp::Fixed(-42),
+            ),
+            Budget::Triple(0.5, 0.75, 0.4),
+        ));
+        let bytes = encoded(&task);
+        // 截断于任意位置⇒错误
+        for len in 0..bytes.len() {
+            assert!(decode(&bytes[..len]).is_err(), "{len}");
+        }
+        // 翻转任意字节⇒错误或另一个值，但不panic
+        for i in 0..bytes.len() {
+            for flip in [0x01, 0x80, 0xff] {
+                let mut corrupted = bytes.clone();
+                corrupted[i] ^= flip;
+                let _ = decode(&corrupted);
+            }
+        }
+        // 过深的嵌套
+        let mut deep = vec![FORMAT_VERSION, tag::NARSESE_TERM];
+        deep.extend([tag::NEGATION; 100_000]);
+        asserts! {
+            decode(&[]) => Err(DecodeError::UnexpectedEof),
+            decode(&[FORMAT_VERSION + 1, 0]) => Err(DecodeError::UnsupportedVersion(FORMAT_VERSION + 1)),
+            decode(&[FORMAT_VERSION, 3]) => Err(DecodeError::InvalidTag { kind: "Narsese值", tag: 3 }),
+            decode(&[FORMAT_VERSION, 0, 0xff]) => Err(DecodeError::InvalidTag { kind: "词项", tag: 0xff }),
+            // 名称不合法
+            decode(&[FORMAT_VERSION, 0, tag::WORD, 0]).is_err(),
+            decode(&[FORMAT_VERSION, 0, tag::WORD, 2, 0xc3, 0x28]) => Err(DecodeError::InvalidUtf8),
+            // 变长整数过长
+            decode(&[FORMAT_VERSION, 0, tag::INTERVAL, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f]) => Err(DecodeError::VarintOverflow),
+            // 声称的组分数远超输入
+            decode(&[FORMAT_VERSION, 0, tag::PRODUCT, 0xff, 0xff, 0xff, 0xff, 0x0f]) => Err(DecodeError::UnexpectedEof),
+            decode(&deep) => Err(DecodeError::MaxDepthExceeded),
+            // 错误信息
+            DecodeError::UnsupportedVersion(9).to_string() => "不支持的格式版本：9",
+        }
+        // 像的占位符越界
+        assert!(matches!(
+            decode(&[
+                FORMAT_VERSION,
+                0,
+                tag::IMAGE_EXTENSION,
+                5,
+                1,
+                tag::PLACEHOLDER
+            ]),
+            Err(DecodeError::InvalidTerm(..))
+        ));
+        // 在有效值之后追加垃圾
+        let mut out = encoded(&task);
+        out.push(0xff);
+        assert!(decode_all(&out).is_err());
+        assert_eq!(decode(&out).unwrap().0, task);
+    }
+}
@@ -0,0 +1,421 @@
+//! 转换为Graphviz DOT
+//! * 🎯调试复杂陈述时，将词项结构渲染为树
+//!   * 📄`dot -Tsvg narsese.dot -o narsese.svg`
+//! * 📌节点标签：变种名 + 原子词项/连接符/系词
+//!   * 📌字符串取自[ASCII格式](FORMAT_ASCII)：`Inheritance\n-->`
+//! * 📌边：父词项 ⇒ 组分，按组分顺序排列
+//!   * 📌像的占位符：单独成节点，形状与其它词项不同
+//!   * 📌无序容器（集合、对称陈述）：虚线边框
+//!     * 🚩其组分按[全序](Ord)排序：输出不依赖集合的遍历顺序
+//! * 📌语句、任务：以「记录」形状的根节点承载标点、真值、时间戳、预算值
+
+use crate::{
+    api::{
+        GetBudget, GetCapacity, GetCategory, GetPunctuation, GetStamp, GetTerm, GetTruth,
+        TermCapacity,
+    },
+    conversion::string::impl_enum::format_instances::FORMAT_ASCII,
+    enum_narsese::{Narsese, Sentence, Task, Term},
+};
+use std::fmt::Write;
+use Term::*;
+
+/// DOT输出选项
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DotOptions {
+    /// 图名
+    pub graph_name: String,
+    /// 布局方向
+    /// * 📄`TB`（自上而下）、`LR`（自左而右）
+    pub rank_dir: String,
+    /// 节点标签是否带变种名
+    /// * 📄`Inheritance\n-->` / `-->`
+    pub show_variant: bool,
+}
+
+/// 默认：自上而下，带变种名
+impl Default for DotOptions {
+    fn default() -> Self {
+        Self {
+            graph_name: "narsese".into(),
+            rank_dir: "TB".into(),
+            show_variant: true,
+        }
+    }
+}
+
+/// 词项的变种名
+fn variant_name(term: &Term) -> &'static str {
+    match term {
+        Word(..) => "Word",
+        Placeholder => "Placeholder",
+        VariableIndependent(..) => "VariableIndependent",
+        VariableDependent(..) => "VariableDependent",
+        VariableQuery(..) => "VariableQuery",
+        Interval(..) => "Interval",
+        Operator(..) => "Operator",
+        SetExtension(..) => "SetExtension",
+        SetIntension(..) => "SetIntension",
+        IntersectionExtension(..) => "IntersectionExtension",
+        IntersectionIntension(..) => "IntersectionIntension",
+        DifferenceExtension(..) => "DifferenceExtension",
+        DifferenceIntension(..) => "DifferenceIntension",
+        Product(..) => "Product",
+        ImageExtension(..) => "ImageExtension",
+        ImageIntension(..) => "ImageIntension",
+        Conjunction(..) => "Conjunction",
+        Disjunction(..) => "Disjunction",
+        Negation(..) => "Negation",
+        ConjunctionSequential(..) => "ConjunctionSequential",
+        ConjunctionParallel(..) => "ConjunctionParallel",
+        Inheritance(..) => "Inheritance",
+        Similarity(..) => "Similarity",
+        Implication(..) => "Implication",
+        Equivalence(..) => "Equivalence",
+        ImplicationPredictive(..) => "ImplicationPredictive",
+        ImplicationConcurrent(..) => "ImplicationConcurrent",
+        ImplicationRetrospective(..) => "ImplicationRetrospective",
+        EquivalencePredictive(..) => "EquivalencePredictive",
+        EquivalenceConcurrent(..) => "EquivalenceConcurrent",
+        EquivalenceRetrospective(..) => "EquivalenceRetrospective",
+    }
+}
+
+/// 词项的特征字串
+/// * 📌原子词项：带前缀的词项名
+/// * 📌词项集：左右括弧
+/// * 📌其它复合词项：连接符
+/// * 📌陈述：系词
+fn feature_string(term: &Term) -> String {
+    let compound = &FORMAT_ASCII.compound;
+    let statement = &FORMAT_ASCII.statement;
+    let feature = match term {
+        SetExtension(..) => {
+            let (left, right) = compound.brackets_set_extension;
+            return format!("{left}{right}");
+        }
+        SetIntension(..) => {
+            let (left, right) = compound.brackets_set_intension;
+            return format!("{left}{right}");
+        }
+        IntersectionExtension(..) => compound.connecter_intersection_extension,
+        IntersectionIntension(..) => compound.connecter_intersection_intension,
+        DifferenceExtension(..) => compound.connecter_difference_extension,
+        DifferenceIntension(..) => compound.connecter_difference_intension,
+        Product(..) => compound.connecter_product,
+        ImageExtension(..) => compound.connecter_image_extension,
+        ImageIntension(..) => compound.connecter_image_intension,
+        Conjunction(..) => compound.connecter_conjunction,
+        Disjunction(..) => compound.connecter_disjunction,
+        Negation(..) => compound.connecter_negation,
+        ConjunctionSequential(..) => compound.connecter_conjunction_sequential,
+        ConjunctionParallel(..) => compound.connecter_conjunction_parallel,
+        Inheritance(..) => statement.copula_inheritance,
+        Similarity(..) => statement.copula_similarity,
+        Implication(..) => statement.copula_implication,
+        Equivalence(..) => statement.copula_equivalence,
+        ImplicationPredictive(..) => statement.copula_implication_predictive,
+        ImplicationConcurrent(..) => statement.copula_implication_concurrent,
+        ImplicationRetrospective(..) => statement.copula_implication_retrospective,
+        EquivalencePredictive(..) => statement.copula_equivalence_predictive,
+        EquivalenceConcurrent(..) => statement.copula_equivalence_concurrent,
+        EquivalenceRetrospective(..) => statement.copula_equivalence_retrospective,
+        // 原子词项⇒格式化为字符串
+        _ => return FORMAT_ASCII.format_term(term),
+    };
+    feature.into()
+}
+
+/// 转义：DOT双引号字符串
+/// * 🚩`\`、`"`加反斜杠，换行转为`\n`
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' | '"' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// 转义：记录形状的字段
+/// * 📝记录标签中`{`、`}`、`|`、`<`、`>`有特殊含义
+fn escape_record(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in escape(s).chars() {
+        if matches!(c, '{' | '}' | '|' | '<' | '>') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// 按组分顺序获取子词项
+/// * 📌像：包括占位符
+/// * 🚩无序容器⇒按全序排序
+fn ordered_components(term: &Term) -> Vec<&Term> {
+    let mut components = term.get_components_including_placeholder();
+    if term.get_capacity() == TermCapacity::Set {
+        components.sort();
+    }
+    components
+}
+
+/// DOT输出器
+/// * 🚩节点按先序遍历依次编号：`n0`、`n1`……
+struct DotWriter<'a> {
+    /// 选项
+    options: &'a DotOptions,
+    /// 输出缓冲区
+    out: String,
+    /// 下一个节点编号
+    next_id: usize,
+}
+
+impl<'a> DotWriter<'a> {
+    /// 开始一张图
+    fn new(options: &'a DotOptions) -> Self {
+        let mut out = String::new();
+        writeln!(out, "digraph \"{}\" {{", escape(&options.graph_name)).unwrap();
+        writeln!(out, "    rankdir=\"{}\";", escape(&options.rank_dir)).unwrap();
+        // 保持出边顺序：组分顺序即从左到右的顺序
+        writeln!(out, "    ordering=out;").unwrap();
+        writeln!(out, "    node [shape=box];").unwrap();
+        Self {
+            options,
+            out,
+            next_id: 0,
+        }
+    }
+
+    /// 结束并返回
+    fn finish(mut self) -> String {
+        self.out.push_str("}\n");
+        self.out
+    }
+
+    /// 分配节点编号
+    fn new_id(&mut self) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    /// 添加边
+    fn edge(&mut self, from: usize, to: usize) {
+        writeln!(self.out, "    n{from} -> n{to};").unwrap();
+    }
+
+    /// 添加词项节点（递归）
+    /// * 🚩返回节点编号
+    fn term(&mut self, term: &Term) -> usize {
+        let id = self.new_id();
+        // 标签
+        let feature = feature_string(term);
+        let label = match self.options.show_variant {
+            true => format!("{}\\n{}", variant_name(term), escape(&feature)),
+            false => escape(&feature),
+        };
+        // 属性
+        let mut attributes = format!("label=\"{label}\"");
+        if let Placeholder = term {
+            attributes.push_str(", shape=circle");
+        }
+        if matches!(
+            term.get_capacity(),
+            TermCapacity::Set | TermCapacity::BinarySet
+        ) {
+            attributes.push_str(", style=dashed");
+        }
+        writeln!(self.out, "    n{id} [{attributes}];").unwrap();
+        // 组分
+        if !term.is_atom() {
+            for component in ordered_components(term) {
+                let child = self.term(component);
+                self.edge(id, child);
+            }
+        }
+        id
+    }
+
+    /// 添加「记录」形状的根节点，并连接到词项
+    /// * 📌空字段（如永恒时间戳、空真值）不输出
+    fn record(&mut self, name: &str, fields: &[(&str, String)], term: &Term) {
+        let id = self.new_id();
+        let mut label = escape_record(name);
+        for (key, value) in fields.iter().filter(|(_, value)| !value.is_empty()) {
+            write!(label, "|{}: {}", escape_record(key), escape_record(value)).unwrap();
+        }
+        writeln!(self.out, "    n{id} [shape=record, label=\"{{{label}}}\"];").unwrap();
+        let child = self.term(term);
+        self.edge(id, child);
+    }
+
+    /// 语句的字段
+    fn sentence_fields(sentence: &Sentence) -> Vec<(&'static str, String)> {
+        let format = &FORMAT_ASCII;
+        let mut fields = vec![(
+            "punctuation",
+            format.format_punctuation(sentence.get_punctuation()),
+        )];
+        if let Some(truth) = sentence.get_truth() {
+            fields.push(("truth", format.format_truth(truth)));
+        }
+        fields.push(("stamp", format.format_stamp(sentence.get_stamp())));
+        fields
+    }
+
+    /// 添加语句
+    fn sentence(&mut self, sentence: &Sentence) {
+        let fields = Self::sentence_fields(sentence);
+        self.record("Sentence", &fields, sentence.get_term());
+    }
+
+    /// 添加任务
+    fn task(&mut self, task: &Task) {
+        let mut fields = vec![("budget", FORMAT_ASCII.format_budget(task.get_budget()))];
+        fields.extend(Self::sentence_fields(task.get_sentence()));
+        self.record("Task", &fields, task.get_term());
+    }
+}
+
+/// 将词项转换为DOT有向图
+/// * 📄`<A --> B>`
+///   * ⇒`n0 [label="Inheritance\n-->"]`
+///   * ⇒`n0 -> n1; n0 -> n2;`
+pub fn term_to_dot(term: &Term, opts: &DotOptions) -> String {
+    let mut writer = DotWriter::new(opts);
+    writer.term(term);
+    writer.finish()
+}
+
+/// 将枚举Narsese转换为DOT有向图
+/// * 📌词项⇒同[`term_to_dot`]
+/// * 📌语句、任务⇒「记录」形状的根节点 + 词项树
+pub fn narsese_to_dot(narsese: &Narsese, opts: &DotOptions) -> String {
+    let mut writer = DotWriter::new(opts);
+    match narsese {
+        Narsese::Term(term) => {
+            writer.term(term);
+        }
+        Narsese::Sentence(sentence) => writer.sentence(sentence),
+        Narsese::Task(task) => writer.task(task),
+    }
+    writer.finish()
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversion::string::impl_enum::tests::_sample_task;
+    use nar_dev_utils::{asserts, show};
+
+    /// 测试/样本任务的输出
+    /// * 🎯固定输出格式，防止回归
+    #[test]
+    fn test_sample_task() {
+        let dot = narsese_to_dot(&Narsese::Task(_sample_task()), &DotOptions::default());
+        show!(&dot);
+        let expected = r#"digraph "narsese" {
+    rankdir="TB";
+    ordering=out;
+    node [shape=box];
+    n0 [shape=record, label="{Task|budget: $0.5;0.75;0.4$|punctuation: .|truth: %1;0.9%|stamp: :!-1:}"];
+    n1 [label="Implication\n==>"];
+    n2 [label="ConjunctionSequential\n&/"];
+    n3 [label="Inheritance\n-->"];
+    n4 [label="SetExtension\n{}", style=dashed];
+    n5 [label="Word\nball"];
+    n4 -> n5;
+    n3 -> n4;
+    n6 [label="SetIntension\n[]", style=dashed];
+    n7 [label="Word\nleft"];
+    n6 -> n7;
+    n3 -> n6;
+    n2 -> n3;
+    n8 [label="Inheritance\n-->"];
+    n9 [label="Product\n*"];
+    n10 [label="SetExtension\n{}", style=dashed];
+    n11 [label="Word\nSELF"];
+    n10 -> n11;
+    n9 -> n10;
+    n12 [label="VariableIndependent\n$any"];
+    n9 -> n12;
+    n13 [label="VariableDependent\n#some"];
+    n9 -> n13;
+    n8 -> n9;
+    n14 [label="Operator\n^do"];
+    n8 -> n14;
+    n2 -> n8;
+    n1 -> n2;
+    n15 [label="Inheritance\n-->"];
+    n16 [label="SetExtension\n{}", style=dashed];
+    n17 [label="Word\nSELF"];
+    n16 -> n17;
+    n15 -> n16;
+    n18 [label="SetIntension\n[]", style=dashed];
+    n19 [label="Word\ngood"];
+    n18 -> n19;
+    n15 -> n18;
+    n1 -> n15;
+    n0 -> n1;
+}
+"#;
+        assert_eq!(dot, expected);
+    }
+
+    /// 测试/占位符、无序容器、选项
+    #[test]
+    fn test_term_to_dot() {
+        let term = Term::new_conjunction([
+            Term::new_image_extension(1, [Term::new_word("R"), Term::new_word("B")]),
+            Term::new_similarity(Term::new_word("A"), Term::new_word("B")),
+        ]);
+        let options = DotOptions {
+            show_variant: false,
+            ..Default::default()
+        };
+        let dot = term_to_dot(&term, &options);
+        show!(&dot);
+        asserts! {
+            dot.contains("n0 [label=\"&&\", style=dashed];"),
+            dot.contains("[label=\"_\", shape=circle];"),
+            dot.contains("[label=\"<->\", style=dashed];"),
+            !dot.contains("Word"),
+        }
+        // 输出不依赖集合的遍历顺序
+        for _ in 0..10 {
+            assert_eq!(term_to_dot(&term.clone(), &options), dot);
+        }
+    }
+
+    /// 测试/标签中的引号、反斜杠均被转义
+    #[test]
+    fn test_escape() {
+        // * 🚩直接构造：绕过词项名检验
+        let term = Term::new_inheritance(Word("say\"hi\"".into()), Word("a\\b".into()));
+        let dot = term_to_dot(&term, &DotOptions::default());
+        show!(&dot);
+        // 每一行的引号内不能有未转义的引号：去掉转义序列后，引号成对出现
+        for line in dot.lines() {
+            let unescaped = line.replace("\\\\", "").replace("\\\"", "");
+            assert_eq!(unescaped.matches('"').count() % 2, 0, "{line}");
+        }
+        asserts! {
+            dot.contains(r#"label="Word\nsay\"hi\"""#),
+            dot.contains(r#"label="Word\na\\b""#),
+        }
+        // 记录字段中的特殊字符
+        asserts! {
+            escape_record("{a|b}") => r"\{a\|b\}",
+            escape("\"\\\n") => r#"\"\\\n"#,
+        }
+    }
+}
@@ -0,0 +1,412 @@
+//! 词法/枚举Narsese与「扁平Narsese」的相互转换
+//! * 🎯让FFI层只需接触[`crate::api::flat`]中的类型
+//! * 🚩词项：词法Narsese⇄扁平Narsese无损互转
+//!   * 📌[`TryFrom<FlatTerm>`]经由标准库对[`From`]的总括实现得到，错误类型为[`std::convert::Infallible`]
+//! * 🚩语句、任务：时间戳、真值、预算值的解析需借助格式
+//!   * 📌词法⇒扁平：解析时间戳与数值，可能失败
+//!   * 📌扁平⇒词法：数值以[`ToString`]的形式输出，与「枚举⇒词法」的展开一致
+//! * 🚩枚举Narsese：经由词法Narsese展开、折叠
+
+use crate::{
+    api::{FlatError, FlatSentence, FlatStamp, FlatTask, FlatTerm},
+    conversion::string::impl_lexical::NarseseFormat as LexicalFormat,
+    lexical::{Sentence, StampKind, Task, Term},
+};
+
+/// 词法词项⇒扁平词项
+impl From<Term> for FlatTerm {
+    fn from(term: Term) -> Self {
+        let children = |terms: Vec<Term>| terms.into_iter().map(FlatTerm::from).collect();
+        match term {
+            Term::Atom { prefix, name } => FlatTerm::Atom { prefix, name },
+            Term::Compound { connecter, terms } => FlatTerm::Compound {
+                connecter,
+                children: children(terms),
+            },
+            Term::Set {
+                left_bracket,
+                terms,
+                right_bracket,
+            } => FlatTerm::Set {
+                left_bracket,
+                children: children(terms),
+                right_bracket,
+            },
+            Term::Statement {
+                copula,
+                subject,
+                predicate,
+            } => FlatTerm::Statement {
+                copula,
+                subject: Box::new((*subject).into()),
+                predicate: Box::new((*predicate).into()),
+            },
+        }
+    }
+}
+
+/// 扁平词项⇒词法词项
+/// * 📌词法词项不做任何检验⇒不会失败
+impl From<FlatTerm> for Term {
+    fn from(term: FlatTerm) -> Self {
+        let terms = |children: Vec<FlatTerm>| children.into_iter().map(Term::from).collect();
+        match term {
+            FlatTerm::Atom { prefix, name } => Term::Atom { prefix, name },
+            FlatTerm::Compound {
+                connecter,
+                children,
+            } => Term::Compound {
+                connecter,
+                terms: terms(children),
+            },
+            FlatTerm::Set {
+                left_bracket,
+                children,
+                right_bracket,
+            } => Term::Set {
+                left_bracket,
+                terms: terms(children),
+                right_bracket,
+            },
+            FlatTerm::Statement {
+                copula,
+                subject,
+                predicate,
+            } => Term::Statement {
+                copula,
+                subject: Box::new((*subject).into()),
+                predicate: Box::new((*predicate).into()),
+            },
+        }
+    }
+}
+
+impl From<StampKind> for FlatStamp {
+    fn from(kind: StampKind) -> Self {
+        match kind {
+            StampKind::Eternal => FlatStamp::Eternal,
+            StampKind::Past => FlatStamp::Past,
+            StampKind::Present => FlatStamp::Present,
+            StampKind::Future => FlatStamp::Future,
+            StampKind::Fixed(time) => FlatStamp::Fixed(time),
+        }
+    }
+}
+
+impl From<FlatStamp> for StampKind {
+    fn from(stamp: FlatStamp) -> Self {
+        match stamp {
+            FlatStamp::Eternal => StampKind::Eternal,
+            FlatStamp::Past => StampKind::Past,
+            FlatStamp::Present => StampKind::Present,
+            FlatStamp::Future => StampKind::Future,
+            FlatStamp::Fixed(time) => StampKind::Fixed(time),
+        }
+    }
+}
+
+/// 工具函数/浮点数组⇒数值字串数组
+fn unfold_floats(floats: &[f64]) -> Vec<String> {
+    floats.iter().map(ToString::to_string).collect()
+}
+
+impl FlatSentence {
+    /// 从词法语句转换
+    /// * 🚩按格式解析时间戳；检验并解析真值
+    pub fn try_from_lexical(sentence: Sentence, format: &LexicalFormat) -> Result<Self, FlatError> {
+        let stamp = format.parse_stamp_kind(&sentence.stamp).map_err(|error| {
+            FlatError::new(format!("{}「{}」", error.message(), sentence.stamp))
+        })?;
+        let truth = format
+            .validate_truth(&sentence.truth)
+            .map_err(|error| FlatError::new(error.to_string()))?;
+        Ok(Self {
+            term: sentence.term.into(),
+            punctuation: sentence.punctuation,
+            stamp: stamp.into(),
+            truth,
+        })
+    }
+
+    /// 转换为词法语句
+    /// * 🚩按格式生成时间戳字符串
+    pub fn into_lexical(self, format: &LexicalFormat) -> Sentence {
+        Sentence {
+            term: self.term.into(),
+            punctuation: self.punctuation,
+            stamp: format.format_stamp_kind(self.stamp.into()),
+            truth: unfold_floats(&self.truth),
+        }
+    }
+}
+
+impl FlatTask {
+    /// 从词法任务转换
+    /// * 🚩检验并解析预算值，其余同[`FlatSentence::try_from_lexical`]
+    pub fn try_from_lexical(task: Task, format: &LexicalFormat) -> Result<Self, FlatError> {
+        let budget = format
+            .validate_budget(&task.budget)
+            .map_err(|error| FlatError::new(error.to_string()))?;
+        Ok(Self {
+            budget,
+            sentence: FlatSentence::try_from_lexical(task.sentence, format)?,
+        })
+    }
+
+    /// 转换为词法任务
+    pub fn into_lexical(self, format: &LexicalFormat) -> Task {
+        Task {
+            budget: unfold_floats(&self.budget),
+            sentence: self.sentence.into_lexical(format),
+        }
+    }
+}
+
+/// 与枚举Narsese的转换
+/// * 🚩词项、标点经由词法Narsese展开/折叠
+/// * 🚩时间戳、真值、预算值直接转换
+#[cfg(feature = "enum_narsese")]
+mod impl_enum {
+    use super::*;
+    use crate::{
+        api::{GetBudget, GetStamp, GetTruth},
+        conversion::{
+            inter_type::lexical_fold::{FoldError, FoldResult, TryFoldInto},
+            string::impl_enum::NarseseFormat as EnumNarseseFormat,
+        },
+        enum_narsese::{
+            Budget as EnumBudget, Sentence as EnumSentence, Stamp as EnumStamp, Task as EnumTask,
+            Term as EnumTerm, Truth as EnumTruth,
+        },
+    };
+
+    impl From<&EnumStamp> for FlatStamp {
+        fn from(stamp: &EnumStamp) -> Self {
+            match *stamp {
+                EnumStamp::Eternal => FlatStamp::Eternal,
+                EnumStamp::Past => FlatStamp::Past,
+                EnumStamp::Present => FlatStamp::Present,
+                EnumStamp::Future => FlatStamp::Future,
+                EnumStamp::Fixed(time) => FlatStamp::Fixed(time as i64),
+            }
+        }
+    }
+
+    /// * ⚠️固定时间超出[`crate::api::IntPrecision`]范围时会被截断
+    impl From<FlatStamp> for EnumStamp {
+        fn from(stamp: FlatStamp) -> Self {
+            match stamp {
+                FlatStamp::Eternal => EnumStamp::Eternal,
+                FlatStamp::Past => EnumStamp::Past,
+                FlatStamp::Present => EnumStamp::Present,
+                FlatStamp::Future => EnumStamp::Future,
+                FlatStamp::Fixed(time) => EnumStamp::Fixed(time as _),
+            }
+        }
+    }
+
+    /// 工具函数/枚举真值⇒浮点数组
+    fn truth_floats(truth: &EnumTruth) -> Vec<f64> {
+        match *truth {
+            EnumTruth::Empty => vec![],
+            EnumTruth::Single(f) => vec![f],
+            EnumTruth::Double(f, c) => vec![f, c],
+            EnumTruth::Triple(f, c, k) => vec![f, c, k],
+        }
+    }
+
+    /// 工具函数/枚举预算值⇒浮点数组
+    fn budget_floats(budget: &EnumBudget) -> Vec<f64> {
+        match *budget {
+            EnumBudget::Empty => vec![],
+            EnumBudget::Single(p) => vec![p],
+            EnumBudget::Double(p, d) => vec![p, d],
+            EnumBudget::Triple(p, d, q) => vec![p, d, q],
+        }
+    }
+
+    impl FlatTerm {
+        /// 从枚举词项转换
+        /// * 🚩按格式展开为词法词项
+        pub fn from_enum(term: &EnumTerm, format: &EnumNarseseFormat<&str>) -> Self {
+            term.to_lexical(format).into()
+        }
+
+        /// 转换为枚举词项
+        /// * 🚩按格式折叠词法词项
+        pub fn try_into_enum(self, format: &EnumNarseseFormat<&str>) -> FoldResult<EnumTerm> {
+            Term::from(self).try_fold_into(format)
+        }
+    }
+
+    impl FlatSentence {
+        /// 从枚举语句转换
+        /// * 📌问题、请求⇒空真值
+        pub fn from_enum(sentence: &EnumSentence, format: &EnumNarseseFormat<&str>) -> Self {
+            let lexical = sentence.to_lexical(format);
+            Self {
+                term: lexical.term.into(),
+                punctuation: lexical.punctuation,
+                stamp: sentence.get_stamp().into(),
+                truth: sentence.get_truth().map(truth_floats).unwrap_or_default(),
+            }
+        }
+
+        /// 转换为枚举语句
+        /// * 🚩标点按格式解析；真值须在`[0, 1]`范围内
+        pub fn try_into_enum(self, format: &EnumNarseseFormat<&str>) -> FoldResult<EnumSentence> {
+            let truth =
+                EnumTruth::try_from_floats(self.truth.into_iter()).map_err(FoldError::from)?;
+            let lexical = Sentence {
+                term: self.term.into(),
+                punctuation: self.punctuation,
+                stamp: format.format_stamp(&self.stamp.into()),
+                truth: truth.to_lexical(),
+            };
+            lexical.try_fold_into(format)
+        }
+    }
+
+    impl FlatTask {
+        /// 从枚举任务转换
+        pub fn from_enum(task: &EnumTask, format: &EnumNarseseFormat<&str>) -> Self {
+            Self {
+                budget: budget_floats(task.get_budget()),
+                sentence: FlatSentence::from_enum(task.get_sentence(), format),
+            }
+        }
+
+        /// 转换为枚举任务
+        /// * 🚩预算值须在`[0, 1]`范围内
+        pub fn try_into_enum(self, format: &EnumNarseseFormat<&str>) -> FoldResult<EnumTask> {
+            let budget =
+                EnumBudget::try_from_floats(self.budget.into_iter()).map_err(FoldError::from)?;
+            let sentence = self.sentence.try_into_enum(format)?;
+            Ok(EnumTask::new(sentence, budget))
+        }
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        conversion::string::impl_lexical::format_instances::*,
+        lexical::{shortcuts::*, Narsese},
+    };
+    use nar_dev_utils::asserts;
+
+    /// 词法⇒扁平⇒词法：词项保持不变
+    #[test]
+    fn test_lexical_term() {
+        let format = &FORMAT_ASCII;
+        let term = format
+            .parse_term("<(&&, <$x --> {A, B}>, (/, R, _, ^op)) ==> [C]>")
+            .unwrap();
+        let flat = FlatTerm::from(term.clone());
+        let back = Term::from(flat.clone());
+        asserts! {
+            back => term,
+            flat => FlatTerm::Statement {
+                copula: "==>".into(),
+                subject: Box::new(FlatTerm::from(format.parse_term("(&&, <$x --> {A, B}>, (/, R, _, ^op))").unwrap())),
+                predicate: Box::new(FlatTerm::Set {
+                    left_bracket: "[".into(),
+                    children: vec![FlatTerm::Atom { prefix: "".into(), name: "C".into() }],
+                    right_bracket: "]".into(),
+                }),
+            },
+        }
+    }
+
+    /// 词法⇄扁平：语句、任务
+    #[test]
+    fn test_lexical_task() {
+        let format = &FORMAT_ASCII;
+        let task = task![
+            budget!["0.5" "0.75" "0.4"];
+            statement!(atom!("A") "-->" atom!("B")) "." ":!-1:" truth!["1.0" "0.9"]
+        ];
+        let flat = FlatTask::try_from_lexical(task.clone(), format).unwrap();
+        asserts! {
+            flat.budget => vec![0.5, 0.75, 0.4],
+            flat.sentence.stamp => FlatStamp::Fixed(-1),
+            flat.sentence.truth => vec![1.0, 0.9],
+            flat.clone().into_lexical(format).sentence.stamp => task.sentence.stamp,
+        }
+        // 扁平⇒词法⇒字符串⇒词法⇒扁平：无损
+        let formats = [
+            (&*FORMAT_ASCII, "-->", "."),
+            (&*FORMAT_LATEX, r"\rightarrow{}", "."),
+            (&*FORMAT_HAN, "是", "。"),
+        ];
+        for (format, copula, punctuation) in formats {
+            let mut flat = flat.clone();
+            if let FlatTerm::Statement { copula: c, .. } = &mut flat.sentence.term {
+                *c = copula.into();
+            }
+            flat.sentence.punctuation = punctuation.into();
+            let input = format.format_task(&flat.clone().into_lexical(format));
+            let Ok(Narsese::Task(task)) = format.parse(&input) else {
+                panic!("任务解析失败：{input}");
+            };
+            assert_eq!(FlatTask::try_from_lexical(task, format).unwrap(), flat);
+        }
+        // 非法的时间戳、真值、预算值
+        let format = &FORMAT_ASCII;
+        let sentence = sentence![atom!("A") "." ":!x:" truth!["1.0" "0.9"]];
+        let error = FlatSentence::try_from_lexical(sentence, format).unwrap_err();
+        assert_eq!(error.message(), "非法时间戳「:!x:」");
+        let sentence = sentence![atom!("A") "." "" truth!["abc"]];
+        assert!(FlatSentence::try_from_lexical(sentence, format).is_err());
+        let task = task![budget!["1.5"]; atom!("A") "?" ":|:" truth![]];
+        assert!(FlatTask::try_from_lexical(task, format).is_err());
+    }
+
+    /// 枚举⇄扁平
+    #[test]
+    #[cfg(feature = "enum_narsese")]
+    fn test_enum() {
+        use crate::{
+            conversion::string::impl_enum::{
+                format_instances as enum_formats, tests::_sample_task,
+            },
+            enum_narsese::tests::generate_term_testset,
+        };
+        let formats = [
+            (enum_formats::FORMAT_ASCII, &*FORMAT_ASCII),
+            (enum_formats::FORMAT_LATEX, &*FORMAT_LATEX),
+            (enum_formats::FORMAT_HAN, &*FORMAT_HAN),
+        ];
+        for (format, lexical_format) in formats {
+            // 词项
+            for term in generate_term_testset() {
+                let flat = FlatTerm::from_enum(&term, &format);
+                assert_eq!(flat.try_into_enum(&format).unwrap(), term);
+            }
+            // 任务
+            let task = _sample_task();
+            let flat = FlatTask::from_enum(&task, &format);
+            assert_eq!(flat.clone().try_into_enum(&format).unwrap(), task);
+            // 经由词法Narsese的扁平表示与直接转换一致
+            let lexical = task.to_lexical(&format);
+            assert_eq!(
+                FlatTask::try_from_lexical(lexical, lexical_format).unwrap(),
+                flat
+            );
+        }
+        // 超出范围的真值
+        let format = &enum_formats::FORMAT_ASCII;
+        let sentence = FlatSentence {
+            term: FlatTerm::Atom {
+                prefix: "".into(),
+                name: "A".into(),
+            },
+            punctuation: ".".into(),
+            stamp: FlatStamp::Eternal,
+            truth: vec![1.5],
+        };
+        assert!(sentence.try_into_enum(format).is_err());
+    }
+}
@@ -4,7 +4,7 @@
 use super::*;
 use crate::{
     api::{FloatPrecision, FromParse, IntPrecision, UIntPrecision},
-    conversion::string::impl_enum::NarseseFormat as EnumNarseseFormat,
+    conversion::string::{impl_enum::NarseseFormat as EnumNarseseFormat, IMAGE_PLACEHOLDERS_MESSAGE},
     enum_narsese::{
         Budget as EnumBudget, Narsese as EnumNarsese, Punctuation, Sentence as EnumSentence, Stamp,
         Task as EnumTask, Term as EnumTerm, Truth as EnumTruth,
@@ -45,11 +45,11 @@ impl<'a> TryFoldInto<'a, EnumNarsese, FoldError> for Narsese {
         // 匹配自己并进行拆包封包
         Ok(match self {
             // 词项
-            Narsese::Term(t) => EnumNarsese::Term(t.try_fold_into(folder)?),
+            Narsese::Term(t) => EnumNarsese::from_term(t.try_fold_into(folder)?),
             // 语句
-            Narsese::Sentence(s) => EnumNarsese::Sentence(s.try_fold_into(folder)?),
+            Narsese::Sentence(s) => EnumNarsese::from_sentence(s.try_fold_into(folder)?),
             // 任务
-            Narsese::Task(t) => EnumNarsese::Task(t.try_fold_into(folder)?),
+            Narsese::Task(t) => EnumNarsese::from_task(t.try_fold_into(folder)?),
         })
     }
 }
@@ -130,8 +130,11 @@ fn fold_statement(
         folder.statement.copula_equivalence_predictive => EnumTerm::new_equivalence_predictive(subject, predicate),
         // 并发性等价
         folder.statement.copula_equivalence_concurrent => EnumTerm::new_equivalence_concurrent(subject, predicate),
-        // 回顾性等价 | ⚠️会在构造时自动转换
-        folder.statement.copula_equivalence_retrospective => EnumTerm::new_equivalence_retrospective(subject, predicate),
+        // 回顾性等价 | ⚠️可按格式配置自动转换
+        folder.statement.copula_equivalence_retrospective => match folder.normalize_retrospective {
+            true => EnumTerm::new_equivalence_retrospective_normalized(subject, predicate),
+            false => EnumTerm::new_equivalence_retrospective(subject, predicate),
+        },
         // 未知 //
         _ => return Err(FoldError!("非法陈述系词「{copula}」")),
     })
@@ -148,6 +151,18 @@ fn fold_terms(terms: Vec<Term>, folder: &EnumNarseseFormat<&str>) -> FoldResult<
     Ok(enum_terms)
 }
 
+/// 子函数/按「重复组分策略」检查无序容器的组分
+/// * 🚩与「枚举Narsese解析器」共用同一套检查逻辑
+/// * 📌警告不在此处给出：折叠前的「良构检查」会指出重复组分
+///   * 📄参见`conversion::string::impl_lexical::lint`
+#[inline(always)]
+fn check_duplicates(folder: &EnumNarseseFormat<&str>, terms: &[EnumTerm]) -> FoldResult<()> {
+    folder
+        .check_duplicate_components(terms)
+        .map(|_| ())
+        .map_err(FoldError)
+}
+
 /// 子函数/折叠集合词项
 #[inline(always)]
 fn fold_set(
@@ -156,6 +171,8 @@ fn fold_set(
     right_bracket: &str,
     terms: Vec<EnumTerm>,
 ) -> Result<EnumTerm, FoldError> {
+    // 词项集均为无序容器⇒检查重复组分
+    check_duplicates(folder, &terms)?;
     Ok(first! {
         ((left_bracket, right_bracket).eq) => (_);
         // NAL-3 //
@@ -168,6 +185,23 @@ fn fold_set(
     })
 }
 
+/// 子函数/从「带占位符的组分」中分离出「占位符索引」与其余组分
+/// * 🎯用于折叠「像」：索引交由[`EnumTerm::try_new_image_extension`]等检验
+/// * ⚠️无占位符、多于一个占位符⇒折叠错误
+fn fold_image_terms(
+    terms: Vec<EnumTerm>,
+    name: &str,
+) -> FoldResult<(UIntPrecision, Vec<EnumTerm>)> {
+    let placeholders = terms.iter().filter(|term| **term == EnumTerm::Placeholder).count();
+    if placeholders > 1 {
+        return Err(FoldError!("{IMAGE_PLACEHOLDERS_MESSAGE}"));
+    }
+    let mut components = vec![];
+    let placeholder_index = EnumTerm::to_terms_with_image(terms, &mut components)
+        .ok_or_else(|| FoldError!("找不到{name}中占位符的位置"))?;
+    Ok((placeholder_index, components))
+}
+
 /// 子函数/折叠复合词项
 #[inline(always)]
 fn fold_compound(
@@ -175,6 +209,17 @@ fn fold_compound(
     connecter: String,
     terms: Vec<EnumTerm>,
 ) -> Result<EnumTerm, FoldError> {
+    // 无序容器⇒检查重复组分
+    let unordered_connecters = [
+        folder.compound.connecter_intersection_extension,
+        folder.compound.connecter_intersection_intension,
+        folder.compound.connecter_conjunction,
+        folder.compound.connecter_disjunction,
+        folder.compound.connecter_conjunction_parallel,
+    ];
+    if unordered_connecters.contains(&connecter.as_str()) {
+        check_duplicates(folder, &terms)?;
+    }
     Ok(first! {
         // * ✅这里不用再怕「短的比长的先被截取」问题
         (connecter.eq) => (_);
@@ -195,15 +240,21 @@ fn fold_compound(
             let mut terms = terms.into_iter(); // * 📝对于「取头部元素，然后抛弃整个数组」的情况，适合用迭代器而非`get`/`remove`
             let left = terms.next().ok_or(FoldError!("在内涵差中找不到左词项"))?;
             let right = terms.next().ok_or(FoldError!("在内涵差中找不到右词项"))?;
-            EnumTerm::new_difference_extension(left, right)
+            EnumTerm::new_difference_intension(left, right)
         },
         // NAL-4 //
         // 乘积
         folder.compound.connecter_product => EnumTerm::new_product(terms),
         // 外延像
-        folder.compound.connecter_image_extension => EnumTerm::to_image_extension_with_placeholder(terms).ok_or(FoldError!("找不到外延像中占位符的位置"))?,
+        folder.compound.connecter_image_extension => {
+            let (placeholder_index, terms) = fold_image_terms(terms, "外延像")?;
+            EnumTerm::try_new_image_extension(placeholder_index, terms)?
+        },
         // 内涵像
-        folder.compound.connecter_image_intension => EnumTerm::to_image_intension_with_placeholder(terms).ok_or(FoldError!("找不到外延像中占位符的位置"))?,
+        folder.compound.connecter_image_intension => {
+            let (placeholder_index, terms) = fold_image_terms(terms, "内涵像")?;
+            EnumTerm::try_new_image_intension(placeholder_index, terms)?
+        },
         // NAL-5
         // 合取
         folder.compound.connecter_conjunction => EnumTerm::new_conjunction(terms),
@@ -231,6 +282,15 @@ fn fold_atom(
     prefix: String,
     name: String,
 ) -> FoldResult<EnumTerm> {
+    // 带依赖列表的变量（如`#1(#2)`）⇒枚举Narsese暂不支持
+    let variables = [
+        folder.atom.prefix_variable_independent,
+        folder.atom.prefix_variable_dependent,
+        folder.atom.prefix_variable_query,
+    ];
+    if variables.contains(&prefix.as_str()) && name.contains(folder.compound.brackets.0) {
+        return Err(FoldError!("暂不支持带依赖列表的变量「{prefix}{name}」"));
+    }
     Ok(first! {
         (prefix.eq) => (_);
         // 词语 | ✅这里不用再害怕「空前缀」问题
@@ -280,9 +340,28 @@ impl<'a> TryFoldInto<'a, EnumTruth, FoldError> for Truth {
     /// 统一使用「枚举Narsese格式」提供信息
     type Folder = EnumNarseseFormat<&'a str>;
 
-    fn try_fold_into(self, _folder: &'a Self::Folder) -> FoldResult<EnumTruth> {
+    /// * 📌三个分量⇒仅在[`allow_extended_truth`](EnumNarseseFormat::allow_extended_truth)开启时折叠为「三真值」
+    /// * ⚠️分量过多⇒折叠错误，而非悄然舍去
+    fn try_fold_into(self, folder: &'a Self::Folder) -> FoldResult<EnumTruth> {
         // 先逐个解析浮点数
         let floats = try_fold_float_vec(&self)?;
+        // 三真值
+        match (&floats[..], folder.allow_extended_truth) {
+            (&[f, c, k], true) => {
+                if !f.is_in_01() || !c.is_in_01() {
+                    return Err(FoldError!("「0-1」区间外的值：{floats:?}"));
+                }
+                if k < 0.0 {
+                    return Err(FoldError!("证据数不能为负：{k}"));
+                }
+                return Ok(EnumTruth::new_triple(f, c, k));
+            }
+            ([_, _, _], false) => {
+                return Err(FoldError!("真值分量过多：{floats:?}（三真值需开启`allow_extended_truth`）"))
+            }
+            _ if floats.len() > 3 => return Err(FoldError!("真值分量过多：{floats:?}")),
+            _ => {}
+        }
         // 然后从浮点数序列构造真值
         EnumTruth::try_from_floats(floats.into_iter()).transform_err(FoldError::from)
     }
@@ -374,6 +453,7 @@ impl<'a> TryFoldInto<'a, EnumTask, FoldError> for Task {
 mod tests {
     use super::*;
     use crate::{
+        api::Located,
         conversion::string::{
             impl_enum::format_instances::*,
             impl_lexical::{
@@ -383,6 +463,7 @@ mod tests {
                 NarseseFormat,
             },
         },
+        enum_narsese::testing::{diff_narsese, display_diffs},
         lexical::tests::_sample_task_ascii,
     };
 
@@ -412,7 +493,269 @@ mod tests {
         dbg!(_test_fold_narsese(format, Narsese::Task(task)));
     }
 
+    /// 测试/内涵差
+    /// * 🎯应折叠为「内涵差」而非「外延差」
+    #[test]
+    fn test_fold_difference_intension() {
+        let term = L_ASCII
+            .parse("(~, A, B)")
+            .unwrap()
+            .try_into_term()
+            .unwrap();
+        let folded: EnumTerm = term.try_fold_into(&FORMAT_ASCII).unwrap();
+        assert_eq!(
+            folded,
+            EnumTerm::new_difference_intension(EnumTerm::new_word("A"), EnumTerm::new_word("B"))
+        );
+    }
+
+    /// 测试/带位置的批量折叠
+    /// * 🎯折叠出错时，能报告原始行号
+    #[test]
+    fn test_fold_located() {
+        // 第4行的「单元素外延差」在词法上合法，但无法折叠
+        let input = "<A --> B>.\n<B --> C>?\n\n<(-, C) --> D>.\n<D --> E>!";
+        let parsed = L_ASCII
+            .parse_stream_located(input, Some("fixture.nal".into()))
+            .map(|located| located.map(Result::unwrap))
+            .collect::<Vec<_>>();
+        asserts! {
+            parsed.iter().map(|l| l.line).collect::<Vec<_>>() => vec![1, 2, 4, 5],
+        }
+        // 批量折叠
+        let results: Vec<Result<Located<EnumNarsese>, Located<FoldError>>> =
+            try_fold_all_located(parsed, &FORMAT_ASCII);
+        show!(&results);
+        asserts! {
+            results.len() => 4,
+            results[0].is_ok(),
+            results[1].is_ok(),
+            results[3].is_ok(),
+            results[3].as_ref().unwrap().location() => "fixture.nal:5:1",
+        }
+        // 折叠错误中带有行号
+        let error = results[2].as_ref().unwrap_err();
+        asserts! {
+            error.line => 4,
+            error.location() => "fixture.nal:4:1",
+        }
+    }
+
+    /// 测试/间隔字面量的规范化
+    /// * 🎯词法Narsese中的间隔名称，与「枚举Narsese」往返后一致
+    #[test]
+    fn test_interval_literals() {
+        use crate::conversion::string::impl_lexical::format_instances::create_format_ascii;
+        // 原子、复合词项、陈述
+        for (input, normalized) in [
+            ("+0123", "+123"),
+            ("+000", "+0"),
+            ("(&/, <A --> B>, +0123)", "(&/, <A --> B>, +123)"),
+            ("<+0123 <-> 加一二三>", "<+123 <-> 加一二三>"),
+            ("<加一二三 <-> +0123>", "<加一二三 <-> +123>"),
+        ] {
+            let lexical = L_ASCII.parse_term(input).unwrap();
+            // 词法层面：规范化后与规范形式一致
+            assert_eq!(lexical, L_ASCII.parse_term(normalized).unwrap());
+            // 跨表示：折叠⇒格式化⇒重新解析，仍然一致
+            let folded: EnumTerm = lexical.clone().try_fold_into(&FORMAT_ASCII).unwrap();
+            let formatted = FORMAT_ASCII.format_term(&folded);
+            asserts! {
+                formatted => normalized,
+                L_ASCII.parse_term(&formatted).unwrap() => lexical,
+            }
+        }
+        // 空名称的间隔：在词法解析时即报错
+        for input in ["+", "(&/, A, +)", "<+ --> A>", "<A --> +>"] {
+            let result = L_ASCII.parse_term(input);
+            show!(&result);
+            assert!(result.is_err());
+        }
+        // 关闭规范化⇒保留原样
+        let raw = NarseseFormat {
+            normalize_interval_literals: false,
+            ..create_format_ascii()
+        };
+        asserts! {
+            raw.parse_term("+0123").unwrap() => crate::lexical_atom!("+", "0123"),
+            raw.parse_term("+").unwrap() => crate::lexical_atom!("+", ""),
+        }
+    }
+
+    /// 测试/带依赖列表的变量
+    /// * 🎯词法Narsese可以解析，但折叠到枚举Narsese时明确报错
+    #[test]
+    fn test_fold_variable_dependency_list() {
+        for input in ["#1(#2)", "<#x(a, b) --> A>"] {
+            let lexical = L_ASCII.parse_term(input).unwrap();
+            let result: FoldResult<EnumTerm> = lexical.try_fold_into(&FORMAT_ASCII);
+            show!(&result);
+            assert!(result.unwrap_err().0.contains("暂不支持带依赖列表的变量"));
+        }
+        // 不带依赖列表⇒正常折叠
+        let folded: EnumTerm = L_ASCII.parse_term("#x").unwrap().try_fold_into(&FORMAT_ASCII).unwrap();
+        assert_eq!(folded, EnumTerm::new_variable_dependent("x"));
+    }
+
+    /// 测试/折叠三真值
+    /// * 🎯关闭时明确报错，而非悄然舍去第三个分量
+    #[test]
+    fn test_fold_extended_truth() {
+        use crate::api::GetTruth;
+        let extended = &EnumNarseseFormat {
+            allow_extended_truth: true,
+            ..FORMAT_ASCII
+        };
+        let input = "<A --> B>. %1.0;0.9;4%";
+        let lexical = L_ASCII.parse(input).unwrap();
+        let fold = |format| -> FoldResult<EnumNarsese> { lexical.clone().try_fold_into(format) };
+        let truth = fold(extended)
+            .unwrap()
+            .try_into_sentence()
+            .unwrap()
+            .get_truth()
+            .cloned();
+        asserts! {
+            truth => Some(EnumTruth::new_triple(1.0, 0.9, 4.0)),
+            // 与枚举解析器一致
+            fold(extended).ok() => extended.parse::<EnumNarsese>(input).ok(),
+            // 关闭⇒报错
+            fold(&FORMAT_ASCII).unwrap_err().0.contains("allow_extended_truth"),
+        }
+        // 证据数为负、分量过多⇒报错
+        for input in ["<A --> B>. %1.0;0.9;-4%", "<A --> B>. %1.0;0.9;4;1%"] {
+            let result: FoldResult<EnumNarsese> = L_ASCII.parse(input).unwrap().try_fold_into(extended);
+            assert!(result.is_err(), "{input}");
+        }
+    }
+
+    /// 测试/折叠像
+    /// * 🎯无占位符、多于一个占位符⇒折叠错误，而非panic
+    #[test]
+    fn test_fold_image() {
+        use crate::lexical::shortcuts::*;
+        let fold = |input| -> FoldResult<EnumTerm> {
+            L_ASCII.parse_term(input).unwrap().try_fold_into(&FORMAT_ASCII)
+        };
+        let fold_term = |term: Term| -> FoldResult<EnumTerm> { term.try_fold_into(&FORMAT_ASCII) };
+        let a = || EnumTerm::new_word("A");
+        asserts! {
+            fold(r"(/, A, _)") => Ok(EnumTerm::new_image_extension(1, [a()])),
+            fold(r"(\, _, A)") => Ok(EnumTerm::new_image_intension(0, [a()])),
+            fold(r"(/, A, B)").unwrap_err().0 => "找不到外延像中占位符的位置",
+            fold(r"(\, A, B)").unwrap_err().0 => "找不到内涵像中占位符的位置",
+            // 多于一个占位符 | 📌词法Narsese直接构造，绕过解析时的检验
+            fold_term(compound!("/", atom!("A"), atom!("_" ""), atom!("_" ""))).unwrap_err().0
+                => IMAGE_PLACEHOLDERS_MESSAGE,
+            fold_term(compound!(r"\", atom!("_" ""), atom!("A"), atom!("_" ""))).unwrap_err().0
+                => IMAGE_PLACEHOLDERS_MESSAGE,
+            // 非像⇒占位符是普通组分
+            fold(r"(*, _)") => Ok(EnumTerm::new_product([EnumTerm::Placeholder])),
+        }
+    }
+
+    /// 测试/重复组分策略
+    /// * 🎯「枚举Narsese解析」与「词法折叠」的行为一致
+    #[test]
+    fn test_duplicate_policy() {
+        use crate::conversion::string::impl_enum::DuplicatePolicy::{self, *};
+        /// 使用枚举Narsese格式直接解析出词项
+        fn parse_term(format: &EnumNarseseFormat<&str>, input: &str) -> Result<EnumTerm, String> {
+            format
+                .parse::<EnumNarsese>(input)
+                .map_err(|e| e.to_string())?
+                .try_into_term()
+                .map_err(|e| e.to_string())
+        }
+        /// 以指定策略同时「直接解析」与「词法折叠」
+        fn parse_both(
+            policy: DuplicatePolicy,
+            input: &str,
+        ) -> (Result<EnumTerm, String>, Result<EnumTerm, String>) {
+            let format = EnumNarseseFormat {
+                duplicate_policy: policy,
+                ..FORMAT_ASCII
+            };
+            let parsed = parse_term(&format, input);
+            let folded = L_ASCII
+                .parse_term(input)
+                .expect("词法解析失败")
+                .try_fold_into(&format)
+                .map_err(|e| e.0);
+            (parsed, folded)
+        }
+        // 无序容器：外延集、外延交、平行合取
+        for (input, deduped) in [
+            ("{A, A, B}", "{A, B}"),
+            ("(&, A, A, B)", "(&, A, B)"),
+            ("(&|, A, A)", "(&|, A)"),
+        ] {
+            let expected = parse_term(&FORMAT_ASCII, deduped).unwrap();
+            // 去重、保留：无法保留⇒降级为去重
+            for policy in [Dedupe, Keep] {
+                let (parsed, folded) = parse_both(policy, input);
+                assert_eq!(parsed.as_ref(), Ok(&expected));
+                assert_eq!(folded.as_ref(), Ok(&expected));
+            }
+            // 报错：消息中含有重复组分
+            let (parsed, folded) = parse_both(Error, input);
+            show!(&parsed, &folded);
+            assert!(parsed.unwrap_err().contains("存在重复组分「A」"));
+            assert!(folded.unwrap_err().contains("存在重复组分「A」"));
+            // 无重复组分⇒各策略结果一致
+            for policy in [Dedupe, Keep, Error] {
+                let (parsed, folded) = parse_both(policy, deduped);
+                assert_eq!(parsed.as_ref(), Ok(&expected));
+                assert_eq!(folded.as_ref(), Ok(&expected));
+            }
+        }
+        // 有序容器不受影响
+        for policy in [Dedupe, Keep, Error] {
+            let (parsed, folded) = parse_both(policy, "(*, A, A)");
+            asserts! {
+                parsed.is_ok(),
+                parsed => folded,
+            }
+        }
+        // 默认策略为「去重」
+        asserts! {
+            FORMAT_ASCII.duplicate_policy => Dedupe,
+            DuplicatePolicy::default() => Dedupe,
+        }
+    }
+
+    /// 测试/重复组分：词法与枚举的判等一致
+    /// * 🎯「去重」策略下，词法Narsese规范化后判等 ⇔ 枚举Narsese判等
+    /// * 📌词法Narsese保留所有组分，规范化时去重
+    #[test]
+    fn test_duplicate_policy_cross_check() {
+        let inputs = [
+            "{A, A, B}",
+            "{B, A}",
+            "{A, B, B}",
+            "{A, B, C}",
+            "(&, A, A, B)",
+            "(&, B, A)",
+            "(&|, A, A)",
+            "(&|, A)",
+            "(&&, <A --> B>, <A --> B>)",
+            "(&&, <A --> B>)",
+            "(*, A, A)",
+            "(*, A)",
+        ];
+        let lexical = inputs.map(|input| L_ASCII.parse_term(input).unwrap());
+        let enums = lexical.clone().map(|term| term.try_fold_into(&FORMAT_ASCII).unwrap());
+        for (l1, e1) in lexical.iter().zip(enums.iter()) {
+            // 规范化后再折叠⇒结果不变
+            assert_eq!(&l1.normalize(&L_ASCII).try_fold_into(&FORMAT_ASCII).unwrap(), e1);
+            for (l2, e2) in lexical.iter().zip(enums.iter()) {
+                assert_eq!(l1.eq_semantic(l2, &L_ASCII), e1 == e2, "{l1:?} {l2:?}");
+            }
+        }
+    }
+
     /// 根据传入的「枚举Narsese格式」「词法Narsese格式」分别生成解析器、格式化器
+    #[allow(clippy::type_complexity)]
     fn _generate_from_format<'a>(
         enum_format: &'a EnumNarseseFormat<&'a str>,
         lexical_format: &'a NarseseFormat,
@@ -531,10 +874,13 @@ mod tests {
                 Self: std::marker::Sized,
             {
                 // 转换自身与「其他」
-                assert_eq!(
-                    self.convert(enum_format, lexical_format),
-                    other.convert(enum_format, lexical_format),
-                    "转换后仍不相等！"
+                let left = self.convert(enum_format, lexical_format);
+                let right = other.convert(enum_format, lexical_format);
+                // 不相等⇒只展示差异
+                assert!(
+                    left == right,
+                    "转换后仍不相等！差异：\n{}",
+                    display_diffs(&diff_narsese(&left, &right))
                 );
             }
             fn convert<'a>(
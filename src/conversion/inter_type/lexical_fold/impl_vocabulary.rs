@@ -0,0 +1,354 @@
+//! 词法Narsese的「词汇折叠」
+//! * 🎯将某一格式的词法Narsese，改写为另一格式词汇的词法Narsese
+//!   * 📄解析漢文Narsese⇒改写为ASCII词汇⇒格式化后输入OpenNARS
+//! * 📌不经过「枚举Narsese」：保留词法结构
+//!   * 📄集合中的重复组分、无序复合词项的组分顺序、实例/属性系词等，均原样保留
+//! * 🚩词汇表取自「枚举Narsese格式」：其中的每个字段即「一种语义」对应的符号
+//!   * ⚠️词法Narsese格式中的「匹配字典」只记录符号、不记录语义，无法对应
+//! * 📌真值、预算值仅包含数值字串，无需改写
+
+use super::*;
+use crate::{
+    conversion::string::impl_enum::NarseseFormat as EnumNarseseFormat,
+    lexical::{Narsese, Sentence, Task, Term},
+};
+
+/// 词汇折叠器
+/// * 🚩「源格式」与「目标格式」的组合
+#[derive(Debug, Clone, Copy)]
+pub struct VocabularyFolder<'a> {
+    /// 源格式：词法Narsese当前所用的词汇
+    pub source: &'a EnumNarseseFormat<&'a str>,
+    /// 目标格式：折叠后所用的词汇
+    pub target: &'a EnumNarseseFormat<&'a str>,
+}
+
+impl<'a> VocabularyFolder<'a> {
+    /// 构造函数
+    pub fn new(
+        source: &'a EnumNarseseFormat<&'a str>,
+        target: &'a EnumNarseseFormat<&'a str>,
+    ) -> Self {
+        Self { source, target }
+    }
+}
+
+/// 便捷方法
+impl EnumNarseseFormat<&str> {
+    /// 将「使用自身词汇」的词法Narsese，改写为「使用目标词汇」的词法Narsese
+    /// * 🚩遇到自身词汇中不存在的符号⇒报错
+    pub fn fold_vocabulary(&self, target: &Self, narsese: Narsese) -> FoldResult<Narsese> {
+        VocabularyFolder::new(self, target).fold_narsese(narsese)
+    }
+}
+
+/// 词汇表/原子词项前缀
+fn atom_prefixes<'s>(format: &EnumNarseseFormat<&'s str>) -> [&'s str; 7] {
+    let atom = &format.atom;
+    [
+        atom.prefix_word,
+        atom.prefix_placeholder,
+        atom.prefix_variable_independent,
+        atom.prefix_variable_dependent,
+        atom.prefix_variable_query,
+        atom.prefix_interval,
+        atom.prefix_operator,
+    ]
+}
+
+/// 词汇表/复合词项连接符
+fn connecters<'s>(format: &EnumNarseseFormat<&'s str>) -> [&'s str; 12] {
+    let compound = &format.compound;
+    [
+        compound.connecter_intersection_extension,
+        compound.connecter_intersection_intension,
+        compound.connecter_difference_extension,
+        compound.connecter_difference_intension,
+        compound.connecter_product,
+        compound.connecter_image_extension,
+        compound.connecter_image_intension,
+        compound.connecter_conjunction,
+        compound.connecter_disjunction,
+        compound.connecter_negation,
+        compound.connecter_conjunction_sequential,
+        compound.connecter_conjunction_parallel,
+    ]
+}
+
+/// 词汇表/词项集括弧
+fn set_brackets<'s>(format: &EnumNarseseFormat<&'s str>) -> [(&'s str, &'s str); 2] {
+    [
+        format.compound.brackets_set_extension,
+        format.compound.brackets_set_intension,
+    ]
+}
+
+/// 词汇表/陈述系词
+fn copulas<'s>(format: &EnumNarseseFormat<&'s str>) -> [&'s str; 13] {
+    let statement = &format.statement;
+    [
+        statement.copula_inheritance,
+        statement.copula_similarity,
+        statement.copula_implication,
+        statement.copula_equivalence,
+        statement.copula_instance,
+        statement.copula_property,
+        statement.copula_instance_property,
+        statement.copula_implication_predictive,
+        statement.copula_implication_concurrent,
+        statement.copula_implication_retrospective,
+        statement.copula_equivalence_predictive,
+        statement.copula_equivalence_concurrent,
+        statement.copula_equivalence_retrospective,
+    ]
+}
+
+/// 词汇表/标点
+fn punctuations<'s>(format: &EnumNarseseFormat<&'s str>) -> [&'s str; 4] {
+    let sentence = &format.sentence;
+    [
+        sentence.punctuation_judgement,
+        sentence.punctuation_goal,
+        sentence.punctuation_question,
+        sentence.punctuation_quest,
+    ]
+}
+
+/// 工具函数/按「词汇表」改写符号
+/// * 🚩在源词汇表中查找位置，取目标词汇表中同一位置的符号
+fn map_symbol<T: PartialEq + Copy>(symbol: T, source: &[T], target: &[T]) -> Option<T> {
+    source
+        .iter()
+        .position(|s| *s == symbol)
+        .map(|index| target[index])
+}
+
+impl VocabularyFolder<'_> {
+    /// 改写原子词项前缀
+    fn fold_atom_prefix(&self, prefix: &str) -> FoldResult<String> {
+        map_symbol(prefix, &atom_prefixes(self.source), &atom_prefixes(self.target))
+            .map(str::to_string)
+            .ok_or_else(|| FoldError::from(format!("非法原子词项词缀「{prefix}」")))
+    }
+
+    /// 改写复合词项连接符
+    fn fold_connecter(&self, connecter: &str) -> FoldResult<String> {
+        map_symbol(connecter, &connecters(self.source), &connecters(self.target))
+            .map(str::to_string)
+            .ok_or_else(|| FoldError::from(format!("非法复合词项连接符「{connecter}」")))
+    }
+
+    /// 改写词项集括弧
+    fn fold_set_brackets(&self, left: &str, right: &str) -> FoldResult<(String, String)> {
+        map_symbol(
+            (left, right),
+            &set_brackets(self.source),
+            &set_brackets(self.target),
+        )
+        .map(|(left, right)| (left.to_string(), right.to_string()))
+        .ok_or_else(|| FoldError::from(format!("非法词项集括弧「{left}」「{right}」")))
+    }
+
+    /// 改写陈述系词
+    fn fold_copula(&self, copula: &str) -> FoldResult<String> {
+        map_symbol(copula, &copulas(self.source), &copulas(self.target))
+            .map(str::to_string)
+            .ok_or_else(|| FoldError::from(format!("非法陈述系词「{copula}」")))
+    }
+
+    /// 改写标点
+    fn fold_punctuation(&self, punctuation: &str) -> FoldResult<String> {
+        map_symbol(
+            punctuation,
+            &punctuations(self.source),
+            &punctuations(self.target),
+        )
+        .map(str::to_string)
+        .ok_or_else(|| FoldError::from(format!("非法标点「{punctuation}」")))
+    }
+
+    /// 改写时间戳
+    /// * 🚩空时间戳（永恒）⇒保持为空
+    /// * 🚩过去/现在/将来⇒整体改写
+    /// * 🚩固定⇒保留其中的数值，改写括弧与前缀
+    fn fold_stamp(&self, stamp: &str) -> FoldResult<String> {
+        if stamp.is_empty() {
+            return Ok(String::new());
+        }
+        let (source, target) = (&self.source.sentence, &self.target.sentence);
+        let error = || FoldError::from(format!("非法时间戳「{stamp}」"));
+        let content = stamp
+            .strip_prefix(source.stamp_brackets.0)
+            .and_then(|s| s.strip_suffix(source.stamp_brackets.1))
+            .ok_or_else(error)?;
+        let content = first! {
+            (content.eq) => (_);
+            source.stamp_past => target.stamp_past.to_string(),
+            source.stamp_present => target.stamp_present.to_string(),
+            source.stamp_future => target.stamp_future.to_string(),
+            _ => match content.strip_prefix(source.stamp_fixed) {
+                Some(time) => format!("{}{time}", target.stamp_fixed),
+                None => return Err(error()),
+            }
+        };
+        Ok(format!(
+            "{}{content}{}",
+            target.stamp_brackets.0, target.stamp_brackets.1
+        ))
+    }
+}
+
+/// 折叠/各类词法Narsese
+/// * ⚠️不实现[`TryFoldInto`]：词法Narsese已有「向枚举Narsese折叠」的实现
+///   * 📌再实现一次会使`narsese.try_fold_into(&format)`失去类型推断
+impl VocabularyFolder<'_> {
+    /// 折叠/Narsese值
+    pub fn fold_narsese(&self, narsese: Narsese) -> FoldResult<Narsese> {
+        Ok(match narsese {
+            Narsese::Term(term) => Narsese::Term(self.fold_term(term)?),
+            Narsese::Sentence(sentence) => Narsese::Sentence(self.fold_sentence(sentence)?),
+            Narsese::Task(task) => Narsese::Task(self.fold_task(task)?),
+        })
+    }
+
+    /// 折叠/词项
+    /// * 🚩递归改写其中的前缀、连接符、括弧、系词
+    pub fn fold_term(&self, term: Term) -> FoldResult<Term> {
+        let fold_terms = |terms: Vec<Term>| -> FoldResult<Vec<Term>> {
+            terms.into_iter().map(|term| self.fold_term(term)).collect()
+        };
+        Ok(match term {
+            Term::Atom { prefix, name } => Term::Atom {
+                prefix: self.fold_atom_prefix(&prefix)?,
+                name,
+            },
+            Term::Compound { connecter, terms } => Term::Compound {
+                connecter: self.fold_connecter(&connecter)?,
+                terms: fold_terms(terms)?,
+            },
+            Term::Set {
+                left_bracket,
+                terms,
+                right_bracket,
+            } => {
+                let (left_bracket, right_bracket) =
+                    self.fold_set_brackets(&left_bracket, &right_bracket)?;
+                Term::Set {
+                    left_bracket,
+                    terms: fold_terms(terms)?,
+                    right_bracket,
+                }
+            }
+            Term::Statement {
+                copula,
+                subject,
+                predicate,
+            } => Term::Statement {
+                copula: self.fold_copula(&copula)?,
+                subject: Box::new(self.fold_term(*subject)?),
+                predicate: Box::new(self.fold_term(*predicate)?),
+            },
+        })
+    }
+
+    /// 折叠/语句
+    pub fn fold_sentence(&self, sentence: Sentence) -> FoldResult<Sentence> {
+        Ok(Sentence {
+            term: self.fold_term(sentence.term)?,
+            punctuation: self.fold_punctuation(&sentence.punctuation)?,
+            stamp: self.fold_stamp(&sentence.stamp)?,
+            // 真值只有数值⇒原样保留
+            truth: sentence.truth,
+        })
+    }
+
+    /// 折叠/任务
+    pub fn fold_task(&self, task: Task) -> FoldResult<Task> {
+        Ok(Task {
+            // 预算值只有数值⇒原样保留
+            budget: task.budget,
+            sentence: self.fold_sentence(task.sentence)?,
+        })
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversion::string::{
+        impl_enum::format_instances::{
+            FORMAT_ASCII as E_ASCII, FORMAT_HAN as E_HAN, FORMAT_LATEX as E_LATEX,
+        },
+        impl_lexical::{
+            format_instances::{FORMAT_ASCII, FORMAT_HAN},
+            tests::{_sample_task_han, _sample_task_latex},
+        },
+    };
+    use nar_dev_utils::{asserts, show};
+
+    /// 与`_sample_task_han`、`_sample_task_latex`对应的ASCII任务
+    /// * ⚠️`lexical::tests::_sample_task_ascii`的内容与之不同（系词、操作名、真值字串），故不直接比较
+    const SAMPLE_ASCII: &str = "$0.5;0.75;0.4$ <(&/, <{ball} --> [left]>, <(*, {SELF}, $any, #some) --> ^do>) ==> <{SELF} --> [good]>>. :!-1: %1;0.9%";
+
+    #[test]
+    fn test_fold_vocabulary() {
+        let expected = FORMAT_ASCII.parse(SAMPLE_ASCII).unwrap();
+        // 漢文⇒ASCII
+        let folded = E_HAN
+            .fold_vocabulary(&E_ASCII, Narsese::Task(_sample_task_han()))
+            .unwrap();
+        show!(&folded);
+        assert_eq!(folded, expected);
+        // LaTeX⇒ASCII
+        let folded = E_LATEX
+            .fold_vocabulary(&E_ASCII, Narsese::Task(_sample_task_latex()))
+            .unwrap();
+        assert_eq!(folded, expected);
+        // 往返：ASCII⇒漢文⇒ASCII
+        let han = E_ASCII.fold_vocabulary(&E_HAN, expected.clone()).unwrap();
+        asserts! {
+            han => Narsese::Task(_sample_task_han()),
+            E_HAN.fold_vocabulary(&E_ASCII, han).unwrap() => expected,
+        }
+    }
+
+    #[test]
+    fn test_preserve_lexical_structure() {
+        // 枚举Narsese会去重、改写实例系词；词法折叠则原样保留
+        let input = "<{A, A} {-- (&&, B, B)>. :|:";
+        let folded = E_ASCII
+            .fold_vocabulary(&E_HAN, FORMAT_ASCII.parse(input).unwrap())
+            .unwrap();
+        let formatted = FORMAT_HAN.format_narsese(&folded);
+        show!(&formatted);
+        asserts! {
+            E_HAN.fold_vocabulary(&E_ASCII, folded).unwrap() => FORMAT_ASCII.parse(input).unwrap(),
+            FORMAT_HAN.parse(&formatted).is_ok(),
+        }
+    }
+
+    #[test]
+    fn test_unknown_symbol() {
+        let unknown = Narsese::Term(Term::new_atom("^^", "A"));
+        let error = E_ASCII.fold_vocabulary(&E_HAN, unknown).unwrap_err();
+        show!(&error);
+        asserts! {
+            E_ASCII.fold_vocabulary(&E_HAN, Narsese::Term(Term::new_compound("??", vec![]))).is_err(),
+            E_ASCII.fold_vocabulary(&E_HAN, Narsese::Term(Term::new_statement("~~>", Term::new_atom("", "A"), Term::new_atom("", "B")))).is_err(),
+        }
+        // 时间戳 | 只比对时间戳部分
+        let fold_stamp = |target, stamp: &str| {
+            let sentence = Sentence::with_stamp_truth(Term::new_atom("", "A"), ".", stamp, vec![]);
+            VocabularyFolder::new(&E_ASCII, target)
+                .fold_sentence(sentence)
+                .map(|sentence| sentence.stamp)
+        };
+        asserts! {
+            fold_stamp(&E_HAN, "") => Ok(String::new()),
+            fold_stamp(&E_HAN, ":|:") => Ok("现在".to_string()),
+            fold_stamp(&E_LATEX, ":!12:") => Ok("t=12".to_string()),
+            fold_stamp(&E_HAN, ":?:").is_err(),
+        }
+    }
+}
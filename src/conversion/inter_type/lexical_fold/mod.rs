@@ -1,6 +1,7 @@
 //! 「词法折叠」功能支持
 //! * 🎯用于从「词法Narsese」转换到其它形式的Narsese
 //! * 📄词法Narsese→枚举Narsese
+//! * 📄词法Narsese→词法Narsese（改写词汇）
 
 use nar_dev_utils::*;
 
@@ -12,4 +13,6 @@ pub_mod_and_pub_use! {
 feature_pub_mod_and_reexport! {
     // 枚举Narsese
     "enum_narsese" => impl_enum
+    // 词汇折叠 | 词汇表取自「枚举Narsese格式」
+    "enum_narsese" => impl_vocabulary
 }
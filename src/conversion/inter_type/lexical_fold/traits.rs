@@ -2,6 +2,8 @@
 //!
 //! ! ❌弃用「统一词法折叠格式」与「关键字字典」机制
 
+use crate::api::Located;
+
 /// 用以实现「尝试朝某个类型折叠」
 /// * 🎯最初用于「词法Narsese→枚举Narsese」
 /// * ⚠️可能会有「折叠失败」的场景
@@ -24,3 +26,34 @@ pub trait TryFoldInto<'a, Target, Error> {
     ///   * 📄如「非法原子词项前缀」
     fn try_fold_into(self, folder: &'a Self::Folder) -> Result<Target, Error>;
 }
+
+/// 对「带位置的值」批量实现「尝试折叠」
+/// * 🎯折叠时保留源码位置，以便在折叠出错时报告`file.nal:123`
+/// * 🚩成功⇒带位置的目标值；失败⇒带位置的错误
+///   * 📌位置信息与原值一致
+impl<'a, T, Target, Error> TryFoldInto<'a, Located<Target>, Located<Error>> for Located<T>
+where
+    T: TryFoldInto<'a, Target, Error>,
+{
+    type Folder = T::Folder;
+
+    fn try_fold_into(self, folder: &'a Self::Folder) -> Result<Located<Target>, Located<Error>> {
+        self.map(|value| value.try_fold_into(folder)).transpose()
+    }
+}
+
+/// 批量折叠「带位置的值」
+/// * 🎯配合「带位置的流式解析」使用：逐条折叠，并保留每条的位置
+/// * 🚩不会因某条出错而中断：每条的结果均单独返回
+pub fn try_fold_all_located<'a, T, Target, Error>(
+    items: impl IntoIterator<Item = Located<T>>,
+    folder: &'a T::Folder,
+) -> Vec<Result<Located<Target>, Located<Error>>>
+where
+    T: TryFoldInto<'a, Target, Error>,
+{
+    items
+        .into_iter()
+        .map(|item| item.try_fold_into(folder))
+        .collect()
+}
@@ -0,0 +1,405 @@
+//! 「词法展开」：从「枚举Narsese」转换到「词法Narsese」
+//! * 🎯「词法折叠」的逆过程
+//!   * 📌无需「格式化成字符串再解析」：直接构造词法结构，且不会失败
+//! * 🚩所用的前缀、连接符、系词、括弧等，均取自「枚举Narsese格式」
+//!   * 📌与该格式的格式化器输出一致：展开结果与「格式化后再词法解析」的结果相同
+//! * 📄像：在记录的索引处插入「占位符」原子词项
+//! * 📄真值、预算值：数值转换为字符串形式
+//! * 📄标点、时间戳：使用格式化器输出的字符串
+//!   * ✨时间戳另可按「词法Narsese格式」直接转换：[`EnumStamp::to_lexical_stamp`]、[`EnumStamp::from_lexical_stamp`]
+
+use crate::{
+    api::{FloatPrecision, GetBudget, GetPunctuation, GetStamp, GetTerm, GetTruth},
+    conversion::string::{
+        impl_enum::NarseseFormat as EnumNarseseFormat,
+        impl_lexical::{NarseseFormat as LexicalNarseseFormat, ParseResult},
+    },
+    enum_narsese::{
+        Budget as EnumBudget, ImageIterator, Narsese as EnumNarsese, Sentence as EnumSentence,
+        Stamp as EnumStamp, Task as EnumTask, Term as EnumTerm, Truth as EnumTruth,
+    },
+    lexical::{Budget, Narsese, Sentence, StampKind, Task, Term, Truth},
+};
+
+/// 实现/词项
+impl EnumTerm {
+    /// 展开为词法词项
+    /// * 🚩递归展开所有组分
+    pub fn to_lexical(&self, format: &EnumNarseseFormat<&str>) -> Term {
+        use EnumTerm::*;
+        let atom = |prefix: &str| Term::new_atom(prefix, self.get_atom_name_unchecked());
+        let compound = |connecter: &str| Term::new_compound(connecter, unfold_terms(self, format));
+        let set =
+            |(left, right): (&str, &str)| Term::new_set(left, unfold_terms(self, format), right);
+        let statement = |copula: &str, subject: &EnumTerm, predicate: &EnumTerm| {
+            Term::new_statement(
+                copula,
+                subject.to_lexical(format),
+                predicate.to_lexical(format),
+            )
+        };
+        let image = |connecter: &str, index| {
+            let terms = ImageIterator::new(self.get_components().into_iter(), index)
+                .map(|term| term.to_lexical(format))
+                .collect();
+            Term::new_compound(connecter, terms)
+        };
+        match self {
+            // 原子词项
+            Word(..) => atom(format.atom.prefix_word),
+            Placeholder => atom(format.atom.prefix_placeholder),
+            VariableIndependent(..) => atom(format.atom.prefix_variable_independent),
+            VariableDependent(..) => atom(format.atom.prefix_variable_dependent),
+            VariableQuery(..) => atom(format.atom.prefix_variable_query),
+            Interval(..) => atom(format.atom.prefix_interval),
+            Operator(..) => atom(format.atom.prefix_operator),
+            // 词项集
+            SetExtension(..) => set(format.compound.brackets_set_extension),
+            SetIntension(..) => set(format.compound.brackets_set_intension),
+            // 复合词项
+            IntersectionExtension(..) => compound(format.compound.connecter_intersection_extension),
+            IntersectionIntension(..) => compound(format.compound.connecter_intersection_intension),
+            DifferenceExtension(..) => compound(format.compound.connecter_difference_extension),
+            DifferenceIntension(..) => compound(format.compound.connecter_difference_intension),
+            Product(..) => compound(format.compound.connecter_product),
+            ImageExtension(index, _) => image(format.compound.connecter_image_extension, *index),
+            ImageIntension(index, _) => image(format.compound.connecter_image_intension, *index),
+            Conjunction(..) => compound(format.compound.connecter_conjunction),
+            Disjunction(..) => compound(format.compound.connecter_disjunction),
+            Negation(..) => compound(format.compound.connecter_negation),
+            ConjunctionSequential(..) => compound(format.compound.connecter_conjunction_sequential),
+            ConjunctionParallel(..) => compound(format.compound.connecter_conjunction_parallel),
+            // 陈述
+            Inheritance(s, p) => statement(format.statement.copula_inheritance, s, p),
+            Similarity(s, p) => statement(format.statement.copula_similarity, s, p),
+            Implication(s, p) => statement(format.statement.copula_implication, s, p),
+            Equivalence(s, p) => statement(format.statement.copula_equivalence, s, p),
+            ImplicationPredictive(s, p) => {
+                statement(format.statement.copula_implication_predictive, s, p)
+            }
+            ImplicationConcurrent(s, p) => {
+                statement(format.statement.copula_implication_concurrent, s, p)
+            }
+            ImplicationRetrospective(s, p) => {
+                statement(format.statement.copula_implication_retrospective, s, p)
+            }
+            EquivalencePredictive(s, p) => {
+                statement(format.statement.copula_equivalence_predictive, s, p)
+            }
+            EquivalenceConcurrent(s, p) => {
+                statement(format.statement.copula_equivalence_concurrent, s, p)
+            }
+            EquivalenceRetrospective(s, p) => {
+                statement(format.statement.copula_equivalence_retrospective, s, p)
+            }
+        }
+    }
+}
+
+/// 工具函数/展开复合词项的所有组分
+fn unfold_terms(term: &EnumTerm, format: &EnumNarseseFormat<&str>) -> Vec<Term> {
+    term.get_components()
+        .into_iter()
+        .map(|term| term.to_lexical(format))
+        .collect()
+}
+
+/// 工具函数/浮点数组⇒数值字串数组
+/// * 📌与格式化器所用的数值形式一致
+fn unfold_floats(floats: &[FloatPrecision]) -> Vec<String> {
+    floats.iter().map(ToString::to_string).collect()
+}
+
+/// 实现/真值
+impl EnumTruth {
+    /// 展开为词法真值
+    pub fn to_lexical(&self) -> Truth {
+        match self {
+            EnumTruth::Empty => unfold_floats(&[]),
+            EnumTruth::Single(f) => unfold_floats(&[*f]),
+            EnumTruth::Double(f, c) => unfold_floats(&[*f, *c]),
+            EnumTruth::Triple(f, c, k) => unfold_floats(&[*f, *c, *k]),
+        }
+    }
+}
+
+/// 实现/预算值
+impl EnumBudget {
+    /// 展开为词法预算值
+    pub fn to_lexical(&self) -> Budget {
+        match self {
+            EnumBudget::Empty => unfold_floats(&[]),
+            EnumBudget::Single(p) => unfold_floats(&[*p]),
+            EnumBudget::Double(p, d) => unfold_floats(&[*p, *d]),
+            EnumBudget::Triple(p, d, q) => unfold_floats(&[*p, *d, *q]),
+        }
+    }
+}
+
+/// 时间戳⇒[时间戳的结构化视图](StampKind)
+impl From<&EnumStamp> for StampKind {
+    fn from(stamp: &EnumStamp) -> Self {
+        match *stamp {
+            EnumStamp::Eternal => StampKind::Eternal,
+            EnumStamp::Past => StampKind::Past,
+            EnumStamp::Present => StampKind::Present,
+            EnumStamp::Future => StampKind::Future,
+            EnumStamp::Fixed(time) => StampKind::Fixed(time as i64),
+        }
+    }
+}
+
+/// [时间戳的结构化视图](StampKind)⇒时间戳
+impl From<StampKind> for EnumStamp {
+    fn from(kind: StampKind) -> Self {
+        match kind {
+            StampKind::Eternal => EnumStamp::Eternal,
+            StampKind::Past => EnumStamp::Past,
+            StampKind::Present => EnumStamp::Present,
+            StampKind::Future => EnumStamp::Future,
+            StampKind::Fixed(time) => EnumStamp::Fixed(time as _),
+        }
+    }
+}
+
+/// 实现/时间戳
+/// * 🎯不经由「枚举Narsese格式」，直接与某一「词法Narsese格式」的时间戳字符串互转
+///   * 📄LaTeX：`t=-1`；漢文：`发生在-1`
+impl EnumStamp {
+    /// 按词法格式展开为时间戳字符串
+    /// * 🚩经由[`StampKind`]：[`LexicalNarseseFormat::format_stamp_kind`]
+    pub fn to_lexical_stamp(&self, format: &LexicalNarseseFormat) -> String {
+        format.format_stamp_kind(self.into())
+    }
+
+    /// 按词法格式解析时间戳字符串
+    /// * 🚩经由[`StampKind`]：[`LexicalNarseseFormat::parse_stamp_kind`]
+    /// * 📌固定时间可带正负号：`:!+1:`、`:!-1:`
+    pub fn from_lexical_stamp(stamp: &str, format: &LexicalNarseseFormat) -> ParseResult<Self> {
+        format.parse_stamp_kind(stamp).map(EnumStamp::from)
+    }
+}
+
+/// 实现/语句
+impl EnumSentence {
+    /// 展开为词法语句
+    /// * 🚩标点、时间戳使用格式化器输出的字符串
+    /// * 🚩无真值（问题、请求）⇒空真值
+    pub fn to_lexical(&self, format: &EnumNarseseFormat<&str>) -> Sentence {
+        Sentence::with_stamp_truth(
+            self.get_term().to_lexical(format),
+            format.format_punctuation(self.get_punctuation()),
+            format.format_stamp(self.get_stamp()),
+            self.get_truth()
+                .map(EnumTruth::to_lexical)
+                .unwrap_or_default(),
+        )
+    }
+}
+
+/// 实现/任务
+impl EnumTask {
+    /// 展开为词法任务
+    pub fn to_lexical(&self, format: &EnumNarseseFormat<&str>) -> Task {
+        Task {
+            budget: self.get_budget().to_lexical(),
+            sentence: self.get_sentence().to_lexical(format),
+        }
+    }
+}
+
+/// 实现/全体Narsese
+impl EnumNarsese {
+    /// 展开为词法Narsese
+    /// * 🚩向下分派
+    pub fn to_lexical(&self, format: &EnumNarseseFormat<&str>) -> Narsese {
+        match self {
+            EnumNarsese::Term(term) => Narsese::Term(term.to_lexical(format)),
+            EnumNarsese::Sentence(sentence) => Narsese::Sentence(sentence.to_lexical(format)),
+            EnumNarsese::Task(task) => Narsese::Task(task.to_lexical(format)),
+        }
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        conversion::{
+            inter_type::lexical_fold::TryFoldInto,
+            string::{
+                impl_enum::{format_instances::*, tests::_sample_task},
+                impl_lexical::format_instances::{
+                    FORMAT_ASCII as L_ASCII, FORMAT_HAN as L_HAN, FORMAT_LATEX as L_LATEX,
+                },
+            },
+        },
+        enum_narsese::{
+            testing::{diff_terms, display_diffs},
+            tests::generate_term_testset,
+        },
+    };
+    use nar_dev_utils::{asserts, show};
+
+    /// 展开⇒折叠：所有词项均保持不变
+    #[test]
+    fn test_round_trip_terms() {
+        for format in [FORMAT_ASCII, FORMAT_LATEX, FORMAT_HAN] {
+            for term in generate_term_testset() {
+                let lexical = term.to_lexical(&format);
+                let folded: EnumTerm = lexical.clone().try_fold_into(&format).unwrap();
+                assert!(
+                    folded == term,
+                    "{lexical:?}\n{}",
+                    display_diffs(&diff_terms(&folded, &term))
+                );
+            }
+        }
+    }
+
+    /// 三种格式的往返矩阵
+    /// * 🎯所有词项（及其嵌套形式）在各格式下均可「格式化⇒解析」往返
+    ///   * 📌枚举Narsese：格式化后直接解析
+    ///   * 📌词法Narsese：展开、格式化、解析后再折叠
+    /// * 🎯防止某格式缺失系词、连接符、前缀，或词法与枚举两侧不一致
+    #[test]
+    fn test_round_trip_formats() {
+        let pairs = [
+            (FORMAT_ASCII, &*L_ASCII),
+            (FORMAT_LATEX, &*L_LATEX),
+            (FORMAT_HAN, &*L_HAN),
+        ];
+        let a = EnumTerm::new_word("A");
+        let terms = generate_term_testset().into_iter().flat_map(|term| {
+            [
+                EnumTerm::new_inheritance(term.clone(), a.clone()),
+                EnumTerm::new_product(vec![a.clone(), term.clone()]),
+                term,
+            ]
+        });
+        for term in terms {
+            for (format, lexical_format) in &pairs {
+                // 枚举Narsese
+                let formatted = format.format_term(&term);
+                let parsed: EnumNarsese = format.parse(&formatted).unwrap();
+                assert_eq!(parsed, EnumNarsese::Term(term.clone()), "{formatted:?}");
+                // 词法Narsese
+                let lexical = term.to_lexical(format);
+                let formatted = lexical_format.format_term(&lexical);
+                let parsed = lexical_format.parse_term(&formatted).unwrap();
+                assert_eq!(parsed, lexical, "{formatted:?}");
+                let folded: EnumTerm = parsed.try_fold_into(format).unwrap();
+                assert_eq!(folded, term, "{formatted:?}");
+            }
+        }
+    }
+
+    /// 展开结果与「格式化后再词法解析」一致
+    #[test]
+    fn test_consistent_with_formatter() {
+        let pairs = [
+            (FORMAT_ASCII, &*L_ASCII),
+            (FORMAT_LATEX, &*L_LATEX),
+            (FORMAT_HAN, &*L_HAN),
+        ];
+        for (format, lexical_format) in pairs {
+            let task = _sample_task();
+            let unfolded = task.to_lexical(&format);
+            show!(&unfolded);
+            let parsed = lexical_format.parse(&format.format_task(&task)).unwrap();
+            assert_eq!(Narsese::Task(unfolded.clone()), parsed);
+            // 折叠回枚举Narsese
+            let folded: EnumTask = unfolded.try_fold_into(&format).unwrap();
+            assert_eq!(folded, task);
+        }
+        for term in generate_term_testset() {
+            let parsed = L_ASCII
+                .parse_term(&FORMAT_ASCII.format_term(&term))
+                .unwrap();
+            assert_eq!(term.to_lexical(&FORMAT_ASCII), parsed);
+        }
+    }
+
+    /// 时间戳矩阵：所有时间戳 × 三种格式
+    /// * 🎯带符号的固定时间在各格式中均能往返
+    ///   * 📌直接转换：展开为词法时间戳字符串⇒解析回来
+    ///   * 📌完整任务：展开、格式化、解析后再折叠
+    #[test]
+    fn test_round_trip_stamps() {
+        let pairs = [
+            (FORMAT_ASCII, &*L_ASCII),
+            (FORMAT_LATEX, &*L_LATEX),
+            (FORMAT_HAN, &*L_HAN),
+        ];
+        let stamps = [
+            EnumStamp::Eternal,
+            EnumStamp::Past,
+            EnumStamp::Present,
+            EnumStamp::Future,
+            EnumStamp::Fixed(0),
+            EnumStamp::Fixed(1),
+            EnumStamp::Fixed(-1),
+            EnumStamp::Fixed(-1024),
+            EnumStamp::Fixed(isize::MAX),
+            EnumStamp::Fixed(isize::MIN),
+        ];
+        let term = || EnumTerm::new_inheritance(EnumTerm::new_word("A"), EnumTerm::new_word("B"));
+        for (format, lexical_format) in &pairs {
+            for stamp in &stamps {
+                // 直接转换 | 与枚举格式化器的输出一致
+                let lexical_stamp = stamp.to_lexical_stamp(lexical_format);
+                asserts! {
+                    lexical_stamp => format.format_stamp(stamp),
+                    EnumStamp::from_lexical_stamp(&lexical_stamp, lexical_format).unwrap() => *stamp,
+                }
+                // 完整任务 | 判断、问题
+                let sentences = [
+                    EnumSentence::new_judgement(term(), EnumTruth::Double(1.0, 0.9), stamp.clone()),
+                    EnumSentence::new_question(term(), stamp.clone()),
+                ];
+                for sentence in sentences {
+                    let task = EnumTask::new(sentence, EnumBudget::Double(0.5, 0.5));
+                    let formatted = lexical_format.format_task(&task.to_lexical(format));
+                    let Narsese::Task(parsed) = lexical_format.parse(&formatted).unwrap() else {
+                        panic!("不是任务：{formatted:?}");
+                    };
+                    let parsed_stamp =
+                        EnumStamp::from_lexical_stamp(&parsed.sentence.stamp, lexical_format);
+                    let folded: EnumTask = parsed.try_fold_into(format).unwrap();
+                    asserts! {
+                        parsed_stamp.unwrap() => *stamp,
+                        folded => task,
+                    }
+                }
+            }
+        }
+        // 显式正号
+        asserts! {
+            EnumStamp::from_lexical_stamp(":!+1:", &L_ASCII).unwrap() => EnumStamp::Fixed(1),
+            EnumStamp::from_lexical_stamp("t=+1", &L_LATEX).unwrap() => EnumStamp::Fixed(1),
+            EnumStamp::from_lexical_stamp("发生在+1", &L_HAN).unwrap() => EnumStamp::Fixed(1),
+            EnumStamp::from_lexical_stamp("t=1-", &L_LATEX).is_err(),
+        }
+    }
+
+    /// 像、真值、预算值、无真值语句
+    #[test]
+    fn test_unfold_details() {
+        let image = EnumTerm::new_image_extension(
+            1,
+            vec![EnumTerm::new_word("R"), EnumTerm::new_word("A")],
+        );
+        let question = FORMAT_ASCII
+            .parse::<EnumNarsese>("<A --> B>?")
+            .unwrap()
+            .to_lexical(&FORMAT_ASCII);
+        asserts! {
+            image.to_lexical(&FORMAT_ASCII) => L_ASCII.parse_term("(/, R, _, A)").unwrap(),
+            EnumTruth::new_double(1.0, 0.9).to_lexical() => ["1", "0.9"],
+            EnumTruth::new_empty().to_lexical() => Truth::new(),
+            EnumBudget::new_triple(0.5, 0.75, 0.4).to_lexical() => ["0.5", "0.75", "0.4"],
+            question => L_ASCII.parse("<A --> B>?").unwrap(),
+        }
+    }
+}
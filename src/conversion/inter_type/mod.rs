@@ -1,5 +1,14 @@
 //! 用于不同结构Narsese的转化
 //! * 🎯最初用于「词法Narsese→枚举Narsese」
+//! * 📄亦有其逆过程「枚举Narsese→词法Narsese」
 
 // 词法Narsese折叠
 pub mod lexical_fold;
+
+// 词法Narsese展开 | 枚举Narsese→词法Narsese
+#[cfg(all(feature = "enum_narsese", feature = "lexical_narsese"))]
+pub mod lexical_unfold;
+
+// 扁平Narsese | 词法/枚举Narsese⇄扁平Narsese
+#[cfg(feature = "lexical_narsese")]
+pub mod flat;
@@ -0,0 +1,346 @@
+//! NAVM/推理器的「输入输出行」
+//! * 🎯处理包裹Narsese的指令行与推理器输出行
+//!   * 📄输入：`IN <A --> B>. %1.0;0.9%`
+//!   * 📄输出：`OUT: <A --> C>. %1.00;0.81% {1 : 2;1}`
+//! * 🚩识别行首的「方向前缀」，其余部分交给[词法解析器](NarseseFormat::parse)
+//!   * 📌前缀不区分大小写，可带冒号：`IN`、`in:`、`OUT:`、`Answer:`、`EXE`
+//!   * 📌未知前缀不报错，而是归为[`IoDirection::Other`]
+//! * 📌行尾附带的证据基`{...}`（如OpenNARS的`{1 : 2;1}`）会先被剥离再解析
+//!   * ⚠️词法解析器会忽略无法识别的尾部，故不能「先整体解析、失败再剥离」
+//!   * 📌仅由数值与`:` `;` `,`组成的`{...}`才被视作证据基，以免误伤末尾的外延集
+//! * ✨亦可解析OpenNARS的任务行：`Task: $0.8;0.8;0.95$ <a --> b>. %1.00;0.90% {1 : 2;3}`
+//!   * 📄参见[`parse_opennars_line`]
+
+use crate::{
+    api::IntPrecision,
+    conversion::string::impl_lexical::{NarseseFormat, ParseError},
+    lexical::Narsese,
+};
+
+/// 输入输出行的方向
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IoDirection {
+    /// 输入 | `IN`
+    In,
+    /// 输出 | `OUT`
+    Out,
+    /// 回答 | `Answer`
+    Answer,
+    /// 执行操作 | `EXE`
+    Exe,
+    /// 其它前缀 | 原样保留
+    Other(String),
+}
+
+impl IoDirection {
+    /// 从前缀识别方向
+    /// * 📌不区分大小写，忽略末尾冒号
+    pub fn from_prefix(prefix: &str) -> Self {
+        let name = prefix.strip_suffix(':').unwrap_or(prefix);
+        match name.to_lowercase().as_str() {
+            "in" => Self::In,
+            "out" => Self::Out,
+            "answer" => Self::Answer,
+            "exe" => Self::Exe,
+            _ => Self::Other(prefix.to_string()),
+        }
+    }
+
+    /// 格式化时使用的前缀
+    pub fn prefix(&self) -> &str {
+        match self {
+            Self::In => "IN",
+            Self::Out => "OUT:",
+            Self::Answer => "Answer:",
+            Self::Exe => "EXE:",
+            Self::Other(prefix) => prefix,
+        }
+    }
+}
+
+/// 一行输入输出
+#[derive(Debug, Clone, PartialEq)]
+pub struct IoLine {
+    /// 方向
+    pub direction: IoDirection,
+    /// 解析出的Narsese
+    /// * 📌未知前缀、空内容、无法解析的操作输出⇒[`None`]
+    pub narsese: Option<Narsese>,
+    /// 原始行
+    pub raw: String,
+}
+
+/// 工具函数：拆分出行尾的证据基`{...}`
+/// * 📌须以空白符与前文隔开，以免误伤末尾的外延集
+/// * 📌内容须形如`创建时间 : 证据1;证据2;……`，创建时间可省略
+///   * 📄`{1 : 2;3}` ⇒ `[2, 3]`；`{0: 1}` ⇒ `[1]`
+/// * ⚙️返回「剥离后的前文」与「证据基」；不匹配⇒[`None`]
+fn split_evidence(payload: &str) -> Option<(&str, Vec<IntPrecision>)> {
+    let payload = payload.trim_end();
+    let inner = payload.strip_suffix('}')?;
+    let left = inner.rfind('{')?;
+    let head = &payload[..left];
+    if !head.ends_with(char::is_whitespace) || head.trim().is_empty() {
+        return None;
+    }
+    let content = &inner[left + 1..];
+    let base = match content.split_once(':') {
+        Some((time, base)) => {
+            time.trim().parse::<IntPrecision>().ok()?;
+            base
+        }
+        None => content,
+    };
+    let evidence = base
+        .split([';', ','])
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::parse)
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+    Some((head.trim_end(), evidence))
+}
+
+/// 工具函数：解析行内的Narsese
+/// * 🚩行尾有证据基⇒先剥离后解析；失败⇒回退到整体解析
+/// * 📄`{A, B} {1 : 2}`⇒`{A, B}`；`<A --> {B}>`⇒不剥离
+fn parse_payload(format: &NarseseFormat, payload: &str) -> Result<Narsese, ParseError> {
+    match split_evidence(payload).map(|(head, _)| format.parse(head)) {
+        Some(Ok(narsese)) => Ok(narsese),
+        _ => format.parse(payload),
+    }
+}
+
+/// 解析一行输入输出
+/// * 🚩首个空白符前为前缀，其后为Narsese
+/// * 📌未知前缀、空内容⇒不解析，[`IoLine::narsese`]为[`None`]
+/// * 📌`EXE`行的内容常为操作调用（如`^left([{SELF}])=null`）⇒尽力解析，失败不报错
+/// * ❌其它已知前缀的内容解析失败⇒返回解析错误
+pub fn parse_io_line(format: &NarseseFormat, line: &str) -> Result<IoLine, ParseError> {
+    let trimmed = line.trim();
+    let (prefix, payload) = trimmed
+        .split_once(|c: char| c.is_whitespace())
+        .map_or((trimmed, ""), |(prefix, payload)| (prefix, payload.trim()));
+    let direction = IoDirection::from_prefix(prefix);
+    let narsese = match (&direction, payload) {
+        (IoDirection::Other(..), _) | (_, "") => None,
+        (IoDirection::Exe, payload) => parse_payload(format, payload).ok(),
+        (_, payload) => Some(parse_payload(format, payload)?),
+    };
+    Ok(IoLine {
+        direction,
+        narsese,
+        raw: line.to_string(),
+    })
+}
+
+/// 格式化一行输入输出
+/// * 🚩有Narsese⇒「前缀 + 空格 + 格式化后的Narsese」
+/// * 📌无Narsese⇒原样返回原始行
+pub fn format_io_line(format: &NarseseFormat, line: &IoLine) -> String {
+    match &line.narsese {
+        Some(narsese) => format!(
+            "{} {}",
+            line.direction.prefix(),
+            format.format_narsese(narsese)
+        ),
+        None => line.raw.clone(),
+    }
+}
+
+/// OpenNARS任务行的解析结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenNarsLine {
+    /// 行首标签 | 不含冒号
+    /// * 📄`Task`、`Derived`、`Input`
+    pub label: Option<String>,
+    /// 解析出的Narsese
+    pub narsese: Narsese,
+    /// 行尾的证据基 | 不含创建时间
+    /// * 📌无证据基⇒[`None`]
+    pub evidence: Option<Vec<IntPrecision>>,
+}
+
+/// OpenNARS任务行的标签
+/// * 📌匹配时不区分大小写
+pub const OPENNARS_LABELS: [&str; 3] = ["Task", "Derived", "Input"];
+
+/// 解析OpenNARS（3.x）的任务行
+/// * 🎯处理OpenNARS以Java`toString`风格打印的任务
+///   * 📄`Task: $0.8000;0.8000;0.9500$ <a --> b>. %1.00;0.90% {1 : 2;3}`
+/// * 🚩先剥离行首标签（可选）与行尾证据基（可选），再交给词法解析器
+///   * 📌标签须带冒号：`Task:`、`Derived:`、`Input:`
+/// * ❌剥离后的内容解析失败⇒返回解析错误
+pub fn parse_opennars_line(format: &NarseseFormat, line: &str) -> Result<OpenNarsLine, ParseError> {
+    let trimmed = line.trim();
+    let label = trimmed.split_once(':').and_then(|(label, rest)| {
+        OPENNARS_LABELS
+            .iter()
+            .any(|known| known.eq_ignore_ascii_case(label))
+            .then_some((label, rest))
+    });
+    let (label, payload) = match label {
+        Some((label, rest)) => (Some(label.to_string()), rest.trim_start()),
+        None => (None, trimmed),
+    };
+    let (narsese, evidence) = match split_evidence(payload) {
+        Some((head, evidence)) => (format.parse(head)?, Some(evidence)),
+        None => (format.parse(payload)?, None),
+    };
+    Ok(OpenNarsLine {
+        label,
+        narsese,
+        evidence,
+    })
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversion::string::impl_lexical::format_instances::FORMAT_ASCII;
+    use nar_dev_utils::{asserts, show};
+
+    /// 测试用记录：OpenNARS命令行的输出
+    const TRANSCRIPT: &str = r"
+IN: <robin --> bird>. %1.00;0.90% {0 : 1}
+IN: <bird --> animal>. %1.00;0.90% {0 : 2}
+IN: <robin --> animal>?  {0 : 3}
+1
+OUT: <robin --> animal>. %1.00;0.81% {1 : 2;1}
+Answer: <robin --> animal>. %1.00;0.81% {1 : 2;1}
+OUT: <animal --> robin>. %1.00;0.45% {1 : 2;1}
+OUT: $0.50;0.50;0.50$ <{tweety} --> bird>. %1.00;0.90% {2 : 4}
+IN: <(*, {SELF}) --> ^left>! {3 : 5}
+EXE: $0.11;0.39;0.38$ ^left([{SELF}])=null
+INFO: reasoner started
+";
+
+    #[test]
+    fn test_parse_transcript() {
+        let lines = TRANSCRIPT
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| parse_io_line(&FORMAT_ASCII, line))
+            .collect::<Result<Vec<_>, _>>()
+            .expect("记录应能完全解析");
+        show!(&lines);
+        let count = |direction: IoDirection| {
+            lines
+                .iter()
+                .filter(|line| line.direction == direction && line.narsese.is_some())
+                .count()
+        };
+        // 证据基被剥离，真值等信息得以保留
+        asserts! {
+            lines[0].narsese => Some(FORMAT_ASCII.parse("<robin --> bird>. %1.00;0.90%").unwrap()),
+            lines[2].narsese => Some(FORMAT_ASCII.parse("<robin --> animal>?").unwrap()),
+        }
+        asserts! {
+            lines.len() => 11,
+            count(IoDirection::In) => 4,
+            count(IoDirection::Out) => 3,
+            count(IoDirection::Answer) => 1,
+            lines[9].direction => IoDirection::Exe,
+            lines[3].direction => IoDirection::Other("1".into()),
+            lines[10].direction => IoDirection::Other("INFO:".into()),
+            lines[10].narsese => None,
+        }
+    }
+
+    #[test]
+    fn test_parse_io_line() {
+        let parse = |line| parse_io_line(&FORMAT_ASCII, line).unwrap();
+        let expected = FORMAT_ASCII.parse("<A --> B>. %1.0;0.9%").unwrap();
+        asserts! {
+            // 前缀不区分大小写、冒号可选
+            parse("IN <A --> B>. %1.0;0.9%").narsese => Some(expected.clone()),
+            parse("in: <A --> B>. %1.0;0.9%").direction => IoDirection::In,
+            parse("Out <A --> B>.").direction => IoDirection::Out,
+            parse("answer: <A --> B>.").direction => IoDirection::Answer,
+            // 末尾的外延集不被当作证据基
+            parse("OUT: <A --> {B}>").narsese => Some(FORMAT_ASCII.parse("<A --> {B}>").unwrap()),
+            parse("OUT: {A, B} {1 : 2}").narsese => Some(FORMAT_ASCII.parse("{A, B}").unwrap()),
+            // 空内容
+            parse("IN").narsese => None,
+            parse("  ").direction => IoDirection::Other("".into()),
+        }
+        // 已知前缀、内容有误⇒报错
+        assert!(parse_io_line(&FORMAT_ASCII, "IN: <A --> >.").is_err());
+        assert!(parse_io_line(&FORMAT_ASCII, "OUT: <A --> >. {1 : 2}").is_err());
+    }
+
+    #[test]
+    fn test_parse_opennars_line() {
+        let parse = |line| parse_opennars_line(&FORMAT_ASCII, line).unwrap();
+        let narsese = |input| FORMAT_ASCII.parse(input).unwrap();
+        let samples = [
+            (
+                "Task: $0.8000;0.8000;0.9500$ <a --> b>. %1.00;0.90% {1 : 2;3}",
+                Some("Task"),
+                "$0.8000;0.8000;0.9500$ <a --> b>. %1.00;0.90%",
+                Some(vec![2, 3]),
+            ),
+            (
+                "Derived: $0.3000;0.5000;0.9500$ <a --> c>. %1.00;0.81% {5 : 1;2}",
+                Some("Derived"),
+                "$0.3000;0.5000;0.9500$ <a --> c>. %1.00;0.81%",
+                Some(vec![1, 2]),
+            ),
+            (
+                "Input: <(*, {SELF}, door) --> ^open>! :|: %1.00;0.90% {0: 7}",
+                Some("Input"),
+                "<(*, {SELF}, door) --> ^open>! :|: %1.00;0.90%",
+                Some(vec![7]),
+            ),
+            // 无标签
+            (
+                "$0.50;0.80;0.95$ <{tweety} --> [yellow]>. %1.00;0.90% {3 : 4}",
+                None,
+                "$0.50;0.80;0.95$ <{tweety} --> [yellow]>. %1.00;0.90%",
+                Some(vec![4]),
+            ),
+            // 无证据基 | 末尾的外延集不被误伤
+            ("task: <a --> b>?", Some("task"), "<a --> b>?", None),
+            ("Task: <a --> {b}>.", Some("Task"), "<a --> {b}>.", None),
+            ("{a, b}", None, "{a, b}", None),
+            (
+                "<a --> b>. %1.00;0.90% {x}",
+                None,
+                "<a --> b>. %1.00;0.90% {x}",
+                None,
+            ),
+        ];
+        for (line, label, expected, evidence) in samples {
+            let parsed = parse(line);
+            asserts! {
+                parsed.label.as_deref() => label,
+                parsed.narsese => narsese(expected),
+                parsed.evidence => evidence,
+            }
+        }
+        // 剥离后仍有误⇒报错
+        assert!(parse_opennars_line(&FORMAT_ASCII, "Task: <a --> >. {1 : 2}").is_err());
+        assert!(parse_opennars_line(&FORMAT_ASCII, "Task: {1 : 2}").is_err());
+    }
+
+    #[test]
+    fn test_format_io_line() {
+        for line in TRANSCRIPT.lines().filter(|line| !line.trim().is_empty()) {
+            let parsed = parse_io_line(&FORMAT_ASCII, line).unwrap();
+            let formatted = format_io_line(&FORMAT_ASCII, &parsed);
+            // 重新解析：方向与内容不变
+            let reparsed = parse_io_line(&FORMAT_ASCII, &formatted).unwrap();
+            asserts! {
+                reparsed.direction => parsed.direction,
+                reparsed.narsese => parsed.narsese,
+            }
+        }
+        let line = IoLine {
+            direction: IoDirection::In,
+            narsese: Some(FORMAT_ASCII.parse("<A --> B>.").unwrap()),
+            raw: String::new(),
+        };
+        assert_eq!(format_io_line(&FORMAT_ASCII, &line), "IN <A --> B>.");
+    }
+}
@@ -0,0 +1,253 @@
+//! 词法Narsese与JSON的相互转换
+//! * 🎯在网络（如WebSocket）间传输词法Narsese
+//! * 📌表示形式稳定：词项带`type`标签，其余字段与结构体字段同名
+//!   * 📄原子词项：`{"type":"Atom","prefix":"$","name":"x"}`
+//!   * 📄复合词项：`{"type":"Compound","connecter":"&/","terms":[…]}`
+//!   * 📄词项集：`{"type":"Set","left_bracket":"{","terms":[…],"right_bracket":"}"}`
+//!   * 📄陈述：`{"type":"Statement","copula":"-->","subject":…,"predicate":…}`
+//!   * 📄语句：`{"term":…,"punctuation":".","stamp":":|:","truth":["1.0","0.9"]}`
+//!   * 📄任务：`{"budget":["0.5","0.5","0.5"],"sentence":…}`
+//!   * 📄Narsese值：`{"type":"Task","value":…}`
+//! * 📌真值、预算值、时间戳、标点本就是字符串（数组），直接对应JSON字符串（数组）
+
+use super::{JsonError, JsonResult, JsonValue};
+use crate::lexical::{Narsese, Sentence, Task, Term};
+
+/// 转换为JSON值
+pub trait ToJson {
+    /// 转换为[`JsonValue`]
+    fn to_json_value(&self) -> JsonValue;
+
+    /// 转换为JSON字符串
+    fn to_json(&self) -> String {
+        self.to_json_value().to_json_string()
+    }
+}
+
+/// 从JSON值转换
+pub trait FromJson: Sized {
+    /// 从[`JsonValue`]转换
+    fn from_json_value(value: &JsonValue) -> JsonResult<Self>;
+
+    /// 从JSON字符串转换
+    fn from_json(json: &str) -> JsonResult<Self> {
+        Self::from_json_value(&JsonValue::parse(json)?)
+    }
+}
+
+/// 字符串：对应标点、时间戳
+impl ToJson for String {
+    fn to_json_value(&self) -> JsonValue {
+        JsonValue::string(self)
+    }
+}
+
+impl FromJson for String {
+    fn from_json_value(value: &JsonValue) -> JsonResult<Self> {
+        value.as_str().map(str::to_string)
+    }
+}
+
+/// 字符串数组：对应真值、预算值
+impl ToJson for Vec<String> {
+    fn to_json_value(&self) -> JsonValue {
+        JsonValue::strings(self)
+    }
+}
+
+impl FromJson for Vec<String> {
+    fn from_json_value(value: &JsonValue) -> JsonResult<Self> {
+        value.as_strings()
+    }
+}
+
+/// 词项：带`type`标签
+impl ToJson for Term {
+    fn to_json_value(&self) -> JsonValue {
+        let terms = |terms: &[Term]| JsonValue::Array(terms.iter().map(Term::to_json_value).collect());
+        match self {
+            Term::Atom { prefix, name } => JsonValue::object([
+                ("type", JsonValue::string("Atom")),
+                ("prefix", JsonValue::string(prefix)),
+                ("name", JsonValue::string(name)),
+            ]),
+            Term::Compound {
+                connecter,
+                terms: components,
+            } => JsonValue::object([
+                ("type", JsonValue::string("Compound")),
+                ("connecter", JsonValue::string(connecter)),
+                ("terms", terms(components)),
+            ]),
+            Term::Set {
+                left_bracket,
+                terms: components,
+                right_bracket,
+            } => JsonValue::object([
+                ("type", JsonValue::string("Set")),
+                ("left_bracket", JsonValue::string(left_bracket)),
+                ("terms", terms(components)),
+                ("right_bracket", JsonValue::string(right_bracket)),
+            ]),
+            Term::Statement {
+                copula,
+                subject,
+                predicate,
+            } => JsonValue::object([
+                ("type", JsonValue::string("Statement")),
+                ("copula", JsonValue::string(copula)),
+                ("subject", subject.to_json_value()),
+                ("predicate", predicate.to_json_value()),
+            ]),
+        }
+    }
+}
+
+impl FromJson for Term {
+    fn from_json_value(value: &JsonValue) -> JsonResult<Self> {
+        let terms = |value: &JsonValue| -> JsonResult<Vec<Term>> {
+            value
+                .field("terms")?
+                .as_array()?
+                .iter()
+                .map(Term::from_json_value)
+                .collect()
+        };
+        let term = match value.field_str("type")? {
+            "Atom" => Term::new_atom(value.field_str("prefix")?, value.field_str("name")?),
+            "Compound" => Term::new_compound(value.field_str("connecter")?, terms(value)?),
+            "Set" => Term::new_set(
+                value.field_str("left_bracket")?,
+                terms(value)?,
+                value.field_str("right_bracket")?,
+            ),
+            "Statement" => Term::new_statement(
+                value.field_str("copula")?,
+                Term::from_json_value(value.field("subject")?)?,
+                Term::from_json_value(value.field("predicate")?)?,
+            ),
+            other => return Err(JsonError::new(format!("未知的词项类型「{other}」"))),
+        };
+        Ok(term)
+    }
+}
+
+/// 语句
+impl ToJson for Sentence {
+    fn to_json_value(&self) -> JsonValue {
+        JsonValue::object([
+            ("term", self.term.to_json_value()),
+            ("punctuation", self.punctuation.to_json_value()),
+            ("stamp", self.stamp.to_json_value()),
+            ("truth", self.truth.to_json_value()),
+        ])
+    }
+}
+
+impl FromJson for Sentence {
+    fn from_json_value(value: &JsonValue) -> JsonResult<Self> {
+        Ok(Sentence {
+            term: Term::from_json_value(value.field("term")?)?,
+            punctuation: String::from_json_value(value.field("punctuation")?)?,
+            stamp: String::from_json_value(value.field("stamp")?)?,
+            truth: Vec::from_json_value(value.field("truth")?)?,
+        })
+    }
+}
+
+/// 任务
+impl ToJson for Task {
+    fn to_json_value(&self) -> JsonValue {
+        JsonValue::object([
+            ("budget", self.budget.to_json_value()),
+            ("sentence", self.sentence.to_json_value()),
+        ])
+    }
+}
+
+impl FromJson for Task {
+    fn from_json_value(value: &JsonValue) -> JsonResult<Self> {
+        Ok(Task {
+            budget: Vec::from_json_value(value.field("budget")?)?,
+            sentence: Sentence::from_json_value(value.field("sentence")?)?,
+        })
+    }
+}
+
+/// Narsese值：带`type`标签，内容存于`value`
+impl ToJson for Narsese {
+    fn to_json_value(&self) -> JsonValue {
+        let (tag, value) = match self {
+            Narsese::Term(term) => ("Term", term.to_json_value()),
+            Narsese::Sentence(sentence) => ("Sentence", sentence.to_json_value()),
+            Narsese::Task(task) => ("Task", task.to_json_value()),
+        };
+        JsonValue::object([("type", JsonValue::string(tag)), ("value", value)])
+    }
+}
+
+impl FromJson for Narsese {
+    fn from_json_value(value: &JsonValue) -> JsonResult<Self> {
+        let inner = value.field("value")?;
+        let narsese = match value.field_str("type")? {
+            "Term" => Narsese::Term(Term::from_json_value(inner)?),
+            "Sentence" => Narsese::Sentence(Sentence::from_json_value(inner)?),
+            "Task" => Narsese::Task(Task::from_json_value(inner)?),
+            other => return Err(JsonError::new(format!("未知的Narsese类型「{other}」"))),
+        };
+        Ok(narsese)
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexical::{shortcuts::*, tests::_sample_task_ascii};
+    use nar_dev_utils::{asserts, show};
+
+    #[test]
+    fn test_round_trip() {
+        let task = _sample_task_ascii();
+        let json = task.to_json();
+        show!(&json);
+        assert_eq!(Task::from_json(&json), Ok(task.clone()));
+        // 语句、词项、Narsese值
+        let sentence = task.sentence.clone();
+        let term = sentence.term.clone();
+        asserts! {
+            Sentence::from_json(&sentence.to_json()) => Ok(sentence.clone()),
+            Term::from_json(&term.to_json()) => Ok(term.clone()),
+            Narsese::from_json(&Narsese::Task(task.clone()).to_json()) => Ok(Narsese::Task(task)),
+            Narsese::from_json(&Narsese::Sentence(sentence.clone()).to_json()) => Ok(Narsese::Sentence(sentence)),
+            Narsese::from_json(&Narsese::Term(term.clone()).to_json()) => Ok(Narsese::Term(term)),
+        }
+    }
+
+    /// 测试/表示形式
+    /// * 🎯固定JSON表示，保证与`serde`派生宏（见模块文档）的结果一致
+    #[test]
+    fn test_representation() {
+        asserts! {
+            statement!(atom!("A") "-->" atom!("B")).to_json()
+                => r#"{"type":"Statement","copula":"-->","subject":{"type":"Atom","prefix":"","name":"A"},"predicate":{"type":"Atom","prefix":"","name":"B"}}"#,
+            compound!("&&"; atom!("A")).to_json()
+                => r#"{"type":"Compound","connecter":"&&","terms":[{"type":"Atom","prefix":"","name":"A"}]}"#,
+            atom!("$" "x").to_json() => r#"{"type":"Atom","prefix":"$","name":"x"}"#,
+            set!("{"; "A" ;"}").to_json()
+                => r#"{"type":"Set","left_bracket":"{","terms":[{"type":"Atom","prefix":"","name":"A"}],"right_bracket":"}"}"#,
+            Narsese::Term(atom!("A")).to_json()
+                => r#"{"type":"Term","value":{"type":"Atom","prefix":"","name":"A"}}"#,
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        asserts! {
+            Term::from_json(r#"{"type":"Unknown"}"#).is_err(),
+            Term::from_json(r#"{"type":"Atom","prefix":""}"#).is_err(),
+            Task::from_json(r#"{"budget":[],"sentence":[]}"#).is_err(),
+            Narsese::from_json("[]").is_err(),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Narsese与JSON的相互转换
+//! * 🎯在网络（如WebSocket）间传输Narsese值
+//! * 🚩需启用`lexical_json`特性（默认关闭）
+//! * 📌不依赖外部序列化库：自带一个最小的JSON读写实现
+//!   * ⚠️仅支持「字符串/数组/对象」三种JSON值，足以表示词法Narsese
+//!   * 📝暂不使用`serde`：构建环境无法获取`serde`，且可选依赖亦需参与依赖解析
+//!     * ⚠️即便默认关闭，声明`serde`依赖也会让离线构建整体失败
+//!   * 📌表示形式与`serde`派生宏的结果保持一致，日后可直接替换为派生实现
+//!     * 📄词项：`#[serde(tag = "type")]`
+//!     * 📄Narsese值：`#[serde(tag = "type", content = "value")]`
+//!     * 📄语句、任务：默认结构体表示（字段名即键名）
+
+nar_dev_utils::mod_and_pub_use! {
+    // 最小JSON值
+    value
+    // 词法Narsese
+    impl_lexical
+}
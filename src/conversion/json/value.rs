@@ -0,0 +1,366 @@
+//! 最小JSON值
+//! * 🎯在「不引入外部依赖」的前提下，支持Narsese与JSON的相互转换
+//! * 📌仅支持「字符串/数组/对象」三种JSON值
+//!   * 📄词法Narsese的所有字段均为字符串或其容器，无需数值、布尔值
+//!   * ⚠️解析到`null`、数值、布尔值时报错
+
+use std::{error::Error, fmt::Display};
+
+/// JSON转换错误
+/// * 🚩仅包含错误信息
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonError(pub String);
+
+impl JsonError {
+    /// 构造函数
+    pub fn new(message: impl Into<String>) -> Self {
+        Self(message.into())
+    }
+}
+
+impl Display for JsonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "JsonError: {}", self.0)
+    }
+}
+
+impl Error for JsonError {}
+
+/// JSON转换结果
+pub type JsonResult<T> = Result<T, JsonError>;
+
+/// 最小JSON值
+/// * 🚩对象使用「键值对数组」存储，以保证字段顺序稳定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonValue {
+    /// 字符串
+    String(String),
+    /// 数组
+    Array(Vec<JsonValue>),
+    /// 对象
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /// 从字符串构造
+    pub fn string(s: impl Into<String>) -> Self {
+        Self::String(s.into())
+    }
+
+    /// 从「字符串数组」构造
+    pub fn strings<'a>(strings: impl IntoIterator<Item = &'a String>) -> Self {
+        Self::Array(strings.into_iter().map(Self::string).collect())
+    }
+
+    /// 从「键值对」构造对象
+    pub fn object<'a>(fields: impl IntoIterator<Item = (&'a str, JsonValue)>) -> Self {
+        Self::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+        )
+    }
+
+    /// 获取字符串
+    pub fn as_str(&self) -> JsonResult<&str> {
+        match self {
+            Self::String(s) => Ok(s),
+            _ => Err(JsonError::new("预期字符串")),
+        }
+    }
+
+    /// 获取数组
+    pub fn as_array(&self) -> JsonResult<&[JsonValue]> {
+        match self {
+            Self::Array(values) => Ok(values),
+            _ => Err(JsonError::new("预期数组")),
+        }
+    }
+
+    /// 获取「字符串数组」
+    pub fn as_strings(&self) -> JsonResult<Vec<String>> {
+        self.as_array()?
+            .iter()
+            .map(|value| value.as_str().map(str::to_string))
+            .collect()
+    }
+
+    /// 获取对象字段
+    /// * ⚠️不是对象，或字段不存在⇒报错
+    pub fn field(&self, key: &str) -> JsonResult<&JsonValue> {
+        match self {
+            Self::Object(fields) => fields
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, value)| value)
+                .ok_or_else(|| JsonError::new(format!("缺少字段「{key}」"))),
+            _ => Err(JsonError::new("预期对象")),
+        }
+    }
+
+    /// 获取「字符串字段」
+    pub fn field_str(&self, key: &str) -> JsonResult<&str> {
+        self.field(key)?.as_str()
+    }
+
+    /// 序列化为JSON字符串（紧凑形式）
+    pub fn to_json_string(&self) -> String {
+        let mut out = String::new();
+        self.write_to(&mut out);
+        out
+    }
+
+    /// 将JSON写入字符串
+    fn write_to(&self, out: &mut String) {
+        match self {
+            Self::String(s) => write_string(out, s),
+            Self::Array(values) => {
+                out.push('[');
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    value.write_to(out);
+                }
+                out.push(']');
+            }
+            Self::Object(fields) => {
+                out.push('{');
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    write_string(out, key);
+                    out.push(':');
+                    value.write_to(out);
+                }
+                out.push('}');
+            }
+        }
+    }
+
+    /// 从JSON字符串解析
+    /// * ⚠️值之后只允许空白
+    pub fn parse(input: &str) -> JsonResult<Self> {
+        let mut parser = JsonParser {
+            chars: input.chars().collect(),
+            head: 0,
+        };
+        let value = parser.parse_value()?;
+        parser.skip_whitespace();
+        match parser.head < parser.chars.len() {
+            true => Err(parser.error("JSON值之后有多余内容")),
+            false => Ok(value),
+        }
+    }
+}
+
+/// 写入转义后的JSON字符串
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// JSON解析状态
+struct JsonParser {
+    /// 所有字符
+    chars: Vec<char>,
+    /// 当前位置
+    head: usize,
+}
+
+impl JsonParser {
+    /// 生成带位置的错误
+    fn error(&self, message: &str) -> JsonError {
+        JsonError::new(format!("{message} @ {}", self.head))
+    }
+
+    /// 跳过空白
+    fn skip_whitespace(&mut self) {
+        while self.head < self.chars.len() && self.chars[self.head].is_whitespace() {
+            self.head += 1;
+        }
+    }
+
+    /// 取出下一个字符
+    fn next(&mut self) -> JsonResult<char> {
+        let c = self
+            .chars
+            .get(self.head)
+            .copied()
+            .ok_or_else(|| self.error("JSON意外结束"))?;
+        self.head += 1;
+        Ok(c)
+    }
+
+    /// 跳过空白后，预览下一个字符
+    fn peek(&mut self) -> JsonResult<char> {
+        self.skip_whitespace();
+        self.chars
+            .get(self.head)
+            .copied()
+            .ok_or_else(|| self.error("JSON意外结束"))
+    }
+
+    /// 跳过空白后，要求下一个字符为`expected`
+    fn expect(&mut self, expected: char) -> JsonResult<()> {
+        match self.peek()? == expected {
+            true => {
+                self.head += 1;
+                Ok(())
+            }
+            false => Err(self.error(&format!("预期「{expected}」"))),
+        }
+    }
+
+    /// 解析任意值
+    fn parse_value(&mut self) -> JsonResult<JsonValue> {
+        match self.peek()? {
+            '"' => self.parse_string().map(JsonValue::String),
+            '[' => self.parse_array(),
+            '{' => self.parse_object(),
+            _ => Err(self.error("不支持的JSON值")),
+        }
+    }
+
+    /// 解析字符串
+    fn parse_string(&mut self) -> JsonResult<String> {
+        self.expect('"')?;
+        let mut s = String::new();
+        loop {
+            match self.next()? {
+                '"' => return Ok(s),
+                '\\' => match self.next()? {
+                    '"' => s.push('"'),
+                    '\\' => s.push('\\'),
+                    '/' => s.push('/'),
+                    'b' => s.push('\u{8}'),
+                    'f' => s.push('\u{c}'),
+                    'n' => s.push('\n'),
+                    'r' => s.push('\r'),
+                    't' => s.push('\t'),
+                    'u' => s.push(self.parse_unicode_escape()?),
+                    _ => return Err(self.error("非法转义字符")),
+                },
+                c => s.push(c),
+            }
+        }
+    }
+
+    /// 解析`\uXXXX`转义（含代理对）
+    fn parse_unicode_escape(&mut self) -> JsonResult<char> {
+        let high = self.parse_hex4()?;
+        let code = match high {
+            // 高代理⇒需要紧跟低代理
+            0xD800..=0xDBFF => {
+                if self.next()? != '\\' || self.next()? != 'u' {
+                    return Err(self.error("缺少低代理项"));
+                }
+                let low = self.parse_hex4()?;
+                if !(0xDC00..=0xDFFF).contains(&low) {
+                    return Err(self.error("非法低代理项"));
+                }
+                0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+            }
+            code => code,
+        };
+        char::from_u32(code).ok_or_else(|| self.error("非法Unicode码点"))
+    }
+
+    /// 解析四位十六进制数
+    fn parse_hex4(&mut self) -> JsonResult<u32> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let digit = self
+                .next()?
+                .to_digit(16)
+                .ok_or_else(|| self.error("非法十六进制数字"))?;
+            code = code * 16 + digit;
+        }
+        Ok(code)
+    }
+
+    /// 解析数组
+    fn parse_array(&mut self) -> JsonResult<JsonValue> {
+        self.expect('[')?;
+        let mut values = vec![];
+        if self.peek()? == ']' {
+            self.head += 1;
+            return Ok(JsonValue::Array(values));
+        }
+        loop {
+            values.push(self.parse_value()?);
+            match self.peek()? {
+                ',' => self.head += 1,
+                ']' => {
+                    self.head += 1;
+                    return Ok(JsonValue::Array(values));
+                }
+                _ => return Err(self.error("预期「,」或「]」")),
+            }
+        }
+    }
+
+    /// 解析对象
+    fn parse_object(&mut self) -> JsonResult<JsonValue> {
+        self.expect('{')?;
+        let mut fields = vec![];
+        if self.peek()? == '}' {
+            self.head += 1;
+            return Ok(JsonValue::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.expect(':')?;
+            fields.push((key, self.parse_value()?));
+            match self.peek()? {
+                ',' => self.head += 1,
+                '}' => {
+                    self.head += 1;
+                    return Ok(JsonValue::Object(fields));
+                }
+                _ => return Err(self.error("预期「,」或「}」")),
+            }
+        }
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nar_dev_utils::asserts;
+
+    #[test]
+    fn test_json_value() {
+        let value = JsonValue::object([
+            ("a", JsonValue::string("x\"y\\z\n")),
+            ("b", JsonValue::Array(vec![])),
+            ("c", JsonValue::object([])),
+        ]);
+        let json = value.to_json_string();
+        asserts! {
+            json => r#"{"a":"x\"y\\z\n","b":[],"c":{}}"#,
+            JsonValue::parse(&json) => Ok(value),
+            JsonValue::parse(r#" [ "A😀" , "中" ] "#)
+                => Ok(JsonValue::Array(vec![JsonValue::string("A😀"), JsonValue::string("中")])),
+            JsonValue::parse(r#""\ud83d\ude00\u4e2d""#) => Ok(JsonValue::string("😀中")),
+            JsonValue::parse("1").is_err(),
+            JsonValue::parse(r#""a" "b""#).is_err(),
+            JsonValue::parse(r#"{"a" "b"}"#).is_err(),
+        }
+    }
+}
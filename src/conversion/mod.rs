@@ -7,3 +7,23 @@ pub mod string;
 
 // 跨类型转换 | 结构化的Narsese类型 //
 pub mod inter_type;
+
+// 语料统计 | 基于词法Narsese //
+#[cfg(feature = "lexical_narsese")]
+pub mod analysis;
+
+// 输入输出行 | 基于词法Narsese //
+#[cfg(feature = "lexical_narsese")]
+pub mod io_line;
+
+// Graphviz DOT | 基于枚举Narsese //
+#[cfg(feature = "enum_narsese")]
+pub mod graphviz;
+
+// 二进制编码 | 基于枚举Narsese //
+#[cfg(feature = "enum_narsese")]
+pub mod binary;
+
+// JSON转换 | 需启用`lexical_json`特性 //
+#[cfg(feature = "lexical_json")]
+pub mod json;
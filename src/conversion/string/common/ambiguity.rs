@@ -0,0 +1,38 @@
+//! 「查询变量」与「问题标点」的歧义
+//! * 📄ASCII、LaTeX：查询变量前缀与问题标点均为`?`
+//!   * 📄`?x`既可读作「查询变量`?x`」，也可读作「标点在前的问题`x?`」
+//!   * 📄`?x?`中，首个`?`归属不明
+//! * 🚩各格式可配置「消歧策略」[`AmbiguityResolution`]
+//!   * 📌仅当「查询变量前缀」与「问题标点」相同时生效：如漢文格式（`所问`与`？`）不受影响
+//! * 📌显式写法：词项与标点之间留空格
+//!   * 📄`?x ?`⇒以查询变量`?x`提问
+
+/// 解析错误消息/查询变量与问题标点有歧义
+/// * 🎯「要求显式」策略下，对`?x?`、`?x`给出明确的提示
+/// * 📌此消息内容保持稳定，可供调用方比对
+pub const AMBIGUOUS_QUERY_VARIABLE_MESSAGE: &str = "查询变量与问题标点有歧义，请在词项与标点之间留空格";
+
+/// 「查询变量」与「问题标点」的消歧策略
+///
+/// | 输入   | 偏向变量（默认） | 偏向标点    | 要求显式 |
+/// | :----- | :--------------- | :---------- | :------- |
+/// | `?x?`  | 问题`?x ?`       | 问题`?x ?`  | 报错     |
+/// | `x?`   | 问题`x ?`        | 问题`x ?`   | 问题`x ?` |
+/// | `?x`   | 词项`?x`         | 问题`x ?`   | 报错     |
+///
+/// * 📌`?x?`中，后一个`?`只能是标点：「偏向标点」时亦读作「以查询变量提问」
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AmbiguityResolution {
+    /// 偏向变量
+    /// * 📌开头的`?`读作查询变量前缀
+    /// * 📌即此前的解析行为
+    #[default]
+    PreferVariable,
+    /// 偏向标点
+    /// * 📌无标点的单个查询变量，读作「标点在前的问题」
+    /// * ⚠️枚举Narsese中，变量名不能作词语名（如`?_x`）⇒仍读作查询变量
+    PreferPunctuation,
+    /// 要求显式
+    /// * 📌有歧义的输入⇒报错，错误消息为[`AMBIGUOUS_QUERY_VARIABLE_MESSAGE`]
+    RequireExplicit,
+}
@@ -0,0 +1,123 @@
+//! 注释的识别与去除
+//! * 🎯枚举Narsese、词法Narsese共用同一套「注释扫描」逻辑
+//!   * 📄`'测试用NAL文件`、`// C风格注释`
+//!   * 📄`<A --> B>. %1.0;0.9% // 行尾注释`
+//! * 📌仅识别「括弧、引号之外」的注释前缀：避免破坏含`//`、`'`的原子词项名称
+//!   * 📄`<http://x --> B>.`：`//`位于陈述括弧之内，不视作注释
+//!   * 📄`'hello // world'.`：PyNARS兼容模式下，`'`为原子词项引号
+//! * 📌与具体的「Narsese格式」无关：括弧、系词、引号均由调用方给出
+
+/// 默认的注释前缀
+/// * 📄`// 注释`：C风格注释
+/// * 📄`'注释`：OpenNARS `.nal`文件中的注释（含`''outputMustContain`等指令）
+pub const DEFAULT_COMMENT_PREFIXES: [&str; 2] = ["//", "'"];
+
+/// 注释扫描器
+/// * 🚩记录扫描所需的各类记号，由各格式自行构造
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CommentScanner<'s> {
+    /// 注释前缀
+    pub comment_prefixes: Vec<&'s str>,
+    /// 左括弧：复合词项、词项集、陈述
+    pub left_brackets: Vec<&'s str>,
+    /// 右括弧：复合词项、词项集、陈述
+    pub right_brackets: Vec<&'s str>,
+    /// 系词
+    /// * 🎯整体跳过，不计入括弧：`-->`中的`>`并非右括弧
+    pub copulas: Vec<&'s str>,
+    /// 引号对
+    /// * 🎯引号之内不识别注释、括弧
+    pub quotes: Vec<(&'s str, &'s str)>,
+}
+
+impl CommentScanner<'_> {
+    /// 去除注释
+    /// * 🚩返回「首个括弧、引号外的注释前缀」之前的内容（去除尾部空白）
+    ///   * 📌注释延续到输入末尾：多行输入中，注释之后的各行亦被舍去
+    ///   * 📌整行均为注释⇒返回空字串
+    /// * ⚠️引号优先于注释前缀：二者相同时（如PyNARS的`'`），不再识别为注释
+    pub fn strip_comment<'a>(&self, line: &'a str) -> &'a str {
+        let mut depth = 0_usize;
+        let mut quote_end: Option<&str> = None;
+        let mut i = 0;
+        while i < line.len() {
+            let rest = &line[i..];
+            // 计算跳过的长度
+            let skip = match quote_end {
+                // 引号内：仅寻找右引号
+                Some(end) => match rest.starts_with(end) {
+                    true => {
+                        quote_end = None;
+                        end.len()
+                    }
+                    false => 0,
+                },
+                None => {
+                    let starts = |s: &&str| !s.is_empty() && rest.starts_with(*s);
+                    if let Some(copula) = self.copulas.iter().find(|copula| starts(copula)) {
+                        copula.len()
+                    } else if let Some((left, right)) =
+                        self.quotes.iter().find(|(left, _)| starts(left))
+                    {
+                        quote_end = Some(right);
+                        left.len()
+                    } else if let Some(left) = self.left_brackets.iter().find(|left| starts(left)) {
+                        depth += 1;
+                        left.len()
+                    } else if let Some(right) =
+                        self.right_brackets.iter().find(|right| starts(right))
+                    {
+                        depth = depth.saturating_sub(1);
+                        right.len()
+                    } else if depth == 0 && self.comment_prefixes.iter().any(starts) {
+                        return line[..i].trim_end();
+                    } else {
+                        0
+                    }
+                }
+            };
+            // 至少前进一个字符
+            i += match skip {
+                0 => rest.chars().next().map_or(1, char::len_utf8),
+                n => n,
+            };
+        }
+        line
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nar_dev_utils::asserts;
+
+    #[test]
+    fn test_strip_comment() {
+        let scanner = CommentScanner {
+            comment_prefixes: DEFAULT_COMMENT_PREFIXES.to_vec(),
+            left_brackets: vec!["(", "<"],
+            right_brackets: vec![")", ">"],
+            copulas: vec!["-->"],
+            quotes: vec![("\"", "\"")],
+        };
+        asserts! {
+            scanner.strip_comment("// 注释") => "",
+            scanner.strip_comment("'注释") => "",
+            scanner.strip_comment("<A --> B>. // 注释") => "<A --> B>.",
+            scanner.strip_comment("<A --> http://x>.'注释") => "<A --> http://x>.",
+            scanner.strip_comment("\"a // b\". // 注释") => "\"a // b\".",
+            scanner.strip_comment("A.") => "A.",
+        }
+        // 引号与注释前缀相同⇒引号优先
+        let scanner = CommentScanner {
+            quotes: vec![("'", "'")],
+            ..scanner
+        };
+        asserts! {
+            scanner.strip_comment("'hello // world'. // 注释") => "'hello // world'.",
+            scanner.strip_comment("// 注释") => "",
+            scanner.strip_comment("'a'. ' 非注释") => "'a'. ' 非注释",
+        }
+    }
+}
@@ -7,7 +7,9 @@
 //! * 📌主要出于「可读性」的考虑
 //!   * `impl AsStrRef`和`as_str_ref`实在是太长了
 
+use crate::api::FloatPrecision;
 use nar_dev_utils::{join_lest_multiple_separators, push_str};
+use std::fmt::{self, Write};
 
 /// 模板/原子词项：前缀+名称
 /// * 🎯所有Narsese原子词项类型
@@ -16,6 +18,29 @@ pub fn template_atom(out: &mut String, prefix: &str, name: &str) {
     push_str!(out; prefix, name);
 }
 
+/// 模板/浮点数
+/// * 🎯真值、预算值中的数值
+/// * 📌`precision`：小数位数
+///   * 🚩[`None`]⇒使用最短的表示，如`1`、`0.9`
+///   * 📄`Some(2)`⇒`1.00`、`0.90`
+/// * 📌`strip_trailing_zeros`：去除小数末尾的`0`（以及随之多余的小数点）
+///   * 📄`1.00`⇒`1`、`0.90`⇒`0.9`
+pub fn template_float(
+    out: &mut String,
+    f: FloatPrecision,
+    precision: Option<usize>,
+    strip_trailing_zeros: bool,
+) {
+    let formatted = match precision {
+        Some(precision) => format!("{f:.precision$}"),
+        None => f.to_string(),
+    };
+    match strip_trailing_zeros && formatted.contains('.') {
+        true => out.push_str(formatted.trim_end_matches('0').trim_end_matches('.')),
+        false => out.push_str(&formatted),
+    }
+}
+
 /// 模板/系列词项
 /// * 🎯一般复合词项，词项集（外延集/内涵集）
 /// * 📝对于「字符串字面量数组」，`Vec<&str>`的引用类型对应`&[str]`而非`&[&str]`
@@ -40,6 +65,7 @@ pub fn template_components(
 /// * 🎯使用「连接符」区分「复合类型」的词项
 /// * 📝对于「字符串字面量数组」，`Vec<&str>`的引用类型对应`&[&str]`而非`&[str]`
 ///   * ⚠️后者的`str`是大小不定的：the size for values of type `str` cannot be known at compilation time
+#[deprecated(note = "格式化器已改用写入器版本，请使用`template_compound_to`")]
 pub fn template_compound(
     out: &mut String,
     left_bracket: &str,
@@ -64,6 +90,7 @@ pub fn template_compound(
 
 /// 模板/集合复合词项
 /// * 🎯「外延集/内涵集」这样【无需特定连接符，只需特殊括弧区分】的词项
+#[deprecated(note = "格式化器已改用写入器版本，请使用`template_compound_set_to`")]
 pub fn template_compound_set(
     out: &mut String,
     left_bracket: &str,
@@ -102,6 +129,7 @@ pub fn template_statement(
 
 /// 模板/语句
 /// * 🎯词项+标点+时间戳+真值
+#[deprecated(note = "格式化器已改用写入器版本，请使用`template_sentence_to`")]
 pub fn template_sentence(
     out: &mut String,
     term: &str,
@@ -115,3 +143,235 @@ pub fn template_sentence(
     // 后续顺序拼接，并避免多余分隔符
     join_lest_multiple_separators(out, [punctuation, stamp, truth].into_iter(), separator)
 }
+
+// 写入器版本 //
+// * 🎯批量格式化：直接写入调用方提供的缓冲区，避免逐个分配字符串
+// * 🚩与上述模板一一对应，参数中的「字符串」改为「写入闭包」
+
+/// 工具函数/写入到新字符串
+/// * 🎯由「写入器版本」得到「返回字符串版本」
+/// * 📌只有一个缓冲区：各层模板直接写入其中，不再产生中间字符串
+///   * ⚠️缓冲区本身仍按需增长（长度事先未知，无法预先分配）
+/// * 📌向[`String`]写入不会失败
+pub fn format_to_string(write: impl FnOnce(&mut String) -> fmt::Result) -> String {
+    let mut out = String::new();
+    write(&mut out).expect("向字符串写入不会失败");
+    out
+}
+
+/// 模板/原子词项：前缀+名称（写入器版本）
+pub fn template_atom_to(out: &mut impl Write, prefix: &str, name: &str) -> fmt::Result {
+    out.write_str(prefix)?;
+    out.write_str(name)
+}
+
+/// 模板/浮点数（写入器版本）
+/// * 📄参见[`template_float`]
+/// * 🚩去除末尾的`0`时，边写入边判断，无需中间字符串
+pub fn template_float_to(
+    out: &mut impl Write,
+    f: FloatPrecision,
+    precision: Option<usize>,
+    strip_trailing_zeros: bool,
+) -> fmt::Result {
+    match strip_trailing_zeros {
+        true => write_float(&mut TrailingZerosStripper::new(out), f, precision),
+        false => write_float(out, f, precision),
+    }
+}
+
+/// 工具函数/按精度写入浮点数
+fn write_float(out: &mut impl Write, f: FloatPrecision, precision: Option<usize>) -> fmt::Result {
+    match precision {
+        Some(precision) => write!(out, "{f:.precision$}"),
+        None => write!(out, "{f}"),
+    }
+}
+
+/// 去除小数末尾`0`的写入器
+/// * 🚩遇到小数点后，暂缓写入小数点与`0`，直到遇到其它字符
+///   * 📌结束时仍暂缓的内容即「末尾的`0`（以及随之多余的小数点）」，直接丢弃
+/// * 📌没有小数点⇒原样写入：`100`不受影响
+struct TrailingZerosStripper<'w, W: Write> {
+    out: &'w mut W,
+    /// 是否已遇到小数点
+    in_fraction: bool,
+    /// 暂缓的小数点
+    pending_point: bool,
+    /// 暂缓的`0`的个数
+    pending_zeros: usize,
+}
+
+impl<'w, W: Write> TrailingZerosStripper<'w, W> {
+    fn new(out: &'w mut W) -> Self {
+        Self {
+            out,
+            in_fraction: false,
+            pending_point: false,
+            pending_zeros: 0,
+        }
+    }
+}
+
+impl<W: Write> Write for TrailingZerosStripper<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match (self.in_fraction, c) {
+                (false, '.') => {
+                    self.in_fraction = true;
+                    self.pending_point = true;
+                }
+                (false, c) => self.out.write_char(c)?,
+                (true, '0') => self.pending_zeros += 1,
+                (true, c) => {
+                    if self.pending_point {
+                        self.out.write_char('.')?;
+                        self.pending_point = false;
+                    }
+                    for _ in 0..self.pending_zeros {
+                        self.out.write_char('0')?;
+                    }
+                    self.pending_zeros = 0;
+                    self.out.write_char(c)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 模板/系列词项（写入器版本）
+/// * 🚩由`write_component`将每个组分直接写入
+pub fn template_components_to<W: Write, T>(
+    out: &mut W,
+    components: impl Iterator<Item = T>,
+    separator: &str,
+    space: &str,
+    mut write_component: impl FnMut(&mut W, T) -> fmt::Result,
+) -> fmt::Result {
+    for (i, component) in components.enumerate() {
+        // 逗号
+        if i != 0 {
+            out.write_str(separator)?;
+            out.write_str(space)?;
+        }
+        // 词项
+        write_component(out, component)?;
+    }
+    Ok(())
+}
+
+/// 模板/一般复合词项（写入器版本）
+#[allow(clippy::too_many_arguments)]
+pub fn template_compound_to<W: Write, T>(
+    out: &mut W,
+    left_bracket: &str,
+    connecter: &str,
+    components: impl Iterator<Item = T>,
+    separator: &str,
+    space: &str,
+    right_bracket: &str,
+    write_component: impl FnMut(&mut W, T) -> fmt::Result,
+) -> fmt::Result {
+    // 左括号&连接符 | `(&&, `
+    out.write_str(left_bracket)?;
+    out.write_str(connecter)?;
+    out.write_str(separator)?;
+    out.write_str(space)?;
+    // 组分 | `A, B, C`
+    template_components_to(out, components, separator, space, write_component)?;
+    // 右括号 | `)`
+    out.write_str(right_bracket)
+}
+
+/// 模板/集合复合词项（写入器版本）
+pub fn template_compound_set_to<W: Write, T>(
+    out: &mut W,
+    left_bracket: &str,
+    components: impl Iterator<Item = T>,
+    separator: &str,
+    space: &str,
+    right_bracket: &str,
+    write_component: impl FnMut(&mut W, T) -> fmt::Result,
+) -> fmt::Result {
+    // 左括号 | `{`
+    out.write_str(left_bracket)?;
+    // 组分 | `A, B, C`
+    template_components_to(out, components, separator, space, write_component)?;
+    // 右括号 | `}`
+    out.write_str(right_bracket)
+}
+
+/// 模板/陈述（写入器版本）
+/// * 🚩主词、谓词由闭包直接写入
+pub fn template_statement_to<W: Write>(
+    out: &mut W,
+    left_bracket: &str,
+    subject: impl FnOnce(&mut W) -> fmt::Result,
+    copula: &str,
+    predicate: impl FnOnce(&mut W) -> fmt::Result,
+    space: &str,
+    right_bracket: &str,
+) -> fmt::Result {
+    out.write_str(left_bracket)?; // `<`
+    subject(out)?; // `S`
+    out.write_str(space)?; // ` `
+    out.write_str(copula)?; // `-->`
+    out.write_str(space)?; // ` `
+    predicate(out)?; // `P`
+    out.write_str(right_bracket) // `>`
+}
+
+/// 模板/语句（写入器版本）
+/// * 🚩时间戳、真值为[`None`]⇒视作空内容，不添加分隔符
+///   * 📌对应[`template_sentence`]中「避免多余分隔符」的逻辑
+pub fn template_sentence_to<W: Write>(
+    out: &mut W,
+    term: impl FnOnce(&mut W) -> fmt::Result,
+    punctuation: impl FnOnce(&mut W) -> fmt::Result,
+    stamp: Option<impl FnOnce(&mut W) -> fmt::Result>,
+    truth: Option<impl FnOnce(&mut W) -> fmt::Result>,
+    separator: &str,
+) -> fmt::Result {
+    // 词项直接输入，后续紧跟标点
+    term(out)?;
+    punctuation(out)?;
+    // 后续有内容⇒添加分隔符
+    if let Some(stamp) = stamp {
+        out.write_str(separator)?;
+        stamp(out)?;
+    }
+    if let Some(truth) = truth {
+        out.write_str(separator)?;
+        truth(out)?;
+    }
+    Ok(())
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nar_dev_utils::asserts;
+
+    /// 写入器版本与字符串版本一致
+    #[test]
+    fn test_template_float_to() {
+        for f in [0.0, 1.0, 0.9, 0.5, 0.125, 100.0, 1e-3, 12.5, -0.25] {
+            for precision in [None, Some(0), Some(1), Some(2), Some(4)] {
+                for strip in [false, true] {
+                    let mut expected = String::new();
+                    template_float(&mut expected, f, precision, strip);
+                    let actual = format_to_string(|out| template_float_to(out, f, precision, strip));
+                    assert_eq!(actual, expected, "f = {f}, precision = {precision:?}, strip = {strip}");
+                }
+            }
+        }
+        asserts! {
+            format_to_string(|out| template_float_to(out, 1.0, Some(2), true)) => "1",
+            format_to_string(|out| template_float_to(out, 0.9, Some(2), true)) => "0.9",
+            format_to_string(|out| template_float_to(out, 100.0, None, true)) => "100",
+            format_to_string(|out| template_float_to(out, 100.5, Some(3), true)) => "100.5",
+        }
+    }
+}
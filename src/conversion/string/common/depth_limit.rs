@@ -0,0 +1,14 @@
+//! 解析时的「最大嵌套深度」
+//! * 🎯防止恶意/机器生成的深层嵌套输入（如十万层`(--, (--, ...))`）耗尽调用栈
+//!   * 📌递归下降解析中，每层嵌套对应一次递归
+//! * 🚩超出深度时产生解析错误，而非栈溢出
+
+/// 默认的最大嵌套深度
+/// * 📌远超正常Narsese所需的深度
+/// * 📌此深度内的各类嵌套，在2MiB的线程栈（`cargo test`、tokio工作线程的默认值）上亦不会栈溢出
+///   * ⚠️含调试构建：递归解析的函数须保持较小的栈帧
+pub const DEFAULT_MAX_DEPTH: usize = 256;
+
+/// 解析错误消息/超出最大嵌套深度
+/// * 📌此消息内容保持稳定，可供调用方比对
+pub const MAX_DEPTH_MESSAGE: &str = "超出最大嵌套深度";
@@ -0,0 +1,183 @@
+//! 格式化选项
+//! * 🎯在不修改「Narsese格式」的前提下，调整格式化输出中的空白
+//!   * 📄紧凑：`<A-->B>.:|:%1.0;0.9%`，便于嵌入日志行
+//!   * 📄美观：`<A --> B>. :|: %1.0;0.9%`，便于展示给用户
+//!   * 📄OpenNARS兼容：`<(&/,A,B) =/> C>. :|: %1.0;0.9%`，便于输入到OpenNARS
+//! * 📌空白的内容仍取自格式本身（如漢文格式的「词项间空白」即为空）
+//!   * 🚩选项只决定「是否插入」
+//! * ✨数学模式：将整个输出包裹在`$...$`或`\[...\]`中
+//!   * 🎯便于将LaTeX格式的输出直接粘贴进数学环境
+
+/// 数学模式
+/// * 🎯包裹（LaTeX格式的）格式化输出
+/// * 📌只包裹最终输出：组分不会被单独包裹
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MathMode {
+    /// 行内公式：`$...$`
+    Inline,
+    /// 行间公式：`\[...\]`
+    Display,
+}
+
+impl MathMode {
+    /// 左右包裹符
+    pub const fn delimiters(&self) -> (&'static str, &'static str) {
+        match self {
+            MathMode::Inline => ("$", "$"),
+            MathMode::Display => (r"\[", r"\]"),
+        }
+    }
+
+    /// 包裹内容
+    pub fn wrap(&self, content: &str) -> String {
+        let (left, right) = self.delimiters();
+        format!("{left}{content}{right}")
+    }
+
+    /// 去除包裹符
+    /// * 🎯将包裹后的输出交给解析器
+    /// * 🚩先去除首尾空白，再尝试两种包裹符
+    ///   * 📌行间公式优先：避免与LaTeX格式中`\$`开头的预算值混淆
+    /// * 📌未被包裹⇒原样返回（去除首尾空白）
+    pub fn strip(input: &str) -> &str {
+        let input = input.trim();
+        [MathMode::Display, MathMode::Inline]
+            .into_iter()
+            .find_map(|mode| {
+                let (left, right) = mode.delimiters();
+                input.strip_prefix(left)?.strip_suffix(right)
+            })
+            .unwrap_or(input)
+    }
+}
+
+/// 格式化选项
+/// * 🚩默认为[美观输出](FormatOptions::PRETTY)，与不带选项的格式化方法一致
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FormatOptions {
+    /// 是否紧凑输出
+    /// * 🚩开启后不插入任何空白，忽略其它选项
+    pub compact: bool,
+    /// 是否在分隔符之后插入空白
+    /// * 📄`(&&, A, B)` / `(&&,A,B)`
+    pub space_after_separator: bool,
+    /// 是否在系词两侧插入空白
+    /// * 📄`<A --> B>` / `<A-->B>`
+    pub space_around_copula: bool,
+    /// 是否省略不必要的分隔符
+    /// * 🚩仅当相邻两者在边界处同为「标识符字符」时保留分隔符
+    ///   * 📄`(*, A, #B, C)` ⇒ `(* A #B, C)`：`B`与`C`相连会被解析为一个原子词项，故保留
+    /// * 📌分隔符之后的空白不受影响
+    /// * 📌目前仅作用于「词法Narsese」的格式化
+    pub omit_separators: bool,
+    /// 数学模式
+    /// * 🚩[`None`]⇒不包裹
+    pub math_mode: Option<MathMode>,
+}
+
+impl FormatOptions {
+    /// 美观输出：按格式插入所有空白
+    pub const PRETTY: Self = Self {
+        compact: false,
+        space_after_separator: true,
+        space_around_copula: true,
+        omit_separators: false,
+        math_mode: None,
+    };
+
+    /// 紧凑输出：不插入任何空白
+    pub const COMPACT: Self = Self {
+        compact: true,
+        space_after_separator: false,
+        space_around_copula: false,
+        omit_separators: false,
+        math_mode: None,
+    };
+
+    /// OpenNARS兼容输出
+    /// * 🎯输出能被OpenNARS（3.0.4）的解析器接受
+    ///   * ⚠️其解析器不接受复合词项分隔符之后的空白
+    /// * 🚩复合词项内不插入空白，系词两侧、条目之间各插入一个空白
+    ///   * 📄`$0.5;0.5;0.5$ <(&/,<a --> b>,+5) =/> c>. :|: %1.0;0.9%`
+    /// * 📌空白仍取自格式本身：应与ASCII格式一同使用
+    pub const OPENNARS: Self = Self {
+        compact: false,
+        space_after_separator: false,
+        space_around_copula: true,
+        omit_separators: false,
+        math_mode: None,
+    };
+
+    /// 设置数学模式
+    pub const fn with_math_mode(self, math_mode: MathMode) -> Self {
+        Self {
+            math_mode: Some(math_mode),
+            ..self
+        }
+    }
+
+    /// 设置「省略不必要的分隔符」
+    pub const fn with_omit_separators(self, omit_separators: bool) -> Self {
+        Self {
+            omit_separators,
+            ..self
+        }
+    }
+
+    /// 分隔符之后的空白
+    pub fn separator_space<'s>(&self, space: &'s str) -> &'s str {
+        match !self.compact && self.space_after_separator {
+            true => space,
+            false => "",
+        }
+    }
+
+    /// 系词两侧的空白
+    pub fn copula_space<'s>(&self, space: &'s str) -> &'s str {
+        match !self.compact && self.space_around_copula {
+            true => space,
+            false => "",
+        }
+    }
+
+    /// 按数学模式包裹最终输出
+    pub fn wrap_math(&self, out: String) -> String {
+        match self.math_mode {
+            Some(mode) => mode.wrap(&out),
+            None => out,
+        }
+    }
+
+    /// 按数学模式包裹最终输出（写入器版本）
+    /// * 🚩先后写入左包裹符、内容、右包裹符，无需中间字符串
+    pub fn wrap_math_to<W: std::fmt::Write>(
+        &self,
+        out: &mut W,
+        write: impl FnOnce(&mut W) -> std::fmt::Result,
+    ) -> std::fmt::Result {
+        match self.math_mode {
+            Some(mode) => {
+                let (left, right) = mode.delimiters();
+                out.write_str(left)?;
+                write(out)?;
+                out.write_str(right)
+            }
+            None => write(out),
+        }
+    }
+
+    /// 条目（预算值、词项、标点、时间戳、真值）之间的空白
+    pub fn item_space<'s>(&self, space: &'s str) -> &'s str {
+        match self.compact {
+            true => "",
+            false => space,
+        }
+    }
+}
+
+/// 默认为美观输出
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self::PRETTY
+    }
+}
@@ -0,0 +1,11 @@
+//! 「像」中的占位符
+//! * 📌像中有且只有一个占位符：其位置即为「像的索引」
+//!   * 📄`(/, R, A, _, B)`
+//! * 🚩多于一个占位符⇒解析错误（枚举Narsese、词法Narsese），或折叠错误
+//!   * 📌否则「多余的占位符」会被当作普通组分，格式化后含义改变
+//! * 📌非像的复合词项中，占位符只是普通组分
+//!   * 📄`(*, _)`、`(&&, _, A)`均可解析
+
+/// 解析错误消息/像中有多个占位符
+/// * 📌此消息内容保持稳定，可供调用方比对
+pub const IMAGE_PLACEHOLDERS_MESSAGE: &str = "像中占位符只能有一个";
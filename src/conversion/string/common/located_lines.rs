@@ -0,0 +1,75 @@
+//! 按行切分输入，并附带源码位置
+//! * 🎯为「带位置的流式解析」提供统一的「行切分」逻辑
+//!   * 📄枚举Narsese、词法Narsese的`parse_stream_located`共用
+//! * 🎯为「解析错误」提供统一的「行号/列号」计算逻辑
+//! * 📌纯字符串处理：与具体的「Narsese格式」无关
+
+use crate::api::Located;
+use std::sync::Arc;
+
+/// 将输入按行切分，跳过空行，并记录每行内容的起始位置
+/// * 🚩行号从`1`开始；列号为「首个非空白字符」所在的字符列（从`1`开始）
+/// * 🚩产出的内容已去除首尾空白
+/// * 📌空行（含纯空白行）不产出任何条目，但仍计入行号
+pub fn located_lines(
+    input: &str,
+    source_name: Option<Arc<str>>,
+) -> impl Iterator<Item = Located<&str>> {
+    input.lines().enumerate().filter_map(move |(i, line)| {
+        // 去除首尾空白
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            return None;
+        }
+        // 计算起始列：前导空白的字符数+1
+        let col = line.chars().take_while(|c| c.is_whitespace()).count() + 1;
+        Some(Located::new(trimmed, i + 1, col, source_name.clone()))
+    })
+}
+
+/// 计算「字符索引」所在的行号、列号
+/// * 🚩按`\n`计算行；行号、列号均从`1`开始
+/// * 📌索引超出范围时，按「输入末尾」计算
+pub fn line_column(chars: impl IntoIterator<Item = char>, index: usize) -> (usize, usize) {
+    let (mut line, mut column) = (1, 1);
+    for c in chars.into_iter().take(index) {
+        match c {
+            '\n' => {
+                line += 1;
+                column = 1;
+            }
+            _ => column += 1,
+        }
+    }
+    (line, column)
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nar_dev_utils::asserts;
+
+    #[test]
+    fn test_located_lines() {
+        let input = "A\n\n  B\r\n\t\n   C  ";
+        let lines = located_lines(input, None)
+            .map(|l| (l.value, l.line, l.col))
+            .collect::<Vec<_>>();
+        asserts! {
+            lines => vec![("A", 1, 1), ("B", 3, 3), ("C", 5, 4)],
+        }
+    }
+
+    #[test]
+    fn test_line_column() {
+        let input = "ab\ncd\n\nef";
+        asserts! {
+            line_column(input.chars(), 0) => (1, 1),
+            line_column(input.chars(), 1) => (1, 2),
+            line_column(input.chars(), 3) => (2, 1),
+            line_column(input.chars(), 7) => (4, 1),
+            line_column(input.chars(), 100) => (4, 3),
+        }
+    }
+}
@@ -9,4 +9,27 @@
 // * 进行重导出
 nar_dev_utils::pub_mod_and_pub_use! {
     common_narsese_templates
+    // 带位置的行切分
+    located_lines
+    // 最大嵌套深度
+    depth_limit
+    // 格式化选项
+    format_options
+    // PyNARS控制台输出的预处理
+    pynars_output
+    // 格式的词汇表
+    vocabulary
+    // 解析错误的种类
+    parse_error_kind
+    // 像中的占位符
+    image_placeholder
+    // 查询变量与问题标点的歧义
+    ambiguity
 }
+
+// 注释
+// * 🚩仅供解析器使用：无解析器时不编译
+#[cfg(any(feature = "enum_narsese", feature = "lexical_narsese"))]
+mod comment;
+#[cfg(any(feature = "enum_narsese", feature = "lexical_narsese"))]
+pub use comment::*;
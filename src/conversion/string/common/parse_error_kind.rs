@@ -0,0 +1,72 @@
+//! 解析错误的「种类」
+//! * 🎯供调用方按种类分支处理，而无需比对（中文的）错误消息
+//!   * 📄缺少词项、真值超出范围、未知系词……
+//! * 📌枚举Narsese、词法Narsese的解析错误共用
+//! * 📌错误消息仍保留：种类只用于程序判断，消息用于展示给用户
+
+use std::fmt::{self, Display, Formatter};
+
+/// 解析错误的种类
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ParseErrorKind {
+    /// 缺少必要的条目
+    /// * 📄缺少词项、标点、预算值
+    MissingTerm,
+    /// 不是有效的数值
+    /// * 📄真值、预算值、间隔、时间戳中的数值
+    InvalidNumber,
+    /// 「0-1」区间外的值
+    /// * 📄真值、预算值
+    OutOfRange01,
+    /// 未知的陈述系词
+    UnknownCopula,
+    /// 未知的复合词项连接符
+    UnknownConnecter,
+    /// 未知的前缀
+    /// * 📄原子词项前缀、标点、时间戳
+    UnknownPrefix,
+    /// 括弧（引号）未闭合，或缺少左右括弧
+    UnclosedBracket,
+    /// 意料之外的字符、多余内容
+    UnexpectedChar,
+    /// 重复的条目
+    /// * 📄`A. %1.0;0.9% %1.0;0.9%`
+    DuplicateItem,
+    /// 输入为空
+    EmptyInput,
+    /// 有歧义的输入
+    /// * 📄`?x?`：查询变量与问题标点
+    Ambiguous,
+    /// 其它错误
+    /// * 📌附带简要描述
+    Other(String),
+}
+
+impl ParseErrorKind {
+    /// 获取稳定的错误代码
+    /// * 🎯便于日志、跨语言传递
+    /// * 📌「其它」错误的代码统一为`other`
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::MissingTerm => "missing_term",
+            Self::InvalidNumber => "invalid_number",
+            Self::OutOfRange01 => "out_of_range_01",
+            Self::UnknownCopula => "unknown_copula",
+            Self::UnknownConnecter => "unknown_connecter",
+            Self::UnknownPrefix => "unknown_prefix",
+            Self::UnclosedBracket => "unclosed_bracket",
+            Self::UnexpectedChar => "unexpected_char",
+            Self::DuplicateItem => "duplicate_item",
+            Self::EmptyInput => "empty_input",
+            Self::Ambiguous => "ambiguous",
+            Self::Other(..) => "other",
+        }
+    }
+}
+
+/// 呈现为错误代码
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
@@ -0,0 +1,105 @@
+//! PyNARS控制台输出的预处理
+//! * 🎯让PyNARS的原始输出行可以直接交给解析器
+//!   * 📄`  0.90  0.90  1.00 OUT   :<A-->B>. %1.000;0.900%`
+//! * 📝PyNARS的输出行结构：〔预算值数字（可空）〕〔类型标记〕`:`〔Narsese〕
+//!   * 📌其间可能夹杂ANSI颜色转义序列
+//! * 📌纯字符串处理：与具体的「Narsese格式」无关
+
+/// PyNARS输出的类型标记
+/// * 📄`IN    :`、`OUT   :`、`ANSWER:`
+pub const PYNARS_OUTPUT_MARKERS: &[&str] = &[
+    "IN", "OUT", "ANSWER", "ACHIEVED", "EXE", "INFO", "ERROR", "COMMENT",
+];
+
+/// 去掉PyNARS输出行的前缀
+/// * 🚩依次跳过：ANSI转义序列、预算值数字与序号、类型标记与冒号
+///   * 📌预算值数字、序号：仅由数字、`.`、`[]`、`#`组成的若干记号
+/// * 🚩结果已去除首尾空白与ANSI转义序列
+/// * 📌没有类型标记⇒视作普通Narsese，仅去除首尾空白与ANSI转义序列
+///   * ⚠️不会误删以数字开头的Narsese（如`1.`）
+pub fn strip_pynars_output_prefix(line: &str) -> &str {
+    let content = trim_ansi_and_spaces(line);
+    let mut rest = content;
+    // 跳过预算值数字、序号
+    loop {
+        let end = rest
+            .find(|c: char| c.is_whitespace() || c == '\u{1b}')
+            .unwrap_or(rest.len());
+        let token = &rest[..end];
+        let is_number_like = !token.is_empty()
+            && token
+                .chars()
+                .all(|c| c.is_ascii_digit() || matches!(c, '.' | '[' | ']' | '#'));
+        match is_number_like {
+            true => rest = trim_ansi_and_spaces(&rest[end..]),
+            false => break,
+        }
+    }
+    // 跳过类型标记与冒号
+    let after_marker = PYNARS_OUTPUT_MARKERS.iter().find_map(|marker| {
+        rest.strip_prefix(marker)
+            .map(str::trim_start)
+            .and_then(|s| s.strip_prefix(':'))
+    });
+    match after_marker {
+        Some(narsese) => trim_ansi_and_spaces(narsese),
+        // 无类型标记⇒原样返回
+        None => content,
+    }
+}
+
+/// 工具函数：去除首尾空白与ANSI转义序列
+/// * 📄`\x1b[36m`、`\x1b[48;2;10;10;124m`、`\x1b[0m`
+fn trim_ansi_and_spaces(mut s: &str) -> &str {
+    loop {
+        s = s.trim();
+        if let Some(rest) = s.strip_prefix("\u{1b}[") {
+            if let Some(end) = rest.find(|c: char| !(c.is_ascii_digit() || c == ';')) {
+                if rest[end..].starts_with('m') {
+                    s = &rest[end + 1..];
+                    continue;
+                }
+            }
+        }
+        if let Some(rest) = s.strip_suffix('m') {
+            if let Some(start) = rest.rfind("\u{1b}[") {
+                if rest[start + 2..].chars().all(|c| c.is_ascii_digit() || c == ';') {
+                    s = &rest[..start];
+                    continue;
+                }
+            }
+        }
+        return s;
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nar_dev_utils::asserts;
+
+    #[test]
+    fn test_strip_pynars_output_prefix() {
+        asserts! {
+            // 预算值+类型标记
+            strip_pynars_output_prefix(" 0.90  0.90  1.00 OUT   :<A-->B>. %1.000;0.900%")
+                => "<A-->B>. %1.000;0.900%",
+            // 无预算值
+            strip_pynars_output_prefix("            IN    :<A-->B>.") => "<A-->B>.",
+            strip_pynars_output_prefix("ANSWER:<A-->C>. %1.000;0.810%") => "<A-->C>. %1.000;0.810%",
+            // 序号
+            strip_pynars_output_prefix("[12] 0.50 0.50 0.50 OUT : A.") => "A.",
+            // ANSI转义序列
+            strip_pynars_output_prefix(
+                "\u{1b}[48;2;124;10;10m 0.90 \u{1b}[49m\u{1b}[36mOUT   :\u{1b}[39m <A-->B>.\u{1b}[0m"
+            ) => "<A-->B>.",
+            // 无类型标记⇒原样
+            strip_pynars_output_prefix("  <A-->B>. ") => "<A-->B>.",
+            strip_pynars_output_prefix("1. %1;0.9%") => "1. %1;0.9%",
+            strip_pynars_output_prefix("") => "",
+            // 类型标记须在开头：Narsese中的同名词语不受影响
+            strip_pynars_output_prefix("<IN --> OUT>.") => "<IN --> OUT>.",
+        }
+    }
+}
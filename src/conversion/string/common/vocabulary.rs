@@ -0,0 +1,226 @@
+//! 格式的「词汇表」
+//! * 🎯为编辑器工具（语法高亮、自动补全）提供格式所识别的全部记号
+//!   * 📌枚举Narsese格式、词法Narsese格式的字段形状各不相同：在此按「角色」统一归类
+//! * 📄枚举Narsese：`impl_enum::NarseseFormat::vocabulary`
+//! * 📄词法Narsese：`impl_lexical::NarseseFormat::vocabulary`
+//! * 📌不含空白符、分隔符，亦不含空字串（如ASCII的「词语前缀」）
+
+/// 括弧的角色
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BracketRole {
+    /// 复合词项 | `(` `)`
+    Compound,
+    /// 陈述 | `<` `>`
+    Statement,
+    /// 外延集 | `{` `}`
+    ExtensionSet,
+    /// 内涵集 | `[` `]`
+    IntensionSet,
+    /// 其它词项集
+    /// * 📌仅出现在词法Narsese中：自定义的词项集括弧
+    Set,
+    /// 真值 | `%` `%`
+    Truth,
+    /// 预算值 | `$` `$`
+    Budget,
+}
+
+/// 记号的角色
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenRole {
+    /// 陈述系词 | `-->`
+    Copula,
+    /// 复合词项连接符 | `&&`
+    Connecter,
+    /// 原子词项前缀 | `$`
+    AtomPrefix,
+    /// 标点 | `.`
+    Punctuation,
+    /// 括弧（左右均可）| `(`
+    Bracket(BracketRole),
+    /// 时间戳记号 | `:|:`
+    StampMarker,
+}
+
+/// 格式的词汇表
+/// * 🚩各列表按格式中的定义顺序排列，已去重
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormatVocabulary {
+    /// 陈述系词
+    pub copulas: Vec<String>,
+    /// 复合词项连接符
+    pub connecters: Vec<String>,
+    /// 原子词项前缀
+    pub atom_prefixes: Vec<String>,
+    /// 标点
+    pub punctuations: Vec<String>,
+    /// 词项的括弧对：复合词项、陈述、词项集
+    pub bracket_pairs: Vec<(String, String, BracketRole)>,
+    /// 时间戳记号
+    /// * 📌「过去」「现在」「将来」为完整的时间戳字串
+    /// * 📌「固定」时间戳给出左右括弧（空字串除外）
+    ///   * 📄ASCII：`:!`、`:`
+    pub stamp_markers: Vec<String>,
+    /// 真值、预算值的括弧对
+    pub truth_budget_brackets: Vec<(String, String, BracketRole)>,
+}
+
+impl FormatVocabulary {
+    /// 判断记号的角色
+    /// * 🚩未知记号⇒[`None`]
+    /// * ⚠️同一记号可能有多个角色：此时按如下顺序取首个
+    ///   * 系词→连接符→括弧→标点→原子词项前缀→时间戳记号
+    ///   * 📄ASCII：`?`⇒标点（亦为「查询变量」前缀）
+    ///   * 📄ASCII：`$`⇒预算值括弧（亦为「独立变量」前缀）
+    pub fn classify(&self, token: &str) -> Option<TokenRole> {
+        let contains = |list: &[String]| list.iter().any(|t| t == token);
+        let bracket_role = |pairs: &[(String, String, BracketRole)]| {
+            pairs
+                .iter()
+                .find(|(left, right, _)| left == token || right == token)
+                .map(|&(_, _, role)| role)
+        };
+        if contains(&self.copulas) {
+            return Some(TokenRole::Copula);
+        }
+        if contains(&self.connecters) {
+            return Some(TokenRole::Connecter);
+        }
+        if let Some(role) =
+            bracket_role(&self.bracket_pairs).or_else(|| bracket_role(&self.truth_budget_brackets))
+        {
+            return Some(TokenRole::Bracket(role));
+        }
+        if contains(&self.punctuations) {
+            return Some(TokenRole::Punctuation);
+        }
+        if contains(&self.atom_prefixes) {
+            return Some(TokenRole::AtomPrefix);
+        }
+        if contains(&self.stamp_markers) {
+            return Some(TokenRole::StampMarker);
+        }
+        None
+    }
+}
+
+/// 工具函数：收集记号
+/// * 🚩去除空字串，并按首次出现的顺序去重
+#[cfg(any(feature = "enum_narsese", feature = "lexical_narsese"))]
+pub(crate) fn collect_tokens<'a>(tokens: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut result: Vec<String> = vec![];
+    for token in tokens {
+        if !token.is_empty() && !result.iter().any(|t| t == token) {
+            result.push(token.to_owned());
+        }
+    }
+    result
+}
+
+/// 工具函数：收集括弧对
+/// * 🚩去除左右均为空的括弧对
+#[cfg(any(feature = "enum_narsese", feature = "lexical_narsese"))]
+pub(crate) fn collect_bracket_pairs<'a>(
+    pairs: impl IntoIterator<Item = (&'a str, &'a str, BracketRole)>,
+) -> Vec<(String, String, BracketRole)> {
+    pairs
+        .into_iter()
+        .filter(|(left, right, _)| !left.is_empty() || !right.is_empty())
+        .map(|(left, right, role)| (left.to_owned(), right.to_owned(), role))
+        .collect()
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nar_dev_utils::{asserts, show};
+
+    /// ASCII格式的全部系词
+    const ASCII_COPULAS: [&str; 13] = [
+        "-->", "<->", "==>", "<=>", "{--", "--]", "{-]", "=/>", "=|>", r"=\>", "</>", "<|>",
+        r"<\>",
+    ];
+
+    /// 工具函数：排序后的记号
+    fn sorted(tokens: &[String]) -> Vec<&str> {
+        let mut tokens = tokens.iter().map(String::as_str).collect::<Vec<_>>();
+        tokens.sort();
+        tokens
+    }
+
+    /// 工具函数：检验ASCII词汇表
+    fn _test_ascii(vocabulary: &FormatVocabulary) {
+        show!(vocabulary);
+        let mut expected_copulas = ASCII_COPULAS.to_vec();
+        expected_copulas.sort();
+        asserts! {
+            sorted(&vocabulary.copulas) => expected_copulas,
+            vocabulary.connecters.len() => 12,
+            sorted(&vocabulary.atom_prefixes) => ["#", "$", "+", "?", "^", "_"],
+            sorted(&vocabulary.punctuations) => ["!", ".", "?", "@"],
+            vocabulary.stamp_markers.contains(&":|:".to_string()),
+            vocabulary.stamp_markers.contains(&":!".to_string()),
+            // 分类
+            vocabulary.classify("==>") => Some(TokenRole::Copula),
+            vocabulary.classify("&&") => Some(TokenRole::Connecter),
+            vocabulary.classify("(") => Some(TokenRole::Bracket(BracketRole::Compound)),
+            vocabulary.classify(">") => Some(TokenRole::Bracket(BracketRole::Statement)),
+            vocabulary.classify("{") => Some(TokenRole::Bracket(BracketRole::ExtensionSet)),
+            vocabulary.classify("]") => Some(TokenRole::Bracket(BracketRole::IntensionSet)),
+            vocabulary.classify("%") => Some(TokenRole::Bracket(BracketRole::Truth)),
+            vocabulary.classify("$") => Some(TokenRole::Bracket(BracketRole::Budget)),
+            vocabulary.classify("?") => Some(TokenRole::Punctuation),
+            vocabulary.classify("#") => Some(TokenRole::AtomPrefix),
+            vocabulary.classify(":/:") => Some(TokenRole::StampMarker),
+            vocabulary.classify("") => None,
+            vocabulary.classify("A") => None,
+        }
+    }
+
+    /// 测试/枚举Narsese格式
+    #[test]
+    #[cfg(feature = "enum_narsese")]
+    fn test_enum() {
+        use crate::conversion::string::impl_enum::format_instances::*;
+        _test_ascii(&FORMAT_ASCII.vocabulary());
+        // 其它格式：不含空字串
+        for format in [&FORMAT_LATEX, &FORMAT_HAN] {
+            let vocabulary = format.vocabulary();
+            asserts! {
+                vocabulary.copulas.len() => 13,
+                vocabulary.atom_prefixes.iter().all(|t| !t.is_empty()),
+                vocabulary.stamp_markers.iter().all(|t| !t.is_empty()),
+                format.classify_token(format.statement.copula_implication) => Some(TokenRole::Copula),
+            }
+        }
+    }
+
+    /// 测试/词法Narsese格式
+    #[test]
+    #[cfg(feature = "lexical_narsese")]
+    fn test_lexical() {
+        use crate::conversion::string::impl_lexical::format_instances::*;
+        _test_ascii(&FORMAT_ASCII.vocabulary());
+        asserts! {
+            FORMAT_ASCII.classify_token("==>") => Some(TokenRole::Copula),
+            FORMAT_HAN.classify_token("「") => Some(TokenRole::Bracket(BracketRole::Statement)),
+        }
+    }
+
+    /// 测试/两种格式的ASCII词汇表一致
+    #[test]
+    #[cfg(all(feature = "enum_narsese", feature = "lexical_narsese"))]
+    fn test_enum_lexical_consistent() {
+        use crate::conversion::string::{impl_enum, impl_lexical};
+        let enum_vocabulary = impl_enum::format_instances::FORMAT_ASCII.vocabulary();
+        let lexical_vocabulary = impl_lexical::format_instances::FORMAT_ASCII.vocabulary();
+        asserts! {
+            sorted(&enum_vocabulary.copulas) => sorted(&lexical_vocabulary.copulas),
+            sorted(&enum_vocabulary.connecters) => sorted(&lexical_vocabulary.connecters),
+            sorted(&enum_vocabulary.atom_prefixes) => sorted(&lexical_vocabulary.atom_prefixes),
+            sorted(&enum_vocabulary.punctuations) => sorted(&lexical_vocabulary.punctuations),
+            sorted(&enum_vocabulary.stamp_markers) => sorted(&lexical_vocabulary.stamp_markers),
+        }
+    }
+}
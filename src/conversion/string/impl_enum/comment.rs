@@ -0,0 +1,77 @@
+//! 枚举Narsese的「注释」处理
+//! * 🎯一次解析`.nal`文件等「多条语句」的输入：跳过整行注释，去除行尾注释
+//!   * 📄`'测试用NAL文件`、`// C风格注释`
+//!   * 📄`<A --> B>. %1.0;0.9% // 行尾注释`
+//! * 🚩注释前缀固定为[`DEFAULT_COMMENT_PREFIXES`]
+//! * 📌仅识别「括弧之外」的注释前缀：与词法Narsese共用[`CommentScanner`]
+
+use super::NarseseFormat;
+use crate::conversion::string::{CommentScanner, DEFAULT_COMMENT_PREFIXES};
+
+impl NarseseFormat<&str> {
+    /// 构造「注释扫描器」
+    /// * 🚩括弧：复合词项、外延集、内涵集、陈述的括弧对
+    /// * 📌枚举Narsese没有「原子词项引号」
+    fn comment_scanner(&self) -> CommentScanner<'_> {
+        let brackets = [
+            self.compound.brackets,
+            self.compound.brackets_set_extension,
+            self.compound.brackets_set_intension,
+            self.statement.brackets,
+        ];
+        CommentScanner {
+            comment_prefixes: DEFAULT_COMMENT_PREFIXES.to_vec(),
+            left_brackets: brackets.iter().map(|(left, _)| *left).collect(),
+            right_brackets: brackets.iter().map(|(_, right)| *right).collect(),
+            copulas: self.copulas().to_vec(),
+            quotes: vec![],
+        }
+    }
+
+    /// 去除注释
+    /// * 🚩返回「首个括弧外注释前缀」之前的内容（去除尾部空白）
+    ///   * 📌整行均为注释⇒返回空字串
+    /// * 📄参见[`CommentScanner::strip_comment`]
+    pub fn strip_comment<'a>(&self, line: &'a str) -> &'a str {
+        self.comment_scanner().strip_comment(line)
+    }
+
+    /// 将输入切分为「逻辑语句」
+    /// * 🚩按行切分，去除注释，跳过空行（含「整行注释」）
+    /// * 🚩产出的内容已去除首尾空白
+    pub fn statement_lines<'a>(&'a self, input: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        let scanner = self.comment_scanner();
+        input
+            .lines()
+            .map(move |line| scanner.strip_comment(line).trim())
+            .filter(|line| !line.is_empty())
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use crate::conversion::string::impl_enum::format_instances::*;
+    use nar_dev_utils::asserts;
+
+    #[test]
+    fn test_strip_comment() {
+        let format = &FORMAT_ASCII;
+        asserts! {
+            // 整行注释
+            format.strip_comment("// C风格注释") => "",
+            format.strip_comment("'测试用NAL文件") => "",
+            // 行尾注释
+            format.strip_comment("<A --> B>. %1.0;0.9% // 行尾注释") => "<A --> B>. %1.0;0.9%",
+            format.strip_comment("<A --> B>.'注释") => "<A --> B>.",
+            // 括弧内：不视作注释
+            format.strip_comment("<A ==> (*, a//b, {c//d})>.") => "<A ==> (*, a//b, {c//d})>.",
+            // 无注释
+            format.strip_comment("<A --> B>.") => "<A --> B>.",
+        }
+        let input = "'注释\n<A --> B>. // 注释\n\n  (*, a//b)?\n";
+        asserts! {
+            format.statement_lines(input).collect::<Vec<_>>() => ["<A --> B>.", "(*, a//b)?"],
+        }
+    }
+}
@@ -33,6 +33,10 @@
 //!   * 5 蕴含
 //!   * 5 等价
 
+use crate::conversion::string::{
+    collect_bracket_pairs, collect_tokens, AmbiguityResolution, BracketRole, FormatVocabulary, TokenRole,
+};
+
 /// Narsese格式/原子词项
 /// * 格式预期：`{前缀}+词项字符串名`
 ///   * 📌将「占位符」也包含在内——相当于「只有前缀，没有内容」的词项
@@ -169,6 +173,20 @@ pub struct NarseseFormatSentence<Content> {
     pub truth_brackets: (Content, Content),
     /// 真值/分隔符 | `;`
     pub truth_separator: Content,
+    /// 真值/备选分隔符 | `,`
+    /// * 🎯兼容ONA等使用`,`分隔的输入：`%1.0,0.9%`
+    /// * 📌仅用于解析，可与[`Self::truth_separator`]混用
+    ///   * 📌格式化时总使用[`Self::truth_separator`]
+    pub truth_separator_alternates: &'static [&'static str],
+    /// 真值/小数位数
+    /// * 🚩[`None`]⇒使用最短的表示
+    /// * 📄OpenNARS：`Some(2)`⇒`%1.00;0.90%`
+    /// * 📌仅用于格式化，不影响解析
+    pub truth_float_precision: Option<usize>,
+    /// 真值/去除小数末尾的`0`
+    /// * 📄`%1.00;0.90%`⇒`%1;0.9%`
+    /// * 📌仅用于格式化，不影响解析
+    pub truth_strip_trailing_zeros: bool,
 }
 
 /// Narsese格式/任务
@@ -179,6 +197,16 @@ pub struct NarseseFormatTask<Content> {
     pub budget_brackets: (Content, Content),
     /// 预算值/分隔符 | `;`
     pub budget_separator: Content,
+    /// 预算值/备选分隔符 | `,`
+    /// * 📄参考[`NarseseFormatSentence::truth_separator_alternates`]
+    pub budget_separator_alternates: &'static [&'static str],
+    /// 预算值/小数位数
+    /// * 🚩[`None`]⇒使用最短的表示
+    /// * 📌仅用于格式化，不影响解析
+    pub budget_float_precision: Option<usize>,
+    /// 预算值/去除小数末尾的`0`
+    /// * 📌仅用于格式化，不影响解析
+    pub budget_strip_trailing_zeros: bool,
 }
 
 /// Narsese格式/空白符
@@ -186,6 +214,12 @@ pub struct NarseseFormatTask<Content> {
 pub struct NarseseFormatSpace<Content> {
     /// 空白符（解析用）
     pub parse: Content,
+    /// 判断字符是否为空白符（解析用）
+    /// * 🎯兼容[`Self::parse`]之外的各类空白：全角空格`U+3000`、不换行空格`U+00A0`、制表符……
+    /// * 🚩解析时，满足此判断的单个字符与[`Self::parse`]同等跳过
+    ///   * 📌换行符总被视作空白，不受此影响
+    /// * 📄与「词法Narsese」的`is_for_parse`一致
+    pub is_for_parse: fn(char) -> bool,
     /// 空白符（格式化/分隔词项）
     /// * 🎯复合词项/陈述
     pub format_terms: Content,
@@ -194,6 +228,30 @@ pub struct NarseseFormatSpace<Content> {
     pub format_items: Content,
 }
 
+/// 「重复组分」的处理策略
+/// * 🎯统一「解析/折叠」到**无序容器**时，对重复组分的处理方式
+///   * 📄外延集、内涵集、外延交、内涵交、合取、析取、平行合取
+///   * 📌有序容器（如乘积、顺序合取）总是保留重复组分，不受此影响
+/// * 📝词法Narsese总是保留所有组分；此策略仅在「解析/折叠为枚举Narsese」时生效
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DuplicatePolicy {
+    /// 去重
+    /// * 🚩默认：与[`std::collections::HashSet`]的行为一致
+    /// * 📌去重时产生警告，而非悄然去重
+    ///   * 📄解析：参见[`NarseseFormat::parse_with_warnings`]
+    ///   * 📄折叠：折叠前的「良构检查」（`impl_lexical::lint`）
+    #[default]
+    Dedupe,
+    /// 保留
+    /// * ⚠️目前枚举Narsese的无序容器基于[`std::collections::HashSet`]，无法真正保留重复组分
+    ///   * 🚩此时降级为「去重」，并产生警告
+    ///   * 📌警告消息与[`Self::Dedupe`]不同：指明「无法保留」
+    Keep,
+    /// 报错
+    /// * 🚩遇到重复组分时，解析/折叠失败，并在报错中给出重复的组分
+    Error,
+}
+
 /// Narsese格式
 /// * 📌记录「枚举Narsese」的各类常量
 ///   * ⚠️只用于存储数据，后续需要载入「解析器状态」
@@ -229,6 +287,54 @@ pub struct NarseseFormat<Content> {
 
     /// 任务的格式
     pub task: NarseseFormatTask<Content>,
+
+    /// 无序容器中「重复组分」的处理策略
+    /// * 🚩默认为「去重」
+    pub duplicate_policy: DuplicatePolicy,
+
+    /// 解析时允许的最大嵌套深度
+    /// * 🎯防止深层嵌套的输入耗尽调用栈
+    /// * 🚩超出时返回解析错误「超出最大嵌套深度」
+    /// * ⚙️默认为[`DEFAULT_MAX_DEPTH`](crate::conversion::string::DEFAULT_MAX_DEPTH)
+    pub max_depth: usize,
+
+    /// 是否严格检查「标点-真值」一致性
+    /// * 🎯避免「问题」「请求」上的真值被悄然舍去
+    ///   * 📄`<A --> B>? %1.0%`：开启后解析报错，否则舍去真值
+    /// * 📌空真值（如`%%`）不受影响
+    /// * ⚙️默认关闭
+    pub strict_truth: bool,
+
+    /// 是否接受「三真值」`%频率;信度;证据数%`
+    /// * 🎯兼容部分实验性NARS变种输出的「k-证据」形式
+    ///   * 📄`<A --> B>. %1.0;0.9;4%` ⇒ [`Truth::Triple`](crate::enum_narsese::Truth::Triple)
+    /// * 📌关闭时，三个分量的真值解析报错（亦适用于词法折叠）
+    /// * ⚙️默认关闭
+    pub allow_extended_truth: bool,
+
+    /// 是否将「回顾性等价」规范化为「预测性等价」
+    /// * 🎯兼容旧行为：`<A <\> B>`解析为`<B </> A>`
+    /// * 📌关闭后保留原始写法，便于「解析→格式化」的往返比对
+    /// * ⚙️默认开启
+    pub normalize_retrospective: bool,
+
+    /// 是否以确定性的顺序格式化
+    /// * 🎯无序容器基于[`HashSet`](std::collections::HashSet)：遍历顺序随散列种子变化
+    ///   * 📄`(&&, A, B, C)`在不同的运行中可能输出为`(&&, B, C, A)`：快照测试不稳定、差异嘈杂
+    /// * 🚩开启后，格式化时按「各组分的格式化结果」排序（递归）
+    ///   * 📌无序容器：外延集、内涵集、外延交、内涵交、合取、析取、平行合取
+    ///   * 📌对称陈述：相似、等价、并发性等价的主谓词
+    /// * 📌不修改被格式化的词项；仅用于格式化，不影响解析
+    /// * 📄值层面的规范化：[`Term::to_canonical`](crate::enum_narsese::Term::to_canonical)
+    /// * ⚙️默认关闭
+    pub canonical_order: bool,
+
+    /// 「查询变量」与「问题标点」的消歧策略
+    /// * 🎯`?x?`、`?x`：开头的`?`是查询变量前缀，还是问题标点
+    /// * 📌仅当查询变量前缀与问题标点相同时生效
+    /// * 📄参见[`AmbiguityResolution`]
+    /// * ⚙️默认「偏向变量」：即此前的解析行为
+    pub ambiguity_resolution: AmbiguityResolution,
     // * 🚩【2024-03-28 14:33:47】现弃用「关键字截断」机制，直接使用「系词前缀匹配」判断
     // pub enable_keyword_truncation: bool,
 }
@@ -259,3 +365,81 @@ impl NarseseFormat<&str> {
         ]
     }
 }
+
+/// 实现/词汇表
+impl NarseseFormat<&str> {
+    /// 获取格式的词汇表
+    /// * 🎯编辑器工具：语法高亮、自动补全
+    /// * 📌时间戳记号已拼接上时间戳括弧：ASCII的「现在」为`:|:`
+    pub fn vocabulary(&self) -> FormatVocabulary {
+        let atom = &self.atom;
+        let compound = &self.compound;
+        let sentence = &self.sentence;
+        let (stamp_left, stamp_right) = sentence.stamp_brackets;
+        let stamp = |content: &str| format!("{stamp_left}{content}{stamp_right}");
+        FormatVocabulary {
+            copulas: collect_tokens(self.copulas()),
+            connecters: collect_tokens([
+                compound.connecter_intersection_extension,
+                compound.connecter_intersection_intension,
+                compound.connecter_difference_extension,
+                compound.connecter_difference_intension,
+                compound.connecter_product,
+                compound.connecter_image_extension,
+                compound.connecter_image_intension,
+                compound.connecter_conjunction,
+                compound.connecter_disjunction,
+                compound.connecter_negation,
+                compound.connecter_conjunction_sequential,
+                compound.connecter_conjunction_parallel,
+            ]),
+            atom_prefixes: collect_tokens([
+                atom.prefix_word,
+                atom.prefix_variable_independent,
+                atom.prefix_variable_dependent,
+                atom.prefix_variable_query,
+                atom.prefix_interval,
+                atom.prefix_operator,
+                atom.prefix_placeholder,
+            ]),
+            punctuations: collect_tokens([
+                sentence.punctuation_judgement,
+                sentence.punctuation_goal,
+                sentence.punctuation_question,
+                sentence.punctuation_quest,
+            ]),
+            bracket_pairs: collect_bracket_pairs([
+                (compound.brackets.0, compound.brackets.1, BracketRole::Compound),
+                (self.statement.brackets.0, self.statement.brackets.1, BracketRole::Statement),
+                (
+                    compound.brackets_set_extension.0,
+                    compound.brackets_set_extension.1,
+                    BracketRole::ExtensionSet,
+                ),
+                (
+                    compound.brackets_set_intension.0,
+                    compound.brackets_set_intension.1,
+                    BracketRole::IntensionSet,
+                ),
+            ]),
+            stamp_markers: collect_tokens([
+                &*stamp(sentence.stamp_past),
+                &*stamp(sentence.stamp_present),
+                &*stamp(sentence.stamp_future),
+                &*format!("{stamp_left}{}", sentence.stamp_fixed),
+                stamp_right,
+            ]),
+            truth_budget_brackets: collect_bracket_pairs([
+                (sentence.truth_brackets.0, sentence.truth_brackets.1, BracketRole::Truth),
+                (self.task.budget_brackets.0, self.task.budget_brackets.1, BracketRole::Budget),
+            ]),
+        }
+    }
+
+    /// 判断记号在此格式中的角色
+    /// * 📄参见[`FormatVocabulary::classify`]
+    /// * ⚠️每次调用均重新生成词汇表：批量判断时宜先调用[`Self::vocabulary`]
+    pub fn classify_token(&self, token: &str) -> Option<TokenRole> {
+        self.vocabulary().classify(token)
+    }
+}
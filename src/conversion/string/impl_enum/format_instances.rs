@@ -4,19 +4,15 @@
 //!   * ℹ️有少量修改
 
 use super::format::*;
+use crate::{
+    conversion::string::{AmbiguityResolution, DEFAULT_MAX_DEPTH},
+    enum_narsese::is_valid_atom_name_char,
+};
 
 /// 工具函数/判断字符是否能作为「词项名」
-/// * 🎯用于判断「合法词项名」
-/// * ⚠️【2024-06-13 19:25:15】[`char::is_alphanumeric`]目前还不是常量函数
+/// * 🚩与「枚举Narsese」构造词项时的检验一致：见[`is_valid_atom_name_char`]
 fn is_valid_atom_name(c: char) -> bool {
-    //  先判断是否为「字母/数字」
-    c.is_alphanumeric()
-    // 特殊：横杠/下划线
-    // //! ↓【2024-02-22 14:46:16】现因需兼顾`<主词-->谓词>`的结构（防止系词中的`-`被消耗），故不再兼容`-`
-    // * 🚩【2024-03-28 14:18:08】现在重新启用对`-`的「原子词项字符兼容」：使用新的「前缀failing匹配」方法
-    || c == '_' || c == '-'
-    // 常见emoji兼容
-    || c > '\u{1f2ff}'
+    is_valid_atom_name_char(c)
 }
 
 /// 通用 ASCII格式
@@ -26,9 +22,10 @@ fn is_valid_atom_name(c: char) -> bool {
 pub const FORMAT_ASCII: NarseseFormat<&str> = NarseseFormat {
     is_valid_atom_name,
     space: NarseseFormatSpace {
-        parse: " ",        // ! 解析时忽略空格
-        format_terms: " ", // 格式化时，词项间需要空格（英文如此）
-        format_items: " ", // 格式化时，条目间需要空格（英文如此）
+        parse: " ",                        // ! 解析时忽略空格
+        is_for_parse: char::is_whitespace, // 各类空白均可跳过
+        format_terms: " ",                 // 格式化时，词项间需要空格（英文如此）
+        format_items: " ",                 // 格式化时，条目间需要空格（英文如此）
     },
     atom: NarseseFormatAtom {
         prefix_word: "",
@@ -85,11 +82,24 @@ pub const FORMAT_ASCII: NarseseFormat<&str> = NarseseFormat {
         stamp_fixed: "!",
         truth_brackets: ("%", "%"),
         truth_separator: ";",
+        truth_separator_alternates: &[","],
+        truth_float_precision: None,
+        truth_strip_trailing_zeros: false,
     },
     task: NarseseFormatTask {
         budget_brackets: ("$", "$"),
         budget_separator: ";",
+        budget_separator_alternates: &[","],
+        budget_float_precision: None,
+        budget_strip_trailing_zeros: false,
     },
+    duplicate_policy: DuplicatePolicy::Dedupe,
+    max_depth: DEFAULT_MAX_DEPTH,
+    strict_truth: false,
+    allow_extended_truth: false,
+    normalize_retrospective: true,
+    canonical_order: false,
+    ambiguity_resolution: AmbiguityResolution::PreferVariable,
     // * 🚩【2024-03-28 14:33:47】现弃用「关键字截断」机制，直接使用「系词前缀匹配」判断
 };
 
@@ -101,9 +111,10 @@ pub const FORMAT_ASCII: NarseseFormat<&str> = NarseseFormat {
 pub const FORMAT_LATEX: NarseseFormat<&str> = NarseseFormat {
     is_valid_atom_name,
     space: NarseseFormatSpace {
-        parse: " ",        // ! 解析时可跳过空格
-        format_terms: " ", // 格式化时，词项间需要分隔（避免代码粘连）
-        format_items: " ", // 格式化时，条目间需要分隔（避免代码粘连）
+        parse: " ",                        // ! 解析时可跳过空格
+        is_for_parse: char::is_whitespace, // 各类空白均可跳过
+        format_terms: " ",                 // 格式化时，词项间需要分隔（避免代码粘连）
+        format_items: " ",                 // 格式化时，条目间需要分隔（避免代码粘连）
     },
     atom: NarseseFormatAtom {
         prefix_word: "",
@@ -162,22 +173,42 @@ pub const FORMAT_LATEX: NarseseFormat<&str> = NarseseFormat {
         stamp_fixed: "t=",                            // ? LaTeX语法未知
         truth_brackets: (r"\langle{}", r"\rangle{}"), // ! 【2024-03-18 23:58:02】末尾使用空参数集分隔
         truth_separator: ",",
+        truth_separator_alternates: &[],
+        truth_float_precision: None,
+        truth_strip_trailing_zeros: false,
     },
     task: NarseseFormatTask {
         budget_brackets: (r"\$", r"\$"),
         budget_separator: ";",
+        budget_separator_alternates: &[],
+        budget_float_precision: None,
+        budget_strip_trailing_zeros: false,
     },
+    duplicate_policy: DuplicatePolicy::Dedupe,
+    max_depth: DEFAULT_MAX_DEPTH,
+    strict_truth: false,
+    allow_extended_truth: false,
+    normalize_retrospective: true,
+    canonical_order: false,
+    ambiguity_resolution: AmbiguityResolution::PreferVariable,
     // * 🚩【2024-03-28 14:33:47】现弃用「关键字截断」机制，直接使用「系词前缀匹配」判断
 };
 
 /// 漢文扩展
 /// * 📌原创
+/// * 📝时序系词：以「将/现/曾」标示时间方向
+///   * 📄蕴含：「将得」「现得」「曾得」；等价：「将同」「现同」「曾同」
+/// * 📝像：「外像」「内像」，占位符为「某」
+/// * 📝原子词项前缀：间隔「间隔」、操作符「操作」
+/// * ⚠️汉字亦可作为词语名：与关键字重名的词语可能无法被解析
+///   * 📄词语「同时」会被当作「平行合取」的连接符
 pub const FORMAT_HAN: NarseseFormat<&str> = NarseseFormat {
     is_valid_atom_name,
     space: NarseseFormatSpace {
-        parse: " ",        // ! 解析时忽略空格
-        format_terms: "",  // 格式化时，词项间无需分隔（避免太过松散）
-        format_items: " ", // 格式化时，条目间需要分隔（避免太过密集）
+        parse: " ",                        // ! 解析时忽略空格
+        is_for_parse: char::is_whitespace, // 含全角空格`U+3000`、不换行空格`U+00A0`、制表符
+        format_terms: "",                  // 格式化时，词项间无需分隔（避免太过松散）
+        format_items: " ",                 // 格式化时，条目间需要分隔（避免太过密集）
     },
     atom: NarseseFormatAtom {
         prefix_word: "", // 置空
@@ -234,11 +265,24 @@ pub const FORMAT_HAN: NarseseFormat<&str> = NarseseFormat {
         stamp_fixed: "发生在",        // 另一个候选是「时为」，但欠缺可读性
         truth_brackets: ("真", "值"), // 大改：兼容单真值、空真值
         truth_separator: "、",
+        truth_separator_alternates: &[],
+        truth_float_precision: None,
+        truth_strip_trailing_zeros: false,
     },
     task: NarseseFormatTask {
         budget_brackets: ("预", "算"),
         budget_separator: "、",
+        budget_separator_alternates: &[],
+        budget_float_precision: None,
+        budget_strip_trailing_zeros: false,
     },
+    duplicate_policy: DuplicatePolicy::Dedupe,
+    max_depth: DEFAULT_MAX_DEPTH,
+    strict_truth: false,
+    allow_extended_truth: false,
+    normalize_retrospective: true,
+    canonical_order: false,
+    ambiguity_resolution: AmbiguityResolution::PreferVariable,
     // * 🚩【2024-03-28 14:33:47】现弃用「关键字截断」机制，直接使用「系词前缀匹配」判断
 };
 
@@ -1,20 +1,22 @@
 //! 实现/格式化器
 
-use super::NarseseFormat;
+use super::{format_instances::FORMAT_ASCII, NarseseFormat};
 use crate::{
     api::{
         FloatPrecision, FormatTo, GetBudget, GetPunctuation, GetStamp, GetTerm, GetTruth,
         UIntPrecision,
     },
-    conversion::string::common_narsese_templates::*,
+    conversion::string::{common_narsese_templates::*, FormatOptions},
     enum_narsese::*,
 };
-use nar_dev_utils::*;
+use std::fmt::{self, Display, Formatter, Write};
 
 /// 实现：转换
 /// * 🚩【2024-04-05 01:47:08】目前保留方法调用上的兼容性
 ///   * 📌类型特定的[`format_term`]等方法调用与`term.format_to`并存
 ///   * 📌有利于代码组织紧凑性：相互调用的函数尽可能放在一起
+/// * 🚩内部统一写入[`Write`]：返回[`String`]的方法只是薄封装
+///   * 🎯批量格式化时，可用`format_xxx_to`直接写入调用方的缓冲区
 ///
 /// ! ℹ️单元测试在[`super::formats`]模块中定义
 ///
@@ -23,337 +25,435 @@ impl NarseseFormat<&str> {
 
     // 针对「枚举Narsese」的格式化 //
 
-    /// 工具函数/原子词项
-    fn format_atom(&self, out: &mut String, atom: &Term, prefix: &str) {
-        template_atom(out, prefix, &atom.get_atom_name_unchecked());
-    }
-
     /// 工具函数/词项集
-    fn format_set(
+    fn format_set<'t, W: Write>(
         &self,
-        out: &mut String,
-        components: Vec<&Term>,
+        out: &mut W,
+        options: &FormatOptions,
+        components: impl Iterator<Item = &'t Term>,
         bracket_left: &str,
         bracket_right: &str,
-    ) {
-        template_compound_set(
+    ) -> fmt::Result {
+        template_compound_set_to(
             out,
             bracket_left,
-            // 批量将内部词项转换成字符串
-            components.iter().map(|term| self.format_term(term)),
+            components,
             self.compound.separator,
-            self.space.format_terms,
+            options.separator_space(self.space.format_terms),
             bracket_right,
-        );
+            // 逐个写入内部词项
+            |out, term| self._format_term(out, term, options),
+        )
     }
 
     /// 工具函数/复合词项
-    fn format_compound(&self, out: &mut String, components: Vec<&Term>, connecter: &str) {
-        template_compound(
+    fn format_compound<'t, W: Write>(
+        &self,
+        out: &mut W,
+        options: &FormatOptions,
+        components: impl Iterator<Item = &'t Term>,
+        connecter: &str,
+    ) -> fmt::Result {
+        template_compound_to(
             out,
             self.compound.brackets.0,
             connecter,
-            components.iter().map(|term| self.format_term(term)),
+            components,
             self.compound.separator,
-            self.space.format_terms,
+            options.separator_space(self.space.format_terms),
             self.compound.brackets.1,
-        );
+            |out, term| self._format_term(out, term, options),
+        )
     }
 
     /// 工具函数/像
-    fn format_image(
+    fn format_image<W: Write>(
         &self,
-        out: &mut String,
+        out: &mut W,
+        options: &FormatOptions,
         index: UIntPrecision,
-        components: Vec<&Term>,
+        components: &[Term],
         connecter: &str,
-    ) {
-        template_compound(
+    ) -> fmt::Result {
+        self.format_compound(
             out,
-            self.compound.brackets.0,
-            connecter,
+            options,
             // 通过特殊的迭代器，连同占位符一起迭代
-            ImageIterator::new(
-                // * 建立迭代器并复制其中的引用（`&&Term => &Term`）
-                // * 📝Clippy：可简化`.map(|&term| term)`为`.copied()`
-                components.iter().copied(),
-                index,
-            )
-            .map(|term| self.format_term(term)),
-            self.compound.separator,
-            self.space.format_terms,
-            self.compound.brackets.1,
+            ImageIterator::new(components.iter(), index),
+            connecter,
         )
     }
 
     /// 工具函数/陈述
-    fn format_statement(&self, out: &mut String, left: &Term, right: &Term, copula: &str) {
-        template_statement(
+    fn format_statement<W: Write>(
+        &self,
+        out: &mut W,
+        options: &FormatOptions,
+        left: &Term,
+        right: &Term,
+        copula: &str,
+    ) -> fmt::Result {
+        template_statement_to(
             out,
             self.statement.brackets.0,
             // 左边
-            &self.format_term(left),
+            |out| self._format_term(out, left, options),
             // 连接符
             copula,
             // 右边
-            &self.format_term(right),
+            |out| self._format_term(out, right, options),
             // 空格
-            self.space.format_terms,
+            options.copula_space(self.space.format_terms),
             // 右边
             self.statement.brackets.1,
         )
     }
 
+    /// 工具函数/无序容器的组分
+    /// * 🚩启用「确定性顺序」⇒按各组分的格式化结果排序；否则按容器的遍历顺序
+    /// * ⚡未启用时不分配额外空间
+    fn set_components<'t>(
+        &self,
+        set: &'t TermSetType,
+        options: &FormatOptions,
+    ) -> impl Iterator<Item = &'t Term> {
+        let sorted = self.canonical_order.then(|| {
+            let mut terms = set.iter().collect::<Vec<_>>();
+            terms.sort_by_cached_key(|term| {
+                format_to_string(|out| self._format_term(out, term, options))
+            });
+            terms
+        });
+        let unsorted = match sorted {
+            Some(..) => None,
+            None => Some(set.iter()),
+        };
+        sorted
+            .into_iter()
+            .flatten()
+            .chain(unsorted.into_iter().flatten())
+    }
+
+    /// 工具函数/对称陈述的主谓词
+    /// * 🚩启用「确定性顺序」⇒按格式化结果排序；否则原样返回
+    fn symmetric_sides<'t>(
+        &self,
+        left: &'t Term,
+        right: &'t Term,
+        options: &FormatOptions,
+    ) -> (&'t Term, &'t Term) {
+        if !self.canonical_order {
+            return (left, right);
+        }
+        let format = |term| format_to_string(|out| self._format_term(out, term, options));
+        match format(left) <= format(right) {
+            true => (left, right),
+            false => (right, left),
+        }
+    }
+
     /// 格式化函数/词项
     /// * 返回一个新字符串
     pub fn format_term(&self, term: &Term) -> String {
-        // 创建一个新字符串
-        let mut s = String::new();
-        // 对字符串注入格式化文本
-        self._format_term(&mut s, term);
-        // 返回注入后的字符串
-        s
+        self.format_term_opts(term, &FormatOptions::default())
+    }
+
+    /// 格式化函数/词项（带选项）
+    /// * 🎯控制输出中的空白，如紧凑输出
+    /// * 📌数学模式只包裹最终输出：组分不会被单独包裹
+    pub fn format_term_opts(&self, term: &Term, options: &FormatOptions) -> String {
+        format_to_string(|out| self.format_term_opts_to(term, options, out))
+    }
+
+    /// 格式化函数/词项（写入器）
+    /// * 🎯批量格式化：写入调用方提供的缓冲区，不另行分配字符串
+    pub fn format_term_to(&self, term: &Term, out: &mut impl Write) -> fmt::Result {
+        self.format_term_opts_to(term, &FormatOptions::default(), out)
+    }
+
+    /// 格式化函数/词项（带选项、写入器）
+    pub fn format_term_opts_to(
+        &self,
+        term: &Term,
+        options: &FormatOptions,
+        out: &mut impl Write,
+    ) -> fmt::Result {
+        options.wrap_math_to(out, |out| self._format_term(out, term, options))
     }
 
     /// 【内部】总格式化函数/词项
-    fn _format_term(&self, out: &mut String, term: &Term) {
+    /// * 🚩直接匹配内部容器，不经由[`Term::get_components`]收集
+    fn _format_term<W: Write>(
+        &self,
+        out: &mut W,
+        term: &Term,
+        options: &FormatOptions,
+    ) -> fmt::Result {
+        let atom = &self.atom;
+        let compound = &self.compound;
+        let statement = &self.statement;
         match term {
             // 原子词项
-            Word(..) => self.format_atom(out, term, self.atom.prefix_word),
-            Placeholder => self.format_atom(out, term, self.atom.prefix_placeholder),
-            VariableIndependent(..) => {
-                self.format_atom(out, term, self.atom.prefix_variable_independent)
-            }
-            VariableDependent(..) => {
-                self.format_atom(out, term, self.atom.prefix_variable_dependent)
+            Word(name) => template_atom_to(out, atom.prefix_word, name),
+            Placeholder => template_atom_to(out, atom.prefix_placeholder, ""),
+            VariableIndependent(name) => {
+                template_atom_to(out, atom.prefix_variable_independent, name)
             }
-            VariableQuery(..) => self.format_atom(out, term, self.atom.prefix_variable_query),
-            Interval(..) => self.format_atom(out, term, self.atom.prefix_interval),
-            Operator(..) => self.format_atom(out, term, self.atom.prefix_operator),
+            VariableDependent(name) => template_atom_to(out, atom.prefix_variable_dependent, name),
+            VariableQuery(name) => template_atom_to(out, atom.prefix_variable_query, name),
+            Interval(interval) => write!(out, "{}{interval}", atom.prefix_interval),
+            Operator(name) => template_atom_to(out, atom.prefix_operator, name),
             // 复合词项
-            SetExtension(..) => self.format_set(
+            SetExtension(set) => self.format_set(
                 out,
-                term.get_components(),
-                self.compound.brackets_set_extension.0,
-                self.compound.brackets_set_extension.1,
+                options,
+                self.set_components(set, options),
+                compound.brackets_set_extension.0,
+                compound.brackets_set_extension.1,
             ),
-            SetIntension(..) => self.format_set(
+            SetIntension(set) => self.format_set(
                 out,
-                term.get_components(),
-                self.compound.brackets_set_intension.0,
-                self.compound.brackets_set_intension.1,
+                options,
+                self.set_components(set, options),
+                compound.brackets_set_intension.0,
+                compound.brackets_set_intension.1,
             ),
-            IntersectionExtension(..) => self.format_compound(
+            IntersectionExtension(set) => self.format_compound(
                 out,
-                term.get_components(),
-                self.compound.connecter_intersection_extension,
+                options,
+                self.set_components(set, options),
+                compound.connecter_intersection_extension,
             ),
-            IntersectionIntension(..) => self.format_compound(
+            IntersectionIntension(set) => self.format_compound(
                 out,
-                term.get_components(),
-                self.compound.connecter_intersection_intension,
+                options,
+                self.set_components(set, options),
+                compound.connecter_intersection_intension,
             ),
-            DifferenceExtension(..) => self.format_compound(
+            DifferenceExtension(left, right) => self.format_compound(
                 out,
-                term.get_components(),
-                self.compound.connecter_difference_extension,
+                options,
+                [&**left, &**right].into_iter(),
+                compound.connecter_difference_extension,
             ),
-            DifferenceIntension(..) => self.format_compound(
+            DifferenceIntension(left, right) => self.format_compound(
                 out,
-                term.get_components(),
-                self.compound.connecter_difference_intension,
+                options,
+                [&**left, &**right].into_iter(),
+                compound.connecter_difference_intension,
             ),
-            Product(..) => {
-                self.format_compound(out, term.get_components(), self.compound.connecter_product)
+            Product(terms) => {
+                self.format_compound(out, options, terms.iter(), compound.connecter_product)
             }
-            ImageExtension(index, _) => self.format_image(
+            ImageExtension(index, terms) => self.format_image(
                 out,
+                options,
                 *index,
-                term.get_components(),
-                self.compound.connecter_image_extension,
+                terms,
+                compound.connecter_image_extension,
             ),
-            ImageIntension(index, _) => self.format_image(
+            ImageIntension(index, terms) => self.format_image(
                 out,
+                options,
                 *index,
-                term.get_components(),
-                self.compound.connecter_image_intension,
+                terms,
+                compound.connecter_image_intension,
             ),
-            Conjunction(..) => self.format_compound(
+            Conjunction(set) => self.format_compound(
                 out,
-                term.get_components(),
-                self.compound.connecter_conjunction,
+                options,
+                self.set_components(set, options),
+                compound.connecter_conjunction,
             ),
-            Disjunction(..) => self.format_compound(
+            Disjunction(set) => self.format_compound(
                 out,
-                term.get_components(),
-                self.compound.connecter_disjunction,
+                options,
+                self.set_components(set, options),
+                compound.connecter_disjunction,
             ),
-            Negation(..) => {
-                self.format_compound(out, term.get_components(), self.compound.connecter_negation)
-            }
-            ConjunctionSequential(..) => self.format_compound(
+            Negation(term) => self.format_compound(
+                out,
+                options,
+                std::iter::once(&**term),
+                compound.connecter_negation,
+            ),
+            ConjunctionSequential(terms) => self.format_compound(
                 out,
-                term.get_components(),
-                self.compound.connecter_conjunction_sequential,
+                options,
+                terms.iter(),
+                compound.connecter_conjunction_sequential,
             ),
-            ConjunctionParallel(..) => self.format_compound(
+            ConjunctionParallel(set) => self.format_compound(
                 out,
-                term.get_components(),
-                self.compound.connecter_conjunction_parallel,
+                options,
+                self.set_components(set, options),
+                compound.connecter_conjunction_parallel,
             ),
             // 陈述
             Inheritance(left, right) => {
-                self.format_statement(out, left, right, self.statement.copula_inheritance)
+                self.format_statement(out, options, left, right, statement.copula_inheritance)
             }
             Similarity(left, right) => {
-                self.format_statement(out, left, right, self.statement.copula_similarity)
+                let (left, right) = self.symmetric_sides(left, right, options);
+                self.format_statement(out, options, left, right, statement.copula_similarity)
             }
             Implication(left, right) => {
-                self.format_statement(out, left, right, self.statement.copula_implication)
+                self.format_statement(out, options, left, right, statement.copula_implication)
             }
             Equivalence(left, right) => {
-                self.format_statement(out, left, right, self.statement.copula_equivalence)
+                let (left, right) = self.symmetric_sides(left, right, options);
+                self.format_statement(out, options, left, right, statement.copula_equivalence)
             }
             ImplicationPredictive(left, right) => self.format_statement(
                 out,
+                options,
                 left,
                 right,
-                self.statement.copula_implication_predictive,
+                statement.copula_implication_predictive,
             ),
             ImplicationConcurrent(left, right) => self.format_statement(
                 out,
+                options,
                 left,
                 right,
-                self.statement.copula_implication_concurrent,
+                statement.copula_implication_concurrent,
             ),
             ImplicationRetrospective(left, right) => self.format_statement(
                 out,
+                options,
                 left,
                 right,
-                self.statement.copula_implication_retrospective,
+                statement.copula_implication_retrospective,
             ),
             EquivalencePredictive(left, right) => self.format_statement(
                 out,
+                options,
                 left,
                 right,
-                self.statement.copula_equivalence_predictive,
+                statement.copula_equivalence_predictive,
             ),
-            EquivalenceConcurrent(left, right) => self.format_statement(
+            EquivalenceConcurrent(left, right) => {
+                let (left, right) = self.symmetric_sides(left, right, options);
+                self.format_statement(
+                    out,
+                    options,
+                    left,
+                    right,
+                    statement.copula_equivalence_concurrent,
+                )
+            }
+            EquivalenceRetrospective(left, right) => self.format_statement(
                 out,
+                options,
                 left,
                 right,
-                self.statement.copula_equivalence_concurrent,
-            ), // ! 「回顾性等价」未有
+                statement.copula_equivalence_retrospective,
+            ),
         }
     }
 
     /// 工具函数/浮点序列
     fn format_floats(
         &self,
-        out: &mut String,
-        bracket_left: &str,
-        bracket_right: &str,
+        out: &mut impl Write,
+        (bracket_left, bracket_right): (&str, &str),
         separator: &str,
         floats: &[FloatPrecision],
-    ) {
-        out.push_str(bracket_left);
+        precision: Option<usize>,
+        strip_trailing_zeros: bool,
+    ) -> fmt::Result {
+        out.write_str(bracket_left)?;
         for (i, f) in floats.iter().enumerate() {
             // 分隔符
             if i != 0 {
-                out.push_str(separator);
+                out.write_str(separator)?;
                 // out.push_str(self.space); // * 目前在OpenNARS、PyNARS中均未使用分隔符
             }
-            out.push_str(&f.to_string());
+            template_float_to(out, *f, precision, strip_trailing_zeros)?;
         }
-        out.push_str(bracket_right);
+        out.write_str(bracket_right)
     }
 
     /// 工具函数/浮点序列/真值
-    fn format_floats_truth(&self, out: &mut String, floats: &[FloatPrecision]) {
+    fn format_floats_truth(&self, out: &mut impl Write, floats: &[FloatPrecision]) -> fmt::Result {
         self.format_floats(
             out,
-            self.sentence.truth_brackets.0,
-            self.sentence.truth_brackets.1,
+            self.sentence.truth_brackets,
             self.sentence.truth_separator,
             floats,
-        );
+            self.sentence.truth_float_precision,
+            self.sentence.truth_strip_trailing_zeros,
+        )
     }
 
     /// 工具函数/浮点序列/预算值
-    fn format_floats_budget(&self, out: &mut String, floats: &[FloatPrecision]) {
+    fn format_floats_budget(&self, out: &mut impl Write, floats: &[FloatPrecision]) -> fmt::Result {
         self.format_floats(
             out,
-            self.task.budget_brackets.0,
-            self.task.budget_brackets.1,
+            self.task.budget_brackets,
             self.task.budget_separator,
             floats,
-        );
+            self.task.budget_float_precision,
+            self.task.budget_strip_trailing_zeros,
+        )
     }
 
     /// 格式化函数/真值
     pub fn format_truth(&self, truth: &Truth) -> String {
-        let mut out = String::new();
-        self._format_truth(&mut out, truth);
-        out
+        format_to_string(|out| self._format_truth(out, truth))
     }
 
     /// 总格式化函数/真值
-    fn _format_truth(&self, out: &mut String, truth: &Truth) {
+    fn _format_truth(&self, out: &mut impl Write, truth: &Truth) -> fmt::Result {
         use Truth::*;
         match truth {
             // 空真值⇒直接为空
-            Empty => {}
+            Empty => Ok(()),
             // 单真值⇒单元素数组
             Single(f) => self.format_floats_truth(out, &[*f]),
             // 双真值⇒二元数组
             Double(f, c) => self.format_floats_truth(out, &[*f, *c]),
+            // 三真值⇒三元数组
+            Triple(f, c, k) => self.format_floats_truth(out, &[*f, *c, *k]),
         }
     }
 
     /// 格式化函数/时间戳
     pub fn format_stamp(&self, stamp: &Stamp) -> String {
-        let mut out = String::new();
-        self._format_stamp(&mut out, stamp);
-        out
+        format_to_string(|out| self._format_stamp(out, stamp))
     }
 
     /// 总格式化函数/时间戳
-    fn _format_stamp(&self, out: &mut String, stamp: &Stamp) {
+    fn _format_stamp(&self, out: &mut impl Write, stamp: &Stamp) -> fmt::Result {
         // 永恒⇒无内容
         if stamp.is_eternal() {
-            return;
+            return Ok(());
         }
         // 括号开始
-        out.push_str(self.sentence.stamp_brackets.0);
+        out.write_str(self.sentence.stamp_brackets.0)?;
         // 添加内容
         use Stamp::*;
         match stamp {
-            Past => out.push_str(self.sentence.stamp_past),
-            Present => out.push_str(self.sentence.stamp_present),
-            Future => out.push_str(self.sentence.stamp_future),
-            Fixed(time) => {
-                out.push_str(self.sentence.stamp_fixed);
-                out.push_str(&time.to_string());
-            }
+            Past => out.write_str(self.sentence.stamp_past)?,
+            Present => out.write_str(self.sentence.stamp_present)?,
+            Future => out.write_str(self.sentence.stamp_future)?,
+            Fixed(time) => write!(out, "{}{time}", self.sentence.stamp_fixed)?,
             // * 这里实际上无需处理：默认为 Eternal
             Eternal => {}
         }
         // 括号结束
-        out.push_str(self.sentence.stamp_brackets.1);
+        out.write_str(self.sentence.stamp_brackets.1)
     }
 
     /// 总格式化函数/标点
     pub fn format_punctuation(&self, punctuation: &Punctuation) -> String {
-        manipulate!(
-            String::new()
-            => [self._format_punctuation](_, punctuation)
-        )
+        format_to_string(|out| self._format_punctuation(out, punctuation))
     }
 
     /// 格式化函数/标点
-    fn _format_punctuation(&self, out: &mut String, punctuation: &Punctuation) {
+    fn _format_punctuation(&self, out: &mut impl Write, punctuation: &Punctuation) -> fmt::Result {
         use Punctuation::*;
-        out.push_str(match punctuation {
+        out.write_str(match punctuation {
             Judgement => self.sentence.punctuation_judgement,
             Goal => self.sentence.punctuation_goal,
             Question => self.sentence.punctuation_question,
@@ -365,33 +465,63 @@ impl NarseseFormat<&str> {
     ///
     /// ! ⚠️注意：没有独立的「标点」一说
     pub fn format_sentence(&self, sentence: &Sentence) -> String {
-        catch_flow!(self._format_sentence; sentence)
+        self.format_sentence_opts(sentence, &FormatOptions::default())
+    }
+
+    /// 格式化函数/语句（带选项）
+    pub fn format_sentence_opts(&self, sentence: &Sentence, options: &FormatOptions) -> String {
+        format_to_string(|out| self.format_sentence_opts_to(sentence, options, out))
+    }
+
+    /// 格式化函数/语句（写入器）
+    pub fn format_sentence_to(&self, sentence: &Sentence, out: &mut impl Write) -> fmt::Result {
+        self.format_sentence_opts_to(sentence, &FormatOptions::default(), out)
+    }
+
+    /// 格式化函数/语句（带选项、写入器）
+    pub fn format_sentence_opts_to(
+        &self,
+        sentence: &Sentence,
+        options: &FormatOptions,
+        out: &mut impl Write,
+    ) -> fmt::Result {
+        options.wrap_math_to(out, |out| self._format_sentence(out, sentence, options))
     }
 
     /// 总格式化函数/语句
-    fn _format_sentence(&self, out: &mut String, sentence: &Sentence) {
-        template_sentence(
+    /// * 🚩永恒时间戳、空真值⇒不输出，亦不添加分隔用空格
+    fn _format_sentence<W: Write>(
+        &self,
+        out: &mut W,
+        sentence: &Sentence,
+        options: &FormatOptions,
+    ) -> fmt::Result {
+        let stamp = sentence.get_stamp();
+        // 真值 | 默认空真值（对「问题」「请求」而言）
+        let truth = sentence.get_truth().unwrap_or(&Truth::Empty);
+        template_sentence_to(
             out,
             // 词项
-            &catch_flow!(self._format_term; &sentence.get_term()),
+            |out| self._format_term(out, sentence.get_term(), options),
             // 标点
-            &catch_flow!(self._format_punctuation; &sentence.get_punctuation()),
+            |out| self._format_punctuation(out, sentence.get_punctuation()),
             // 时间戳
-            &catch_flow!(self._format_stamp; &sentence.get_stamp()),
-            // 真值 | 默认空真值（对「问题」「请求」而言）
-            &catch_flow!(self._format_truth; &sentence.get_truth().unwrap_or(&Truth::Empty)),
+            (!stamp.is_eternal()).then_some(|out: &mut W| self._format_stamp(out, stamp)),
+            // 真值
+            (!matches!(truth, Truth::Empty))
+                .then_some(|out: &mut W| self._format_truth(out, truth)),
             // 分隔用空格
-            self.space.format_terms,
-        );
+            options.item_space(self.space.format_terms),
+        )
     }
 
     /// 格式化函数/预算值
     pub fn format_budget(&self, budget: &Budget) -> String {
-        catch_flow!(self._format_budget; budget)
+        format_to_string(|out| self._format_budget(out, budget))
     }
 
     /// 总格式化函数/预算值
-    fn _format_budget(&self, out: &mut String, budget: &Budget) {
+    fn _format_budget(&self, out: &mut impl Write, budget: &Budget) -> fmt::Result {
         use Budget::*;
         match budget {
             // 空预算⇒空数组，仅含括弧 // ! 若无括弧，解析器将识别成语句
@@ -407,36 +537,86 @@ impl NarseseFormat<&str> {
 
     /// 格式化函数/任务
     pub fn format_task(&self, task: &Task) -> String {
-        catch_flow!(self._format_task; task)
+        self.format_task_opts(task, &FormatOptions::default())
+    }
+
+    /// 格式化函数/任务（带选项）
+    pub fn format_task_opts(&self, task: &Task, options: &FormatOptions) -> String {
+        format_to_string(|out| self.format_task_opts_to(task, options, out))
+    }
+
+    /// 格式化函数/任务（写入器）
+    /// * 🎯批量格式化：如将大量任务逐行写入同一缓冲区
+    pub fn format_task_to(&self, task: &Task, out: &mut impl Write) -> fmt::Result {
+        self.format_task_opts_to(task, &FormatOptions::default(), out)
+    }
+
+    /// 格式化函数/任务（带选项、写入器）
+    pub fn format_task_opts_to(
+        &self,
+        task: &Task,
+        options: &FormatOptions,
+        out: &mut impl Write,
+    ) -> fmt::Result {
+        options.wrap_math_to(out, |out| self._format_task(out, task, options))
     }
 
     /// 总格式化函数/任务
-    fn _format_task(&self, out: &mut String, task: &Task) {
-        // 临时缓冲区 | 用于「有内容⇒添加空格」的逻辑
-        let mut buffer = String::new();
+    /// * 📌语句总有词项与标点，故总是非空：直接添加分隔用空格
+    fn _format_task(
+        &self,
+        out: &mut impl Write,
+        task: &Task,
+        options: &FormatOptions,
+    ) -> fmt::Result {
         // 预算值
-        self._format_budget(out, task.get_budget());
-        // 语句
-        self._format_sentence(&mut buffer, task.get_sentence());
+        self._format_budget(out, task.get_budget())?;
         // 添加空格
-        add_space_if_necessary_and_flush_buffer(out, &mut buffer, self.space.format_items);
+        out.write_str(options.item_space(self.space.format_items))?;
+        // 语句
+        self._format_sentence(out, task.get_sentence(), options)
     }
 
     /// 格式化函数/Narsese
     /// * 🚩自动分派
     pub fn format_narsese(&self, narsese: &Narsese) -> String {
-        catch_flow!(self._format_narsese; narsese)
+        self.format_narsese_opts(narsese, &FormatOptions::default())
+    }
+
+    /// 格式化函数/Narsese（带选项）
+    pub fn format_narsese_opts(&self, narsese: &Narsese, options: &FormatOptions) -> String {
+        format_to_string(|out| self.format_narsese_opts_to(narsese, options, out))
+    }
+
+    /// 格式化函数/Narsese（写入器）
+    pub fn format_narsese_to(&self, narsese: &Narsese, out: &mut impl Write) -> fmt::Result {
+        self.format_narsese_opts_to(narsese, &FormatOptions::default(), out)
+    }
+
+    /// 格式化函数/Narsese（带选项、写入器）
+    pub fn format_narsese_opts_to(
+        &self,
+        narsese: &Narsese,
+        options: &FormatOptions,
+        out: &mut impl Write,
+    ) -> fmt::Result {
+        options.wrap_math_to(out, |out| self._format_narsese(out, narsese, options))
     }
 
     /// 总格式化函数/Narsese
-    fn _format_narsese(&self, out: &mut String, narsese: &Narsese) {
+    fn _format_narsese(
+        &self,
+        out: &mut impl Write,
+        narsese: &Narsese,
+        options: &FormatOptions,
+    ) -> fmt::Result {
         match narsese {
             // 词项
-            Narsese::Term(term) => self._format_term(out, term),
+            Narsese::Term(term) => self._format_term(out, term, options),
             // 语句
-            Narsese::Sentence(sentence) => self._format_sentence(out, sentence),
+            Narsese::Sentence(sentence) => self._format_sentence(out, sentence, options),
             // 任务
-            Narsese::Task(task) => self._format_task(out, task),
+            Narsese::Task(task) => self._format_task(out, task, options),
         }
     }
 
@@ -497,6 +677,30 @@ impl FormatTo<&NarseseFormat<&str>, String> for Task {
 
 // * ✅Narsese的格式化接口已自动实现
 
+/// 批量实现[`Display`]
+/// * 🎯让枚举Narsese可直接用于`println!("{}")`、报错信息等场合
+/// * 🚩统一委托到[`FORMAT_ASCII`]
+///   * 📌直接写入[`Formatter`]，不经由中间字符串
+///   * 📌输出与`FORMAT_ASCII.format_xxx`完全一致，可被`FORMAT_ASCII.parse`解析回原值
+macro_rules! impl_display_ascii {
+    ($($t:ty => $f:ident)*) => {
+        $(
+            impl Display for $t {
+                fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    FORMAT_ASCII.$f(self, f)
+                }
+            }
+        )*
+    };
+}
+
+impl_display_ascii! {
+    Term => format_term_to
+    Sentence => format_sentence_to
+    Task => format_task_to
+    Narsese => format_narsese_to
+}
+
 /// 单元测试
 #[cfg(test)]
 mod test {
@@ -504,7 +708,8 @@ mod test {
         super::{format_instances::*, tests::_sample_task},
         *,
     };
-    use nar_dev_utils::{f_parallel, show};
+    use crate::{conversion::string::MathMode, enum_narsese::tests::generate_term_testset};
+    use nar_dev_utils::{asserts, f_parallel, show};
 
     /// 测试其中一个格式
     fn _test(format: NarseseFormat<&str>, name: &str, expected: &str) {
@@ -520,6 +725,41 @@ mod test {
         assert_eq!(formatted, expected);
     }
 
+    /// 测试/[`Display`]实现
+    /// * 🎯所有种类的词项均能呈现，且可被ASCII格式解析回原值
+    #[test]
+    fn test_display() {
+        for term in generate_term_testset() {
+            let displayed = term.to_string();
+            show!(&displayed);
+            assert_eq!(displayed, FORMAT_ASCII.format_term(&term));
+            let parsed = FORMAT_ASCII
+                .parse::<Narsese>(&displayed)
+                .expect("词项解析失败")
+                .try_into_term()
+                .expect("不是词项");
+            assert_eq!(parsed, term);
+        }
+        // 像的占位符、集合的括弧
+        asserts! {
+            Term::new_image_extension(1, vec![Term::new_word("A"), Term::new_word("B")]).to_string()
+                => "(/, A, _, B)",
+            Term::new_image_intension(0, vec![Term::new_word("A"), Term::new_word("B")]).to_string()
+                => r"(\, _, A, B)",
+            Term::new_set_extension(vec![Term::new_word("A")]).to_string() => "{A}",
+            Term::new_set_intension(vec![Term::new_word("A")]).to_string() => "[A]",
+        }
+        // 语句、任务、Narsese值
+        let task = _sample_task();
+        let sentence = task.get_sentence().clone();
+        asserts! {
+            task.to_string() => FORMAT_ASCII.format_task(&task),
+            sentence.to_string() => FORMAT_ASCII.format_sentence(&sentence),
+            Narsese::Task(task.clone()).to_string() => FORMAT_ASCII.format_task(&task),
+            FORMAT_ASCII.parse::<Narsese>(&task.to_string()).unwrap() => Narsese::Task(task),
+        }
+    }
+
     #[test]
     fn test() {
         // 平行测试
@@ -530,4 +770,268 @@ mod test {
             FORMAT_HAN   "漢"      "预0.5、0.75、0.4算 「（接连，「『ball』是【left】」，「（积，『SELF』，任一any，其一some）是操作do」）得「『SELF』是【good】」」。发生在-1真1、0.9值";
         ];
     }
+
+    /// 测试/格式化选项
+    /// * 🎯紧凑、美观输出均可被解析回原值
+    #[test]
+    fn test_format_options() {
+        let task = _sample_task();
+        let compact = FORMAT_ASCII.format_task_opts(&task, &FormatOptions::COMPACT);
+        show!(&compact);
+        asserts! {
+            compact => "$0.5;0.75;0.4$<(&/,<{ball}-->[left]>,<(*,{SELF},$any,#some)-->^do>)==><{SELF}-->[good]>>.:!-1:%1;0.9%",
+            FORMAT_ASCII.format_task_opts(&task, &FormatOptions::PRETTY) => FORMAT_ASCII.format_task(&task),
+        }
+        // 往返
+        for format in [FORMAT_ASCII, FORMAT_HAN] {
+            for options in [FormatOptions::COMPACT, FormatOptions::PRETTY] {
+                let formatted = format.format_task_opts(&task, &options);
+                show!(&formatted);
+                let parsed = format.parse::<Narsese>(&formatted).unwrap();
+                assert_eq!(parsed, Narsese::Task(task.clone()));
+            }
+        }
+        for term in generate_term_testset() {
+            let formatted = FORMAT_ASCII.format_term_opts(&term, &FormatOptions::COMPACT);
+            let parsed = FORMAT_ASCII.parse::<Narsese>(&formatted).unwrap();
+            assert_eq!(parsed.try_into_term().unwrap(), term);
+        }
+    }
+
+    /// 测试/真值、预算值的小数位数
+    /// * 🎯OpenNARS风格`%1.00;0.90%`与PyNARS风格`%1;0.9%`
+    #[test]
+    fn test_float_precision() {
+        let truth = Truth::Double(1.0, 0.9);
+        let budget = Budget::Triple(0.5, 0.75, 0.4);
+        // 默认：保持原有行为
+        asserts! {
+            FORMAT_ASCII.format_truth(&truth) => "%1;0.9%",
+            FORMAT_ASCII.format_budget(&budget) => "$0.5;0.75;0.4$",
+        }
+        // 固定两位小数
+        let mut format = FORMAT_ASCII;
+        format.sentence.truth_float_precision = Some(2);
+        format.task.budget_float_precision = Some(2);
+        asserts! {
+            format.format_truth(&truth) => "%1.00;0.90%",
+            format.format_budget(&budget) => "$0.50;0.75;0.40$",
+        }
+        // 去除末尾的`0`
+        format.sentence.truth_strip_trailing_zeros = true;
+        format.task.budget_strip_trailing_zeros = true;
+        asserts! {
+            format.format_truth(&truth) => "%1;0.9%",
+            format.format_budget(&budget) => "$0.5;0.75;0.4$",
+        }
+        // 解析不受影响
+        format.sentence.truth_strip_trailing_zeros = false;
+        let sentence = Sentence::new_judgement(Term::new_word("A"), truth, Stamp::Eternal);
+        let formatted = format.format_sentence(&sentence);
+        asserts! {
+            formatted => "A. %1.00;0.90%",
+            format.parse::<Narsese>(&formatted).unwrap() => Narsese::Sentence(sentence),
+        }
+    }
+
+    /// 测试/LaTeX中的间隔与操作符
+    #[test]
+    fn test_latex_interval_operator() {
+        let term = Term::new_conjunction_sequential(vec![
+            Term::new_interval(137),
+            Term::new_inheritance(
+                Term::new_product(vec![Term::new_word("SELF")]),
+                Term::new_operator("go"),
+            ),
+        ]);
+        let formatted = FORMAT_LATEX.format_term(&term);
+        asserts! {
+            formatted => r"\left(,\; +137\; \left<\left(\times{}\; SELF\right) \rightarrow{} \Uparrow{}go\right>\right)",
+            FORMAT_LATEX.parse::<Narsese>(&formatted).unwrap() => Narsese::Term(term),
+        }
+    }
+
+    /// 测试/数学模式
+    /// * 🎯只包裹最终输出；去除包裹符后可被解析回原值
+    #[test]
+    fn test_math_mode() {
+        let task = _sample_task();
+        let sentence = task.get_sentence().clone();
+        let term = task.get_term().clone();
+        let plain = FORMAT_LATEX.format_task(&task);
+        for mode in [MathMode::Inline, MathMode::Display] {
+            let options = FormatOptions::PRETTY.with_math_mode(mode);
+            let (left, right) = mode.delimiters();
+            let formatted = FORMAT_LATEX.format_task_opts(&task, &options);
+            show!(&formatted);
+            asserts! {
+                formatted => format!("{left}{plain}{right}"),
+                MathMode::strip(&formatted) => plain,
+                // 组分不会被单独包裹
+                FORMAT_LATEX.format_term_opts(&term, &options) => mode.wrap(&FORMAT_LATEX.format_term(&term)),
+            }
+            // 往返
+            let parsed = [
+                FORMAT_LATEX.format_task_opts(&task, &options),
+                FORMAT_LATEX.format_sentence_opts(&sentence, &options),
+                FORMAT_LATEX.format_term_opts(&term, &options),
+            ]
+            .map(|formatted| {
+                FORMAT_LATEX
+                    .parse::<Narsese>(MathMode::strip(&formatted))
+                    .unwrap()
+            });
+            asserts! {
+                parsed[0] => Narsese::Task(task.clone()),
+                parsed[1] => Narsese::Sentence(sentence.clone()),
+                parsed[2] => Narsese::Term(term.clone()),
+            }
+        }
+        // 未包裹⇒原样返回
+        asserts! {
+            MathMode::strip(" A. ") => "A.",
+        }
+    }
+
+    /// 测试/写入器版本
+    /// * 🎯与固定的期望字符串逐字节一致（不依赖返回字符串的版本）
+    /// * 🎯与返回字符串的版本逐字节一致
+    /// * 🎯批量写入同一缓冲区：结果即各次输出的拼接
+    #[test]
+    fn test_format_to() {
+        // 固定期望
+        let format_to = |format: &NarseseFormat<&str>, input: &str, options: &FormatOptions| {
+            let task = FORMAT_ASCII
+                .parse::<Narsese>(input)
+                .unwrap()
+                .try_into_task()
+                .unwrap();
+            let mut buffer = String::new();
+            format
+                .format_task_opts_to(&task, options, &mut buffer)
+                .unwrap();
+            buffer
+        };
+        let inline = FormatOptions::PRETTY.with_math_mode(MathMode::Inline);
+        asserts! {
+            format_to(&FORMAT_ASCII, "$0.5$ <A --> B>? :|:", &FormatOptions::PRETTY) => "$0.5$ <A --> B>? :|:",
+            format_to(&FORMAT_ASCII, "$0.5$ <A --> B>? :|:", &FormatOptions::COMPACT) => "$0.5$<A-->B>?:|:",
+            format_to(&FORMAT_ASCII, "$0.5$ <A --> B>? :|:", &inline) => "$$0.5$ <A --> B>? :|:$",
+            format_to(&FORMAT_LATEX, "$0.5$ <A --> B>? :|:", &FormatOptions::PRETTY)
+                => r"\$0.5\$ \left<A \rightarrow{} B\right>? |\!\!\!\!\!\Rightarrow{}",
+            format_to(&FORMAT_LATEX, "$$ (--, A)! %0.5%", &FormatOptions::COMPACT)
+                => r"\$\$\left(\neg{}\;A\right)!\langle{}0.5\rangle{}",
+            format_to(&FORMAT_HAN, "$0.5$ <A --> B>? :|:", &FormatOptions::PRETTY) => "预0.5算 「A是B」？现在",
+            format_to(&FORMAT_HAN, "$$ (--, A)! %0.5%", &FormatOptions::COMPACT) => "预算（非，A）！真0.5值",
+        }
+        // 与返回字符串的版本对照
+        let tasks = [
+            _sample_task(),
+            FORMAT_ASCII
+                .parse::<Narsese>("$0.5$ <A --> B>? :|:")
+                .unwrap()
+                .try_into_task()
+                .unwrap(),
+            FORMAT_ASCII
+                .parse::<Narsese>("$$ (--, A)! %0.5%")
+                .unwrap()
+                .try_into_task()
+                .unwrap(),
+        ];
+        let options = [
+            FormatOptions::PRETTY,
+            FormatOptions::COMPACT,
+            FormatOptions::PRETTY.with_math_mode(MathMode::Inline),
+        ];
+        for format in [&FORMAT_ASCII, &FORMAT_LATEX, &FORMAT_HAN] {
+            for options in &options {
+                // 缓冲区复用：每次先清空
+                let mut buffer = String::new();
+                let mut expected_all = String::new();
+                let mut all = String::new();
+                for task in &tasks {
+                    let sentence = task.get_sentence();
+                    let narsese = Narsese::Task(task.clone());
+                    buffer.clear();
+                    format
+                        .format_task_opts_to(task, options, &mut buffer)
+                        .unwrap();
+                    assert_eq!(buffer, format.format_task_opts(task, options));
+                    buffer.clear();
+                    format
+                        .format_sentence_opts_to(sentence, options, &mut buffer)
+                        .unwrap();
+                    assert_eq!(buffer, format.format_sentence_opts(sentence, options));
+                    buffer.clear();
+                    format
+                        .format_term_opts_to(sentence.get_term(), options, &mut buffer)
+                        .unwrap();
+                    assert_eq!(
+                        buffer,
+                        format.format_term_opts(sentence.get_term(), options)
+                    );
+                    buffer.clear();
+                    format
+                        .format_narsese_opts_to(&narsese, options, &mut buffer)
+                        .unwrap();
+                    assert_eq!(buffer, format.format_narsese_opts(&narsese, options));
+                    // 逐行追加
+                    format.format_task_opts_to(task, options, &mut all).unwrap();
+                    all.push('\n');
+                    expected_all += &format.format_task_opts(task, options);
+                    expected_all.push('\n');
+                }
+                assert_eq!(all, expected_all);
+            }
+        }
+        // 默认选项
+        let task = &tasks[0];
+        let mut buffer = String::new();
+        FORMAT_ASCII.format_task_to(task, &mut buffer).unwrap();
+        asserts! {
+            buffer => FORMAT_ASCII.format_task(task),
+            buffer => task.to_string(),
+        }
+    }
+
+    /// 测试/确定性顺序
+    /// * 🎯同一无序容器以不同顺序构造，格式化结果逐字节一致
+    #[test]
+    fn test_canonical_order() {
+        let format = NarseseFormat {
+            canonical_order: true,
+            ..FORMAT_ASCII
+        };
+        let words = |names: &[&str]| {
+            names
+                .iter()
+                .map(|&name| Term::new_word(name))
+                .collect::<Vec<_>>()
+        };
+        let conjunction = |names: &[&str]| Term::new_conjunction(words(names));
+        let nested = |names: &[&str]| {
+            Term::new_equivalence(
+                Term::new_set_extension(words(names)),
+                Term::new_similarity(conjunction(names), Term::new_word("A")),
+            )
+        };
+        let orders: [&[&str]; 3] = [&["A", "B", "C"], &["C", "B", "A"], &["B", "C", "A"]];
+        for names in orders {
+            asserts! {
+                format.format_term(&conjunction(names)) => "(&&, A, B, C)",
+                format.format_term(&nested(names)) => "<<(&&, A, B, C) <-> A> <=> {A, B, C}>",
+            }
+        }
+        // 不修改输入；解析回来与原词项相等
+        for original in [conjunction(&["C", "A", "B"]), nested(&["C", "A", "B"])] {
+            let backup = original.clone();
+            let canonical = format.format_term(&original);
+            asserts! {
+                original => backup,
+                format.parse::<Narsese>(&canonical).unwrap() => Narsese::Term(original.clone()),
+                format.parse::<Narsese>(&canonical).unwrap()
+                    => format.parse::<Narsese>(&FORMAT_ASCII.format_term(&original)).unwrap(),
+            }
+        }
+    }
 }
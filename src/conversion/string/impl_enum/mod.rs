@@ -24,6 +24,10 @@ pub mod format_instances;
 // 实用宏
 mod macros;
 
+// 注释
+// * 🚩直接对「枚举Narsese格式」实现方法，没有需导出的内容
+mod comment;
+
 /// 快捷方式
 pub mod shortcuts {
     pub use crate::{
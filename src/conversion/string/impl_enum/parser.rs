@@ -24,14 +24,22 @@
 //!     * ✨被称作「解析环境」
 //!   * 📌解析函数总是从某个「起始位置」开始，通过系列解析过程，返回「解析结果」以及
 //!     * ✨有相应的「结果索引」类型
+//!   * ✨「解析环境」可替换为`&str`：零拷贝解析，见[`NarseseFormat::parse_str_fast`]
 
-use super::format::*;
+use super::{format::*, format_instances::FORMAT_ASCII};
 use crate::{
-    api::{FloatPrecision, FromParse, IntPrecision, NarseseOptions, UIntPrecision},
+    api::{
+        FloatPrecision, FromParse, GetCapacity, IntPrecision, ItemKind, Located, NarseseOptions,
+        ParsedBatch, TermCapacity, UIntPrecision,
+    },
+    conversion::string::{
+        line_column, located_lines, AmbiguityResolution, ParseErrorKind,
+        AMBIGUOUS_QUERY_VARIABLE_MESSAGE, DEFAULT_COMMENT_PREFIXES, IMAGE_PLACEHOLDERS_MESSAGE, MAX_DEPTH_MESSAGE,
+    },
     enum_narsese::*,
 };
 use nar_dev_utils::*;
-use std::{error::Error, fmt::Display, io::ErrorKind};
+use std::{error::Error, fmt::Display, sync::Arc};
 
 /// 特化「CommonNarsese结果」到「枚举Narsese」版本
 /// * 🎯用于存储「最终被解析出来的CommonNarsese对象」
@@ -40,43 +48,7 @@ use std::{error::Error, fmt::Display, io::ErrorKind};
 ///   * 任务
 pub type NarseseResult = Narsese;
 
-// 实现`(try_)From/To`转换方法
-impl TryFrom<NarseseResult> for Term {
-    type Error = std::io::Error;
-    fn try_from(value: NarseseResult) -> Result<Self, Self::Error> {
-        match value {
-            NarseseResult::Term(term) => Ok(term),
-            _ => Err(Self::Error::new(
-                ErrorKind::InvalidData,
-                format!("类型不匹配，无法转换为词项：{value:?}"),
-            )),
-        }
-    }
-}
-impl TryFrom<NarseseResult> for Sentence {
-    type Error = std::io::Error;
-    fn try_from(value: NarseseResult) -> Result<Self, Self::Error> {
-        match value {
-            NarseseResult::Sentence(sentence) => Ok(sentence),
-            _ => Err(Self::Error::new(
-                ErrorKind::InvalidData,
-                format!("类型不匹配，无法转换为语句：{value:?}"),
-            )),
-        }
-    }
-}
-impl TryFrom<NarseseResult> for Task {
-    type Error = std::io::Error;
-    fn try_from(value: NarseseResult) -> Result<Self, Self::Error> {
-        match value {
-            NarseseResult::Task(task) => Ok(task),
-            _ => Err(Self::Error::new(
-                ErrorKind::InvalidData,
-                format!("类型不匹配，无法转换为任务：{value:?}"),
-            )),
-        }
-    }
-}
+// * 📌`(try_)From/To`转换方法：统一在`crate::enum_narsese`中实现
 
 /// 定义「CommonNarsese组分」的结构
 /// * 🎯用于存储「中间解析结果」
@@ -98,6 +70,345 @@ type ParseEnv<T = char> = Vec<T>;
 /// 用于表征「解析索引」
 type ParseIndex = UIntPrecision;
 
+/// 「解析环境」的抽象
+/// * 🎯让同一套解析逻辑运行在不同的「环境表示」之上
+///   * 📌[`ParseEnv`]（字符数组）：解析前整体收集，索引按字符计
+///   * 📌`&str`（字符串切片）：零拷贝，索引按字节计
+/// * 🚩「解析索引」的单位由环境自身决定
+///   * 📌报错时统一换算为「字符数组+字符索引」，以保证两者的错误信息一致
+pub(crate) trait ParseEnvironment<'a> {
+    /// 从输入构造环境
+    fn build(input: &'a str) -> Self;
+
+    /// 环境长度（按索引单位计）
+    fn len_env(&self) -> UIntPrecision;
+
+    /// 判断输入是否「为空」
+    /// * 📄参见[`is_empty_input`]
+    fn is_empty_input(&self) -> bool;
+
+    /// 获取指定索引处的字符
+    /// * ⚠️未检查边界，可能会panic
+    fn char_at(&self, index: ParseIndex) -> char;
+
+    /// 指定索引处的字符所占的索引单位数
+    /// * ⚠️未检查边界，可能会panic
+    fn char_len_at(&self, index: ParseIndex) -> UIntPrecision;
+
+    /// 字符串所占的索引单位数
+    fn len_of(s: &str) -> UIntPrecision;
+
+    /// 判断环境是否在指定索引处以指定字符串开头
+    fn starts_with_at(&self, index: ParseIndex, to_compare: &str) -> bool;
+
+    /// 匹配「语法元素」时是否用到字符池
+    /// * 🎯用不到时，构造[`FormatTokens`]可省去字符池的分配
+    const USES_TOKEN_CHARS: bool;
+
+    /// 判断环境是否在指定索引处以指定「语法元素」开头
+    /// * 🎯热点路径：使用预计算的[`FormatTokens`]，避免反复解码格式字串
+    fn starts_with_token_at(&self, index: ParseIndex, token: &Token, tokens: &FormatTokens) -> bool;
+
+    /// 「语法元素」所占的索引单位数
+    fn len_of_token(token: &Token) -> UIntPrecision;
+
+    /// 换算为「字符数组+字符索引」
+    /// * 🎯用于生成[`ParseError`]
+    fn to_chars_and_index(&self, index: ParseIndex) -> (ParseEnv, ParseIndex);
+}
+
+/// 字符数组：索引按字符计
+impl<'a> ParseEnvironment<'a> for ParseEnv {
+    #[inline(always)]
+    fn build(input: &'a str) -> Self {
+        input.chars().collect()
+    }
+
+    #[inline(always)]
+    fn len_env(&self) -> UIntPrecision {
+        self.len()
+    }
+
+    fn is_empty_input(&self) -> bool {
+        is_empty_input(self.iter().copied())
+    }
+
+    #[inline(always)]
+    fn char_at(&self, index: ParseIndex) -> char {
+        self[index]
+    }
+
+    #[inline(always)]
+    fn char_len_at(&self, _: ParseIndex) -> UIntPrecision {
+        1
+    }
+
+    #[inline(always)]
+    fn len_of(s: &str) -> UIntPrecision {
+        s.chars().count()
+    }
+
+    fn starts_with_at(&self, index: ParseIndex, to_compare: &str) -> bool {
+        // 长度检验
+        if self.len() < index + to_compare.chars().count() {
+            // 长度不够⇒肯定不匹配
+            return false;
+        }
+        // 逐个字符比较
+        for (i, c) in to_compare.chars().enumerate() {
+            if self[index + i] != c {
+                return false;
+            }
+        }
+        true
+    }
+
+    const USES_TOKEN_CHARS: bool = true;
+
+    /// * 🚩先比较首字符，再比较字符切片
+    #[inline(always)]
+    fn starts_with_token_at(&self, index: ParseIndex, token: &Token, tokens: &FormatTokens) -> bool {
+        // 首字符不同⇒快速排除
+        if let Some(first) = token.first {
+            if self.get(index) != Some(&first) {
+                return false;
+            }
+        }
+        self.get(index..index + token.len)
+            .is_some_and(|chars| chars == tokens.chars_of(token))
+    }
+
+    #[inline(always)]
+    fn len_of_token(token: &Token) -> UIntPrecision {
+        token.len
+    }
+
+    fn to_chars_and_index(&self, index: ParseIndex) -> (ParseEnv, ParseIndex) {
+        (self.clone(), index)
+    }
+}
+
+/// 字符串切片：索引按字节计
+/// * 🎯批量解析大量短输入时，省去每次解析的「字符数组」分配
+/// * 📌索引总是落在字符边界上：递进时按当前字符的UTF-8长度跳过
+impl<'a> ParseEnvironment<'a> for &'a str {
+    #[inline(always)]
+    fn build(input: &'a str) -> Self {
+        input
+    }
+
+    #[inline(always)]
+    fn len_env(&self) -> UIntPrecision {
+        self.len()
+    }
+
+    fn is_empty_input(&self) -> bool {
+        is_empty_input(self.chars())
+    }
+
+    /// * 📌越界或不在字符边界上⇒返回`'\0'`，而非panic
+    #[inline(always)]
+    fn char_at(&self, index: ParseIndex) -> char {
+        self.get(index..)
+            .and_then(|rest| rest.chars().next())
+            .unwrap_or('\0')
+    }
+
+    #[inline(always)]
+    fn char_len_at(&self, index: ParseIndex) -> UIntPrecision {
+        self.char_at(index).len_utf8()
+    }
+
+    #[inline(always)]
+    fn len_of(s: &str) -> UIntPrecision {
+        s.len()
+    }
+
+    #[inline(always)]
+    fn starts_with_at(&self, index: ParseIndex, to_compare: &str) -> bool {
+        // 按字节比较 | 索引总在字符边界上，故与「按字符比较」结果一致
+        self.as_bytes()
+            .get(index..)
+            .is_some_and(|rest| rest.starts_with(to_compare.as_bytes()))
+    }
+
+    const USES_TOKEN_CHARS: bool = false;
+
+    /// * 🚩按字节比较原字串即可，无需字符池
+    #[inline(always)]
+    fn starts_with_token_at(&self, index: ParseIndex, token: &Token, _: &FormatTokens) -> bool {
+        self.starts_with_at(index, token.text)
+    }
+
+    #[inline(always)]
+    fn len_of_token(token: &Token) -> UIntPrecision {
+        token.text.len()
+    }
+
+    /// * 📌越界或不在字符边界上⇒索引取末尾
+    fn to_chars_and_index(&self, index: ParseIndex) -> (ParseEnv, ParseIndex) {
+        let chars = self.chars().collect::<ParseEnv>();
+        let index = match self.get(..index) {
+            Some(head) => head.chars().count(),
+            None => chars.len(),
+        };
+        (chars, index)
+    }
+}
+
+/// 格式中的「语法元素」
+/// * 🎯预先计算「字符数」与「首字符」，避免匹配时反复解码格式字串
+///   * 📌字符本身存放于[`FormatTokens`]的字符池中
+/// * 📌在解析器构造时一次性生成：格式在解析期间不变
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Token<'a> {
+    /// 原字串 | 供「字符串切片」环境按字节比较
+    text: &'a str,
+    /// 在字符池中的起始索引
+    start: usize,
+    /// 字符数 | 按字符池计，未填充字符池时为`0`
+    len: usize,
+    /// 首字符 | 空字串⇒[`None`]
+    first: Option<char>,
+}
+
+/// 格式中「语法元素」的预计算表
+/// * 🎯加速解析的热点路径：空白、括弧、原子词项前缀、复合词项连接符、陈述系词
+///   * 📌先比较首字符，再比较字符切片
+/// * 🚩所有字符共用一个字符池：构造时只分配一次
+pub(crate) struct FormatTokens<'a> {
+    /// 字符池
+    chars: Vec<char>,
+    // 空白 & 括弧 //
+    space: Token<'a>,
+    budget_brackets: (Token<'a>, Token<'a>),
+    stamp_brackets: (Token<'a>, Token<'a>),
+    truth_brackets: (Token<'a>, Token<'a>),
+    compound_brackets: (Token<'a>, Token<'a>),
+    set_extension_brackets: (Token<'a>, Token<'a>),
+    set_intension_brackets: (Token<'a>, Token<'a>),
+    statement_brackets: (Token<'a>, Token<'a>),
+    separator: Token<'a>,
+    // 原子词项前缀 //
+    prefix_placeholder: Token<'a>,
+    prefix_variable_independent: Token<'a>,
+    prefix_variable_dependent: Token<'a>,
+    prefix_variable_query: Token<'a>,
+    prefix_interval: Token<'a>,
+    prefix_operator: Token<'a>,
+    prefix_word: Token<'a>,
+    // 复合词项连接符 //
+    connecter_conjunction: Token<'a>,
+    connecter_disjunction: Token<'a>,
+    connecter_negation: Token<'a>,
+    connecter_conjunction_sequential: Token<'a>,
+    connecter_conjunction_parallel: Token<'a>,
+    connecter_intersection_extension: Token<'a>,
+    connecter_intersection_intension: Token<'a>,
+    connecter_difference_extension: Token<'a>,
+    connecter_difference_intension: Token<'a>,
+    connecter_product: Token<'a>,
+    connecter_image_extension: Token<'a>,
+    connecter_image_intension: Token<'a>,
+    /// 陈述系词 | 顺序同[`NarseseFormat::copulas`]
+    copulas: [Token<'a>; 13],
+}
+
+// 各系词在[`FormatTokens::copulas`]中的索引 | 顺序同[`NarseseFormat::copulas`]
+const COPULA_INHERITANCE: usize = 0;
+const COPULA_SIMILARITY: usize = 1;
+const COPULA_IMPLICATION: usize = 2;
+const COPULA_EQUIVALENCE: usize = 3;
+const COPULA_INSTANCE: usize = 4;
+const COPULA_PROPERTY: usize = 5;
+const COPULA_INSTANCE_PROPERTY: usize = 6;
+const COPULA_IMPLICATION_PREDICTIVE: usize = 7;
+const COPULA_IMPLICATION_CONCURRENT: usize = 8;
+const COPULA_IMPLICATION_RETROSPECTIVE: usize = 9;
+const COPULA_EQUIVALENCE_PREDICTIVE: usize = 10;
+const COPULA_EQUIVALENCE_CONCURRENT: usize = 11;
+const COPULA_EQUIVALENCE_RETROSPECTIVE: usize = 12;
+
+impl<'a> FormatTokens<'a> {
+    /// 从格式生成
+    /// * 📌`with_chars`为假⇒不填充字符池，只能按原字串匹配
+    pub fn new(format: &'a NarseseFormat<&'a str>, with_chars: bool) -> Self {
+        let mut chars = vec![];
+        let mut token = |text: &'a str| {
+            let start = chars.len();
+            if with_chars {
+                chars.extend(text.chars());
+            }
+            Token {
+                text,
+                start,
+                len: chars.len() - start,
+                first: text.chars().next(),
+            }
+        };
+        let space = token(format.space.parse);
+        let mut brackets = |(left, right): (&'a str, &'a str)| (token(left), token(right));
+        let budget_brackets = brackets(format.task.budget_brackets);
+        let stamp_brackets = brackets(format.sentence.stamp_brackets);
+        let truth_brackets = brackets(format.sentence.truth_brackets);
+        let compound_brackets = brackets(format.compound.brackets);
+        let set_extension_brackets = brackets(format.compound.brackets_set_extension);
+        let set_intension_brackets = brackets(format.compound.brackets_set_intension);
+        let statement_brackets = brackets(format.statement.brackets);
+        let atom = &format.atom;
+        let compound = &format.compound;
+        Self {
+            space,
+            budget_brackets,
+            stamp_brackets,
+            truth_brackets,
+            compound_brackets,
+            set_extension_brackets,
+            set_intension_brackets,
+            statement_brackets,
+            separator: token(compound.separator),
+            prefix_placeholder: token(atom.prefix_placeholder),
+            prefix_variable_independent: token(atom.prefix_variable_independent),
+            prefix_variable_dependent: token(atom.prefix_variable_dependent),
+            prefix_variable_query: token(atom.prefix_variable_query),
+            prefix_interval: token(atom.prefix_interval),
+            prefix_operator: token(atom.prefix_operator),
+            prefix_word: token(atom.prefix_word),
+            connecter_conjunction: token(compound.connecter_conjunction),
+            connecter_disjunction: token(compound.connecter_disjunction),
+            connecter_negation: token(compound.connecter_negation),
+            connecter_conjunction_sequential: token(compound.connecter_conjunction_sequential),
+            connecter_conjunction_parallel: token(compound.connecter_conjunction_parallel),
+            connecter_intersection_extension: token(compound.connecter_intersection_extension),
+            connecter_intersection_intension: token(compound.connecter_intersection_intension),
+            connecter_difference_extension: token(compound.connecter_difference_extension),
+            connecter_difference_intension: token(compound.connecter_difference_intension),
+            connecter_product: token(compound.connecter_product),
+            connecter_image_extension: token(compound.connecter_image_extension),
+            connecter_image_intension: token(compound.connecter_image_intension),
+            copulas: format.copulas().map(token),
+            chars,
+        }
+    }
+
+    /// 获取「语法元素」的字符切片
+    #[inline(always)]
+    fn chars_of(&self, token: &Token) -> &[char] {
+        &self.chars[token.start..token.start + token.len]
+    }
+}
+
+/// 工具函数：以组分依次覆盖定长复合词项的各个位置
+/// * 🎯一元、二元复合词项：`(--, A)`、`(-, A, B)`
+/// * 📌调用前须检查组分数目；多余的组分被忽略
+/// * ⚠️不内联：避免增大递归解析的栈帧
+#[inline(never)]
+fn fill_slots<const N: usize>(slots: [&mut Box<Term>; N], terms: Vec<Term>) {
+    for (slot, term) in slots.into_iter().zip(terms) {
+        *slot.as_mut() = term;
+    }
+}
+
 /// 用于表征「解析结果」
 /// * 用于表示「解析对象」
 ///
@@ -112,89 +423,328 @@ type ConsumeResult = ParseResult<()>;
 
 /// 用于表征「解析错误」
 /// * 📝不要依赖于任何外部引用：后续需要【脱离】解析环境
+/// * ⚠️解析结果在递归中逐层返回：结构体尺寸直接影响栈空间占用
+///   * 🚩消息、环境切片均使用装箱切片，不保留多余容量
+///   * 🚩其余信息装箱于[`ParseErrorDetail`]
 #[derive(Debug, Clone)]
 pub struct ParseError {
     /// 错误消息 | 一般不含冒号
     /// * 🎯用于描述出错原因
-    message: String,
+    message: Box<str>,
     /// 裁剪出的「解析环境」切片
     /// * 🎯用于展示出错范围
-    env_slice: ParseEnv,
+    env_slice: Box<[char]>,
     /// 出错所在的「解析索引」
     /// * 🎯用于指示出错位置
     index: ParseIndex,
+    /// 装箱的其余信息
+    detail: Box<ParseErrorDetail>,
+}
+
+/// 解析错误的其余信息
+/// * 🎯装箱存放，控制[`ParseError`]的尺寸
+#[derive(Debug, Clone)]
+struct ParseErrorDetail {
+    /// 出错位置在原始输入中的字节偏移
+    /// * 🎯便于调用方直接对原始`&str`切片
+    /// * 📌由「解析索引」按UTF-8编码长度换算而来
+    byte_offset: usize,
+    /// 出错位置在「环境切片」中的字符索引
+    /// * 🎯用于在报错文本中定位插入符`^`
+    slice_index: usize,
+    /// 出错所在的行号（从1开始）
+    /// * 🚩根据原始输入中的`\n`计算
+    line: usize,
+    /// 出错所在的列号（从1开始，按字符计）
+    column: usize,
+    /// 错误的种类
+    /// * 🎯供调用方按种类分支处理
+    kind: ParseErrorKind,
+    /// 引发此错误的内部错误
+    /// * 📄数值解析错误、词项修改错误
+    /// * 🚩使用[`Arc`]以保持[`Clone`]
+    source: Option<Arc<dyn Error + Send + Sync>>,
 }
 impl ParseError {
     /// 工具函数/生成「环境切片」
-    fn generate_env_slice(env: ParseEnv, index: ParseIndex) -> ParseEnv {
+    /// * 📌索引越界⇒按末尾处理
+    /// * 🚩返回「环境切片」及「出错位置在切片中的索引」
+    fn generate_env_slice(env: ParseEnv, index: ParseIndex) -> (Box<[char]>, usize) {
+        let index = index.min(env.len());
         // 字符范围下限 | 后续截取包含
-        let char_range_left = match index > ERR_CHAR_VIEW_RANGE {
-            true => index - ERR_CHAR_VIEW_RANGE,
-            false => 0,
-        };
+        let char_range_left = index.saturating_sub(ERR_CHAR_VIEW_RANGE);
         // 字符范围上限 | 后续截取不包含
-        let char_range_right = match index + ERR_CHAR_VIEW_RANGE + 1 < env.len() {
-            true => index + ERR_CHAR_VIEW_RANGE + 1,
-            false => env.len(),
-        };
+        let char_range_right = index
+            .saturating_add(ERR_CHAR_VIEW_RANGE + 1)
+            .min(env.len());
         // 截取字符，生成环境
-        env[char_range_left..char_range_right].into()
+        (
+            env[char_range_left..char_range_right].into(),
+            index - char_range_left,
+        )
     }
 
     /// 构造函数
+    /// * 📌解析索引按字符计；越界⇒字节偏移按输入末尾计
+    /// * 📌种类默认为「其它」：需要时使用[`Self::with_kind`]指定
     pub fn new(message: &str, env: ParseEnv, index: ParseIndex) -> ParseError {
+        let (line, column) = line_column(env.iter().copied(), index);
+        let byte_offset = env[..index.min(env.len())]
+            .iter()
+            .map(|c| c.len_utf8())
+            .sum();
+        let (env_slice, slice_index) = ParseError::generate_env_slice(env, index);
         ParseError {
-            message: message.to_string(),
-            env_slice: ParseError::generate_env_slice(env, index),
+            message: message.into(),
+            env_slice,
             index,
+            detail: Box::new(ParseErrorDetail {
+                byte_offset,
+                slice_index,
+                line,
+                column,
+                kind: ParseErrorKind::Other(message.into()),
+                source: None,
+            }),
+        }
+    }
+
+    /// 指定错误的种类
+    pub fn with_kind(mut self, kind: ParseErrorKind) -> Self {
+        self.detail.kind = kind;
+        self
+    }
+
+    /// 指定引发此错误的内部错误
+    pub fn with_source(mut self, source: impl Error + Send + Sync + 'static) -> Self {
+        self.detail.source = Some(Arc::new(source));
+        self
+    }
+
+    /// 将错误位置平移到「解析起点」之后
+    /// * 🎯让「从更大输入中截取出来」的片段报告其在整个输入中的位置
+    /// * 📌首行的列号需加上起点列号；其余行的列号不变
+    fn offset_by(mut self, origin: &ParseOrigin) -> Self {
+        if self.detail.line == 1 {
+            self.detail.column += origin.column - 1;
+        }
+        self.detail.line += origin.line - 1;
+        self.index += origin.index;
+        self.detail.byte_offset += origin.byte_offset;
+        self
+    }
+}
+
+/// 解析起点
+/// * 🎯记录「解析环境」在整个输入中的起始位置
+///   * 📄[`NarseseFormat::parse_stream_located`]逐行解析时，各行的起点
+/// * 📌默认为整个输入的开头：不影响错误位置
+#[derive(Debug, Clone, Copy)]
+struct ParseOrigin {
+    /// 起始字符索引
+    index: ParseIndex,
+    /// 起始字节偏移
+    byte_offset: usize,
+    /// 起始行号（从1开始）
+    line: usize,
+    /// 起始列号（从1开始）
+    column: usize,
+}
+impl Default for ParseOrigin {
+    fn default() -> Self {
+        Self {
+            index: 0,
+            byte_offset: 0,
+            line: 1,
+            column: 1,
         }
     }
 }
+/// 解析错误消息/输入为空
+/// * 🎯用于快速、稳定地区分「输入为空」与「Narsese格式错误」
+///   * 📄空字串、纯空白、仅含BOM的输入
+/// * 📌此消息内容保持稳定，可供调用方比对
+pub const EMPTY_INPUT_MESSAGE: &str = "输入为空";
+
+/// 解析错误消息/带依赖列表的变量
+/// * 🎯对OpenNARS输出中的`#1(#2)`、`#x(a,b)`给出明确的提示
+///   * 📌枚举Narsese暂不支持；词法Narsese将依赖列表并入变量名称
+/// * 📌此消息内容保持稳定，可供调用方比对
+pub const VARIABLE_DEPENDENCY_LIST_MESSAGE: &str = "暂不支持带依赖列表的变量";
+
+/// 判断输入是否「为空」
+/// * 🚩仅由空白符与BOM（`\u{feff}`）组成⇒为空
+fn is_empty_input(mut chars: impl Iterator<Item = char>) -> bool {
+    chars.all(|c| c.is_whitespace() || c == '\u{feff}')
+}
+
+impl ParseError {
+    /// 获取错误消息
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// 获取错误的种类
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.detail.kind
+    }
+
+    /// 判断是否为「输入为空」错误
+    /// * 📄参见[`EMPTY_INPUT_MESSAGE`]
+    pub fn is_empty_input(&self) -> bool {
+        &*self.message == EMPTY_INPUT_MESSAGE
+    }
+
+    /// 判断是否为「超出最大嵌套深度」错误
+    /// * 📄参见[`MAX_DEPTH_MESSAGE`]
+    pub fn is_max_depth_exceeded(&self) -> bool {
+        &*self.message == MAX_DEPTH_MESSAGE
+    }
+
+    /// 判断是否为「带依赖列表的变量」错误
+    /// * 📄参见[`VARIABLE_DEPENDENCY_LIST_MESSAGE`]
+    pub fn is_variable_dependency_list(&self) -> bool {
+        &*self.message == VARIABLE_DEPENDENCY_LIST_MESSAGE
+    }
+
+    /// 判断是否为「查询变量与问题标点有歧义」错误
+    /// * 📄参见[`AMBIGUOUS_QUERY_VARIABLE_MESSAGE`]
+    pub fn is_ambiguous_query_variable(&self) -> bool {
+        &*self.message == AMBIGUOUS_QUERY_VARIABLE_MESSAGE
+    }
+
+    /// 获取出错所在的「解析索引」（按字符计）
+    pub fn index(&self) -> ParseIndex {
+        self.index
+    }
+
+    /// 获取出错位置在原始输入中的字节偏移
+    /// * 📌恒为字符边界：可直接用于`&input[error.byte_offset()..]`
+    pub fn byte_offset(&self) -> usize {
+        self.detail.byte_offset
+    }
+
+    /// 获取出错所在的行号（从1开始）
+    pub fn line(&self) -> usize {
+        self.detail.line
+    }
+
+    /// 获取出错所在的列号（从1开始）
+    pub fn column(&self) -> usize {
+        self.detail.column
+    }
+}
+
 /// 用于在报错时展示周边文本
 const ERR_CHAR_VIEW_RANGE: UIntPrecision = 4;
+/// 工具函数/字符在终端中的显示宽度
+/// * 🎯让插入符`^`对准含中文、emoji的环境切片
+/// * 📌仅粗略区分「全角」与「半角」：东亚宽字符、emoji记为2，其余记为1
+fn char_display_width(c: char) -> usize {
+    match c as u32 {
+        0x1100..=0x115F
+        | 0x2E80..=0x303E
+        | 0x3041..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1F64F
+        | 0x1F900..=0x1F9FF
+        | 0x20000..=0x3FFFD => 2,
+        _ => 1,
+    }
+}
+
 /// 呈现报错文本
+/// * 🚩第一行：错误消息与位置
+/// * 🚩后两行：环境切片，及指向出错字符的插入符`^`
+///   * 📌切片中的空白符统一显示为空格，以免换行打乱对齐
 impl Display for ParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         // 输出
         write!(
             f,
-            "Narsese解析错误：{} @ {} in {:?}",
+            "Narsese解析错误：{} @ {} (byte {}) in {:?} at line {}, column {}",
             self.message,
             self.index,
-            String::from_iter(self.env_slice.iter())
-        )
+            self.detail.byte_offset,
+            String::from_iter(self.env_slice.iter()),
+            self.detail.line,
+            self.detail.column
+        )?;
+        // 环境切片
+        let slice = self
+            .env_slice
+            .iter()
+            .map(|&c| match c.is_whitespace() {
+                true => ' ',
+                false => c,
+            })
+            .collect::<String>();
+        // 插入符 | 出错位置在末尾⇒指向切片之后
+        let caret_indent = self.env_slice[..self.detail.slice_index]
+            .iter()
+            .map(|&c| char_display_width(c))
+            .sum::<usize>();
+        write!(f, "\n    {slice}\n    {:caret_indent$}^", "")
+    }
+}
+/// 实现[`Error::source`]：返回引发此错误的内部错误
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.detail.source.as_deref().map(|e| e as &(dyn Error + 'static))
     }
 }
-impl Error for ParseError {}
 
 /// 定义一个「解析器状态」类型
 /// * 🎯除了内置「格式」外，还可【缓存】解析状态
 /// * 📄学习参考：[tomllib/parser.rs](https://github.com/joelself/tomllib/blob/master/src/internals/parser.rs)
 /// * 📌【2024-06-13 19:51:35】限制可见性：只允许在包内使用（macros中需要用到）
-pub(crate) struct ParseState<'a, Content = &'a str> {
+pub(crate) struct ParseState<'a, Content = &'a str, Env = ParseEnv> {
     /// 引用的「解析格式」
     format: &'a NarseseFormat<Content>,
     /// 「解析环境」
-    env: ParseEnv,
+    /// * 📌默认为「字符数组」；零拷贝解析时为`&str`
+    env: Env,
     /// 「解析环境」的长度 | 用于缓存常用变量
     len_env: UIntPrecision,
     /// 当前解析的位置 | 亦用作「下一起始索引」
     head: ParseIndex,
     /// 「中间解析结果」
     mid_result: MidParseResult,
+    /// 当前的嵌套深度
+    /// * 🎯限制递归层数，防止深层嵌套的输入耗尽调用栈
+    depth: usize,
+    /// 解析中产生的警告
+    /// * 🎯记录「不影响解析结果，但可能出乎意料」的情况
+    ///   * 📄无序复合词项中的重复组分被去重
+    /// * 📌与错误共用[`ParseError`]：含消息、种类、位置
+    warnings: Vec<ParseError>,
+    /// 「解析环境」在整个输入中的起点
+    /// * 🎯生成错误时据此平移位置
+    origin: ParseOrigin,
+    /// 预计算的「语法元素」表
+    /// * 🎯加速前缀匹配；重置状态时保留
+    tokens: FormatTokens<'a>,
     // * 🚩【2024-03-28 14:33:47】现弃用「关键字截断」机制，直接使用「系词前缀匹配」判断
 }
 
 /// 实现/通用：重置、生成
-impl<'a, C> ParseState<'a, C> {
+impl<'a, C, E: ParseEnvironment<'a>> ParseState<'a, C, E> {
     /// 重置状态到指定情形
     /// * 用于重定向上下文
     /// * 📌自动内联
     #[inline(always)]
-    pub fn reset_to(&mut self, input: &str, head: ParseIndex) {
-        self.env = ParseState::_build_env(input);
-        self.len_env = self.env.len();
+    pub fn reset_to(&mut self, input: &'a str, head: ParseIndex) {
+        self.env = E::build(input);
+        self.len_env = self.env.len_env();
         self.head = head;
+        self.warnings.clear();
     }
 
     /// 重置状态
@@ -219,8 +769,8 @@ impl<'a, C> ParseState<'a, C> {
     /// * 在[`parse_error`]的基础上包装成[`ParseResult`]
     /// * 🎯用于最后「生成结果」的情况
     #[inline(always)]
-    pub fn err<T>(&self, message: &str) -> ParseResult<T> {
-        Err(self.parse_error(message))
+    pub fn err<T>(&self, kind: ParseErrorKind, message: &str) -> ParseResult<T> {
+        Err(self.parse_error(kind, message))
     }
 
     /// 生成「解析错误」：直接根据消息内联自身解析状态
@@ -231,8 +781,11 @@ impl<'a, C> ParseState<'a, C> {
     /// * 📝合并「消耗错误」结果：泛型参数可以自动捕获返回类型
     /// * 📌自动内联
     #[inline(always)]
-    pub fn parse_error(&self, message: &str) -> ParseError {
-        ParseError::new(message, self.env.clone(), self.head)
+    pub fn parse_error(&self, kind: ParseErrorKind, message: &str) -> ParseError {
+        let (env, index) = self.env.to_chars_and_index(self.head);
+        ParseError::new(message, env, index)
+            .with_kind(kind)
+            .offset_by(&self.origin)
     }
 
     /// 生成「消耗成功」结果：无需内联自身状态
@@ -246,8 +799,10 @@ impl<'a, C> ParseState<'a, C> {
 
 /// 匹配首个前缀匹配的分支，自动跳过前缀并执行代码
 /// * 🚩先跳过前缀，再执行代码
+/// * 📌前缀为预计算的「语法元素」[`Token`]
 /// * 🎯用于快速识别并跳过指定前缀
 /// * 🎯用于避免遗漏「跳过前缀」的操作
+///
 /// 📝`self`是一个内容相关的关键字，必须向其中传递`self`作为参数
 macro_rules! first_prefix_and_skip_first {
     {
@@ -260,9 +815,9 @@ macro_rules! first_prefix_and_skip_first {
     } => {
         // 插入`first!`宏中
         first! {
-            $( $self_.starts_with($prefix) => {
+            $( $self_.starts_with_token($prefix) => {
                 // ! 先跳过前缀
-                $self_.head_skip($prefix);
+                $self_.head_skip_token($prefix);
                 // * 再执行（并返回）代码
                 $branch
             } ),*,
@@ -276,6 +831,7 @@ macro_rules! first_prefix_and_skip_first {
 ///   * 📌对匹配失败者：还原头索引，并继续下一匹配
 /// * 📌用于消歧义：💢「独立变量」和「预算值」开头撞了
 /// * 📌用于消歧义：💢「查询变量」和「问题」标点撞了
+///
 /// 📝`self`是一个内容相关的关键字，必须向其中传递`self`作为参数
 macro_rules! first_method_ok {
     // 不带「错误收集」的版本
@@ -355,7 +911,7 @@ macro_rules! first_method_ok {
                                 // 为`Err`时，收集错误并继续匹配
                                 Err(err) => {
                                     // 收集错误：追加至末尾
-                                    $to_collect.push(err.to_string());
+                                    $to_collect.push(err.clone());
                                     // 尝试继续匹配
                                     false
                                 }
@@ -376,31 +932,21 @@ macro_rules! first_method_ok {
 /// 2. 构建「中间解析结果」
 /// 3. 根据内容填充「中间解析结果」
 /// 4. 转换「中间解析结果」为最终结果
-impl<'a> ParseState<'a, &'a str> {
+impl<'a, E: ParseEnvironment<'a>> ParseState<'a, &'a str, E> {
     // 构造 | 入口 //
 
-    /// 构造解析环境
-    #[inline(always)]
-    fn _build_env(input: &'a str) -> ParseEnv {
-        input.chars().collect()
-    }
-
     /// 根据格式构造parser
     /// * 🚩方法：默认状态+重定向
     #[inline(always)]
     fn new(format: &'a NarseseFormat<&str>, input: &'a str, head: ParseIndex) -> Self {
-        Self::from_env(format, Self::_build_env(input), head)
+        Self::from_env(format, E::build(input), head)
     }
 
     /// 从指定的「解析环境」构造parser
     /// * 🚩方法：默认状态+重定向
-    pub(crate) fn from_env(
-        format: &'a NarseseFormat<&str>,
-        env: ParseEnv,
-        head: ParseIndex,
-    ) -> Self {
+    pub(crate) fn from_env(format: &'a NarseseFormat<&str>, env: E, head: ParseIndex) -> Self {
         // 生成环境长度 // ! 直接插入会有「同时引用」的所有权问题
-        let len_env = env.len();
+        let len_env = env.len_env();
         // 构造结构体
         ParseState {
             // 直接指向格式
@@ -413,6 +959,14 @@ impl<'a> ParseState<'a, &'a str> {
             head,
             // 从空结果开始
             mid_result: MidParseResult::new(),
+            // 从顶层开始
+            depth: 0,
+            // 从无警告开始
+            warnings: vec![],
+            // 从整个输入的开头开始
+            origin: ParseOrigin::default(),
+            // 预计算格式中的语法元素
+            tokens: FormatTokens::new(format, E::USES_TOKEN_CHARS),
         }
     }
 
@@ -430,6 +984,28 @@ impl<'a> ParseState<'a, &'a str> {
         ParseResult::from_parse((), self)
     }
 
+    /// 判断输入是否「为空」
+    /// * 🚩仅由空白符与BOM组成⇒为空
+    /// * 🚩去除开头的空白符与BOM后，以注释前缀开头⇒为空
+    ///   * 📌与[`NarseseFormat::strip_comment`]一致：括弧外的注释延续到输入末尾
+    ///   * 📄`// 注释`、`'注释`
+    fn is_empty_input(&self) -> bool {
+        if self.env.is_empty_input() {
+            return true;
+        }
+        // 跳过开头的空白符与BOM
+        let mut index = 0;
+        while index < self.len_env && {
+            let c = self.env.char_at(index);
+            c.is_whitespace() || c == '\u{feff}'
+        } {
+            index += self.env.char_len_at(index);
+        }
+        DEFAULT_COMMENT_PREFIXES
+            .iter()
+            .any(|prefix| self.env.starts_with_at(index, prefix))
+    }
+
     // 消耗文本 | 构建「中间解析结果」 //
 
     /// 判断「是否可继续消耗」
@@ -448,7 +1024,7 @@ impl<'a> ParseState<'a, &'a str> {
     /// * ⚠️未检查边界，可能会panic
     #[inline(always)]
     fn head_char(&self) -> char {
-        self.env[self.head]
+        self.env.char_at(self.head)
     }
 
     /// 头索引移动
@@ -463,7 +1039,7 @@ impl<'a> ParseState<'a, &'a str> {
 
     /// 头索引递进
     /// * 🎯用于抽象「头部索引位移」的过程
-    ///   * ⚠️跳过的是字符，不是字节
+    ///   * ⚠️跳过的是「索引单位」：字符数组中为字符，字符串切片中为字节
     /// * 🚩逻辑：头部索引增加赋值
     /// * 📌自动内联
     #[inline(always)]
@@ -477,17 +1053,24 @@ impl<'a> ParseState<'a, &'a str> {
     /// * 📌自动内联
     #[inline(always)]
     fn head_step_one(&mut self) {
-        self.head_step(1)
+        self.head_step(self.env.char_len_at(self.head))
     }
 
     /// 头索引跳过
     /// * 🎯用于抽象「头部索引跳过」的过程
-    /// * 🚩逻辑：头部索引根据字符数量递进
+    /// * 🚩逻辑：头部索引根据「所占索引单位数」递进
     /// * 📌自动内联
     #[inline(always)]
     fn head_skip(&mut self, to_be_skip: &str) {
-        // 跳过「字符数」个字符
-        self.head_step(to_be_skip.chars().count())
+        self.head_step(E::len_of(to_be_skip))
+    }
+
+    /// 头索引跳过「语法元素」
+    /// * 🚩逻辑：同[`Self::head_skip`]，但使用预计算的长度
+    /// * 📌自动内联
+    #[inline(always)]
+    fn head_skip_token(&mut self, to_be_skip: Token) {
+        self.head_step(E::len_of_token(&to_be_skip))
     }
     /*
     /// 头索引尝试跳过
@@ -514,8 +1097,26 @@ impl<'a> ParseState<'a, &'a str> {
     /// * 📌自动内联
     #[inline(always)]
     fn head_skip_spaces(&mut self) {
-        while self.starts_with(self.format.space.parse) {
-            self.head_skip(self.format.space.parse);
+        loop {
+            match self.starts_with_token(self.tokens.space) {
+                true => self.head_skip_token(self.tokens.space),
+                false if self.starts_with_space_char() => self.head_step_one(),
+                false => break,
+            }
+        }
+    }
+
+    /// 判断头部是否为「单字符空白」
+    /// * 🎯支持多行输入：换行符（`\n`、`\r`）与空白同等对待
+    ///   * 📌解析出错时，据此可报告「第几行」
+    /// * 🎯支持格式自定义的空白：全角空格、不换行空格、制表符等
+    ///   * 📄参见[`NarseseFormatSpace::is_for_parse`](super::format::NarseseFormatSpace::is_for_parse)
+    /// * 📌自动内联
+    #[inline(always)]
+    fn starts_with_space_char(&self) -> bool {
+        self.can_consume() && {
+            let c = self.head_char();
+            matches!(c, '\n' | '\r') || (self.format.space.is_for_parse)(c)
         }
     }
 
@@ -524,9 +1125,9 @@ impl<'a> ParseState<'a, &'a str> {
     /// * 🚩逻辑：合并上述代码
     /// * 📌自动内联
     #[inline(always)]
-    fn head_skip_and_spaces(&mut self, to_be_skip: &str) {
+    fn head_skip_and_spaces(&mut self, to_be_skip: Token) {
         // 跳过字符串
-        self.head_skip(to_be_skip);
+        self.head_skip_token(to_be_skip);
         // 跳过空白
         self.head_skip_spaces();
     }
@@ -535,12 +1136,20 @@ impl<'a> ParseState<'a, &'a str> {
     /// * 🎯用于抽象「头部索引跳过空白序列及之后的字符串」的过程
     /// * 🚩逻辑：合并上述代码
     /// * 📌自动内联
+    /// * 📌到达末尾⇒视作「自动闭合」，不再跳过
+    /// * ❌未到末尾且不以该字串开头⇒报错
+    ///   * ⚠️不能盲目跳过：可能越界，或落在多字节字符的中间
     #[inline(always)]
-    fn head_skip_after_spaces(&mut self, to_be_skip: &str) {
+    fn head_skip_after_spaces(&mut self, to_be_skip: Token) -> ConsumeResult {
         // 跳过空白
         self.head_skip_spaces();
         // 跳过字符串
-        self.head_skip(to_be_skip);
+        match (self.can_consume(), self.starts_with_token(to_be_skip)) {
+            (_, true) => self.head_skip_token(to_be_skip),
+            (false, false) => {}
+            (true, false) => return self.err(ParseErrorKind::UnclosedBracket, &format!("缺少右括弧{:?}", to_be_skip.text)),
+        }
+        Self::ok_consume()
     }
 
     /// 构建「中间解析结果」/入口
@@ -549,7 +1158,7 @@ impl<'a> ParseState<'a, &'a str> {
     ///   * 2 直到「头部索引」超过文本长度（越界）
     fn build_mid_result(&mut self) -> ConsumeResult {
         // 初始化可收集的错误
-        let mut errs: Vec<String> = vec![];
+        let mut errs: Vec<ParseError> = vec![];
         // 在「可以继续消耗」时
         while self.can_consume() {
             // 索引跳过系列空白 | 用于处理对象之间的空白
@@ -564,20 +1173,225 @@ impl<'a> ParseState<'a, &'a str> {
         Self::ok_consume()
     }
 
-    /// 检查自己的「解析环境」是否在「头部索引」处以指定字符串开头
-    fn starts_with(&self, to_compare: &str) -> bool {
-        // 长度检验
-        if self.len_env < self.head + to_compare.chars().count() {
-            // 长度不够⇒肯定不匹配
-            return false;
+    /// 构建「中间解析结果」/前缀
+    /// * 🎯只消耗开头的一个Narsese值，忽略其后的内容
+    /// * 🚩逐个消耗条目，直到遇上无法消耗的条目为止
+    ///   * 📌尚未解析出词项时出错⇒照常报错
+    ///   * 📌已解析出词项后出错⇒头部索引移回该条目之前，停止消耗
+    /// * ⚙️返回「最后一个已消耗条目」的右边界（不含其后的空白）
+    fn build_mid_result_prefix(&mut self) -> ParseResult<ParseIndex> {
+        let mut errs: Vec<ParseError> = vec![];
+        let mut end = self.head;
+        loop {
+            // 索引跳过系列空白
+            self.head_skip_spaces();
+            if !self.can_consume() {
+                break;
+            }
+            let begin = self.head;
+            match self.consume_one(&mut errs) {
+                Ok(()) => end = self.head,
+                Err(err) if self.mid_result.term.is_none() => return Err(err),
+                Err(..) => {
+                    self.head_move(begin);
+                    break;
+                }
+            }
         }
-        // 逐个字符比较
-        for (i, c) in to_compare.chars().enumerate() {
-            if self.env[self.head + i] != c {
-                return false;
+        Ok(end)
+    }
+
+    /// 构建「中间解析结果」/宽松
+    /// * 🎯尽可能多地解析条目：出错的条目留空，继续解析其后的条目
+    /// * 🚩依次尝试各条目（顺序同[`Self::consume_one`]）
+    ///   * 📌有一个成功⇒继续
+    ///   * 📌全部失败⇒记录「走得最远」的错误；该条目不再尝试，并跳至下一个可识别的边界
+    /// * ⚙️返回收集到的错误
+    fn build_mid_result_lenient(&mut self) -> Vec<ParseError> {
+        let mut errors = vec![];
+        let mut failed = vec![];
+        loop {
+            // 索引跳过系列空白
+            self.head_skip_spaces();
+            if !self.can_consume() {
+                break;
+            }
+            let begin = self.head;
+            let mut furthest: Option<(ItemKind, ParseError)> = None;
+            let mut consumed = false;
+            for item in ItemKind::ALL {
+                self.head_move(begin);
+                if failed.contains(&item) || !self.can_consume_item(item) {
+                    continue;
+                }
+                match self.consume_item(item) {
+                    Ok(()) => {
+                        consumed = true;
+                        break;
+                    }
+                    // 出错⇒清除可能已置入的值，保留「走得最远」的错误
+                    Err(err) => {
+                        self.clear_item(item);
+                        if furthest
+                            .as_ref()
+                            .is_none_or(|(_, furthest)| err.index() > furthest.index())
+                        {
+                            furthest = Some((item, err));
+                        }
+                    }
+                }
+            }
+            if consumed {
+                continue;
+            }
+            self.head_move(begin);
+            match furthest {
+                Some((item, err)) => {
+                    errors.push(err);
+                    failed.push(item);
+                    self.skip_item(item);
+                }
+                None => {
+                    errors.push(self.parse_error(ParseErrorKind::UnexpectedChar, "没有可解析的条目"));
+                    self.skip_to_space();
+                }
+            }
+            // 至少前进一个字符，以免原地打转
+            if self.head == begin {
+                self.head_step_one();
             }
         }
-        true
+        errors
+    }
+
+    /// 宽松解析/能否在头部索引处尝试消耗某条目
+    /// * 📌条件与[`Self::consume_one`]中各分支一致
+    fn can_consume_item(&self, item: ItemKind) -> bool {
+        match item {
+            ItemKind::Budget => {
+                self.starts_with(self.format.task.budget_brackets.0)
+                    && self.mid_result.budget.is_none()
+            }
+            ItemKind::Term => self.mid_result.term.is_none(),
+            ItemKind::Punctuation => self.mid_result.punctuation.is_none(),
+            ItemKind::Stamp => {
+                self.starts_with(self.format.sentence.stamp_brackets.0)
+                    && self.mid_result.stamp.is_none()
+            }
+            ItemKind::Truth => {
+                self.starts_with(self.format.sentence.truth_brackets.0)
+                    && self.mid_result.truth.is_none()
+            }
+        }
+    }
+
+    /// 宽松解析/消耗某条目
+    fn consume_item(&mut self, item: ItemKind) -> ConsumeResult {
+        match item {
+            ItemKind::Budget => self.consume_budget(),
+            ItemKind::Term => self.consume_term(),
+            ItemKind::Punctuation => self.consume_punctuation(),
+            ItemKind::Stamp => self.consume_stamp(),
+            ItemKind::Truth => self.consume_truth(),
+        }
+    }
+
+    /// 宽松解析/清除某条目
+    /// * 🎯如「时间戳」在检查右括弧之前即已置入
+    fn clear_item(&mut self, item: ItemKind) {
+        match item {
+            ItemKind::Budget => self.mid_result.budget = None,
+            ItemKind::Term => self.mid_result.term = None,
+            ItemKind::Punctuation => self.mid_result.punctuation = None,
+            ItemKind::Stamp => self.mid_result.stamp = None,
+            ItemKind::Truth => self.mid_result.truth = None,
+        }
+    }
+
+    /// 宽松解析/跳过出错的条目
+    /// * 🚩预算值、时间戳、真值⇒跳至右括弧之后
+    /// * 🚩词项⇒按括弧配对跳过；原子词项⇒跳至空白处
+    /// * 🚩标点⇒跳至空白处
+    fn skip_item(&mut self, item: ItemKind) {
+        match item {
+            ItemKind::Budget => self.skip_bracketed(self.format.task.budget_brackets),
+            ItemKind::Stamp => self.skip_bracketed(self.format.sentence.stamp_brackets),
+            ItemKind::Truth => self.skip_bracketed(self.format.sentence.truth_brackets),
+            ItemKind::Term => self.skip_term(),
+            ItemKind::Punctuation => self.skip_to_space(),
+        }
+    }
+
+    /// 宽松解析/跳至空白处
+    fn skip_to_space(&mut self) {
+        while self.can_consume()
+            && !self.starts_with(self.format.space.parse)
+            && !self.starts_with_space_char()
+        {
+            self.head_step_one();
+        }
+    }
+
+    /// 宽松解析/跳过括弧包裹的条目
+    /// * 📌右括弧为空（如LaTeX的时间戳）⇒跳至空白处
+    fn skip_bracketed(&mut self, (left, right): (&str, &str)) {
+        self.head_skip(left);
+        if right.is_empty() {
+            return self.skip_to_space();
+        }
+        while self.can_consume() && !self.starts_with(right) {
+            self.head_step_one();
+        }
+        if self.can_consume() {
+            self.head_skip(right);
+        }
+    }
+
+    /// 宽松解析/按括弧配对跳过词项
+    /// * 🚩复合词项、陈述、词项集的括弧计入层级；层级归零时停止
+    /// * ⚠️优先跳过系词：如`-->`中的`>`不应视作陈述右括弧
+    fn skip_term(&mut self) {
+        let compound = &self.format.compound;
+        let brackets = [
+            compound.brackets,
+            self.format.statement.brackets,
+            compound.brackets_set_extension,
+            compound.brackets_set_intension,
+        ];
+        if !brackets.iter().any(|(left, _)| self.starts_with(left)) {
+            return self.skip_to_space();
+        }
+        let copulas = self.format.copulas();
+        let mut depth = 0;
+        while self.can_consume() {
+            if let Some(copula) = copulas.iter().find(|copula| self.starts_with(copula)) {
+                self.head_skip(copula);
+            } else if let Some((left, _)) = brackets.iter().find(|(left, _)| self.starts_with(left)) {
+                self.head_skip(left);
+                depth += 1;
+            } else if let Some((_, right)) = brackets.iter().find(|(_, right)| self.starts_with(right)) {
+                self.head_skip(right);
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            } else {
+                self.head_step_one();
+            }
+        }
+    }
+
+    /// 检查自己的「解析环境」是否在「头部索引」处以指定字符串开头
+    #[inline(always)]
+    fn starts_with(&self, to_compare: &str) -> bool {
+        self.env.starts_with_at(self.head, to_compare)
+    }
+
+    /// 检查自己的「解析环境」是否在「头部索引」处以指定「语法元素」开头
+    /// * 🎯热点路径：参见[`FormatTokens`]
+    #[inline(always)]
+    fn starts_with_token(&self, token: Token) -> bool {
+        self.env.starts_with_token_at(self.head, &token, &self.tokens)
     }
 
     /// 消耗文本&置入「中间结果」
@@ -596,8 +1410,9 @@ impl<'a> ParseState<'a, &'a str> {
     /// * 🚩【2024-02-21 23:33:25】现在使用「匹配到就跳过」的手段
     ///   * 📌若已有词项，则一定不会再次消耗词项
     /// * 🚩现在使用「自动录入错误集」来追溯错误来源
-    ///   * 📌若`errs`直接存储错误对象，会导致所有权问题（部分借用返回值）
-    fn consume_one(&mut self, errs: &mut Vec<String>) -> ConsumeResult {
+    ///   * 📌`errs`存储错误对象的副本，以免「部分借用返回值」的所有权问题
+    ///   * 📌链式错误的位置取「走得最远」的子错误
+    fn consume_one(&mut self, errs: &mut Vec<ParseError>) -> ConsumeResult {
         first_method_ok! {
             // 当匹配失败时移回原始索引
             self.head_move;
@@ -607,13 +1422,18 @@ impl<'a> ParseState<'a, &'a str> {
             errs;
 
             // 空格⇒跳过 //
-            self.starts_with(self.format.space.parse) => {
-                self.head_skip(self.format.space.parse);
+            self.starts_with_token(self.tokens.space) => {
+                self.head_skip_token(self.tokens.space);
                 Ok(()) // * 📌Clippy：明确返回单元值，而非（可能后续会变的）「索引头跳过」的结果
             },
+            // 换行等单字符空白⇒跳过 //
+            self.starts_with_space_char() => {
+                self.head_step_one();
+                Ok(())
+            },
             // 1 预算值 //
             (
-                self.starts_with(self.format.task.budget_brackets.0) &&
+                self.starts_with_token(self.tokens.budget_brackets.0) &&
                 self.mid_result.budget.is_none()
             ) => self.consume_budget(),
             // 2 词项 //
@@ -628,12 +1448,12 @@ impl<'a> ParseState<'a, &'a str> {
             ) => self.consume_punctuation(),
             // 4 时间戳 //
             (
-                self.starts_with(self.format.sentence.stamp_brackets.0) &&
+                self.starts_with_token(self.tokens.stamp_brackets.0) &&
                 self.mid_result.stamp.is_none()
             )  => self.consume_stamp(),
             // 5 真值 //
             (
-                self.starts_with(self.format.sentence.truth_brackets.0) &&
+                self.starts_with_token(self.tokens.truth_brackets.0) &&
                 self.mid_result.truth.is_none()
             )  => self.consume_truth(),
             // 不会存在的情况 //
@@ -641,14 +1461,31 @@ impl<'a> ParseState<'a, &'a str> {
                 // *【2024-02-21 23:39:30】目前选择报错
                 match errs.is_empty() {
                     // 无追踪⇒直接呈现
-                    true => self.err("没有可解析的条目"),
+                    true => self.err(ParseErrorKind::UnexpectedChar, "没有可解析的条目"),
                     // 有追踪⇒链式呈现
+                    // * 🚩出错位置取「走得最远」的子错误：通常即出错的词元所在
                     false => {
+                        // 超出最大嵌套深度、带依赖列表的变量、查询变量的歧义⇒直接上抛，不与其它错误混杂
+                        if let Some(err) = errs.iter().find(|err| {
+                            err.is_max_depth_exceeded()
+                                || err.is_variable_dependency_list()
+                                || err.is_ambiguous_query_variable()
+                        }) {
+                            return Err(err.clone());
+                        }
+                        let (env, _) = self.env.to_chars_and_index(self.head);
+                        // * 🚩种类亦取「走得最远」的子错误，并将其作为内部错误
+                        let furthest = errs.iter().max_by_key(|err| err.index()).unwrap();
                         // 链式呈现
-                        self.err(&format!(
+                        let message = format!(
                             "没有可解析的条目 from [\n\t{}\n]",
-                            errs.join("\n\t"),
-                        ))
+                            errs.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n\t"),
+                        );
+                        // * 📌子错误已平移过位置：需先换回当前环境中的索引
+                        Err(ParseError::new(&message, env, furthest.index() - self.origin.index)
+                            .offset_by(&self.origin)
+                            .with_kind(furthest.kind().clone())
+                            .with_source(furthest.clone()))
                     },
                 }
             },
@@ -670,7 +1507,7 @@ impl<'a> ParseState<'a, &'a str> {
             // 请求
             self.format.sentence.punctuation_quest => self.consume_punctuation_quest(),
             // 否则⇒错误
-            _ => self.err("未知的标点")
+            _ => self.err(ParseErrorKind::UnknownPrefix, "未知的标点")
         }
     }
 
@@ -726,9 +1563,11 @@ impl<'a> ParseState<'a, &'a str> {
     /// * 使用常量`N`指定解析的数目
     ///   * 多的会报错
     ///   * 少的会忽略（额外返回「解析出的数目」作为标记）
+    /// * 📌主分隔符、备选分隔符均可作分隔，允许混用
     fn parse_separated_floats<const N: UIntPrecision>(
         &mut self,
         separator: &str,
+        alternates: &[&str],
         right_bracket: &str,
     ) -> ParseResult<([FloatPrecision; N], UIntPrecision)> {
         // 直接初始化定长数组
@@ -738,19 +1577,26 @@ impl<'a> ParseState<'a, &'a str> {
         // 填充数组
         let mut i: UIntPrecision = 0;
         while self.can_consume() && i < N {
-            match self.head_char() {
+            // 当前位置的分隔符 | 优先匹配主分隔符
+            let matched_separator = std::iter::once(separator)
+                .chain(alternates.iter().copied())
+                .find(|separator| self.starts_with(separator));
+            match (self.head_char(), matched_separator) {
                 // 空白⇒跳过
                 _ if self.starts_with(self.format.space.parse) => {
                     self.head_skip(self.format.space.parse)
                 }
-                // 小数点
-                // 数值|小数点⇒计入缓冲区&跳过
-                '.' | '0'..='9' => {
+                // 换行等单字符空白⇒跳过
+                _ if self.starts_with_space_char() => self.head_step_one(),
+                // 数值|小数点|正负号|指数标记⇒计入缓冲区&跳过
+                // * 📌只收集字符，由[`str::parse`]最终检验数值格式
+                //   * 📄`1e-2` `+0.5` `9E-1`
+                ('.' | '0'..='9' | '+' | '-' | 'e' | 'E', _) => {
                     value_buffer.push(self.head_char());
                     self.head_step_one();
                 }
                 // 分隔符⇒解析并存入数值&跳过
-                _ if self.starts_with(separator) => {
+                (_, Some(separator)) => {
                     // 解析并存入数值
                     match value_buffer.parse::<FloatPrecision>() {
                         // 有效数值
@@ -765,9 +1611,12 @@ impl<'a> ParseState<'a, &'a str> {
                             i += 1;
                         }
                         // 无效数值
-                        Err(_) => {
+                        Err(error) => {
                             // 无效数值
-                            return self.err(&format!("{value_buffer:?}不是有效的数值"));
+                            let message = format!("{value_buffer:?}不是有效的数值");
+                            return Err(self
+                                .parse_error(ParseErrorKind::InvalidNumber, &message)
+                                .with_source(error));
                         }
                     }
                 }
@@ -786,7 +1635,7 @@ impl<'a> ParseState<'a, &'a str> {
                     // 跳出循环
                     break;
                 } // 其它⇒无效字符
-                c => return self.err(&format!("在解析浮点序列时出现无效字符{c:?}")),
+                (c, _) => return self.err(ParseErrorKind::UnexpectedChar, &format!("在解析浮点序列时出现无效字符{c:?}")),
             }
         }
         // 返回最终结果
@@ -817,16 +1666,19 @@ impl<'a> ParseState<'a, &'a str> {
         }
         // 扫描后检查「是否有递进」 | 无递进⇒空整数值
         if self.head == start {
-            return self.err("空的无符号整数值");
+            return self.err(ParseErrorKind::InvalidNumber, "空的无符号整数值");
         }
         // 解析并存入数值
         match int_buffer.parse::<IntPrecision>() {
             // 有效数值
             Ok(value) => Self::ok(value),
             // 无效数值
-            Err(_) => {
+            Err(error) => {
                 // 无效数值
-                self.err(&format!("{int_buffer:?}不是有效的数值"))
+                let message = format!("{int_buffer:?}不是有效的数值");
+                Err(self
+                    .parse_error(ParseErrorKind::InvalidNumber, &message)
+                    .with_source(error))
             }
         }
     }
@@ -836,7 +1688,7 @@ impl<'a> ParseState<'a, &'a str> {
     /// * 📌需要在此完成专有的挪位
     fn consume_stamp(&mut self) -> ConsumeResult {
         // 跳过左括弧
-        self.head_skip_and_spaces(self.format.sentence.stamp_brackets.0);
+        self.head_skip_and_spaces(self.tokens.stamp_brackets.0);
         // 开始匹配时间戳类型标识符
         let stamp = first! {
             // 前缀匹配
@@ -872,12 +1724,12 @@ impl<'a> ParseState<'a, &'a str> {
                 Stamp::Future
             },
             // 无效类型
-            _ => return self.err("无效时间戳类型"),
+            _ => return self.err(ParseErrorKind::UnknownPrefix, "无效时间戳类型"),
         };
         // 置入时间戳
         let _ = self.mid_result.stamp.insert(stamp);
         // 跳过右括弧 | // ! ⚠️默认「匹配完类型后就是右括弧」
-        self.head_skip_after_spaces(self.format.sentence.stamp_brackets.1);
+        self.head_skip_after_spaces(self.tokens.stamp_brackets.1)?;
         // 返回
         Self::ok_consume()
     }
@@ -887,14 +1739,27 @@ impl<'a> ParseState<'a, &'a str> {
     /// * 📌需要在此完成专有的挪位
     fn consume_truth(&mut self) -> ConsumeResult {
         // 跳过左括弧
-        self.head_skip_and_spaces(self.format.sentence.truth_brackets.0);
-        let ([f, c], num) = self.parse_separated_floats::<2>(
-            self.format.sentence.truth_separator,
-            self.format.sentence.truth_brackets.1,
-        )?;
+        self.head_skip_and_spaces(self.tokens.truth_brackets.0);
+        let sentence = &self.format.sentence;
+        let (separator, alternates, right) = (
+            sentence.truth_separator,
+            sentence.truth_separator_alternates,
+            sentence.truth_brackets.1,
+        );
+        // 启用「三真值」⇒至多解析三个分量
+        let ([f, c, k], num) = match self.format.allow_extended_truth {
+            true => self.parse_separated_floats::<3>(separator, alternates, right)?,
+            false => {
+                let ([f, c], num) = self.parse_separated_floats::<2>(separator, alternates, right)?;
+                ([f, c, 0.0], num)
+            }
+        };
         // 验证真值合法性
         if !f.is_in_01() || !c.is_in_01() {
-            return self.err("「0-1」区间外的值（建议：`0<x<1`）");
+            return self.err(ParseErrorKind::OutOfRange01, "「0-1」区间外的值（建议：`0<x<1`）");
+        }
+        if k < 0.0 {
+            return self.err(ParseErrorKind::InvalidNumber, "证据数不能为负");
         }
         // 构造真值
         let truth = match num {
@@ -903,10 +1768,12 @@ impl<'a> ParseState<'a, &'a str> {
             // 单⇒单真值
             1 => Truth::new_single(f),
             // 双⇒双真值
-            _ => Truth::new_double(f, c),
+            2 => Truth::new_double(f, c),
+            // 三⇒三真值
+            _ => Truth::new_triple(f, c, k),
         };
         // 跳过右括弧
-        self.head_skip_after_spaces(self.format.sentence.truth_brackets.1);
+        self.head_skip_after_spaces(self.tokens.truth_brackets.1)?;
         // 直接置入真值 | 因为先前`consume_one`已经假定「未曾置入真值」
         let _ = self.mid_result.truth.insert(truth);
         Self::ok_consume()
@@ -917,14 +1784,15 @@ impl<'a> ParseState<'a, &'a str> {
     /// * 📌需要在此完成专有的挪位
     fn consume_budget(&mut self) -> ConsumeResult {
         // 跳过左括弧
-        self.head_skip_and_spaces(self.format.task.budget_brackets.0);
+        self.head_skip_and_spaces(self.tokens.budget_brackets.0);
         let ([p, d, q], num) = self.parse_separated_floats::<3>(
             self.format.task.budget_separator,
+            self.format.task.budget_separator_alternates,
             self.format.task.budget_brackets.1,
         )?;
         // 验证预算值合法性
         if !p.is_in_01() || !d.is_in_01() || !q.is_in_01() {
-            return self.err("「0-1」区间外的值（建议：`0<x<1`）");
+            return self.err(ParseErrorKind::OutOfRange01, "「0-1」区间外的值（建议：`0<x<1`）");
         }
         // 构造预算
         let budget = match num {
@@ -938,7 +1806,15 @@ impl<'a> ParseState<'a, &'a str> {
             _ => Budget::new_triple(p, d, q),
         };
         // 跳过右括弧
-        self.head_skip_after_spaces(self.format.task.budget_brackets.1);
+        // * ⚠️不同于其它条目，输入末尾也必须有右括弧
+        //   * 📄单独的独立变量`$1`：若视作「缺右括弧的预算值」，将解析不出词项
+        //   * 🚩报错后回退，转而尝试解析词项
+        self.head_skip_spaces();
+        if !self.starts_with(self.format.task.budget_brackets.1) {
+            let right = self.format.task.budget_brackets.1;
+            return self.err(ParseErrorKind::UnclosedBracket, &format!("缺少右括弧{right:?}"));
+        }
+        self.head_skip(self.format.task.budget_brackets.1);
         // 直接置入预算值 | 因为先前`consume_one`已经假定「未曾置入预算值」
         let _ = self.mid_result.budget.insert(budget);
         Self::ok_consume()
@@ -950,26 +1826,96 @@ impl<'a> ParseState<'a, &'a str> {
     fn consume_term(&mut self) -> ConsumeResult {
         // 先解析词项
         let term = self.parse_term()?;
+        // 查询变量后紧跟问题标点⇒检查歧义
+        self.verify_query_variable_explicit(&term)?;
         // 直接置入词项 | 因为先前`consume_one`已经假定「未曾置入词项」
         let _ = self.mid_result.term.insert(term);
         Self::ok_consume()
     }
 
+    /// 判断「查询变量」与「问题标点」是否有歧义
+    /// * 📌查询变量前缀与问题标点相同⇒有歧义
+    ///   * 📄ASCII、LaTeX：均为`?`
+    fn is_query_variable_ambiguous(&self) -> bool {
+        self.format.atom.prefix_variable_query == self.format.sentence.punctuation_question
+    }
+
+    /// 检查「查询变量」与「问题标点」的歧义
+    /// * 🎯「要求显式」时，`?x?`须写作`?x ?`
+    /// * 🚩查询变量之后紧跟问题标点⇒报错
+    ///   * 📌该错误在[`Self::consume_one`]中直接上抛，不会被「标点在前」的读法掩盖
+    /// * 📄参见[`AmbiguityResolution`]
+    #[inline(never)]
+    fn verify_query_variable_explicit(&self, term: &Term) -> ConsumeResult {
+        match term {
+            Term::VariableQuery(..)
+                if self.format.ambiguity_resolution == AmbiguityResolution::RequireExplicit
+                    && self.is_query_variable_ambiguous()
+                    && self.starts_with(self.format.sentence.punctuation_question) =>
+            {
+                self.err(ParseErrorKind::Ambiguous, AMBIGUOUS_QUERY_VARIABLE_MESSAGE)
+            }
+            _ => Self::ok_consume(),
+        }
+    }
+
+    /// 消解「无标点的查询变量」的歧义
+    /// * 🎯`?x`：查询变量，或「标点在前」的问题`x?`
+    /// * 🚩按格式的消歧策略处理
+    ///   * 偏向变量⇒不变
+    ///   * 偏向标点⇒改为「以词语提问」
+    ///     * ⚠️名称不能作词语名（如`?_x`）⇒仍读作查询变量
+    ///   * 要求显式⇒报错
+    /// * 📄参见[`AmbiguityResolution`]
+    fn resolve_query_variable_ambiguity(&mut self) -> ConsumeResult {
+        let name = match (&self.mid_result.term, &self.mid_result.punctuation) {
+            (Some(Term::VariableQuery(name)), None) if self.is_query_variable_ambiguous() => name,
+            _ => return Self::ok_consume(),
+        };
+        match self.format.ambiguity_resolution {
+            AmbiguityResolution::PreferVariable => Self::ok_consume(),
+            AmbiguityResolution::PreferPunctuation => {
+                let Ok(term) = Term::try_new_word(name.clone()) else {
+                    return Self::ok_consume();
+                };
+                self.mid_result.term = Some(term);
+                self.mid_result.punctuation = Some(Punctuation::Question);
+                Self::ok_consume()
+            }
+            AmbiguityResolution::RequireExplicit => {
+                self.err(ParseErrorKind::Ambiguous, AMBIGUOUS_QUERY_VARIABLE_MESSAGE)
+            }
+        }
+    }
+
     /// 消耗&解析/词项
     /// * 🎯仍然只负责分派方法
     /// * ⚠️解析的同时跳过词项
     ///   * 乃至无需`?`语法糖（错误直接传递，而无需提取值）
+    /// * 🚩超出「最大嵌套深度」时直接报错，不再深入
     fn parse_term(&mut self) -> ParseResult<Term> {
+        if self.depth >= self.format.max_depth {
+            return self.err(ParseErrorKind::Other(MAX_DEPTH_MESSAGE.into()), MAX_DEPTH_MESSAGE);
+        }
+        self.depth += 1;
+        let result = self.parse_term_nested();
+        self.depth -= 1;
+        result
+    }
+
+    /// 消耗&解析/词项（内部实现）
+    /// * 🚩根据开头分派到各类词项
+    fn parse_term_nested(&mut self) -> ParseResult<Term> {
         first! {
-            (self.starts_with) => (_);
+            (self.starts_with_token) => (_);
             // 词项/外延集
-            self.format.compound.brackets_set_extension.0 => self.parse_compound_set_extension(),
+            self.tokens.set_extension_brackets.0 => self.parse_compound_set_extension(),
             // 词项/内涵集
-            self.format.compound.brackets_set_intension.0 => self.parse_compound_set_intension(),
+            self.tokens.set_intension_brackets.0 => self.parse_compound_set_intension(),
             // 词项/复合词项
-            self.format.compound.brackets.0 => self.parse_compound(),
+            self.tokens.compound_brackets.0 => self.parse_compound(),
             // 词项/陈述
-            self.format.statement.brackets.0 => self.parse_statement(),
+            self.tokens.statement_brackets.0 => self.parse_statement(),
             // 词项/原子（兜底）
             _ => self.parse_atom()
         }
@@ -979,21 +1925,28 @@ impl<'a> ParseState<'a, &'a str> {
     /// * ⚠️必须保证从「可消耗的词项」开始
     ///   * ✅"term1, term2"
     ///   * ❌" term1, term2"
+    /// * 📌不强制要求分隔符：上一词项结束后可直接开始新词项
+    ///   * 📄OpenNARS输出中的`(&/, <a --> b>, +5 <c --> d>)`
     /// * 📌自动内联
     #[inline(always)]
     fn parse_compound_terms(
         &mut self,
         target: &mut Vec<Term>,
-        right_bracket: &str,
+        right_bracket: Token,
     ) -> ConsumeResult {
         while self.can_consume() {
+            // 换行等单字符空白⇒跳过
+            if self.starts_with_space_char() {
+                self.head_step_one();
+                continue;
+            }
             first! {
                 // 检查开头
-                (self.starts_with) => (_);
+                (self.starts_with_token) => (_);
                 // 空白⇒跳过
-                self.format.space.parse => self.head_skip(self.format.space.parse),
+                self.tokens.space => self.head_skip_token(self.tokens.space),
                 // 分隔符⇒跳过
-                self.format.compound.separator => self.head_skip(self.format.compound.separator),
+                self.tokens.separator => self.head_skip_token(self.tokens.separator),
                 // 右括号⇒停止 // ! 跳过的逻辑交由调用者
                 right_bracket => break,
                 // 其它⇒尝试置入词项
@@ -1014,22 +1967,32 @@ impl<'a> ParseState<'a, &'a str> {
     fn parse_term_set(
         &mut self,
         mut terms: Vec<Term>,
-        left_bracket: &str,
-        right_bracket: &str,
+        (left_bracket, right_bracket): (Token, Token),
     ) -> ParseResult<Vec<Term>> {
         // 跳过左括弧&连续空白
         self.head_skip_and_spaces(left_bracket);
         // 填充词项序列
         self.parse_compound_terms(&mut terms, right_bracket)?;
+        // 检查组分，并跳过右括弧
+        self.finish_term_set(terms, right_bracket)
+    }
+
+    /// 工具函数/「词项集」的收尾部分
+    /// * 🚩跳过连续空白&右括弧，再检查组分
+    /// * ⚠️不内联：避免增大递归解析的栈帧
+    #[inline(never)]
+    fn finish_term_set(&mut self, terms: Vec<Term>, right_bracket: Token) -> ParseResult<Vec<Term>> {
         // 跳过连续空白&右括弧
-        self.head_skip_after_spaces(right_bracket);
-        // 判空&返回
-        match terms.is_empty() {
+        self.head_skip_after_spaces(right_bracket)?;
+        // 判空
+        if terms.is_empty() {
             // 空集⇒驳回
-            true => self.err("词项集为空"),
-            // 非空⇒成功
-            false => Self::ok(terms),
+            return self.err(ParseErrorKind::MissingTerm, "词项集为空");
         }
+        // 检查重复组分
+        self.check_duplicates(&terms)?;
+        // 返回
+        Self::ok(terms)
     }
 
     /// 消耗&置入/词项/复合（外延集）
@@ -1037,11 +2000,7 @@ impl<'a> ParseState<'a, &'a str> {
     /// * 📌需要在此完成专有的挪位
     fn parse_compound_set_extension(&mut self) -> ParseResult<Term> {
         // 解析词项集&组分
-        let terms = self.parse_term_set(
-            vec![],
-            self.format.compound.brackets_set_extension.0,
-            self.format.compound.brackets_set_extension.1,
-        )?; // * 📝不用考虑空间开销，编译器自己懂得内联
+        let terms = self.parse_term_set(vec![], self.tokens.set_extension_brackets)?; // * 📝不用考虑空间开销，编译器自己懂得内联
             // 返回成功
         Self::ok(Term::new_set_extension(terms))
     }
@@ -1051,19 +2010,17 @@ impl<'a> ParseState<'a, &'a str> {
     /// * 📌需要在此完成专有的挪位
     fn parse_compound_set_intension(&mut self) -> ParseResult<Term> {
         // 解析词项集&组分
-        let terms = self.parse_term_set(
-            vec![],
-            self.format.compound.brackets_set_intension.0,
-            self.format.compound.brackets_set_intension.1,
-        )?; // * 📝不用考虑空间开销，编译器自己懂得内联
+        let terms = self.parse_term_set(vec![], self.tokens.set_intension_brackets)?; // * 📝不用考虑空间开销，编译器自己懂得内联
             // 返回成功
         Self::ok(Term::new_set_intension(terms))
     }
 
     /// 工具函数/像
     /// * 🚩找到并删除首个像占位符，并返回索引
-    /// * 📌自动内联
-    #[inline(always)]
+    /// * ⚠️多于一个占位符⇒报错，而非将多余的占位符当作组分
+    ///   * 📄参见[`IMAGE_PLACEHOLDERS_MESSAGE`]
+    /// * ⚠️不内联：避免增大递归解析的栈帧
+    #[inline(never)]
     fn parse_terms_with_image(&self, terms: &mut Vec<Term>) -> ParseResult<UIntPrecision> {
         // 找到首个像占位符的位置
         let placeholder_index = terms.iter().position(|term| *term == Term::Placeholder);
@@ -1073,11 +2030,15 @@ impl<'a> ParseState<'a, &'a str> {
             Some(index) => {
                 // 删除此处的像占位符
                 terms.remove(index);
+                // 仍有占位符⇒报错
+                if terms.contains(&Term::Placeholder) {
+                    return self.err(ParseErrorKind::DuplicateItem, IMAGE_PLACEHOLDERS_MESSAGE);
+                }
                 // 返回成功
                 Self::ok(index)
             }
             // 返回失败
-            None => self.err("未在词项序列中找到占位符"),
+            None => self.err(ParseErrorKind::MissingTerm, "未在词项序列中找到占位符"),
         }
     }
 
@@ -1087,51 +2048,31 @@ impl<'a> ParseState<'a, &'a str> {
     /// * 🚩采用「先构造词项，再填充元素」的构造方法
     ///   * ❗因为需要「根据连接符取得相应类型」且「根据后边序列取得元素」
     ///   * 📌对于「创建时就需指定所有元素」的「一元复合词项」「二元复合词项」，使用「占位符」预先占位
+    /// * ⚠️递归解析的一环：填充组分的逻辑放在[`Self::finish_compound`]中，以减小栈帧
     fn parse_compound(&mut self) -> ParseResult<Term> {
         // 跳过左括弧&连续空白
-        self.head_skip_and_spaces(self.format.compound.brackets.0);
+        self.head_skip_and_spaces(self.tokens.compound_brackets.0);
+        // OpenNARS风格操作 | 以「操作符前缀」开头
+        if self.starts_with_token(self.tokens.prefix_operator) {
+            return self.parse_compound_operator();
+        }
         // 解析连接符
-        let mut term = first_prefix_and_skip_first! {
-            self;
-            // ! 暂不支持OpenNARS风格操作
-            self.format.atom.prefix_operator => return self.err("暂不支持OpenNARS风格`(^操作名, 参数)`操作，建议使用`<(*, 参数) --> 操作名>`代替"),
-            // NAL-5 // ! ⚠️长的`&&`必须比短的`&`先匹配（`||`、`--`同理）
-            // 合取 | 🚩空数组
-            self.format.compound.connecter_conjunction => Term::new_conjunction(vec![]),
-            // 析取 | 🚩空数组
-            self.format.compound.connecter_disjunction => Term::new_disjunction(vec![]),
-            // 否定 | 🚩使用占位符初始化，后续将被覆盖
-            self.format.compound.connecter_negation => Term::new_negation(Term::new_placeholder()),
-            // NAL-7 //
-            // 顺序合取 | 🚩空数组
-            self.format.compound.connecter_conjunction_sequential => Term::new_conjunction_sequential(vec![]),
-            // 平行合取 | 🚩空数组
-            self.format.compound.connecter_conjunction_parallel => Term::new_conjunction_parallel(vec![]),
-            // NAL-3 //
-            // 外延交 | 🚩空数组
-            self.format.compound.connecter_intersection_extension => Term::new_intersection_extension(vec![]),
-            // 内涵交 | 🚩空数组
-            self.format.compound.connecter_intersection_intension => Term::new_intersection_intension(vec![]),
-            // 外延差 | 🚩使用占位符初始化，后续将被覆盖
-            self.format.compound.connecter_difference_extension => Term::new_difference_extension(Term::new_placeholder(),Term::new_placeholder()),
-            // 内涵差 | 🚩使用占位符初始化，后续将被覆盖
-            self.format.compound.connecter_difference_intension => Term::new_difference_intension(Term::new_placeholder(),Term::new_placeholder()),
-            // NAL-4 //
-            // 乘积 | 🚩空数组
-            self.format.compound.connecter_product => Term::new_product(vec![]),
-            // 外延像 | 🚩空数组&0索引
-            self.format.compound.connecter_image_extension => Term::new_image_extension(0, vec![]),
-            // 内涵像 | 🚩空数组&0索引
-            self.format.compound.connecter_image_intension => Term::new_image_intension(0, vec![]),
-            // 未知 //
-            _ => return self.err("未知的复合词项连接符"),
-        };
+        let term = self.parse_connecter()?;
         // 解析组分
         let mut terms = vec![];
-        self.parse_compound_terms(&mut terms, self.format.compound.brackets.1)?;
+        self.parse_compound_terms(&mut terms, self.tokens.compound_brackets.1)?;
+        // 填充组分，并跳过右括弧
+        self.finish_compound(term, terms)
+    }
+
+    /// 消耗&解析/复合词项的收尾部分
+    /// * 🚩向「待填充组分」的复合词项填充组分，再跳过连续空白&右括弧
+    /// * ⚠️不内联：避免增大递归解析的栈帧
+    #[inline(never)]
+    fn finish_compound(&mut self, mut term: Term, mut terms: Vec<Term>) -> ParseResult<Term> {
         // ! 不允许空集
         if terms.is_empty() {
-            return self.err("复合词项内容不能为空");
+            return self.err(ParseErrorKind::MissingTerm, "复合词项内容不能为空");
         }
         // 填充组分 | 此处类似「针对容量」但实际上还是需要「具体类型具体填充」
         match &mut term {
@@ -1139,12 +2080,10 @@ impl<'a> ParseState<'a, &'a str> {
             Term::Negation(inner_box) => {
                 // 检查长度
                 if terms.len() != 1 {
-                    return self.err("一元内容长度不为1");
+                    return self.err(ParseErrorKind::MissingTerm, "一元内容长度不为1");
                 }
-                // 解包并追加进第一个元素
-                // 📝Rust支持对函数结果（只要是引用）进行「解引用赋值」
-                *inner_box.as_mut() = unsafe { terms.pop().unwrap_unchecked() };
-                // ! ↑SAFETY: 上方「检查长度」已确保是非空集
+                // 解包并覆盖唯一的元素
+                fill_slots([inner_box], terms);
             }
             // 二元序列⇒覆盖 | 📌实际上「蕴含」「等价」都算
             Term::DifferenceExtension(ref1, ref2)
@@ -1155,12 +2094,10 @@ impl<'a> ParseState<'a, &'a str> {
             | Term::Equivalence(ref1, ref2) => {
                 // 检查长度
                 if terms.len() != 2 {
-                    return self.err("二元序列长度不为2");
+                    return self.err(ParseErrorKind::MissingTerm, "二元序列长度不为2");
                 }
-                // 解包并倒序追加俩元素
-                // ! ↑SAFETY: 上方「检查长度」已确保是非空集
-                *ref2.as_mut() = unsafe { terms.pop().unwrap_unchecked() };
-                *ref1.as_mut() = unsafe { terms.pop().unwrap_unchecked() };
+                // 解包并依次覆盖俩元素
+                fill_slots([ref1, ref2], terms);
             }
             // 二元集合⇒清空&重新添加 | ⚠️暂时没有
             // 像：特殊处理
@@ -1174,91 +2111,221 @@ impl<'a> ParseState<'a, &'a str> {
             }
             // 其它（序列/集合）⇒直接添加 | 📌其一定为复合词项，但对「二元词项」会报错
             _ => {
+                // 无序容器⇒检查重复组分
+                if term.get_capacity() == TermCapacity::Set {
+                    self.check_duplicates(&terms)?;
+                }
                 // 直接识别并传播错误
                 if let Err(err) = term.push_components(terms) {
-                    return self.err(&err.to_string());
+                    return self.err_push_components(err);
                 }
             }
         }
         // 跳过连续空白&右括弧
-        self.head_skip_after_spaces(self.format.compound.brackets.1);
+        self.head_skip_after_spaces(self.tokens.compound_brackets.1)?;
         // 返回
         Self::ok(term)
     }
 
+    /// 工具函数/检查将要放入无序容器的组分
+    /// * 🚩按格式的「重复组分策略」处理：报错，或记录警告
+    /// * 📄参见[`NarseseFormat::check_duplicate_components`]
+    /// * ⚠️不内联：避免增大递归解析的栈帧
+    #[inline(never)]
+    fn check_duplicates(&mut self, terms: &[Term]) -> ConsumeResult {
+        match self.format.check_duplicate_components(terms) {
+            Ok(None) => Self::ok_consume(),
+            Ok(Some(warning)) => {
+                let warning = self.parse_error(ParseErrorKind::DuplicateItem, &warning);
+                self.warnings.push(warning);
+                Self::ok_consume()
+            }
+            Err(message) => self.err(ParseErrorKind::DuplicateItem, &message),
+        }
+    }
+
+    /// 工具函数/将「追加组分」的错误转换为解析错误
+    /// * 📌原错误作为内部错误
+    /// * ⚠️不内联：避免增大递归解析的栈帧
+    #[inline(never)]
+    fn err_push_components<T>(&self, error: TermEditError) -> ParseResult<T> {
+        let message = error.to_string();
+        Err(self
+            .parse_error(ParseErrorKind::Other(message.clone()), &message)
+            .with_source(error))
+    }
+
+    /// 消耗&解析/复合词项连接符
+    /// * 🚩匹配并跳过连接符，返回「待填充组分」的复合词项
+    /// * ⚠️不内联：避免增大递归解析的栈帧
+    #[inline(never)]
+    fn parse_connecter(&mut self) -> ParseResult<Term> {
+        first_prefix_and_skip_first! {
+            self;
+            // NAL-5 // ! ⚠️长的`&&`必须比短的`&`先匹配（`||`、`--`同理）
+            // 合取 | 🚩空数组
+            self.tokens.connecter_conjunction => Ok(Term::new_conjunction(vec![])),
+            // 析取 | 🚩空数组
+            self.tokens.connecter_disjunction => Ok(Term::new_disjunction(vec![])),
+            // 否定 | 🚩使用占位符初始化，后续将被覆盖
+            self.tokens.connecter_negation => Ok(Term::new_negation(Term::new_placeholder())),
+            // NAL-7 //
+            // 顺序合取 | 🚩空数组
+            self.tokens.connecter_conjunction_sequential => Ok(Term::new_conjunction_sequential(vec![])),
+            // 平行合取 | 🚩空数组
+            self.tokens.connecter_conjunction_parallel => Ok(Term::new_conjunction_parallel(vec![])),
+            // NAL-3 //
+            // 外延交 | 🚩空数组
+            self.tokens.connecter_intersection_extension => Ok(Term::new_intersection_extension(vec![])),
+            // 内涵交 | 🚩空数组
+            self.tokens.connecter_intersection_intension => Ok(Term::new_intersection_intension(vec![])),
+            // 外延差 | 🚩使用占位符初始化，后续将被覆盖
+            self.tokens.connecter_difference_extension => Ok(Term::new_difference_extension(Term::new_placeholder(),Term::new_placeholder())),
+            // 内涵差 | 🚩使用占位符初始化，后续将被覆盖
+            self.tokens.connecter_difference_intension => Ok(Term::new_difference_intension(Term::new_placeholder(),Term::new_placeholder())),
+            // NAL-4 //
+            // 乘积 | 🚩空数组
+            self.tokens.connecter_product => Ok(Term::new_product(vec![])),
+            // 外延像 | 🚩空数组&0索引
+            self.tokens.connecter_image_extension => Ok(Term::new_image_extension(0, vec![])),
+            // 内涵像 | 🚩空数组&0索引
+            self.tokens.connecter_image_intension => Ok(Term::new_image_intension(0, vec![])),
+            // 未知 //
+            _ => self.err(ParseErrorKind::UnknownConnecter, "未知的复合词项连接符"),
+        }
+    }
+
+    /// 消耗&置入/词项/操作（OpenNARS风格）
+    /// * 📄`(^操作名, 参数1, 参数2)`：ONA、OpenNARS的输出日志中常见
+    /// * 📌传入之前提：已跳过左括弧，且头部为「操作符前缀」
+    /// * 🚩脱糖为`<(*, 参数1, 参数2) --> ^操作名>`
+    ///   * 📌参数可以为空：`(^操作名)` ⇒ `<(*) --> ^操作名>`
+    ///   * ⚠️格式化时不会还原此语法
+    fn parse_compound_operator(&mut self) -> ParseResult<Term> {
+        // 解析操作符 | 复用原子词项的解析
+        let operator = self.parse_atom()?;
+        // 解析参数
+        let mut arguments = vec![];
+        self.parse_compound_terms(&mut arguments, self.tokens.compound_brackets.1)?;
+        // 跳过连续空白&右括弧
+        self.head_skip_spaces();
+        if !self.starts_with_token(self.tokens.compound_brackets.1) {
+            return self.err(ParseErrorKind::UnclosedBracket, "缺少复合词项右括弧");
+        }
+        self.head_skip_token(self.tokens.compound_brackets.1);
+        // 脱糖
+        Self::ok(Term::new_inheritance(Term::new_product(arguments), operator))
+    }
+
     /// 消耗&置入/词项/陈述
     /// * 📌传入之前提：已识别出相应的「特征开头」
     /// * 📌需要在此完成专有的挪位
+    /// * ⚠️递归解析的一环：除解析主词、谓词外的逻辑均放在不内联的函数中，以减小栈帧
+    ///   * 📄默认的[最大嵌套深度](NarseseFormat::max_depth)下，嵌套陈述在2MiB的线程栈上亦不会栈溢出
     fn parse_statement(&mut self) -> ParseResult<Term> {
         // 跳过左括弧&连续空白
-        self.head_skip_and_spaces(self.format.statement.brackets.0);
+        self.head_skip_and_spaces(self.tokens.statement_brackets.0);
         // 解析主词
         let subject = self.parse_term()?;
+        // 解析系词
+        let copula = self.parse_statement_copula()?;
+        // 解析谓词
+        let predicate = self.parse_term()?;
+        // 构造陈述，并跳过右括弧
+        self.finish_statement(copula, subject, predicate)
+    }
+
+    /// 消耗&解析/陈述的系词部分
+    /// * 🚩跳过系词前后的空白，返回系词在[`FormatTokens::copulas`]中的索引
+    /// * ⚠️不内联：避免增大递归解析的栈帧
+    #[inline(never)]
+    fn parse_statement_copula(&mut self) -> ParseResult<usize> {
         // 跳过空白
         self.head_skip_spaces();
-        // 使用闭包简化「跳过空白⇒解析谓词」的操作
-        // * 💭实际上是一种「先进行后处理，然后处理中间分派的结果」的思想
-        // * 📌产生原因：先根据遇到的「连接词」生成词项，然后才能解析并置入后边的谓词
-        // * 📝此中不能直接捕获`self`（会捕获所有权），需要引入`Self`类型的可变引用作为参数
-        //    * 保证对象安全
-        let parse_predicate = |self_: &mut Self| {
-            // 跳过空白
-            self_.head_skip_spaces();
-            // 解析谓词
-            self_.parse_term()
-        };
         // 解析系词
-        let term = first_prefix_and_skip_first! {
-            // 先匹配，然后跳过，再执行分支内的代码
-            self;
+        let Some(copula) = self.parse_copula() else {
+            return self.err(ParseErrorKind::UnknownCopula, "未知的陈述系词");
+        };
+        // 跳过空白
+        self.head_skip_spaces();
+        Self::ok(copula)
+    }
+
+    /// 消耗&解析/陈述的收尾部分
+    /// * 🚩构造陈述，再跳过连续空白&右括弧
+    /// * ⚠️不内联：避免增大递归解析的栈帧
+    #[inline(never)]
+    fn finish_statement(&mut self, copula: usize, subject: Term, predicate: Term) -> ParseResult<Term> {
+        let term = self.build_statement(copula, subject, predicate);
+        // 跳过连续空白&右括弧
+        self.head_skip_after_spaces(self.tokens.statement_brackets.1)?;
+        // 返回
+        Self::ok(term)
+    }
+
+    /// 构造/陈述
+    /// * 🚩根据系词在[`FormatTokens::copulas`]中的索引，构造相应的陈述
+    fn build_statement(&self, copula: usize, subject: Term, predicate: Term) -> Term {
+        match copula {
             // 继承
-            self.format.statement.copula_inheritance => Term::new_inheritance(subject, parse_predicate(self)?),
+            COPULA_INHERITANCE => Term::new_inheritance(subject, predicate),
             // 相似
-            self.format.statement.copula_similarity => Term::new_similarity(subject, parse_predicate(self)?),
+            COPULA_SIMILARITY => Term::new_similarity(subject, predicate),
             // 蕴含
-            self.format.statement.copula_implication => Term::new_implication(subject, parse_predicate(self)?),
+            COPULA_IMPLICATION => Term::new_implication(subject, predicate),
             // 等价
-            self.format.statement.copula_equivalence => Term::new_equivalence(subject, parse_predicate(self)?),
+            COPULA_EQUIVALENCE => Term::new_equivalence(subject, predicate),
             // 实例
-            self.format.statement.copula_instance => Term::new_instance(subject, parse_predicate(self)?),
+            COPULA_INSTANCE => Term::new_instance(subject, predicate),
             // 属性
-            self.format.statement.copula_property => Term::new_property(subject, parse_predicate(self)?),
+            COPULA_PROPERTY => Term::new_property(subject, predicate),
             // 实例属性
-            self.format.statement.copula_instance_property => Term::new_instance_property(subject, parse_predicate(self)?),
+            COPULA_INSTANCE_PROPERTY => Term::new_instance_property(subject, predicate),
             // 预测性蕴含
-            self.format.statement.copula_implication_predictive => Term::new_implication_predictive(subject, parse_predicate(self)?),
+            COPULA_IMPLICATION_PREDICTIVE => Term::new_implication_predictive(subject, predicate),
             // 并发性蕴含
-            self.format.statement.copula_implication_concurrent => Term::new_implication_concurrent(subject, parse_predicate(self)?),
+            COPULA_IMPLICATION_CONCURRENT => Term::new_implication_concurrent(subject, predicate),
             // 回顾性蕴含
-            self.format.statement.copula_implication_retrospective => Term::new_implication_retrospective(subject, parse_predicate(self)?),
+            COPULA_IMPLICATION_RETROSPECTIVE => Term::new_implication_retrospective(subject, predicate),
             // 预测性等价
-            self.format.statement.copula_equivalence_predictive => Term::new_equivalence_predictive(subject, parse_predicate(self)?),
+            COPULA_EQUIVALENCE_PREDICTIVE => Term::new_equivalence_predictive(subject, predicate),
             // 并发性等价
-            self.format.statement.copula_equivalence_concurrent => Term::new_equivalence_concurrent(subject, parse_predicate(self)?),
-            // 回顾性等价 | ⚠️会在构造时自动转换
-            self.format.statement.copula_equivalence_retrospective => Term::new_equivalence_retrospective(subject, parse_predicate(self)?),
-            // 未知 //
-            _ => return self.err("未知的陈述系词"),
-        };
-        // 跳过连续空白&右括弧
-        self.head_skip_after_spaces(self.format.statement.brackets.1);
-        // 返回
-        Self::ok(term)
+            COPULA_EQUIVALENCE_CONCURRENT => Term::new_equivalence_concurrent(subject, predicate),
+            // 回顾性等价 | ⚠️可按格式配置自动转换
+            COPULA_EQUIVALENCE_RETROSPECTIVE => match self.format.normalize_retrospective {
+                true => Term::new_equivalence_retrospective_normalized(subject, predicate),
+                false => Term::new_equivalence_retrospective(subject, predicate),
+            },
+            // 系词索引只来自[`Self::parse_copula`]
+            _ => unreachable!("未知的系词索引：{copula}"),
+        }
+    }
+
+    /// 消耗&解析/陈述系词
+    /// * 🚩匹配并跳过系词，返回其在[`FormatTokens::copulas`]中的索引
+    ///   * 📌按[`NarseseFormat::copulas`]的顺序匹配首个系词
+    /// * ⚠️不内联：避免增大递归解析的栈帧
+    #[inline(never)]
+    fn parse_copula(&mut self) -> Option<usize> {
+        let index = self
+            .tokens
+            .copulas
+            .iter()
+            .position(|copula| self.starts_with_token(*copula))?;
+        self.head_skip_token(self.tokens.copulas[index]);
+        Some(index)
     }
 
     /// 判断环境位置是否以系词开头
     /// * 🎯兼容 `^go-to` `坐标-5-6`
     /// * 🎯避免`<外延-->内涵>`变成`外延--`、`>`、`内涵`
     fn is_copula_starts_at_head(&self, start: usize) -> bool {
-        // 获取环境后缀切片
-        let env_slice = &self.env[start..];
-        // 开始遍历
-        self.format
+        self.tokens
             // 所有系词
-            .copulas()
-            .into_iter()
-            // 是否有任意一个是「环境切片」的开头
-            .any(|copula| env_slice.starts_with_str(copula))
+            .copulas
+            .iter()
+            // 是否有任意一个是「环境」在该位置的开头
+            .any(|copula| self.env.starts_with_token_at(start, copula, &self.tokens))
     }
 
     /// 消耗&置入/词项/原子
@@ -1269,21 +2336,21 @@ impl<'a> ParseState<'a, &'a str> {
         let mut term = first_prefix_and_skip_first! {
             self;
             // 占位符 | 此举相当于识别以「_」开头的词项
-            self.format.atom.prefix_placeholder => Term::new_placeholder(),
+            self.tokens.prefix_placeholder => Term::new_placeholder(),
             // 独立变量
-            self.format.atom.prefix_variable_independent => Term::new_variable_independent(""),
+            self.tokens.prefix_variable_independent => Term::VariableIndependent(String::new()),
             // 非独变量
-            self.format.atom.prefix_variable_dependent => Term::new_variable_dependent(""),
+            self.tokens.prefix_variable_dependent => Term::VariableDependent(String::new()),
             // 查询变量
-            self.format.atom.prefix_variable_query => Term::new_variable_query(""),
+            self.tokens.prefix_variable_query => Term::VariableQuery(String::new()),
             // 间隔
-            self.format.atom.prefix_interval => Term::new_interval(0),
+            self.tokens.prefix_interval => Term::new_interval(0),
             // 操作符
-            self.format.atom.prefix_operator => Term::new_operator(""),
+            self.tokens.prefix_operator => Term::Operator(String::new()),
             // 词语 | ⚠️必须以此兜底（空字串也算前缀）
-            self.format.atom.prefix_word => Term::new_word(""),
+            self.tokens.prefix_word => Term::Word(String::new()),
             _ => {
-                return self.err("未知的原子词项前缀")
+                return self.err(ParseErrorKind::UnknownPrefix, "未知的原子词项前缀")
             }
         };
         // 新建缓冲区
@@ -1299,6 +2366,14 @@ impl<'a> ParseState<'a, &'a str> {
             if self.is_copula_starts_at_head(self.head) {
                 break;
             }
+            // 间隔⇒截止于首个非数字字符
+            // * 🎯兼容OpenNARS输出中「间隔后缺少分隔符」的顺序合取：`(&/, a, +5b)`
+            if matches!(term, Term::Interval(..))
+                && !name_buffer.is_empty()
+                && !head_char.is_ascii_digit()
+            {
+                break;
+            }
             // 尝试解析
             match (self.format.is_valid_atom_name)(head_char) {
                 // 合法词项名字符⇒加入缓冲区&递进
@@ -1318,15 +2393,28 @@ impl<'a> ParseState<'a, &'a str> {
         }
         // 非「占位符」检验名称非空
         if name_buffer.is_empty() {
-            return self.err("词项名不能为空");
+            return self.err(ParseErrorKind::MissingTerm, "词项名不能为空");
+        }
+        // 变量名之后紧跟左括弧⇒带依赖列表的变量 | 📄OpenNARS输出中的`#1(#2)`
+        // * ⚠️枚举Narsese暂不支持：直接报错，以免将依赖列表误作复合词项
+        if matches!(
+            term,
+            Term::VariableIndependent(..) | Term::VariableDependent(..) | Term::VariableQuery(..)
+        ) && self.starts_with_token(self.tokens.compound_brackets.0)
+        {
+            return self.err(
+                ParseErrorKind::Other(VARIABLE_DEPENDENCY_LIST_MESSAGE.into()),
+                VARIABLE_DEPENDENCY_LIST_MESSAGE,
+            );
         }
         // 尝试将缓冲区转为词项名，返回词项/错误
         // ! ❌【2024-03-20 21:55:48】此处无法使用[`transform`]：闭包の所有权と生命周期の问题
-        match term.set_atom_name(&name_buffer) {
+        // * 🚩不检验名称：字符已由格式检验
+        match term.set_atom_name_unchecked(&name_buffer) {
             // 成功⇒返回词项
             Ok(_) => Ok(term),
             // 失败⇒传播错误 | 💭总是要转换错误类型
-            Err(_) => self.err(&format!("非法词项名 {name_buffer:?}")),
+            Err(_) => self.err(ParseErrorKind::UnexpectedChar, &format!("非法词项名 {name_buffer:?}")),
         }
     }
 
@@ -1338,39 +2426,57 @@ impl<'a> ParseState<'a, &'a str> {
     ///   * 📌可以使用`take`实现：
     ///     * 1 移交所有权给调用者
     ///     * 2 将自身设置为`None`
-    fn form_term(&mut self) -> Term {
-        self.mid_result.term.take().unwrap()
+    /// * ❌缺失⇒报错（而非panic）
+    fn form_term(&mut self) -> ParseResult<Term> {
+        match self.mid_result.term.take() {
+            Some(term) => Ok(term),
+            None => self.err(ParseErrorKind::MissingTerm, "词项缺失"),
+        }
     }
 
     /// 组装 | 将「中间结果」转换为语句
     /// * 📌其中「词项」「标点」必须具有
-    ///   * ⚠️若无⇒`panic`（所以请确保有）
+    ///   * ❌若无⇒报错
     /// * 📝在「中间结果内联入状态」后，需要「使用[`Option::take`]转交所有权」并对代码进行拆分
+    ///
     /// ! 📝不能混用「结构体整体」`result: MidParseResult`与其成员：无法「部分移动」所有权
     ///   * 📌【2024-02-20 21:56:21】现在又可复用「转换词项」「转换语句」了
     ///     * 原因：使用[`Option::take`]避开了所有权冲突
-    fn form_sentence(&mut self) -> Sentence {
-        Sentence::from_punctuation(
-            // 必要的「词项」「标点」
-            self.form_term(),
-            self.mid_result.punctuation.take().unwrap(),
-            // ! 默认时间戳为「永恒」
-            self.mid_result.stamp.take().unwrap_or(Stamp::Eternal),
-            // ! 默认真值为「空真值」
-            self.mid_result.truth.take().unwrap_or(Truth::new_empty()),
-        )
+    /// * 🚩严格模式⇒「问题」「请求」带有真值时报错；否则舍去真值
+    fn form_sentence(&mut self) -> ParseResult<Sentence> {
+        // 必要的「词项」「标点」
+        let term = self.form_term()?;
+        let punctuation = match self.mid_result.punctuation.take() {
+            Some(punctuation) => punctuation,
+            None => return self.err(ParseErrorKind::MissingTerm, "标点缺失"),
+        };
+        // ! 默认时间戳为「永恒」
+        let stamp = self.mid_result.stamp.take().unwrap_or(Stamp::Eternal);
+        // ! 默认真值为「空真值」
+        let truth = self.mid_result.truth.take().unwrap_or(Truth::new_empty());
+        match self.format.strict_truth {
+            true => Sentence::try_from_punctuation(term, punctuation, stamp, truth)
+                .map_err(|error| {
+                    let message = error.to_string();
+                    self.parse_error(ParseErrorKind::Other(message.clone()), &message)
+                        .with_source(error)
+                }),
+            false => Ok(Sentence::from_punctuation(term, punctuation, stamp, truth)),
+        }
     }
 
     /// 组装 | 将「中间结果」转换为任务
     /// * 📌其中「预算」「词项」「标点」必须具有
-    ///   * ⚠️若无⇒`panic`（所以请确保有）
+    ///   * ❌若无⇒报错
+    ///
     /// ! 📝无法复用[`form_sentence`]代码：无法复用所有权
-    fn form_task(&mut self) -> Task {
-        Task::new(
-            self.form_sentence(),
-            // 必要的「预算值」
-            self.mid_result.budget.take().unwrap(),
-        )
+    fn form_task(&mut self) -> ParseResult<Task> {
+        let sentence = self.form_sentence()?;
+        // 必要的「预算值」
+        match self.mid_result.budget.take() {
+            Some(budget) => Ok(Task::new(sentence, budget)),
+            None => self.err(ParseErrorKind::MissingTerm, "预算值缺失"),
+        }
     }
 
     /// 组装 | 将「中间结果」转换为最终结果
@@ -1380,6 +2486,8 @@ impl<'a> ParseState<'a, &'a str> {
     ///   * 📌必须先【可变借用】产生「元素」，再【不可变借用】产生「结果」
     ///   * 📌【2024-02-20 21:55:25】现在重新
     fn transform_mid_result(&mut self) -> ParseResult {
+        // 先消解「查询变量」与「问题标点」的歧义
+        self.resolve_query_variable_ambiguity()?;
         // 直接匹配各个属性 | 按照CommonNarsese语序`预算值 词项 标点 时间戳 真值`排列
         match (
             // ! 📝此处必须要用「不可变借用」以避免「部分所有权移动」问题
@@ -1390,27 +2498,27 @@ impl<'a> ParseState<'a, &'a str> {
             &self.mid_result.truth,
         ) {
             // 没词项不行
-            (_, None, _, _, _) => self.err("词项缺失"),
+            (_, None, _, _, _) => self.err(ParseErrorKind::MissingTerm, "词项缺失"),
             // 有预算&标点&词项⇒任务
             (Some(_), Some(_), Some(_), ..) => {
                 // !【2024-02-20 21:58:21】必须先进行可变借用
-                let value = self.form_task();
+                let value = self.form_task()?;
                 // 然后再进行不可变借用（以构造最终值）
-                Self::ok(NarseseResult::Task(value))
+                Self::ok(NarseseResult::from_task(value))
             }
             // else有标点&词项⇒语句
             (_, Some(_), Some(_), ..) => {
                 // !【2024-02-20 21:58:21】必须先进行可变借用
-                let value = self.form_sentence();
+                let value = self.form_sentence()?;
                 // 然后再进行不可变借用（以构造最终值）
-                Self::ok(NarseseResult::Sentence(value))
+                Self::ok(NarseseResult::from_sentence(value))
             }
             // else有词项⇒词项
             (_, Some(_), ..) => {
                 // !【2024-02-20 21:58:21】必须先进行可变借用
-                let value = self.form_term();
+                let value = self.form_term()?;
                 // 然后再进行不可变借用（以构造最终值）
-                Self::ok(NarseseResult::Term(value))
+                Self::ok(NarseseResult::from_term(value))
             }
         }
     }
@@ -1418,9 +2526,14 @@ impl<'a> ParseState<'a, &'a str> {
 
 /// 解析状态的入口实现
 /// * 🎯多态化`parse`函数：简写`parse_budget`、`parse_truth`等函数
-impl<'s> FromParse<(), &'s mut ParseState<'_>> for ParseResult {
+impl<'s, 'a, E: ParseEnvironment<'a>> FromParse<(), &'s mut ParseState<'a, &'a str, E>>
+    for ParseResult {
     /// 原先在[`ParseState`]的「解析总入口」留到这儿执行
-    fn from_parse(_: (), parser: &'s mut ParseState) -> Self {
+    fn from_parse(_: (), parser: &'s mut ParseState<'a, &'a str, E>) -> Self {
+        // 预先判空 | 🎯输入为空时，不进行后续解析
+        if parser.is_empty_input() {
+            return parser.err(ParseErrorKind::EmptyInput, EMPTY_INPUT_MESSAGE);
+        }
         // 消耗文本，构建「中间解析结果」
         parser.build_mid_result()?;
         // 转换解析结果
@@ -1431,9 +2544,10 @@ impl<'s> FromParse<(), &'s mut ParseState<'_>> for ParseResult {
 /// 解析状态的入口实现
 /// * 🎯用于自定义的「条目提取」功能
 /// * 🎯最初用于「词法折叠」，但后续不用
-impl<'s> FromParse<(), &'s mut ParseState<'_>> for ParseResult<MidParseResult> {
+impl<'s, 'a, E: ParseEnvironment<'a>> FromParse<(), &'s mut ParseState<'a, &'a str, E>>
+    for ParseResult<MidParseResult> {
     /// 原先在[`ParseState`]的「解析总入口」留到这儿执行
-    fn from_parse(_: (), parser: &'s mut ParseState) -> Self {
+    fn from_parse(_: (), parser: &'s mut ParseState<'a, &'a str, E>) -> Self {
         // 消耗文本，构建「中间解析结果」
         parser.build_mid_result()?;
         // 直接拿走并返回「中间解析结果」
@@ -1441,13 +2555,14 @@ impl<'s> FromParse<(), &'s mut ParseState<'_>> for ParseResult<MidParseResult> {
     }
 }
 
-impl<'s> FromParse<(), &'s mut ParseState<'_>> for ParseResult<Truth> {
+impl<'s, 'a, E: ParseEnvironment<'a>> FromParse<(), &'s mut ParseState<'a, &'a str, E>>
+    for ParseResult<Truth> {
     /// 侧门/解析真值
     /// * 🎯用于单独解析真值
     ///   * 📄【2024-03-20 14:14:51】最初case: 词法折叠
     /// * 🚩直接消耗一个真值，然后返回
     /// * 📄case: `%0.5; 0.5%`
-    fn from_parse(_: (), parser: &'s mut ParseState) -> Self {
+    fn from_parse(_: (), parser: &'s mut ParseState<'a, &'a str, E>) -> Self {
         // 尝试消耗一个真值
         // ! 不能「消耗条目，然后默认条目」：还是原子词项的问题（LaTeX/漢文 情况）
         // * ✅【2024-03-21 00:26:05】安全：有进行长度检验
@@ -1457,7 +2572,7 @@ impl<'s> FromParse<(), &'s mut ParseState<'_>> for ParseResult<Truth> {
             .mid_result
             .truth
             .take()
-            .ok_or(parser.parse_error("无法解析出真值"))?;
+            .ok_or(parser.parse_error(ParseErrorKind::MissingTerm, "无法解析出真值"))?;
         // 返回真值
         // ! 📌这里引用关联函数无法自动使用`C`的默认值，干脆直接上`Ok`
         // ! 否则就要用`ParseState::<&'a str>::ok(truth)`，过于冗长
@@ -1465,16 +2580,17 @@ impl<'s> FromParse<(), &'s mut ParseState<'_>> for ParseResult<Truth> {
     }
 }
 
-impl<'s> FromParse<(), &'s mut ParseState<'_>> for ParseResult<Stamp> {
+impl<'s, 'a, E: ParseEnvironment<'a>> FromParse<(), &'s mut ParseState<'a, &'a str, E>>
+    for ParseResult<Stamp> {
     /// 侧门/解析时间戳
     /// * 🎯用于单独解析时间戳
     ///   * 📄【2024-03-20 14:14:51】最初case: 词法折叠
     /// * 🚩直接消耗一个时间戳，然后返回
     /// * 📄case: `:|:` ``
-    fn from_parse(_: (), parser: &'s mut ParseState) -> Self {
+    fn from_parse(_: (), parser: &'s mut ParseState<'a, &'a str, E>) -> Self {
         // 空字串⇒时间戳「永恒」 | 中间结果折叠 也是直接硬编码
         if_return! {
-            parser.env.is_empty() => ParseResult::Ok(Stamp::Eternal)
+            parser.len_env == 0 => ParseResult::Ok(Stamp::Eternal)
         }
         // 尝试消耗一个时间戳
         // ! 不能「消耗条目，然后默认条目」：还是原子词项的问题（LaTeX/漢文 情况）
@@ -1485,7 +2601,7 @@ impl<'s> FromParse<(), &'s mut ParseState<'_>> for ParseResult<Stamp> {
             .mid_result
             .stamp
             .take()
-            .ok_or(parser.parse_error("无法解析出时间戳"))?;
+            .ok_or(parser.parse_error(ParseErrorKind::MissingTerm, "无法解析出时间戳"))?;
         // 返回时间戳
         // ! 📌这里引用关联函数无法自动使用`C`的默认值，干脆直接上`Ok`
         // ! 否则就要用`ParseState::<&'a str>::ok(truth)`，过于冗长
@@ -1493,13 +2609,14 @@ impl<'s> FromParse<(), &'s mut ParseState<'_>> for ParseResult<Stamp> {
     }
 }
 
-impl<'s> FromParse<(), &'s mut ParseState<'_>> for ParseResult<Punctuation> {
+impl<'s, 'a, E: ParseEnvironment<'a>> FromParse<(), &'s mut ParseState<'a, &'a str, E>>
+    for ParseResult<Punctuation> {
     /// 侧门/解析标点
     /// * 🎯用于单独解析标点
     ///   * 📄【2024-03-20 14:14:51】最初case: 词法折叠
     /// * 🚩直接消耗一个标点，然后返回
     /// * 📄case: `.`
-    fn from_parse(_: (), parser: &'s mut ParseState) -> Self {
+    fn from_parse(_: (), parser: &'s mut ParseState<'a, &'a str, E>) -> Self {
         // 尝试消耗一个标点 | 默认这个条目会是标点
         // * ✅【2024-03-21 00:26:05】安全：有进行长度检验
         parser.consume_punctuation()?;
@@ -1508,7 +2625,7 @@ impl<'s> FromParse<(), &'s mut ParseState<'_>> for ParseResult<Punctuation> {
             .mid_result
             .punctuation
             .take()
-            .ok_or(parser.parse_error("无法解析出标点"))?;
+            .ok_or(parser.parse_error(ParseErrorKind::MissingTerm, "无法解析出标点"))?;
         // 返回标点
         // ! 📌这里引用关联函数无法自动使用`C`的默认值，干脆直接上`Ok`
         // ! 否则就要用`ParseState::<&'a str>::ok(truth)`，过于冗长
@@ -1516,13 +2633,14 @@ impl<'s> FromParse<(), &'s mut ParseState<'_>> for ParseResult<Punctuation> {
     }
 }
 
-impl<'s> FromParse<(), &'s mut ParseState<'_>> for ParseResult<Budget> {
+impl<'s, 'a, E: ParseEnvironment<'a>> FromParse<(), &'s mut ParseState<'a, &'a str, E>>
+    for ParseResult<Budget> {
     /// 侧门/解析预算值
     /// * 🎯用于单独解析预算值
     ///   * 📄【2024-03-20 14:14:51】最初case: 词法折叠
     /// * 🚩直接消耗一个预算值，然后返回
     /// * 📄case: `$0.5; 0.5; 0.5$`
-    fn from_parse(_: (), parser: &'s mut ParseState) -> Self {
+    fn from_parse(_: (), parser: &'s mut ParseState<'a, &'a str, E>) -> Self {
         // 尝试消耗一个预算值
         // ! 不能「消耗条目，然后默认条目」：还是原子词项的问题（LaTeX/漢文 情况）
         // * ✅【2024-03-21 00:26:05】安全：有进行长度检验
@@ -1532,7 +2650,7 @@ impl<'s> FromParse<(), &'s mut ParseState<'_>> for ParseResult<Budget> {
             .mid_result
             .budget
             .take()
-            .ok_or(parser.parse_error("无法解析出预算值"))?;
+            .ok_or(parser.parse_error(ParseErrorKind::MissingTerm, "无法解析出预算值"))?;
         // 返回预算值
         // ! 📌这里引用关联函数无法自动使用`C`的默认值，干脆直接上`Ok`
         // ! 否则就要用`ParseState::<&'a str>::ok(truth)`，过于冗长
@@ -1542,33 +2660,249 @@ impl<'s> FromParse<(), &'s mut ParseState<'_>> for ParseResult<Budget> {
 
 /// 主解析实现 | 从解析格式开始解析
 impl NarseseFormat<&str> {
+    /// 按「重复组分策略」检查将要放入无序容器的组分
+    /// * 🎯统一「解析」与「词法折叠」时的重复组分处理
+    /// * 🚩无重复组分⇒`Ok(None)`
+    /// * 🚩有重复组分⇒按策略返回警告或报错消息
+    ///   * [`DuplicatePolicy::Dedupe`]、[`DuplicatePolicy::Keep`]⇒`Ok(Some(警告))`：随后去重
+    ///   * [`DuplicatePolicy::Error`]⇒`Err(报错)`
+    ///   * 📌消息中含有（首个）「重复组分」的格式化文本
+    pub(crate) fn check_duplicate_components(
+        &self,
+        terms: &[Term],
+    ) -> Result<Option<String>, String> {
+        // 逐个检查 | 📌与无序容器一致地使用散列集：「对称陈述」等的散列与判等亦一致
+        let mut seen = std::collections::HashSet::new();
+        let Some(term) = terms.iter().find(|term| !seen.insert(*term)) else {
+            return Ok(None);
+        };
+        let term = self.format_term(term);
+        match self.duplicate_policy {
+            DuplicatePolicy::Dedupe => Ok(Some(format!("无序复合词项中的重复组分「{term}」已被去重"))),
+            DuplicatePolicy::Keep => Ok(Some(format!("无序复合词项无法保留重复组分「{term}」，已去重"))),
+            DuplicatePolicy::Error => Err(format!("无序复合词项中存在重复组分「{term}」")),
+        }
+    }
+
     /// 构造解析状态
     /// * 索引默认从开头开始
-    pub(crate) fn build_parse_state<'a>(&'a self, input: &'a str) -> ParseState<'_, &str> {
+    pub(crate) fn build_parse_state<'a>(&'a self, input: &'a str) -> ParseState<'a, &'a str> {
         ParseState::new(self, input, 0)
     }
 
-    /// 主解析函数
-    /// * ✨【2024-03-20 15:30:43】现在支持任意扩展可解析的目标类型，而无需为之分别添加函数
-    ///   * 📌通过模仿标准库[`String::parse`]与[`From`]实现
-    /// * ❌无法通过特别的「输入」泛型进行非破坏性修改
-    ///   * 新增泛型参数：即便【能自动推断】仍然需要手动填入⇒影响已有代码
-    pub fn parse<'a, To>(&'a self, input: &'a str) -> ParseResult<To>
-    where
-        ParseResult<To>: FromParse<&'a str, &'a Self>,
-    {
-        // 调用关联函数进行解析
-        ParseResult::from_parse(input, self)
+    /// 主解析函数
+    /// * ✨【2024-03-20 15:30:43】现在支持任意扩展可解析的目标类型，而无需为之分别添加函数
+    ///   * 📌通过模仿标准库[`String::parse`]与[`From`]实现
+    /// * ❌无法通过特别的「输入」泛型进行非破坏性修改
+    ///   * 新增泛型参数：即便【能自动推断】仍然需要手动填入⇒影响已有代码
+    pub fn parse<'a, To>(&'a self, input: &'a str) -> ParseResult<To>
+    where
+        ParseResult<To>: FromParse<&'a str, &'a Self>,
+    {
+        // 调用关联函数进行解析
+        ParseResult::from_parse(input, self)
+    }
+
+    /// 主解析函数 for `Vec<char>`
+    /// * 🎯最初用于自动解析宏（不涉及内部`ParseState`类型）
+    pub fn parse_chars<'a, To>(&'a self, input: Vec<char>) -> ParseResult<To>
+    where
+        ParseResult<To>: FromParse<Vec<char>, &'a Self>,
+    {
+        // 调用关联函数进行解析
+        ParseResult::from_parse(input, self)
+    }
+
+    /// 主解析函数（零拷贝版本）
+    /// * 🎯批量解析大量短输入：省去每次解析时「收集字符数组」的分配
+    /// * 🚩直接在`&str`上解析，索引按字节计
+    ///   * 📌解析结果（含错误信息）与[`Self::parse`]完全一致
+    pub fn parse_str_fast(&self, input: &str) -> ParseResult {
+        ParseState::<&str, &str>::new(self, input, 0).parse()
+    }
+
+    /// 解析，并返回解析中产生的警告
+    /// * 🎯不影响解析结果，但可能出乎意料的情况
+    ///   * 📄`{A, A, B}`：重复组分被去重（参见[`DuplicatePolicy`]）
+    /// * 🚩解析结果与[`Self::parse`]一致
+    /// * 📌警告与错误共用[`ParseError`]：含消息、种类、位置
+    pub fn parse_with_warnings(&self, input: &str) -> (ParseResult, Vec<ParseError>) {
+        let mut state = self.build_parse_state(input);
+        let result = state.parse();
+        (result, state.warnings)
+    }
+
+    /// 前缀解析函数
+    /// * 🎯在更大的语法中嵌入Narsese：只解析开头的一个Narsese值
+    ///   * 📄`believe(<A --> B>., 0.9)`
+    /// * 🚩直接在`&str`上解析，索引按字节计
+    /// * ⚙️返回Narsese值与「首个未消耗字符」的字节偏移
+    /// * 📌其后的非Narsese内容不视作错误
+    pub fn parse_prefix(&self, input: &str) -> ParseResult<(Narsese, usize)> {
+        let mut state = ParseState::<&str, &str>::new(self, input, 0);
+        // 预先判空
+        if state.is_empty_input() {
+            return state.err(ParseErrorKind::EmptyInput, EMPTY_INPUT_MESSAGE);
+        }
+        let end = state.build_mid_result_prefix()?;
+        let narsese = state.transform_mid_result()?;
+        Ok((narsese, end))
+    }
+
+    /// 单条目解析
+    /// * 🎯统一「单独解析真值/预算值/时间戳/标点」的逻辑
+    /// * 🚩跳过空白⇒检查左括弧⇒消耗条目⇒跳过空白⇒检查多余内容⇒取出条目
+    ///   * 📌左括弧为空⇒不检查（标点）
+    /// * ⚠️条目之后有多余内容⇒报错
+    fn parse_single_item<'a, T>(
+        &'a self,
+        input: &'a str,
+        name: &str,
+        left_bracket: fn(&FormatTokens<'a>) -> Option<Token<'a>>,
+        consume: fn(&mut ParseState<'a, &'a str, &'a str>) -> ConsumeResult,
+        take: fn(&mut MidParseResult) -> Option<T>,
+    ) -> ParseResult<T> {
+        let mut state = ParseState::<&str, &str>::new(self, input, 0);
+        state.head_skip_spaces();
+        // 预先判空
+        if !state.can_consume() {
+            return state.err(ParseErrorKind::EmptyInput, EMPTY_INPUT_MESSAGE);
+        }
+        // 检查左括弧 | ⚠️「消耗」函数默认已识别出左括弧
+        if let Some(left) = left_bracket(&state.tokens) {
+            if !state.starts_with_token(left) {
+                return state.err(ParseErrorKind::UnclosedBracket, &format!("缺少{name}的左括弧"));
+            }
+        }
+        consume(&mut state)?;
+        // 检查多余内容
+        state.head_skip_spaces();
+        if state.can_consume() {
+            return state.err(ParseErrorKind::UnexpectedChar, &format!("{name}之后有多余内容"));
+        }
+        match take(&mut state.mid_result) {
+            Some(item) => Ok(item),
+            None => state.err(ParseErrorKind::MissingTerm, &format!("无法解析出{name}")),
+        }
+    }
+
+    /// 解析真值
+    /// * 📄`%1.0;0.9%`、`%1.0%`、`%%`
+    /// * 📌空白输入⇒空真值：与[`Self::format_truth`]对应
+    /// * 📌允许前后空白，不允许多余内容
+    pub fn parse_truth(&self, input: &str) -> ParseResult<Truth> {
+        if input.trim().is_empty() {
+            return Ok(Truth::new_empty());
+        }
+        self.parse_single_item(
+            input,
+            "真值",
+            |tokens| Some(tokens.truth_brackets.0),
+            ParseState::consume_truth,
+            |mid_result| mid_result.truth.take(),
+        )
+    }
+
+    /// 解析预算值
+    /// * 📄`$0.5;0.5;0.5$`、`$0.5$`、`$$`
+    /// * 📌允许前后空白，不允许多余内容
+    pub fn parse_budget(&self, input: &str) -> ParseResult<Budget> {
+        self.parse_single_item(
+            input,
+            "预算值",
+            |tokens| Some(tokens.budget_brackets.0),
+            ParseState::consume_budget,
+            |mid_result| mid_result.budget.take(),
+        )
+    }
+
+    /// 解析时间戳
+    /// * 📄`:|:`、`:\:`、`:/:`、`:!-1:`
+    /// * 📌空白输入⇒永恒：与[`Self::format_stamp`]对应
+    /// * 📌允许前后空白，不允许多余内容
+    pub fn parse_stamp(&self, input: &str) -> ParseResult<Stamp> {
+        if input.trim().is_empty() {
+            return Ok(Stamp::Eternal);
+        }
+        self.parse_single_item(
+            input,
+            "时间戳",
+            |tokens| Some(tokens.stamp_brackets.0),
+            ParseState::consume_stamp,
+            |mid_result| mid_result.stamp.take(),
+        )
+    }
+
+    /// 解析标点
+    /// * 📄`.`、`!`、`?`、`@`
+    /// * 📌允许前后空白，不允许多余内容
+    pub fn parse_punctuation(&self, input: &str) -> ParseResult<Punctuation> {
+        self.parse_single_item(
+            input,
+            "标点",
+            |_| None,
+            ParseState::consume_punctuation,
+            |mid_result| mid_result.punctuation.take(),
+        )
+    }
+
+    /// 宽松解析
+    /// * 🎯编辑器集成：某条目出错时，仍然给出其它条目
+    ///   * 📄`<A --> B>. %1.0;2.0%`⇒语句（无真值）+真值的错误
+    /// * 🚩出错的条目留空，跳至下一个可识别的边界后继续解析；最后折叠已有的条目
+    ///   * 📌词项缺失且已有错误⇒不再重复报告「词项缺失」
+    /// * ⚠️与严格解析相同的歧义：ASCII中出错的预算值`$0.5;9$`，其开头可能被解析为独立变量`$0`
+    /// * 📌不影响[`Self::parse`]：默认仍在首个错误处中止
+    pub fn parse_lenient(&self, input: &str) -> (Option<NarseseResult>, Vec<ParseError>) {
+        let mut state = self.build_parse_state(input);
+        // 预先判空
+        if state.is_empty_input() {
+            return (None, vec![state.parse_error(ParseErrorKind::EmptyInput, EMPTY_INPUT_MESSAGE)]);
+        }
+        let mut errors = state.build_mid_result_lenient();
+        if state.mid_result.term.is_none() && !errors.is_empty() {
+            return (None, errors);
+        }
+        match state.transform_mid_result() {
+            Ok(narsese) => (Some(narsese), errors),
+            Err(error) => {
+                errors.push(error);
+                (None, errors)
+            }
+        }
     }
 
-    /// 主解析函数 for `Vec<char>`
-    /// * 🎯最初用于自动解析宏（不涉及内部`ParseState`类型）
-    pub fn parse_chars<'a, To>(&'a self, input: Vec<char>) -> ParseResult<To>
-    where
-        ParseResult<To>: FromParse<Vec<char>, &'a Self>,
-    {
-        // 调用关联函数进行解析
-        ParseResult::from_parse(input, self)
+    /// 宽松解析/条目
+    /// * 🎯返回「中间解析结果」：即便无法折叠成Narsese值，也能得到已解析出的条目
+    ///   * 📄`<A --> >.`⇒无词项，但有标点「判断」
+    /// * 🚩同[`Self::parse_lenient`]，但不折叠
+    pub fn parse_items_lenient(
+        &self,
+        input: &str,
+    ) -> (
+        NarseseOptions<Budget, Term, Punctuation, Stamp, Truth>,
+        Vec<ParseError>,
+    ) {
+        let mut state = self.build_parse_state(input);
+        // 预先判空
+        if state.is_empty_input() {
+            return (MidParseResult::new(), vec![state.parse_error(ParseErrorKind::EmptyInput, EMPTY_INPUT_MESSAGE)]);
+        }
+        let errors = state.build_mid_result_lenient();
+        (state.mid_result.take(), errors)
+    }
+
+    /// 解析并驻留词项
+    /// * 🎯批量加载时，让结构相同的词项共享内存
+    /// * 🚩解析出Narsese值后，将其中的词项放入驻留池[`TermPool`]
+    ///   * 📌语句、任务⇒标点、时间戳、真值、预算值原样保留
+    pub fn parse_interned(
+        &self,
+        input: &str,
+        pool: &mut TermPool,
+    ) -> ParseResult<InternedNarsese> {
+        let narsese = self.parse_str_fast(input)?;
+        Ok(pool.intern_narsese(narsese))
     }
 
     /// 解析多个Narsese
@@ -1594,6 +2928,65 @@ impl NarseseFormat<&str> {
         results
         // ! 随后丢弃状态
     }
+
+    /// 一次解析多条语句
+    /// * 🎯直接解析`.nal`文件等「多条语句」的完整内容
+    /// * 🚩自行切分语句：按行切分，去除注释，跳过空行与整行注释
+    ///   * 📄参见[`Self::statement_lines`]
+    ///   * 📌与[`Self::parse_multi`]不同：后者的每个输入即一条语句
+    /// * 🚩每条逻辑语句对应一个解析结果
+    pub fn parse_all(&self, input: &str) -> Vec<ParseResult> {
+        self.parse_multi(self.statement_lines(input))
+    }
+
+    /// 一次解析多条语句（迭代器版本）
+    /// * 🚩语句切分同[`Self::parse_all`]，但惰性解析
+    pub fn parse_iter<'a>(&'a self, input: &'a str) -> impl Iterator<Item = ParseResult> + 'a {
+        self.statement_lines(input)
+            .map(|statement| self.parse(statement))
+    }
+
+    /// 解析多个Narsese，并分离成功与失败
+    /// * ✨相比[`Self::parse_multi`]，返回可直接遍历「成功值」的[`ParsedBatch`]
+    /// * 🚩错误连同其「输入索引」一并保存
+    pub fn parse_multi_collect<'a, Inputs>(&'a self, inputs: Inputs) -> ParsedBatch<Narsese, ParseError>
+    where
+        Inputs: IntoIterator<Item = &'a str>,
+    {
+        ParsedBatch::from_results(self.parse_multi(inputs))
+    }
+
+    /// 带位置的流式解析
+    /// * 🎯按行解析多条Narsese，并给每条结果附上「行号/列号/来源名」
+    ///   * 📄后续的校验等出错时，可据此报告`file.nal:123`
+    /// * 🚩每个非空行视作一条Narsese；空行被跳过（但计入行号）
+    /// * 🚩解析错误的位置（行号、列号、索引、字节偏移）均相对于整个输入
+    /// * 📌不影响[`NarseseFormat::parse`]等「纯值」API
+    pub fn parse_stream_located<'a>(
+        &'a self,
+        input: &'a str,
+        source_name: Option<Arc<str>>,
+    ) -> impl Iterator<Item = Located<ParseResult>> + 'a {
+        // 已扫过的「字节偏移/字符索引」：逐行递进，避免重复计数
+        let (mut scanned_bytes, mut scanned_chars) = (0, 0);
+        located_lines(input, source_name).map(move |line| {
+            // 行切片在整个输入中的字节偏移
+            let byte_offset = line.value.as_ptr() as usize - input.as_ptr() as usize;
+            scanned_chars += input[scanned_bytes..byte_offset].chars().count();
+            scanned_bytes = byte_offset;
+            let origin = ParseOrigin {
+                index: scanned_chars,
+                byte_offset,
+                line: line.line,
+                column: line.col,
+            };
+            line.map(|input| {
+                let mut state = self.build_parse_state(input);
+                state.origin = origin;
+                state.parse()
+            })
+        })
+    }
 }
 
 /// 对所有「能被[`ParseState`]解析出来的」实现「能被[`NarseseFormat`]解析出来」
@@ -1640,16 +3033,37 @@ where
     }
 }
 
+// 实现[`FromStr`]：基于ASCII格式解析
+// * 🎯支持`let t: Term = "<A --> B>".parse()?;`
+crate::api::impl_from_str!(ParseError, |s| FORMAT_ASCII.parse::<Narsese>(s); Narsese => Term, Sentence, Task);
+
 /// 单元测试
 #[cfg(test)]
 mod tests_parse {
     use super::{super::format_instances::*, *};
-    use nar_dev_utils::{f_tensor, fail_tests, show};
+    use crate::api::FromStrError;
+    use nar_dev_utils::{asserts, f_tensor, fail_tests, show};
+
+    /// 通用测试/解析，并检验「零拷贝解析」与之一致
+    /// * 🚩成功⇒值相等；失败⇒错误信息（含位置）相等
+    /// * 🎯所有经由此处的测试输入，都会同时检验两种解析路径
+    fn _test_parse_both(format: &NarseseFormat<&str>, input: &str) -> ParseResult {
+        let result = format.parse(input);
+        let fast = format.parse_str_fast(input);
+        match (&result, &fast) {
+            (Ok(expected), Ok(fast)) => assert_eq!(expected, fast, "{input:?}"),
+            (Err(expected), Err(fast)) => {
+                assert_eq!(expected.to_string(), fast.to_string(), "{input:?}")
+            }
+            _ => panic!("零拷贝解析结果不一致：{input:?}\n{result:?}\n{fast:?}"),
+        }
+        result
+    }
 
     /// 通通用测试/尝试解析并返回错误
     fn __test_parse(format: &NarseseFormat<&str>, input: &str) -> NarseseResult {
         // 解析
-        let result = format.parse(input);
+        let result = _test_parse_both(format, input);
         // 检验
         match result {
             // 词项⇒解析出词项
@@ -1797,13 +3211,68 @@ mod tests_parse {
         show!(matrix);
     }
 
+    /// 测试/顺序合取中「间隔后缺少分隔符」
+    /// * 📄OpenNARS输出：`(&/, <a --> b>, +5 <c --> d>)`
+    #[test]
+    fn test_parse_interval_without_separator() {
+        let format = &FORMAT_ASCII;
+        let expected = _test_parse_term(format, "(&/, <a --> b>, +5, <c --> d>)");
+        let expected_word = _test_parse_term(format, "(&/, a, +5, c)");
+        asserts! {
+            _test_parse_term(format, "(&/, <a --> b>, +5 <c --> d>)") => expected.clone(),
+            _test_parse_term(format, "(&/, <a --> b>, +5<c --> d>)") => expected.clone(),
+            _test_parse_term(format, "(&/,<a --> b>,+5 <c --> d>)") => expected,
+            // 间隔之后紧跟原子词项
+            _test_parse_term(format, "(&/, a, +5 c)") => expected_word.clone(),
+            _test_parse_term(format, "(&/, a, +5c)") => expected_word,
+            // 多位数字
+            _test_parse_term(format, "(&/, a, +120b)") => _test_parse_term(format, "(&/, a, +120, b)"),
+        }
+        // 间隔名称仍须以数字开头
+        assert!(format.parse::<Narsese>("(&/, a, +c)").is_err());
+    }
+
+    /// 测试/复合词项/OpenNARS风格操作
+    /// * 🚩脱糖为「乘积→操作符」的继承
+    #[test]
+    fn test_parse_compound_operator() {
+        let format = &FORMAT_ASCII;
+        let operation = |arguments: Vec<Term>, name: &str| {
+            Term::new_inheritance(Term::new_product(arguments), Term::new_operator(name))
+        };
+        asserts! {
+            // 无参数
+            _test_parse_term(format, "(^操作名)") => operation(vec![], "操作名"),
+            _test_parse_term(format, "( ^left )") => operation(vec![], "left"),
+            // 单参数
+            _test_parse_term(format, "(^操作名, 参数)") => operation(vec![Term::new_word("参数")], "操作名"),
+            _test_parse_term(format, "(^go-to, {SELF})") => _test_parse_term(format, "<(*, {SELF}) --> ^go-to>"),
+            // 多参数
+            _test_parse_term(format, "(^pick, {SELF}, $x, (&&, A, B))")
+                => _test_parse_term(format, "<(*, {SELF}, $x, (&&, A, B)) --> ^pick>"),
+            // 嵌套
+            _test_parse_term(format, "<(^left, {SELF}) =/> <A --> B>>")
+                => _test_parse_term(format, "<<(*, {SELF}) --> ^left> =/> <A --> B>>"),
+        }
+        // 语句中
+        assert_eq!(
+            format.parse::<Narsese>("(^left, {SELF})! :|:").unwrap(),
+            format.parse::<Narsese>("<(*, {SELF}) --> ^left>! :|:").unwrap()
+        );
+        // 未闭合
+        for input in ["(^left, {SELF}", "(^left", "(^, A)"] {
+            let result = format.parse::<Narsese>(input);
+            show!(&result);
+            assert!(result.is_err());
+        }
+    }
+
     // 测试/复合词项/失败
     fail_tests_parse! {
         // 格式/测试函数
         FORMAT_ASCII;
         _test_parse_term;
         // 情形
-        test_parse_compound_fail_唯一操作表达式 => "(^操作名, 参数)"
         test_parse_compound_fail_无起始符1 => ")"
         test_parse_compound_fail_无起始符2 => "}"
         test_parse_compound_fail_无起始符3 => "]"
@@ -1817,6 +3286,8 @@ mod tests_parse {
         test_parse_compound_fail_多余元素_内涵差 => "( ~, 要被减掉, 被减掉了, 我是多余的)"
         test_parse_compound_fail_缺少占位符_外延像 => "( /, 为什么, 这里没有, 占位符呢)"
         test_parse_compound_fail_缺少占位符_内涵像 => "( \\, 为什么, 这里没有, 占位符呢)"
+        test_parse_compound_fail_多余占位符_外延像 => "(/, A, _, _, B)"
+        test_parse_compound_fail_多余占位符_内涵像 => "(\\, _, A, _)"
         test_parse_compound_fail_多余元素_否定 => "( --  , 我是被否定的, 我是多余的)"
         test_parse_compound_fail_未知连接符 => "(我是未知的, word, ^op)"
     }
@@ -1926,6 +3397,125 @@ mod tests_parse {
         test_parse_truth_fail_超范围2 => "A. %1;-1%"
         test_parse_truth_fail_超范围3 => "A. %2;1%"
         test_parse_truth_fail_超范围4 => "A. %1;2%"
+        test_parse_truth_fail_指数超范围 => "A. %1e5%"
+        test_parse_truth_fail_无效指数 => "A. %1e;0.9%"
+        test_parse_truth_fail_多个符号 => "A. %+-1;0.9%"
+    }
+
+    /// 测试/三真值
+    /// * 🎯关闭时保持现有行为（报错）；开启时可往返
+    #[test]
+    fn test_parse_extended_truth() {
+        use crate::api::GetTruth;
+        let extended = &NarseseFormat {
+            allow_extended_truth: true,
+            ..FORMAT_ASCII
+        };
+        let input = "<A --> B>. %1.0;0.9;4%";
+        let parsed = extended.parse::<Narsese>(input).unwrap();
+        let formatted = extended.format_narsese(&parsed);
+        show!(&parsed, &formatted);
+        asserts! {
+            // 关闭⇒报错
+            FORMAT_ASCII.parse::<Narsese>(input).is_err(),
+            // 开启⇒三真值
+            parsed.clone().try_into_sentence().unwrap().get_truth().cloned() => Some(Truth::new_triple(1.0, 0.9, 4.0)),
+            formatted => "<A --> B>. %1;0.9;4%",
+            extended.parse::<Narsese>(&formatted).unwrap() => parsed,
+            // 开启后，一、二分量的真值照常解析
+            extended.parse::<Narsese>("<A --> B>. %1.0;0.9%").ok() => FORMAT_ASCII.parse::<Narsese>("<A --> B>. %1.0;0.9%").ok(),
+            // 分量过多、证据数为负⇒报错
+            extended.parse::<Narsese>("<A --> B>. %1.0;0.9;4;1%").is_err(),
+            extended.parse::<Narsese>("<A --> B>. %1.0;0.9;-4%").is_err(),
+        }
+    }
+
+    /// 测试/「标点-真值」一致性：宽松与严格模式
+    #[test]
+    fn test_parse_strict_truth() {
+        let lenient = &FORMAT_ASCII;
+        let strict = &NarseseFormat {
+            strict_truth: true,
+            ..FORMAT_ASCII
+        };
+        let parse = |format: &NarseseFormat<&str>, input: &str| format.parse::<Narsese>(input);
+        // 判断、目标：两种模式下都保留真值
+        for input in ["<A --> B>. %1.0%", "<A --> B>! %1.0;0.9%", "$0.5$ <A --> B>. %1.0%"] {
+            let expected = parse(lenient, input).unwrap();
+            let without_truth = &input[..input.find('%').unwrap()];
+            asserts! {
+                parse(strict, input).unwrap() => expected,
+                parse(lenient, without_truth).unwrap() != expected,
+            }
+        }
+        // 问题、请求：宽松模式舍去真值，严格模式报错
+        for input in ["<A --> B>? %1.0%", "<A --> B>@ %1.0;0.9%", "$0.5$ <A --> B>? %1.0%"] {
+            let without_truth = &input[..input.find('%').unwrap()];
+            asserts! {
+                parse(lenient, input).unwrap() => parse(lenient, without_truth).unwrap(),
+                parse(strict, input).is_err(),
+            }
+        }
+        // 问题、请求：没有真值或空真值⇒严格模式下同样可以
+        for input in ["<A --> B>?", "<A --> B>@", "<A --> B>? %%", "$0.5$ <A --> B>@"] {
+            assert_eq!(parse(strict, input).unwrap(), parse(lenient, input).unwrap());
+        }
+    }
+
+    /// 测试/回顾性等价：规范化与保留原样
+    #[test]
+    fn test_parse_normalize_retrospective() {
+        let normalized = &FORMAT_ASCII;
+        let preserved = &NarseseFormat {
+            normalize_retrospective: false,
+            ..FORMAT_ASCII
+        };
+        let term = |format: &NarseseFormat<&str>, input: &str| {
+            format.parse::<Narsese>(input).unwrap().try_into_term().unwrap()
+        };
+        let a = Term::new_word("A");
+        let b = Term::new_word("B");
+        // 默认：自动转换为「预测性等价」
+        let input = r"<A <\> B>";
+        asserts! {
+            term(normalized, input) => Term::new_equivalence_predictive(b.clone(), a.clone()),
+            normalized.format_term(&term(normalized, input)) => "<B </> A>",
+        }
+        // 关闭：保留原样，可往返
+        let retrospective = term(preserved, input);
+        asserts! {
+            retrospective => Term::new_equivalence_retrospective(a.clone(), b.clone()),
+            retrospective != Term::new_equivalence_predictive(b.clone(), a.clone()),
+            preserved.format_term(&retrospective) => input,
+            // 显式规范化：与默认模式结果一致
+            retrospective.normalize_temporal() => term(normalized, input),
+            // 嵌套：递归规范化
+            term(preserved, r"(&&, <A <\> B>, C)").normalize_temporal() => term(normalized, r"(&&, <A <\> B>, C)"),
+        }
+        // 其它格式：可正常格式化并解析回来
+        for format in [&FORMAT_LATEX, &FORMAT_HAN] {
+            let preserved = &NarseseFormat {
+                normalize_retrospective: false,
+                ..format.clone()
+            };
+            let formatted = preserved.format_term(&retrospective);
+            asserts! {
+                formatted.contains(format.statement.copula_equivalence_retrospective),
+                term(preserved, &formatted) => retrospective,
+            }
+        }
+    }
+
+    /// 测试/真值、预算值中的科学计数法与正号
+    #[test]
+    fn test_parse_float_notation() {
+        let format = &FORMAT_ASCII;
+        asserts! {
+            _test_parse_sentence(format, "A. %1e0;9e-1%") => _test_parse_sentence(format, "A. %1.0;0.9%"),
+            _test_parse_sentence(format, "A. %+0.5;0.9%") => _test_parse_sentence(format, "A. %0.5;0.9%"),
+            _test_parse_sentence(format, "A. %1E-2;+.9%") => _test_parse_sentence(format, "A. %0.01;0.9%"),
+            _test_parse_task(format, "$5e-1;+7.5E-1;4e-1$ A. %1e0%") => _test_parse_task(format, "$0.5;0.75;0.4$ A. %1.0%"),
+        }
     }
 
     /// 测试/预算值（任务）
@@ -1961,6 +3551,7 @@ mod tests_parse {
         test_parse_budget_fail_超范围4 => "$2;1;1$ A."
         test_parse_budget_fail_超范围5 => "$1;2;1$ A."
         test_parse_budget_fail_超范围6 => "$1;1;2$ A."
+        test_parse_budget_fail_指数超范围 => "$1e1;1;1$ A."
     }
 
     /// 测试/时间戳（语句）
@@ -2019,7 +3610,7 @@ mod tests_parse {
     /// * 🎯仅用于检测是否会panic
     fn _test_parse_stability(format: &NarseseFormat<&str>, input: &str) {
         // 解析，忽略结果
-        let _: ParseResult = format.parse(input);
+        let _ = _test_parse_both(format, input);
     }
 
     /// 集成测试/健壮性测试
@@ -2067,6 +3658,496 @@ mod tests_parse {
         }
     }
 
+    /// 测试/错误的行号、列号
+    #[test]
+    fn test_parse_error_line_column() {
+        let format = &FORMAT_ASCII;
+        // 第二行出错：非法的原子词项前缀
+        let input = "<(&&,\n  A, ^^B),\n --> C>.";
+        let error = format.parse::<Narsese>(input).unwrap_err();
+        show!(&error);
+        asserts! {
+            error.line() => 2,
+            error.to_string().contains("at line 2, column"),
+        }
+        // 单行⇒第一行
+        let error = format.parse::<Narsese>("(A, ^^B)").unwrap_err();
+        show!(&error);
+        asserts! {
+            error.line() => 1,
+            error.column() => error.index() + 1,
+        }
+    }
+
+    /// 测试/多字节字符：字节偏移、插入符
+    #[test]
+    fn test_parse_error_multi_byte() {
+        // 中文：每字3字节
+        let input = "「甲是乙」？？";
+        let error = FORMAT_HAN.parse::<Narsese>(input).unwrap_err();
+        show!(&error);
+        let byte_offset = error.byte_offset();
+        asserts! {
+            input.is_char_boundary(byte_offset),
+            input[..byte_offset].chars().count() => error.index(),
+            byte_offset => error.index() * 3,
+        }
+        // emoji：每个4字节
+        let input = "<😀 --> 😂😂>.)";
+        let error = FORMAT_ASCII.parse::<Narsese>(input).unwrap_err();
+        show!(&error);
+        let byte_offset = error.byte_offset();
+        asserts! {
+            input.is_char_boundary(byte_offset),
+            input[..byte_offset].chars().count() => error.index(),
+            byte_offset > error.index(),
+        }
+        // 插入符对准出错字符 | 中文、emoji按双宽计
+        let error = ParseError::new("测试", "甲😀b?".chars().collect(), 3);
+        asserts! {
+            error.byte_offset() => 3 + 4 + 1,
+            error.to_string().ends_with("\n    甲😀b?\n         ^"),
+        }
+    }
+
+    /// 测试/出错位置在开头、末尾⇒不panic
+    #[test]
+    fn test_parse_error_boundary() {
+        let inputs = ["", ")😀", "」甲", "<A -->", "「甲是", "<😀 --> 乙", "甲。真1,0.9值"];
+        for input in inputs {
+            for format in [&FORMAT_ASCII, &FORMAT_LATEX, &FORMAT_HAN] {
+                // 部分输入在其它格式下合法（如作为词语）
+                let Err(error) = format.parse::<Narsese>(input) else {
+                    continue;
+                };
+                show!(&error.to_string());
+                assert!(input.is_char_boundary(error.byte_offset()));
+                assert!(error.byte_offset() <= input.len());
+            }
+        }
+        // 直接构造：位置0、末尾、越界
+        let env = || "甲😀".chars().collect::<ParseEnv>();
+        asserts! {
+            ParseError::new("测试", env(), 0).byte_offset() => 0,
+            ParseError::new("测试", env(), 0).to_string().ends_with("\n    甲😀\n    ^"),
+            ParseError::new("测试", env(), 2).byte_offset() => 7,
+            ParseError::new("测试", env(), 2).to_string().ends_with("\n    甲😀\n        ^"),
+            ParseError::new("测试", env(), usize::MAX).byte_offset() => 7,
+            ParseError::new("测试", vec![], usize::MAX).byte_offset() => 0,
+        }
+    }
+
+    /// 测试/像中的占位符
+    /// * 🎯有且只有一个占位符；非像的复合词项中，占位符是普通组分
+    #[test]
+    fn test_parse_image_placeholders() {
+        let format = &FORMAT_ASCII;
+        let parse = |input| format.parse::<Narsese>(input).map(|n| n.try_into_term().unwrap());
+        let [a, b] = [Term::new_word("A"), Term::new_word("B")];
+        for (input, kind) in [(r"(/, A, _, _, B)", "外延像"), (r"(\, _, A, _)", "内涵像")] {
+            let error = parse(input).unwrap_err();
+            show!(&error);
+            assert!(error.message().contains(IMAGE_PLACEHOLDERS_MESSAGE), "{kind}");
+            assert_eq!(error.kind(), &ParseErrorKind::DuplicateItem, "{kind}");
+        }
+        asserts! {
+            // 一个占位符
+            parse(r"(/, A, _, B)").unwrap() => Term::new_image_extension(1, [a.clone(), b.clone()]),
+            parse(r"(\, A, B, _)").unwrap() => Term::new_image_intension(2, [a.clone(), b.clone()]),
+            // 无占位符
+            parse(r"(/, A, B)").is_err(),
+            parse(r"(\, A, B)").is_err(),
+            // 非像⇒占位符是普通组分
+            parse("(*, _)").unwrap() => Term::new_product([Term::Placeholder]),
+            parse("(*, _, _)").unwrap() => Term::new_product([Term::Placeholder, Term::Placeholder]),
+            format.format_term(&parse("(*, _)").unwrap()) => "(*, _)",
+        }
+    }
+
+    /// 测试/查询变量与问题标点的歧义
+    #[test]
+    fn test_parse_ambiguity_resolution() {
+        let question = |term| Narsese::Sentence(Sentence::new_question(term, Stamp::Eternal));
+        let [var_x, word_x] = [Term::new_variable_query("x"), Term::new_word("x")];
+        let parse = |resolution, input| {
+            let format = NarseseFormat {
+                ambiguity_resolution: resolution,
+                ..FORMAT_ASCII
+            };
+            format.parse::<Narsese>(input)
+        };
+        // 偏向变量（默认）
+        let prefer_variable = |input| parse(AmbiguityResolution::PreferVariable, input);
+        asserts! {
+            FORMAT_ASCII.ambiguity_resolution => AmbiguityResolution::PreferVariable,
+            prefer_variable("?x?").unwrap() => question(var_x.clone()),
+            prefer_variable("x?").unwrap() => question(word_x.clone()),
+            prefer_variable("?x").unwrap() => Narsese::Term(var_x.clone()),
+        }
+        // 偏向标点
+        let prefer_punctuation = |input| parse(AmbiguityResolution::PreferPunctuation, input);
+        asserts! {
+            prefer_punctuation("?x?").unwrap() => question(var_x.clone()),
+            prefer_punctuation("x?").unwrap() => question(word_x.clone()),
+            prefer_punctuation("?x").unwrap() => question(word_x.clone()),
+            // 不能作词语名⇒仍为查询变量
+            prefer_punctuation("?_x").unwrap() => Narsese::Term(Term::new_variable_query("_x")),
+        }
+        // 要求显式
+        let require_explicit = |input| parse(AmbiguityResolution::RequireExplicit, input);
+        for input in ["?x?", "?x"] {
+            let error = require_explicit(input).unwrap_err();
+            show!(&error);
+            assert!(error.is_ambiguous_query_variable(), "{input:?}");
+            assert_eq!(error.kind(), &ParseErrorKind::Ambiguous, "{input:?}");
+        }
+        asserts! {
+            require_explicit("x?").unwrap() => question(word_x.clone()),
+            // 显式写法
+            require_explicit("?x ?").unwrap() => question(var_x.clone()),
+            require_explicit("<?x --> A>?").is_ok(),
+        }
+        // 无歧义的格式不受影响
+        let han = NarseseFormat {
+            ambiguity_resolution: AmbiguityResolution::RequireExplicit,
+            ..FORMAT_HAN
+        };
+        asserts! {
+            han.parse::<Narsese>("所问x？").unwrap() => question(var_x.clone()),
+            han.parse::<Narsese>("所问x").unwrap() => Narsese::Term(var_x),
+        }
+    }
+
+    /// 测试/错误种类
+    #[test]
+    fn test_parse_error_kind() {
+        let format = &FORMAT_ASCII;
+        let kind = |input| format.parse::<Narsese>(input).unwrap_err().kind().clone();
+        asserts! {
+            kind("") => ParseErrorKind::EmptyInput,
+            kind("<A --> B>. %1.5;0.9%") => ParseErrorKind::OutOfRange01,
+            kind("$0.5;2.0;0.5$ A.") => ParseErrorKind::OutOfRange01,
+            kind("<A --> B>. :!x:") => ParseErrorKind::InvalidNumber,
+            kind("<A -=> B>.") => ParseErrorKind::UnknownCopula,
+            kind("(%%, A, B)") => ParseErrorKind::UnknownConnecter,
+            kind("<A --> B>. %1.0;0.9;-1%") => ParseErrorKind::UnclosedBracket,
+            kind("{}") => ParseErrorKind::MissingTerm,
+            kind("(-, A)") => ParseErrorKind::MissingTerm,
+            kind("<A --> B>. %1.a;0.9%") => ParseErrorKind::UnexpectedChar,
+            kind("A. %1.0;0.9%x") => ParseErrorKind::UnexpectedChar,
+            kind("<A --> B>) ") => ParseErrorKind::UnknownPrefix,
+            kind("<A --> B>. %1.5;0.9%").code() => "out_of_range_01",
+        }
+        // 链式错误⇒内部错误为「走得最远」的子错误
+        let error = format.parse::<Narsese>("<A -=> B>.").unwrap_err();
+        let source = error.source().unwrap().downcast_ref::<ParseError>().unwrap();
+        asserts! {
+            source.kind() => error.kind(),
+            source.index() => error.index(),
+        }
+        // 无内部错误
+        assert!(format.parse::<Narsese>("").unwrap_err().source().is_none());
+    }
+
+    /// 测试/一次解析多条语句
+    #[test]
+    fn test_parse_all() {
+        let format = &FORMAT_ASCII;
+        let input = "
+            '测试用NAL文件
+            <robin --> bird>. // 行尾注释
+            // C风格注释
+
+            <bird --> animal>. %1.0;0.9%
+            ''outputMustContain('<robin --> animal>.')
+            <robin --> animal>?
+            (A, B
+        ";
+        let results = format.parse_all(input);
+        show!(&results);
+        asserts! {
+            results.len() => 4,
+            results[..3].iter().all(Result::is_ok),
+            results[3].is_err(),
+            format.parse_iter(input).count() => 4,
+        }
+        // 与「按行逐个解析」一致
+        let expected: ParseResult = format.parse("<bird --> animal>. %1.0;0.9%");
+        assert_eq!(results[1].as_ref().ok(), expected.as_ref().ok());
+    }
+
+    /// 测试/[`FromStr`]
+    #[test]
+    fn test_from_str() {
+        use crate::api::GetTerm;
+        // 成功
+        let term: Term = "<A --> B>".parse().unwrap();
+        let sentence: Sentence = "<A --> B>.".parse().unwrap();
+        let task: Task = "$0.5;0.5;0.5$ <A --> B>.".parse().unwrap();
+        let narsese: Narsese = "<A --> B>?".parse().unwrap();
+        asserts! {
+            Narsese::Term(term) => FORMAT_ASCII.parse::<Narsese>("<A --> B>").unwrap(),
+            sentence.get_term() => &"<A --> B>".parse::<Term>().unwrap(),
+            task.get_term() => sentence.get_term(),
+            narsese.is_sentence(),
+        }
+        // 类型不匹配
+        let error = "A.".parse::<Term>().unwrap_err();
+        show!(&error);
+        asserts! {
+            matches!(error, FromStrError::WrongVariant { expected: "词项", .. }),
+            error.to_string().contains("语句"),
+            matches!("A".parse::<Sentence>(), Err(FromStrError::WrongVariant { .. })),
+            matches!("A.".parse::<Task>(), Err(FromStrError::WrongVariant { .. })),
+        }
+        // 语法错误
+        asserts! {
+            matches!("(A, B".parse::<Term>(), Err(FromStrError::Parse(..))),
+            matches!("".parse::<Narsese>(), Err(FromStrError::Parse(..))),
+        }
+    }
+
+    /// 测试/批量解析并分离成功与失败
+    #[test]
+    fn test_parse_multi_collect() {
+        let format = &FORMAT_ASCII;
+        let inputs = ["<A --> B>.", "(A, B", "C", "", "<C --> D>?"];
+        let batch = format.parse_multi_collect(inputs);
+        show!(&batch);
+        asserts! {
+            batch.values().len() => 3,
+            batch.errors().iter().map(|(i, _)| *i).collect::<Vec<_>>() => [1, 3],
+            batch.iter().filter(|v| v.is_sentence()).count() => 2,
+            batch.clone().into_iter().filter(Narsese::is_term).count() => 1,
+            batch.clone().into_values().len() => 3,
+        }
+        // 要求全部成功
+        let error = batch.require_all().unwrap_err();
+        show!(&error);
+        asserts! {
+            error.indices() => [1, 3],
+            error.to_string().contains("[1, 3]"),
+        }
+        // 全部成功
+        let values = format
+            .parse_multi_collect(["A", "B."])
+            .require_all()
+            .unwrap();
+        assert_eq!(values.len(), 2);
+    }
+
+    /// 测试/空输入
+    #[test]
+    fn test_parse_empty_input() {
+        let format = &FORMAT_ASCII;
+        for input in ["", "   ", "\t\n", "\u{feff}", "\u{feff}  \r\n"] {
+            let error = _test_parse_both(format, input).unwrap_err();
+            show!(&error);
+            assert!(error.is_empty_input());
+            assert_eq!(error.message(), EMPTY_INPUT_MESSAGE);
+        }
+        // 仅有注释⇒亦为「空输入」
+        for input in ["// hi", "  'comment", "\u{feff}// A.", "\n  // 注释\n"] {
+            let error = _test_parse_both(format, input).unwrap_err();
+            show!(&error);
+            asserts! {
+                error.is_empty_input(),
+                error.kind() => &ParseErrorKind::EmptyInput,
+                format.parse_prefix(input).unwrap_err().is_empty_input(),
+                format.parse_lenient(input).1[0].is_empty_input(),
+            }
+        }
+        // 仅有标点⇒不是「空输入」
+        let error = format.parse::<Narsese>(".").unwrap_err();
+        show!(&error);
+        assert!(!error.is_empty_input());
+    }
+
+    /// 测试/最大嵌套深度
+    /// * 🎯深层嵌套的输入⇒解析错误，而非栈溢出
+    #[test]
+    fn test_parse_max_depth() {
+        /// 生成指定层数的嵌套否定
+        fn nested_negation(depth: usize) -> String {
+            format!("{}A{}", "(--, ".repeat(depth), ")".repeat(depth))
+        }
+        let format = &FORMAT_ASCII;
+        // 超深嵌套⇒报错
+        let error = format.parse::<Narsese>(&nested_negation(10000)).unwrap_err();
+        show!(error.message());
+        assert!(error.is_max_depth_exceeded());
+        assert_eq!(error.message(), MAX_DEPTH_MESSAGE);
+        let error = format
+            .parse::<Narsese>(&format!("<{} --> B>.", nested_negation(10000)))
+            .unwrap_err();
+        assert!(error.is_max_depth_exceeded());
+        // 深度限制内⇒正常解析 | 原子词项本身占一层
+        let format = NarseseFormat {
+            max_depth: 4,
+            ..FORMAT_ASCII
+        };
+        assert!(format.parse::<Narsese>(&nested_negation(3)).is_ok());
+        assert!(format
+            .parse::<Narsese>(&nested_negation(4))
+            .unwrap_err()
+            .is_max_depth_exceeded());
+        assert!(format.parse::<Narsese>("<(--, (--, A)) --> B>").is_ok());
+        assert!(format
+            .parse::<Narsese>("<(--, (--, (--, A))) --> B>")
+            .unwrap_err()
+            .is_max_depth_exceeded());
+    }
+
+    /// 测试/解析中的警告
+    /// * 🎯重复组分被去重时给出警告，而非悄然去重
+    #[test]
+    fn test_parse_with_warnings() {
+        let parse = |policy, input| {
+            let format = NarseseFormat {
+                duplicate_policy: policy,
+                ..FORMAT_ASCII
+            };
+            let (result, warnings) = format.parse_with_warnings(input);
+            let messages = warnings.iter().map(|w| w.message().to_owned()).collect::<Vec<_>>();
+            show!(&result, &messages);
+            assert!(warnings.iter().all(|w| w.kind() == &ParseErrorKind::DuplicateItem));
+            (result, messages)
+        };
+        let deduped = |input| FORMAT_ASCII.parse::<Narsese>(input).unwrap();
+        // 去重
+        let (result, messages) = parse(DuplicatePolicy::Dedupe, "<{A, A, B} --> (&|, C, C)>.");
+        asserts! {
+            result.unwrap() => deduped("<{A, B} --> (&|, C)>."),
+            messages => [
+                "无序复合词项中的重复组分「A」已被去重",
+                "无序复合词项中的重复组分「C」已被去重",
+            ],
+        }
+        // 保留：无法保留⇒降级为去重
+        let (result, messages) = parse(DuplicatePolicy::Keep, "(&, A, A, B)");
+        asserts! {
+            result.unwrap() => deduped("(&, A, B)"),
+            messages => ["无序复合词项无法保留重复组分「A」，已去重"],
+        }
+        // 报错⇒无警告
+        let (result, messages) = parse(DuplicatePolicy::Error, "{A, A}");
+        asserts! {
+            result.unwrap_err().kind() => &ParseErrorKind::DuplicateItem,
+            messages.is_empty(),
+        }
+        // 无重复、有序容器⇒无警告
+        for input in ["{A, B}", "(*, A, A)", "(&/, A, A)"] {
+            assert!(parse(DuplicatePolicy::Dedupe, input).1.is_empty(), "{input:?}");
+        }
+    }
+
+    /// 测试/最大嵌套深度/栈空间
+    /// * 🎯默认深度限制内的各类嵌套，在2MiB的线程栈上亦不会栈溢出
+    ///   * 📌2MiB：`cargo test`、tokio工作线程的默认栈大小
+    #[test]
+    fn test_parse_max_depth_stack() {
+        let depth = crate::conversion::string::DEFAULT_MAX_DEPTH - 1;
+        let inputs = [
+            // 陈述（主词嵌套）
+            format!("{}A{}", "<".repeat(depth), " --> B>".repeat(depth)),
+            // 陈述（谓词嵌套）
+            format!("{}A{}", "<B --> ".repeat(depth), ">".repeat(depth)),
+            // 否定
+            format!("{}A{}", "(--, ".repeat(depth), ")".repeat(depth)),
+            // 外延集
+            format!("{}A{}", "{".repeat(depth), "}".repeat(depth)),
+        ];
+        std::thread::Builder::new()
+            .stack_size(2 << 20)
+            .spawn(move || {
+                for input in inputs {
+                    assert!(FORMAT_ASCII.parse::<Narsese>(&input).is_ok());
+                    let sentence = format!("{input}.");
+                    assert!(FORMAT_ASCII.parse::<Narsese>(&sentence).is_ok());
+                }
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    /// 测试/带依赖列表的变量
+    /// * 🎯明确报错，而非将依赖列表误作复合词项
+    #[test]
+    fn test_parse_variable_dependency_list() {
+        for format in [&FORMAT_ASCII, &FORMAT_LATEX, &FORMAT_HAN] {
+            let variable = |name: &str| {
+                let term = Term::new_variable_dependent(name);
+                let formatted = format.format_term(&term);
+                (term, formatted)
+            };
+            let (x, x_str) = variable("x");
+            let (_, one_str) = variable("1");
+            let (_, two_str) = variable("2");
+            let word = |name: &str| format.format_term(&Term::new_word(name));
+            let left = format.compound.brackets.0;
+            let right = format.compound.brackets.1;
+            let separator = format.compound.separator;
+            for input in [
+                format!("{one_str}{left}{two_str}{right}"),
+                format!("{x_str}{left}{}{separator} {}{right}", word("a"), word("b")),
+            ] {
+                let error = _test_parse_both(format, &input).unwrap_err();
+                show!(&error);
+                assert!(error.is_variable_dependency_list(), "{input:?}");
+            }
+            // 不带依赖列表⇒正常解析
+            assert_eq!(_test_parse_both(format, &x_str).unwrap(), Narsese::Term(x));
+        }
+        // 嵌套在陈述中
+        let error = FORMAT_ASCII.parse::<Narsese>("<#1(#2) --> A>.").unwrap_err();
+        assert_eq!(error.message(), VARIABLE_DEPENDENCY_LIST_MESSAGE);
+    }
+
+    /// 集成测试/带位置的流式解析
+    #[test]
+    fn test_parse_stream_located() {
+        let format = &FORMAT_ASCII;
+        let input = "<A --> B>.\n\n\t<B --> C>?\n<C --> D>. %1.0;0.9;0.5%\n";
+        let results = format
+            .parse_stream_located(input, Some("fixture.nal".into()))
+            .collect::<Vec<_>>();
+        show!(&results);
+        asserts! {
+            results.len() => 3,
+            results[0].location() => "fixture.nal:1:1",
+            results[1].location() => "fixture.nal:3:2",
+            results[2].location() => "fixture.nal:4:1",
+            results[0].value.is_ok(),
+            results[1].value.is_ok(),
+            // 真值参数过多⇒解析失败
+            results[2].value.is_err(),
+        }
+    }
+
+    /// 测试/带位置的流式解析：错误位置相对于整个输入
+    #[test]
+    fn test_parse_stream_located_error_position() {
+        let format = &FORMAT_ASCII;
+        let input = "<A --> B>.\n\n   <C --> >.";
+        let results = format.parse_stream_located(input, None).collect::<Vec<_>>();
+        let error = results[1].value.as_ref().unwrap_err();
+        // 单独解析该行：位置相对于行首
+        let line_error = format.parse::<Narsese>("<C --> >.").unwrap_err();
+        let line_start = input.find("<C").unwrap();
+        show!(error, &line_error);
+        asserts! {
+            results[1].line => 3,
+            results[1].col => 4,
+            error.line() => 3,
+            error.column() => line_error.column() + 3,
+            error.byte_offset() => line_error.byte_offset() + line_start,
+            error.index() => line_error.index() + line_start,
+            // 链式呈现的子错误亦按整个输入定位
+            !error.to_string().contains("line 1,"),
+        }
+    }
+
     /// 集成测试/解析器
     #[test]
     fn test_parse_integrated() {
@@ -2129,4 +4210,317 @@ mod tests_parse {
         ];
         show!(matrix);
     }
+    /// 测试/零拷贝解析
+    /// * 🎯在完整的ASCII语料上，与常规解析结果完全一致
+    ///   * 📌语料：词项测试集、样本任务、`parse_all`切分的多行输入
+    /// * 🎯非ASCII字符：字节索引与字符索引的换算
+    #[test]
+    fn test_parse_str_fast() {
+        use crate::{
+            conversion::string::impl_enum::tests::_sample_task,
+            enum_narsese::tests::generate_term_testset,
+        };
+        // 词项测试集 | 三种格式
+        for format in [&FORMAT_ASCII, &FORMAT_LATEX, &FORMAT_HAN] {
+            for term in generate_term_testset() {
+                let input = format.format_term(&term);
+                let parsed = _test_parse_both(format, &input).unwrap();
+                assert_eq!(parsed, Narsese::Term(term));
+            }
+            let input = format.format_task(&_sample_task());
+            _test_parse_both(format, &input).unwrap();
+        }
+        // 多行输入、非ASCII字符、错误
+        let inputs = [
+            "$0.5;0.75;0.4$ <(&/, <ball --> [left]>, <(*, {SELF}) --> ^go-to>) ==> <SELF --> [good]>>. :!-1: %1.0;0.9%",
+            "<(^pick, {SELF}, $x)\n --> 目标>! :|:",
+            "<中文 --> 词项>. %1.0; 0.9%",
+            "<中文 --> 词项",
+            "wo啊/d",
+            "(&&, 甲, 乙, 甲)",
+            "$0.5;0.5;0.5$ 完形填空?",
+            "<A --> B>.\n%1.0;0.9%\n:|:",
+            "A. %1.0;0.9;0.8%",
+            "",
+            " \u{feff} ",
+        ];
+        for input in inputs {
+            let result = _test_parse_both(&FORMAT_ASCII, input);
+            show!(&result);
+        }
+        // 错误位置按字符计
+        let error = FORMAT_ASCII.parse_str_fast("<中文 --> 词项>> .").unwrap_err();
+        show!(&error);
+        assert_eq!(error.to_string(), FORMAT_ASCII.parse::<Narsese>("<中文 --> 词项>> .").unwrap_err().to_string());
+    }
+
+    /// 测试/预计算的「语法元素」
+    /// * 🎯与逐字比较格式字串的结果一致：两种环境、所有位置、所有系词
+    #[test]
+    fn test_format_tokens() {
+        let input = "<中文 --> 词项>. <A <=> B>! 〈甲是乙〉 (&&, A, B)";
+        for format in [&FORMAT_ASCII, &FORMAT_LATEX, &FORMAT_HAN] {
+            let chars = ParseEnv::build(input);
+            let chars_tokens = FormatTokens::new(format, true);
+            let str_tokens = FormatTokens::new(format, false);
+            for (copula, token) in format.copulas().into_iter().zip(chars_tokens.copulas) {
+                for index in 0..=chars.len() + 1 {
+                    assert_eq!(
+                        chars.starts_with_token_at(index, &token, &chars_tokens),
+                        chars.starts_with_at(index, copula),
+                        "{copula:?} @ {index}"
+                    );
+                }
+                assert_eq!(ParseEnv::len_of_token(&token), ParseEnv::len_of(copula));
+            }
+            for (copula, token) in format.copulas().into_iter().zip(str_tokens.copulas) {
+                for (index, _) in input.char_indices() {
+                    assert_eq!(
+                        input.starts_with_token_at(index, &token, &str_tokens),
+                        input.starts_with_at(index, copula),
+                    );
+                }
+                assert_eq!(<&str>::len_of_token(&token), <&str>::len_of(copula));
+            }
+        }
+    }
+
+    /// 测试/前缀解析
+    /// * 🎯将Narsese嵌入更大的语法中，并检验「已消耗长度」
+    #[test]
+    fn test_parse_prefix() {
+        let format = &FORMAT_ASCII;
+        // 返回「解析结果」与「剩余输入」
+        let parse_prefix = |input| {
+            let (narsese, end) = format.parse_prefix(input).expect("前缀解析失败");
+            (narsese, &input[end..])
+        };
+        let parse = |input| format.parse::<Narsese>(input).expect("解析失败");
+        // 嵌入在DSL中的任务
+        let dsl = "believe($0.5;0.75;0.4$ <A --> B>. :|: %1.0;0.9%, 0.9)";
+        let task = "$0.5;0.75;0.4$ <A --> B>. :|: %1.0;0.9%";
+        let begin = "believe(".len();
+        let (narsese, end) = format.parse_prefix(&dsl[begin..]).unwrap();
+        asserts! {
+            narsese => parse(task),
+            end => task.len(),
+            &dsl[begin + end..] => ", 0.9)",
+        }
+        asserts! {
+            // 词项、语句
+            parse_prefix("<A --> B> and more") => (parse("<A --> B>"), " and more"),
+            parse_prefix("A. rest") => (parse("A."), " rest"),
+            parse_prefix("<A --> B>? %1.0;0.9%)") => (parse("<A --> B>? %1.0;0.9%"), ")"),
+            // 只消耗一个值
+            parse_prefix("<A --> B>. <C --> D>.") => (parse("<A --> B>."), " <C --> D>."),
+            // 时间戳、真值不扫描到其后的内容中
+            parse_prefix("<A --> B>. :|:, :!1:") => (parse("<A --> B>. :|:"), ", :!1:"),
+            parse_prefix("<A --> B>. %1.0;0.9% %0.5;0.5%") => (parse("<A --> B>. %1.0;0.9%"), " %0.5;0.5%"),
+            // 多字节字符：偏移按字节计
+            parse_prefix("<甲 --> 乙>. 其余") => (parse("<甲 --> 乙>."), " 其余"),
+            // 整个输入
+            parse_prefix("<A --> B>.") => (parse("<A --> B>."), ""),
+        }
+        // 开头即非Narsese⇒报错
+        asserts! {
+            format.parse_prefix(")<A --> B>.").is_err(),
+            format.parse_prefix("  ").unwrap_err().is_empty_input(),
+        }
+    }
+
+    /// 测试/单条目解析
+    /// * 🎯真值、预算值、时间戳、标点的单独解析，及其与格式化的往返
+    #[test]
+    fn test_parse_single_item() {
+        let format = &FORMAT_ASCII;
+        asserts! {
+            // 真值
+            format.parse_truth("%%").ok() => Some(Truth::new_empty()),
+            format.parse_truth("").ok() => Some(Truth::new_empty()),
+            format.parse_truth("%1.0%").ok() => Some(Truth::new_single(1.0)),
+            format.parse_truth(" %1.0;0.9% ").ok() => Some(Truth::new_double(1.0, 0.9)),
+            // 预算值
+            format.parse_budget("$$").ok() => Some(Budget::new_empty()),
+            format.parse_budget("$0.5$").ok() => Some(Budget::new_single(0.5)),
+            format.parse_budget("\n$0.5;0.5;0.5$ ").ok() => Some(Budget::new_triple(0.5, 0.5, 0.5)),
+            // 时间戳
+            format.parse_stamp("").ok() => Some(Stamp::Eternal),
+            format.parse_stamp(":/:").ok() => Some(Stamp::Future),
+            format.parse_stamp(":|:").ok() => Some(Stamp::Present),
+            format.parse_stamp(":\\:").ok() => Some(Stamp::Past),
+            format.parse_stamp(" :!-1: ").ok() => Some(Stamp::Fixed(-1)),
+            // 标点
+            format.parse_punctuation(".").ok() => Some(Punctuation::Judgement),
+            format.parse_punctuation("!").ok() => Some(Punctuation::Goal),
+            format.parse_punctuation("?").ok() => Some(Punctuation::Question),
+            format.parse_punctuation(" @ ").ok() => Some(Punctuation::Quest),
+        }
+        // 多余内容、缺失括弧、空输入⇒错误
+        asserts! {
+            format.parse_truth("%1.0% A").is_err(),
+            format.parse_truth("1.0%").is_err(),
+            format.parse_budget("").is_err(),
+            format.parse_budget("$0.5$$").is_err(),
+            format.parse_stamp(":|: :|:").is_err(),
+            format.parse_stamp("|:").is_err(),
+            format.parse_punctuation("..").is_err(),
+            format.parse_punctuation("A").is_err(),
+        }
+        // 三种格式下的往返
+        let truths = [
+            Truth::new_empty(),
+            Truth::new_single(1.0),
+            Truth::new_double(1.0, 0.9),
+        ];
+        let budgets = [
+            Budget::new_empty(),
+            Budget::new_single(0.5),
+            Budget::new_double(0.5, 0.75),
+            Budget::new_triple(0.5, 0.75, 0.4),
+        ];
+        let stamps = [
+            Stamp::Eternal,
+            Stamp::Past,
+            Stamp::Present,
+            Stamp::Future,
+            Stamp::Fixed(42),
+        ];
+        let punctuations = [
+            Punctuation::Judgement,
+            Punctuation::Goal,
+            Punctuation::Question,
+            Punctuation::Quest,
+        ];
+        for format in [&FORMAT_ASCII, &FORMAT_LATEX, &FORMAT_HAN] {
+            for truth in &truths {
+                assert_eq!(format.parse_truth(&format.format_truth(truth)).ok().as_ref(), Some(truth));
+            }
+            for budget in &budgets {
+                assert_eq!(format.parse_budget(&format.format_budget(budget)).ok().as_ref(), Some(budget));
+            }
+            for stamp in &stamps {
+                assert_eq!(format.parse_stamp(&format.format_stamp(stamp)).ok().as_ref(), Some(stamp));
+            }
+            for punctuation in &punctuations {
+                let formatted = format.format_punctuation(punctuation);
+                assert_eq!(format.parse_punctuation(&formatted).ok().as_ref(), Some(punctuation));
+            }
+        }
+    }
+
+    /// 测试/各类空白
+    /// * 🎯全角空格`U+3000`、不换行空格`U+00A0`、制表符与普通空格同等对待
+    #[test]
+    fn test_parse_mixed_spaces() {
+        // 将普通空格轮流替换为各类空白
+        let mix_spaces = |input: &str| {
+            let mut spaces = ['\t', '\u{3000}', '\u{a0}'].into_iter().cycle();
+            input
+                .chars()
+                .map(|c| match c {
+                    ' ' => spaces.next().unwrap(),
+                    c => c,
+                })
+                .collect::<String>()
+        };
+        let ascii = "<(*, {SELF}, $any, #some) --> ^do>. :|: %1.0;0.9%";
+        let latex = FORMAT_LATEX.format_narsese(&FORMAT_ASCII.parse(ascii).unwrap());
+        let cases = [
+            (&FORMAT_ASCII, ascii.to_string(), mix_spaces(ascii)),
+            (
+                &FORMAT_ASCII,
+                ascii.to_string(),
+                "<(*,\t{SELF},\u{3000}$any,\u{a0}#some)\u{3000}-->\t^do>.\u{a0}:|:\t%1.0;\u{3000}0.9%".into(),
+            ),
+            (&FORMAT_LATEX, latex.clone(), mix_spaces(&latex)),
+            (
+                &FORMAT_HAN,
+                "「（积，『SELF』，任一any，其一some）是操作do」。现在真1.0、0.9值".into(),
+                "「（积，\u{3000}『SELF』，\u{a0}任一any，\t其一some \u{3000}）是操作do」\u{3000}。\t现在\u{a0}真1.0、\u{3000}0.9值".into(),
+            ),
+        ];
+        for (format, standard, spaced) in cases {
+            assert_ne!(standard, spaced);
+            let expected = __test_parse(format, &standard);
+            assert_eq!(__test_parse(format, &spaced), expected, "{spaced:?}");
+        }
+    }
+
+    /// 测试/备选分隔符
+    /// * 🎯兼容ONA等使用`,`分隔的真值、预算值
+    /// * 🚩解析时可混用；格式化时总使用主分隔符
+    #[test]
+    fn test_alternate_separators() {
+        let format = &FORMAT_ASCII;
+        let parse = |input| format.parse::<Narsese>(input).expect("解析失败");
+        asserts! {
+            parse("A. %1.0,0.9%") => parse("A. %1.0;0.9%"),
+            parse("$0.5,0.5;0.5$ A. %1.0%") => parse("$0.5;0.5;0.5$ A. %1.0%"),
+            parse("$0.5 , 0.75,0.4$ <A --> B>! :|: %1.0,0.9%") => parse("$0.5;0.75;0.4$ <A --> B>! :|: %1.0;0.9%"),
+            // 格式化：使用主分隔符
+            format.format_narsese(&parse("A. %1.0,0.9%")) => "A. %1;0.9%",
+            format.format_narsese(&parse("$0.5,0.5;0.5$ A.")) => "$0.5;0.5;0.5$ A.",
+            // 组分过多仍然报错
+            format.parse::<Narsese>("A. %1.0,0.9,0.5%").is_err(),
+            // 未配置备选分隔符的格式不受影响
+            FORMAT_HAN.parse::<Narsese>("甲。真1,0.9值").is_err(),
+        }
+    }
+
+    /// 测试/宽松解析
+    /// * 🎯出错的条目留空，其余条目照常解析
+    #[test]
+    fn test_parse_lenient() {
+        let format = &FORMAT_ASCII;
+        // 真值越界⇒语句（无真值）+一个错误
+        let (result, errors) = format.parse_lenient("<A --> B>. %1.0;2.0%");
+        show!(&result, &errors);
+        asserts! {
+            result => format.parse::<Narsese>("<A --> B>.").ok(),
+            errors.len() => 1,
+            errors[0].message().contains("0-1"),
+        }
+        // 词项出错⇒无词项+一个错误，但仍有标点
+        let (items, errors) = format.parse_items_lenient("<A --> >.");
+        show!(&items, &errors);
+        asserts! {
+            items.term => None,
+            items.punctuation => Some(Punctuation::Judgement),
+            errors.len() => 1,
+            // 折叠失败时不重复报告「词项缺失」
+            format.parse_lenient("<A --> >.").0 => None,
+            format.parse_lenient("<A --> >.").1.len() => 1,
+        }
+        // 多个条目出错⇒各自记录，其余条目照常解析
+        let (items, errors) = format.parse_items_lenient("$0.5;0.5;0.5$ <A --> B>! :!x: %1.0;2.0%");
+        show!(&items, &errors);
+        asserts! {
+            items.budget => Some(Budget::new_triple(0.5, 0.5, 0.5)),
+            items.term => format.parse::<Narsese>("<A --> B>").unwrap().try_into_term().ok(),
+            items.punctuation => Some(Punctuation::Goal),
+            items.stamp => None,
+            items.truth => None,
+            errors.len() => 2,
+        }
+        // ⚠️出错的预算值可能被当作独立变量（同严格解析）
+        let (items, _) = format.parse_items_lenient("$0.5;9$ A.");
+        asserts! {
+            items.budget => None,
+            items.term => Some(Term::new_variable_independent("0")),
+        }
+        // 无错误⇒与严格解析一致
+        for input in ["A", "<A --> B>.", "$0.5;0.5;0.5$ <A --> B>! :|: %1.0;0.9%", "<$1 --> B>?"] {
+            let (result, errors) = format.parse_lenient(input);
+            asserts! {
+                result => format.parse::<Narsese>(input).ok(),
+                errors.is_empty(),
+            }
+        }
+        // 空输入、无词项
+        asserts! {
+            format.parse_lenient("  ").1[0].is_empty_input(),
+            format.parse_lenient(".").0 => None,
+            format.parse_lenient(".").1.len() => 1,
+        }
+    }
 }
@@ -0,0 +1,823 @@
+//! 「词法Narsese格式」的构建器
+//! * 🎯允许用户基于现有格式，定义自己的「方言」
+//!   * 📄ONA：系词、时间戳等与OpenNARS略有不同
+//! * 🚩从现有格式出发，逐项覆盖其中的字段，最后在[`NarseseFormatBuilder::build`]时统一检验
+//!   * 📌列表类字段（前缀、连接符、系词、标点、括弧对）以「列表」形式暂存，构建时再转换为「匹配字典」
+//!   * ⚠️构建时拒绝有歧义的配置：重复的符号、与原子词项前缀冲突的系词等
+//! * 📌构建出的格式与内置格式无异：可直接用于现有的解析器与格式化器
+
+use super::format::*;
+use crate::{api::TermCapacity, conversion::string::AmbiguityResolution};
+use nar_dev_utils::{BiFixMatchDictPair, PrefixMatch, PrefixMatchDict, SuffixMatch, SuffixMatchDictPair};
+use std::{error::Error, fmt::Display};
+
+/// 格式构建错误
+/// * 🚩仅包含错误信息
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatBuildError(String);
+
+impl FormatBuildError {
+    /// 获取错误信息
+    pub fn message(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for FormatBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FormatBuildError: {}", self.0)
+    }
+}
+
+impl Error for FormatBuildError {}
+
+/// 快捷构造宏
+macro_rules! FormatBuildError {
+    ($($content:tt)*) => {
+        FormatBuildError(format!($($content)*))
+    };
+}
+
+/// 格式构建结果
+pub type FormatBuildResult<T = NarseseFormat> = Result<T, FormatBuildError>;
+
+/// 词法Narsese格式构建器
+/// * 🚩总是从一个现有格式出发：`NarseseFormatBuilder::from(&*FORMAT_ASCII)`
+/// * 🚩所有设置方法均为「链式调用」
+#[derive(Debug, Clone)]
+pub struct NarseseFormatBuilder {
+    /// 基础格式
+    /// * 🚩非列表类字段直接在此修改
+    /// * 📌其中的「匹配字典」在构建时被下边的列表覆盖
+    base: NarseseFormat,
+    /// 原子词项前缀
+    atom_prefixes: Vec<String>,
+    /// 词项集括弧对
+    set_brackets: Vec<(String, String)>,
+    /// 复合词项连接符
+    connecters: Vec<String>,
+    /// 满足交换律的复合词项连接符
+    commutative_connecters: Vec<String>,
+    /// 陈述系词
+    copulas: Vec<String>,
+    /// 满足对称性的陈述系词
+    symmetric_copulas: Vec<String>,
+    /// 标点
+    punctuations: Vec<String>,
+    /// 时间戳括弧对
+    stamp_brackets: Vec<(String, String)>,
+}
+
+/// 从现有格式出发
+impl From<&NarseseFormat> for NarseseFormatBuilder {
+    fn from(base: &NarseseFormat) -> Self {
+        Self {
+            atom_prefixes: strings(base.atom.prefixes.iter_x_fixes()),
+            set_brackets: base.compound.set_brackets.prefix_terms().cloned().collect(),
+            connecters: strings(base.compound.connecters.iter_x_fixes()),
+            commutative_connecters: strings(base.compound.commutative_connecters.iter_x_fixes()),
+            copulas: strings(base.statement.copulas.iter_x_fixes()),
+            symmetric_copulas: strings(base.statement.symmetric_copulas.iter_x_fixes()),
+            punctuations: strings(base.sentence.punctuations.iter_x_fixes()),
+            stamp_brackets: base.sentence.stamp_brackets.suffix_terms().cloned().collect(),
+            base: base.clone(),
+        }
+    }
+}
+
+impl NarseseFormat {
+    /// 以自身为基础，创建一个[格式构建器](NarseseFormatBuilder)
+    pub fn builder(&self) -> NarseseFormatBuilder {
+        NarseseFormatBuilder::from(self)
+    }
+}
+
+/// 工具函数：收集字符串
+fn strings<'a>(iter: impl Iterator<Item = &'a String>) -> Vec<String> {
+    iter.cloned().collect()
+}
+
+/// 工具函数：转换为字符串列表
+fn into_strings(iter: impl IntoIterator<Item = impl Into<String>>) -> Vec<String> {
+    iter.into_iter().map(Into::into).collect()
+}
+
+/// 工具函数：转换为字符串对列表
+fn into_pairs(
+    iter: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+) -> Vec<(String, String)> {
+    iter.into_iter()
+        .map(|(left, right)| (left.into(), right.into()))
+        .collect()
+}
+
+/// 工具函数：转换为容量表
+fn into_capacities(
+    iter: impl IntoIterator<Item = (impl Into<String>, TermCapacity)>,
+) -> Vec<(String, TermCapacity)> {
+    iter.into_iter()
+        .map(|(symbol, capacity)| (symbol.into(), capacity))
+        .collect()
+}
+
+/// 设置/空白符
+impl NarseseFormatBuilder {
+    /// 设置「解析时的空白符判断」
+    pub fn is_space(mut self, is_for_parse: fn(char) -> bool) -> Self {
+        self.base.space.is_for_parse = is_for_parse;
+        self
+    }
+
+    /// 设置「解析前是否筛除空白符」
+    pub fn remove_spaces_before_parse(mut self, value: bool) -> Self {
+        self.base.space.remove_spaces_before_parse = value;
+        self
+    }
+
+    /// 设置「格式化时词项间、条目间的空白符」
+    pub fn format_spaces(mut self, terms: impl Into<String>, items: impl Into<String>) -> Self {
+        self.base.space.format_terms = terms.into();
+        self.base.space.format_items = items.into();
+        self
+    }
+}
+
+/// 设置/词项
+impl NarseseFormatBuilder {
+    /// 设置「原子词项前缀」
+    pub fn atom_prefixes(mut self, prefixes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.atom_prefixes = into_strings(prefixes);
+        self
+    }
+
+    /// 设置「间隔」前缀
+    pub fn prefix_interval(mut self, prefix: impl Into<String>) -> Self {
+        self.base.atom.prefix_interval = prefix.into();
+        self
+    }
+
+    /// 设置「词语」前缀
+    pub fn prefix_word(mut self, prefix: impl Into<String>) -> Self {
+        self.base.atom.prefix_word = prefix.into();
+        self
+    }
+
+    /// 设置「占位符」前缀
+    pub fn prefix_placeholder(mut self, prefix: impl Into<String>) -> Self {
+        self.base.atom.prefix_placeholder = prefix.into();
+        self
+    }
+
+    /// 设置「变量」前缀
+    pub fn prefix_variables(mut self, prefixes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.base.atom.prefix_variables = into_strings(prefixes);
+        self
+    }
+
+    /// 设置「原子词项引号对」
+    /// * 📄`("\"", "\"")`：`"word term"`
+    pub fn quotes(mut self, left: impl Into<String>, right: impl Into<String>) -> Self {
+        self.base.atom.quotes = Some((left.into(), right.into()));
+        self
+    }
+
+    /// 设置「原子词项标识符」的判断
+    pub fn is_identifier(mut self, is_identifier: fn(char) -> bool) -> Self {
+        self.base.atom.is_identifier = is_identifier;
+        self
+    }
+
+    /// 设置「复合词项括弧对」
+    pub fn compound_brackets(mut self, left: impl Into<String>, right: impl Into<String>) -> Self {
+        self.base.compound.brackets = (left.into(), right.into());
+        self
+    }
+
+    /// 设置「复合词项元素分隔符」
+    pub fn compound_separator(mut self, separator: impl Into<String>) -> Self {
+        self.base.compound.separator = separator.into();
+        self
+    }
+
+    /// 设置「词项集括弧对」
+    pub fn set_brackets(
+        mut self,
+        brackets: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+    ) -> Self {
+        self.set_brackets = into_pairs(brackets);
+        self
+    }
+
+    /// 设置「外延集」「内涵集」的括弧对
+    /// * 🎯用于[`NarseseFormat::desugar`]等
+    /// * 📄ASCII：`(("{", "}"), ("[", "]"))`
+    pub fn set_kinds(
+        mut self,
+        extension: (impl Into<String>, impl Into<String>),
+        intension: (impl Into<String>, impl Into<String>),
+    ) -> Self {
+        let compound = &mut self.base.compound;
+        compound.set_extension_brackets = (extension.0.into(), extension.1.into());
+        compound.set_intension_brackets = (intension.0.into(), intension.1.into());
+        self
+    }
+
+    /// 设置「复合词项连接符」
+    pub fn connecters(mut self, connecters: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.connecters = into_strings(connecters);
+        self
+    }
+
+    /// 设置「满足交换律的复合词项连接符」
+    pub fn commutative_connecters(
+        mut self,
+        connecters: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.commutative_connecters = into_strings(connecters);
+        self
+    }
+
+    /// 设置「顺序合取」连接符
+    pub fn connecter_conjunction_sequential(mut self, connecter: impl Into<String>) -> Self {
+        self.base.compound.connecter_conjunction_sequential = connecter.into();
+        self
+    }
+
+    /// 设置「外延像」「内涵像」连接符
+    pub fn connecter_images(
+        mut self,
+        extension: impl Into<String>,
+        intension: impl Into<String>,
+    ) -> Self {
+        self.base.compound.connecter_image_extension = extension.into();
+        self.base.compound.connecter_image_intension = intension.into();
+        self
+    }
+
+    /// 设置「陈述括弧对」
+    pub fn statement_brackets(mut self, left: impl Into<String>, right: impl Into<String>) -> Self {
+        self.base.statement.brackets = (left.into(), right.into());
+        self
+    }
+
+    /// 设置「陈述系词」
+    pub fn copulas(mut self, copulas: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.copulas = into_strings(copulas);
+        self
+    }
+
+    /// 设置「继承」「实例」「属性」「实例属性」系词
+    /// * 🎯用于[`NarseseFormat::desugar`]等
+    /// * 📄ASCII：`("-->", "{--", "--]", "{-]")`
+    pub fn copula_kinds(
+        mut self,
+        inheritance: impl Into<String>,
+        instance: impl Into<String>,
+        property: impl Into<String>,
+        instance_property: impl Into<String>,
+    ) -> Self {
+        let statement = &mut self.base.statement;
+        statement.copula_inheritance = inheritance.into();
+        statement.copula_instance = instance.into();
+        statement.copula_property = property.into();
+        statement.copula_instance_property = instance_property.into();
+        self
+    }
+
+    /// 设置「满足对称性的陈述系词」
+    pub fn symmetric_copulas(mut self, copulas: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.symmetric_copulas = into_strings(copulas);
+        self
+    }
+
+    /// 设置「复合词项连接符」的容量
+    /// * 📌构建时只保留「复合词项连接符」中存在的条目
+    pub fn connecter_capacities(
+        mut self,
+        capacities: impl IntoIterator<Item = (impl Into<String>, TermCapacity)>,
+    ) -> Self {
+        self.base.compound.connecter_capacities = into_capacities(capacities);
+        self
+    }
+
+    /// 设置「陈述系词」的容量
+    /// * 📌构建时只保留「陈述系词」中存在的条目
+    pub fn copula_capacities(
+        mut self,
+        capacities: impl IntoIterator<Item = (impl Into<String>, TermCapacity)>,
+    ) -> Self {
+        self.base.statement.copula_capacities = into_capacities(capacities);
+        self
+    }
+}
+
+/// 设置/语句与任务
+impl NarseseFormatBuilder {
+    /// 设置「标点」
+    pub fn punctuations(mut self, punctuations: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.punctuations = into_strings(punctuations);
+        self
+    }
+
+    /// 设置各类标点的语义
+    /// * 🎯用于[`Sentence::is_judgement_with`](crate::lexical::Sentence::is_judgement_with)等
+    /// * 📄ASCII：`(".", "!", "?", "@")`
+    pub fn punctuation_kinds(
+        mut self,
+        judgement: impl Into<String>,
+        goal: impl Into<String>,
+        question: impl Into<String>,
+        quest: impl Into<String>,
+    ) -> Self {
+        let sentence = &mut self.base.sentence;
+        sentence.punctuation_judgement = judgement.into();
+        sentence.punctuation_goal = goal.into();
+        sentence.punctuation_question = question.into();
+        sentence.punctuation_quest = quest.into();
+        self
+    }
+
+    /// 设置「时间戳括弧对」
+    /// * 📄ASCII：`("", ":|:")`（现在）、`(":!", ":")`（固定）
+    pub fn stamp_brackets(
+        mut self,
+        brackets: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+    ) -> Self {
+        self.stamp_brackets = into_pairs(brackets);
+        self
+    }
+
+    /// 设置各类时间戳的语义
+    /// * 🎯用于[`NarseseFormat::parse_stamp_kind`]
+    /// * 📄ASCII：`(":\\:", ":|:", ":/:", (":!", ":"))`
+    pub fn stamp_kinds(
+        mut self,
+        past: impl Into<String>,
+        present: impl Into<String>,
+        future: impl Into<String>,
+        (fixed_left, fixed_right): (impl Into<String>, impl Into<String>),
+    ) -> Self {
+        let sentence = &mut self.base.sentence;
+        sentence.stamp_past = past.into();
+        sentence.stamp_present = present.into();
+        sentence.stamp_future = future.into();
+        sentence.stamp_fixed = (fixed_left.into(), fixed_right.into());
+        self
+    }
+
+    /// 设置「固定时间戳内容」的判断
+    pub fn is_stamp_content(mut self, is_stamp_content: fn(char) -> bool) -> Self {
+        self.base.sentence.is_stamp_content = is_stamp_content;
+        self
+    }
+
+    /// 设置「真值括弧对」
+    pub fn truth_brackets(mut self, left: impl Into<String>, right: impl Into<String>) -> Self {
+        self.base.sentence.truth_brackets = (left.into(), right.into());
+        self
+    }
+
+    /// 设置「真值内部分隔符」
+    pub fn truth_separator(mut self, separator: impl Into<String>) -> Self {
+        self.base.sentence.truth_separator = separator.into();
+        self
+    }
+
+    /// 设置「真值内部备选分隔符」
+    /// * 📌仅用于解析
+    pub fn truth_separator_alternates(
+        mut self,
+        separators: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.base.sentence.truth_separator_alternates = into_strings(separators);
+        self
+    }
+
+    /// 设置「真值内容」的判断
+    pub fn is_truth_content(mut self, is_truth_content: fn(char) -> bool) -> Self {
+        self.base.sentence.is_truth_content = is_truth_content;
+        self
+    }
+
+    /// 设置「预算值括弧对」
+    pub fn budget_brackets(mut self, left: impl Into<String>, right: impl Into<String>) -> Self {
+        self.base.task.budget_brackets = (left.into(), right.into());
+        self
+    }
+
+    /// 设置「预算值内部分隔符」
+    pub fn budget_separator(mut self, separator: impl Into<String>) -> Self {
+        self.base.task.budget_separator = separator.into();
+        self
+    }
+
+    /// 设置「预算值内部备选分隔符」
+    /// * 📌仅用于解析
+    pub fn budget_separator_alternates(
+        mut self,
+        separators: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.base.task.budget_separator_alternates = into_strings(separators);
+        self
+    }
+
+    /// 设置「预算值内容」的判断
+    pub fn is_budget_content(mut self, is_budget_content: fn(char) -> bool) -> Self {
+        self.base.task.is_budget_content = is_budget_content;
+        self
+    }
+}
+
+/// 设置/选项
+impl NarseseFormatBuilder {
+    /// 设置「PyNARS兼容模式」
+    pub fn pynars_compat(mut self, value: bool) -> Self {
+        self.base.pynars_compat = value;
+        self
+    }
+
+    /// 设置「是否规范化间隔字面量」
+    pub fn normalize_interval_literals(mut self, value: bool) -> Self {
+        self.base.normalize_interval_literals = value;
+        self
+    }
+
+    /// 设置「最大嵌套深度」
+    pub fn max_depth(mut self, value: usize) -> Self {
+        self.base.max_depth = value;
+        self
+    }
+
+    /// 设置「是否接受未知的陈述系词」
+    pub fn accept_unknown_copulas(mut self, value: bool) -> Self {
+        self.base.accept_unknown_copulas = value;
+        self
+    }
+
+    /// 设置「是否接受未知的复合词项连接符」
+    pub fn accept_unknown_connecters(mut self, value: bool) -> Self {
+        self.base.accept_unknown_connecters = value;
+        self
+    }
+
+    /// 设置「查询变量与问题标点的消歧策略」
+    pub fn ambiguity_resolution(mut self, value: AmbiguityResolution) -> Self {
+        self.base.ambiguity_resolution = value;
+        self
+    }
+
+    /// 设置「注释前缀」
+    /// * 📌传入空列表⇒不识别任何注释
+    pub fn comment_prefixes(mut self, prefixes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.base.comment_prefixes = into_strings(prefixes);
+        self
+    }
+}
+
+/// 检验/工具函数
+/// * 🚩找到第一个重复的元素
+fn find_duplicate<'a>(items: impl IntoIterator<Item = &'a String>) -> Option<&'a String> {
+    let mut seen = vec![];
+    for item in items {
+        if seen.contains(&item) {
+            return Some(item);
+        }
+        seen.push(item);
+    }
+    None
+}
+
+/// 构建
+impl NarseseFormatBuilder {
+    /// 检验配置
+    /// * 🚩拒绝以下「有歧义」的配置
+    ///   * 重复的前缀、连接符、系词、标点、括弧
+    ///   * 空的连接符、系词、标点、复合词项/陈述括弧、原子词项引号
+    ///   * 「可交换连接符」「对称系词」「间隔前缀」「各类标点」不在相应列表中
+    ///   * 某个原子词项前缀以系词开头：解析时无法区分
+    ///   * 最大嵌套深度为0：无法解析任何词项
+    ///   * 空的注释前缀：会将所有输入视作注释
+    fn validate(&self) -> FormatBuildResult<()> {
+        // 重复
+        let lists = [
+            ("原子词项前缀", &self.atom_prefixes),
+            ("复合词项连接符", &self.connecters),
+            ("陈述系词", &self.copulas),
+            ("标点", &self.punctuations),
+        ];
+        for (name, list) in lists {
+            if let Some(duplicate) = find_duplicate(list) {
+                return Err(FormatBuildError!("重复的{name}「{duplicate}」"));
+            }
+        }
+        if let Some(duplicate) = find_duplicate(self.set_brackets.iter().map(|(left, _)| left)) {
+            return Err(FormatBuildError!("重复的词项集左括弧「{duplicate}」"));
+        }
+        if let Some(duplicate) = find_duplicate(self.set_brackets.iter().map(|(_, right)| right)) {
+            return Err(FormatBuildError!("重复的词项集右括弧「{duplicate}」"));
+        }
+        if let Some(duplicate) = find_duplicate(self.stamp_brackets.iter().map(|(_, right)| right)) {
+            return Err(FormatBuildError!("重复的时间戳右括弧「{duplicate}」"));
+        }
+        // 空符号
+        let symbols = [
+            ("复合词项连接符", &self.connecters),
+            ("陈述系词", &self.copulas),
+            ("标点", &self.punctuations),
+        ];
+        for (name, list) in symbols {
+            if list.iter().any(String::is_empty) {
+                return Err(FormatBuildError!("{name}不能为空"));
+            }
+        }
+        let brackets = [
+            ("复合词项括弧", &self.base.compound.brackets),
+            ("陈述括弧", &self.base.statement.brackets),
+        ]
+        .into_iter()
+        .chain(self.base.atom.quotes.iter().map(|quotes| ("原子词项引号", quotes)));
+        for (name, (left, right)) in brackets {
+            if left.is_empty() || right.is_empty() {
+                return Err(FormatBuildError!("{name}不能为空"));
+            }
+        }
+        // 子集
+        let subsets = [
+            ("可交换连接符", &self.commutative_connecters, "复合词项连接符", &self.connecters),
+            ("对称系词", &self.symmetric_copulas, "陈述系词", &self.copulas),
+        ];
+        for (name, subset, parent_name, parent) in subsets {
+            if let Some(missing) = subset.iter().find(|item| !parent.contains(item)) {
+                return Err(FormatBuildError!("{name}「{missing}」不在{parent_name}中"));
+            }
+        }
+        let compound = &self.base.compound;
+        let connecters = [
+            ("顺序合取连接符", &compound.connecter_conjunction_sequential),
+            ("外延像连接符", &compound.connecter_image_extension),
+            ("内涵像连接符", &compound.connecter_image_intension),
+        ];
+        for (name, connecter) in connecters {
+            if !self.connecters.contains(connecter) {
+                return Err(FormatBuildError!("{name}「{connecter}」不在复合词项连接符中"));
+            }
+        }
+        let set_kinds = [
+            ("外延集括弧", &compound.set_extension_brackets),
+            ("内涵集括弧", &compound.set_intension_brackets),
+        ];
+        for (name, brackets @ (left, right)) in set_kinds {
+            if !self.set_brackets.contains(brackets) {
+                return Err(FormatBuildError!("{name}「{left}」「{right}」不在词项集括弧中"));
+            }
+        }
+        let statement = &self.base.statement;
+        let copulas = [
+            ("继承系词", &statement.copula_inheritance),
+            ("实例系词", &statement.copula_instance),
+            ("属性系词", &statement.copula_property),
+            ("实例属性系词", &statement.copula_instance_property),
+        ];
+        for (name, copula) in copulas {
+            if !self.copulas.contains(copula) {
+                return Err(FormatBuildError!("{name}「{copula}」不在陈述系词中"));
+            }
+        }
+        let atom = &self.base.atom;
+        let prefixes = [
+            ("间隔前缀", &atom.prefix_interval),
+            ("词语前缀", &atom.prefix_word),
+            ("占位符前缀", &atom.prefix_placeholder),
+        ]
+        .into_iter()
+        .chain(atom.prefix_variables.iter().map(|prefix| ("变量前缀", prefix)));
+        for (name, prefix) in prefixes {
+            if !self.atom_prefixes.contains(prefix) {
+                return Err(FormatBuildError!("{name}「{prefix}」不在原子词项前缀中"));
+            }
+        }
+        let sentence = &self.base.sentence;
+        let punctuations = [
+            ("判断标点", &sentence.punctuation_judgement),
+            ("目标标点", &sentence.punctuation_goal),
+            ("问题标点", &sentence.punctuation_question),
+            ("请求标点", &sentence.punctuation_quest),
+        ];
+        for (name, punctuation) in punctuations {
+            if !self.punctuations.contains(punctuation) {
+                return Err(FormatBuildError!("{name}「{punctuation}」不在标点中"));
+            }
+        }
+        // 系词与原子词项前缀冲突
+        for prefix in self.atom_prefixes.iter().filter(|prefix| !prefix.is_empty()) {
+            if let Some(copula) = self.copulas.iter().find(|copula| prefix.starts_with(copula.as_str())) {
+                return Err(FormatBuildError!("原子词项前缀「{prefix}」以系词「{copula}」开头，解析时存在歧义"));
+            }
+        }
+        // 嵌套深度
+        if self.base.max_depth == 0 {
+            return Err(FormatBuildError!("最大嵌套深度不能为0"));
+        }
+        // 注释前缀
+        if self.base.comment_prefixes.iter().any(String::is_empty) {
+            return Err(FormatBuildError!("注释前缀不能为空"));
+        }
+        // 备选分隔符
+        let alternates = [
+            ("真值备选分隔符", &self.base.sentence.truth_separator_alternates),
+            ("预算值备选分隔符", &self.base.task.budget_separator_alternates),
+        ];
+        for (name, list) in alternates {
+            if list.iter().any(String::is_empty) {
+                return Err(FormatBuildError!("{name}不能为空"));
+            }
+        }
+        Ok(())
+    }
+
+    /// 构建格式
+    /// * 🚩先检验配置，再将各列表转换为「匹配字典」
+    /// * 🚩容量表中不在连接符、系词列表中的条目被移除
+    pub fn build(self) -> FormatBuildResult {
+        self.validate()?;
+        let Self {
+            mut base,
+            atom_prefixes,
+            set_brackets,
+            connecters,
+            commutative_connecters,
+            copulas,
+            symmetric_copulas,
+            punctuations,
+            stamp_brackets,
+        } = self;
+        base.atom.prefixes = x_fix_dict(atom_prefixes);
+        base.compound.set_brackets = {
+            let mut dict = BiFixMatchDictPair::default();
+            for pair in set_brackets {
+                dict.insert(pair);
+            }
+            dict
+        };
+        // 容量表 | 移除已不存在的符号，以免自定义方言继承原格式的条目
+        base.compound
+            .connecter_capacities
+            .retain(|(connecter, _)| connecters.contains(connecter));
+        base.statement
+            .copula_capacities
+            .retain(|(copula, _)| copulas.contains(copula));
+        base.compound.connecters = x_fix_dict(connecters);
+        base.compound.commutative_connecters = x_fix_dict(commutative_connecters);
+        base.statement.copulas = x_fix_dict(copulas);
+        base.statement.symmetric_copulas = x_fix_dict(symmetric_copulas);
+        base.sentence.punctuations = x_fix_dict(punctuations);
+        base.sentence.stamp_brackets = {
+            let mut dict = SuffixMatchDictPair::default();
+            for pair in stamp_brackets {
+                dict.insert(pair);
+            }
+            dict
+        };
+        Ok(base)
+    }
+}
+
+/// 工具函数：构造「词缀匹配字典」
+/// * 🚩逐个插入，以保证内部有序
+fn x_fix_dict(items: Vec<String>) -> PrefixMatchDict {
+    let mut dict = PrefixMatchDict::default();
+    for item in items {
+        dict.insert(item);
+    }
+    dict
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        conversion::string::impl_lexical::{
+            format_instances::{FORMAT_ASCII, FORMAT_HAN, FORMAT_LATEX},
+            tests::{_sample_task_han, _sample_task_latex},
+        },
+        lexical::Narsese,
+    };
+    use nar_dev_utils::{asserts, show};
+
+    /// 类ONA方言
+    /// * 🚩不支持回顾性、并发性的蕴含/等价
+    /// * 🚩仅支持「现在」与「固定」时间戳
+    /// * 🚩预算值使用`[ ]`括弧、`,`分隔（仅作演示）
+    fn format_ona() -> NarseseFormat {
+        FORMAT_ASCII
+            .builder()
+            .copulas(["-->", "<->", "==>", "<=>", "{--", "--]", "{-]", "=/>"])
+            .symmetric_copulas(["<->", "<=>"])
+            .stamp_brackets([("", ":|:"), (":!", ":")])
+            .budget_brackets("[", "]")
+            .budget_separator(",")
+            .is_budget_content(|c| matches!(c, '0'..='9' | '.' | ','))
+            .build()
+            .expect("类ONA方言构建失败")
+    }
+
+    /// 内置格式可以原样重建
+    #[test]
+    fn test_rebuild_builtin() {
+        let samples = [
+            (&*FORMAT_ASCII, crate::lexical::tests::_sample_task_ascii()),
+            (&*FORMAT_LATEX, _sample_task_latex()),
+            (&*FORMAT_HAN, _sample_task_han()),
+        ];
+        for (format, task) in samples {
+            let rebuilt = format.builder().build().unwrap();
+            let formatted = format.format_task(&task);
+            asserts! {
+                rebuilt.format_task(&task) => formatted,
+                rebuilt.parse(&formatted).unwrap() => Narsese::Task(task),
+            }
+        }
+    }
+
+    /// 类ONA方言：往返
+    #[test]
+    fn test_ona_round_trip() {
+        let ona = format_ona();
+        let input = "[0.8,0.5,0.5] <(&/, <A --> B>, ^left) =/> <C --> D>>! :|: %1.0;0.9%";
+        let task = ona.parse(input).unwrap();
+        let formatted = ona.format_narsese(&task);
+        show!(&formatted);
+        asserts! {
+            matches!(task, Narsese::Task(..)),
+            formatted => input,
+            ona.parse(&formatted).unwrap() => task,
+            // 删去的系词无法解析
+            ona.parse(r"<A =\> B>.").is_err(),
+            // 原格式则可以
+            FORMAT_ASCII.parse(r"<A =\> B>.").is_ok(),
+        }
+    }
+
+    /// 有歧义的配置
+    #[test]
+    fn test_invalid() {
+        let build = |builder: NarseseFormatBuilder| builder.build().unwrap_err();
+        let errors = [
+            // 重复的连接符
+            build(FORMAT_ASCII.builder().connecters(["&&", "||", "&&"])),
+            // 重复的词项集括弧
+            build(FORMAT_ASCII.builder().set_brackets([("{", "}"), ("[", "}")])),
+            // 空系词
+            build(FORMAT_ASCII.builder().copulas(["-->", ""])),
+            // 可交换连接符不在连接符中
+            build(FORMAT_ASCII.builder().commutative_connecters(["&&", "?"])),
+            // 对称系词不在系词中
+            build(FORMAT_ASCII.builder().copulas(["-->"])),
+            // 间隔前缀不在原子词项前缀中
+            build(FORMAT_ASCII.builder().prefix_interval("!")),
+            // 原子词项前缀以系词开头
+            build(FORMAT_ASCII.builder().atom_prefixes(["", "_", "$", "#", "?", "-->x", "+"])),
+            // 最大嵌套深度为0
+            build(FORMAT_ASCII.builder().max_depth(0)),
+            // 变量前缀不在原子词项前缀中
+            build(FORMAT_ASCII.builder().prefix_variables(["$", "#", "%"])),
+            // 空引号
+            build(FORMAT_ASCII.builder().quotes("\"", "")),
+            // 顺序合取连接符不在连接符中
+            build(FORMAT_ASCII.builder().connecter_conjunction_sequential("&&/")),
+            // 像连接符不在连接符中
+            build(FORMAT_ASCII.builder().connecter_images("/", "\\\\")),
+            // 占位符前缀不在原子词项前缀中
+            build(FORMAT_ASCII.builder().prefix_placeholder("__")),
+            // 空的注释前缀
+            build(FORMAT_ASCII.builder().comment_prefixes(["//", ""])),
+            // 空的备选分隔符
+            build(FORMAT_ASCII.builder().truth_separator_alternates([""])),
+            build(FORMAT_ASCII.builder().budget_separator_alternates([",", ""])),
+            // 标点语义不在标点中
+            build(FORMAT_ASCII.builder().punctuations([".", "!", "?"])),
+            // 系词语义不在系词中
+            build(FORMAT_ASCII.builder().copula_kinds("-->", "{--", "--]", "{-}")),
+            // 词项集语义不在词项集括弧中
+            build(FORMAT_ASCII.builder().set_kinds(("{", "}"), ("[", ")"))),
+        ];
+        show!(&errors);
+        asserts! {
+            errors[0].message() => "重复的复合词项连接符「&&」",
+            errors[6].message() => "原子词项前缀「-->x」以系词「-->」开头，解析时存在歧义",
+            errors[7].message() => "最大嵌套深度不能为0",
+            errors[8].message() => "变量前缀「%」不在原子词项前缀中",
+            errors[9].message() => "原子词项引号不能为空",
+            errors[10].message() => "顺序合取连接符「&&/」不在复合词项连接符中",
+            errors[11].message() => r"内涵像连接符「\\」不在复合词项连接符中",
+            errors[12].message() => "占位符前缀「__」不在原子词项前缀中",
+            errors[13].message() => "注释前缀不能为空",
+            errors[14].message() => "真值备选分隔符不能为空",
+            errors[15].message() => "预算值备选分隔符不能为空",
+            errors[16].message() => "请求标点「@」不在标点中",
+            errors[17].message() => "实例属性系词「{-}」不在陈述系词中",
+            errors[18].message() => "内涵集括弧「[」「)」不在词项集括弧中",
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! 词法词项的「带检验构造」
+//! * 🎯词法Narsese本身不做校验：`lexical_set!("{"; "SELF"; "]")`也能构造
+//!   * ⚠️这样的词项能格式化，但格式化出的文本无法被解析回来
+//! * 🚩提供按格式检验的构造路径，而宏与[`Term::new_set`]保持宽松
+//!   * 📌词项集括弧：须为格式中的[括弧对](super::NarseseFormatCompound::set_brackets)
+//! * 📄完整的良构检查：[`lint`](super::lint)
+//! * 📄格式化时的检查：[`NarseseFormat::format_term_checked`]
+//!   * 📌普通的格式化不检查：允许「用一种格式的值套另一种格式」
+
+use super::NarseseFormat;
+use crate::lexical::Term;
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// 词法词项构造错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TermBuildError {
+    /// 词项集的左右括弧不是格式中的括弧对
+    /// * 📄ASCII：`{`与`]`
+    UnknownSetBrackets { left: String, right: String },
+}
+
+impl Display for TermBuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownSetBrackets { left, right } => {
+                write!(f, "未知的词项集括弧「{left}」「{right}」")
+            }
+        }
+    }
+}
+
+impl Error for TermBuildError {}
+
+/// 实现/带检验构造
+impl Term {
+    /// 新建集合（带检验）
+    /// * 🚩左右括弧须为格式中的括弧对
+    /// * 📌不检验组分：组分应已经过检验
+    pub fn new_set_checked(
+        left_bracket: impl Into<String>,
+        terms: Vec<Term>,
+        right_bracket: impl Into<String>,
+        format: &NarseseFormat,
+    ) -> Result<Term, TermBuildError> {
+        let term = Term::new_set(left_bracket, terms, right_bracket);
+        term.check_brackets(format)?;
+        Ok(term)
+    }
+
+    /// 检验所有词项集的括弧
+    /// * 🚩递归检验所有子词项，返回先序遍历中的首个错误
+    pub fn check_brackets(&self, format: &NarseseFormat) -> Result<(), TermBuildError> {
+        for term in self.iter_dfs() {
+            if let Term::Set {
+                left_bracket,
+                right_bracket,
+                ..
+            } = term
+            {
+                if !format.is_set_brackets(left_bracket, right_bracket) {
+                    return Err(TermBuildError::UnknownSetBrackets {
+                        left: left_bracket.clone(),
+                        right: right_bracket.clone(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 实现/带检验格式化
+impl NarseseFormat {
+    /// 格式化函数/词项（带检验）
+    /// * 🎯避免格式化出「无法被解析回来」的文本
+    /// * 🚩先[检验所有词项集的括弧](Term::check_brackets)，再格式化
+    pub fn format_term_checked(&self, term: &Term) -> Result<String, TermBuildError> {
+        term.check_brackets(self)?;
+        Ok(self.format_term(term))
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        conversion::string::impl_lexical::format_instances::{FORMAT_ASCII, FORMAT_HAN},
+        lexical::shortcuts::*,
+    };
+    use nar_dev_utils::asserts;
+
+    /// 测试/括弧匹配与不匹配
+    #[test]
+    fn test_new_set_checked() {
+        let ascii = &*FORMAT_ASCII;
+        let han = &*FORMAT_HAN;
+        let self_ = || vec![atom!("SELF")];
+        let unknown = |left: &str, right: &str| TermBuildError::UnknownSetBrackets {
+            left: left.into(),
+            right: right.into(),
+        };
+        asserts! {
+            // 匹配
+            Term::new_set_checked("{", self_(), "}", ascii) => Ok(set!("{"; "SELF"; "}")),
+            Term::new_set_checked("[", self_(), "]", ascii) => Ok(set!("["; "SELF"; "]")),
+            Term::new_set_checked("『", self_(), "』", han) => Ok(set!("『"; "SELF"; "』")),
+            Term::new_set_checked("【", self_(), "】", han) => Ok(set!("【"; "SELF"; "】")),
+            // 不匹配
+            Term::new_set_checked("{", self_(), "]", ascii) => Err(unknown("{", "]")),
+            Term::new_set_checked("[", self_(), "}", ascii) => Err(unknown("[", "}")),
+            Term::new_set_checked("『", self_(), "】", han) => Err(unknown("『", "】")),
+            // 跨格式
+            Term::new_set_checked("{", self_(), "}", han) => Err(unknown("{", "}")),
+            Term::new_set_checked("『", self_(), "』", ascii) => Err(unknown("『", "』")),
+        }
+    }
+
+    /// 测试/递归检验
+    #[test]
+    fn test_check_brackets() {
+        let ascii = &*FORMAT_ASCII;
+        let good = statement!(set!("{"; "SELF"; "}") "-->" set!("["; "good"; "]"));
+        let bad = compound!("&&", atom!("A"), statement!(atom!("B") "-->" set!("["; "C"; "}")));
+        asserts! {
+            good.check_brackets(ascii) => Ok(()),
+            bad.check_brackets(ascii) => Err(TermBuildError::UnknownSetBrackets {
+                left: "[".into(),
+                right: "}".into(),
+            }),
+            bad.check_brackets(ascii).unwrap_err().to_string() => "未知的词项集括弧「[」「}」",
+        }
+    }
+
+    /// 测试/带检验格式化
+    #[test]
+    fn test_format_term_checked() {
+        let ascii = &*FORMAT_ASCII;
+        let bad = set!("{"; "SELF"; "]");
+        asserts! {
+            ascii.format_term_checked(&set!("{"; "SELF"; "}")) => Ok("{SELF}".into()),
+            ascii.format_term_checked(&bad) => Err(TermBuildError::UnknownSetBrackets {
+                left: "{".into(),
+                right: "]".into(),
+            }),
+            // 不带检验⇒照常格式化
+            ascii.format_term(&bad) => "{SELF]",
+            // 跨格式
+            FORMAT_HAN.format_term_checked(&set!("{"; "SELF"; "}")).is_err(),
+        }
+    }
+}
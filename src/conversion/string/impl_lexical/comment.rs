@@ -0,0 +1,148 @@
+//! 词法Narsese的「注释」处理
+//! * 🎯直接解析真实的`.nal`文件：其中含有整行注释与行尾注释
+//!   * 📄`'测试用NAL文件`、`// C风格注释`
+//!   * 📄`<A --> B>. %1.0;0.9% // 行尾注释`
+//! * 🚩注释前缀见[`NarseseFormat::comment_prefixes`]
+//! * 📌仅识别「括弧之外」的注释前缀：避免破坏含`//`的原子词项名称
+//!   * 📄`<http://x --> B>.`：`//`位于陈述括弧之内，不视作注释
+//! * 📌扫描逻辑与枚举Narsese共用：参见[`CommentScanner`]
+
+use super::NarseseFormat;
+use crate::conversion::string::CommentScanner;
+use nar_dev_utils::PrefixMatch;
+
+impl NarseseFormat {
+    /// 构造「注释扫描器」
+    /// * 🚩括弧：复合词项、词项集、陈述的括弧对
+    /// * 🚩引号：原子词项引号对（及PyNARS兼容模式下的单引号）
+    fn comment_scanner(&self) -> CommentScanner<'_> {
+        let (compound_left, compound_right) = &self.compound.brackets;
+        let (statement_left, statement_right) = &self.statement.brackets;
+        let mut scanner = CommentScanner {
+            comment_prefixes: self.comment_prefixes.iter().map(String::as_str).collect(),
+            left_brackets: vec![compound_left, statement_left],
+            right_brackets: vec![compound_right, statement_right],
+            copulas: self
+                .statement
+                .copulas
+                .prefix_terms()
+                .map(String::as_str)
+                .collect(),
+            quotes: vec![],
+        };
+        for (left, right) in self.compound.set_brackets.prefix_terms() {
+            scanner.left_brackets.push(left);
+            scanner.right_brackets.push(right);
+        }
+        if let Some((left, right)) = &self.atom.quotes {
+            scanner.quotes.push((left, right));
+        }
+        if self.pynars_compat {
+            scanner.quotes.push(("'", "'"));
+        }
+        scanner
+    }
+
+    /// 去除注释
+    /// * 🚩返回「首个括弧外注释前缀」之前的内容（去除尾部空白）
+    ///   * 📌注释延续到输入末尾：多行输入中，注释之后的各行亦被舍去
+    ///   * 📌整行均为注释⇒返回空字串
+    /// * 🚩括弧：复合词项、词项集、陈述的括弧对
+    ///   * 📌系词整体跳过，不计入括弧：`-->`中的`>`并非右括弧
+    /// * 🚩引号：原子词项引号对（及PyNARS兼容模式下的单引号）之内不识别注释
+    ///   * ⚠️PyNARS兼容模式下，`'`优先视作引号：`'`开头的整行注释将不再被识别
+    /// * 📄参见[`CommentScanner::strip_comment`]
+    pub fn strip_comment<'a>(&self, line: &'a str) -> &'a str {
+        self.comment_scanner().strip_comment(line)
+    }
+
+    /// 将输入切分为「逻辑语句」
+    /// * 🚩按行切分，去除注释，跳过空行（含「整行注释」）
+    /// * 🚩产出的内容已去除首尾空白
+    pub fn statement_lines<'a>(&'a self, input: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        let scanner = self.comment_scanner();
+        input
+            .lines()
+            .map(move |line| scanner.strip_comment(line).trim())
+            .filter(|line| !line.is_empty())
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use crate::conversion::string::impl_lexical::format_instances::*;
+    use nar_dev_utils::asserts;
+
+    #[test]
+    fn test_strip_comment() {
+        let format = &FORMAT_ASCII;
+        asserts! {
+            // 整行注释
+            format.strip_comment("// C风格注释") => "",
+            format.strip_comment("'测试用NAL文件") => "",
+            format.strip_comment("''outputMustContain('<robin --> animal>.')") => "",
+            // 行尾注释
+            format.strip_comment("<A --> B>. %1.0;0.9% // 行尾注释") => "<A --> B>. %1.0;0.9%",
+            format.strip_comment("<A --> B>.'注释") => "<A --> B>.",
+            format.strip_comment("$0.5;0.5;0.5$ (&&, A, B)! :|: // 注释") => "$0.5;0.5;0.5$ (&&, A, B)! :|:",
+            // 括弧内：不视作注释
+            format.strip_comment("<http://x --> B>.") => "<http://x --> B>.",
+            format.strip_comment("<A --> http://x>. // 注释") => "<A --> http://x>.",
+            format.strip_comment("<A ==> (*, a//b, {c//d})>.") => "<A ==> (*, a//b, {c//d})>.",
+            // 无注释
+            format.strip_comment("<A --> B>.") => "<A --> B>.",
+            format.strip_comment("") => "",
+        }
+        // 自定义注释前缀
+        let format = FORMAT_ASCII
+            .builder()
+            .comment_prefixes(["#!", "*"])
+            .build()
+            .unwrap();
+        asserts! {
+            format.strip_comment("*volume=0") => "",
+            format.strip_comment("<A --> B>. #! 注释") => "<A --> B>.",
+            format.strip_comment("<A --> B>. // 非注释") => "<A --> B>. // 非注释",
+            // 括弧内的连接符不受影响
+            format.strip_comment("(*, A, B). * 注释") => "(*, A, B).",
+        }
+        // 原子词项引号、PyNARS单引号
+        let quoted = FORMAT_ASCII.builder().quotes("\"", "\"").build().unwrap();
+        asserts! {
+            quoted.strip_comment("\"a // b\". // 注释") => "\"a // b\".",
+            FORMAT_PYNARS.strip_comment("'hello // world'. // 注释") => "'hello // world'.",
+        }
+    }
+
+    #[test]
+    fn test_parse_with_comments() {
+        let format = &FORMAT_ASCII;
+        let parse = |input| format.parse(input).unwrap();
+        // 行尾注释⇒与无注释一致
+        asserts! {
+            format.parse("<A --> B>. %1.0;0.9% // 行尾注释").unwrap() => parse("<A --> B>. %1.0;0.9%"),
+            format.parse("(&&, A, B)? 'OpenNARS注释").unwrap() => parse("(&&, A, B)?"),
+        }
+        // 整行注释⇒「输入为空」
+        for input in ["// 注释", "  'comment", "''outputMustContain('A.')"] {
+            assert!(format.parse(input).unwrap_err().is_empty_input());
+        }
+        // 批量解析：跳过整行注释
+        let input = "
+            '测试用NAL文件
+            <robin --> bird>. // 知更鸟是鸟
+            // C风格注释
+            <bird --> animal>. %1.0;0.9% 'OpenNARS风格行尾注释
+            ''outputMustContain('<robin --> animal>.')
+            <robin --> animal>?
+        ";
+        let results = format.parse_all(input);
+        asserts! {
+            results.len() => 3,
+            results[0].as_ref().unwrap() => &parse("<robin --> bird>."),
+            results[1].as_ref().unwrap() => &parse("<bird --> animal>. %1.0;0.9%"),
+            results[2].as_ref().unwrap() => &parse("<robin --> animal>?"),
+        }
+    }
+}
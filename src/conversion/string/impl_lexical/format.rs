@@ -14,8 +14,16 @@
 //!     * 生命周期管理冗杂 | 💭允许牺牲一定性能，专注功能
 //!     * 前缀匹配字典不兼容 | 无法合并「动态字串前缀匹配」与「静态字串前缀匹配」
 
+use crate::{
+    api::TermCapacity,
+    conversion::string::{
+        collect_bracket_pairs, collect_tokens, AmbiguityResolution, BracketRole, FormatVocabulary, TokenRole,
+    },
+    lexical::Term,
+};
 use nar_dev_utils::{
-    BiFixMatchDict, BiFixMatchDictPair, PrefixMatchDict, SuffixMatchDict, SuffixMatchDictPair,
+    BiFixMatchDict, BiFixMatchDictPair, PrefixMatch, PrefixMatchDict, SuffixMatchDict,
+    SuffixMatchDictPair,
 };
 
 /// Narsese格式/空白符
@@ -62,8 +70,45 @@ pub struct NarseseFormatAtom {
 
     /// 用于判断字符是否为「合法原子标识符」的函数
     pub is_identifier: fn(char) -> bool,
+
+    /// 「间隔」的前缀
+    /// * 🎯用于在解析时识别「间隔」，以便规范化其数值
+    ///   * 📄ASCII：`+`
+    /// * ⚠️应当同时存在于[`Self::prefixes`]中
+    pub prefix_interval: String,
+
+    /// 「词语」的前缀
+    /// * 🎯用于在不引入「枚举Narsese」的前提下识别「词语」
+    ///   * 📄ASCII：空字串
+    /// * ⚠️应当同时存在于[`Self::prefixes`]中
+    pub prefix_word: String,
+
+    /// 「占位符」的前缀
+    /// * 🎯用于在不引入「枚举Narsese」的前提下识别「像」中的占位符
+    ///   * 📄ASCII：`_`
+    /// * ⚠️应当同时存在于[`Self::prefixes`]中
+    pub prefix_placeholder: String,
+
+    /// 「变量」的前缀
+    /// * 🎯用于在不引入「枚举Narsese」的前提下识别「变量」
+    ///   * 📄ASCII：`$`、`#`、`?`
+    /// * ⚠️应当同时存在于[`Self::prefixes`]中
+    pub prefix_variables: Vec<String>,
+
+    /// 原子词项名称的引号对（可选）
+    /// * 🎯使含空白、分隔符、括弧等字符的名称也能往返
+    ///   * 📄`"word term"` ⇒ 名称为`word term`的词语
+    /// * 📌引号位于前缀之后：`^"go to"`
+    /// * 📌引号内以[`ATOM_QUOTE_ESCAPE`]转义下一个字符：`"say \"hi\""`
+    /// * 🚩解析时保留引号内的空白；格式化时仅对含「非标识符字符」的名称加引号
+    /// * ⚙️内置格式均不启用
+    pub quotes: Option<(String, String)>,
 }
 
+/// 原子词项引号内的转义符
+/// * 📄参见[`NarseseFormatAtom::quotes`]
+pub const ATOM_QUOTE_ESCAPE: char = '\\';
+
 /// 复合词项格式
 #[derive(Debug, Clone)]
 pub struct NarseseFormatCompound {
@@ -72,6 +117,19 @@ pub struct NarseseFormatCompound {
     /// * 内涵集
     pub set_brackets: BiFixMatchDictPair,
 
+    /// 「外延集」的括弧对
+    /// * 🎯用于在不引入「枚举Narsese」的前提下识别「外延集」
+    ///   * 📄[`NarseseFormat::desugar`]
+    /// * ⚠️应当同时存在于[`Self::set_brackets`]中
+    /// * 📄ASCII：`{`、`}`
+    pub set_extension_brackets: (String, String),
+
+    /// 「内涵集」的括弧对
+    /// * 🎯用于在不引入「枚举Narsese」的前提下识别「内涵集」
+    /// * ⚠️应当同时存在于[`Self::set_brackets`]中
+    /// * 📄ASCII：`[`、`]`
+    pub set_intension_brackets: (String, String),
+
     /// 通用的「复合词项括弧对」
     pub brackets: (String, String),
 
@@ -87,6 +145,39 @@ pub struct NarseseFormatCompound {
     /// * 否定
     /// * 顺序合取/平行合取
     pub connecters: PrefixMatchDict,
+
+    /// 满足交换律的「复合词项连接符」
+    /// * 🎯用于「词项规范化」：其组分可被排序、去重
+    /// * 外延交/内涵交
+    /// * 合取/析取
+    /// * 平行合取
+    /// * ⚠️应当同时存在于[`Self::connecters`]中
+    pub commutative_connecters: PrefixMatchDict,
+
+    /// 「顺序合取」的连接符
+    /// * 🎯用于在不引入「枚举Narsese」的前提下识别「顺序合取」
+    ///   * 📄ASCII：`&/`
+    /// * ⚠️应当同时存在于[`Self::connecters`]中
+    pub connecter_conjunction_sequential: String,
+
+    /// 「外延像」的连接符
+    /// * 🎯用于在不引入「枚举Narsese」的前提下识别「像」
+    ///   * 📄ASCII：`/`
+    /// * ⚠️应当同时存在于[`Self::connecters`]中
+    pub connecter_image_extension: String,
+
+    /// 「内涵像」的连接符
+    /// * 🎯用于在不引入「枚举Narsese」的前提下识别「像」
+    ///   * 📄ASCII：`\`
+    /// * ⚠️应当同时存在于[`Self::connecters`]中
+    pub connecter_image_intension: String,
+
+    /// 「复合词项连接符」的容量
+    /// * 🎯用于在不引入「枚举Narsese」的前提下获取词项容量
+    ///   * 📄ASCII：`--`⇒一元，`-`⇒二元序列，`&&`⇒集合
+    /// * 📌不在表中的连接符⇒容量未知
+    /// * ⚠️应当同时存在于[`Self::connecters`]中
+    pub connecter_capacities: Vec<(String, TermCapacity)>,
 }
 
 /// 陈述格式
@@ -104,6 +195,42 @@ pub struct NarseseFormatStatement {
     /// * 预测性/并发性/回顾性 蕴含
     /// * 预测性/并发性/回顾性 等价
     pub copulas: BiFixMatchDict,
+
+    /// 满足对称性的「中缀系词」
+    /// * 🎯用于「词项规范化」：其主词、谓词可被交换
+    /// * 相似
+    /// * 等价
+    /// * 并发性等价
+    /// * ⚠️应当同时存在于[`Self::copulas`]中
+    pub symmetric_copulas: BiFixMatchDict,
+
+    /// 「继承」系词
+    /// * 🎯用于在不引入「枚举Narsese」的前提下展开语法糖
+    ///   * 📄[`NarseseFormat::desugar`]
+    /// * ⚠️应当同时存在于[`Self::copulas`]中
+    /// * 📄ASCII：`-->`
+    pub copula_inheritance: String,
+
+    /// 「实例」系词
+    /// * 📌`A {-- B` ⇔ `{A} --> B`
+    /// * ⚠️应当同时存在于[`Self::copulas`]中
+    pub copula_instance: String,
+
+    /// 「属性」系词
+    /// * 📌`A --] B` ⇔ `A --> [B]`
+    /// * ⚠️应当同时存在于[`Self::copulas`]中
+    pub copula_property: String,
+
+    /// 「实例属性」系词
+    /// * 📌`A {-] B` ⇔ `{A} --> [B]`
+    /// * ⚠️应当同时存在于[`Self::copulas`]中
+    pub copula_instance_property: String,
+
+    /// 「中缀系词」的容量
+    /// * 📌对称系词⇒二元集合，其余⇒二元序列
+    /// * 📌不在表中的系词⇒容量未知
+    /// * ⚠️应当同时存在于[`Self::copulas`]中
+    pub copula_capacities: Vec<(String, TermCapacity)>,
 }
 
 /// 语句格式（含标点、真值、时间戳）
@@ -112,6 +239,25 @@ pub struct NarseseFormatSentence {
     /// 合法的「标点」
     pub punctuations: SuffixMatchDict,
 
+    /// 「判断」标点
+    /// * 🎯为「标点字符串」提供语义：判断语句的类型
+    ///   * 📄[`Sentence::is_judgement_with`](crate::lexical::Sentence::is_judgement_with)
+    /// * ⚠️应当同时存在于[`Self::punctuations`]中
+    /// * 📄ASCII：`.`
+    pub punctuation_judgement: String,
+
+    /// 「目标」标点
+    /// * 📄ASCII：`!`
+    pub punctuation_goal: String,
+
+    /// 「问题」标点
+    /// * 📄ASCII：`?`
+    pub punctuation_question: String,
+
+    /// 「请求」标点
+    /// * 📄ASCII：`@`
+    pub punctuation_quest: String,
+
     /// 真值括弧
     /// * 🚩通过括弧捕获整个「真值」字符串，然后拆分其内部结构
     pub truth_brackets: (String, String),
@@ -125,6 +271,13 @@ pub struct NarseseFormatSentence {
     ///   * 📄形如`vec!["1.0", "0.9"]`目前是最佳实践
     pub truth_separator: String,
 
+    /// 真值内部的备选分隔符
+    /// * 🎯兼容ONA等使用`,`分隔的输入：`%1.0,0.9%`
+    /// * 📌仅用于解析：格式化时总使用[`Self::truth_separator`]
+    /// * 📌可与[`Self::truth_separator`]混用：`%1.0,0.9%`与`%1.0;0.9%`等价
+    /// * ⚠️须同时被[`Self::is_truth_content`]接受
+    pub truth_separator_alternates: Vec<String>,
+
     /// 判断是否为「真值内部允许的字符」
     /// * 🎯用于提供信息以更快分割边界（从预算值而来）
     pub is_truth_content: fn(char) -> bool,
@@ -149,6 +302,26 @@ pub struct NarseseFormatSentence {
     /// * 📌通过「合法字符序列」兼容「前后缀不固定的『固定』时间戳类型」
     ///   * 📄ASCIIの「固定」：`:!-123:`
     pub is_stamp_content: fn(char) -> bool,
+
+    /// 「过去」时间戳
+    /// * 🎯为「时间戳字符串」提供语义：解析出[`StampKind`](crate::lexical::StampKind)
+    /// * 📌完整的时间戳字符串，须在[`Self::stamp_brackets`]中有「空前缀」对应
+    ///   * 📄ASCII：`:\:`
+    pub stamp_past: String,
+
+    /// 「现在」时间戳
+    /// * 📄ASCII：`:|:`
+    pub stamp_present: String,
+
+    /// 「将来」时间戳
+    /// * 📄ASCII：`:/:`
+    pub stamp_future: String,
+
+    /// 「固定」时间戳的括弧
+    /// * 📌括弧之间为带可选正负号的整数
+    /// * 📄ASCII：`(":!", ":")`
+    /// * 📄LaTeX：`("t=", "")`
+    pub stamp_fixed: (String, String),
 }
 
 /// 任务格式（含预算值）
@@ -163,6 +336,10 @@ pub struct NarseseFormatTask {
     /// * 📄来由、用法等参考[`NarseseFormatSentence::truth_separator`]
     pub budget_separator: String,
 
+    /// 预算值内部的备选分隔符
+    /// * 📄参考[`NarseseFormatSentence::truth_separator_alternates`]
+    pub budget_separator_alternates: Vec<String>,
+
     /// 判断是否为「预算值内部允许的字符」
     /// * 🎯用于解决可能的「预算值🆚独立变量」「误报的预算值范围」的问题
     /// * 📌在「总解析方法」中，以此为凭据分割「预算值」
@@ -217,6 +394,176 @@ pub struct NarseseFormat {
 
     /// 任务格式（含预算值）
     pub task: NarseseFormatTask,
+
+    /// PyNARS兼容模式
+    /// * 🎯兼容PyNARS（`Narsese.py`）所产出的一些文本约定
+    /// * ⚠️默认关闭；开启后才会启用以下语法
+    ///   * 📄真值、时间戳顺序无关：`<A --> B>. %1.0;0.9% :|:`
+    ///   * 📄单引号包裹的原子词项：`'hello world'`（名称保留引号与其中空白）
+    ///   * 📄前缀否定：`--A` ⇒ `(--, A)`
+    /// * 📌仅对ASCII格式有意义：其中的「否定」连接符固定为`--`
+    pub pynars_compat: bool,
+
+    /// 是否在解析时规范化「间隔」字面量
+    /// * 🎯使「词法Narsese」中的间隔名称，与「枚举Narsese」往返后的结果一致
+    ///   * 📄`+0123` ⇒ `+123`；`+000` ⇒ `+0`
+    /// * 🚩开启后，名称为空的间隔（如单独的`+`）将在解析时报错
+    /// * 📌仅处理「纯数字」的间隔名称
+    /// * ⚙️默认开启
+    pub normalize_interval_literals: bool,
+
+    /// 解析时允许的最大嵌套深度
+    /// * 🎯防止深层嵌套的输入耗尽调用栈
+    /// * 🚩超出时返回解析错误「超出最大嵌套深度」
+    /// * ⚙️默认为[`DEFAULT_MAX_DEPTH`](crate::conversion::string::DEFAULT_MAX_DEPTH)
+    pub max_depth: usize,
+
+    /// 注释前缀
+    /// * 🎯直接解析含注释的`.nal`文件
+    /// * 🚩解析前去除「括弧之外」首个注释前缀及其之后的内容
+    ///   * 📄`<A --> B>. // 注释` ⇒ `<A --> B>.`
+    ///   * 📌整行注释⇒「输入为空」错误；批量解析时跳过
+    /// * ⚙️默认为[`DEFAULT_COMMENT_PREFIXES`](crate::conversion::string::DEFAULT_COMMENT_PREFIXES)：`//`、`'`
+    pub comment_prefixes: Vec<String>,
+
+    /// 是否接受未知的「陈述系词」
+    /// * 🎯前向兼容：解析较新推理器输出的、本库未收录的系词
+    ///   * 📄`<A ~~> B>` ⇒ 系词为`~~>`
+    /// * 🚩开启后，主词之后若无已知系词，则取其后的一段「符号字符」作为系词
+    ///   * 📌符号字符：非标识符、非空白，且不是左括弧、分隔符的开头
+    ///   * 📌从短到长尝试，取首个能使「谓词+右括弧」解析成功者
+    /// * ⚙️默认关闭
+    pub accept_unknown_copulas: bool,
+
+    /// 是否接受未知的「复合词项连接符」
+    /// * 🎯前向兼容：同[`Self::accept_unknown_copulas`]
+    ///   * 📄`(@@, A, B)` ⇒ 连接符为`@@`
+    /// * 🚩开启后，左括弧之后若无已知连接符，则取其后最长的一段「符号字符」作为连接符
+    ///   * 📌符号字符：同上，且不是复合词项右括弧的开头
+    /// * ⚙️默认关闭
+    pub accept_unknown_connecters: bool,
+
+    /// 「查询变量」与「问题标点」的消歧策略
+    /// * 🎯`?x?`、`?x`：开头的`?`是查询变量前缀，还是问题标点
+    /// * 📌仅当问题标点亦为变量前缀时生效
+    /// * 📄参见[`AmbiguityResolution`]
+    /// * ⚙️默认「偏向变量」：即此前的解析行为
+    pub ambiguity_resolution: AmbiguityResolution,
     // ! 相比「枚举Narsese」不再有「关键词截断选项」
     // ! 🚩【2024-03-15 17:48:03】目前`enable_keyword_truncation`强制为`true`
 }
+
+/// 实现/语义信息
+impl NarseseFormat {
+    /// 判断「复合词项连接符」是否满足交换律
+    pub fn is_commutative_connecter(&self, connecter: &str) -> bool {
+        self.compound
+            .commutative_connecters
+            .has(&connecter.to_string())
+    }
+
+    /// 判断「陈述系词」是否满足对称性
+    pub fn is_symmetric_copula(&self, copula: &str) -> bool {
+        self.statement.symmetric_copulas.has(&copula.to_string())
+    }
+
+    /// 判断「复合词项连接符」是否为「像」的连接符
+    /// * 📄ASCII：`/`、`\`
+    pub fn is_image_connecter(&self, connecter: &str) -> bool {
+        connecter == self.compound.connecter_image_extension
+            || connecter == self.compound.connecter_image_intension
+    }
+
+    /// 判断词项是否为「占位符」
+    /// * 📌前缀为占位符前缀的原子词项
+    pub fn is_placeholder(&self, term: &Term) -> bool {
+        matches!(term, Term::Atom { prefix, .. } if *prefix == self.atom.prefix_placeholder)
+    }
+
+    /// 判断左右括弧是否为「词项集括弧对」
+    pub fn is_set_brackets(&self, left: &str, right: &str) -> bool {
+        self.compound
+            .set_brackets
+            .prefix_terms()
+            .any(|(l, r)| l == left && r == right)
+    }
+
+    /// 获取「复合词项连接符」的容量
+    /// * 🚩未知连接符⇒[`None`]
+    pub fn connecter_capacity(&self, connecter: &str) -> Option<TermCapacity> {
+        lookup_capacity(&self.compound.connecter_capacities, connecter)
+    }
+
+    /// 获取「陈述系词」的容量
+    /// * 🚩未知系词⇒[`None`]
+    pub fn copula_capacity(&self, copula: &str) -> Option<TermCapacity> {
+        lookup_capacity(&self.statement.copula_capacities, copula)
+    }
+}
+
+/// 实现/词汇表
+impl NarseseFormat {
+    /// 获取格式的词汇表
+    /// * 🎯编辑器工具：语法高亮、自动补全
+    /// * 📌词项集括弧：与外延集、内涵集括弧相同者归入对应角色，其余归入「其它词项集」
+    pub fn vocabulary(&self) -> FormatVocabulary {
+        let compound = &self.compound;
+        let sentence = &self.sentence;
+        let set_role = |left: &str, right: &str| {
+            let is = |(l, r): &(String, String)| l == left && r == right;
+            match () {
+                _ if is(&compound.set_extension_brackets) => BracketRole::ExtensionSet,
+                _ if is(&compound.set_intension_brackets) => BracketRole::IntensionSet,
+                _ => BracketRole::Set,
+            }
+        };
+        fn pair((left, right): &(String, String), role: BracketRole) -> (&str, &str, BracketRole) {
+            (left, right, role)
+        }
+        FormatVocabulary {
+            copulas: collect_tokens(self.statement.copulas.iter_x_fixes().map(String::as_str)),
+            connecters: collect_tokens(compound.connecters.iter_x_fixes().map(String::as_str)),
+            atom_prefixes: collect_tokens(self.atom.prefixes.iter_x_fixes().map(String::as_str)),
+            punctuations: collect_tokens(sentence.punctuations.iter_x_fixes().map(String::as_str)),
+            bracket_pairs: collect_bracket_pairs(
+                [
+                    pair(&compound.brackets, BracketRole::Compound),
+                    pair(&self.statement.brackets, BracketRole::Statement),
+                ]
+                .into_iter()
+                .chain(
+                    compound
+                        .set_brackets
+                        .prefix_terms()
+                        .map(|(left, right)| (left.as_str(), right.as_str(), set_role(left, right))),
+                ),
+            ),
+            stamp_markers: collect_tokens([
+                sentence.stamp_past.as_str(),
+                sentence.stamp_present.as_str(),
+                sentence.stamp_future.as_str(),
+                sentence.stamp_fixed.0.as_str(),
+                sentence.stamp_fixed.1.as_str(),
+            ]),
+            truth_budget_brackets: collect_bracket_pairs([
+                pair(&sentence.truth_brackets, BracketRole::Truth),
+                pair(&self.task.budget_brackets, BracketRole::Budget),
+            ]),
+        }
+    }
+
+    /// 判断记号在此格式中的角色
+    /// * 📄参见[`FormatVocabulary::classify`]
+    /// * ⚠️每次调用均重新生成词汇表：批量判断时宜先调用[`Self::vocabulary`]
+    pub fn classify_token(&self, token: &str) -> Option<TokenRole> {
+        self.vocabulary().classify(token)
+    }
+}
+
+/// 工具函数：在容量表中查找符号
+fn lookup_capacity(table: &[(String, TermCapacity)], symbol: &str) -> Option<TermCapacity> {
+    table
+        .iter()
+        .find(|(key, _)| key == symbol)
+        .map(|(_, capacity)| *capacity)
+}
@@ -8,6 +8,10 @@
 //!   * 🎯加快开发，牺牲一定性能，规避一系列的生命周期标注与复杂的生命周期问题
 
 use super::format::*;
+use crate::{
+    api::TermCapacity,
+    conversion::string::{AmbiguityResolution, DEFAULT_COMMENT_PREFIXES, DEFAULT_MAX_DEPTH},
+};
 use lazy_static::lazy_static;
 use nar_dev_utils::{
     bi_fix_match_dict_pair, suffix_match_dict_pair, x_fix_match_dict, PrefixMatchDict,
@@ -24,6 +28,13 @@ macro_rules! s {
     };
 }
 
+/// 工具宏：构造「符号⇒容量」表
+macro_rules! capacities {
+    ( $( $symbol:literal => $capacity:ident )* ) => {
+        vec![$( ($symbol.to_string(), TermCapacity::$capacity) ),*]
+    };
+}
+
 // * 📝有关「全局常量」定义，闭包↔死局？ * //
 // 这里不可以：`Box::new`并非常量函数
 // pub const CLJ: Box<dyn Fn(char)> = Box::new(|_c: char| {});
@@ -100,6 +111,13 @@ lazy_static! {
     /// * 📄使用[`lazy_static`]实现「静态常量」
     ///   * 详请参考[`create_format_ascii`]
     pub static ref FORMAT_HAN: NarseseFormat = create_format_han();
+
+    /// PyNARS方言
+    /// * 📌基于ASCII格式，开启PyNARS兼容模式
+    ///
+    /// * 📄使用[`lazy_static`]实现「静态常量」
+    ///   * 详请参考[`create_format_pynars`]
+    pub static ref FORMAT_PYNARS: NarseseFormat = create_format_pynars();
 }
 
 /// 简单判断是否为原子词项（标识符）
@@ -114,6 +132,25 @@ fn is_identifier(c: char) -> bool {
     c.is_alphanumeric() || c == '_' || c == '-' || c > '\u{1f2ff}' // 常见emoji兼容
 }
 
+/// 判断是否为「浮点数内部允许的字符」
+/// * 🎯用于真值、预算值的内容判断
+/// * 📌数字、小数点、正负号、科学计数法的指数标记
+///   * 📄`1e-2` `+0.5` `9E-1`
+/// * ⚠️只判断字符，数值本身交由后续检验
+const fn is_float_content(c: char) -> bool {
+    matches!(c, '0'..='9' | '.' | '+' | '-' | 'e' | 'E')
+}
+
+/// 判断是否为「时间戳内部允许的字符」
+/// * 🎯用于「固定」时间戳的内容判断
+/// * 📌数字、正负号：固定时间可为负
+///   * 📄`:!-1:` `t=-1` `发生在-1`
+/// * 📌三种格式共用，以保证带符号的固定时间在各格式中均能往返
+/// * ⚠️只判断字符，数值本身交由后续检验
+const fn is_stamp_content(c: char) -> bool {
+    matches!(c, '0'..='9' | '+' | '-') // regex:`[0-9+\-]`
+}
+
 /// 通用 ASCII格式
 /// * 来源：文档 `NARS ASCII Input.pdf`
 /// * 另可参考：<https://github.com/opennars/opennars/wiki/Narsese-Grammar-(Input-Output-Format)>
@@ -128,14 +165,11 @@ fn is_identifier(c: char) -> bool {
 ///   * ✅成功通过「函数指针类型」去除了其中的Box堆分配，
 ///   * ⚠️但涉及「前后缀匹配字典」的堆分配，仍然需要使用[`lazy_static`]
 pub fn create_format_ascii() -> NarseseFormat {
-    const fn is_stamp_content(c: char) -> bool {
-        matches!(c, '0'..='9' | '+' | '-') // regex:`[0-9+\-]`
-    }
     const fn is_truth_content(c: char) -> bool {
-        matches!(c, '0'..='9' | '.' | ';')
+        is_float_content(c) || c == ';' || c == ','
     }
     const fn is_budget_content(c: char) -> bool {
-        matches!(c, '0'..='9' | '.' | ';')
+        is_float_content(c) || c == ';' || c == ','
     }
     NarseseFormat {
         space: NarseseFormatSpace {
@@ -160,6 +194,15 @@ pub fn create_format_ascii() -> NarseseFormat {
             ),
             // 一般文字、数字、连带`-`均算入在内
             is_identifier,
+            // 间隔前缀
+            prefix_interval: s!("+"),
+            // 词语、变量前缀
+            prefix_word: s!(""),
+            prefix_variables: vec![s!("$"), s!("#"), s!("?")],
+            // 占位符前缀
+            prefix_placeholder: s!("_"),
+            // 不启用引号
+            quotes: None,
         },
         compound: NarseseFormatCompound {
             // 外延集/内涵集
@@ -167,6 +210,9 @@ pub fn create_format_ascii() -> NarseseFormat {
                 "{" => "}" // 外延集
                 "[" => "]" // 内涵集
             ),
+            // 外延集/内涵集的括弧对
+            set_extension_brackets: s!("{", "}"),
+            set_intension_brackets: s!("[", "]"),
             // 普通括号
             brackets: s!("(", ")"),
             // 普通分隔符
@@ -186,6 +232,34 @@ pub fn create_format_ascii() -> NarseseFormat {
                 "&/" // 顺序合取
                 "&|" // 平行合取
             ),
+            // 满足交换律的连接符
+            commutative_connecters: x_fix_match_dict!(
+                "&"  // 外延交
+                "|"  // 内涵交
+                "&&" // 合取
+                "||" // 析取
+                "&|" // 平行合取
+            ),
+            // 顺序合取连接符
+            connecter_conjunction_sequential: s!("&/"),
+            // 像连接符
+            connecter_image_extension: s!("/"),
+            connecter_image_intension: s!(r"\"),
+            // 连接符容量
+            connecter_capacities: capacities!(
+                "&" => Set // 外延交
+                "|" => Set // 内涵交
+                "-" => BinaryVec // 外延差
+                "~" => BinaryVec // 内涵差
+                "*" => Vec // 乘积
+                "/" => Vec // 外延像
+                r"\" => Vec // 内涵像
+                "&&" => Set // 合取
+                "||" => Set // 析取
+                "--" => Unary // 否定
+                "&/" => Vec // 顺序合取
+                "&|" => Set // 平行合取
+            ),
         },
         statement: NarseseFormatStatement {
             // 陈述括弧
@@ -206,6 +280,33 @@ pub fn create_format_ascii() -> NarseseFormat {
                 r"<|>" // 并发性等价
                 r"<\>" // 回顾性等价
             ),
+            // 对称系词
+            symmetric_copulas: x_fix_match_dict!(
+                "<->" // 相似
+                "<=>" // 等价
+                r"<|>" // 并发性等价
+            ),
+            // 继承、实例、属性、实例属性系词
+            copula_inheritance: s!("-->"),
+            copula_instance: s!("{--"),
+            copula_property: s!("--]"),
+            copula_instance_property: s!("{-]"),
+            // 系词容量
+            copula_capacities: capacities!(
+                "-->" => BinaryVec // 继承
+                "<->" => BinarySet // 相似
+                "==>" => BinaryVec // 蕴含
+                "<=>" => BinarySet // 等价
+                "{--" => BinaryVec // 实例
+                "--]" => BinaryVec // 属性
+                "{-]" => BinaryVec // 实例属性
+                "=/>" => BinaryVec // 预测性蕴含
+                "=|>" => BinaryVec // 并发性蕴含
+                r"=\>" => BinaryVec // 回顾性蕴含
+                "</>" => BinaryVec // 预测性等价
+                "<|>" => BinarySet // 并发性等价
+                r"<\>" => BinaryVec // 回顾性等价
+            ),
         },
         sentence: NarseseFormatSentence {
             // 所有标点
@@ -215,6 +316,10 @@ pub fn create_format_ascii() -> NarseseFormat {
                 "?" // 问题
                 "@" // 请求
             ),
+            punctuation_judgement: s!("."),
+            punctuation_goal: s!("!"),
+            punctuation_question: s!("?"),
+            punctuation_quest: s!("@"),
             // 时间戳
             stamp_brackets: suffix_match_dict_pair!(
                 // * 🚩空前缀匹配
@@ -225,9 +330,15 @@ pub fn create_format_ascii() -> NarseseFormat {
                 ":!" => r":" // 固定
             ),
             is_stamp_content,
+            stamp_past: s!(r":\:"),
+            stamp_present: s!(r":|:"),
+            stamp_future: s!(r":/:"),
+            stamp_fixed: s!(":!", ":"),
             // 真值 | 内容已不包含空格
             truth_brackets: s!("%", "%"),
             truth_separator: s!(";"),
+            // 备选分隔符 | 兼容ONA的`%1.0,0.9%`
+            truth_separator_alternates: vec![s!(",")],
             // ! 【2024-03-22 20:23:39】↓虽说此时使用分隔符，但在「截取」阶段仍然需要将分隔符作为「内容」
             is_truth_content,
         },
@@ -235,8 +346,22 @@ pub fn create_format_ascii() -> NarseseFormat {
             // 预算 | 内容已不包含空格
             budget_brackets: s!("$", "$"),
             budget_separator: s!(";"),
+            budget_separator_alternates: vec![s!(",")],
             is_budget_content,
         },
+        // PyNARS兼容模式 | 默认关闭
+        pynars_compat: false,
+        // 规范化间隔字面量 | 默认开启
+        normalize_interval_literals: true,
+        // 最大嵌套深度
+        max_depth: DEFAULT_MAX_DEPTH,
+        // 注释前缀
+        comment_prefixes: DEFAULT_COMMENT_PREFIXES.map(String::from).to_vec(),
+        // 未知系词、连接符 | 默认不接受
+        accept_unknown_copulas: false,
+        accept_unknown_connecters: false,
+        // 查询变量与问题标点的消歧 | 默认偏向变量
+        ambiguity_resolution: AmbiguityResolution::PreferVariable,
     }
 }
 
@@ -249,14 +374,11 @@ pub fn create_format_ascii() -> NarseseFormat {
 ///   * ℹ️LaTeX扩展本身不会有多少「需要由此转换成Narsese」的场景
 /// * 🆕更新@2024-04-05：时序系词与时态由「前缀竖杠」变为「中缀竖杠」
 pub fn create_format_latex() -> NarseseFormat {
-    const fn is_stamp_content(c: char) -> bool {
-        matches!(c, '0'..='9' | '+' | '-') // regex:`[0-9+\-]`
-    }
     const fn is_truth_content(c: char) -> bool {
-        matches!(c, '0'..='9' | '.' | ',') // ! LaTeX使用逗号而非分号
+        is_float_content(c) || c == ',' // ! LaTeX使用逗号而非分号
     }
     const fn is_budget_content(c: char) -> bool {
-        matches!(c, '0'..='9' | '.' | ';')
+        is_float_content(c) || c == ';'
     }
     NarseseFormat {
         space: NarseseFormatSpace {
@@ -279,6 +401,15 @@ pub fn create_format_latex() -> NarseseFormat {
                 r"\Uparrow{}" // ! 此处即「后缀空参数」
             ),
             is_identifier,
+            // 间隔前缀
+            prefix_interval: s!("+"),
+            // 词语、变量前缀
+            prefix_word: s!(""),
+            prefix_variables: vec![s!(r"\$"), s!(r"\#"), s!("?")],
+            // 占位符前缀
+            prefix_placeholder: s!(r"\diamond{}"),
+            // 不启用引号
+            quotes: None,
         },
         compound: NarseseFormatCompound {
             // 左右括弧
@@ -292,6 +423,9 @@ pub fn create_format_latex() -> NarseseFormat {
                 r"\left\{" => r"\right\}" // 外延集
                 r"\left[" => r"\right]" // 内涵集
             ),
+            // 外延集/内涵集的括弧对
+            set_extension_brackets: s!(r"\left\{", r"\right\}"),
+            set_intension_brackets: s!(r"\left[", r"\right]"),
             // 复合词项连接符
             connecters: x_fix_match_dict!(
                 r"\cap{}" // 外延交
@@ -307,6 +441,34 @@ pub fn create_format_latex() -> NarseseFormat {
                 "," // 顺序合取
                 ";" // 平行合取
             ),
+            // 满足交换律的连接符
+            commutative_connecters: x_fix_match_dict!(
+                r"\cap{}" // 外延交
+                r"\cup{}" // 内涵交
+                r"\wedge{}" // 合取
+                r"\vee{}" // 析取
+                ";" // 平行合取
+            ),
+            // 顺序合取连接符
+            connecter_conjunction_sequential: s!(","),
+            // 像连接符
+            connecter_image_extension: s!("/"),
+            connecter_image_intension: s!(r"\backslash{}"),
+            // 连接符容量
+            connecter_capacities: capacities!(
+                r"\cap{}" => Set // 外延交
+                r"\cup{}" => Set // 内涵交
+                r"\minus{}" => BinaryVec // 外延差
+                r"\sim{}" => BinaryVec // 内涵差
+                r"\times{}" => Vec // 乘积
+                "/" => Vec // 外延像
+                r"\backslash{}" => Vec // 内涵像
+                r"\wedge{}" => Set // 合取
+                r"\vee{}" => Set // 析取
+                r"\neg{}" => Unary // 否定
+                "," => Vec // 顺序合取
+                ";" => Set // 平行合取
+            ),
         },
         statement: NarseseFormatStatement {
             brackets: s!(r"\left<", r"\right>"),
@@ -325,6 +487,33 @@ pub fn create_format_latex() -> NarseseFormat {
                 r"|\!\!\!\Leftrightarrow{}" // 并发性等价
                 r"\backslash\!\!\!\Leftrightarrow{}" // 回顾性等价
             ),
+            // 对称系词
+            symmetric_copulas: x_fix_match_dict!(
+                r"\leftrightarrow{}" // 相似
+                r"\Leftrightarrow{}" // 等价
+                r"|\!\!\!\Leftrightarrow{}" // 并发性等价
+            ),
+            // 继承、实例、属性、实例属性系词
+            copula_inheritance: s!(r"\rightarrow{}"),
+            copula_instance: s!(r"\circ\!\!\!\rightarrow{}"),
+            copula_property: s!(r"\rightarrow\!\!\!\circ{}"),
+            copula_instance_property: s!(r"\circ\!\!\!\rightarrow\!\!\!\circ{}"),
+            // 系词容量
+            copula_capacities: capacities!(
+                r"\rightarrow{}" => BinaryVec // 继承
+                r"\leftrightarrow{}" => BinarySet // 相似
+                r"\Rightarrow{}" => BinaryVec // 蕴含
+                r"\Leftrightarrow{}" => BinarySet // 等价
+                r"\circ\!\!\!\rightarrow{}" => BinaryVec // 实例
+                r"\rightarrow\!\!\!\circ{}" => BinaryVec // 属性
+                r"\circ\!\!\!\rightarrow\!\!\!\circ{}" => BinaryVec // 实例属性
+                r"/\!\!\!\!\!\Rightarrow{}" => BinaryVec // 预测性蕴含
+                r"|\!\!\!\!\!\Rightarrow{}" => BinaryVec // 并发性蕴含
+                r"\backslash\!\!\!\!\!\Rightarrow{}" => BinaryVec // 回顾性蕴含
+                r"/\!\!\!\Leftrightarrow{}" => BinaryVec // 预测性等价
+                r"|\!\!\!\Leftrightarrow{}" => BinarySet // 并发性等价
+                r"\backslash\!\!\!\Leftrightarrow{}" => BinaryVec // 回顾性等价
+            ),
         },
         sentence: NarseseFormatSentence {
             // 标点
@@ -335,6 +524,10 @@ pub fn create_format_latex() -> NarseseFormat {
                 "¿" // 请求
                 // ! 💭【20230806 23:46:18】倒问号没有对应的LaTeX。。。
             ),
+            punctuation_judgement: s!("."),
+            punctuation_goal: s!("!"),
+            punctuation_question: s!("?"),
+            punctuation_quest: s!("¿"),
             // 时间戳
             stamp_brackets: suffix_match_dict_pair!(
                 // * 🚩空前缀匹配
@@ -345,38 +538,60 @@ pub fn create_format_latex() -> NarseseFormat {
                 "t=" => "", // ? LaTeX语法未知
             ),
             is_stamp_content,
+            stamp_past: s!(r"\backslash\!\!\!\!\!\Rightarrow{}"),
+            stamp_present: s!(r"|\!\!\!\!\!\Rightarrow{}"),
+            stamp_future: s!(r"/\!\!\!\!\!\Rightarrow{}"),
+            stamp_fixed: s!("t=", ""),
             // 真值
             truth_brackets: s!(r"\langle{}", r"\rangle{}"),
             truth_separator: s!(","), // ! LaTeX格式使用`,`作为真值分隔符
+            truth_separator_alternates: vec![],
             is_truth_content,
         },
         task: NarseseFormatTask {
             // 预算
             budget_brackets: s!(r"\$", r"\$"),
             budget_separator: s!(";"),
+            budget_separator_alternates: vec![],
             is_budget_content,
         },
+        // PyNARS兼容模式 | 默认关闭
+        pynars_compat: false,
+        // 规范化间隔字面量 | 默认开启
+        normalize_interval_literals: true,
+        // 最大嵌套深度
+        max_depth: DEFAULT_MAX_DEPTH,
+        // 注释前缀
+        comment_prefixes: DEFAULT_COMMENT_PREFIXES.map(String::from).to_vec(),
+        // 未知系词、连接符 | 默认不接受
+        accept_unknown_copulas: false,
+        accept_unknown_connecters: false,
+        // 查询变量与问题标点的消歧 | 默认偏向变量
+        ambiguity_resolution: AmbiguityResolution::PreferVariable,
     }
 }
 
 /// 漢文扩展
 /// * 📌原创
+/// * 📝时序系词：以「将/现/曾」标示时间方向
+///   * 📄蕴含：「将得」「现得」「曾得」；等价：「将同」「现同」「曾同」
+/// * 📝像：「外像」「内像」，占位符为「某」
+/// * 📝原子词项前缀：间隔「间隔」、操作符「操作」
+/// * ⚠️汉字亦可作为词语名：与关键字重名的词语可能无法被解析
+///   * 📄词语「同时」会被当作「平行合取」的连接符
 pub fn create_format_han() -> NarseseFormat {
-    const fn is_stamp_content(c: char) -> bool {
-        matches!(c, '0'..='9' | '+' | '-') // regex:`[0-9+\-]`
-    }
     const fn is_truth_content(c: char) -> bool {
-        matches!(c, '0'..='9' | '.' | '、') // 此处有特别的分隔符「、」
+        is_float_content(c) || c == '、' // 此处有特别的分隔符「、」
     }
     const fn is_budget_content(c: char) -> bool {
-        matches!(c, '0'..='9' | '.' | '、') // 此处有特别的分隔符「、」
+        is_float_content(c) || c == '、' // 此处有特别的分隔符「、」
     }
     NarseseFormat {
         space: NarseseFormatSpace {
-            is_for_parse: char::is_whitespace, // ! 解析时忽略空格
+            is_for_parse: char::is_whitespace, // ! 解析时忽略空格 | 含全角空格`U+3000`、不换行空格`U+00A0`、制表符
             format_terms: s!(""),              // 格式化时，词项间无需分隔（避免太过松散）
             format_items: s!(" "),             // 格式化时，条目间需要分隔（避免太过密集）
-            // ! ❌【2024-03-22 23:25:40】暂时不能支持全角空格：枚举Narsese处只能有一种空格
+            // * ✅全角空格等亦可：枚举Narsese处已有对应的`is_for_parse`
             remove_spaces_before_parse: true, // 漢文亦空格无关
         },
         atom: NarseseFormatAtom {
@@ -393,6 +608,15 @@ pub fn create_format_han() -> NarseseFormat {
                 "操作"
             ),
             is_identifier,
+            // 间隔前缀
+            prefix_interval: s!("间隔"),
+            // 词语、变量前缀
+            prefix_word: s!(""),
+            prefix_variables: vec![s!("任一"), s!("其一"), s!("所问")],
+            // 占位符前缀
+            prefix_placeholder: s!("某"),
+            // 不启用引号
+            quotes: None,
         },
         compound: NarseseFormatCompound {
             brackets: s!("（", "）"),
@@ -401,6 +625,9 @@ pub fn create_format_han() -> NarseseFormat {
                 "『" => "』" // 外延集
                 "【" => "】" // 内涵集
             ),
+            // 外延集/内涵集的括弧对
+            set_extension_brackets: s!("『", "』"),
+            set_intension_brackets: s!("【", "】"),
             // 复合词项连接符
             connecters: x_fix_match_dict!(
                 "外交" // 外延交
@@ -416,6 +643,34 @@ pub fn create_format_han() -> NarseseFormat {
                 "接连" // 顺序合取
                 "同时" // 平行合取
             ),
+            // 满足交换律的连接符
+            commutative_connecters: x_fix_match_dict!(
+                "外交" // 外延交
+                "内交" // 内涵交
+                "与" // 合取
+                "或" // 析取
+                "同时" // 平行合取
+            ),
+            // 顺序合取连接符
+            connecter_conjunction_sequential: s!("接连"),
+            // 像连接符
+            connecter_image_extension: s!("外像"),
+            connecter_image_intension: s!("内像"),
+            // 连接符容量
+            connecter_capacities: capacities!(
+                "外交" => Set // 外延交
+                "内交" => Set // 内涵交
+                "外差" => BinaryVec // 外延差
+                "内差" => BinaryVec // 内涵差
+                "积" => Vec // 乘积
+                "外像" => Vec // 外延像
+                "内像" => Vec // 内涵像
+                "与" => Set // 合取
+                "或" => Set // 析取
+                "非" => Unary // 否定
+                "接连" => Vec // 顺序合取
+                "同时" => Set // 平行合取
+            ),
         },
         statement: NarseseFormatStatement {
             brackets: s!("「", "」"),
@@ -434,6 +689,33 @@ pub fn create_format_han() -> NarseseFormat {
                 "现同" // 并发性等价
                 "曾同" // 回顾性等价
             ),
+            // 对称系词
+            symmetric_copulas: x_fix_match_dict!(
+                "似" // 相似
+                "同" // 等价
+                "现同" // 并发性等价
+            ),
+            // 继承、实例、属性、实例属性系词
+            copula_inheritance: s!("是"),
+            copula_instance: s!("为"),
+            copula_property: s!("有"),
+            copula_instance_property: s!("具有"),
+            // 系词容量
+            copula_capacities: capacities!(
+                "是" => BinaryVec // 继承
+                "似" => BinarySet // 相似
+                "得" => BinaryVec // 蕴含
+                "同" => BinarySet // 等价
+                "为" => BinaryVec // 实例
+                "有" => BinaryVec // 属性
+                "具有" => BinaryVec // 实例属性
+                "将得" => BinaryVec // 预测性蕴含
+                "现得" => BinaryVec // 并发性蕴含
+                "曾得" => BinaryVec // 回顾性蕴含
+                "将同" => BinaryVec // 预测性等价
+                "现同" => BinarySet // 并发性等价
+                "曾同" => BinaryVec // 回顾性等价
+            ),
         },
         sentence: NarseseFormatSentence {
             // 标点
@@ -444,6 +726,10 @@ pub fn create_format_han() -> NarseseFormat {
                 "；" // 请求
                 // ! 暂且没有更合适、更方便输入的全角标点
             ),
+            punctuation_judgement: s!("。"),
+            punctuation_goal: s!("！"),
+            punctuation_question: s!("？"),
+            punctuation_quest: s!("；"),
             // 时间戳
             stamp_brackets: suffix_match_dict_pair!(
                 // * 🚩空前缀匹配
@@ -454,17 +740,52 @@ pub fn create_format_han() -> NarseseFormat {
                 "发生在" => "",
             ),
             is_stamp_content,
+            stamp_past: s!("过去"),
+            stamp_present: s!("现在"),
+            stamp_future: s!("将来"),
+            stamp_fixed: s!("发生在", ""),
             // 真值
             truth_brackets: s!("真", "值"), // 大改：兼容单真值、空真值
             truth_separator: s!("、"),
+            truth_separator_alternates: vec![],
             is_truth_content,
         },
         task: NarseseFormatTask {
             // 预算
             budget_brackets: s!("预", "算"),
             budget_separator: s!("、"),
+            budget_separator_alternates: vec![],
             is_budget_content,
         },
+        // PyNARS兼容模式 | 默认关闭
+        pynars_compat: false,
+        // 规范化间隔字面量 | 默认开启
+        normalize_interval_literals: true,
+        // 最大嵌套深度
+        max_depth: DEFAULT_MAX_DEPTH,
+        // 注释前缀
+        comment_prefixes: DEFAULT_COMMENT_PREFIXES.map(String::from).to_vec(),
+        // 未知系词、连接符 | 默认不接受
+        accept_unknown_copulas: false,
+        accept_unknown_connecters: false,
+        // 查询变量与问题标点的消歧 | 默认偏向变量
+        ambiguity_resolution: AmbiguityResolution::PreferVariable,
+    }
+}
+
+/// PyNARS方言
+/// * 🔗参考：<https://github.com/bowen-xu/PyNARS>
+/// * 🚩基于ASCII格式：前缀、连接符、系词、标点、真值、预算值均与之一致
+/// * 🚩开启[PyNARS兼容模式](NarseseFormat::pynars_compat)
+///   * 📄真值、时间戳顺序无关
+///   * 📄单引号包裹的原子词项
+///   * 📄前缀否定：`--A`
+/// * 📌控制台输出行需先经[`strip_pynars_output_prefix`](crate::conversion::string::strip_pynars_output_prefix)去除前缀
+/// * ⚠️「枚举Narsese」无此格式：其解析器不支持上述兼容语法，而其余部分与ASCII格式无异
+pub fn create_format_pynars() -> NarseseFormat {
+    NarseseFormat {
+        pynars_compat: true,
+        ..create_format_ascii()
     }
 }
 
@@ -494,5 +815,6 @@ mod tests_enum_narsese {
         test_format("ASCII", &FORMAT_ASCII);
         test_format("LaTeX", &FORMAT_LATEX);
         test_format("漢文", &FORMAT_HAN);
+        test_format("PyNARS", &FORMAT_PYNARS);
     }
 }
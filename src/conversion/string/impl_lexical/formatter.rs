@@ -1,102 +1,244 @@
 //! 实现/格式化器
 
-use super::NarseseFormat;
+use super::{format::ATOM_QUOTE_ESCAPE, NarseseFormat};
 use crate::{
     api::{FormatTo, GetBudget, GetTerm},
-    conversion::string::common_narsese_templates::*,
+    conversion::string::{common_narsese_templates::*, FormatOptions},
     lexical::{Budget, Narsese, Sentence, Task, Term, Truth},
 };
-use nar_dev_utils::{add_space_if_necessary_and_flush_buffer, catch_flow, join_to};
+use std::fmt::{self, Write};
 
 /// 实现：转换
+/// * 🚩内部统一写入[`Write`]：返回[`String`]的方法只是薄封装
+///   * 🎯批量格式化时，可用`format_xxx_to`直接写入调用方的缓冲区
 ///
 /// ! ℹ️单元测试在[`super::formats`]模块中定义
 impl NarseseFormat {
     /// 工具函数/词项
-    fn _format_term(&self, out: &mut String, term: &Term) {
+    fn _format_term(
+        &self,
+        out: &mut impl Write,
+        term: &Term,
+        options: &FormatOptions,
+    ) -> fmt::Result {
         match term {
             // 原子词项
-            Term::Atom { prefix, name } => template_atom(out, prefix, name),
-            // 复合词项（包括「像」）
-            Term::Compound { connecter, terms } => template_compound(
-                out,
-                &self.compound.brackets.0,
-                connecter,
-                terms.iter().map(|term| self.format_term(term)),
-                &self.compound.separator,
-                &self.space.format_terms,
-                &self.compound.brackets.1,
-            ),
+            Term::Atom { prefix, name } => {
+                out.write_str(prefix)?;
+                self._format_atom_name(out, name)
+            }
+            // 复合词项（包括「像」） | 连接符与组分之间亦有分隔符
+            Term::Compound { connecter, terms } => {
+                out.write_str(&self.compound.brackets.0)?;
+                self._format_components(out, Some(connecter), terms, options)?;
+                out.write_str(&self.compound.brackets.1)
+            }
             // 复合词项集合
             Term::Set {
                 left_bracket,
                 terms,
                 right_bracket,
-            } => template_compound_set(
-                out,
-                left_bracket,
-                terms.iter().map(|term| self.format_term(term)),
-                &self.compound.separator,
-                &self.space.format_terms,
-                right_bracket,
-            ),
+            } => {
+                // * 📌不检查括弧是否成对：允许「用一种格式的值套另一种格式」
+                //   * 📄带检查的格式化：[`Self::format_term_checked`]
+                out.write_str(left_bracket)?;
+                self._format_components(out, None, terms, options)?;
+                out.write_str(right_bracket)
+            }
             // 陈述
             Term::Statement {
                 copula,
                 subject,
                 predicate,
-            } => template_statement(
+            } => template_statement_to(
                 out,
                 &self.statement.brackets.0,
-                &self.format_term(subject),
+                |out| self._format_term(out, subject, options),
                 copula,
-                &self.format_term(predicate),
-                &self.space.format_terms,
+                |out| self._format_term(out, predicate, options),
+                options.copula_space(&self.space.format_terms),
                 &self.statement.brackets.1,
             ),
         }
     }
 
+    /// 工具函数/组分序列
+    /// * 🚩与[`template_components`]一致：在相邻条目之间插入分隔符与空白
+    ///   * 📌复合词项的连接符作为首个条目
+    /// * ✨[`FormatOptions::omit_separators`]：仅在[必要时](Self::needs_separator)插入分隔符
+    ///   * ⚠️需比对相邻两侧的字符：此时逐个格式化到临时字符串
+    fn _format_components(
+        &self,
+        out: &mut impl Write,
+        connecter: Option<&str>,
+        terms: &[Term],
+        options: &FormatOptions,
+    ) -> fmt::Result {
+        let separator = &self.compound.separator;
+        let space = options.separator_space(&self.space.format_terms);
+        if options.omit_separators {
+            let items = connecter.map(str::to_owned).into_iter().chain(
+                terms
+                    .iter()
+                    .map(|term| format_to_string(|out| self._format_term(out, term, options))),
+            );
+            let mut last = None;
+            for (i, item) in items.enumerate() {
+                if i != 0 {
+                    if self.needs_separator(last, item.chars().next()) {
+                        out.write_str(separator)?;
+                    }
+                    out.write_str(space)?;
+                }
+                out.write_str(&item)?;
+                last = item.chars().last().or(last);
+            }
+            return Ok(());
+        }
+        if let Some(connecter) = connecter {
+            out.write_str(connecter)?;
+            if !terms.is_empty() {
+                out.write_str(separator)?;
+                out.write_str(space)?;
+            }
+        }
+        template_components_to(out, terms.iter(), separator, space, |out, term| {
+            self._format_term(out, term, options)
+        })
+    }
+
+    /// 工具函数/相邻两者之间是否必须有分隔符
+    /// * 📌解析时空白会被去除：两侧同为「标识符字符」⇒会被连成一个原子词项
+    /// * 🚩否则（括弧、前缀、引号等）边界本身即可区分
+    fn needs_separator(&self, left: Option<char>, right: Option<char>) -> bool {
+        matches!(
+            (left, right),
+            (Some(left), Some(right)) if (self.atom.is_identifier)(left) && (self.atom.is_identifier)(right)
+        )
+    }
+
+    /// 工具函数/原子词项名称
+    /// * 🎯名称含「非标识符字符」（空白、分隔符、括弧等）时，加引号以保证能被解析回来
+    /// * 🚩在转义符、右引号之前插入转义符
+    /// * 📌未启用引号、或无需加引号⇒原样写入
+    fn _format_atom_name(&self, out: &mut impl Write, name: &str) -> fmt::Result {
+        let quotes = self
+            .atom
+            .quotes
+            .as_ref()
+            .filter(|(left, right)| !left.is_empty() && !right.is_empty());
+        let (left, right) = match quotes {
+            Some(quotes) if !name.chars().all(self.atom.is_identifier) => quotes,
+            _ => return out.write_str(name),
+        };
+        out.write_str(left)?;
+        for (i, c) in name.char_indices() {
+            if c == ATOM_QUOTE_ESCAPE || name[i..].starts_with(right.as_str()) {
+                out.write_char(ATOM_QUOTE_ESCAPE)?;
+            }
+            out.write_char(c)?;
+        }
+        out.write_str(right)
+    }
+
     /// 格式化函数/词项
     /// * 返回一个新字符串
     #[inline(always)]
     pub fn format_term(&self, term: &Term) -> String {
-        catch_flow!(self._format_term; term)
+        self.format_term_opts(term, &FormatOptions::default())
+    }
+
+    /// 格式化函数/词项（带选项）
+    /// * 🎯控制输出中的空白，如紧凑输出
+    /// * 📌数学模式只包裹最终输出：组分不会被单独包裹
+    pub fn format_term_opts(&self, term: &Term, options: &FormatOptions) -> String {
+        format_to_string(|out| self.format_term_opts_to(term, options, out))
+    }
+
+    /// 格式化函数/词项（写入器）
+    /// * 🎯批量格式化：写入调用方提供的缓冲区，不另行分配字符串
+    #[inline(always)]
+    pub fn format_term_to(&self, term: &Term, out: &mut impl Write) -> fmt::Result {
+        self.format_term_opts_to(term, &FormatOptions::default(), out)
+    }
+
+    /// 格式化函数/词项（带选项、写入器）
+    pub fn format_term_opts_to(
+        &self,
+        term: &Term,
+        options: &FormatOptions,
+        out: &mut impl Write,
+    ) -> fmt::Result {
+        options.wrap_math_to(out, |out| self._format_term(out, term, options))
+    }
+
+    /// 格式化函数/规范形式词项
+    /// * 🎯用作「去重」「比较」的键：输出只取决于词项本身
+    /// * 📌保证（对「本格式可表示」的词项，如解析所得者）：
+    ///   * 输出总能被解析回相等的词项
+    ///   * 相等的词项，无论如何构造，输出逐字节相同
+    /// * 🚩紧凑输出，且总是插入分隔符：不依赖「是否省略分隔符」的判断
+    pub fn format_term_canonical(&self, term: &Term) -> String {
+        self.format_term_opts(term, &FormatOptions::COMPACT)
+    }
+
+    /// 工具函数/用分隔符连接各个值
+    /// * 🎯真值、预算值
+    fn _format_values(
+        &self,
+        out: &mut impl Write,
+        values: &[String],
+        separator: &str,
+    ) -> fmt::Result {
+        for (i, value) in values.iter().enumerate() {
+            if i != 0 {
+                out.write_str(separator)?;
+            }
+            out.write_str(value)?;
+        }
+        Ok(())
     }
 
     /// 格式化函数/真值
     /// * 🚩【2024-03-22 23:19:22】返回的是**紧凑**形式，没有额外空白符！
-    fn _format_truth(&self, out: &mut String, truth: &Truth) {
+    fn _format_truth(&self, out: &mut impl Write, truth: &Truth) -> fmt::Result {
         // 空真值⇒提前返回
         if truth.is_empty() {
-            return;
+            return Ok(());
         }
         // 左括弧
-        out.push_str(&self.sentence.truth_brackets.0);
+        out.write_str(&self.sentence.truth_brackets.0)?;
         // 中间内容
-        join_to(out, truth.iter(), &self.sentence.truth_separator);
+        self._format_values(out, truth, &self.sentence.truth_separator)?;
         // 右括弧
-        out.push_str(&self.sentence.truth_brackets.1);
+        out.write_str(&self.sentence.truth_brackets.1)
     }
 
     /// 格式化函数/真值
     /// * 返回一个新字符串
     pub fn format_truth(&self, truth: &Truth) -> String {
-        catch_flow!(self._format_truth; truth)
+        format_to_string(|out| self._format_truth(out, truth))
     }
 
     /// 格式化函数/语句
-    fn _format_sentence(&self, out: &mut String, sentence: &Sentence) {
-        template_sentence(
+    /// * 🚩空时间戳、空真值⇒不输出，亦不添加分隔用空格
+    fn _format_sentence<W: Write>(
+        &self,
+        out: &mut W,
+        sentence: &Sentence,
+        options: &FormatOptions,
+    ) -> fmt::Result {
+        template_sentence_to(
             out,
-            &self.format_term(sentence.get_term()),
-            &sentence.punctuation,
-            &sentence.stamp,
-            &self.format_truth(&sentence.truth),
+            |out| self._format_term(out, sentence.get_term(), options),
+            |out| out.write_str(&sentence.punctuation),
+            (!sentence.stamp.is_empty()).then_some(|out: &mut W| out.write_str(&sentence.stamp)),
+            (!sentence.truth.is_empty())
+                .then_some(|out: &mut W| self._format_truth(out, &sentence.truth)),
             // ! ↑此处不用`.get_truth`，因为「可能没有」
             // * 并且「语义明确」失败：无法兼顾地让`get_truth`同时支持返回`Option<&Truth>`与`&Truth`
             // * 📄参考：[`GetTruth`]
-            &self.space.format_items,
+            options.item_space(&self.space.format_items),
         )
     }
 
@@ -104,62 +246,136 @@ impl NarseseFormat {
     /// * 返回一个新字符串
     #[inline(always)]
     pub fn format_sentence(&self, sentence: &Sentence) -> String {
-        catch_flow!(self._format_sentence; sentence)
+        self.format_sentence_opts(sentence, &FormatOptions::default())
+    }
+
+    /// 格式化函数/语句（带选项）
+    pub fn format_sentence_opts(&self, sentence: &Sentence, options: &FormatOptions) -> String {
+        format_to_string(|out| self.format_sentence_opts_to(sentence, options, out))
+    }
+
+    /// 格式化函数/语句（写入器）
+    #[inline(always)]
+    pub fn format_sentence_to(&self, sentence: &Sentence, out: &mut impl Write) -> fmt::Result {
+        self.format_sentence_opts_to(sentence, &FormatOptions::default(), out)
+    }
+
+    /// 格式化函数/语句（带选项、写入器）
+    pub fn format_sentence_opts_to(
+        &self,
+        sentence: &Sentence,
+        options: &FormatOptions,
+        out: &mut impl Write,
+    ) -> fmt::Result {
+        options.wrap_math_to(out, |out| self._format_sentence(out, sentence, options))
     }
 
     /// 格式化函数/预算值
     /// * ❌【2024-03-24 03:14:29】不能「在空白时省略」：会遇到「空预算⇒被解析回语句」的混淆情况
     ///   * 📌目前面向「命令行输入」的解决方案：尝试将空预算转换成语句，然后按语句进行格式化并置入
     /// * 🚩【2024-03-22 23:19:22】返回的是**紧凑**形式，没有额外空白符！
-    fn _format_budget(&self, out: &mut String, budget: &Budget) {
+    fn _format_budget(&self, out: &mut impl Write, budget: &Budget) -> fmt::Result {
         // 左括弧
-        out.push_str(&self.task.budget_brackets.0);
+        out.write_str(&self.task.budget_brackets.0)?;
         // 中间内容
-        join_to(out, budget.iter(), &self.task.budget_separator);
+        self._format_values(out, budget, &self.task.budget_separator)?;
         // 右括弧
-        out.push_str(&self.task.budget_brackets.1);
+        out.write_str(&self.task.budget_brackets.1)
     }
 
     /// 格式化函数/预算值
     /// * 返回一个新字符串
     pub fn format_budget(&self, budget: &Budget) -> String {
-        catch_flow!(self._format_budget; budget)
+        format_to_string(|out| self._format_budget(out, budget))
     }
 
     /// 格式化函数/任务
-    fn _format_task(&self, out: &mut String, task: &Task) {
-        // 临时缓冲区 | 用于「有内容⇒添加空格」的逻辑
-        let mut buffer = String::new();
+    /// * 📌语句总有词项与标点，故视作非空：直接添加分隔用空格
+    fn _format_task(
+        &self,
+        out: &mut impl Write,
+        task: &Task,
+        options: &FormatOptions,
+    ) -> fmt::Result {
         // 预算值 | 第一个直接添加
-        self._format_budget(out, task.get_budget());
+        self._format_budget(out, task.get_budget())?;
+        // 添加空格
+        out.write_str(options.item_space(&self.space.format_items))?;
         // 语句
-        self._format_sentence(&mut buffer, task.get_sentence());
-        add_space_if_necessary_and_flush_buffer(out, &mut buffer, &self.space.format_items);
+        self._format_sentence(out, task.get_sentence(), options)
     }
 
     /// 格式化函数/任务
     /// * 返回一个新字符串
     #[inline(always)]
     pub fn format_task(&self, task: &Task) -> String {
-        catch_flow!(self._format_task; task)
+        self.format_task_opts(task, &FormatOptions::default())
+    }
+
+    /// 格式化函数/任务（带选项）
+    pub fn format_task_opts(&self, task: &Task, options: &FormatOptions) -> String {
+        format_to_string(|out| self.format_task_opts_to(task, options, out))
+    }
+
+    /// 格式化函数/任务（写入器）
+    /// * 🎯批量格式化：如将大量任务逐行写入同一缓冲区
+    #[inline(always)]
+    pub fn format_task_to(&self, task: &Task, out: &mut impl Write) -> fmt::Result {
+        self.format_task_opts_to(task, &FormatOptions::default(), out)
+    }
+
+    /// 格式化函数/任务（带选项、写入器）
+    pub fn format_task_opts_to(
+        &self,
+        task: &Task,
+        options: &FormatOptions,
+        out: &mut impl Write,
+    ) -> fmt::Result {
+        options.wrap_math_to(out, |out| self._format_task(out, task, options))
     }
 
     /// 格式化函数/Narsese
-    fn _format_narsese(&self, out: &mut String, narsese: &Narsese) {
+    fn _format_narsese(
+        &self,
+        out: &mut impl Write,
+        narsese: &Narsese,
+        options: &FormatOptions,
+    ) -> fmt::Result {
         match narsese {
             // 词项
-            Narsese::Term(term) => self._format_term(out, term),
+            Narsese::Term(term) => self._format_term(out, term, options),
             // 语句
-            Narsese::Sentence(sentence) => self._format_sentence(out, sentence),
+            Narsese::Sentence(sentence) => self._format_sentence(out, sentence, options),
             // 任务
-            Narsese::Task(task) => self._format_task(out, task),
+            Narsese::Task(task) => self._format_task(out, task, options),
         }
     }
 
     /// 格式化函数/Narsese
     /// * 🚩自动分派
     pub fn format_narsese(&self, narsese: &Narsese) -> String {
-        catch_flow!(self._format_narsese; narsese)
+        self.format_narsese_opts(narsese, &FormatOptions::default())
+    }
+
+    /// 格式化函数/Narsese（带选项）
+    pub fn format_narsese_opts(&self, narsese: &Narsese, options: &FormatOptions) -> String {
+        format_to_string(|out| self.format_narsese_opts_to(narsese, options, out))
+    }
+
+    /// 格式化函数/Narsese（写入器）
+    #[inline(always)]
+    pub fn format_narsese_to(&self, narsese: &Narsese, out: &mut impl Write) -> fmt::Result {
+        self.format_narsese_opts_to(narsese, &FormatOptions::default(), out)
+    }
+
+    /// 格式化函数/Narsese（带选项、写入器）
+    pub fn format_narsese_opts_to(
+        &self,
+        narsese: &Narsese,
+        options: &FormatOptions,
+        out: &mut impl Write,
+    ) -> fmt::Result {
+        options.wrap_math_to(out, |out| self._format_narsese(out, narsese, options))
     }
 
     /// 总格式化函数/基于[`FormatTo`]特征
@@ -218,7 +434,7 @@ mod tests {
         conversion::string::impl_lexical::format_instances::*,
         lexical::tests::_sample_task_ascii as _sample_task,
     };
-    use nar_dev_utils::f_parallel;
+    use nar_dev_utils::{asserts, f_parallel, show};
 
     /// 测试其中一个格式
     fn _test(format: &NarseseFormat, name: &str, expected: &str) {
@@ -252,6 +468,259 @@ mod tests {
             &FORMAT_HAN   "漢"      "预0.5、0.75、0.4算 「（&/，「ball{-]left」，「（*，{SELF}，$any，#some）-->^go-to」）==>「SELF{-]good」」. :!-1: 真1.0、0.9值";
         ];
     }
+
+    /// 测试/格式化选项
+    /// * 🎯紧凑、美观输出均可被解析回原值
+    #[test]
+    fn test_format_options() {
+        let task = _sample_task();
+        let format = &FORMAT_ASCII;
+        let compact = format.format_task_opts(&task, &FormatOptions::COMPACT);
+        let pretty = format.format_task_opts(&task, &FormatOptions::PRETTY);
+        let copula_only = format.format_task_opts(
+            &task,
+            &FormatOptions {
+                compact: false,
+                space_after_separator: false,
+                space_around_copula: true,
+                omit_separators: false,
+                math_mode: None,
+            },
+        );
+        show!(&compact, &pretty, &copula_only);
+        asserts! {
+            compact => "$0.5;0.75;0.4$<(&/,<ball{-]left>,<(*,{SELF},$any,#some)-->^go-to>)==><SELF{-]good>>.:!-1:%1.0;0.9%",
+            copula_only => "$0.5;0.75;0.4$ <(&/,<ball {-] left>,<(*,{SELF},$any,#some) --> ^go-to>) ==> <SELF {-] good>>. :!-1: %1.0;0.9%",
+            // 默认选项⇒与原有方法一致
+            pretty => format.format_task(&task),
+            format.format_term_opts(&task.sentence.term, &FormatOptions::default()) => format.format_term(&task.sentence.term),
+            format.format_sentence_opts(&task.sentence, &FormatOptions::COMPACT) => "<(&/,<ball{-]left>,<(*,{SELF},$any,#some)-->^go-to>)==><SELF{-]good>>.:!-1:%1.0;0.9%",
+            // 往返
+            format.parse(&compact).unwrap() => Narsese::Task(task.clone()),
+            format.parse(&pretty).unwrap() => Narsese::Task(task.clone()),
+            format.parse(&copula_only).unwrap() => Narsese::Task(task),
+        }
+    }
+
+    /// 测试/OpenNARS兼容输出
+    /// * 🎯固定输出：复合词项内无空白，系词两侧、条目之间各一个空白
+    /// * 🚩期望的字串均可被OpenNARS 3.0.4接受
+    #[test]
+    fn test_format_opennars() {
+        let format = &FORMAT_ASCII;
+        let options = FormatOptions::OPENNARS;
+        for (input, expected) in [
+            ("<a --> b>.", "<a --> b>."),
+            ("<a --> b>. %1.0;0.9%", "<a --> b>. %1.0;0.9%"),
+            ("<a --> b>. :|: %1.0;0.9%", "<a --> b>. :|: %1.0;0.9%"),
+            (
+                "$0.8;0.5;0.95$ <(&/, <a --> b>, +5) =/> <c --> d>>. :|: %1.0;0.9%",
+                "$0.8;0.5;0.95$ <(&/,<a --> b>,+5) =/> <c --> d>>. :|: %1.0;0.9%",
+            ),
+            (
+                "<(&|, <a --> b>, <c --> d>) =|> <e --> f>>. :|:",
+                "<(&|,<a --> b>,<c --> d>) =|> <e --> f>>. :|:",
+            ),
+            (
+                r"<<a --> b> =\> <c --> d>>. %0.9;0.8%",
+                r"<<a --> b> =\> <c --> d>>. %0.9;0.8%",
+            ),
+            (
+                "(&&, a, b, (||, c, d))! %1.0;0.9%",
+                "(&&,a,b,(||,c,d))! %1.0;0.9%",
+            ),
+            (
+                "<?x --> (*, {SELF}, [warm])>?",
+                "<?x --> (*,{SELF},[warm])>?",
+            ),
+            (
+                "$0.9;0.9$ <(*, $x, #y) --> ^go-to>@ :/:",
+                "$0.9;0.9$ <(*,$x,#y) --> ^go-to>@ :/:",
+            ),
+            (
+                "<(/, open, _, door) --> key>. :!10: %0.5;0.9%",
+                "<(/,open,_,door) --> key>. :!10: %0.5;0.9%",
+            ),
+        ] {
+            let narsese = format.parse(input).unwrap();
+            let formatted = format.format_narsese_opts(&narsese, &options);
+            assert_eq!(formatted, expected, "{input:?}");
+            // 往返
+            assert_eq!(format.parse(&formatted).unwrap(), narsese, "{formatted:?}");
+        }
+    }
+
+    /// 工具函数：解析词项
+    fn parse_term(format: &NarseseFormat, input: &str) -> Term {
+        format.parse(input).unwrap().try_into_term().unwrap()
+    }
+
+    /// 工具函数：检验「规范形式」与「省略分隔符」的往返性质
+    /// * 🚩`parse(canon(parse(s))) == parse(s)`，且规范形式幂等
+    /// * 🚩省略分隔符后（紧凑、美观）亦能被解析回原值
+    fn _test_canonical(format: &NarseseFormat, term: &Term) {
+        let canonical = format.format_term_canonical(term);
+        let reparsed = parse_term(format, &canonical);
+        assert_eq!(&reparsed, term, "规范形式往返失败：{canonical:?}");
+        assert_eq!(format.format_term_canonical(&reparsed), canonical);
+        for options in [FormatOptions::COMPACT, FormatOptions::PRETTY] {
+            let omitted = format.format_term_opts(term, &options.with_omit_separators(true));
+            assert_eq!(
+                &parse_term(format, &omitted),
+                term,
+                "省略分隔符往返失败：{omitted:?}"
+            );
+        }
+    }
+
+    /// 测试/规范形式
+    /// * 🎯对解析器接受的所有词项，规范形式都能往返且幂等
+    #[test]
+    fn test_format_term_canonical() {
+        // ASCII | 解析器测试中的成功用例
+        let format = &FORMAT_ASCII;
+        for input in [
+            "🌀",
+            "💬现在已经支持复杂的emoji",
+            "有些emoji会降级为文本格式-🏗️",
+            "#A",
+            "真の词项",
+            "_",
+            "_占位符",
+            "+123",
+            "^op",
+            "<^op --> あ>",
+            "<<A --> B> ==> <B --> C>>",
+            "(*, A, B, C)",
+            "(* A #B #C)",
+            "(*, A  B, C)",
+            "(*, A)",
+            "(*, _)",
+            "(/, R, _, A)",
+            "(&&, <A --> B>, <B --> C>, <C --> D>)",
+            "(--, <A --> B>)",
+            "(-, {A}, [B])",
+            "{SELF}",
+            "{A, $B, #C, ?D, ^E, +1}",
+            "<(*, {SELF}, $any, #some) --> ^go-to>",
+        ] {
+            _test_canonical(format, &parse_term(format, input));
+        }
+        _test_canonical(format, &_sample_task().sentence.term);
+        // 相等的词项⇒逐字节相同 | 与原输入中的空白、分隔符无关
+        asserts! {
+            format.format_term_canonical(&parse_term(format, "(* A #B #C)"))
+                => format.format_term_canonical(&parse_term(format, "( *,A,#B,  #C )")),
+            format.format_term_canonical(&parse_term(format, "(* A #B #C)")) => "(*,A,#B,#C)",
+        }
+        // LaTeX、漢文
+        let latex = super::super::tests::_sample_task_latex();
+        _test_canonical(&FORMAT_LATEX, &latex.sentence.term);
+        let han = super::super::tests::_sample_task_han();
+        _test_canonical(&FORMAT_HAN, &han.sentence.term);
+        // 带引号的原子词项
+        let quoted = FORMAT_ASCII.builder().quotes("\"", "\"").build().unwrap();
+        for input in [r#"(*, "a b", "c", d)"#, r#"{"x\"y", z}"#] {
+            _test_canonical(&quoted, &parse_term(&quoted, input));
+        }
+    }
+
+    /// 测试/省略分隔符
+    #[test]
+    fn test_omit_separators() {
+        let format = &FORMAT_ASCII;
+        let term = parse_term(format, "(*, A, #B, C, <D --> E>, {F, G})");
+        let compact = FormatOptions::COMPACT.with_omit_separators(true);
+        let pretty = FormatOptions::PRETTY.with_omit_separators(true);
+        asserts! {
+            // 仅在两侧同为标识符字符时保留分隔符
+            format.format_term_opts(&term, &compact) => "(*A#B,C<D-->E>{F,G})",
+            // 分隔符之后的空白不受影响
+            format.format_term_opts(&term, &pretty) => "(* A #B, C <D --> E> {F, G})",
+            // 默认不省略
+            format.format_term_opts(&term, &FormatOptions::PRETTY) => format.format_term(&term),
+        }
+    }
+
+    /// 测试/写入器版本
+    /// * 🎯与固定的期望字符串逐字节一致（不依赖返回字符串的版本）
+    /// * 🎯与返回字符串的版本逐字节一致
+    /// * 🎯批量写入同一缓冲区：结果即各次输出的拼接
+    #[test]
+    fn test_format_to() {
+        use crate::conversion::string::MathMode;
+        // 固定期望
+        let format_to = |format: &NarseseFormat, task: &Task, options: &FormatOptions| {
+            let mut buffer = String::new();
+            format
+                .format_task_opts_to(task, options, &mut buffer)
+                .unwrap();
+            buffer
+        };
+        let task = _sample_task();
+        let task_han = super::super::tests::_sample_task_han();
+        asserts! {
+            format_to(&FORMAT_ASCII, &task, &FormatOptions::PRETTY)
+                => "$0.5;0.75;0.4$ <(&/, <ball {-] left>, <(*, {SELF}, $any, #some) --> ^go-to>) ==> <SELF {-] good>>. :!-1: %1.0;0.9%",
+            format_to(&FORMAT_ASCII, &task, &FormatOptions::COMPACT)
+                => "$0.5;0.75;0.4$<(&/,<ball{-]left>,<(*,{SELF},$any,#some)-->^go-to>)==><SELF{-]good>>.:!-1:%1.0;0.9%",
+            format_to(&FORMAT_ASCII, &task, &FormatOptions::COMPACT.with_omit_separators(true))
+                => "$0.5;0.75;0.4$<(&/<ball{-]left><(*{SELF}$any#some)-->^go-to>)==><SELF{-]good>>.:!-1:%1.0;0.9%",
+            format_to(&FORMAT_ASCII, &task, &FormatOptions::PRETTY.with_math_mode(MathMode::Display))
+                => r"\[$0.5;0.75;0.4$ <(&/, <ball {-] left>, <(*, {SELF}, $any, #some) --> ^go-to>) ==> <SELF {-] good>>. :!-1: %1.0;0.9%\]",
+            format_to(&FORMAT_HAN, &task_han, &FormatOptions::PRETTY)
+                => "预0.5、0.75、0.4算 「（接连，「『ball』是【left】」，「（积，『SELF』，任一any，其一some）是操作do」）得「『SELF』是【good】」」。 发生在-1 真1、0.9值",
+            format_to(&FORMAT_HAN, &task_han, &FormatOptions::COMPACT)
+                => "预0.5、0.75、0.4算「（接连，「『ball』是【left】」，「（积，『SELF』，任一any，其一some）是操作do」）得「『SELF』是【good】」」。发生在-1真1、0.9值",
+        }
+        // 与返回字符串的版本对照
+        let samples = [
+            (&FORMAT_ASCII as &NarseseFormat, _sample_task()),
+            (&FORMAT_LATEX, super::super::tests::_sample_task_latex()),
+            (&FORMAT_HAN, super::super::tests::_sample_task_han()),
+        ];
+        let options = [
+            FormatOptions::PRETTY,
+            FormatOptions::COMPACT,
+            FormatOptions::COMPACT.with_omit_separators(true),
+            FormatOptions::PRETTY.with_math_mode(MathMode::Display),
+        ];
+        for (format, task) in &samples {
+            let narsese = Narsese::Task(task.clone());
+            for options in &options {
+                let mut buffer = String::new();
+                format
+                    .format_task_opts_to(task, options, &mut buffer)
+                    .unwrap();
+                assert_eq!(buffer, format.format_task_opts(task, options));
+                buffer.clear();
+                format
+                    .format_sentence_opts_to(&task.sentence, options, &mut buffer)
+                    .unwrap();
+                assert_eq!(buffer, format.format_sentence_opts(&task.sentence, options));
+                buffer.clear();
+                format
+                    .format_term_opts_to(&task.sentence.term, options, &mut buffer)
+                    .unwrap();
+                assert_eq!(
+                    buffer,
+                    format.format_term_opts(&task.sentence.term, options)
+                );
+                buffer.clear();
+                format
+                    .format_narsese_opts_to(&narsese, options, &mut buffer)
+                    .unwrap();
+                assert_eq!(buffer, format.format_narsese_opts(&narsese, options));
+            }
+            // 逐行追加
+            let mut all = String::new();
+            for _ in 0..3 {
+                format.format_task_to(task, &mut all).unwrap();
+                all.push('\n');
+            }
+            assert_eq!(all, format!("{}\n", format.format_task(task)).repeat(3));
+        }
+    }
 }
 
 /// 单元测试 & 枚举Narsese
@@ -270,7 +739,9 @@ mod tests_with_enum_narsese {
             NarseseFormat as EnumNarseseFormat,
         },
         impl_lexical::{format_instances::*, NarseseFormat},
+        FormatOptions, MathMode,
     };
+    use crate::lexical::{tests::_sample_task_ascii, Narsese};
     use nar_dev_utils::f_parallel;
 
     /// 测试其中一个格式
@@ -304,4 +775,24 @@ mod tests_with_enum_narsese {
             &F_E_HAN,   &FORMAT_HAN,   "漢",      "预0.5、0.75、0.4算 「（接连，「ball具有left」，「（积，『SELF』，任一any，其一some）是操作do」）得「SELF具有good」」. 发生在-1 真1.0、0.9值";
         ];
     }
+
+    /// 测试/ASCII样本改写为LaTeX词汇
+    /// * 🎯固定LaTeX输出，并确认（去除数学模式包裹后）可被解析回原值
+    #[test]
+    fn test_latex_golden() {
+        let task = Narsese::Task(_sample_task_ascii());
+        let latex = F_E_ASCII.fold_vocabulary(&F_E_LATEX, task).unwrap();
+        let formatted = FORMAT_LATEX.format_narsese(&latex);
+        assert_eq!(
+            formatted,
+            r#"\$0.5;0.75;0.4\$ \left<\left(,\; \left<ball \circ\!\!\!\rightarrow\!\!\!\circ{} left\right>\; \left<\left(\times{}\; \left\{SELF\right\}\; \$any\; \#some\right) \rightarrow{} \Uparrow{}go-to\right>\right) \Rightarrow{} \left<SELF \circ\!\!\!\rightarrow\!\!\!\circ{} good\right>\right>. t=-1 \langle{}1.0,0.9\rangle{}"#
+        );
+        let options = FormatOptions::PRETTY.with_math_mode(MathMode::Display);
+        let wrapped = FORMAT_LATEX.format_narsese_opts(&latex, &options);
+        assert_eq!(wrapped, format!(r"\[{formatted}\]"));
+        assert_eq!(
+            FORMAT_LATEX.parse(MathMode::strip(&wrapped)).unwrap(),
+            latex
+        );
+    }
 }
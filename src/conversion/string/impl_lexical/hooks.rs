@@ -0,0 +1,263 @@
+//! 词法解析器的「钩子」
+//! * 🎯在解析时就地规范化：下游组件见到的都是规范形式
+//!   * 📄词语名统一小写、去除命名空间前缀
+//! * 📌词法格式实例均为静态常量：钩子不放入格式，而是与格式一同包装成[`ParserWithHooks`]
+//! * 🚩钩子的调用时机
+//!   * 📌原子词项/词项：每个词项构造完成后，自底向上调用
+//!     * 📌原子词项先调用[`ParseHooks::on_atom`]，再调用[`ParseHooks::on_term`]
+//!   * 📌语句/任务：折叠「中间结果」后调用
+//!     * 📌任务中的语句亦调用[`ParseHooks::on_sentence`]，之后再调用[`ParseHooks::on_task`]
+//! * ⚡无钩子时的解析路径不变：不装箱、不调用
+
+use super::{parser, NarseseFormat, ParseResult};
+use crate::lexical::{Narsese, Sentence, Task, Term};
+use std::fmt::{self, Debug, Formatter};
+
+/// 钩子/词项
+pub type TermHook = Box<dyn Fn(Term) -> Term + Send + Sync>;
+/// 钩子/语句
+pub type SentenceHook = Box<dyn Fn(Sentence) -> Sentence + Send + Sync>;
+/// 钩子/任务
+pub type TaskHook = Box<dyn Fn(Task) -> Task + Send + Sync>;
+
+/// 解析钩子
+/// * 📌所有钩子均可选：[`Default`]即「无钩子」
+#[derive(Default)]
+pub struct ParseHooks {
+    /// 原子词项构造完成后
+    pub on_atom: Option<TermHook>,
+    /// 任意词项构造完成后 | 自底向上
+    pub on_term: Option<TermHook>,
+    /// 语句折叠完成后 | 含任务中的语句
+    pub on_sentence: Option<SentenceHook>,
+    /// 任务折叠完成后
+    pub on_task: Option<TaskHook>,
+}
+
+/// 钩子为闭包，只显示「是否设置」
+impl Debug for ParseHooks {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParseHooks")
+            .field("on_atom", &self.on_atom.is_some())
+            .field("on_term", &self.on_term.is_some())
+            .field("on_sentence", &self.on_sentence.is_some())
+            .field("on_task", &self.on_task.is_some())
+            .finish()
+    }
+}
+
+/// 实现/构造
+impl ParseHooks {
+    /// 构造函数
+    /// * 🚩无钩子
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置钩子/原子词项
+    pub fn on_atom(mut self, hook: impl Fn(Term) -> Term + Send + Sync + 'static) -> Self {
+        self.on_atom = Some(Box::new(hook));
+        self
+    }
+
+    /// 设置钩子/词项
+    pub fn on_term(mut self, hook: impl Fn(Term) -> Term + Send + Sync + 'static) -> Self {
+        self.on_term = Some(Box::new(hook));
+        self
+    }
+
+    /// 设置钩子/语句
+    pub fn on_sentence(
+        mut self,
+        hook: impl Fn(Sentence) -> Sentence + Send + Sync + 'static,
+    ) -> Self {
+        self.on_sentence = Some(Box::new(hook));
+        self
+    }
+
+    /// 设置钩子/任务
+    pub fn on_task(mut self, hook: impl Fn(Task) -> Task + Send + Sync + 'static) -> Self {
+        self.on_task = Some(Box::new(hook));
+        self
+    }
+}
+
+/// 实现/调用
+impl ParseHooks {
+    /// 对刚构造完成的词项调用钩子
+    pub(super) fn apply_term(&self, term: Term) -> Term {
+        let term = match (&self.on_atom, &term) {
+            (Some(on_atom), Term::Atom { .. }) => on_atom(term),
+            _ => term,
+        };
+        match &self.on_term {
+            Some(on_term) => on_term(term),
+            None => term,
+        }
+    }
+
+    /// 对刚折叠完成的Narsese值调用钩子
+    /// * 📌词项已在构造时调用过钩子
+    pub(super) fn apply_narsese(&self, narsese: Narsese) -> Narsese {
+        let apply_sentence = |sentence| match &self.on_sentence {
+            Some(on_sentence) => on_sentence(sentence),
+            None => sentence,
+        };
+        match narsese {
+            Narsese::Term(..) => narsese,
+            Narsese::Sentence(sentence) => Narsese::Sentence(apply_sentence(sentence)),
+            Narsese::Task(mut task) => {
+                task.sentence = apply_sentence(task.sentence);
+                Narsese::Task(match &self.on_task {
+                    Some(on_task) => on_task(task),
+                    None => task,
+                })
+            }
+        }
+    }
+}
+
+/// 带钩子的解析器
+/// * 🎯与[`NarseseFormat`]相同的解析接口
+#[derive(Debug)]
+pub struct ParserWithHooks<'a> {
+    /// 词法格式
+    pub format: &'a NarseseFormat,
+    /// 解析钩子
+    pub hooks: ParseHooks,
+}
+
+impl<'a> ParserWithHooks<'a> {
+    /// 构造函数
+    pub fn new(format: &'a NarseseFormat, hooks: ParseHooks) -> Self {
+        Self { format, hooks }
+    }
+
+    /// 主解析函数@字符串
+    /// * 📄[`NarseseFormat::parse`]
+    pub fn parse(&self, input: &str) -> ParseResult {
+        parser::parse_hooked(self.format, Some(&self.hooks), input)
+    }
+
+    /// 解析函数/词项@字符串
+    /// * 📄[`NarseseFormat::parse_term`]
+    pub fn parse_term(&self, input: &str) -> ParseResult<Term> {
+        parser::parse_term_hooked(self.format, Some(&self.hooks), input)
+    }
+}
+
+impl NarseseFormat {
+    /// 与钩子一同包装成解析器
+    pub fn with_hooks(&self, hooks: ParseHooks) -> ParserWithHooks<'_> {
+        ParserWithHooks::new(self, hooks)
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        conversion::string::impl_lexical::format_instances::{FORMAT_ASCII, FORMAT_HAN},
+        lexical::tests::_sample_task_ascii,
+    };
+    use nar_dev_utils::{asserts, show};
+    use std::sync::{Arc, Mutex};
+
+    /// 工具函数：原子词项名称小写
+    fn lowercase(term: Term) -> Term {
+        match term {
+            Term::Atom { prefix, name } => Term::new_atom(prefix, name.to_lowercase()),
+            term => term,
+        }
+    }
+
+    /// 测试/原子词项
+    #[test]
+    fn test_on_atom() {
+        let format = &*FORMAT_ASCII;
+        let parser = format.with_hooks(ParseHooks::new().on_atom(lowercase));
+        let parsed = parser.parse("<SELF --> Good>.").unwrap();
+        show!(&parsed);
+        asserts! {
+            format.format_narsese(&parsed) => "<self --> good>.",
+            parser.parse_term("(&&, $X, [Y], <Z --> ^Op>)").unwrap()
+                => format.parse_term("(&&, $x, [y], <z --> ^op>)").unwrap(),
+        }
+        // 其它格式 | 去除命名空间前缀
+        let parser = FORMAT_HAN.with_hooks(ParseHooks::new().on_atom(|term| match term {
+            Term::Atom { prefix, name } => {
+                let name = name.strip_prefix("ns_").map(str::to_owned).unwrap_or(name);
+                Term::new_atom(prefix, name)
+            }
+            term => term,
+        }));
+        asserts! {
+            parser.parse_term("「ns_A是ns_B」").unwrap()
+                => FORMAT_HAN.parse_term("「A是B」").unwrap(),
+        }
+    }
+
+    /// 测试/词项：自底向上
+    #[test]
+    fn test_on_term() {
+        let format = &*FORMAT_ASCII;
+        let log = Arc::new(Mutex::new(vec![]));
+        let log_hook = log.clone();
+        let parser = format.with_hooks(ParseHooks::new().on_term(move |term| {
+            log_hook.lock().unwrap().push(format.format_term(&term));
+            term
+        }));
+        parser.parse_term("<(*, A, B) --> C>").unwrap();
+        let log = log.lock().unwrap();
+        show!(&log);
+        // 组分先于整体，整体最后
+        let position = |s: &str| log.iter().position(|t| t == s).unwrap();
+        asserts! {
+            position("A") < position("(*, A, B)"),
+            position("B") < position("(*, A, B)"),
+            position("(*, A, B)") < position("<(*, A, B) --> C>"),
+            log.last().unwrap() => "<(*, A, B) --> C>",
+        }
+    }
+
+    /// 测试/语句、任务
+    #[test]
+    fn test_on_sentence_task() {
+        let format = &*FORMAT_ASCII;
+        let parser = format.with_hooks(
+            ParseHooks::new()
+                .on_sentence(|mut sentence| {
+                    if sentence.truth.is_empty() {
+                        sentence.truth = vec!["1.0".into(), "0.9".into()];
+                    }
+                    sentence
+                })
+                .on_task(|mut task| {
+                    task.budget.truncate(1);
+                    task
+                }),
+        );
+        asserts! {
+            format.format_narsese(&parser.parse("<A --> B>.").unwrap())
+                => "<A --> B>. %1.0;0.9%",
+            format.format_narsese(&parser.parse("$0.5;0.5$ <A --> B>.").unwrap())
+                => "$0.5$ <A --> B>. %1.0;0.9%",
+            // 词项不受影响
+            parser.parse("<A --> B>").unwrap() => format.parse("<A --> B>").unwrap(),
+        }
+    }
+
+    /// 测试/无钩子⇒行为不变
+    #[test]
+    fn test_no_hooks() {
+        let format = &*FORMAT_ASCII;
+        let parser = format.with_hooks(ParseHooks::new());
+        let input = format.format_task(&_sample_task_ascii());
+        show!(&parser);
+        asserts! {
+            parser.parse(&input).unwrap() => format.parse(&input).unwrap(),
+            parser.parse("<A -->").is_err(),
+        }
+    }
+}
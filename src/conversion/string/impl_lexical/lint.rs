@@ -0,0 +1,380 @@
+//! 词法Narsese的「良构检查」
+//! * 🎯在格式化、折叠之前，尽早发现「词法上可构造，但格式中不存在」的元素
+//!   * 📄`Term::Compound { connecter: "&&&", .. }`、系词为`"-?>"`的陈述
+//! * 📌可选的检查：词法Narsese本身仍然不做任何校验
+//! * 🚩检查所有问题，不在首个问题处停止
+//! * 🚩检查内容
+//!   * 词项：未知的原子词项前缀/复合词项连接符/陈述系词/词项集括弧、空的复合词项、无占位符的像、名称含非法字符的原子词项
+//!   * 词项：无序复合词项（词项集、可交换的复合词项）中的重复组分
+//!     * 📌折叠为枚举Narsese时将被去重，或按其「重复组分策略」报错
+//!   * 语句：未知的标点、非法的时间戳、非法的真值
+//!   * 任务：非法的预算值
+//! * 📌问题按「先序遍历」的顺序给出
+
+use super::{NarseseFormat, EMPTY_SET_MESSAGE};
+use crate::{
+    api::UIntPrecision,
+    conversion::string::IMAGE_PLACEHOLDERS_MESSAGE,
+    lexical::{Sentence, Task, Term},
+};
+use std::fmt::{self, Display, Formatter};
+
+/// 问题的严重程度
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintSeverity {
+    /// 警告：能解析、能格式化，但语义上可疑
+    /// * 📄没有占位符的像
+    /// * 📄无序复合词项中的重复组分
+    Warning,
+    /// 错误：无法在该格式下解析或格式化
+    Error,
+}
+
+/// 问题所在的条目
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintItem {
+    /// 词项 | 具体位置见[`LintIssue::path`]
+    Term,
+    /// 标点
+    Punctuation,
+    /// 时间戳
+    Stamp,
+    /// 真值
+    Truth,
+    /// 预算值
+    Budget,
+}
+
+/// 检查出的问题
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LintIssue {
+    /// 问题所在的条目
+    pub item: LintItem,
+    /// 问题所在的子词项路径
+    /// * 📌与[`Term::get_at`]的路径一致
+    /// * 📌非词项条目⇒空路径
+    pub path: Vec<UIntPrecision>,
+    /// 严重程度
+    pub severity: LintSeverity,
+    /// 问题描述
+    pub message: String,
+}
+
+impl LintIssue {
+    /// 构造函数
+    pub fn new(
+        item: LintItem,
+        path: Vec<UIntPrecision>,
+        severity: LintSeverity,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            item,
+            path,
+            severity,
+            message: message.into(),
+        }
+    }
+
+    /// 是否为「错误」
+    pub fn is_error(&self) -> bool {
+        self.severity == LintSeverity::Error
+    }
+}
+
+impl Display for LintIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            LintSeverity::Warning => "警告",
+            LintSeverity::Error => "错误",
+        };
+        match self.item {
+            LintItem::Term => write!(f, "[{severity}] 词项{:?}：{}", self.path, self.message),
+            item => write!(f, "[{severity}] {item:?}：{}", self.message),
+        }
+    }
+}
+
+/// 检查词项
+/// * 🚩递归检查所有子词项
+pub fn check(term: &Term, format: &NarseseFormat) -> Vec<LintIssue> {
+    let mut issues = vec![];
+    check_term(term, format, &mut vec![], &mut issues);
+    issues
+}
+
+/// 检查语句
+/// * 🚩词项 + 标点 + 时间戳 + 真值
+pub fn check_sentence(sentence: &Sentence, format: &NarseseFormat) -> Vec<LintIssue> {
+    let mut issues = check(&sentence.term, format);
+    let issue = |item, message: String| LintIssue::new(item, vec![], LintSeverity::Error, message);
+    // 标点
+    if !format.sentence.punctuations.has(&sentence.punctuation) {
+        issues.push(issue(
+            LintItem::Punctuation,
+            format!("未知的标点「{}」", sentence.punctuation),
+        ));
+    }
+    // 时间戳
+    if let Err(error) = format.parse_stamp_kind(&sentence.stamp) {
+        issues.push(issue(
+            LintItem::Stamp,
+            format!("{}「{}」", error.message(), sentence.stamp),
+        ));
+    }
+    // 真值
+    if let Err(error) = format.validate_truth(&sentence.truth) {
+        issues.push(issue(LintItem::Truth, error.to_string()));
+    }
+    issues
+}
+
+/// 检查任务
+/// * 🚩语句 + 预算值
+pub fn check_task(task: &Task, format: &NarseseFormat) -> Vec<LintIssue> {
+    let mut issues = check_sentence(&task.sentence, format);
+    if let Err(error) = format.validate_budget(&task.budget) {
+        issues.push(LintIssue::new(
+            LintItem::Budget,
+            vec![],
+            LintSeverity::Error,
+            error.to_string(),
+        ));
+    }
+    issues
+}
+
+/// 递归检查词项
+/// * 🚩`path`在递归时压入/弹出，避免反复分配
+fn check_term(
+    term: &Term,
+    format: &NarseseFormat,
+    path: &mut Vec<UIntPrecision>,
+    issues: &mut Vec<LintIssue>,
+) {
+    let mut push = |severity, message: String| {
+        issues.push(LintIssue::new(
+            LintItem::Term,
+            path.clone(),
+            severity,
+            message,
+        ))
+    };
+    use LintSeverity::*;
+    match term {
+        Term::Atom { prefix, name } => {
+            if !format.atom.prefixes.has(prefix) {
+                push(Error, format!("未知的原子词项前缀「{prefix}」"));
+            }
+            // 启用引号⇒任何名称都能往返
+            if format.atom.quotes.is_none() {
+                if let Some(c) = name.chars().find(|&c| !(format.atom.is_identifier)(c)) {
+                    push(Error, format!("原子词项名称「{name}」含有非法字符{c:?}"));
+                }
+            }
+        }
+        Term::Compound { connecter, terms } => {
+            let compound = &format.compound;
+            if !compound.connecters.has(connecter) {
+                push(Error, format!("未知的复合词项连接符「{connecter}」"));
+            }
+            if terms.is_empty() {
+                push(Error, format!("复合词项「{connecter}」没有组分"));
+            }
+            let placeholders = terms
+                .iter()
+                .filter(|term| format.is_placeholder(term))
+                .count();
+            if format.is_image_connecter(connecter) {
+                if !terms.is_empty() && placeholders == 0 {
+                    push(Warning, format!("像「{connecter}」中没有占位符"));
+                }
+                if placeholders > 1 {
+                    push(Error, IMAGE_PLACEHOLDERS_MESSAGE.to_string());
+                }
+            }
+            if format.is_commutative_connecter(connecter) {
+                if let Some(message) = duplicate_message(terms, format) {
+                    push(Warning, message);
+                }
+            }
+        }
+        Term::Set {
+            left_bracket,
+            terms,
+            right_bracket,
+        } => {
+            if !format.is_set_brackets(left_bracket, right_bracket) {
+                push(
+                    Error,
+                    format!("未知的词项集括弧「{left_bracket}」「{right_bracket}」"),
+                );
+            }
+            if terms.is_empty() {
+                push(Error, EMPTY_SET_MESSAGE.to_string());
+            }
+            if let Some(message) = duplicate_message(terms, format) {
+                push(Warning, message);
+            }
+        }
+        Term::Statement { copula, .. } => {
+            if !format.statement.copulas.has(copula) {
+                push(Error, format!("未知的陈述系词「{copula}」"));
+            }
+        }
+    }
+    // 子词项
+    let components: Vec<&Term> = match term {
+        Term::Atom { .. } => vec![],
+        Term::Compound { terms, .. } | Term::Set { terms, .. } => terms.iter().collect(),
+        Term::Statement {
+            subject, predicate, ..
+        } => vec![subject, predicate],
+    };
+    for (i, component) in components.into_iter().enumerate() {
+        path.push(i);
+        check_term(component, format, path, issues);
+        path.pop();
+    }
+}
+
+/// 检查无序复合词项中的重复组分
+/// * 🚩返回首个重复组分的警告消息
+fn duplicate_message(terms: &[Term], format: &NarseseFormat) -> Option<String> {
+    let mut seen = std::collections::HashSet::new();
+    let term = terms.iter().find(|term| !seen.insert(*term))?;
+    Some(format!(
+        "无序复合词项中存在重复组分「{}」",
+        format.format_term(term)
+    ))
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        conversion::string::impl_lexical::format_instances::{FORMAT_ASCII, FORMAT_HAN},
+        lexical::{shortcuts::*, tests::_sample_task_ascii},
+    };
+    use nar_dev_utils::{asserts, show};
+    use LintItem::*;
+    use LintSeverity::*;
+
+    /// 工具函数：构造词项中的问题
+    fn term_issue(path: &[UIntPrecision], severity: LintSeverity, message: &str) -> LintIssue {
+        LintIssue::new(Term, path.to_vec(), severity, message)
+    }
+
+    /// 工具函数：构造非词项条目的问题
+    fn item_issue(item: LintItem, message: &str) -> LintIssue {
+        LintIssue::new(item, vec![], Error, message)
+    }
+
+    /// 良构的Narsese⇒无问题
+    #[test]
+    fn test_well_formed() {
+        let format = &FORMAT_ASCII;
+        asserts! {
+            check_task(&_sample_task_ascii(), format) => vec![],
+            check(&format.parse_term("<(/, A, _, B) --> (\\, _, C)>").unwrap(), format) => vec![],
+            check(&FORMAT_HAN.parse_term("（外像，甲，某）").unwrap(), &FORMAT_HAN) => vec![],
+        }
+    }
+
+    /// 有问题的词项
+    #[test]
+    fn test_check_term() {
+        let format = &FORMAT_ASCII;
+        // 未知的连接符/系词/前缀，且不在首个问题处停止
+        let term = statement!(
+            compound!("&&&", atom!("A"), atom!("@" "B"))
+            "-?>"
+            set!("{"; atom!("C") ; "]")
+        );
+        let issues = check(&term, format);
+        show!(&issues);
+        asserts! {
+            issues => vec![
+                term_issue(&[], Error, "未知的陈述系词「-?>」"),
+                term_issue(&[0], Error, "未知的复合词项连接符「&&&」"),
+                term_issue(&[0, 1], Error, "未知的原子词项前缀「@」"),
+                term_issue(&[1], Error, "未知的词项集括弧「{」「]」"),
+            ]
+        }
+        // 空的复合词项、空集、无占位符的像、非法字符、多个占位符的像
+        let term = compound!(
+            "*",
+            compound!("&&",),
+            set!("["; ; "]"),
+            compound!("/", atom!("A"), atom!("B")),
+            compound!(r"\", atom!("_" ""), atom!("C")),
+            atom!("a b"),
+            atom!("$" "x.y"),
+            compound!("/", atom!("_" ""), atom!("_" "")),
+        );
+        let issues = check(&term, format);
+        show!(&issues);
+        asserts! {
+            issues => vec![
+                term_issue(&[0], Error, "复合词项「&&」没有组分"),
+                term_issue(&[1], Error, EMPTY_SET_MESSAGE),
+                term_issue(&[2], Warning, "像「/」中没有占位符"),
+                term_issue(&[4], Error, "原子词项名称「a b」含有非法字符' '"),
+                term_issue(&[5], Error, "原子词项名称「x.y」含有非法字符'.'"),
+                term_issue(&[6], Error, IMAGE_PLACEHOLDERS_MESSAGE),
+            ]
+        }
+        // 启用引号⇒名称不受限制
+        let quoted = FORMAT_ASCII.builder().quotes("\"", "\"").build().unwrap();
+        asserts! {
+            check(&atom!("a b"), &quoted) => vec![],
+            // 检查依格式而定
+            check(&compound!("外像", atom!("甲")), &FORMAT_HAN) => vec![term_issue(&[], Warning, "像「外像」中没有占位符")],
+            check(&compound!("外像", atom!("甲")), format) => vec![term_issue(&[], Error, "未知的复合词项连接符「外像」")],
+        }
+    }
+
+    /// 无序复合词项中的重复组分⇒警告
+    #[test]
+    fn test_check_duplicates() {
+        let format = &FORMAT_ASCII;
+        let term = format
+            .parse_term("(*, {A, A}, (&&, B, C, B), (&/, D, D), [E, F])")
+            .unwrap();
+        let issues = check(&term, format);
+        show!(&issues);
+        asserts! {
+            issues => vec![
+                term_issue(&[0], Warning, "无序复合词项中存在重复组分「A」"),
+                term_issue(&[1], Warning, "无序复合词项中存在重复组分「B」"),
+            ]
+        }
+    }
+
+    /// 有问题的语句、任务
+    #[test]
+    fn test_check_task() {
+        let format = &FORMAT_ASCII;
+        let task = task![
+            budget!["0.5" "abc" "0.5" "0.5"];
+            compound!("&&&", atom!("A")) "。" ":!x:" truth!["1.0" "1.5"]
+        ];
+        let issues = check_task(&task, format);
+        show!(&issues);
+        asserts! {
+            issues => vec![
+                term_issue(&[], Error, "未知的复合词项连接符「&&&」"),
+                item_issue(Punctuation, "未知的标点「。」"),
+                item_issue(Stamp, "非法时间戳「:!x:」"),
+                item_issue(Truth, "真值的第2个组分「1.5」超出[0, 1]范围"),
+                item_issue(Budget, "预算值组分过多：至多3个，实际4个"),
+            ]
+        }
+        // 仅检查语句
+        asserts! {
+            check_sentence(&task.sentence, format).len() => 4,
+            check_sentence(&sentence![atom!("A") "." ":|:" truth!["1.0" "0.9"]], format) => vec![],
+        }
+    }
+}
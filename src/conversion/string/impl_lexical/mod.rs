@@ -3,8 +3,18 @@
 nar_dev_utils::mod_and_pub_use! {
     // 格式
     format
+    // 格式构建器
+    builder
     // 解析器
     parser
+    // 流式解析器
+    streaming
+    // 数值检验
+    validate
+    // 带检验构造
+    checked
+    // 解析钩子
+    hooks
 }
 
 // 格式化
@@ -12,6 +22,12 @@ nar_dev_utils::mod_and_pub_use! {
 //   * 所以没导出模块内容
 mod formatter;
 
+// 注释 | 直接对「词法Narsese格式」实现方法，无需导出
+mod comment;
+
+// 语法糖 | 直接对「词法Narsese格式」实现方法，无需导出
+mod sugar;
+
 // 解析格式的实例
 // * 🚩目前仍作为单独的子模块导出，而**不导出其内元素**
 //  * 其内元素可能会造成名称混淆
@@ -20,6 +36,11 @@ mod formatter;
 pub mod format_instances;
 // pub use format_instances as instances;
 
+// 良构检查
+// * 🚩作为单独的子模块导出，而**不导出其内元素**
+//   * 其内函数名（`check`等）较为通用，可能会造成名称混淆
+pub mod lint;
+
 // 实用宏
 mod macros;
 
@@ -33,7 +54,7 @@ pub mod shortcuts {
 
 /// 集成测试@词法Narsese/字符串解析&格式化
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::NarseseFormat;
     use crate::{
         conversion::string::impl_lexical::format_instances::*,
@@ -32,13 +32,25 @@
 //!   * 💫即便使用「字符数组切片」，「截取子环境→子环境解析」的作用仍然有限
 //!     * 许多时候仍然是在模拟「枚举Narsese」的「头索引递进」机制
 
-use super::NarseseFormat;
+use super::{
+    format::ATOM_QUOTE_ESCAPE, format_instances::FORMAT_ASCII, NarseseFormat, ParseHooks,
+};
 use crate::{
-    api::UIntPrecision,
-    lexical::{Budget, Narsese, Sentence, Task, Term, Truth},
+    api::{ItemKind, Located, NarseseOptions, ParsedBatch, UIntPrecision},
+    conversion::string::{
+        line_column, located_lines, AmbiguityResolution, ParseErrorKind,
+        AMBIGUOUS_QUERY_VARIABLE_MESSAGE, IMAGE_PLACEHOLDERS_MESSAGE, MAX_DEPTH_MESSAGE,
+    },
+    lexical::{Budget, Narsese, Punctuation, Sentence, Stamp, StampKind, Task, Term, Truth},
 };
 use nar_dev_utils::{PrefixMatch, StartsWithStr, SuffixMatch};
-use std::{error::Error, fmt::Display};
+use std::{
+    cell::Cell,
+    error::Error,
+    fmt::Display,
+    ops::{Bound, Deref, Range, RangeBounds},
+    sync::Arc,
+};
 
 /// 词法解析 辅助结构对象
 /// * 🚩放在一个独立的模块内，以便折叠
@@ -52,7 +64,52 @@ mod structs {
     use nar_dev_utils::matches_or;
 
     /// 定义「解析环境」：字符数组切片
-    pub(super) type ParseEnv<'a> = &'a [char];
+    /// * 🚩附带其在「理想化后的输入」中的起始索引
+    ///   * 🎯出错时据此定位到原始输入中的行号、列号
+    ///   * 📌子环境均经[`ParseEnv::slice`]截取，偏移随之累加
+    /// * 📌可解引用为字符数组切片，以使用切片的各类方法
+    #[derive(Debug, Clone, Copy, Default)]
+    pub(super) struct ParseEnv<'a> {
+        /// 字符数组切片
+        chars: &'a [char],
+        /// 在「理想化后的输入」中的起始索引
+        offset: ParseIndex,
+    }
+
+    impl<'a> ParseEnv<'a> {
+        /// 构造函数
+        /// * 🚩从「理想化后的输入」开头开始：偏移为0
+        pub fn new(chars: &'a [char]) -> Self {
+            Self { chars, offset: 0 }
+        }
+
+        /// 截取子环境
+        /// * 🚩偏移累加上子环境的起始索引
+        pub fn slice(self, range: impl RangeBounds<ParseIndex>) -> Self {
+            let start = match range.start_bound() {
+                Bound::Included(&start) => start,
+                Bound::Excluded(&start) => start + 1,
+                Bound::Unbounded => 0,
+            };
+            Self {
+                chars: &self.chars[(range.start_bound().cloned(), range.end_bound().cloned())],
+                offset: self.offset + start,
+            }
+        }
+
+        /// 获取在「理想化后的输入」中的起始索引
+        pub fn offset(self) -> ParseIndex {
+            self.offset
+        }
+    }
+
+    impl Deref for ParseEnv<'_> {
+        type Target = [char];
+
+        fn deref(&self) -> &[char] {
+            self.chars
+        }
+    }
 
     /// 定义具备所有权的「解析环境」：字符数组
     pub(super) type ParseEnvOwned = Vec<char>;
@@ -71,17 +128,24 @@ mod structs {
     ///   * 🏷️预算、词项、标点、时间戳、真值
     /// * 📌其内字段均具有所有权
     ///   * ✅均可以被直接拿取，并解析为Narsese值
-    pub(super) type MidParseResult = NarseseOptions<Budget, Term, Punctuation, Stamp, Truth>;
+    /// * 📌对外公开：可用于检视「部分输入」
+    ///   * 🎯如REPL中的自动补全：「已有词项和`%`，期待真值」
+    ///   * 🚩通过[`NarseseFormat::parse_partial`]获取
+    ///   * 📄缺失的条目参见[`NarseseOptions::missing_items`]
+    pub type MidParseResult = NarseseOptions<Budget, Term, Punctuation, Stamp, Truth>;
 
     /// 实现「可选折叠」
     /// * 📝Rust的`struct`有访问控制，但`impl`没有
     ///   * 📝同时，Rust对`type`实现方法，影响到的是此「泛型结构特化后的一个具体类型」的功能
     ///   * ⚠️【2024-06-13 22:13:27】该功能有可能被外界使用，故需要考虑版本兼容性
+    ///   * 📌现已作为公开API，签名保持稳定
     impl MidParseResult {
         /// 从「Narsese可选值」到「Narsese值」
         /// * 🎯实现最终的「词项/语句/任务」限制
         /// * ⚠️会直接递交所有权：需要取出其中的值
         /// * 🚩暂且最纯粹地实现为[`Option`]，[`Err`]生成交给调用者
+        /// * 📌优先级：任务 > 语句 > 词项
+        ///   * 📄无词项、或只有标点没有词项⇒[`None`]
         pub fn fold(self) -> Option<Narsese> {
             matches_or! {
                 // * 🚩对self进行匹配，只对给定模式返回Some，缺省情况返回None
@@ -94,7 +158,7 @@ mod structs {
                     stamp,
                     truth,
                     ..
-                } => Narsese::Task(Task {
+                } => Narsese::from_task(Task {
                     budget,
                     sentence: Sentence {
                         term,
@@ -110,7 +174,7 @@ mod structs {
                     stamp,
                     truth,
                     ..
-                } => Narsese::Sentence(Sentence {
+                } => Narsese::from_sentence(Sentence {
                     term,
                     punctuation,
                     stamp: stamp.unwrap_or(Stamp::new()),
@@ -119,11 +183,50 @@ mod structs {
                 // 词项
                 MidParseResult {
                     term: Some(term), ..
-                } => Narsese::Term(term),
+                } => Narsese::from_term(term),
             }
         }
     }
 
+    /// 定义「条目范围」
+    /// * 🎯记录各条目在「理想化后的环境」中的索引范围
+    /// * 📌词项的范围总是存在（可能为空）
+    pub(super) type ItemRanges = NarseseOptions<
+        Range<ParseIndex>,
+        Range<ParseIndex>,
+        Range<ParseIndex>,
+        Range<ParseIndex>,
+        Range<ParseIndex>,
+    >;
+
+    /// 各条目在原始输入中的**字节**范围
+    /// * 🎯语法高亮：标出预算值、词项、标点、时间戳、真值所在的区域
+    /// * 📌仅有五个顶层条目，不含嵌套词项的范围
+    /// * 📌范围不含条目之间被跳过的空白
+    #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+    pub struct Spans {
+        /// 预算值
+        pub budget: Option<Range<usize>>,
+        /// 词项
+        pub term: Range<usize>,
+        /// 标点
+        pub punctuation: Option<Range<usize>>,
+        /// 时间戳
+        pub stamp: Option<Range<usize>>,
+        /// 真值
+        pub truth: Option<Range<usize>>,
+    }
+
+    /// 附带「条目范围」的解析结果
+    /// * 🚩通过[`NarseseFormat::parse_spanned`]获取
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub struct SpannedNarsese {
+        /// 解析出的Narsese值
+        pub narsese: Narsese,
+        /// 各条目在原始输入中的字节范围
+        pub spans: Spans,
+    }
+
     /// 用于表征「解析错误」
     /// * 📝不要依赖于任何外部引用：后续需要【脱离】解析环境
     /// * 🚩【2024-03-16 21:24:22】自「枚举Narsese」迁移而来
@@ -136,6 +239,8 @@ mod structs {
         /// 裁剪出的「解析环境」切片（具有所有权）
         /// * 🎯用于展示出错范围
         /// * 🚩【2024-03-17 01:59:26】现在直接一步到位变成字符串
+        /// * ⚠️只保留开头至多[`ENV_SCOPE_MAX_CHARS`]个字符：此前保留整个剩余环境
+        ///   * 📌需要完整上下文时，以[`ParseError::line`]、[`ParseError::column`]回到原始输入中查看
         env_scope: String,
         // /// 出错所在的「解析索引」
         // /// * 🎯用于指示出错位置
@@ -143,23 +248,171 @@ mod structs {
         //   * 📌解析的方法本身已经和「头索引」无关
         //   * 【无法也没必要】给错误定位
         // index: ParseIndex,
+        /// 出错「解析环境」在「理想化后的输入」中的起始索引
+        /// * 🎯在入口处经「原始索引」映射，推算出错位置
+        /// * 📌不在解析环境中构造的错误（如输入为空）⇒[`None`]
+        env_offset: Option<ParseIndex>,
+        /// 出错所在的行号（从1开始）
+        /// * 🚩由入口函数根据原始输入补充；无法定位时为[`None`]
+        /// * 📌定位到「出错的子环境」的起始处，而非精确的出错字符
+        line: Option<usize>,
+        /// 出错所在的列号（从1开始，按字符计）
+        column: Option<usize>,
+        /// 错误的种类
+        /// * 🎯供调用方按种类分支处理
+        /// * 📌装箱以控制错误的大小：解析结果大量经由[`Result`]传递
+        kind: Box<ParseErrorKind>,
+        /// 引发此错误的内部错误
+        /// * 📄数值检验错误
+        /// * 🚩使用[`Arc`]以保持[`Clone`]
+        source: Option<Arc<dyn Error + Send + Sync>>,
     }
+    /// 错误中「环境切片」的最大字符数
+    pub(super) const ENV_SCOPE_MAX_CHARS: usize = 64;
+
     impl ParseError {
         /// 工具函数/生成「环境切片」
         /// * 🚩【2024-03-17 01:58:27】现在因为「与『头索引』概念解绑」无需再选取范围
-        fn generate_env_scope(env: ParseEnv) -> String {
-            // 直接获取所有权即可
-            String::from_iter(env.iter())
+        /// * ⚠️只截取开头至多[`ENV_SCOPE_MAX_CHARS`]个字符，超出部分以`…`表示
+        ///   * 📌解析时会尝试多种词项，每次失败都会构造错误
+        ///   * 📄若复制整个剩余环境，解析超长合取等输入时将有平方级开销（`benches/parsers.rs`）
+        fn generate_env_scope(env: &[char]) -> String {
+            match env.len() > ENV_SCOPE_MAX_CHARS {
+                true => env[..ENV_SCOPE_MAX_CHARS].iter().chain(['…'].iter()).collect(),
+                false => String::from_iter(env.iter()),
+            }
         }
 
         /// 构造函数
         /// * ⚠️【2024-03-17 01:57:33】现在不再需要
-        pub fn new(message: &str, env: ParseEnv) -> ParseError {
+        /// * 📌种类默认为「其它」：需要时使用[`Self::with_kind`]指定
+        pub fn new(message: &str, env: &[char]) -> ParseError {
             ParseError {
                 message: message.into(),
                 env_scope: ParseError::generate_env_scope(env),
+                env_offset: None,
+                line: None,
+                column: None,
+                kind: Box::new(ParseErrorKind::Other(message.into())),
+                source: None,
+            }
+        }
+
+        /// 指定错误的种类
+        pub fn with_kind(self, kind: ParseErrorKind) -> Self {
+            Self {
+                kind: Box::new(kind),
+                ..self
+            }
+        }
+
+        /// 指定出错「解析环境」的起始索引
+        /// * 📄参见[`Self::locate`]
+        pub(super) fn with_offset(self, offset: ParseIndex) -> Self {
+            Self {
+                env_offset: Some(offset),
+                ..self
+            }
+        }
+
+        /// 指定引发此错误的内部错误
+        pub fn with_source(self, source: impl Error + Send + Sync + 'static) -> Self {
+            Self {
+                source: Some(Arc::new(source)),
+                ..self
             }
         }
+
+        /// 根据「原始索引」补充行号、列号
+        /// * 🎯让错误可定位到原始（可能多行的）输入中
+        /// * 🚩出错子环境的起始索引（在「理想化后的输入」中）经`original_indices`映射回原始输入中的字符索引
+        ///   * 📌起始索引超出范围（出错于末尾）⇒定位到原始输入末尾
+        /// * 📌无起始索引（如输入为空）⇒不补充
+        pub(super) fn locate(mut self, input: &str, original_indices: &[usize]) -> Self {
+            if let (None, Some(index)) = (self.line, self.env_offset) {
+                let original = original_indices
+                    .get(index)
+                    .copied()
+                    .unwrap_or_else(|| input.chars().count());
+                let (line, column) = line_column(input.chars(), original);
+                self.line = Some(line);
+                self.column = Some(column);
+            }
+            self
+        }
+
+        /// 将行号、列号平移到「起始行号、列号」之后
+        /// * 🎯让「从更大输入中截取出来」的片段报告其在整个输入中的位置
+        /// * 📌首行的列号需加上起始列号；其余行的列号不变
+        pub(super) fn offset_by(mut self, line: usize, column: usize) -> Self {
+            if let (Some(self_line), Some(self_column)) = (&mut self.line, &mut self.column) {
+                if *self_line == 1 {
+                    *self_column += column - 1;
+                }
+                *self_line += line - 1;
+            }
+            self
+        }
+    }
+    impl ParseError {
+        /// 获取错误消息
+        pub fn message(&self) -> &str {
+            &self.message
+        }
+
+        /// 获取错误的种类
+        pub fn kind(&self) -> &ParseErrorKind {
+            &self.kind
+        }
+
+        /// 判断是否为「输入为空」错误
+        /// * 📄参见[`EMPTY_INPUT_MESSAGE`]
+        pub fn is_empty_input(&self) -> bool {
+            self.message == EMPTY_INPUT_MESSAGE
+        }
+
+        /// 判断是否为「超出最大嵌套深度」错误
+        /// * 📄参见[`MAX_DEPTH_MESSAGE`]
+        pub fn is_max_depth_exceeded(&self) -> bool {
+            self.message == MAX_DEPTH_MESSAGE
+        }
+
+        /// 判断是否为「集合词项为空」错误
+        /// * 📄参见[`EMPTY_SET_MESSAGE`]
+        pub fn is_empty_set(&self) -> bool {
+            self.message == EMPTY_SET_MESSAGE
+        }
+
+        /// 判断是否为「像中有多个占位符」错误
+        /// * 📄参见[`IMAGE_PLACEHOLDERS_MESSAGE`]
+        pub fn is_image_placeholders(&self) -> bool {
+            self.message == IMAGE_PLACEHOLDERS_MESSAGE
+        }
+
+        /// 判断是否为「查询变量与问题标点有歧义」错误
+        /// * 📄参见[`AMBIGUOUS_QUERY_VARIABLE_MESSAGE`]
+        pub fn is_ambiguous_query_variable(&self) -> bool {
+            self.message == AMBIGUOUS_QUERY_VARIABLE_MESSAGE
+        }
+
+        /// 判断是否须直接上抛，不再尝试其它类词项
+        /// * 📌超出最大嵌套深度、集合词项为空、像中有多个占位符、解析未有进展
+        pub(super) fn is_fatal(&self) -> bool {
+            self.is_max_depth_exceeded()
+                || self.is_empty_set()
+                || self.is_image_placeholders()
+                || self.message == NO_PROGRESS_MESSAGE
+        }
+
+        /// 获取出错所在的行号（从1开始）
+        pub fn line(&self) -> Option<usize> {
+            self.line
+        }
+
+        /// 获取出错所在的列号（从1开始）
+        pub fn column(&self) -> Option<usize> {
+            self.column
+        }
     }
     /// 呈现报错文本
     impl Display for ParseError {
@@ -169,10 +422,19 @@ mod structs {
                 f,
                 "Narsese解析错误：{} in {:?}",
                 self.message, self.env_scope
-            )
+            )?;
+            if let (Some(line), Some(column)) = (self.line, self.column) {
+                write!(f, " at line {line}, column {column}")?;
+            }
+            Ok(())
+        }
+    }
+    /// 实现[`Error::source`]：返回引发此错误的内部错误
+    impl Error for ParseError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            self.source.as_deref().map(|e| e as &(dyn Error + 'static))
         }
     }
-    impl Error for ParseError {}
 
     /// 词法解析状态
     /// * 🚩只持有引用，相当于一个局部变量上下文
@@ -189,6 +451,18 @@ mod structs {
         // ! 🚩【2024-03-17 01:29:17】现在不再内置于「解析状态」中
         // * 📌原因：实际推断中发现「解析状态」的「解析环境」经常会变
         //   * 💭更适合作为函数参数传递，而非
+        /// 当前的嵌套深度
+        /// * 🎯限制递归层数，防止深层嵌套的输入耗尽调用栈
+        /// * 📌使用[`Cell`]：解析方法均只持有不可变引用
+        pub depth: Cell<usize>,
+        /// 解析钩子
+        /// * 📌[`None`]⇒不调用任何钩子
+        pub hooks: Option<&'a ParseHooks>,
+        /// 「理想化」后各字符在原始输入中的字符索引
+        /// * 🎯判断条目在原始输入中是否紧邻：理想化时已删去其间的空白
+        ///   * 📄`?x ?`与`?x?`理想化后相同
+        /// * 📌[`None`]⇒视作「理想化」未删去任何字符
+        pub original_indices: Option<&'a [usize]>,
     }
     /// 通用实现 / 非「词法解析」的方法
     impl<'a> ParseState<'a> {
@@ -196,17 +470,46 @@ mod structs {
         /// * ⚠️【2024-03-17 01:30:50】不再与「解析环境」绑定
         ///   * 📌后者改为「在方法中动态传入」
         pub fn new(format: &'a NarseseFormat) -> Self {
-            Self { format }
+            Self {
+                format,
+                depth: Cell::new(0),
+                hooks: None,
+                original_indices: None,
+            }
+        }
+
+        /// 构造函数（带钩子）
+        pub fn with_hooks(format: &'a NarseseFormat, hooks: Option<&'a ParseHooks>) -> Self {
+            Self {
+                hooks,
+                ..Self::new(format)
+            }
+        }
+
+        /// 附带「原始索引」
+        /// * 📄参见[`Self::original_indices`]
+        pub fn with_original_indices(self, original_indices: &'a [usize]) -> Self {
+            Self {
+                original_indices: Some(original_indices),
+                ..self
+            }
         }
 
         /// 快速构造`ParseError`
-        pub fn parse_error(&self, env: ParseEnv, message: &str) -> ParseError {
-            ParseError::new(message, env)
+        pub fn parse_error(
+            &self,
+            env: ParseEnv,
+            kind: ParseErrorKind,
+            message: &str,
+        ) -> ParseError {
+            ParseError::new(message, &env)
+                .with_kind(kind)
+                .with_offset(env.offset())
         }
 
         /// 快速构造`Err`
-        pub fn err<T>(&self, env: ParseEnv, message: &str) -> ParseResult<T> {
-            Err(self.parse_error(env, message))
+        pub fn err<T>(&self, env: ParseEnv, kind: ParseErrorKind, message: &str) -> ParseResult<T> {
+            Err(self.parse_error(env, kind, message))
         }
     }
 }
@@ -233,44 +536,368 @@ impl<T, U> RightUnwrapOr<T, U> for Option<(T, U)> {
     }
 }
 
+/// 在「字符数组切片」上进行前缀匹配
+/// * 🎯替代[`PrefixMatch::match_prefix_char_slice`]：后者每次匹配都会将整个切片转换为字符串
+///   * 📄解析时总在「剩余环境」上反复匹配，造成平方级开销（见`benches/parsers.rs`）
+/// * 🚩逐字符比对，不分配内存；匹配顺序与原方法一致
+/// * ⚠️不使用[`StartsWithStr::starts_with_str`]：其在「环境比前缀短」时也会返回`true`
+trait MatchPrefixChars<PrefixTerm> {
+    fn match_prefix_chars(&self, env: &[char]) -> Option<&PrefixTerm>;
+}
+impl<PrefixTerm, T: PrefixMatch<PrefixTerm>> MatchPrefixChars<PrefixTerm> for T {
+    fn match_prefix_chars(&self, env: &[char]) -> Option<&PrefixTerm> {
+        self.prefix_terms().find(|&term| {
+            let mut chars = env.iter();
+            Self::get_prefix_from_term(term)
+                .chars()
+                .all(|c| chars.next() == Some(&c))
+        })
+    }
+}
+
+/// 解析错误消息/输入为空
+/// * 🎯用于快速、稳定地区分「输入为空」与「Narsese格式错误」
+///   * 📄空字串、纯空白、仅含BOM的输入
+/// * 📌此消息内容保持稳定，可供调用方比对
+pub const EMPTY_INPUT_MESSAGE: &str = "输入为空";
+
+/// 解析错误消息/集合词项为空
+/// * 📄`{}`、`[]`
+/// * 📌此消息内容保持稳定，可供调用方比对
+pub const EMPTY_SET_MESSAGE: &str = "集合词项为空";
+
+/// 解析错误消息/解析未有进展
+/// * 🎯防止「元素循环」原地打转：某次迭代未消耗任何字符⇒立即报错
+const NO_PROGRESS_MESSAGE: &str = "词项解析未消耗任何字符";
+
+/// 条目的名称
+/// * 🎯用于「重复的标点」「真值位置错误」等报错信息
+fn item_name(kind: ItemKind) -> &'static str {
+    match kind {
+        ItemKind::Budget => "预算值",
+        ItemKind::Term => "词项",
+        ItemKind::Punctuation => "标点",
+        ItemKind::Stamp => "时间戳",
+        ItemKind::Truth => "真值",
+    }
+}
+
+/// 判断输入是否「为空」
+/// * 🚩仅由空白符与BOM（`\u{feff}`）组成⇒为空
+fn is_empty_input(input: &str) -> bool {
+    input.chars().all(|c| c.is_whitespace() || c == '\u{feff}')
+}
+
+/// 按「主分隔符+备选分隔符」拆分字符串
+/// * 🎯真值、预算值内部的拆分：`1.0,0.9;0.5` ⇒ `["1.0", "0.9", "0.5"]`
+/// * 🚩任一分隔符均可，允许混用；同一位置优先匹配主分隔符
+fn split_separators<'s>(
+    s: &'s str,
+    separator: &'s str,
+    alternates: &'s [String],
+) -> impl Iterator<Item = &'s str> + 's {
+    let separators = std::iter::once(separator)
+        .chain(alternates.iter().map(String::as_str))
+        .filter(|separator| !separator.is_empty());
+    let mut rest = Some(s);
+    std::iter::from_fn(move || {
+        let current = rest?;
+        let found = current.char_indices().find_map(|(i, _)| {
+            separators
+                .clone()
+                .find(|separator| current[i..].starts_with(separator))
+                .map(|separator| (i, separator.len()))
+        });
+        match found {
+            Some((i, len)) => {
+                rest = Some(&current[i + len..]);
+                Some(&current[..i])
+            }
+            None => rest.take(),
+        }
+    })
+}
+
 /// 总入口
 /// * 🚩构造「解析状态」然后转发到「解析状态的实例方法」中去
 pub fn parse(format: &NarseseFormat, input: &str) -> ParseResult {
+    parse_hooked(format, None, input)
+}
+
+/// 总入口（带钩子）
+/// * 📄[`super::ParserWithHooks::parse`]
+pub(super) fn parse_hooked(
+    format: &NarseseFormat,
+    hooks: Option<&ParseHooks>,
+    input: &str,
+) -> ParseResult {
+    // 去除注释 | 整行注释⇒输入为空
+    let input = format.strip_comment(input);
+    // 预先判空 | 🎯输入为空时，不进行后续解析
+    if is_empty_input(input) {
+        return Err(ParseError::new(EMPTY_INPUT_MESSAGE, &[]).with_kind(ParseErrorKind::EmptyInput));
+    }
     // 「理想化」构造解析状态
     // ! 📌此处「理想化」必须在构造之前，否则很难修改
-    let chars = idealize_env(format, input);
-    let mut state = ParseState::new(format);
-    // 用状态进行解析
-    state.parse(&chars)
+    let (chars, original_indices) = idealize_env_indexed(format, input);
+    let mut state = ParseState::with_hooks(format, hooks).with_original_indices(&original_indices);
+    // 用状态进行解析 | 出错时补充行号、列号
+    state
+        .parse(ParseEnv::new(&chars))
+        .map_err(|error| error.locate(input, &original_indices))
     // ! 随后丢弃状态
 }
 
+/// 入口/部分解析
+/// * 🎯获取「中间结果」，而不折叠为Narsese值
+///   * 📄REPL自动补全：`<A --> B>. %`⇒已有词项、标点，期待真值
+/// * 📌不要求词项存在：`$0.5$`⇒只有预算值
+/// * ❌词项解析出错时，仍然返回错误
+pub fn parse_partial(format: &NarseseFormat, input: &str) -> ParseResult<MidParseResult> {
+    // 去除注释 | 整行注释⇒输入为空
+    let input = format.strip_comment(input);
+    // 预先判空
+    if is_empty_input(input) {
+        return Err(ParseError::new(EMPTY_INPUT_MESSAGE, &[]).with_kind(ParseErrorKind::EmptyInput));
+    }
+    // 「理想化」构造解析状态
+    let (chars, original_indices) = idealize_env_indexed(format, input);
+    let mut state = ParseState::new(format).with_original_indices(&original_indices);
+    // 只解析出「中间结果」 | 出错时补充行号、列号
+    state
+        .parse_items(ParseEnv::new(&chars))
+        .map_err(|error| error.locate(input, &original_indices))
+}
+
+/// 入口/附带条目范围
+/// * 🎯语法高亮：获知各条目在原始输入中的字节范围
+/// * 🚩同[`parse`]，但保留切分时得到的各条目边界
+///   * 📌「理想化」时删去的空白经原始索引映射回原始输入
+pub fn parse_spanned(format: &NarseseFormat, input: &str) -> ParseResult<SpannedNarsese> {
+    // 去除注释 | 整行注释⇒输入为空
+    // * 📌去除注释后的输入是原始输入的前缀：字节偏移不变
+    let input = format.strip_comment(input);
+    // 预先判空
+    if is_empty_input(input) {
+        return Err(ParseError::new(EMPTY_INPUT_MESSAGE, &[]).with_kind(ParseErrorKind::EmptyInput));
+    }
+    // 「理想化」构造解析状态
+    let (chars, original_indices) = idealize_env_indexed(format, input);
+    let mut state = ParseState::new(format).with_original_indices(&original_indices);
+    let (narsese, ranges) = state
+        .parse_ranged(ParseEnv::new(&chars))
+        .map_err(|error| error.locate(input, &original_indices))?;
+    // 「理想化」后的索引范围 ⇒ 原始输入中的字节范围
+    let byte_offsets = input
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain([input.len()])
+        .collect::<Vec<_>>();
+    let to_bytes = |range: Range<ParseIndex>| {
+        let start = original_indices
+            .get(range.start)
+            .map_or(input.len(), |&i| byte_offsets[i]);
+        match range.is_empty() {
+            true => start..start,
+            false => start..byte_offsets[original_indices[range.end - 1] + 1],
+        }
+    };
+    let spans = Spans {
+        budget: ranges.budget.map(to_bytes),
+        term: ranges.term.map_or(0..0, to_bytes),
+        punctuation: ranges.punctuation.map(to_bytes),
+        stamp: ranges.stamp.map(to_bytes),
+        truth: ranges.truth.map(to_bytes),
+    };
+    Ok(SpannedNarsese { narsese, spans })
+}
+
+/// 入口/宽松解析条目
+/// * 🎯编辑器集成：词项出错时，仍然给出标点、真值等其它条目
+///   * 📄`<A --> >.`⇒无词项，但有标点`.`
+/// * 🚩出错的条目留空，错误附带行号、列号
+pub fn parse_items_lenient(
+    format: &NarseseFormat,
+    input: &str,
+) -> (
+    NarseseOptions<Budget, Term, Punctuation, Stamp, Truth>,
+    Vec<ParseError>,
+) {
+    // 去除注释 | 整行注释⇒输入为空
+    let input = format.strip_comment(input);
+    // 预先判空
+    if is_empty_input(input) {
+        return (
+            MidParseResult::new(),
+            vec![ParseError::new(EMPTY_INPUT_MESSAGE, &[]).with_kind(ParseErrorKind::EmptyInput)],
+        );
+    }
+    // 「理想化」构造解析状态
+    let (chars, original_indices) = idealize_env_indexed(format, input);
+    let mut state = ParseState::new(format).with_original_indices(&original_indices);
+    let (mid_result, errors) = state.parse_items_lenient(ParseEnv::new(&chars));
+    let errors = errors
+        .into_iter()
+        .map(|error| error.locate(input, &original_indices))
+        .collect();
+    (mid_result, errors)
+}
+
 /// 入口/词项
 /// * 🚩单独解析出一个「词项」
 pub fn parse_term(format: &NarseseFormat, input: &str) -> ParseResult<Term> {
+    parse_term_hooked(format, None, input)
+}
+
+/// 入口/词项（带钩子）
+/// * 📄[`super::ParserWithHooks::parse_term`]
+pub(super) fn parse_term_hooked(
+    format: &NarseseFormat,
+    hooks: Option<&ParseHooks>,
+    input: &str,
+) -> ParseResult<Term> {
     // 构造解析状态
-    let state = ParseState::new(format);
+    let state = ParseState::with_hooks(format, hooks);
     // 封装「解析状态」的入口
     state.parse_term(input)
     // ! 随后丢弃状态
 }
 
+/// 入口/前缀
+/// * 🎯从输入开头解析出**一个**Narsese值，并报告其所消耗的长度
+///   * 📄用于在更大的语法中嵌入Narsese：`believe(<A --> B>., 0.9)`
+/// * 🚩其后的非Narsese内容不视作错误
+/// * ⚙️返回「Narsese值」及「首个未消耗字符」在原始输入中的**字节**偏移
+pub fn parse_prefix(format: &NarseseFormat, input: &str) -> ParseResult<(Narsese, usize)> {
+    // 预先判空
+    if is_empty_input(input) {
+        return Err(ParseError::new(EMPTY_INPUT_MESSAGE, &[]).with_kind(ParseErrorKind::EmptyInput));
+    }
+    // 「理想化」构造解析状态
+    let (chars, original_indices) = idealize_env_indexed(format, input);
+    let mut state = ParseState::new(format).with_original_indices(&original_indices);
+    let (narsese, end) = state
+        .parse_prefix(ParseEnv::new(&chars))
+        .map_err(|error| error.locate(input, &original_indices))?;
+    // 「理想化」后的索引 ⇒ 原始输入中的字符索引 ⇒ 字节偏移
+    // * 📌取「最后一个已消耗字符」之后的位置，不计入其后的空白
+    let end_char = match end {
+        0 => 0,
+        _ => original_indices[end - 1] + 1,
+    };
+    let end_byte = input
+        .char_indices()
+        .nth(end_char)
+        .map_or(input.len(), |(i, _)| i);
+    Ok((narsese, end_byte))
+}
+
+/// PyNARS兼容模式/原子词项引号
+const PYNARS_QUOTE: char = '\'';
+
+/// PyNARS兼容模式/前缀否定
+/// * 📌与ASCII格式的「否定」连接符一致
+const PYNARS_NEGATION: &str = "--";
+
 /// 预处理/理想化
 /// * 📌将一个「字符串」进行「理想化」以便后续解析
 /// * 🎯用于「预处理删去空格」这一类情况
 ///   * ❗每个`&str`字符串在被解析之前，都要经过此处解析
+/// * 📌正式入口使用[`idealize_env_indexed`]，此处仅供测试
+#[cfg(test)]
 fn idealize_env(format: &NarseseFormat, input: &str) -> ParseEnvOwned {
-    // 获取字符迭代器
-    let chars = input.chars();
-    // 对「字符迭代器」进行处理 | 不能提取`.collect::<ParseEnvOwned>()`，因为其所应用的类型不一致
+    idealize_env_indexed(format, input).0
+}
+
+/// 预处理/理想化（附带原始索引）
+/// * 🚩在[`idealize_env`]的基础上，记录每个保留字符在原始输入中的字符索引
+///   * 🎯用于在出错时定位「原始输入中的行号、列号」
+fn idealize_env_indexed(format: &NarseseFormat, input: &str) -> (ParseEnvOwned, Vec<usize>) {
+    // 标记「原子词项引号」内的字符 | 其中的空格须保留
+    let chars = input.chars().collect::<Vec<_>>();
+    let quoted = quoted_mask(format, &chars);
+    // 获取「带索引的字符迭代器」
+    let chars = chars.into_iter().enumerate();
+    // 对「字符迭代器」进行处理
     match format.space.remove_spaces_before_parse {
+        // PyNARS兼容模式⇒预删去空格，但保留单引号内的空格
+        true if format.pynars_compat => {
+            let mut in_quote = false;
+            chars
+                .filter(|&(i, c)| {
+                    if c == PYNARS_QUOTE && !quoted[i] {
+                        in_quote = !in_quote;
+                    }
+                    in_quote || quoted[i] || !(format.space.is_for_parse)(c)
+                })
+                .map(|(i, c)| (c, i))
+                .unzip()
+        }
         // 预删去空格
         true => chars
-            .filter(|&c| !(format.space.is_for_parse)(c))
-            .collect::<ParseEnvOwned>(),
+            .filter(|&(i, c)| quoted[i] || !(format.space.is_for_parse)(c))
+            .map(|(i, c)| (c, i))
+            .unzip(),
         // 不删去空格
-        false => chars.collect::<ParseEnvOwned>(),
+        false => chars.map(|(i, c)| (c, i)).unzip(),
+    }
+}
+
+/// 工具函数：获取「原子词项引号」的字符数组
+/// * 📌未启用、或有空引号⇒[`None`]
+fn quote_chars(format: &NarseseFormat) -> Option<(Vec<char>, Vec<char>)> {
+    let (left, right) = format.atom.quotes.as_ref()?;
+    match left.is_empty() || right.is_empty() {
+        true => None,
+        false => Some((left.chars().collect(), right.chars().collect())),
+    }
+}
+
+/// 工具函数：扫描「引号原子名称」
+/// * 📌`env`须以左引号开头
+/// * 🚩遇到转义符⇒原样收入下一个字符
+/// * ⚙️返回「反转义后的名称」与「含右引号的右边界」；未闭合⇒[`None`]
+fn scan_quoted(env: &[char], (left, right): (&[char], &[char])) -> Option<(String, ParseIndex)> {
+    let mut name = String::new();
+    let mut i = left.len();
+    while i < env.len() {
+        match env[i] {
+            ATOM_QUOTE_ESCAPE => {
+                // 转义符在末尾⇒下一轮退出循环，视作未闭合
+                name.extend(env.get(i + 1));
+                i += 2;
+            }
+            _ if env[i..].starts_with(right) => return Some((name, i + right.len())),
+            c => {
+                name.push(c);
+                i += 1;
+            }
+        }
+    }
+    None
+}
+
+/// 工具函数：标记处在「原子词项引号」内的字符（含引号本身）
+/// * 🎯预处理时保留引号内的空白
+/// * 📌未闭合的引号⇒一直标记到末尾（随后在解析时报错）
+fn quoted_mask(format: &NarseseFormat, chars: &[char]) -> Vec<bool> {
+    let mut mask = vec![false; chars.len()];
+    let Some((left, right)) = quote_chars(format) else {
+        return mask;
+    };
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i..].starts_with(&left) {
+            true => {
+                let end = scan_quoted(&chars[i..], (&left, &right))
+                    .map_or(chars.len(), |(_, len)| i + len);
+                mask[i..end].fill(true);
+                i = end;
+            }
+            false => i += 1,
+        }
     }
+    mask
 }
 
 /// 开始在「解析状态」的基础上进行解析
@@ -283,11 +910,31 @@ impl ParseState<'_> {
         // 先解析出「中间结果」
         let mid_result = self.parse_items(env)?;
         // 再折叠「中间结果」得到最终情况
+        self.fold_items(env, mid_result)
+    }
+
+    /// 附带条目范围的解析入口
+    /// * 🚩同[`Self::parse`]，但一并返回各条目在`env`中的索引范围
+    pub fn parse_ranged(&mut self, env: ParseEnv) -> ParseResult<(Narsese, ItemRanges)> {
+        let (mut mid_result, ranges) = self.segment_items_ranged(env);
+        let env_term = env.slice(ranges.term.clone().unwrap_or_default());
+        mid_result.term = self.parse_items_term(env_term, &mid_result)?;
+        self.resolve_query_variable_ambiguity(env, &mut mid_result, &ranges)?;
+        Ok((self.fold_items(env, mid_result)?, ranges))
+    }
+
+    /// 折叠「中间结果」
+    /// * 🚩缺少词项等必要条目⇒报错
+    /// * 🚩有钩子⇒对折叠结果调用钩子
+    fn fold_items(&self, env: ParseEnv, mid_result: MidParseResult) -> ParseResult {
         match mid_result.fold() {
             // 解析出了结果⇒返回最终结果
-            Some(result) => Ok(result),
+            Some(result) => Ok(match self.hooks {
+                Some(hooks) => hooks.apply_narsese(result),
+                None => result,
+            }),
             // 没有解析出结果⇒返回错误
-            None => self.err(env, "缺省条目，无法解析成词项/语句/任务"),
+            None => self.err(env, ParseErrorKind::MissingTerm, "缺省条目，无法解析成词项/语句/任务"),
         }
     }
 
@@ -298,48 +945,329 @@ impl ParseState<'_> {
     /// * 📄从「中间结果」到「Narsese值」参见
     /// * ⚠️注意：「没解析到」和「解析时出错」是不一样的
     ///   * 比如「没解析到预算值」也可以是如`$A.`的情况
-    fn parse_items(&mut self, env: ParseEnv) -> ParseResult<MidParseResult> {
+    /// * 📌不要求词项存在：缺失的条目均为[`None`]
+    pub fn parse_items(&mut self, env: ParseEnv) -> ParseResult<MidParseResult> {
+        let (mut mid_result, ranges) = self.segment_items_ranged(env);
+        let env_term = env.slice(ranges.term.clone().unwrap_or_default());
+        mid_result.term = self.parse_items_term(env_term, &mid_result)?;
+        self.resolve_query_variable_ambiguity(env, &mut mid_result, &ranges)?;
+        Ok(mid_result)
+    }
+
+    /// 消解「查询变量」与「问题标点」的歧义
+    /// * 🎯问题标点亦为变量前缀时（如ASCII的`?`）
+    ///   * 📄`?x?`：首个`?`归属不明
+    ///   * 📄`?x`：查询变量，或「标点在前」的问题`x?`
+    /// * 🚩按格式的消歧策略处理
+    ///   * 偏向变量⇒不变
+    ///   * 偏向标点⇒无标点的`?x`改为「以词语提问」
+    ///   * 要求显式⇒无标点，或问题标点紧跟在变量之后⇒报错
+    /// * 📄参见[`AmbiguityResolution`]
+    fn resolve_query_variable_ambiguity(
+        &self,
+        env: ParseEnv,
+        mid_result: &mut MidParseResult,
+        ranges: &ItemRanges,
+    ) -> ParseResult<()> {
+        let question = &self.format.sentence.punctuation_question;
+        let name = match &mid_result.term {
+            Some(Term::Atom { prefix, name })
+                if prefix == question && self.format.atom.prefix_variables.contains(prefix) =>
+            {
+                name
+            }
+            _ => return Ok(()),
+        };
+        match (self.format.ambiguity_resolution, &mid_result.punctuation) {
+            // 无标点
+            (AmbiguityResolution::PreferPunctuation, None) => {
+                let term = Term::new_atom(&self.format.atom.prefix_word, name);
+                mid_result.term = Some(match self.hooks {
+                    Some(hooks) => hooks.apply_term(term),
+                    None => term,
+                });
+                mid_result.punctuation = Some(question.clone());
+                Ok(())
+            }
+            (AmbiguityResolution::RequireExplicit, None) => self.err(
+                env.slice(ranges.term.clone().unwrap_or_default()),
+                ParseErrorKind::Ambiguous,
+                AMBIGUOUS_QUERY_VARIABLE_MESSAGE,
+            ),
+            // 问题标点紧跟在变量之后
+            (AmbiguityResolution::RequireExplicit, Some(punctuation)) if punctuation == question => {
+                let start = ranges.punctuation.clone().unwrap_or_default().start;
+                let adjacent = start > 0
+                    && !env[start - 1].is_whitespace()
+                    && self
+                        .original_indices
+                        .is_none_or(|indices| indices[start] == indices[start - 1] + 1);
+                match adjacent {
+                    true => self.err(
+                        env.slice(start - 1..),
+                        ParseErrorKind::Ambiguous,
+                        AMBIGUOUS_QUERY_VARIABLE_MESSAGE,
+                    ),
+                    false => Ok(()),
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// 宽松解析过程
+    /// * 🎯编辑器集成：词项出错时，仍然给出其它条目
+    /// * 🚩同[`Self::parse_items`]，但词项出错时留空并记录错误
+    ///   * 📌词法Narsese中，只有词项的解析会出错：真值、时间戳、预算值仅作切分
+    pub fn parse_items_lenient(&mut self, env: ParseEnv) -> (MidParseResult, Vec<ParseError>) {
+        let (mut mid_result, ranges) = self.segment_items_ranged(env);
+        let env_term = env.slice(ranges.term.clone().unwrap_or_default());
+        let mut errors = vec![];
+        match self.parse_items_term(env_term, &mid_result) {
+            Ok(term) => mid_result.term = term,
+            Err(error) => errors.push(error),
+        }
+        if let Err(error) = self.resolve_query_variable_ambiguity(env, &mut mid_result, &ranges) {
+            errors.push(error);
+        }
+        (mid_result, errors)
+    }
+
+    /// 切分出词项以外的条目，并记录各条目的索引范围
+    /// * ⚙️返回「词项为空」的「中间结果」，以及各条目（含词项）在`env`中的范围
+    fn segment_items_ranged(&self, env: ParseEnv) -> (MidParseResult, ItemRanges) {
+        let mut ranges = ItemRanges::new();
+
         // 前缀切割出预算值 //
-        let budget = self.segment_budget(env);
+        let budget = self.segment_budget(&env);
         // 默认值 "" | 词项的起始索引（含）
         let (budget, begin_index) = budget.right_unwrap_or(0);
+        ranges.budget = budget.as_ref().map(|_| 0..begin_index);
 
         // 后缀连续切割出真值、时间戳、标点 //
-        let truth = self.segment_truth(env);
+        let truth = self.segment_truth(&env);
         // 默认值 "" | 时间戳的索引上界（不含）
         let (truth, right_border) = truth.right_unwrap_or(env.len());
+        ranges.truth = truth.as_ref().map(|_| right_border..env.len());
 
         // 时间戳
         let stamp = self.segment_stamp(&env[..right_border]);
         // 默认值 "" | 标点的索引上界（不含）
-        let (stamp, right_border) = stamp.right_unwrap_or(right_border);
+        let (stamp, stamp_border) = stamp.right_unwrap_or(right_border);
+        ranges.stamp = stamp.as_ref().map(|_| stamp_border..right_border);
+        let right_border = stamp_border;
+
+        // PyNARS兼容模式：真值可能在时间戳之前
+        let (truth, right_border) = match truth {
+            None if self.format.pynars_compat => {
+                let (truth, truth_border) = self
+                    .segment_truth(&env[..right_border])
+                    .right_unwrap_or(right_border);
+                ranges.truth = truth.as_ref().map(|_| truth_border..right_border);
+                (truth, truth_border)
+            }
+            _ => (truth, right_border),
+        };
 
         // 标点
         let punctuation = self.segment_punctuation(&env[..right_border]);
         // 默认值 "" | 词项的索引上界（不含）
-        let (punctuation, right_border) = punctuation.right_unwrap_or(right_border);
-
-        // 前后缀切割完毕，最后解析出词项 //
-        // 获得「词项」的「字符数组切片」
-        let env_term = &env[begin_index..right_border];
-
-        // 开始解析词项
-        let term = match begin_index < right_border {
-            // 在此提取词项
-            // ! 解析过程出错，仍然上报错误
-            true => Some(self.segment_term(env_term)?.0),
-            // ! 🚩不再上抛错误，而是诚实反馈「解析失败」
-            false => None,
-        };
+        let (punctuation, punctuation_border) = punctuation.right_unwrap_or(right_border);
+        ranges.punctuation = punctuation
+            .as_ref()
+            .map(|_| punctuation_border..right_border);
+        let right_border = punctuation_border;
 
         // 构造「中间结果」 //
-        Ok(MidParseResult {
-            term,
+        let mid_result = MidParseResult {
+            term: None,
             truth,
             stamp,
             punctuation,
             budget,
-        })
+        };
+        // 前后缀切割完毕，剩下的即为词项 //
+        // * 📌预算值与标点重叠（如`$A.`）⇒词项为空
+        ranges.term = Some(begin_index.min(right_border)..right_border);
+        (mid_result, ranges)
+    }
+
+    /// 解析「切分后剩余」的词项
+    /// * 🚩先检查「未闭合的括弧」，避免被当作词项的一部分去解析
+    /// * 📌为空⇒[`None`]：诚实反馈「解析失败」，而不上抛错误
+    /// * 🚩词项未能占满整个区间⇒检查「重复/错位的条目」
+    ///   * 📄`<A-->B>..`：重复的标点
+    ///   * 📄`%1.0% <A-->B>.`：真值位置错误
+    fn parse_items_term(
+        &mut self,
+        env_term: ParseEnv,
+        mid_result: &MidParseResult,
+    ) -> ParseResult<Option<Term>> {
+        // 检查「未闭合的括弧」 | 避免被当作词项的一部分去解析
+        self.verify_brackets_closed(env_term)?;
+        // ! 🚩为空时不再上抛错误，而是诚实反馈「解析失败」
+        if env_term.is_empty() {
+            return Ok(None);
+        }
+        // 在此提取词项
+        let result = self.segment_term(env_term);
+        if matches!(result, Ok((_, len)) if len == env_term.len()) {
+            return Ok(result.ok().map(|(term, _)| term));
+        }
+        // 词项之前有条目 | 如「真值在前」
+        self.verify_items_before_term(env_term)?;
+        // ! 解析过程出错，仍然上报错误
+        let (term, len) = result?;
+        // 词项之后有条目 | 如「重复的标点」
+        self.verify_items_after_term(env_term.slice(len..), mid_result)?;
+        // 其它残留内容⇒照旧忽略
+        Ok(Some(term))
+    }
+
+    /// 检查「词项之前的条目」
+    /// * 🎯真值、时间戳、标点须在词项之后
+    ///   * 📄`%1.0% <A-->B>.`
+    /// * 🚩词项区间以「完整的条目」开头，且其后仍有内容⇒报错「位置错误」
+    fn verify_items_before_term(&self, env: ParseEnv) -> ParseResult<()> {
+        match self.segment_item_prefix(&env) {
+            Some((kind, len)) if len < env.len() => {
+                self.err(env.slice(..len), ParseErrorKind::UnexpectedChar, &format!("{}位置错误", item_name(kind)))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// 检查「词项之后的条目」
+    /// * 🎯词项之后残留完整的条目：重复或错位
+    ///   * 📄`<A-->B>.. %1.0%`：重复的标点
+    ///   * 📄`<A-->B>. %1.0% %1.0%`：重复的真值
+    ///   * 📄`<A-->B> %1.0%.`：真值位置错误（应在标点之后）
+    /// * 🚩将残留内容逐个切分为条目
+    ///   * 与已切分出的条目（或残留中先前的条目）同类⇒「重复的…」
+    ///   * 否则⇒首个条目「位置错误」
+    /// * 📌残留内容并非全由条目组成⇒不在此报错
+    fn verify_items_after_term(&self, env: ParseEnv, mid_result: &MidParseResult) -> ParseResult<()> {
+        let mut items = vec![];
+        let mut start = 0;
+        while start < env.len() {
+            match self.segment_item_prefix(&env[start..]) {
+                Some((kind, len)) => {
+                    items.push((kind, start..start + len));
+                    start += len;
+                }
+                None => return Ok(()),
+            }
+        }
+        // 重复的条目
+        for (i, (kind, range)) in items.iter().enumerate() {
+            if mid_result.has_item(*kind) || items[..i].iter().any(|(k, _)| k == kind) {
+                return self.err(env.slice(range.clone()), ParseErrorKind::DuplicateItem, &format!("重复的{}", item_name(*kind)));
+            }
+        }
+        // 错位的条目
+        match items.first() {
+            Some((kind, range)) => {
+                self.err(env.slice(range.clone()), ParseErrorKind::UnexpectedChar, &format!("{}位置错误", item_name(*kind)))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// 前缀截取「词项以外的条目」
+    /// * 🚩依次尝试标点、真值、时间戳、预算值
+    /// * ⚙️返回条目种类及其长度
+    fn segment_item_prefix(&self, env: &[char]) -> Option<(ItemKind, ParseIndex)> {
+        let sentence = &self.format.sentence;
+        if let Some(punctuation) = sentence.punctuations.match_prefix_chars(env) {
+            return Some((ItemKind::Punctuation, punctuation.chars().count()));
+        }
+        if let Some((_, len)) =
+            self.segment_brackets_prefix(env, &sentence.truth_brackets, sentence.is_truth_content)
+        {
+            return Some((ItemKind::Truth, len));
+        }
+        if let Some(len) = self.segment_stamp_prefix(env) {
+            return Some((ItemKind::Stamp, len));
+        }
+        self.segment_budget(env)
+            .map(|(_, len)| (ItemKind::Budget, len))
+    }
+
+    /// 前缀解析入口
+    /// * 🎯只解析开头的一个Narsese值，忽略其后的内容
+    /// * 🚩先「向后」确定Narsese值的右边界，再在边界内按原流程解析
+    ///   * 📌真值、时间戳、标点的「后缀截取」不会扫描到边界之外的内容
+    /// * ⚙️返回Narsese值与其（在「解析环境」中的）右边界
+    pub fn parse_prefix(&mut self, env: ParseEnv) -> ParseResult<(Narsese, ParseIndex)> {
+        let end = self.segment_prefix_end(env)?;
+        Ok((self.parse(env.slice(..end))?, end))
+    }
+
+    /// 向后确定「前缀Narsese值」的右边界
+    /// * 🚩预算值 ⇒ 词项 ⇒ 标点 ⇒ 时间戳 ⇒ 真值
+    ///   * 📌时间戳、真值须跟在标点之后；PyNARS兼容模式下二者顺序可互换
+    /// * ❌词项解析失败⇒报错
+    fn segment_prefix_end(&self, env: ParseEnv) -> ParseResult<ParseIndex> {
+        let sentence = &self.format.sentence;
+        // 预算值
+        let (_, mut end) = self.segment_budget(&env).right_unwrap_or(0);
+        // 词项
+        end += self.segment_term(env.slice(end..))?.1;
+        // 标点 | 无⇒到此为止
+        let punctuation = match sentence.punctuations.match_prefix_chars(&env[end..]) {
+            Some(punctuation) => punctuation,
+            None => return Ok(end),
+        };
+        end += punctuation.chars().count();
+        // 时间戳、真值
+        let truth_end = |start: ParseIndex| {
+            self.segment_brackets_prefix(
+                &env[start..],
+                &sentence.truth_brackets,
+                sentence.is_truth_content,
+            )
+            .map_or(start, |(_, len)| start + len)
+        };
+        let stamp_end = |start: ParseIndex| {
+            self.segment_stamp_prefix(&env[start..])
+                .map_or(start, |len| start + len)
+        };
+        end = match (stamp_end(end), self.format.pynars_compat) {
+            // PyNARS兼容模式：真值可能在时间戳之前
+            (after_stamp, true) if after_stamp == end => stamp_end(truth_end(end)),
+            (after_stamp, _) => truth_end(after_stamp),
+        };
+        Ok(end)
+    }
+
+    /// 前缀截取时间戳
+    /// * 🎯[`Self::segment_stamp`]的「向后」版本
+    /// * 🚩逐个尝试时间戳括弧
+    ///   * 📌右括弧为空（如LaTeX的`t=`）⇒尽可能多地截取时间戳内容
+    /// * ⚙️返回时间戳的长度
+    fn segment_stamp_prefix(&self, env: &[char]) -> Option<ParseIndex> {
+        let sentence = &self.format.sentence;
+        sentence
+            .stamp_brackets
+            .iter_terms()
+            .find_map(|(left, right)| {
+                let left = left.chars().collect::<Vec<_>>();
+                let right = right.chars().collect::<Vec<_>>();
+                if !env.starts_with(&left) {
+                    return None;
+                }
+                match right.is_empty() {
+                    true => {
+                        let end = self.collect_some_prefix(env, left.len(), |_, c| {
+                            (sentence.is_stamp_content)(c)
+                        });
+                        (end > left.len()).then_some(end)
+                    }
+                    false => self
+                        .segment_some_prefix(env, left.len(), &right, sentence.is_stamp_content)
+                        .ok(),
+                }
+            })
     }
 
     /// 🛠️工具函数/在环境中从某处索引截取字符序列
@@ -358,9 +1286,9 @@ impl ParseState<'_> {
     #[inline(always)]
     fn segment_some_prefix(
         &self,
-        env: ParseEnv,
+        env: &[char],
         start: ParseIndex,
-        right_chars: ParseEnv,
+        right_chars: &[char],
         verify_char: impl Fn(char) -> bool,
     ) -> Result<ParseIndex, ParseIndex> {
         // 自动计算长度
@@ -400,7 +1328,7 @@ impl ParseState<'_> {
     #[inline(always)]
     fn collect_some_prefix(
         &self,
-        env: ParseEnv,
+        env: &[char],
         start: ParseIndex,
         verify: impl Fn(ParseIndex, char) -> bool,
     ) -> ParseIndex {
@@ -435,8 +1363,8 @@ impl ParseState<'_> {
     #[inline(always)]
     fn segment_some_suffix(
         &self,
-        env: ParseEnv,
-        left_chars: ParseEnv,
+        env: &[char],
+        left_chars: &[char],
         verify_char: impl Fn(char) -> bool,
     ) -> Result<ParseIndex, ParseIndex> {
         // 自动计算长度，然后从末尾开始
@@ -480,7 +1408,7 @@ impl ParseState<'_> {
     /// ```no-test
     /// fn segment_brackets_prefix<S: Deref<Target = str>>(
     ///    &self,
-    ///    env: ParseEnv,
+    ///    env: &[char],
     ///    brackets: impl PrefixMatch<(S, S)>,
     ///    verify_char: impl Fn(char) -> bool,
     ///) -> Option<(String, ParseIndex)>
@@ -493,12 +1421,12 @@ impl ParseState<'_> {
     /// * 📝【2024-03-19 00:15:02】似乎`rust,no-test`在此又失效了
     fn segment_brackets_prefix(
         &self,
-        env: ParseEnv,
+        env: &[char],
         brackets: &impl PrefixMatch<(String, String)>,
         verify_char: impl Fn(char) -> bool,
     ) -> Option<(String, ParseIndex)> {
         // 尝试前缀匹配
-        let (left, right) = brackets.match_prefix_char_slice(env)?;
+        let (left, right) = brackets.match_prefix_chars(env)?;
 
         // 匹配成功⇒将右括弧变成字符数组 | 字符数组不能直接与「静态字串」比对
         let right_chars = right.chars().collect::<Vec<_>>();
@@ -525,7 +1453,7 @@ impl ParseState<'_> {
     ///   * 📄例如：`("abc", start = 1)` ⇒ `(&"abc"[..2])`
     fn segment_brackets_suffix(
         &self,
-        env: ParseEnv,
+        env: &[char],
         brackets: &impl SuffixMatch<(String, String)>,
         verify_char: impl Fn(char) -> bool,
     ) -> Option<(String, ParseIndex)> {
@@ -565,7 +1493,7 @@ impl ParseState<'_> {
     ///     * 🎯返回并直接使用「词项部分」的开头索引，同时也无需做「-1」偏移
     /// * 📄匹配的环境如：`$0.5;0.5;0.5$<A-->B>.%1.0;0.9%`
     /// * 📄匹配的结果如：`Some(("$0.5;0.5;0.5$", 12))` | `12` 对应第二个`$`
-    fn segment_budget(&self, env: ParseEnv) -> Option<(Budget, ParseIndex)> {
+    fn segment_budget(&self, env: &[char]) -> Option<(Budget, ParseIndex)> {
         // * 📌至于「解析出『vec![".9"]』和『vec!["0.9"]』之后，如何能判等」的问题：不应该以这里的「词法Narsese」作为判等依据
         // 尝试前缀匹配
         let (budget_string, right_border) = self.segment_brackets_prefix(
@@ -581,11 +1509,14 @@ impl ParseState<'_> {
         // * 🚩【2024-03-22 20:13:04】目前专注上层，不再细写字串分割逻辑了
         // * 🚩【2024-03-24 02:57:17】此处的空字串必须被过滤掉，以便让`$$`等价于`[]`而非`[""]`
         Some((
-            budget_string
-                .split(&self.format.task.budget_separator)
-                .filter(|s| !s.is_empty())
-                .map(str::to_owned)
-                .collect::<Budget>(),
+            split_separators(
+                budget_string,
+                &self.format.task.budget_separator,
+                &self.format.task.budget_separator_alternates,
+            )
+            .filter(|s| !s.is_empty())
+            .map(str::to_owned)
+            .collect::<Budget>(),
             right_border,
         ))
     }
@@ -597,7 +1528,7 @@ impl ParseState<'_> {
     ///   * 📌要么返回「匹配到的完整真值，以及其在『解析环境』中的开头位置（用于切分时间戳）」
     /// * 📄匹配的环境如：`$0.5;0.5;0.5$<A-->B>.%1.0;0.9%`
     /// * 📄匹配的结果如：`Some(("$0.5;0.5;0.5$", 21))` | `21` 对应第一个`%`
-    fn segment_truth(&self, env: ParseEnv) -> Option<(Truth, ParseIndex)> {
+    fn segment_truth(&self, env: &[char]) -> Option<(Truth, ParseIndex)> {
         // 尝试后缀匹配
         let (truth_string, right_border) = self.segment_brackets_suffix(
             env,
@@ -613,12 +1544,15 @@ impl ParseState<'_> {
         // * 🚩【2024-03-24 02:57:17】此处的空字串必须被过滤掉，以便让`$$`等价于`[]`而非`[""]`
         Some((
             // 不要括弧！
-            truth_string
-                // 拆分
-                .split(&self.format.sentence.truth_separator)
-                .map(str::to_owned)
-                .filter(|s| !s.is_empty())
-                .collect::<Truth>(),
+            // 拆分 | 主分隔符、备选分隔符均可
+            split_separators(
+                truth_string,
+                &self.format.sentence.truth_separator,
+                &self.format.sentence.truth_separator_alternates,
+            )
+            .map(str::to_owned)
+            .filter(|s| !s.is_empty())
+            .collect::<Truth>(),
             right_border,
         ))
     }
@@ -634,7 +1568,7 @@ impl ParseState<'_> {
     /// * 📄匹配的环境如：`G!:|:`
     ///   * ⚠️此时应该已经截去了真值
     /// * 📄匹配的结果如：`Some((":|:", 2))` | `2` 对应第一个`:`
-    fn segment_stamp(&self, env: ParseEnv) -> Option<(String, ParseIndex)> {
+    fn segment_stamp(&self, env: &[char]) -> Option<(String, ParseIndex)> {
         // 尝试后缀匹配
         self.segment_brackets_suffix(
             env,
@@ -652,7 +1586,7 @@ impl ParseState<'_> {
     ///   * 📌要么返回「匹配到的完整标点，以及其在『解析环境』中的开头位置（用于切分出词项）」
     /// * 📄匹配的环境如：`<A-->B>!`
     /// * 📄匹配的结果如：`Some(("!", 7))` | `7` 对应`!`
-    fn segment_punctuation(&self, env: ParseEnv) -> Option<(String, ParseIndex)> {
+    fn segment_punctuation(&self, env: &[char]) -> Option<(String, ParseIndex)> {
         // 尝试解析出标点
         let punctuation = self
             .format
@@ -665,12 +1599,118 @@ impl ParseState<'_> {
         Some((punctuation, var_name))
     }
 
+    /// 检查「未闭合的括弧」
+    /// * 🎯前后缀切割失败时，给出有针对性的报错
+    ///   * 📄`<A --> B>. %1.0;0.9`：真值缺少右括弧，否则会被当作词项的一部分去解析
+    /// * 🚩在切割出标点后，对剩下的「词项区间」进行检查
+    ///   * 真值：区间以「左括弧+真值内容」结尾，且之前是标点（及可能的时间戳）
+    ///   * 时间戳：区间以「固定时间戳的左括弧+时间戳内容」或「时间戳的一部分」结尾，且之前是标点
+    ///   * 预算值：区间以「左括弧+预算内容」开头，且内容中有非标识符字符
+    ///     * 📌避免与「以预算左括弧为前缀」的原子词项混淆：如ASCII中的`$1`
+    /// * 📌报错时，环境定位到未闭合的部分
+    fn verify_brackets_closed(&self, env: ParseEnv) -> ParseResult<()> {
+        if let Some(start) = self.unclosed_truth_start(&env) {
+            return self.err(env.slice(start..), ParseErrorKind::UnclosedBracket, "真值未闭合");
+        }
+        if let Some(start) = self.unclosed_stamp_start(&env) {
+            return self.err(env.slice(start..), ParseErrorKind::UnclosedBracket, "时间戳未闭合");
+        }
+        if let Some(end) = self.unclosed_budget_end(&env) {
+            return self.err(env.slice(..end), ParseErrorKind::UnclosedBracket, "预算值未闭合");
+        }
+        Ok(())
+    }
+
+    /// 工具函数/环境末尾「连续合法字符」的起始索引
+    fn suffix_run_start(env: &[char], verify_char: impl Fn(char) -> bool) -> ParseIndex {
+        env.iter()
+            .rposition(|&c| !verify_char(c))
+            .map_or(0, |i| i + 1)
+    }
+
+    /// 工具函数/环境以字符串结尾⇒该字符串的起始索引
+    fn suffix_start(env: &[char], suffix: &str) -> Option<ParseIndex> {
+        let suffix = suffix.chars().collect::<Vec<_>>();
+        env.ends_with(&suffix).then(|| env.len() - suffix.len())
+    }
+
+    /// 判断环境是否以「标点」结尾
+    /// * 📌`allow_stamp`：标点之后可跟随完整的时间戳
+    fn ends_with_punctuation(&self, env: &[char], allow_stamp: bool) -> bool {
+        let env = match allow_stamp {
+            true => self.segment_stamp(env).map_or(env, |(_, i)| &env[..i]),
+            false => env,
+        };
+        self.segment_punctuation(env).is_some()
+    }
+
+    /// 未闭合真值的起始索引（左括弧处）
+    fn unclosed_truth_start(&self, env: &[char]) -> Option<ParseIndex> {
+        let sentence = &self.format.sentence;
+        let run_start = Self::suffix_run_start(env, sentence.is_truth_content);
+        let start = Self::suffix_start(&env[..run_start], &sentence.truth_brackets.0)?;
+        self.ends_with_punctuation(&env[..start], true)
+            .then_some(start)
+    }
+
+    /// 未闭合时间戳的起始索引
+    /// * 📌固定时间戳无右括弧（如漢文的`发生在`）⇒不会未闭合
+    fn unclosed_stamp_start(&self, env: &[char]) -> Option<ParseIndex> {
+        let sentence = &self.format.sentence;
+        // 固定时间戳：有左括弧与内容，缺右括弧
+        let (left, right) = &sentence.stamp_fixed;
+        let fixed = match right.is_empty() {
+            true => None,
+            false => {
+                let run_start = Self::suffix_run_start(env, sentence.is_stamp_content);
+                Self::suffix_start(&env[..run_start], left)
+            }
+        };
+        // 枚举时间戳：只有前一部分
+        let partial = [
+            &sentence.stamp_past,
+            &sentence.stamp_present,
+            &sentence.stamp_future,
+        ]
+        .into_iter()
+        .flat_map(|stamp| {
+            let chars = stamp.chars().collect::<Vec<_>>();
+            (1..chars.len())
+                .filter(move |&len| env.ends_with(&chars[..len]))
+                .map(|len| env.len() - len)
+        });
+        fixed
+            .into_iter()
+            .chain(partial)
+            .find(|&start| self.ends_with_punctuation(&env[..start], false))
+    }
+
+    /// 未闭合预算值的右边界（预算内容之后）
+    fn unclosed_budget_end(&self, env: &[char]) -> Option<ParseIndex> {
+        let task = &self.format.task;
+        let left = task.budget_brackets.0.chars().collect::<Vec<_>>();
+        if !env.starts_with(&left) {
+            return None;
+        }
+        let end = self.collect_some_prefix(env, left.len(), |_, c| (task.is_budget_content)(c));
+        env[left.len()..end]
+            .iter()
+            .any(|&c| !(self.format.atom.is_identifier)(c))
+            .then_some(end)
+    }
+
     /// 递归解析词项
     /// * 内部函数[`Self::segment_term`]的独立对外接口
     /// * 🚩返回一个包含「词项」或「解析错误」的结果
     pub fn parse_term(&self, input: &str) -> ParseResult<Term> {
-        let idealized = idealize_env(self.format, input);
-        Ok(self.segment_term(&idealized)?.0)
+        // 预先判空
+        if is_empty_input(input) {
+            return Err(ParseError::new(EMPTY_INPUT_MESSAGE, &[]).with_kind(ParseErrorKind::EmptyInput));
+        }
+        let (idealized, original_indices) = idealize_env_indexed(self.format, input);
+        self.segment_term(ParseEnv::new(&idealized))
+            .map(|(term, _)| term)
+            .map_err(|error| error.locate(input, &original_indices))
     }
 
     /// 递归分隔词项
@@ -681,23 +1721,98 @@ impl ParseState<'_> {
     ///   * 📌要么返回「解析成功」：词项及其右边界（即长度）
     /// * 🚩因为「递归解析」需要传递信息，故需要额外传递索引
     /// * 📌不传递额外信息、直接传递字符串的才能叫「parse」
+    /// * 🚩超出「最大嵌套深度」时直接报错，不再深入
+    /// * 🚩有钩子⇒对构造完成的词项调用钩子
     fn segment_term(&self, env: ParseEnv) -> ParseResult<(Term, ParseIndex)> {
-        // 先解析「集合词项」
-        if let Ok(result) = self.segment_term_set(env) {
-            return Ok(result);
+        let depth = self.depth.get();
+        if depth >= self.format.max_depth {
+            return self.err(env, ParseErrorKind::Other(MAX_DEPTH_MESSAGE.into()), MAX_DEPTH_MESSAGE);
         }
-        // 然后解析「复合词项」
-        if let Ok(result) = self.segment_compound(env) {
-            return Ok(result);
+        self.depth.set(depth + 1);
+        let result = self.segment_term_nested(env);
+        self.depth.set(depth);
+        // 有钩子⇒对构造完成的词项调用钩子 | 组分已先行调用，故为自底向上
+        match self.hooks {
+            Some(hooks) => result.map(|(term, len)| (hooks.apply_term(term), len)),
+            None => result,
+        }
+    }
+
+    /// 递归分隔词项（内部实现）
+    /// * 🚩依次尝试各类词项，首个成功者即为结果
+    /// * ⚠️「超出最大嵌套深度」「集合词项为空」等错误须直接上抛，不再尝试其它类词项
+    ///   * 📌否则会被后续「原子词项」的错误掩盖
+    fn segment_term_nested(&self, env: ParseEnv) -> ParseResult<(Term, ParseIndex)> {
+        // PyNARS兼容模式：引号原子、前缀否定
+        if self.format.pynars_compat {
+            if let Some(result) = self.segment_pynars_term(env) {
+                return result;
+            }
         }
-        // 再解析「陈述」
-        if let Ok(result) = self.segment_statement(env) {
-            return Ok(result);
+        // 先解析「集合词项」，然后解析「复合词项」，再解析「陈述」
+        let segments = [
+            Self::segment_term_set,
+            Self::segment_compound,
+            Self::segment_statement,
+        ];
+        for segment in segments {
+            match segment(self, env) {
+                Ok(result) => return Ok(result),
+                Err(error) if error.is_fatal() => return Err(error),
+                Err(..) => {}
+            }
         }
         // 最后解析「原子」 | 此时不会附加「停止条件」（只会在陈述上下文中开启）
         self.segment_atom(env)
     }
 
+    /// 解析PyNARS特有的词项语法
+    /// * 🎯仅在PyNARS兼容模式下启用
+    /// * 📄单引号原子：`'hello world'` ⇒ 名称为`'hello world'`的词语
+    ///   * 📌保留引号，以便格式化后仍能被解析
+    /// * 📄前缀否定：`--A` ⇒ `(--, A)`
+    /// * ⚙️不匹配⇒[`None`]，交由常规流程解析
+    fn segment_pynars_term(&self, env: ParseEnv) -> Option<ParseResult<(Term, ParseIndex)>> {
+        // 单引号原子
+        if env.first() == Some(&PYNARS_QUOTE) {
+            let result = match env[1..].iter().position(|&c| c == PYNARS_QUOTE) {
+                // 含右引号的右边界
+                Some(i) => {
+                    let right_border = i + 2;
+                    let term = Term::Atom {
+                        prefix: String::new(),
+                        name: String::from_iter(&env[..right_border]),
+                    };
+                    Ok((term, right_border))
+                }
+                None => self.err(env, ParseErrorKind::UnclosedBracket, "单引号原子词项未闭合"),
+            };
+            return Some(result);
+        }
+        // 前缀否定 | ⚠️需排除系词（如`-->` `--]`）
+        if env.starts_with_str(PYNARS_NEGATION)
+            && self
+                .format
+                .statement
+                .copulas
+                .match_prefix_chars(&env)
+                .is_none()
+        {
+            let start = PYNARS_NEGATION.chars().count();
+            let result = self
+                .segment_term(env.slice(start..))
+                .map(|(term, len)| {
+                    let term = Term::Compound {
+                        connecter: PYNARS_NEGATION.into(),
+                        terms: vec![term],
+                    };
+                    (term, start + len)
+                });
+            return Some(result);
+        }
+        None
+    }
+
     /// 前缀解析原子词项（贪婪匹配）
     /// * 🎯正常情况下的原子词项：纯原子词项、复合词项中、陈述主词
     /// * ❗遇到陈述系词总会停下
@@ -719,83 +1834,197 @@ impl ParseState<'_> {
             .format
             .atom
             .prefixes
-            .match_prefix_char_slice(env)
+            .match_prefix_chars(&env)
             // 从Option打包成Result，然后尝试解包
-            .ok_or(self.parse_error(env, "未匹配到原子词项前缀"))?
+            .ok_or_else(|| self.parse_error(env, ParseErrorKind::UnknownPrefix, "未匹配到原子词项前缀"))?
             .to_owned();
         // 计算出所有系词的首字符 // ! 用于【统一】应对「分割陈述」时「原子词项做主词」的情况
         let copulas = &self.format.statement.copulas;
         // 计算出起始索引
         let content_start = prefix.chars().count();
+        // 带引号的名称⇒原样截取，不检查标识符
+        if let Some((left, right)) = quote_chars(self.format) {
+            if env[content_start..].starts_with(&left) {
+                let (name, len) = scan_quoted(&env[content_start..], (&left, &right))
+                    .ok_or_else(|| self.parse_error(env, ParseErrorKind::UnclosedBracket, "原子词项引号未闭合"))?;
+                return Ok((Term::Atom { prefix, name }, content_start + len));
+            }
+        }
         // 朝后贪婪扫描字符
         let right_border = self.collect_some_prefix(
-            env,
+            &env,
             content_start,
             // 检验
             |i, c| {
                 // 首先是合法字符
                 (self.format.atom.is_identifier)(c) &&
                 // 其次是「不能以系词作为开头」（遇到系词⇒截止）
-                copulas.match_prefix_char_slice(&env[i..]).is_none()
+                copulas.match_prefix_chars(&env[i..]).is_none()
             },
         );
+        // 以数字开头的间隔⇒截止于首个非数字字符
+        // * 🎯兼容OpenNARS输出中「间隔后缺少分隔符」的顺序合取：`(&/, a, +5 b)`
+        //   * 📌预处理去除空白后为`+5b`：应切分为`+5`与`b`，而非名为`5b`的间隔
+        // * 📌不以数字开头的间隔名称原样保留
+        let right_border = match prefix == self.format.atom.prefix_interval {
+            true => match env[content_start..right_border]
+                .iter()
+                .position(|c| !c.is_ascii_digit())
+            {
+                Some(0) | None => right_border,
+                Some(digits) => content_start + digits,
+            },
+            false => right_border,
+        };
         // 检查非空
         // ! 不允许名称为空的原子词项
         if content_start >= right_border && prefix.is_empty() {
-            return self.err(env, "原子词项名称与前缀不能同时为空");
+            return self.err(env, ParseErrorKind::MissingTerm, "原子词项名称与前缀不能同时为空");
         }
+        // 变量的依赖列表⇒并入名称 | 📄`#1(#2)`、`#x(a,b)`
+        let right_border = match self.format.atom.prefix_variables.contains(&prefix)
+            && right_border > content_start
+        {
+            true => self.dependency_list_end(env, right_border)?,
+            false => right_border,
+        };
         // 获取名称
         let name = String::from_iter(&env[content_start..right_border]);
+        // 规范化间隔字面量
+        let name = match self.format.normalize_interval_literals
+            && prefix == self.format.atom.prefix_interval
+        {
+            true => self.normalize_interval_name(env, name)?,
+            false => name,
+        };
         // 构造
         let term = Term::Atom { prefix, name };
         // 返回
         Ok((term, right_border))
     }
 
-    /// 解析集合词项
-    fn segment_term_set(&self, env: ParseEnv) -> ParseResult<(Term, ParseIndex)> {
-        // 前缀匹配并跳过左括弧
-        let (left, right) = self
-            .format
-            .compound
-            .set_brackets
-            .match_prefix_char_slice(env)
-            .ok_or(self.parse_error(env, "缺少陈述左括弧"))?;
+    /// 变量依赖列表的右边界
+    /// * 🎯兼容OpenNARS输出中带依赖列表的变量：`#1(#2)`、`#x(a,b)`
+    ///   * 📌依赖列表作为名称的一部分原样保留，格式化后仍能被解析
+    /// * 🚩名称之后紧跟复合词项左括弧⇒按括弧配对，跳至对应的右括弧之后
+    ///   * 📌没有依赖列表⇒原样返回起始索引
+    ///   * ❌括弧未闭合⇒报错
+    fn dependency_list_end(&self, env: ParseEnv, start: ParseIndex) -> ParseResult<ParseIndex> {
+        let (left, right) = &self.format.compound.brackets;
+        if !env[start..].starts_with_str(left) {
+            return Ok(start);
+        }
+        let mut depth = 0;
+        let mut i = start;
+        while i < env.len() {
+            if env[i..].starts_with_str(left) {
+                depth += 1;
+                i += left.chars().count();
+            } else if env[i..].starts_with_str(right) {
+                depth -= 1;
+                i += right.chars().count();
+                if depth == 0 {
+                    return Ok(i);
+                }
+            } else {
+                i += 1;
+            }
+        }
+        self.err(env.slice(start..), ParseErrorKind::UnclosedBracket, "变量依赖列表未闭合")
+    }
+
+    /// 规范化「间隔」名称
+    /// * 🎯使间隔名称与「枚举Narsese」往返后的结果一致
+    /// * 🚩纯数字⇒去除前导零（全为零时保留一个`0`）
+    /// * 🚩名称为空⇒报错
+    /// * 📌其它名称原样保留
+    fn normalize_interval_name(&self, env: ParseEnv, name: String) -> ParseResult<String> {
+        if name.is_empty() {
+            return self.err(env, ParseErrorKind::InvalidNumber, "间隔名称为空");
+        }
+        if !name.chars().all(|c| c.is_ascii_digit()) {
+            return Ok(name);
+        }
+        let trimmed = name.trim_start_matches('0');
+        Ok(match trimmed.is_empty() {
+            true => "0".into(),
+            false => trimmed.into(),
+        })
+    }
+
+    /// 解析集合词项
+    fn segment_term_set(&self, env: ParseEnv) -> ParseResult<(Term, ParseIndex)> {
+        // 前缀匹配并跳过左括弧
+        let (left, right) = self
+            .format
+            .compound
+            .set_brackets
+            .match_prefix_chars(&env)
+            .ok_or_else(|| self.parse_error(env, ParseErrorKind::UnclosedBracket, "缺少陈述左括弧"))?;
 
         // 前缀切片最需要注意的是长度
         let mut term_begin = left.chars().count();
 
-        // 开始解析其中的元素
-        let mut terms = Vec::new();
-        let right_border;
-        // 第一个元素
-        let (term, term_len) = self.segment_term(&env[term_begin..])?;
-        terms.push(term);
+        // 空集合⇒专门报错
+        if env[term_begin..].starts_with_str(right) {
+            return self.err(env, ParseErrorKind::MissingTerm, EMPTY_SET_MESSAGE);
+        }
+
+        // 第一个元素 | 不允许前导分隔符
+        let (term, term_len) = self.segment_term(env.slice(term_begin..))?;
         term_begin += term_len;
+        // 其余元素
+        let (mut terms, right_border) = self.segment_elements(env, term_begin, right)?;
+        terms.insert(0, term);
+
+        // 解包 & 构造 //
+        let term = Term::Set {
+            left_bracket: left.clone(),
+            terms,
+            right_bracket: right.clone(),
+        };
+        // 返回
+        Ok((term, right_border))
+    }
+
+    /// 解析「分隔符-词项-分隔符-词项……-右括弧」序列
+    /// * 🎯集合词项、复合词项共用
+    /// * 📌分隔符可省略；右括弧前的单个分隔符（如`(*, A, B,)`）被容忍
+    /// * ⚠️某次迭代未消耗任何字符⇒报错，而非原地打转
+    /// * ⚙️返回所有元素，以及右括弧的右边界
+    fn segment_elements(
+        &self,
+        env: ParseEnv,
+        mut term_begin: ParseIndex,
+        right: &str,
+    ) -> ParseResult<(Vec<Term>, ParseIndex)> {
+        let separator = &self.format.compound.separator;
+        let mut terms = Vec::new();
         loop {
             // 右括弧⇒跳过，结束
             if env[term_begin..].starts_with_str(right) {
-                right_border = term_begin + right.chars().count();
-                break;
+                return Ok((terms, term_begin + right.chars().count()));
             }
             // 分隔符⇒跳过
-            if env[term_begin..].starts_with_str(&self.format.compound.separator) {
-                term_begin += self.format.compound.separator.chars().count();
+            if env[term_begin..].starts_with_str(separator) {
+                term_begin += separator.chars().count();
+                // 末尾分隔符⇒结束
+                if env[term_begin..].starts_with_str(right) {
+                    return Ok((terms, term_begin + right.chars().count()));
+                }
             }
             // 解析一个词项
-            let (term, term_len) = self.segment_term(&env[term_begin..])?;
+            let (term, term_len) = self.segment_term(env.slice(term_begin..))?;
+            if term_len == 0 {
+                return self.err(
+                    env.slice(term_begin..),
+                    ParseErrorKind::Other(NO_PROGRESS_MESSAGE.into()),
+                    NO_PROGRESS_MESSAGE,
+                );
+            }
             terms.push(term);
             term_begin += term_len;
         }
-
-        // 解包 & 构造 //
-        let term = Term::Set {
-            left_bracket: left.clone(),
-            terms,
-            right_bracket: right.clone(),
-        };
-        // 返回
-        Ok((term, right_border))
     }
 
     /// 解析复合词项
@@ -805,39 +2034,40 @@ impl ParseState<'_> {
             .format
             .compound
             .brackets
-            .match_prefix_char_slice(env)
-            .ok_or(self.parse_error(env, "缺少陈述左括弧"))?;
+            .match_prefix_chars(&env)
+            .ok_or_else(|| self.parse_error(env, ParseErrorKind::UnclosedBracket, "缺少陈述左括弧"))?;
 
         // 前缀切片最需要注意的是长度
         let connecter_start = left.chars().count();
 
         // 解析连接符 //
-        let connecter = self
+        let connecter = match self
             .format
             .compound
             .connecters
-            .match_prefix_char_slice(&env[connecter_start..])
-            .ok_or(self.parse_error(env, "缺少陈述左括弧"))?
-            .clone();
+            .match_prefix_chars(&env[connecter_start..])
+        {
+            Some(connecter) => connecter.clone(),
+            // 未知连接符⇒取最长的一段符号字符
+            None if self.format.accept_unknown_connecters => {
+                let len = self.symbol_run_len(&env[connecter_start..], Some(right));
+                if len == 0 {
+                    return self.err(env, ParseErrorKind::UnknownConnecter, "未解析出连接符");
+                }
+                String::from_iter(&env[connecter_start..connecter_start + len])
+            }
+            None => return self.err(env, ParseErrorKind::UnclosedBracket, "缺少陈述左括弧"),
+        };
 
         // 不断解析「分隔符-词项-分隔符-词项……」
-        let mut terms = Vec::new();
-        let mut term_begin = connecter_start + connecter.chars().count();
-        let right_border;
-        loop {
-            // 右括弧⇒跳过，结束
-            if env[term_begin..].starts_with_str(right) {
-                right_border = term_begin + right.chars().count();
-                break;
-            }
-            // 分隔符⇒跳过
-            if env[term_begin..].starts_with_str(&self.format.compound.separator) {
-                term_begin += self.format.compound.separator.chars().count();
-            }
-            // 解析一个词项
-            let (term, term_len) = self.segment_term(&env[term_begin..])?;
-            terms.push(term);
-            term_begin += term_len;
+        let term_begin = connecter_start + connecter.chars().count();
+        let (terms, right_border) = self.segment_elements(env, term_begin, right)?;
+
+        // 像⇒至多一个占位符 | 📌无占位符不报错：词法Narsese不检验词项结构
+        if self.format.is_image_connecter(&connecter)
+            && terms.iter().filter(|term| self.format.is_placeholder(term)).count() > 1
+        {
+            return self.err(env, ParseErrorKind::DuplicateItem, IMAGE_PLACEHOLDERS_MESSAGE);
         }
 
         // 解包 & 构造 //
@@ -860,6 +2090,7 @@ impl ParseState<'_> {
     /// * ❌【2024-03-19 19:10:28】不要过于复杂化：解析主词最好跟其它情况一样
     /// * ❌【2024-03-19 16:29:22】弃用「后缀匹配谓词，再以此定位系词」的方案：后缀匹配还得分开「无前缀原子词项」的情况
     /// * 🚩方案：使用「原子词项前缀」结合「原子词项内容（首个字符）」作为判断依据
+    ///
     /// ! ⚠️不能直接使用「原子词项前缀」作为判断依据：必须考虑**空前缀**情况
     fn segment_statement(&self, env: ParseEnv) -> ParseResult<(Term, ParseIndex)> {
         // 前缀匹配并跳过左括弧
@@ -867,35 +2098,35 @@ impl ParseState<'_> {
             .format
             .statement
             .brackets
-            .match_prefix_char_slice(env)
-            .ok_or(self.parse_error(env, "缺少陈述左括弧"))?;
+            .match_prefix_chars(&env)
+            .ok_or_else(|| self.parse_error(env, ParseErrorKind::UnclosedBracket, "缺少陈述左括弧"))?;
         // 前缀切片最需要注意的是长度
         let subject_start = left.chars().count();
 
         // 解析主词 //
         // ! 【2024-03-19 19:26:16】现在不再特别区分对待「原子词项作为主词，贪婪解析内容吃掉系词」的情况了
         // * 🚩解决方案：「一刀切」拒绝系词开头作为原子词项内容
-        let (subject, subject_len) = self.segment_term(&env[subject_start..])?;
+        let (subject, subject_len) = self.segment_term(env.slice(subject_start..))?;
         let copula_start = subject_start + subject_len;
 
-        // 解析系词 //
-        let copula = self
+        // 解析系词、谓词 //
+        let (copula, predicate, right_border) = match self
             .format
             .statement
             .copulas
-            .match_prefix_char_slice(&env[copula_start..])
-            .ok_or(self.parse_error(env, "未解析出系词"))?
-            .clone();
-        let predicate_start = copula_start + copula.chars().count();
-
-        // 解析谓词 //
-        let (predicate, relative_len) = self.segment_term(&env[predicate_start..])?;
-
-        // 跳过右括弧 //
-        let right_bracket_start = predicate_start + relative_len;
-        let right_border = match env[right_bracket_start..].starts_with_str(right) {
-            true => right_bracket_start + right.chars().count(),
-            false => return self.err(env, "未匹配到右括弧"),
+            .match_prefix_chars(&env[copula_start..])
+        {
+            Some(copula) => {
+                let predicate_start = copula_start + copula.chars().count();
+                let (predicate, right_border) =
+                    self.segment_predicate(env, predicate_start, right)?;
+                (copula.clone(), predicate, right_border)
+            }
+            // 未知系词⇒在符号字符中从短到长尝试
+            None if self.format.accept_unknown_copulas => {
+                self.segment_unknown_copula(env, copula_start, right)?
+            }
+            None => return self.err(env, ParseErrorKind::UnknownCopula, "未解析出系词"),
         };
 
         // 解包 & 构造 //
@@ -910,6 +2141,73 @@ impl ParseState<'_> {
         // 返回
         Ok((term, right_border))
     }
+
+    /// 解析「谓词+右括弧」
+    /// * ⚙️返回谓词，以及右括弧的右边界
+    fn segment_predicate(
+        &self,
+        env: ParseEnv,
+        predicate_start: ParseIndex,
+        right: &str,
+    ) -> ParseResult<(Term, ParseIndex)> {
+        // 解析谓词 //
+        let (predicate, relative_len) = self.segment_term(env.slice(predicate_start..))?;
+
+        // 跳过右括弧 //
+        let right_bracket_start = predicate_start + relative_len;
+        match env[right_bracket_start..].starts_with_str(right) {
+            true => Ok((predicate, right_bracket_start + right.chars().count())),
+            false => self.err(env, ParseErrorKind::UnclosedBracket, "未匹配到右括弧"),
+        }
+    }
+
+    /// 解析未知系词及其后的谓词
+    /// * 🎯[`NarseseFormat::accept_unknown_copulas`]
+    /// * 🚩系词之后的谓词可能以符号字符开头（📄`<A ~~> $x>`）⇒从短到长尝试，取首个成功者
+    /// * ⚙️返回系词、谓词，以及右括弧的右边界
+    fn segment_unknown_copula(
+        &self,
+        env: ParseEnv,
+        copula_start: ParseIndex,
+        right: &str,
+    ) -> ParseResult<(String, Term, ParseIndex)> {
+        let run = self.symbol_run_len(&env[copula_start..], None);
+        let mut error = self.parse_error(env, ParseErrorKind::UnknownCopula, "未解析出系词");
+        for len in 1..=run {
+            match self.segment_predicate(env, copula_start + len, right) {
+                Ok((predicate, right_border)) => {
+                    let copula = String::from_iter(&env[copula_start..copula_start + len]);
+                    return Ok((copula, predicate, right_border));
+                }
+                Err(e) if e.is_fatal() => return Err(e),
+                Err(e) => error = e,
+            }
+        }
+        Err(error)
+    }
+
+    /// 开头一段「符号字符」的长度
+    /// * 🎯未知系词、未知连接符
+    /// * 📌符号字符：非标识符、非空白，且不是左括弧、分隔符（及可选的额外截止符）的开头
+    fn symbol_run_len(&self, env: &[char], extra_stop: Option<&str>) -> ParseIndex {
+        let compound = &self.format.compound;
+        let is_stop = |rest: &[char]| {
+            rest.starts_with_str(&compound.brackets.0)
+                || rest.starts_with_str(&compound.separator)
+                || rest.starts_with_str(&self.format.statement.brackets.0)
+                || compound
+                    .set_brackets
+                    .prefix_terms()
+                    .any(|(left, _)| rest.starts_with_str(left))
+                || extra_stop.is_some_and(|stop| rest.starts_with_str(stop))
+        };
+        env.iter()
+            .enumerate()
+            .position(|(i, &c)| {
+                (self.format.atom.is_identifier)(c) || c.is_whitespace() || is_stop(&env[i..])
+            })
+            .unwrap_or(env.len())
+    }
 }
 
 /// 侧门 [`NarseseFormat::parse(format, input)`]
@@ -928,15 +2226,175 @@ impl NarseseFormat {
     pub fn parse_term(&self, input: &str) -> ParseResult<Term> {
         parse_term(self, input)
     }
+
+    /// 部分解析函数@字符串
+    /// * 🎯返回「中间结果」，不要求词项存在
+    /// * 📄`$0.5$`⇒只有预算值
+    pub fn parse_partial(&self, input: &str) -> ParseResult<MidParseResult> {
+        parse_partial(self, input)
+    }
+
+    /// 解析函数/附带条目范围@字符串
+    /// * 🎯语法高亮：获知预算值、词项、标点、时间戳、真值在原始输入中的字节范围
+    /// * 📄`$0.5$ <A --> B>.`⇒预算值`0..5`、词项`6..15`、标点`15..16`
+    pub fn parse_spanned(&self, input: &str) -> ParseResult<SpannedNarsese> {
+        parse_spanned(self, input)
+    }
+
+    /// 宽松解析函数/条目@字符串
+    /// * 🎯词项出错时，仍然给出其它条目
+    /// * 📌不影响[`Self::parse`]：默认仍在出错时中止
+    pub fn parse_items_lenient(
+        &self,
+        input: &str,
+    ) -> (
+        NarseseOptions<Budget, Term, Punctuation, Stamp, Truth>,
+        Vec<ParseError>,
+    ) {
+        parse_items_lenient(self, input)
+    }
+
+    /// 解析函数/前缀@字符串
+    /// * 🎯在更大的语法中嵌入Narsese：只解析开头的一个Narsese值
+    /// * ⚙️返回Narsese值与「首个未消耗字符」的字节偏移
+    /// * 📌其后的非Narsese内容不视作错误
+    pub fn parse_prefix(&self, input: &str) -> ParseResult<(Narsese, usize)> {
+        parse_prefix(self, input)
+    }
+
+    /// 一次解析多条语句
+    /// * 🎯直接解析`.nal`文件等「多条语句」的完整内容
+    /// * 🚩自行切分语句：按行切分，去除注释，跳过空行与整行注释
+    ///   * 📌注释前缀见[`NarseseFormat::comment_prefixes`]
+    /// * 🚩每条逻辑语句对应一个解析结果
+    pub fn parse_all(&self, input: &str) -> Vec<ParseResult> {
+        self.parse_iter(input).collect()
+    }
+
+    /// 一次解析多条语句（迭代器版本）
+    /// * 🚩语句切分同[`Self::parse_all`]，但惰性解析
+    pub fn parse_iter<'a>(&'a self, input: &'a str) -> impl Iterator<Item = ParseResult> + 'a {
+        self.statement_lines(input)
+            .map(|statement| self.parse(statement))
+    }
+
+    /// 解析多个Narsese，并分离成功与失败
+    /// * 🎯批量解析一系列输入，返回可直接遍历「成功值」的[`ParsedBatch`]
+    /// * 🚩错误连同其「输入索引」一并保存
+    pub fn parse_multi_collect<'a>(
+        &self,
+        inputs: impl IntoIterator<Item = &'a str>,
+    ) -> ParsedBatch<Narsese, ParseError> {
+        ParsedBatch::from_results(inputs.into_iter().map(|input| self.parse(input)))
+    }
+
+    /// 带位置的流式解析
+    /// * 🎯按行解析多条Narsese，并给每条结果附上「行号/列号/来源名」
+    ///   * 📄后续的折叠、校验出错时，可据此报告`file.nal:123`
+    /// * 🚩每个非空行视作一条Narsese；空行被跳过（但计入行号）
+    /// * 🚩解析错误的行号、列号均相对于整个输入
+    /// * 📌不影响[`NarseseFormat::parse`]等「纯值」API
+    pub fn parse_stream_located<'a>(
+        &'a self,
+        input: &'a str,
+        source_name: Option<Arc<str>>,
+    ) -> impl Iterator<Item = Located<ParseResult>> + 'a {
+        located_lines(input, source_name).map(|line| {
+            let (line_number, column) = (line.line, line.col);
+            line.map(|input| {
+                self.parse(input)
+                    .map_err(|error| error.offset_by(line_number, column))
+            })
+        })
+    }
+
+    /// 解析时间戳字符串，得到其[结构化视图](StampKind)
+    /// * 🎯下游无需再自行解析`:!-1:`之类的字符串
+    /// * 🚩空字串⇒永恒；「过去」「现在」「将来」⇒完全匹配
+    /// * 🚩固定⇒去掉括弧，内容须为带可选正负号的整数
+    ///   * ❌`:!1.0:`、`:!:`、`:!+-1:`
+    pub fn parse_stamp_kind(&self, stamp: &str) -> ParseResult<StampKind> {
+        let sentence = &self.sentence;
+        let kind = match stamp {
+            "" => StampKind::Eternal,
+            _ if stamp == sentence.stamp_past => StampKind::Past,
+            _ if stamp == sentence.stamp_present => StampKind::Present,
+            _ if stamp == sentence.stamp_future => StampKind::Future,
+            _ => {
+                let (left, right) = &sentence.stamp_fixed;
+                let time = stamp
+                    .strip_prefix(left.as_str())
+                    .and_then(|s| s.strip_suffix(right.as_str()))
+                    .filter(|time| time.chars().all(sentence.is_stamp_content))
+                    .and_then(|time| time.parse().ok());
+                match time {
+                    Some(time) => StampKind::Fixed(time),
+                    None => {
+                        let env = stamp.chars().collect::<ParseEnvOwned>();
+                        return Err(ParseError::new("非法时间戳", &env).with_kind(ParseErrorKind::UnknownPrefix));
+                    }
+                }
+            }
+        };
+        Ok(kind)
+    }
+
+    /// 将[时间戳的结构化视图](StampKind)格式化为时间戳字符串
+    /// * 📌[`Self::parse_stamp_kind`]的逆过程
+    /// * 🚩固定⇒加上括弧，不带正号：`:!1:`、`:!-1:`
+    pub fn format_stamp_kind(&self, kind: StampKind) -> String {
+        let sentence = &self.sentence;
+        match kind {
+            StampKind::Eternal => String::new(),
+            StampKind::Past => sentence.stamp_past.clone(),
+            StampKind::Present => sentence.stamp_present.clone(),
+            StampKind::Future => sentence.stamp_future.clone(),
+            StampKind::Fixed(time) => {
+                let (left, right) = &sentence.stamp_fixed;
+                format!("{left}{time}{right}")
+            }
+        }
+    }
+
+    /// 平移时间戳字符串中的「固定」时间
+    /// * 🎯对齐不同会话的时间基准
+    /// * 🚩固定⇒解析、平移、重新格式化；其它⇒原样返回
+    /// * ⚠️非法时间戳、平移溢出⇒报错
+    pub fn shift_stamp(&self, stamp: &str, delta: i64) -> ParseResult<String> {
+        match self.parse_stamp_kind(stamp)? {
+            StampKind::Fixed(time) => match time.checked_add(delta) {
+                Some(time) => Ok(self.format_stamp_kind(StampKind::Fixed(time))),
+                None => {
+                    let env = stamp.chars().collect::<ParseEnvOwned>();
+                    Err(ParseError::new("时间戳平移溢出", &env).with_kind(ParseErrorKind::InvalidNumber))
+                }
+            },
+            _ => Ok(stamp.to_owned()),
+        }
+    }
 }
 
+/// 时间戳的结构化视图
+impl Sentence {
+    /// 按指定格式解析自身的时间戳
+    /// * 📌自身存储的字符串保持不变
+    /// * 📄参见[`NarseseFormat::parse_stamp_kind`]
+    pub fn stamp_kind(&self, format: &NarseseFormat) -> ParseResult<StampKind> {
+        format.parse_stamp_kind(&self.stamp)
+    }
+}
+
+// 实现[`FromStr`]：基于ASCII格式解析
+// * 🎯支持`let t: Term = "<A --> B>".parse()?;`
+crate::api::impl_from_str!(ParseError, |s| FORMAT_ASCII.parse(s); Narsese => Term, Sentence, Task);
+
 /// 单元测试
 #[cfg(test)]
 mod test {
     #![allow(unused)]
 
     use super::{super::format_instances::*, *};
-    use crate::lexical::shortcuts::*;
+    use crate::{api::FromStrError, lexical::shortcuts::*};
     use nar_dev_utils::*;
 
     /// 通通用测试/尝试解析并返回错误
@@ -1008,6 +2466,8 @@ mod test {
         let idealized = "$0.5;0.5;0.5$"; // 去掉空格
         test_budget! {
             "$0.5; 0.5; 0.5$" => (expected, idealized.chars().count())
+            // 科学计数法、正号
+            "$5e-1; +0.5; 5E-1$" => (vec!["5e-1", "+0.5", "5E-1"], "$5e-1;+0.5;5E-1$".chars().count())
         }
 
         // 所有的失败case
@@ -1019,7 +2479,7 @@ mod test {
             // 失败case 3 | 前缀不匹配
             "0.5; 0.5; 0.5$"
             // 失败case 4 | 非法字符
-            "$0.5; 0.5; +0.5$"
+            "$0.5; 0.5; #0.5$"
             // 失败case 5 | 只有左括弧
             "$"
             // 失败case 6 | 不是开头前缀
@@ -1057,6 +2517,8 @@ mod test {
                 // * 时间戳的右边界 第一个'%'
                 "<A-->B>.:|:%1.0;0.9%".find('%').unwrap(),
             )
+            // 科学计数法、正号
+            "%1e0; +9e-1%" => (vec!["1e0", "+9e-1"], 0)
         };
 
         // 所有的失败case
@@ -1068,7 +2530,7 @@ mod test {
             // 失败case 3 | 后缀不匹配
             "%1.0; 0.9"
             // 失败case 4 | 非法字符
-            "%1.0; +0.9%"
+            "%1.0; #0.9%"
             // 失败case 5 | 只有右括弧
             "%"
             // 失败case 6 | 不是末尾后缀
@@ -1317,10 +2779,11 @@ mod test {
             // "[A, B, C]]" // ! ←这些会只认前缀
             // "{A, B, C}}" // ! ←这些会只认前缀
             // 多余分隔符 | 分隔符可缺省，但不可多余
+            // * 📌右括弧前的单个分隔符被容忍，参见`test_parse_trailing_separator`
             "(*,, A,  B,  C )"
             "(*,  A,, B,  C )"
             "(*,  A,  B,, C )"
-            "(*,  A,  B,  C,)"
+            "(*,  A,  B,  C,,)"
             // 陈述 //
             // 缺少括弧
             "<A --> B"
@@ -1354,7 +2817,7 @@ mod test {
 
             // 解析出条目（中间结果）
             let result = state
-                .parse_items(&idealize_env(format, narsese))
+                .parse_items(ParseEnv::new(&idealize_env(format, narsese)))
                 .expect("条目解析失败！");
 
             // 断言
@@ -1422,4 +2885,1019 @@ mod test {
         //     assert!(result.is_ok());
         // }
     }
+
+    /// 测试/带位置的流式解析
+    #[test]
+    fn test_parse_stream_located() {
+        let format = &FORMAT_ASCII;
+        let input = "<A --> B>.\n\n  <B --> C>?\n<C --> (&&, D>.\n";
+        let results = format
+            .parse_stream_located(input, Some("fixture.nal".into()))
+            .collect::<Vec<_>>();
+        show!(&results);
+        // 行号、列号
+        asserts! {
+            results.len() => 3,
+            results[0].location() => "fixture.nal:1:1",
+            results[1].location() => "fixture.nal:3:3",
+            results[2].location() => "fixture.nal:4:1",
+        }
+        // 结果：最后一条解析失败
+        asserts! {
+            results[0].value.is_ok(),
+            results[1].value.is_ok(),
+            results[2].value.is_err(),
+        }
+    }
+
+    /// 测试/带位置的流式解析：错误位置相对于整个输入
+    #[test]
+    fn test_parse_stream_located_error_position() {
+        let format = &FORMAT_ASCII;
+        let input = "<A --> B>.\n\n   <C --> (&&, D>.";
+        let results = format.parse_stream_located(input, None).collect::<Vec<_>>();
+        let error = results[1].value.as_ref().unwrap_err();
+        // 单独解析该行：位置相对于行首
+        let line_error = format.parse("<C --> (&&, D>.").unwrap_err();
+        show!(error, &line_error);
+        asserts! {
+            error.line() => Some(3),
+            error.column() => line_error.column().map(|column| column + 3),
+            error.to_string().contains("at line 3,"),
+        }
+    }
+
+    /// 测试/错误中的「环境切片」
+    /// * 🎯超长环境只保留开头，超出部分以`…`表示
+    #[test]
+    fn test_parse_error_env_scope() {
+        use super::structs::ENV_SCOPE_MAX_CHARS;
+        let short = ['A'; ENV_SCOPE_MAX_CHARS];
+        let long = ['A'; ENV_SCOPE_MAX_CHARS + 1];
+        let kept = "A".repeat(ENV_SCOPE_MAX_CHARS);
+        asserts! {
+            ParseError::new("错误", &short).to_string() => format!("Narsese解析错误：错误 in {kept:?}"),
+            ParseError::new("错误", &long).to_string() => format!("Narsese解析错误：错误 in \"{kept}…\""),
+        }
+    }
+
+    /// 测试/错误的行号、列号
+    #[test]
+    fn test_parse_error_line_column() {
+        let format = &FORMAT_ASCII;
+        // 第二行出错：词项中多余分隔符
+        // * 📌定位到「出错的子环境」的起始处：此处即词项开头
+        let input = "$0.5;0.5;0.5$\n  (&&, A,, )!\n:|:";
+        let error = format.parse(input).unwrap_err();
+        show!(&error);
+        asserts! {
+            error.line() => Some(2),
+            error.column() => Some(3),
+            error.to_string().contains("at line 2, column 3"),
+        }
+        // 词项入口同理
+        let error = format.parse_term("\n (&&, A,, )").unwrap_err();
+        asserts! {
+            error.line() => Some(2),
+            error.column() => Some(2),
+        }
+        // 嵌套的子环境：偏移逐层累加
+        let error = format.parse("<A -->\n  <B -->\n   {}>>.").unwrap_err();
+        show!(&error);
+        asserts! {
+            error.is_empty_set(),
+            error.line() => Some(3),
+            error.column() => Some(4),
+        }
+        // 条目：未闭合的真值
+        let error = format.parse("<A --> B>.\n%1.0;").unwrap_err();
+        asserts! {
+            error.line() => Some(2),
+            error.column() => Some(1),
+        }
+        // 空输入⇒无法定位
+        let error = format.parse("").unwrap_err();
+        asserts! {
+            error.line() => None,
+        }
+    }
+
+    /// 测试/一次解析多条语句
+    #[test]
+    fn test_parse_all() {
+        let format = &FORMAT_ASCII;
+        let input = "
+            '测试用NAL文件
+            <robin --> bird>.
+            // C风格注释
+
+            <bird --> animal>. %1.0;0.9%
+            ''outputMustContain('<robin --> animal>.')
+            <robin --> animal>?
+            (A, B
+        ";
+        let results = format.parse_all(input);
+        show!(&results);
+        asserts! {
+            results.len() => 4,
+            results[..3].iter().all(Result::is_ok),
+            results[3].is_err(),
+            format.parse_iter(input).count() => 4,
+        }
+        // 与「按行逐个解析」一致
+        let expected = format.parse("<bird --> animal>. %1.0;0.9%");
+        assert_eq!(results[1].as_ref().ok(), expected.as_ref().ok());
+    }
+
+    /// 测试/[`FromStr`]
+    #[test]
+    fn test_from_str() {
+        use crate::api::GetTerm;
+        // 成功
+        let term: Term = "<A --> B>".parse().unwrap();
+        let sentence: Sentence = "<A --> B>.".parse().unwrap();
+        let task: Task = "$0.5;0.5;0.5$ <A --> B>.".parse().unwrap();
+        let narsese: Narsese = "<A --> B>?".parse().unwrap();
+        asserts! {
+            Narsese::Term(term) => FORMAT_ASCII.parse("<A --> B>").unwrap(),
+            sentence.get_term() => &"<A --> B>".parse::<Term>().unwrap(),
+            task.get_term() => sentence.get_term(),
+            narsese.is_sentence(),
+        }
+        // 类型不匹配
+        let error = "A.".parse::<Term>().unwrap_err();
+        show!(&error);
+        asserts! {
+            matches!(error, FromStrError::WrongVariant { expected: "词项", .. }),
+            error.to_string().contains("语句"),
+            matches!("A".parse::<Sentence>(), Err(FromStrError::WrongVariant { .. })),
+            matches!("A.".parse::<Task>(), Err(FromStrError::WrongVariant { .. })),
+        }
+        // 语法错误
+        asserts! {
+            matches!("(A, B".parse::<Term>(), Err(FromStrError::Parse(..))),
+            matches!("".parse::<Narsese>(), Err(FromStrError::Parse(..))),
+        }
+    }
+
+    /// 测试/批量解析并分离成功与失败
+    #[test]
+    fn test_parse_multi_collect() {
+        let format = &FORMAT_ASCII;
+        let inputs = ["<A --> B>.", "(A, B", "C", "", "<C --> D>?"];
+        let batch = format.parse_multi_collect(inputs);
+        show!(&batch);
+        asserts! {
+            batch.values().len() => 3,
+            batch.errors().iter().map(|(i, _)| *i).collect::<Vec<_>>() => [1, 3],
+            batch.iter().filter(|v| v.is_sentence()).count() => 2,
+            batch.clone().into_iter().filter(Narsese::is_term).count() => 1,
+            batch.clone().into_values().len() => 3,
+        }
+        // 要求全部成功
+        let error = batch.require_all().unwrap_err();
+        show!(&error);
+        asserts! {
+            error.indices() => [1, 3],
+            error.to_string().contains("[1, 3]"),
+        }
+    }
+
+    /// 测试/空输入
+    #[test]
+    fn test_parse_empty_input() {
+        let format = &FORMAT_ASCII;
+        for input in ["", "   ", "\t\n", "\u{feff}", "\u{feff}  \r\n"] {
+            let error = format.parse(input).unwrap_err();
+            show!(&error);
+            asserts! {
+                error.is_empty_input(),
+                error.message() => EMPTY_INPUT_MESSAGE,
+                format.parse_term(input).unwrap_err().is_empty_input(),
+            }
+        }
+        // 仅有标点⇒不是「空输入」，而是「缺少词项」
+        let error = format.parse(".").unwrap_err();
+        show!(&error);
+        assert!(!error.is_empty_input());
+    }
+
+    /// 测试/空集合与末尾分隔符
+    /// * 🎯空集合有专门的报错；右括弧前的单个分隔符被容忍
+    #[test]
+    fn test_parse_trailing_separator() {
+        let format = &FORMAT_ASCII;
+        let parse = |input: &str| format.parse_term(input).unwrap();
+        // 空集合 | 嵌套其中也直接报错
+        for input in ["{}", "[ ]", "<{} --> A>", "(*, A, [])"] {
+            let error = format.parse_term(input).unwrap_err();
+            show!(&error);
+            asserts! {
+                error.is_empty_set(),
+                error.message() => EMPTY_SET_MESSAGE,
+            }
+        }
+        // 末尾分隔符
+        asserts! {
+            parse("{A,}") => parse("{A}"),
+            parse("[A,]") => parse("[A]"),
+            parse("{A, B ,}") => parse("{A, B}"),
+            parse("(*, A, B,)") => parse("(*, A, B)"),
+            parse("<(&&, A,) --> [B,]>") => parse("<(&&, A) --> [B]>"),
+            // 仅有一个分隔符⇒空复合词项，而非幻影元素
+            parse("(* ,)") => parse("(*)"),
+        }
+        // 多个分隔符、前导分隔符⇒仍然报错
+        for input in ["{A,,}", "(*, A,,)", "{,A}", "(*,,)"] {
+            assert!(format.parse_term(input).is_err(), "{input}");
+        }
+    }
+
+    /// 测试/顺序合取中「间隔后缺少分隔符」
+    /// * 📄OpenNARS输出：`(&/, <a --> b>, +5 <c --> d>)`
+    /// * 🚩以数字开头的间隔截止于首个非数字字符：预处理去除空白后不会与后续词项粘连
+    #[test]
+    fn test_parse_interval_without_separator() {
+        let format = &FORMAT_ASCII;
+        let parse = |input: &str| format.parse_term(input).unwrap();
+        let expected = parse("(&/, <a --> b>, +5, <c --> d>)");
+        asserts! {
+            parse("(&/, <a --> b>, +5 <c --> d>)") => expected,
+            parse("(&/, <a --> b>, +5<c --> d>)") => expected,
+            parse("(&/,<a --> b>,+5 <c --> d>)") => expected,
+            // 间隔之后紧跟原子词项
+            parse("(&/, a, +5 c)") => parse("(&/, a, +5, c)"),
+            parse("(&/, a, +120b)") => parse("(&/, a, +120, b)"),
+            parse("(&/, a, +5 c)") => compound!("&/", atom!("a"), atom!("+" "5"), atom!("c")),
+            // 不以数字开头的间隔名称原样保留
+            parse("+x") => atom!("+" "x"),
+        }
+    }
+
+    /// 测试/未闭合的真值、预算值、时间戳
+    /// * 🎯报错有针对性，且定位到未闭合的部分
+    #[test]
+    fn test_parse_unclosed() {
+        let samples = [
+            // 真值
+            (&*FORMAT_ASCII, "<A --> B>. %1.0;0.9", "真值未闭合", "%1.0;0.9"),
+            (&*FORMAT_ASCII, "<A --> B>. :|: %1.0;0.9", "真值未闭合", "%1.0;0.9"),
+            (&*FORMAT_ASCII, "<A --> B>. %", "真值未闭合", "%"),
+            (&*FORMAT_LATEX, r"A. \langle{}1.0,0.9", "真值未闭合", r"\langle{}1.0,0.9"),
+            (&*FORMAT_HAN, "甲。真1.0、0.9", "真值未闭合", "真1.0、0.9"),
+            // 预算值
+            (&*FORMAT_ASCII, "$0.5;0.5 <A --> B>.", "预算值未闭合", "$0.5;0.5"),
+            (&*FORMAT_LATEX, r"\$0.5;0.5 A.", "预算值未闭合", r"\$0.5;0.5"),
+            (&*FORMAT_HAN, "预0.5、0.5甲。", "预算值未闭合", "预0.5、0.5"),
+            // 时间戳
+            (&*FORMAT_ASCII, "<A --> B>. :|", "时间戳未闭合", ":|"),
+            (&*FORMAT_ASCII, "<A --> B>. :!-1 %1.0;0.9%", "时间戳未闭合", ":!-1"),
+            (&*FORMAT_LATEX, r"A. |\!\!\!\!\!\Rightarrow", "时间戳未闭合", r"|\!\!\!\!\!\Rightarrow"),
+            (&*FORMAT_HAN, "甲。现", "时间戳未闭合", "现"),
+        ];
+        for (format, input, message, scope) in samples {
+            let error = format.parse(input).unwrap_err();
+            show!(&error);
+            asserts! {
+                error.message() => message,
+                error.to_string().contains(&format!("{scope:?}")),
+            }
+        }
+        // 不影响「以括弧为前缀/后缀」的合法词项
+        let valid = [
+            (&*FORMAT_ASCII, "$1."),
+            (&*FORMAT_ASCII, "<$1 --> A>. %1.0;0.9%"),
+            (&*FORMAT_HAN, "认真。"),
+            (&*FORMAT_HAN, "发现。"),
+            (&*FORMAT_HAN, "预测。"),
+        ];
+        for (format, input) in valid {
+            assert!(format.parse(input).is_ok(), "{input}");
+        }
+    }
+
+    /// 测试/重复、错位的条目
+    /// * 🎯不再把多余的标点、真值当作词项的一部分
+    #[test]
+    fn test_parse_duplicate_items() {
+        let samples = [
+            // 重复的标点
+            (&*FORMAT_ASCII, "<A-->B>.. %1.0%", "重复的标点", "."),
+            (&*FORMAT_LATEX, r"A.. \langle{}1,0.9\rangle{}", "重复的标点", "."),
+            (&*FORMAT_HAN, "甲。。 真1、0.9值", "重复的标点", "。"),
+            // 重复的真值
+            (&*FORMAT_ASCII, "<A-->B>. %1.0% %1.0%", "重复的真值", "%1.0%"),
+            (
+                &*FORMAT_LATEX,
+                r"A. \langle{}1,0.9\rangle{} \langle{}1,0.9\rangle{}",
+                "重复的真值",
+                r"\langle{}1,0.9\rangle{}",
+            ),
+            (&*FORMAT_HAN, "甲。 真1、0.9值 真1、0.9值", "重复的真值", "真1、0.9值"),
+            // 真值在词项之前
+            (&*FORMAT_ASCII, "%1.0% <A-->B>.", "真值位置错误", "%1.0%"),
+            (&*FORMAT_LATEX, r"\langle{}1,0.9\rangle{} A.", "真值位置错误", r"\langle{}1,0.9\rangle{}"),
+            (&*FORMAT_HAN, "真1、0.9值 甲。", "真值位置错误", "真1、0.9值"),
+            // 其它条目
+            (&*FORMAT_ASCII, "A. :|: :|:", "重复的时间戳", ":|:"),
+            (&*FORMAT_ASCII, "A :|:.", "时间戳位置错误", ":|:"),
+        ];
+        for (format, input, message, scope) in samples {
+            let error = format.parse(input).unwrap_err();
+            show!(&error);
+            asserts! {
+                error.message() => message,
+                error.to_string().contains(&format!("{scope:?}")),
+            }
+        }
+    }
+
+    /// 测试/像中的占位符
+    /// * 🎯至多一个占位符；无占位符不报错（词法Narsese不检验词项结构）
+    #[test]
+    fn test_parse_image_placeholders() {
+        let format = &*FORMAT_ASCII;
+        for input in [r"(/, A, _, _, B)", r"(\, _, A, _)", r"<(/, _, _) --> B>."] {
+            let error = format.parse(input).unwrap_err();
+            show!(&error);
+            asserts! {
+                error.is_image_placeholders(),
+                error.kind() => &ParseErrorKind::DuplicateItem,
+            }
+        }
+        asserts! {
+            // 一个占位符
+            format.parse_term(r"(/, A, _, B)").unwrap() => compound!("/", atom!("A"), atom!("_" ""), atom!("B")),
+            format.parse_term(r"(\, A, B, _)").is_ok(),
+            // 无占位符
+            format.parse_term(r"(/, A, B)").is_ok(),
+            format.parse_term(r"(\, A, B)").is_ok(),
+            // 非像⇒占位符是普通组分
+            format.parse_term("(*, _)").unwrap() => compound!("*", atom!("_" "")),
+            format.parse_term("(*, _, _)").is_ok(),
+        }
+    }
+
+    /// 测试/查询变量与问题标点的歧义
+    #[test]
+    fn test_parse_ambiguity_resolution() {
+        let ascii = &*FORMAT_ASCII;
+        let [var_question, word_question] = [ascii.parse("?x ?").unwrap(), ascii.parse("x ?").unwrap()];
+        let var_term = ascii.parse("?x").unwrap();
+        let format_with = |resolution| {
+            ascii
+                .builder()
+                .ambiguity_resolution(resolution)
+                .build()
+                .unwrap()
+        };
+        // 偏向变量（默认）
+        let format = format_with(AmbiguityResolution::PreferVariable);
+        asserts! {
+            ascii.ambiguity_resolution => AmbiguityResolution::PreferVariable,
+            format.parse("?x?").unwrap() => var_question,
+            format.parse("x?").unwrap() => word_question,
+            format.parse("?x").unwrap() => var_term,
+        }
+        // 偏向标点
+        let format = format_with(AmbiguityResolution::PreferPunctuation);
+        asserts! {
+            format.parse("?x?").unwrap() => var_question,
+            format.parse("x?").unwrap() => word_question,
+            format.parse("?x").unwrap() => word_question,
+            format.parse_partial("?x").unwrap().punctuation => Some("?".into()),
+        }
+        // 要求显式
+        let format = format_with(AmbiguityResolution::RequireExplicit);
+        for input in ["?x?", "?x", "$0.5$ ?x? %1.0;0.9%"] {
+            let error = format.parse(input).unwrap_err();
+            show!(&error);
+            asserts! {
+                error.is_ambiguous_query_variable(),
+                error.kind() => &ParseErrorKind::Ambiguous,
+            }
+        }
+        asserts! {
+            format.parse("x?").unwrap() => word_question,
+            // 显式写法
+            format.parse("?x ?").unwrap() => var_question,
+            format.parse("<?x --> A>?").is_ok(),
+            format.parse_spanned("?x ?").is_ok(),
+            format.parse_spanned("?x?").is_err(),
+            format.parse_items_lenient("?x?").1.len() => 1,
+        }
+        // 无歧义的格式不受影响
+        let han = FORMAT_HAN
+            .builder()
+            .ambiguity_resolution(AmbiguityResolution::RequireExplicit)
+            .build()
+            .unwrap();
+        asserts! {
+            han.parse("所问x？").unwrap() => FORMAT_HAN.parse("所问x？").unwrap(),
+            han.parse("所问x").unwrap() => FORMAT_HAN.parse("所问x").unwrap(),
+        }
+    }
+
+    /// 测试/错误种类
+    #[test]
+    fn test_parse_error_kind() {
+        use crate::conversion::string::impl_lexical::ValidateError;
+        let format = &*FORMAT_ASCII;
+        let kind = |input| format.parse(input).unwrap_err().kind().clone();
+        let numeric_kind = |input| format.parse_numeric(input).unwrap_err().kind().clone();
+        asserts! {
+            kind("") => ParseErrorKind::EmptyInput,
+            kind("<A --> B>. %1.0;0.9") => ParseErrorKind::UnclosedBracket,
+            kind("<A-->B>.. %1.0%") => ParseErrorKind::DuplicateItem,
+            kind("%1.0% <A-->B>.") => ParseErrorKind::UnexpectedChar,
+            kind("{}") => ParseErrorKind::MissingTerm,
+            kind("(&&, A,, )") => ParseErrorKind::MissingTerm,
+            kind("+.") => ParseErrorKind::InvalidNumber,
+            numeric_kind("<A --> B>. %1.5;0.9%") => ParseErrorKind::OutOfRange01,
+            numeric_kind("$0.5;0.5;2$ A.") => ParseErrorKind::OutOfRange01,
+            format.parse_stamp_kind(":!x:").unwrap_err().kind() => &ParseErrorKind::UnknownPrefix,
+            format.shift_stamp(&format!(":!{}:", i64::MAX), 1).unwrap_err().kind()
+                => &ParseErrorKind::InvalidNumber,
+            kind("<A-->B>.. %1.0%").code() => "duplicate_item",
+        }
+        // 数值检验错误⇒作为内部错误
+        let error = format.parse_numeric("<A --> B>. %1.5;0.9%").unwrap_err();
+        let source = error.source().unwrap().downcast_ref::<ValidateError>();
+        assert!(matches!(source, Some(ValidateError::OutOfRange { .. })));
+        // 消息不变
+        asserts! {
+            error.message() => source.unwrap().to_string(),
+            format.parse("").unwrap_err().source().is_none(),
+        }
+    }
+
+    /// 测试/时间戳种类
+    /// * 🎯三种格式下的时间戳⇒结构化的[`StampKind`]，并能格式化回去
+    /// * 🎯固定时间戳的平移；非法时间戳⇒报错
+    #[test]
+    fn test_parse_stamp_kind() {
+        use StampKind::*;
+        // 三种格式下的五种时间戳
+        let cases = [
+            (&*FORMAT_ASCII, [r":\:", ":|:", ":/:", ":!137:", ":!-1:", ":!+1:"]),
+            (
+                &*FORMAT_LATEX,
+                [
+                    r"\backslash\!\!\!\!\!\Rightarrow{}",
+                    r"|\!\!\!\!\!\Rightarrow{}",
+                    r"/\!\!\!\!\!\Rightarrow{}",
+                    "t=137",
+                    "t=-1",
+                    "t=+1",
+                ],
+            ),
+            (&*FORMAT_HAN, ["过去", "现在", "将来", "发生在137", "发生在-1", "发生在+1"]),
+        ];
+        let expected = [Past, Present, Future, Fixed(137), Fixed(-1), Fixed(1)];
+        for (format, stamps) in cases {
+            assert_eq!(format.parse_stamp_kind("").unwrap(), Eternal);
+            for (stamp, expected) in stamps.into_iter().zip(expected) {
+                assert_eq!(format.parse_stamp_kind(stamp).unwrap(), expected, "{stamp}");
+                // 逆过程：格式化后能解析回来
+                let formatted = format.format_stamp_kind(expected);
+                assert_eq!(format.parse_stamp_kind(&formatted).unwrap(), expected, "{formatted}");
+            }
+            assert_eq!(format.format_stamp_kind(Eternal), "");
+        }
+        assert_eq!(FORMAT_ASCII.format_stamp_kind(Fixed(-1)), ":!-1:");
+        // 平移
+        asserts! {
+            FORMAT_ASCII.shift_stamp(":!1:", 10).unwrap() => ":!11:",
+            FORMAT_ASCII.shift_stamp(":!+1:", -10).unwrap() => ":!-9:",
+            FORMAT_LATEX.shift_stamp("t=-1", 1).unwrap() => "t=0",
+            FORMAT_HAN.shift_stamp("发生在5", -2).unwrap() => "发生在3",
+            // 非固定时间戳原样返回
+            FORMAT_ASCII.shift_stamp(":|:", 10).unwrap() => ":|:",
+            FORMAT_ASCII.shift_stamp("", 10).unwrap() => "",
+            // 溢出、非法时间戳
+            FORMAT_ASCII.shift_stamp(&format!(":!{}:", i64::MAX), 1).unwrap_err().message() => "时间戳平移溢出",
+            FORMAT_ASCII.shift_stamp(&format!(":!{}:", i64::MIN), -1).is_err(),
+            FORMAT_ASCII.shift_stamp(":!x:", 1).unwrap_err().message() => "非法时间戳",
+        }
+        // 非法时间戳
+        for stamp in [":!1.0:", ":!:", ":!+-1:", ":!1", ":?:", "现在"] {
+            let result = FORMAT_ASCII.parse_stamp_kind(stamp);
+            show!(&result);
+            assert!(result.is_err(), "{stamp}");
+        }
+        assert!(FORMAT_HAN.parse_stamp_kind("发生在一").is_err());
+        // 从语句获取 | 原字符串保持不变
+        let samples = [
+            (&*FORMAT_ASCII, "A. :!-1:", Fixed(-1)),
+            (&*FORMAT_ASCII, "<A --> B>! :|:", Present),
+            (&*FORMAT_ASCII, "A?", Eternal),
+            (&*FORMAT_LATEX, r"A. /\!\!\!\!\!\Rightarrow{}", Future),
+            (&*FORMAT_HAN, "甲。过去", Past),
+        ];
+        for (format, input, expected) in samples {
+            let Ok(Narsese::Sentence(sentence)) = format.parse(input) else {
+                panic!("语句解析失败：{input}");
+            };
+            assert_eq!(sentence.stamp_kind(format).unwrap(), expected, "{input}");
+        }
+        let sentence = FORMAT_ASCII.parse("A. :!+1:").unwrap();
+        let Narsese::Sentence(sentence) = sentence else {
+            unreachable!()
+        };
+        asserts! {
+            sentence.stamp_kind(&FORMAT_ASCII).unwrap() => Fixed(1),
+            sentence.stamp => ":!+1:",
+        }
+    }
+
+    /// 测试/最大嵌套深度
+    /// * 🎯深层嵌套的输入⇒解析错误，而非栈溢出
+    #[test]
+    fn test_parse_max_depth() {
+        /// 生成指定层数的嵌套否定
+        fn nested_negation(depth: usize) -> String {
+            format!("{}A{}", "(--, ".repeat(depth), ")".repeat(depth))
+        }
+        let format = &FORMAT_ASCII;
+        // 超深嵌套⇒报错
+        let error = format.parse(&nested_negation(10000)).unwrap_err();
+        show!(error.message());
+        asserts! {
+            error.is_max_depth_exceeded(),
+            error.message() => MAX_DEPTH_MESSAGE,
+            format.parse_term(&nested_negation(10000)).unwrap_err().is_max_depth_exceeded(),
+            format.parse(&format!("<{} --> B>.", nested_negation(10000))).unwrap_err().is_max_depth_exceeded(),
+        }
+        // 深度限制内⇒正常解析 | 原子词项本身占一层
+        let format = NarseseFormat {
+            max_depth: 4,
+            ..FORMAT_ASCII.clone()
+        };
+        asserts! {
+            format.parse_term(&nested_negation(3)).is_ok(),
+            format.parse_term(&nested_negation(4)).unwrap_err().is_max_depth_exceeded(),
+            format.parse_term("<(--, (--, A)) --> B>").is_ok(),
+            format.parse_term("<(--, (--, (--, A))) --> B>").unwrap_err().is_max_depth_exceeded(),
+        }
+    }
+
+    /// 测试/PyNARS兼容模式
+    #[test]
+    fn test_pynars_compat() {
+        let compat = &FORMAT_PYNARS;
+        let normal = &FORMAT_ASCII;
+        // 真值、时间戳顺序无关
+        let expected = normal.parse("<robin --> bird>. :|: %1.00;0.90%").unwrap();
+        asserts! {
+            compat.parse("<robin --> bird>. :|: %1.00;0.90%").unwrap() => expected,
+            compat.parse("<robin --> bird>. %1.00;0.90% :|:").unwrap() => expected,
+            // 默认关闭时，无法正确识别出真值
+            normal.parse("<robin --> bird>. %1.00;0.90% :|:").ok() != Some(expected),
+        }
+        // 单引号原子词项
+        asserts! {
+            compat.parse_term("<'hello world' --> greeting>").unwrap()
+                => statement!(atom!("'hello world'") "-->" atom!("greeting")),
+            compat.parse_term("'unclosed").is_err(),
+            normal.parse_term("'hello world'").is_err(),
+        }
+        // 前缀否定
+        asserts! {
+            compat.parse("<--robin --> bird>.").unwrap()
+                => normal.parse("<(--, robin) --> bird>.").unwrap(),
+            compat.parse_term("(&&, --A, B)").unwrap()
+                => normal.parse_term("(&&, (--, A), B)").unwrap(),
+            compat.parse_term("(--,A)").unwrap() => normal.parse_term("(--, A)").unwrap(),
+            // 默认关闭时，`--robin`被视作词语
+            normal.parse_term("--robin").unwrap() => atom!("--robin"),
+        }
+    }
+
+    /// PyNARS控制台会话日志（样本）
+    /// * 📄`预算值 类型标记:Narsese`，带ANSI颜色转义序列
+    /// * 📌每行对应的ASCII等价形式见[`test_pynars_session_log`]
+    const PYNARS_SESSION_LOG: &str = "\
+\u{1b}[48;2;124;10;10m 0.90 \u{1b}[49m\u{1b}[48;2;10;124;10m 0.90 \u{1b}[49m\u{1b}[48;2;10;10;124m 1.00 \u{1b}[49m\u{1b}[36mIN    :\u{1b}[39m <robin-->bird>. %1.000;0.900%
+\u{1b}[48;2;124;10;10m 0.90 \u{1b}[49m\u{1b}[48;2;10;124;10m 0.90 \u{1b}[49m\u{1b}[48;2;10;10;124m 1.00 \u{1b}[49m\u{1b}[36mIN    :\u{1b}[39m <bird-->animal>. %1.000;0.900%
+                  \u{1b}[36mIN    :\u{1b}[39m <robin-->animal>?
+\u{1b}[48;2;124;10;10m 0.81 \u{1b}[49m\u{1b}[48;2;10;124;10m 0.90 \u{1b}[49m\u{1b}[48;2;10;10;124m 0.43 \u{1b}[49m\u{1b}[33mOUT   :\u{1b}[39m <robin-->animal>. %1.000;0.810%
+\u{1b}[48;2;124;10;10m 0.72 \u{1b}[49m\u{1b}[48;2;10;124;10m 0.90 \u{1b}[49m\u{1b}[48;2;10;10;124m 0.34 \u{1b}[49m\u{1b}[33mOUT   :\u{1b}[39m <animal-->robin>. %1.000;0.448%
+                  \u{1b}[32mANSWER:\u{1b}[39m <robin-->animal>. %1.000;0.810%
+\u{1b}[48;2;124;10;10m 0.90 \u{1b}[49m\u{1b}[48;2;10;124;10m 0.90 \u{1b}[49m\u{1b}[48;2;10;10;124m 1.00 \u{1b}[49m\u{1b}[36mIN    :\u{1b}[39m <(*, {SELF}, 'the door') --> open>! %1.000;0.900% :|:
+\u{1b}[48;2;124;10;10m 0.80 \u{1b}[49m\u{1b}[48;2;10;124;10m 0.80 \u{1b}[49m\u{1b}[48;2;10;10;124m 0.50 \u{1b}[49m\u{1b}[33mOUT   :\u{1b}[39m (&&, --<robin-->fish>, <robin-->bird>). %0.900;0.730%
+";
+
+    /// PyNARS会话日志⇒与ASCII格式的等价形式解析结果一致
+    #[test]
+    fn test_pynars_session_log() {
+        use crate::conversion::string::strip_pynars_output_prefix;
+        let expected = [
+            "<robin --> bird>. %1.000;0.900%",
+            "<bird --> animal>. %1.000;0.900%",
+            "<robin --> animal>?",
+            "<robin --> animal>. %1.000;0.810%",
+            "<animal --> robin>. %1.000;0.448%",
+            "<robin --> animal>. %1.000;0.810%",
+            "<(*, {SELF}, 'the door') --> open>! :|: %1.000;0.900%",
+            "(&&, (--, <robin --> fish>), <robin --> bird>). %0.900;0.730%",
+        ];
+        let lines = PYNARS_SESSION_LOG.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), expected.len());
+        for (line, expected) in lines.into_iter().zip(expected) {
+            let parsed = FORMAT_PYNARS.parse(strip_pynars_output_prefix(line));
+            show!(&parsed);
+            // * 📌单引号原子词项仅在兼容模式下可解析⇒期望值同样用PyNARS格式解析
+            let expected = match expected.contains('\'') {
+                true => FORMAT_PYNARS.parse(expected),
+                false => FORMAT_ASCII.parse(expected),
+            };
+            assert_eq!(parsed.unwrap(), expected.unwrap(), "{line:?}");
+        }
+    }
+
+    /// 测试/带引号的原子词项名称
+    #[test]
+    fn test_parse_quoted() {
+        let format = FORMAT_ASCII.builder().quotes("\"", "\"").build().unwrap();
+        let word = |name: &str| Term::Atom {
+            prefix: "".into(),
+            name: name.into(),
+        };
+        asserts! {
+            // 引号内的空白、分隔符、括弧原样保留
+            format.parse_term("\"word term\"").unwrap() => word("word term"),
+            format.parse_term("<\"a, <b>\" --> \"  c  \">").unwrap() => Term::Statement {
+                copula: "-->".into(),
+                subject: Box::new(word("a, <b>")),
+                predicate: Box::new(word("  c  ")),
+            },
+            // 前缀在引号之外
+            format.parse_term("^\"go to\"").unwrap() => Term::Atom {
+                prefix: "^".into(),
+                name: "go to".into(),
+            },
+            // 转义
+            format.parse_term(r#""say \"hi\" \\ ok""#).unwrap() => word(r#"say "hi" \ ok"#),
+            // 引号外的空白仍被删去
+            format.parse_term("(*, \"A B\" ,  C )").unwrap() => Term::Compound {
+                connecter: "*".into(),
+                terms: vec![word("A B"), word("C")],
+            },
+            // 未闭合
+            format.parse_term("\"word term").is_err(),
+            format.parse_term(r#""word\""#).is_err(),
+            // 默认不启用引号
+            FORMAT_ASCII.parse_term("\"word term\"").is_err(),
+        }
+    }
+
+    /// 测试/带引号的原子词项名称：往返
+    #[test]
+    fn test_quoted_round_trip() {
+        use crate::lexical::tests::_sample_task_ascii_0;
+        // 额外加入样本中的连接符
+        let format = FORMAT_ASCII
+            .builder()
+            .quotes("\"", "\"")
+            .connecters([
+                "&", "|", "-", "~", "*", r"/", r"\", "&&", "||", "--", "&/", "&|", "复合词项连接词",
+            ])
+            .build()
+            .unwrap();
+        let task = _sample_task_ascii_0();
+        let formatted = format.format_task(&task);
+        show!(&formatted);
+        asserts! {
+            formatted.contains("\"word term\""),
+            format.parse(&formatted).unwrap() => Narsese::Task(task),
+        }
+        // 含转义符、引号的名称
+        let term = Term::Atom {
+            prefix: "^".into(),
+            name: r#"say "hi" \ {ok}"#.into(),
+        };
+        let formatted = format.format_term(&term);
+        asserts! {
+            formatted => r#"^"say \"hi\" \\ {ok}""#,
+            format.parse_term(&formatted).unwrap() => term,
+            // 无需引号⇒不加引号
+            format.format_term(&format.parse_term("<A --> ^op>").unwrap()) => "<A --> ^op>",
+        }
+    }
+
+    /// 测试/带依赖列表的变量
+    /// * 🎯兼容OpenNARS输出：依赖列表并入变量名称
+    #[test]
+    fn test_parse_variable_dependency_list() {
+        let format = &FORMAT_ASCII;
+        let variable = |prefix: &str, name: &str| Term::Atom {
+            prefix: prefix.into(),
+            name: name.into(),
+        };
+        asserts! {
+            format.parse_term("#1(#2)").unwrap() => variable("#", "1(#2)"),
+            // 空白被删去
+            format.parse_term("#x(a, b)").unwrap() => variable("#", "x(a,b)"),
+            format.parse_term("#x").unwrap() => variable("#", "x"),
+            // 嵌套括弧、陈述中
+            format.parse_term("#x((a), b)").unwrap() => variable("#", "x((a),b)"),
+            format.parse_term("<#1(#2) --> A>").unwrap() => Term::Statement {
+                copula: "-->".into(),
+                subject: Box::new(variable("#", "1(#2)")),
+                predicate: Box::new(variable("", "A")),
+            },
+            // 格式化后仍能被解析
+            format.format_term(&variable("#", "x(a,b)")) => "#x(a,b)",
+            // 未闭合
+            format.parse_term("#x(a, b").is_err(),
+        }
+    }
+
+    /// 测试/未知系词、连接符
+    /// * 🎯宽松模式下原样保留未知符号；严格模式下报错
+    #[test]
+    fn test_parse_unknown_symbols() {
+        let strict = &FORMAT_ASCII;
+        let permissive = &FORMAT_ASCII
+            .builder()
+            .accept_unknown_copulas(true)
+            .accept_unknown_connecters(true)
+            .build()
+            .unwrap();
+        let atom = |prefix: &str, name: &str| Term::Atom {
+            prefix: prefix.into(),
+            name: name.into(),
+        };
+        // 严格模式⇒报错
+        asserts! {
+            strict.parse_term("<A ~~> B>").is_err(),
+            strict.parse_term("(@@, A, B)").is_err(),
+        }
+        // 宽松模式⇒原样保留
+        asserts! {
+            permissive.parse_term("<A ~~> B>").unwrap() => Term::Statement {
+                copula: "~~>".into(),
+                subject: Box::new(atom("", "A")),
+                predicate: Box::new(atom("", "B")),
+            },
+            permissive.parse_term("(@@, A, B)").unwrap() => Term::Compound {
+                connecter: "@@".into(),
+                terms: vec![atom("", "A"), atom("", "B")],
+            },
+            // 谓词以前缀开头、嵌套
+            permissive.parse_term("<A ~~> $x>").unwrap() => Term::Statement {
+                copula: "~~>".into(),
+                subject: Box::new(atom("", "A")),
+                predicate: Box::new(atom("$", "x")),
+            },
+            permissive.parse_term("<(@@, A) ~~> {B}>").is_ok(),
+            // 已知符号不受影响
+            permissive.parse_term("<A --> B>").unwrap() => strict.parse_term("<A --> B>").unwrap(),
+            permissive.parse_term("(&&, A, B)").unwrap() => strict.parse_term("(&&, A, B)").unwrap(),
+            // 缺少符号⇒仍然报错
+            permissive.parse_term("<A B>").is_err(),
+            permissive.parse_term("(, A)").is_err(),
+        }
+        // 格式化后往返
+        for input in ["<A ~~> B>.", "(@@, A, B)!", "<(@@, A) ~~> {B}>?"] {
+            let parsed = permissive.parse(input).unwrap();
+            let formatted = permissive.format_narsese(&parsed);
+            asserts! {
+                formatted => input,
+                permissive.parse(&formatted).unwrap() => parsed,
+            }
+        }
+    }
+
+    /// 测试/前缀解析
+    /// * 🎯将Narsese嵌入更大的语法中，并检验「已消耗长度」
+    #[test]
+    fn test_parse_prefix() {
+        let format = &FORMAT_ASCII;
+        // 返回「解析结果」与「剩余输入」
+        let parse_prefix = |input| {
+            let (narsese, end) = format.parse_prefix(input).expect("前缀解析失败");
+            (narsese, &input[end..])
+        };
+        let parse = |input| format.parse(input).expect("解析失败");
+        // 嵌入在DSL中的任务
+        let dsl = "believe($0.5;0.75;0.4$ <A --> B>. :|: %1.0;0.9%, 0.9)";
+        let task = "$0.5;0.75;0.4$ <A --> B>. :|: %1.0;0.9%";
+        let begin = "believe(".len();
+        let (narsese, end) = format.parse_prefix(&dsl[begin..]).unwrap();
+        asserts! {
+            narsese => parse(task),
+            end => task.len(),
+            &dsl[begin + end..] => ", 0.9)",
+        }
+        asserts! {
+            // 词项、语句
+            parse_prefix("<A --> B> and more") => (parse("<A --> B>"), " and more"),
+            parse_prefix("A. rest") => (parse("A."), " rest"),
+            parse_prefix("<A --> B>? %1.0;0.9%)") => (parse("<A --> B>? %1.0;0.9%"), ")"),
+            // 只消耗一个值
+            parse_prefix("<A --> B>. <C --> D>.") => (parse("<A --> B>."), " <C --> D>."),
+            // 时间戳、真值不扫描到其后的内容中
+            parse_prefix("<A --> B>. :|:, :!1:") => (parse("<A --> B>. :|:"), ", :!1:"),
+            parse_prefix("<A --> B>. %1.0;0.9% %0.5;0.5%") => (parse("<A --> B>. %1.0;0.9%"), " %0.5;0.5%"),
+            parse_prefix("<A --> B>.%1.0;0.9") => (parse("<A --> B>."), "%1.0;0.9"),
+            // 多字节字符：偏移按字节计
+            parse_prefix("<甲 --> 乙>. 其余") => (parse("<甲 --> 乙>."), " 其余"),
+            // 整个输入
+            parse_prefix("<A --> B>.") => (parse("<A --> B>."), ""),
+        }
+        // 开头即非Narsese⇒报错
+        asserts! {
+            format.parse_prefix(")<A --> B>.").is_err(),
+            format.parse_prefix("  ").unwrap_err().is_empty_input(),
+        }
+    }
+
+    /// 测试/超长输入
+    /// * 🎯前缀匹配、错误构造不随剩余环境长度增长（参见`benches/parsers.rs`）
+    /// * 📌错误中的「环境切片」被截断
+    #[test]
+    fn test_parse_long_input() {
+        let format = &*FORMAT_ASCII;
+        let components = (0..1000).map(|i| format!("<a{i} --> b>")).collect::<Vec<_>>();
+        let input = format!("(&&, {})", components.join(", "));
+        let term = format.parse_term(&input).expect("超长合取解析失败");
+        let error = format.parse_term(&input[..input.len() - 1]).unwrap_err();
+        show!(&error);
+        asserts! {
+            matches!(&term, Term::Compound { terms, .. } if terms.len() == 1000),
+            error.to_string().chars().count() < 200,
+            // 前缀比环境长⇒不匹配
+            format.parse_term("<A -").is_err(),
+        }
+    }
+
+    /// 测试/各类空白
+    /// * 🎯全角空格`U+3000`、不换行空格`U+00A0`、制表符与普通空格同等对待
+    #[test]
+    fn test_parse_mixed_spaces() {
+        let cases = [
+            (
+                &*FORMAT_ASCII,
+                "<(*, {SELF}, $any, #some) --> ^do>. :|: %1.0;0.9%",
+                "<(*,\t{SELF},\u{3000}$any,\u{a0}#some)\u{3000}-->\t^do>.\u{a0}:|:\t%1.0;\u{3000}0.9%",
+            ),
+            (
+                &*FORMAT_HAN,
+                "「（积，『SELF』，任一any，其一some）是操作do」。现在真1.0、0.9值",
+                "「（积，\u{3000}『SELF』，\u{a0}任一any，\t其一some \u{3000}）是操作do」\u{3000}。\t现在\u{a0}真1.0、\u{3000}0.9值",
+            ),
+        ];
+        for (format, standard, spaced) in cases {
+            asserts! {
+                format.parse(spaced).unwrap() => format.parse(standard).unwrap(),
+            }
+        }
+    }
+
+    /// 测试/备选分隔符
+    /// * 🚩解析时可混用；格式化时总使用主分隔符
+    #[test]
+    fn test_alternate_separators() {
+        let format = &FORMAT_ASCII;
+        let parse = |input| format.parse(input).expect("解析失败");
+        asserts! {
+            parse("A. %1.0,0.9%") => parse("A. %1.0;0.9%"),
+            parse("$0.5,0.5;0.5$ A.") => parse("$0.5;0.5;0.5$ A."),
+            parse("$0.5,0.75,0.4$ <A --> B>! :|: %1.0,0.9%") => parse("$0.5;0.75;0.4$ <A --> B>! :|: %1.0;0.9%"),
+            // 格式化：使用主分隔符
+            format.format_narsese(&parse("A. %1.0,0.9%")) => "A. %1.0;0.9%",
+            format.format_narsese(&parse("$0.5,0.5;0.5$ A.")) => "$0.5;0.5;0.5$ A.",
+        }
+        // 自定义备选分隔符
+        let format = FORMAT_ASCII
+            .builder()
+            .truth_separator_alternates(["|", ","])
+            .is_truth_content(|c| matches!(c, '0'..='9' | '.' | ';' | ',' | '|'))
+            .build()
+            .unwrap();
+        asserts! {
+            format.parse("A. %1.0|0.9%").unwrap() => parse("A. %1.0;0.9%"),
+            format.format_narsese(&format.parse("A. %1.0|0.9%").unwrap()) => "A. %1.0;0.9%",
+        }
+    }
+
+    /// 测试/宽松解析
+    /// * 🎯词项出错时，其它条目仍被解析出来
+    #[test]
+    fn test_parse_lenient() {
+        let format = &FORMAT_ASCII;
+        // 正常输入：与严格解析一致，无错误
+        let (items, errors) = format.parse_items_lenient("$0.5;0.5;0.5$ <A --> B>. :|: %1.0;0.9%");
+        asserts! {
+            errors.is_empty(),
+            items.budget.is_some(),
+            items.term.is_some(),
+            items.punctuation => Some(".".into()),
+            items.stamp => Some(":|:".into()),
+            items.truth.is_some(),
+        }
+        // 词项括弧未闭合：词项缺失，但仍有标点、真值
+        let (items, errors) = format.parse_items_lenient("<A --> (*, B>. %1.0;0.9%");
+        asserts! {
+            errors.len() => 1,
+            items.term.is_none(),
+            items.punctuation => Some(".".into()),
+            items.truth.is_some(),
+        }
+        // 空输入
+        let (items, errors) = format.parse_items_lenient("  ");
+        asserts! {
+            items.term.is_none(),
+            errors.len() => 1,
+            errors[0].is_empty_input(),
+        }
+    }
+
+    /// 测试/部分解析
+    /// * 🎯不要求词项存在，并列出缺失的条目
+    #[test]
+    fn test_parse_partial() {
+        use crate::api::ItemKind::*;
+        let format = &FORMAT_ASCII;
+        // 只有预算值
+        let partial = format.parse_partial("$0.5$").unwrap();
+        asserts! {
+            partial.budget => Some(budget!["0.5"]),
+            partial.missing_items() => vec![Term, Punctuation, Stamp, Truth],
+            partial.fold() => None,
+        }
+        // 词项+标点：缺失时间戳、真值
+        let partial = format.parse_partial("<A-->B>.").unwrap();
+        asserts! {
+            partial.term.is_some(),
+            partial.punctuation => Some(".".into()),
+            partial.missing_items() => vec![Budget, Stamp, Truth],
+            partial.fold() => Some(format.parse("<A-->B>.").unwrap()),
+        }
+        // 全部条目
+        let partial = format.parse_partial("$0.5;0.5;0.5$ <A --> B>. :|: %1.0;0.9%").unwrap();
+        asserts! {
+            partial.missing_items() => vec![],
+        }
+        // 出错、空输入⇒报错
+        asserts! {
+            format.parse_partial("<A --> (*, B>.").is_err(),
+            format.parse_partial("  ").unwrap_err().is_empty_input(),
+        }
+    }
+
+    /// 测试/附带条目范围
+    /// * 🎯各条目的范围指向原始输入，不受「理想化」删去的空白影响
+    #[test]
+    fn test_parse_spanned() {
+        // 工具函数：按范围截取原始输入
+        fn slice(input: &str, range: Option<Range<usize>>) -> Option<&str> {
+            range.map(|range| &input[range])
+        }
+        let input = "$0.5$ <A --> B>. :|: %1.0%";
+        let SpannedNarsese { narsese, spans } = FORMAT_ASCII.parse_spanned(input).unwrap();
+        asserts! {
+            narsese => FORMAT_ASCII.parse(input).unwrap(),
+            spans => Spans {
+                budget: Some(0..5),
+                term: 6..15,
+                punctuation: Some(15..16),
+                stamp: Some(17..20),
+                truth: Some(21..26),
+            },
+        }
+        // 多余的空白、多字节字符
+        let input = "  $0.5$   < A-->B >  .  :|:   %1.0%  ";
+        let spans = FORMAT_ASCII.parse_spanned(input).unwrap().spans;
+        asserts! {
+            slice(input, spans.budget) => Some("$0.5$"),
+            &input[spans.term] => "< A-->B >",
+            slice(input, spans.punctuation) => Some("."),
+            slice(input, spans.stamp) => Some(":|:"),
+            slice(input, spans.truth) => Some("%1.0%"),
+        }
+        let input = "预0.5算 「甲是乙」。 现在 真1、0.9值";
+        let spans = FORMAT_HAN.parse_spanned(input).unwrap().spans;
+        asserts! {
+            slice(input, spans.budget) => Some("预0.5算"),
+            &input[spans.term] => "「甲是乙」",
+            slice(input, spans.punctuation) => Some("。"),
+            slice(input, spans.stamp) => Some("现在"),
+            slice(input, spans.truth) => Some("真1、0.9值"),
+        }
+        // 缺失的条目⇒无范围
+        let spans = FORMAT_ASCII.parse_spanned("<A --> B>").unwrap().spans;
+        asserts! {
+            spans => Spans {
+                term: 0..9,
+                ..Default::default()
+            },
+        }
+        // 出错⇒与常规解析一致
+        asserts! {
+            FORMAT_ASCII.parse_spanned("<A --> >.").is_err(),
+            FORMAT_ASCII.parse_spanned("  ").unwrap_err().is_empty_input(),
+        }
+    }
 }
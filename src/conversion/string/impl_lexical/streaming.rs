@@ -0,0 +1,291 @@
+//! 词法Narsese的流式解析
+//! * 🎯解析「分块到达」的输入
+//!   * 📄从TCP套接字读取Narsese：一条任务可能被拆分到多次读取中
+//! * 🚩内部缓冲所有输入，每当有「完整语句」时产出一个解析结果，剩余部分继续缓冲
+//!   * 📌已产出的部分只推进偏移量，不逐条从缓冲区开头移除：追加输入时再一并移除
+//!   * 📌已查找过的部分不再查找换行符；没有新输入时，不再重复解析末尾的不完整内容
+//! * 📌「完整语句」的判断
+//!   * 📍遇到换行符：换行符之前的内容即一条完整语句（去除注释，跳过空行与整行注释）
+//!   * 📍以「真值」结尾的语句/任务：真值是语句的最后一个条目，不可能再有后续
+//!     * ⚠️[PyNARS兼容模式](NarseseFormat::pynars_compat)下，真值之后仍可能到达时间戳
+//!     * 🚩此时须真值、时间戳俱全，才视作完整
+//!   * ⚠️其它情况（如`A.`、`<A --> B>`）下，后续仍可能到达标点、时间戳或真值
+//!     * 🚩此时不会提前产出，需等到换行符或[`StreamingParser::finish`]
+
+use super::{NarseseFormat, ParseResult};
+use crate::lexical::Narsese;
+
+/// 流式解析器
+/// * 🚩持有「词法Narsese格式」的引用与内部缓冲区
+/// * 📌每次[`Self::push_str`]后，反复调用[`Self::try_next`]直到其返回[`None`]
+#[derive(Debug, Clone)]
+pub struct StreamingParser<'a> {
+    /// 所用的格式
+    format: &'a NarseseFormat,
+    /// 输入缓冲区 | 含已产出的部分
+    buffer: String,
+    /// 已产出部分的长度（字节）
+    /// * 📌`buffer[consumed..]`即尚未解析的输入
+    consumed: usize,
+    /// 已查找过换行符的长度（字节）
+    /// * 📌`buffer[consumed..scanned]`中没有换行符
+    scanned: usize,
+    /// 是否已尝试解析末尾的不完整内容
+    /// * 🚩追加输入时重置
+    tail_attempted: bool,
+}
+
+impl<'a> StreamingParser<'a> {
+    /// 构造函数
+    pub fn new(format: &'a NarseseFormat) -> Self {
+        Self {
+            format,
+            buffer: String::new(),
+            consumed: 0,
+            scanned: 0,
+            tail_attempted: false,
+        }
+    }
+
+    /// 获取尚未解析的输入
+    pub fn buffered(&self) -> &str {
+        &self.buffer[self.consumed..]
+    }
+
+    /// 追加一块输入
+    /// * 🚩先移除已产出的部分，再追加
+    pub fn push_str(&mut self, chunk: &str) {
+        self.buffer.drain(..self.consumed);
+        self.scanned -= self.consumed;
+        self.consumed = 0;
+        self.buffer.push_str(chunk);
+        self.tail_attempted = false;
+    }
+
+    /// 将已产出部分推进到指定位置
+    fn consume_to(&mut self, end: usize) {
+        self.consumed = end;
+        self.scanned = end;
+    }
+
+    /// 尝试产出下一个解析结果
+    /// * 🚩有完整语句⇒产出其解析结果，并从缓冲区中移除
+    /// * 🚩无完整语句⇒[`None`]，等待更多输入
+    /// * ⚠️解析失败的行同样会被产出（作为错误）并移除，不会阻塞后续输入
+    pub fn try_next(&mut self) -> Option<ParseResult<Narsese>> {
+        // 按换行符切分：换行之前的内容必定完整
+        // * 🚩只在尚未查找过的部分中查找
+        while let Some(i) = self.buffer[self.scanned..].find('\n') {
+            let end = self.scanned + i;
+            let line = self.format.strip_comment(self.buffer[self.consumed..end].trim());
+            let result = match line.is_empty() {
+                true => None,
+                false => Some(self.format.parse(line)),
+            };
+            self.consume_to(end + 1);
+            if result.is_some() {
+                return result;
+            }
+        }
+        self.scanned = self.buffer.len();
+        // 无换行符：仅在「不可能再有后续」时视作完整
+        // * 🚩没有新输入⇒结果同上次，无需重复解析
+        if self.tail_attempted {
+            return None;
+        }
+        self.tail_attempted = true;
+        let rest = self.format.strip_comment(self.buffered().trim());
+        if rest.is_empty() {
+            return None;
+        }
+        match self.format.parse(rest) {
+            Ok(narsese) if is_complete(self.format, &narsese) => {
+                self.consume_to(self.buffer.len());
+                Some(Ok(narsese))
+            }
+            // 解析失败或可能尚未结束⇒继续等待
+            _ => None,
+        }
+    }
+
+    /// 结束输入，解析缓冲区中剩余的所有内容
+    /// * 🎯输入流关闭时调用
+    /// * 🚩剩余内容按行切分，去除注释，跳过空行与整行注释，同[`NarseseFormat::parse_all`]
+    pub fn finish(&mut self) -> Vec<ParseResult<Narsese>> {
+        let mut rest = std::mem::take(&mut self.buffer);
+        rest.drain(..self.consumed);
+        self.consume_to(0);
+        self.tail_attempted = false;
+        self.format
+            .statement_lines(&rest)
+            .map(|statement| self.format.parse(statement))
+            .collect()
+    }
+}
+
+/// 判断Narsese是否「不可能再有后续」
+/// * 📌真值是语句的最后一个条目：之后不可能再有标点、时间戳
+/// * 📌[PyNARS兼容模式](NarseseFormat::pynars_compat)下，真值、时间戳顺序无关
+///   * 🚩须两者俱全：`<A --> B>. %1;0.9%`之后仍可能到达` :|:`
+fn is_complete(format: &NarseseFormat, narsese: &Narsese) -> bool {
+    let sentence = match narsese {
+        Narsese::Term(..) => return false,
+        Narsese::Sentence(sentence) => sentence,
+        Narsese::Task(task) => &task.sentence,
+    };
+    match format.pynars_compat {
+        true => !sentence.truth.is_empty() && !sentence.stamp.is_empty(),
+        false => !sentence.truth.is_empty(),
+    }
+}
+
+/// 便捷构造
+impl NarseseFormat {
+    /// 基于此格式，构造一个[流式解析器](StreamingParser)
+    pub fn streaming_parser(&self) -> StreamingParser<'_> {
+        StreamingParser::new(self)
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversion::string::impl_lexical::format_instances::{FORMAT_ASCII, FORMAT_PYNARS};
+    use nar_dev_utils::{asserts, show};
+
+    /// 解析参照值
+    fn parse(input: &str) -> Narsese {
+        FORMAT_ASCII.parse(input).unwrap()
+    }
+
+    /// 一条任务拆成两块到达
+    #[test]
+    fn test_split_task() {
+        let mut parser = FORMAT_ASCII.streaming_parser();
+        parser.push_str("$0.5;0.5;0.5$ <A -->");
+        assert!(parser.try_next().is_none());
+        parser.push_str(" B>. :|: %1.0%");
+        let result = parser.try_next();
+        show!(&result);
+        let expected = parse("$0.5;0.5;0.5$ <A --> B>. :|: %1.0%");
+        asserts! {
+            matches!(result, Some(Ok(Narsese::Task(..)))),
+            result.unwrap().unwrap() => expected,
+            parser.buffered() => "",
+            parser.try_next().is_none(),
+            parser.finish().is_empty(),
+        }
+    }
+
+    /// 不会提前产出「可能尚未结束」的内容
+    #[test]
+    fn test_no_premature_yield() {
+        let mut parser = FORMAT_ASCII.streaming_parser();
+        // 词项之后可能还有标点
+        parser.push_str("<A --> B>");
+        assert!(parser.try_next().is_none());
+        // 标点之后可能还有时间戳、真值
+        parser.push_str(".");
+        assert!(parser.try_next().is_none());
+        parser.push_str(" :|:");
+        assert!(parser.try_next().is_none());
+        // 换行⇒完整
+        parser.push_str("\n");
+        asserts! {
+            parser.try_next().unwrap().unwrap() => parse("<A --> B>. :|:"),
+            parser.try_next().is_none(),
+        }
+        // 词项单独成行
+        parser.push_str("A\n");
+        assert_eq!(parser.try_next().unwrap().unwrap(), parse("A"));
+    }
+
+    /// PyNARS兼容模式：真值之后仍可能到达时间戳
+    #[test]
+    fn test_pynars_truth_before_stamp() {
+        let format = &FORMAT_PYNARS;
+        let mut parser = format.streaming_parser();
+        parser.push_str("<A --> B>. %1;0.9%");
+        assert!(parser.try_next().is_none());
+        parser.push_str(" :|:");
+        asserts! {
+            parser.try_next().unwrap().unwrap() => format.parse("<A --> B>. %1;0.9% :|:").unwrap(),
+            parser.buffered() => "",
+        }
+        // 无时间戳⇒等到换行
+        parser.push_str("<A --> C>. %1;0.9%");
+        assert!(parser.try_next().is_none());
+        parser.push_str("\n");
+        asserts! {
+            parser.try_next().unwrap().unwrap() => format.parse("<A --> C>. %1;0.9%").unwrap(),
+            parser.try_next().is_none(),
+        }
+        // 时间戳在前⇒同样须等到真值
+        parser.push_str("<A --> D>. :|:");
+        assert!(parser.try_next().is_none());
+        parser.push_str(" %1;0.9%");
+        assert_eq!(
+            parser.try_next().unwrap().unwrap(),
+            format.parse("<A --> D>. :|: %1;0.9%").unwrap()
+        );
+    }
+
+    /// 一块输入中的多行：逐条产出，缓冲区随之缩短
+    #[test]
+    fn test_many_lines() {
+        let mut parser = FORMAT_ASCII.streaming_parser();
+        let lines = (0..100).map(|i| format!("<A{i} --> B>.")).collect::<Vec<_>>();
+        parser.push_str(&lines.join("\n"));
+        for (i, line) in lines.iter().enumerate().take(99) {
+            assert_eq!(parser.try_next().unwrap().unwrap(), parse(line));
+            assert_eq!(parser.buffered(), lines[i + 1..].join("\n"));
+        }
+        // 最后一行尚无换行符：可能尚未结束
+        asserts! {
+            parser.try_next().is_none(),
+            parser.try_next().is_none(),
+            parser.buffered() => "<A99 --> B>.",
+        }
+        // 追加输入后继续
+        parser.push_str(" %1.0;0.9%");
+        asserts! {
+            parser.try_next().unwrap().unwrap() => parse("<A99 --> B>. %1.0;0.9%"),
+            parser.buffered() => "",
+            parser.try_next().is_none(),
+        }
+        // 换行符分块到达
+        parser.push_str("<C --> D>");
+        assert!(parser.try_next().is_none());
+        parser.push_str("!\nE");
+        asserts! {
+            parser.try_next().unwrap().unwrap() => parse("<C --> D>!"),
+            parser.buffered() => "E",
+            parser.finish().len() => 1,
+            parser.buffered() => "",
+        }
+    }
+
+    /// 多行、注释、错误与结束
+    #[test]
+    fn test_lines_and_finish() {
+        let mut parser = FORMAT_ASCII.streaming_parser();
+        parser.push_str("// 注释\nA.\n\n(A, B\n<C --> D>?");
+        let first = parser.try_next();
+        let second = parser.try_next();
+        asserts! {
+            first.unwrap().unwrap() => parse("A."),
+            matches!(second, Some(Err(..))),
+            // 剩余内容可能尚未结束
+            parser.try_next().is_none(),
+            parser.buffered() => "<C --> D>?",
+        }
+        // 结束时一并解析
+        let rest = parser.finish();
+        asserts! {
+            rest.len() => 1,
+            rest[0].as_ref().unwrap() => &parse("<C --> D>?"),
+            parser.buffered() => "",
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! 词法Narsese的「语法糖」展开与折叠
+//! * 🎯在不引入「枚举Narsese」的前提下，消除「实例」「属性」「实例属性」系词
+//!   * 📄`A {-- B` ⇔ `<{A} --> B>`
+//!   * 📄`A --] B` ⇔ `<A --> [B]>`
+//!   * 📄`A {-] B` ⇔ `<{A} --> [B]>`
+//! * 📌系词、集合括弧的语义来自格式
+//!   * 📄[`NarseseFormatStatement::copula_instance`]等
+//!   * 📄[`NarseseFormatCompound::set_extension_brackets`]等
+//! * 🚩递归作用于所有子词项；不匹配的词项保持不变
+
+use super::format::*;
+use crate::lexical::Term;
+
+/// 对词项的所有直接组分作变换
+/// * 📌原子词项⇒不变
+fn map_components(term: Term, mut f: impl FnMut(Term) -> Term) -> Term {
+    match term {
+        Term::Atom { .. } => term,
+        Term::Compound { connecter, terms } => Term::Compound {
+            connecter,
+            terms: terms.into_iter().map(f).collect(),
+        },
+        Term::Set {
+            left_bracket,
+            terms,
+            right_bracket,
+        } => Term::Set {
+            left_bracket,
+            terms: terms.into_iter().map(f).collect(),
+            right_bracket,
+        },
+        Term::Statement {
+            copula,
+            subject,
+            predicate,
+        } => Term::new_statement(copula, f(*subject), f(*predicate)),
+    }
+}
+
+/// 用括弧对包装成单元素集合
+fn wrap_set((left, right): &(String, String), term: Term) -> Term {
+    Term::new_set(left, vec![term], right)
+}
+
+/// 若为「以指定括弧对包装的单元素集合」，则解包；否则原样返回
+fn unwrap_set((left, right): &(String, String), term: Term) -> Result<Term, Term> {
+    match term {
+        Term::Set {
+            left_bracket,
+            mut terms,
+            right_bracket,
+        } if left_bracket == *left && right_bracket == *right && terms.len() == 1 => {
+            Ok(terms.pop().expect("已检查长度"))
+        }
+        term => Err(term),
+    }
+}
+
+/// 实现/语法糖
+impl NarseseFormat {
+    /// 展开语法糖
+    /// * 🚩「实例」「属性」「实例属性」⇒「继承」+单元素集合
+    ///   * 📄`A {-] B` ⇒ `<{A} --> [B]>`
+    /// * 📌递归展开所有子词项
+    /// * 📄逆操作：[`NarseseFormat::resugar`]
+    pub fn desugar(&self, term: Term) -> Term {
+        let term = map_components(term, |component| self.desugar(component));
+        let Term::Statement {
+            copula,
+            subject,
+            predicate,
+        } = term
+        else {
+            return term;
+        };
+        let statement = &self.statement;
+        let (wrap_subject, wrap_predicate) = match copula {
+            _ if copula == statement.copula_instance => (true, false),
+            _ if copula == statement.copula_property => (false, true),
+            _ if copula == statement.copula_instance_property => (true, true),
+            _ => return Term::new_statement(copula, *subject, *predicate),
+        };
+        let compound = &self.compound;
+        let subject = match wrap_subject {
+            true => wrap_set(&compound.set_extension_brackets, *subject),
+            false => *subject,
+        };
+        let predicate = match wrap_predicate {
+            true => wrap_set(&compound.set_intension_brackets, *predicate),
+            false => *predicate,
+        };
+        Term::new_statement(&statement.copula_inheritance, subject, predicate)
+    }
+
+    /// 折叠语法糖
+    /// * 🚩「继承」+单元素集合⇒「实例」「属性」「实例属性」
+    ///   * 📄`<{A} --> [B]>` ⇒ `A {-] B`
+    /// * 📌递归折叠所有子词项
+    /// * 📄逆操作：[`NarseseFormat::desugar`]
+    pub fn resugar(&self, term: Term) -> Term {
+        let term = map_components(term, |component| self.resugar(component));
+        let statement = &self.statement;
+        let (subject, predicate) = match term {
+            Term::Statement {
+                copula,
+                subject,
+                predicate,
+            } if copula == statement.copula_inheritance => (*subject, *predicate),
+            term => return term,
+        };
+        let compound = &self.compound;
+        let subject = unwrap_set(&compound.set_extension_brackets, subject);
+        let predicate = unwrap_set(&compound.set_intension_brackets, predicate);
+        let (copula, subject, predicate) = match (subject, predicate) {
+            (Ok(subject), Ok(predicate)) => {
+                (&statement.copula_instance_property, subject, predicate)
+            }
+            (Ok(subject), Err(predicate)) => (&statement.copula_instance, subject, predicate),
+            (Err(subject), Ok(predicate)) => (&statement.copula_property, subject, predicate),
+            (Err(subject), Err(predicate)) => (&statement.copula_inheritance, subject, predicate),
+        };
+        Term::new_statement(copula, subject, predicate)
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::GetTerm,
+        conversion::string::impl_lexical::format_instances::*,
+        lexical::{shortcuts::*, tests::_sample_task_ascii, Term},
+    };
+    use nar_dev_utils::{asserts, show};
+
+    /// 测试/展开与折叠
+    #[test]
+    fn test_desugar_resugar() {
+        let format = &*FORMAT_ASCII;
+        let a = || atom!("A");
+        let b = || atom!("B");
+        let ext = |term: Term| set!("{"; term; "}");
+        let int = |term: Term| set!("["; term; "]");
+        let pairs = [
+            (statement!(a() "{--" b()), statement!(ext(a()) "-->" b())),
+            (statement!(a() "--]" b()), statement!(a() "-->" int(b()))),
+            (
+                statement!(a() "{-]" b()),
+                statement!(ext(a()) "-->" int(b())),
+            ),
+            // 嵌套
+            (
+                compound!(
+                    "&&",
+                    statement!(a() "{-]" b()),
+                    statement!(statement!(a() "--]" b()) "==>" a())
+                ),
+                compound!(
+                    "&&",
+                    statement!(ext(a()) "-->" int(b())),
+                    statement!(statement!(a() "-->" int(b())) "==>" a()),
+                ),
+            ),
+        ];
+        for (sugared, desugared) in pairs {
+            asserts! {
+                format.desugar(sugared.clone()) => desugared.clone(),
+                format.resugar(desugared) => sugared,
+            }
+        }
+        // 不匹配⇒不变
+        let unchanged = [
+            statement!(a() "-->" b()),
+            statement!(set!("{"; a(), b(); "}") "-->" b()),
+            statement!(a() "<->" int(b())),
+            compound!("*", ext(a()), int(b())),
+            a(),
+        ];
+        for term in unchanged {
+            asserts! {
+                format.desugar(term.clone()) => term.clone(),
+                format.resugar(term.clone()) => term,
+            }
+        }
+    }
+
+    /// 测试/其它格式
+    #[test]
+    fn test_desugar_han() {
+        let format = &*FORMAT_HAN;
+        let term = format.parse("「A具有B」").unwrap().try_into_term().unwrap();
+        let desugared = format.desugar(term.clone());
+        show!(format.format_term(&desugared));
+        asserts! {
+            format.format_term(&desugared) => "「『A』是【B】」",
+            format.resugar(desugared) => term,
+        }
+    }
+
+    /// 测试/展开后折叠为枚举Narsese
+    /// * 🎯展开前后语义相同
+    #[test]
+    #[cfg(feature = "enum_narsese")]
+    fn test_desugar_fold() {
+        use crate::{
+            conversion::{
+                inter_type::lexical_fold::TryFoldInto,
+                string::impl_enum::format_instances::FORMAT_ASCII as ENUM_ASCII,
+            },
+            enum_narsese::Term as EnumTerm,
+        };
+        let format = &*FORMAT_ASCII;
+        let term = _sample_task_ascii().get_term().clone();
+        let desugared = format.desugar(term.clone());
+        show!(format.format_term(&desugared));
+        let fold = |term: Term| -> EnumTerm { term.try_fold_into(&ENUM_ASCII).unwrap() };
+        let expected =
+            EnumTerm::new_instance_property(EnumTerm::new_word("ball"), EnumTerm::new_word("left"));
+        asserts! {
+            // 不再含有语法糖
+            !format.format_term(&desugared).contains("{-]"),
+            format.resugar(desugared.clone()) => term.clone(),
+            fold(desugared.clone()) => fold(term),
+            fold(format.desugar(statement!(atom!("ball") "{-]" atom!("left")))) => expected,
+        }
+    }
+}
@@ -0,0 +1,433 @@
+//! 词法Narsese的「真值/预算值」数值检验
+//! * 🎯在「折叠为枚举Narsese」之前，尽早发现非法的数值
+//!   * 📄`%abc;1.5%`：非数值、超出范围
+//! * 📌可选的检验：词法解析器本身仍然宽松，不做此检验
+//! * 🚩检验内容
+//!   * 组分个数：真值至多2个，预算值至多3个
+//!   * 每个组分均能解析为[`FloatPrecision`]
+//!   * 每个组分均在`[0, 1]`范围内
+//! * ✨另可一步到位解析出「数值化」的词法Narsese：[`NarseseFormat::parse_numeric`]
+
+use super::{NarseseFormat, ParseError, ParseResult};
+use crate::{
+    api::{FloatPrecision, Located},
+    conversion::string::ParseErrorKind,
+    lexical::{
+        Budget, Narsese, NumericNarsese, NumericSentence, NumericTask, Sentence, Task, Truth,
+    },
+};
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// 真值组分的最大个数
+pub const MAX_TRUTH_COMPONENTS: usize = 2;
+
+/// 预算值组分的最大个数
+pub const MAX_BUDGET_COMPONENTS: usize = 3;
+
+/// 数值检验错误
+/// * 📌`item`：被检验的条目名称，如「真值」「预算值」
+/// * 📌`index`：出错组分的索引（从0开始）
+///   * 📌报错文本中按序数显示（从1开始）：`$0.5;2$`中的`2`为「第2个组分」
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidateError {
+    /// 组分过多
+    TooManyComponents {
+        item: &'static str,
+        max: usize,
+        len: usize,
+    },
+    /// 组分不是数值
+    NotANumber {
+        item: &'static str,
+        index: usize,
+        component: String,
+    },
+    /// 组分超出`[0, 1]`范围
+    OutOfRange {
+        item: &'static str,
+        index: usize,
+        component: String,
+    },
+}
+
+impl Display for ValidateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidateError::TooManyComponents { item, max, len } => {
+                write!(f, "{item}组分过多：至多{max}个，实际{len}个")
+            }
+            ValidateError::NotANumber {
+                item,
+                index,
+                component,
+            } => write!(f, "{item}的第{}个组分「{component}」不是数值", index + 1),
+            ValidateError::OutOfRange {
+                item,
+                index,
+                component,
+            } => write!(f, "{item}的第{}个组分「{component}」超出[0, 1]范围", index + 1),
+        }
+    }
+}
+
+impl Error for ValidateError {}
+
+/// 数值检验结果
+pub type ValidateResult<T = Vec<FloatPrecision>> = Result<T, ValidateError>;
+
+/// 带位置的数值检验结果
+/// * 🚩成功⇒带位置的数值；失败⇒带位置的错误
+///   * 📌位置信息与原值一致
+pub type LocatedValidateResult<T = Vec<FloatPrecision>> = Result<Located<T>, Located<ValidateError>>;
+
+/// 检验一串数值组分
+fn validate_floats(item: &'static str, components: &[String], max: usize) -> ValidateResult {
+    if components.len() > max {
+        return Err(ValidateError::TooManyComponents {
+            item,
+            max,
+            len: components.len(),
+        });
+    }
+    components
+        .iter()
+        .enumerate()
+        .map(|(index, component)| {
+            let value = component
+                .parse::<FloatPrecision>()
+                .map_err(|_| ValidateError::NotANumber {
+                    item,
+                    index,
+                    component: component.clone(),
+                })?;
+            // * 📌`NaN`亦不在范围内
+            match (0.0..=1.0).contains(&value) {
+                true => Ok(value),
+                false => Err(ValidateError::OutOfRange {
+                    item,
+                    index,
+                    component: component.clone(),
+                }),
+            }
+        })
+        .collect()
+}
+
+/// 实现/数值检验
+impl NarseseFormat {
+    /// 检验真值，并解析出其中的数值
+    /// * 📌空真值⇒空数组
+    pub fn validate_truth(&self, truth: &Truth) -> ValidateResult {
+        validate_floats("真值", truth, MAX_TRUTH_COMPONENTS)
+    }
+
+    /// 检验预算值，并解析出其中的数值
+    /// * 📌空预算值⇒空数组
+    pub fn validate_budget(&self, budget: &Budget) -> ValidateResult {
+        validate_floats("预算值", budget, MAX_BUDGET_COMPONENTS)
+    }
+
+    /// 检验带位置的真值
+    /// * 🎯配合「带位置的流式解析」使用：出错时可报告`file.nal:123`
+    /// * 📄参见[`Self::validate_truth`]
+    pub fn validate_truth_located(&self, truth: Located<&Truth>) -> LocatedValidateResult {
+        truth.map(|truth| self.validate_truth(truth)).transpose()
+    }
+
+    /// 检验带位置的预算值
+    /// * 🎯配合「带位置的流式解析」使用：出错时可报告`file.nal:123`
+    /// * 📄参见[`Self::validate_budget`]
+    pub fn validate_budget_located(&self, budget: Located<&Budget>) -> LocatedValidateResult {
+        budget.map(|budget| self.validate_budget(budget)).transpose()
+    }
+}
+
+/// 实现/数值化
+impl NarseseFormat {
+    /// 解析并数值化
+    /// * 🎯一步到位得到真值、预算值的数值，以及时间戳的[结构化视图](crate::lexical::StampKind)
+    /// * 🚩先按[`Self::parse`]解析，再检验并转换其中的真值、预算值、时间戳
+    ///   * 📌词项、标点保持词法形式
+    /// * ⚠️数值检验出错⇒解析错误：错误消息同[`ValidateError`]
+    /// * 📌[`Self::parse`]本身不受影响，仍然宽松
+    pub fn parse_numeric(&self, input: &str) -> ParseResult<NumericNarsese> {
+        let narsese = self.parse(input)?;
+        // 检验失败⇒以原始输入为出错环境
+        let to_parse_error = |error: ValidateError| {
+            let env = input.chars().collect::<Vec<_>>();
+            let kind = match error {
+                ValidateError::OutOfRange { .. } => ParseErrorKind::OutOfRange01,
+                ValidateError::NotANumber { .. } => ParseErrorKind::InvalidNumber,
+                ValidateError::TooManyComponents { .. } => ParseErrorKind::Other(error.to_string()),
+            };
+            ParseError::new(&error.to_string(), &env)
+                .with_kind(kind)
+                .with_source(error)
+        };
+        let numeric = match narsese {
+            Narsese::Term(term) => NumericNarsese::Term(term),
+            Narsese::Sentence(sentence) => NumericNarsese::Sentence(
+                self.numeric_sentence(sentence, to_parse_error)?,
+            ),
+            Narsese::Task(task) => {
+                let budget = self.validate_budget(&task.budget).map_err(to_parse_error)?;
+                let sentence = self.numeric_sentence(task.sentence, to_parse_error)?;
+                NumericNarsese::Task(NumericTask { budget, sentence })
+            }
+        };
+        Ok(numeric)
+    }
+
+    /// 数值化语句
+    fn numeric_sentence(
+        &self,
+        sentence: Sentence,
+        to_parse_error: impl Fn(ValidateError) -> ParseError,
+    ) -> ParseResult<NumericSentence> {
+        let truth = self.validate_truth(&sentence.truth).map_err(to_parse_error)?;
+        let stamp = self.parse_stamp_kind(&sentence.stamp)?;
+        Ok(NumericSentence {
+            term: sentence.term,
+            punctuation: sentence.punctuation,
+            stamp,
+            truth,
+        })
+    }
+}
+
+/// 便捷方法/语句
+impl Sentence {
+    /// 检验自身的真值，并解析出其中的数值
+    /// * 📄参见[`NarseseFormat::validate_truth`]
+    pub fn validated_truth(&self, format: &NarseseFormat) -> ValidateResult {
+        format.validate_truth(&self.truth)
+    }
+}
+
+/// 便捷方法/任务
+impl Task {
+    /// 检验自身的预算值，并解析出其中的数值
+    /// * 📄参见[`NarseseFormat::validate_budget`]
+    pub fn validated_budget(&self, format: &NarseseFormat) -> ValidateResult {
+        format.validate_budget(&self.budget)
+    }
+}
+
+/// 便捷方法/带位置的语句
+impl Located<Sentence> {
+    /// 检验自身的真值，出错时附带位置
+    /// * 📄参见[`NarseseFormat::validate_truth_located`]
+    pub fn validated_truth(&self, format: &NarseseFormat) -> LocatedValidateResult {
+        format.validate_truth_located(self.with_value(&self.value.truth))
+    }
+}
+
+/// 便捷方法/带位置的任务
+impl Located<Task> {
+    /// 检验自身的预算值，出错时附带位置
+    /// * 📄参见[`NarseseFormat::validate_budget_located`]
+    pub fn validated_budget(&self, format: &NarseseFormat) -> LocatedValidateResult {
+        format.validate_budget_located(self.with_value(&self.value.budget))
+    }
+
+    /// 检验自身语句的真值，出错时附带位置
+    /// * 📄参见[`NarseseFormat::validate_truth_located`]
+    pub fn validated_truth(&self, format: &NarseseFormat) -> LocatedValidateResult {
+        format.validate_truth_located(self.with_value(&self.value.sentence.truth))
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        conversion::string::impl_lexical::format_instances::{FORMAT_ASCII, FORMAT_HAN},
+        lexical::{tests::_sample_task_ascii, Narsese},
+    };
+    use nar_dev_utils::{asserts, show};
+
+    /// 工具函数：构造字符串数组
+    fn strings(components: &[&str]) -> Vec<String> {
+        components.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_validate() {
+        let format = &FORMAT_ASCII;
+        asserts! {
+            // 合法
+            format.validate_truth(&strings(&["1.0", "0.9"])) => Ok(vec![1.0, 0.9]),
+            format.validate_truth(&strings(&[".5"])) => Ok(vec![0.5]),
+            // 科学计数法、正号
+            format.validate_truth(&strings(&["1e0", "+9e-1"])) => Ok(vec![1.0, 0.9]),
+            format.validate_truth(&strings(&["1e5"])) => Err(ValidateError::OutOfRange {
+                item: "真值",
+                index: 0,
+                component: "1e5".into(),
+            }),
+            format.validate_truth(&strings(&[])) => Ok(vec![]),
+            format.validate_budget(&strings(&["0.5", "0.75", "0.4"])) => Ok(vec![0.5, 0.75, 0.4]),
+            // 非数值
+            format.validate_truth(&strings(&["abc", "0.9"])) => Err(ValidateError::NotANumber {
+                item: "真值",
+                index: 0,
+                component: "abc".into(),
+            }),
+            // 超出范围
+            format.validate_truth(&strings(&["1.0", "1.5"])) => Err(ValidateError::OutOfRange {
+                item: "真值",
+                index: 1,
+                component: "1.5".into(),
+            }),
+            format.validate_budget(&strings(&["0.5", "-0.1"])) => Err(ValidateError::OutOfRange {
+                item: "预算值",
+                index: 1,
+                component: "-0.1".into(),
+            }),
+            format.validate_truth(&strings(&["NaN"])) => Err(ValidateError::OutOfRange {
+                item: "真值",
+                index: 0,
+                component: "NaN".into(),
+            }),
+            // 组分过多
+            format.validate_truth(&strings(&["1", "0.9", "0.5"])) => Err(ValidateError::TooManyComponents {
+                item: "真值",
+                max: 2,
+                len: 3,
+            }),
+            format.validate_budget(&strings(&["0.5", "0.5", "0.5", "0.5"])) => Err(ValidateError::TooManyComponents {
+                item: "预算值",
+                max: 3,
+                len: 4,
+            }),
+        }
+        // 错误信息：指明出错的组分及其索引
+        let error = format
+            .validate_truth(&strings(&["abc", "0.9"]))
+            .unwrap_err();
+        show!(&error);
+        assert_eq!(error.to_string(), "真值的第1个组分「abc」不是数值");
+    }
+
+    /// 解析器保持宽松；检验需手动进行
+    #[test]
+    fn test_validated() {
+        let task = _sample_task_ascii();
+        asserts! {
+            task.validated_budget(&FORMAT_ASCII) => Ok(vec![0.5, 0.75, 0.4]),
+            task.sentence.validated_truth(&FORMAT_ASCII) => Ok(vec![1.0, 0.9]),
+        }
+        // 解析器只检查字符，不检查数值
+        let Ok(Narsese::Task(task)) = FORMAT_ASCII.parse("$0.5;2$ A. %1..0;0.9%") else {
+            panic!("解析失败");
+        };
+        asserts! {
+            task.validated_budget(&FORMAT_ASCII).unwrap_err().to_string() => "预算值的第2个组分「2」超出[0, 1]范围",
+            task.sentence.validated_truth(&FORMAT_ASCII).unwrap_err().to_string() => "真值的第1个组分「1..0」不是数值",
+        }
+        let Ok(Narsese::Sentence(sentence)) = FORMAT_HAN.parse("甲。真1、0.9值") else {
+            panic!("解析失败");
+        };
+        assert_eq!(sentence.validated_truth(&FORMAT_HAN), Ok(vec![1.0, 0.9]));
+    }
+
+    /// 带位置的检验
+    /// * 🎯检验出错时，能报告原始行号
+    #[test]
+    fn test_validate_located() {
+        let input = "A. %1.0;0.9%\n\n$0.5;2$ B. %1.0;0.9%\n  C. %1.5%";
+        let parsed = FORMAT_ASCII
+            .parse_stream_located(input, Some("fixture.nal".into()))
+            .map(|located| located.map(Result::unwrap))
+            .collect::<Vec<_>>();
+        let sentence = |located: &Located<Narsese>| {
+            located.with_value(located.value.clone().try_into_sentence().unwrap())
+        };
+        let task = |located: &Located<Narsese>| {
+            located.with_value(located.value.clone().try_into_task().unwrap())
+        };
+        // 合法⇒带位置的数值
+        let truth = sentence(&parsed[0]).validated_truth(&FORMAT_ASCII).unwrap();
+        asserts! {
+            truth.value => vec![1.0, 0.9],
+            truth.location() => "fixture.nal:1:1",
+            task(&parsed[1]).validated_truth(&FORMAT_ASCII).unwrap().value => vec![1.0, 0.9],
+        }
+        // 非法⇒带位置的错误
+        let budget_error = task(&parsed[1]).validated_budget(&FORMAT_ASCII).unwrap_err();
+        let truth_error = sentence(&parsed[2]).validated_truth(&FORMAT_ASCII).unwrap_err();
+        asserts! {
+            budget_error.location() => "fixture.nal:3:1",
+            budget_error.to_string() => "fixture.nal:3:1: 预算值的第2个组分「2」超出[0, 1]范围",
+            truth_error.location() => "fixture.nal:4:3",
+            truth_error.value => ValidateError::OutOfRange {
+                item: "真值",
+                index: 0,
+                component: "1.5".into(),
+            },
+        }
+        // 格式上的版本：直接传入带位置的真值
+        let located_truth = parsed[2].as_ref().map(|narsese| match narsese {
+            Narsese::Sentence(sentence) => &sentence.truth,
+            _ => unreachable!(),
+        });
+        assert_eq!(
+            FORMAT_ASCII.validate_truth_located(located_truth),
+            Err(truth_error)
+        );
+    }
+
+    /// 解析并数值化
+    #[test]
+    fn test_parse_numeric() {
+        use crate::lexical::{shortcuts::*, StampKind};
+        let term = statement!(atom!("A") "-->" atom!("B"));
+        asserts! {
+            // 词项：原样
+            FORMAT_ASCII.parse_numeric("<A --> B>").unwrap() => NumericNarsese::Term(term.clone()),
+            // 语句
+            FORMAT_ASCII.parse_numeric("<A --> B>. :!-1: %1.0;0.9%").unwrap() => NumericNarsese::Sentence(NumericSentence {
+                term: term.clone(),
+                punctuation: ".".into(),
+                stamp: StampKind::Fixed(-1),
+                truth: vec![1.0, 0.9],
+            }),
+            // 任务 | 空真值、永恒
+            FORMAT_ASCII.parse_numeric("$0.5;0.75;0.4$ <A --> B>?").unwrap() => NumericNarsese::Task(NumericTask {
+                budget: vec![0.5, 0.75, 0.4],
+                sentence: NumericSentence {
+                    term: term.clone(),
+                    punctuation: "?".into(),
+                    stamp: StampKind::Eternal,
+                    truth: vec![],
+                },
+            }),
+            // 漢文
+            FORMAT_HAN.parse_numeric("预0.5、0.5算「甲是乙」。现在真1.0、0.9值").unwrap() => NumericNarsese::Task(NumericTask {
+                budget: vec![0.5, 0.5],
+                sentence: NumericSentence {
+                    term: statement!(atom!("甲") "是" atom!("乙")),
+                    punctuation: "。".into(),
+                    stamp: StampKind::Present,
+                    truth: vec![1.0, 0.9],
+                },
+            }),
+        }
+        // 错误：超出范围、非数值
+        let error = |format: &NarseseFormat, input| format.parse_numeric(input).unwrap_err().message().to_string();
+        asserts! {
+            error(&FORMAT_ASCII, "A. %1.5;0.9%") => "真值的第1个组分「1.5」超出[0, 1]范围",
+            error(&FORMAT_ASCII, "$0.5;2$ A.") => "预算值的第2个组分「2」超出[0, 1]范围",
+            error(&FORMAT_ASCII, "A. %1..0;0.9%") => "真值的第1个组分「1..0」不是数值",
+            error(&FORMAT_HAN, "甲。真1、1.9值") => "真值的第2个组分「1.9」超出[0, 1]范围",
+            error(&FORMAT_HAN, "预0.5、0.5、0.5、0.5算甲。") => "预算值组分过多：至多3个，实际4个",
+            error(&FORMAT_HAN, "甲。真1..0值") => "真值的第1个组分「1..0」不是数值",
+            // 解析错误原样上抛
+            FORMAT_ASCII.parse_numeric("").unwrap_err().is_empty_input(),
+        }
+    }
+}
@@ -26,3 +26,7 @@ pub mod impl_lexical;
 
 // 实现/Typst格式化器
 pub mod typst_formatter;
+
+// 健壮性测试 | 解析器对任意输入均不panic
+#[cfg(test)]
+mod robustness;
@@ -0,0 +1,223 @@
+//! 解析器的健壮性测试
+//! * 🎯保证：对任意输入，解析器只会返回错误，绝不panic
+//! * 🚩两部分
+//!   * 📌语料：曾导致panic的输入（含模糊测试所得）
+//!   * 📌随机：固定种子的伪随机输入，偏向Narsese中的特殊字符与多字节字符
+//! * 📌覆盖「枚举Narsese」与「词法Narsese」的所有内置格式
+//! * 📌前缀解析返回的偏移须落在字符边界上
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// 曾导致panic的输入
+const CRASH_CORPUS: &[&str] = &[
+    // 多个真值/预算值 | 数组越界
+    "1. %1;1;1%",
+    "$1;1;1;1$ 1.",
+    "$1;1;1;1;1;1;1;1;1;1;1$ 1. %1;1;1;1;1;1;1;1;1%",
+    // 缺少右括弧 | 跳过右括弧时越界
+    r"\langle{}",
+    r"\langle{}0",
+    r"?\langle{}+9",
+    r"$\langle{}1e5",
+    r"\langle{}1,0.9 A",
+    r"A. \langle{}1,0.9",
+    "%1;0.9",
+    ":|é",
+    ":!1",
+    "$0.5;0.5",
+    // 未闭合的括弧与占位符
+    "(/, _",
+    "(\\, _, _",
+    "<(/, _ --> _>",
+    "$0.5$ (/",
+    "$$ _.",
+    "$0.5;0.5$ <_ --> {",
+    // 多字节字符出现在边界处
+    "<甲 --> 乙>",
+    "<A -->乙",
+    "<A ——> B>",
+    "「甲是乙",
+    "（接连，甲",
+    "『",
+    "<A --> é>.%",
+    "$é",
+    "é$",
+    ":|",
+    "%é%",
+    // 空白、BOM与控制字符
+    "\u{feff}<A --> B>",
+    "<A\u{0}--> B>.",
+    "\t\r\n.",
+    // 仅有分隔符/括弧
+    "<",
+    ">",
+    "<>",
+    "(,)",
+    "{,}",
+    "[]",
+    "%%",
+    "$$",
+    "::",
+    ".!?@",
+];
+
+/// 伪随机数生成器（xorshift64）
+/// * 🎯不引入外部依赖，且结果可复现
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+/// 随机输入的字符表
+/// * 📌以各格式中的特殊字符为主，辅以多字节字符
+const ALPHABET: &[char] = &[
+    '<', '>', '(', ')', '{', '}', '[', ']', '-', '=', '/', '\\', '|', '&', '*', '~', ',', ';', ':',
+    '!', '?', '.', '@', '$', '#', '%', '^', '+', '_', '0', '1', '9', 'A', 'b', ' ', '\t', '\n',
+    '"', '\'', '甲', '是', '「', '」', '（', '）', '，', '。', '『', '』', '【', '】', '接', '连',
+    '预', '真', '、', 'é', '\u{feff}',
+];
+
+/// 随机输入的词符表
+/// * 🎯使随机输入更容易形成「部分合法」的结构，深入解析器内部
+/// * 📌各格式的括弧、系词、连接符、真值、预算值、时间戳
+const TOKENS: &[&str] = &[
+    "<",
+    ">",
+    "-->",
+    "<->",
+    "==>",
+    "=/>",
+    "(",
+    ")",
+    "(&/,",
+    "(/,",
+    r"(\,",
+    "(--,",
+    "(*,",
+    "{",
+    "}",
+    "[",
+    "]",
+    ",",
+    " ",
+    "_",
+    "A",
+    "$x",
+    "#",
+    "?q",
+    "^op",
+    "+3",
+    "+",
+    "$0.5;0.5;0.5$",
+    "$",
+    "%1.0;0.9%",
+    "%",
+    ";",
+    ":|:",
+    ":!1:",
+    ":",
+    ".",
+    "?",
+    "!",
+    "@",
+    "--",
+    "0",
+    "1e5",
+    "-1",
+    r"\left(",
+    r"\cap{}",
+    r"\rightarrow{}",
+    r"\left<",
+    r"\right>",
+    r"\langle{}",
+    r"\rangle{}",
+    "甲",
+    "「",
+    "」",
+    "（",
+    "接连，",
+    "『",
+    "预",
+    "真",
+    "、",
+    "。",
+    "是",
+    "é",
+];
+
+/// 生成一个随机输入
+/// * 🚩随机拼接词符与单个字符
+fn random_input(rng: &mut XorShift) -> String {
+    let len = rng.below(32);
+    (0..len)
+        .map(|_| match rng.below(4) {
+            0 => ALPHABET[rng.below(ALPHABET.len())].to_string(),
+            _ => TOKENS[rng.below(TOKENS.len())].to_string(),
+        })
+        .collect()
+}
+
+/// 随机输入的个数
+const RANDOM_CASES: usize = 3000;
+
+/// 生成所有待测输入
+/// * 🚩语料 + 固定种子的随机输入
+fn inputs() -> Vec<String> {
+    let mut rng = XorShift(0x5EED_2024_0415_0001);
+    CRASH_CORPUS
+        .iter()
+        .map(|input| input.to_string())
+        .chain((0..RANDOM_CASES).map(|_| random_input(&mut rng)))
+        .collect()
+}
+
+/// 对所有输入运行解析函数，收集所有panic的输入
+/// * ⚠️不替换全局的panic钩子：测试并行运行，替换会吞掉其它测试的panic信息
+fn panicking_inputs(parse: impl Fn(&str)) -> Vec<String> {
+    inputs()
+        .into_iter()
+        .filter(|input| catch_unwind(AssertUnwindSafe(|| parse(input))).is_err())
+        .collect()
+}
+
+/// 枚举Narsese：所有内置格式均不panic
+#[test]
+#[cfg(feature = "enum_narsese")]
+fn test_enum_parser_no_panic() {
+    use super::impl_enum::format_instances::*;
+    use crate::enum_narsese::Narsese;
+    for format in [&FORMAT_ASCII, &FORMAT_LATEX, &FORMAT_HAN] {
+        let panicked = panicking_inputs(|input| {
+            let _ = format.parse::<Narsese>(input);
+            let _ = format.parse_prefix(input).map(|(_, end)| &input[end..]);
+        });
+        assert!(panicked.is_empty(), "以下输入导致panic：{panicked:?}");
+    }
+}
+
+/// 词法Narsese：所有内置格式均不panic
+#[test]
+#[cfg(feature = "lexical_narsese")]
+fn test_lexical_parser_no_panic() {
+    use super::impl_lexical::format_instances::*;
+    for format in [&*FORMAT_ASCII, &*FORMAT_LATEX, &*FORMAT_HAN] {
+        let panicked = panicking_inputs(|input| {
+            let _ = format.parse(input);
+            let _ = format.parse_term(input);
+            let _ = format.parse_prefix(input).map(|(_, end)| &input[end..]);
+        });
+        assert!(panicked.is_empty(), "以下输入导致panic：{panicked:?}");
+    }
+}
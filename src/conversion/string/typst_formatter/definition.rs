@@ -10,6 +10,8 @@
 //!   * 🎯除用于格式化「枚举Narsese」外，还可被其它 解析器/格式化器 用于更多目的
 
 use crate::api::FormatTo;
+#[cfg(any(feature = "enum_narsese", feature = "lexical_narsese"))]
+use crate::conversion::string::template_components;
 use nar_dev_utils::if_return;
 
 /// Typst格式化器
@@ -21,11 +23,54 @@ impl FormatterTypst {
     /// * ✨枚举Narsese
     /// * ❌词法Narsese
     ///   * 🚩【2024-04-05 20:13:46】缺乏语义信息
+    ///   * ✅可使用`format_lexical_term`等方法尽力格式化
     pub fn format<'s, T>(&'s self, target: &impl FormatTo<&'s Self, T>) -> T {
         target.format_to(self)
     }
 }
 
+/// 通用模板
+/// * 🎯供「枚举Narsese」「词法Narsese」共用
+impl FormatterTypst {
+    /// 模板/一般复合词项
+    /// * 🎯使用「连接符」区分「复合类型」的词项
+    /// * 📝对于「字符串字面量数组」，`Vec<&str>`的引用类型对应`&[&str]`而非`&[str]`
+    ///   * ⚠️后者的`str`是大小不定的：the size for values of type `str` cannot be known at compilation time
+    #[cfg(any(feature = "enum_narsese", feature = "lexical_narsese"))]
+    pub(super) fn template_compound(
+        out: &mut String,
+        brackets: (&str, &str),
+        connecter: &str,
+        components: impl Iterator<Item = String>,
+        separator: &str,
+    ) {
+        // 先收集迭代器
+        let strings = components.collect::<Vec<_>>();
+        // 左括号
+        out.push_str(brackets.0);
+        // 分派方法：针对内容数目、连接符是否为「集合词项」（是否为空）
+        match (strings.len(), connecter) {
+            // 集合⇒直接上内容
+            (_, "") => template_components(out, strings.into_iter(), separator, ""),
+            // 二元非集合⇒中缀形式
+            // * 🚩组分 & 连接符 as 分隔符 | `A * B`
+            (2, _) => template_components(out, strings.into_iter(), connecter, ""),
+            // 一元/多元 非集合⇒前缀形式
+            // * 🚩组分 | `A, B, C`
+            _ => {
+                //连接符与分隔符
+                out.push_str(connecter);
+                // 分隔符
+                out.push_str(separator);
+                // 组分
+                template_components(out, strings.into_iter(), separator, "")
+            }
+        }
+        // 右括号 | `)`
+        out.push_str(brackets.1);
+    }
+}
+
 // * 原子词项前缀 * //
 
 /// 原子词项前缀/词语
@@ -44,7 +89,7 @@ pub const TERM_PREFIX_INTERVAL: &str = " + #h(-0.05em) ";
 pub const TERM_PREFIX_OPERATOR: &str = " arrow.t.double #h(-0.05em) ";
 
 // * 括弧 * //
-/// * 🚩在各自代码中区分「是否内包空格」
+// * 🚩在各自代码中区分「是否内包空格」
 
 /// 复合词项括弧
 pub const BRACKETS_COMPOUND: (&str, &str) = (" lr(( ", " )) ");
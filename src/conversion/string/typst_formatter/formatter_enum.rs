@@ -8,7 +8,7 @@ use crate::{
         FloatPrecision, FormatTo, GetBudget, GetCategory, GetPunctuation, GetStamp, GetTerm,
         GetTruth, TermCategory,
     },
-    conversion::string::{template_atom, template_components, template_statement},
+    conversion::string::{template_atom, template_statement},
     enum_narsese::{Budget, Punctuation, Sentence, Stamp, Task, Term, Truth},
 };
 use nar_dev_utils::{manipulate, ToDebug};
@@ -57,6 +57,7 @@ impl FormatterTypst {
             ImplicationRetrospective(..) => COPULA_IMPLICATION_RETROSPECTIVE,
             EquivalencePredictive(..) => COPULA_EQUIVALENCE_PREDICTIVE,
             EquivalenceConcurrent(..) => COPULA_EQUIVALENCE_CONCURRENT,
+            EquivalenceRetrospective(..) => COPULA_EQUIVALENCE_RETROSPECTIVE,
         }
     }
 
@@ -83,43 +84,6 @@ impl FormatterTypst {
         }
     }
 
-    /// 模板/一般复合词项
-    /// * 🎯使用「连接符」区分「复合类型」的词项
-    /// * 📝对于「字符串字面量数组」，`Vec<&str>`的引用类型对应`&[&str]`而非`&[str]`
-    ///   * ⚠️后者的`str`是大小不定的：the size for values of type `str` cannot be known at compilation time
-    fn template_compound(
-        out: &mut String,
-        brackets: (&str, &str),
-        connecter: &str,
-        components: impl Iterator<Item = String>,
-        separator: &str,
-    ) {
-        // 先收集迭代器
-        let strings = components.collect::<Vec<_>>();
-        // 左括号
-        out.push_str(brackets.0);
-        // 分派方法：针对内容数目、连接符是否为「集合词项」（是否为空）
-        match (strings.len(), connecter) {
-            // 集合⇒直接上内容
-            (_, "") => template_components(out, strings.into_iter(), separator, ""),
-            // 二元非集合⇒中缀形式
-            // * 🚩组分 & 连接符 as 分隔符 | `A * B`
-            (2, _) => template_components(out, strings.into_iter(), connecter, ""),
-            // 一元/多元 非集合⇒前缀形式
-            // * 🚩组分 | `A, B, C`
-            _ => {
-                //连接符与分隔符
-                out.push_str(connecter);
-                // 分隔符
-                out.push_str(separator);
-                // 组分
-                template_components(out, strings.into_iter(), separator, "")
-            }
-        }
-        // 右括号 | `)`
-        out.push_str(brackets.1);
-    }
-
     /// 【内部】格式化/词项
     fn format_term(&self, out: &mut String, term: &Term) {
         // 特征字串/括弧字串
@@ -225,6 +189,10 @@ impl FormatterTypst {
             Single(f) => self._format_floats(out, BRACKETS_TRUTH, SEPARATOR_TRUTH, &[*f]),
             // 双真值⇒二元数组
             Double(f, c) => self._format_floats(out, BRACKETS_TRUTH, SEPARATOR_TRUTH, &[*f, *c]),
+            // 三真值⇒三元数组
+            Triple(f, c, k) => {
+                self._format_floats(out, BRACKETS_TRUTH, SEPARATOR_TRUTH, &[*f, *c, *k])
+            }
         }
     }
 
@@ -1,47 +1,340 @@
 //! 词法Narsese的Typst格式化
-//! * ❌【2024-04-05 19:56:02】废弃：词法Narsese无法有效承载「语义信息」
+//! * 📌【2024-04-05 19:56:02】词法Narsese无法有效承载「语义信息」
 //!   * ℹ️词法Narsese仅提供「词法层面的内容」，而不提供「语义层面的信息」
-//!     * ❌因此无法将「原子词项前缀」「复合词项连接词」「陈述系词」等元素与Typst公式一一对应
 //!     * 📄即便知道「陈述系词」是`"-->"`，除非联动「枚举Narsese」，也不能就直接映射到`arrow.r`
-//! * 📌【2024-04-05 20:08:40】只有「枚举Narsese」才能真正提供「语义支持」
-//!   * ❗无需顾忌「哪种陈述系词对应哪个Typst公式」：如`"==>"`🆚`"=/>"`
-//!   * ❗无需顾忌「一种陈述系词在各个Narsese格式中如何表示」：如`-->`🆚`是`
-//!   * ❌相比之下，词法Narsese中`"-->"`和`"是"`不是同一种系词——即便语义相同
-//! * ❓几个可能的替代使用方案
-//!   * 🔦「词法折叠」方法：尝试折叠到「枚举Narsese」，再格式化为Typst公式
-//!   * 🔦「尽可能回归枚举Narsese」方法：尽可能映射到「枚举Narsese」的情况
-//!     * 建立「原子词项前缀/复合词项连接词/陈述系词 → Typst公式」的映射
-//!     * 若在映射表内，将其特别转换为Typst公式
-//!     * 若不在映射表内，使用默认转换方式
-
-// use super::FormatterTypst;
-// use crate::{api::FormatTo, lexical::Term};
-// use nar_dev_utils::ToDebug;
-
-// /// 【占位符】将「需要转换为Typst公式的内容」转换为Typst公式
-// /// * 🚩【2024-04-05 19:45:50】目前仅将其稍作「引用」处理
-// ///   * 📌附带转义
-// ///   * 🎯仅要求其能在Typst处正常显示
-// fn to_typst(s: &str) -> String {
-//     s.to_debug()
-// }
-
-// /// 格式化/词项
-// impl FormatTo<&FormatterTypst, String> for Term {
-//     fn format_to(&self, formatter: &FormatterTypst) -> String {
-//         match self {
-//             Term::Atom { prefix, name } => format!("{} {}", to_typst(prefix), name),
-//             Term::Compound { connecter, terms } => todo!(),
-//             Term::Set {
-//                 left_bracket,
-//                 terms,
-//                 right_bracket,
-//             } => todo!(),
-//             Term::Statement {
-//                 copula,
-//                 subject,
-//                 predicate,
-//             } => todo!(),
-//         }
-//     }
-// }
+//!   * ❌「词法折叠」到「枚举Narsese」会丢失方言特有的词项（如`{-]`会被展开）
+//! * 🚩采用「尽力而为」方案：尽可能回归「枚举Narsese」的情况
+//!   * 📌以**ASCII格式**的「原子词项前缀/复合词项连接词/陈述系词」为映射表
+//!   * ✅若在映射表内，转换为与「枚举Narsese」相同的Typst公式
+//!   * ⚠️若不在映射表内，作为「带引号的字面量」原样输出，而不报错
+//!   * 📌原子词项名称一律加引号（附带转义）
+//! * 🚩真值、预算值能解析为浮点数时，按浮点数格式化，与「枚举Narsese」保持一致
+
+use super::definition::*;
+use crate::{
+    api::FloatPrecision,
+    conversion::string::{
+        impl_lexical::format_instances::FORMAT_ASCII, template_atom, template_statement,
+    },
+    lexical::{Budget, Punctuation, Sentence, Stamp, StampKind, Task, Term, Truth},
+};
+use nar_dev_utils::{manipulate, ToDebug};
+
+/// 将「不在映射表内」的内容转换为Typst字面量
+/// * 🚩加上引号并转义，两侧附带空格
+fn quoted(s: &str) -> String {
+    format!(" {} ", s.to_debug())
+}
+
+/// 映射/原子词项前缀
+fn map_atom_prefix(prefix: &str) -> Option<&'static str> {
+    Some(match prefix {
+        "" => TERM_PREFIX_WORD,
+        "_" => TERM_PREFIX_PLACEHOLDER,
+        "$" => TERM_PREFIX_I_VAR,
+        "#" => TERM_PREFIX_D_VAR,
+        "?" => TERM_PREFIX_Q_VAR,
+        "+" => TERM_PREFIX_INTERVAL,
+        "^" => TERM_PREFIX_OPERATOR,
+        _ => return None,
+    })
+}
+
+/// 映射/复合词项连接词
+fn map_connecter(connecter: &str) -> Option<&'static str> {
+    Some(match connecter {
+        "&" => CONNECTER_EXT_INTERSECT,
+        "|" => CONNECTER_INT_INTERSECT,
+        "-" => CONNECTER_EXT_DIFFERENCE,
+        "~" => CONNECTER_INT_DIFFERENCE,
+        "*" => CONNECTER_PRODUCT,
+        "/" => CONNECTER_EXT_IMAGE,
+        r"\" => CONNECTER_INT_IMAGE,
+        "&&" => CONNECTER_CONJUNCTION,
+        "||" => CONNECTER_DISJUNCTION,
+        "--" => CONNECTER_NEGATION,
+        "&/" => CONNECTER_SEQ_CONJUNCTION,
+        "&|" => CONNECTER_PAR_CONJUNCTION,
+        _ => return None,
+    })
+}
+
+/// 映射/集合括弧
+fn map_set_brackets(left: &str, right: &str) -> Option<(&'static str, &'static str)> {
+    match (left, right) {
+        ("{", "}") => Some(BRACKETS_EXT_SET),
+        ("[", "]") => Some(BRACKETS_INT_SET),
+        _ => None,
+    }
+}
+
+/// 映射/陈述系词
+fn map_copula(copula: &str) -> Option<&'static str> {
+    Some(match copula {
+        "-->" => COPULA_INHERITANCE,
+        "<->" => COPULA_SIMILARITY,
+        "==>" => COPULA_IMPLICATION,
+        "<=>" => COPULA_EQUIVALENCE,
+        "{--" => COPULA_INSTANCE,
+        "--]" => COPULA_PROPERTY,
+        "{-]" => COPULA_INSTANCE_PROPERTY,
+        "=/>" => COPULA_IMPLICATION_PREDICTIVE,
+        "=|>" => COPULA_IMPLICATION_CONCURRENT,
+        r"=\>" => COPULA_IMPLICATION_RETROSPECTIVE,
+        "</>" => COPULA_EQUIVALENCE_PREDICTIVE,
+        "<|>" => COPULA_EQUIVALENCE_CONCURRENT,
+        r"<\>" => COPULA_EQUIVALENCE_RETROSPECTIVE,
+        _ => return None,
+    })
+}
+
+/// 映射/标点
+fn map_punctuation(punctuation: &str) -> Option<&'static str> {
+    Some(match punctuation {
+        "." => PUNCTUATION_JUDGEMENT,
+        "!" => PUNCTUATION_GOAL,
+        "?" => PUNCTUATION_QUESTION,
+        "@" => PUNCTUATION_QUEST,
+        _ => return None,
+    })
+}
+
+/// 内部格式化方法
+impl FormatterTypst {
+    /// 【内部】格式化/词法词项
+    fn format_lexical_term_to(&self, out: &mut String, term: &Term) {
+        let format_all = |terms: &[Term]| {
+            terms
+                .iter()
+                .map(|t| self.format_lexical_term(t))
+                .collect::<Vec<_>>()
+                .into_iter()
+        };
+        match term {
+            // 原子词项 | 前缀 + 带引号的词项名
+            Term::Atom { prefix, name } => template_atom(
+                out,
+                &map_atom_prefix(prefix).map_or_else(|| quoted(prefix), str::to_string),
+                &name.to_debug(),
+            ),
+            // 复合词项
+            Term::Compound { connecter, terms } => Self::template_compound(
+                out,
+                BRACKETS_COMPOUND,
+                &map_connecter(connecter).map_or_else(|| quoted(connecter), str::to_string),
+                format_all(terms),
+                SEPARATOR_COMPOUND,
+            ),
+            // 集合 | 未知括弧⇒带引号的括弧作为连接符
+            Term::Set {
+                left_bracket,
+                terms,
+                right_bracket,
+            } => match map_set_brackets(left_bracket, right_bracket) {
+                Some(brackets) => Self::template_compound(
+                    out,
+                    brackets,
+                    "",
+                    format_all(terms),
+                    SEPARATOR_COMPOUND,
+                ),
+                None => Self::template_compound(
+                    out,
+                    (
+                        &format!("{}{}", BRACKETS_COMPOUND.0, quoted(left_bracket)),
+                        &format!("{}{}", quoted(right_bracket), BRACKETS_COMPOUND.1),
+                    ),
+                    "",
+                    format_all(terms),
+                    SEPARATOR_COMPOUND,
+                ),
+            },
+            // 陈述
+            Term::Statement {
+                copula,
+                subject,
+                predicate,
+            } => template_statement(
+                out,
+                BRACKETS_STATEMENT.0,
+                &self.format_lexical_term(subject),
+                &map_copula(copula).map_or_else(|| quoted(copula), str::to_string),
+                &self.format_lexical_term(predicate),
+                SEPARATOR_STATEMENT,
+                BRACKETS_STATEMENT.1,
+            ),
+        }
+    }
+
+    /// 【内部】格式化/词法标点
+    fn format_lexical_punctuation(&self, out: &mut String, punctuation: &Punctuation) {
+        match map_punctuation(punctuation) {
+            Some(s) => out.push_str(s),
+            None => out.push_str(&quoted(punctuation)),
+        }
+    }
+
+    /// 【内部】格式化/词法时间戳
+    /// * 🚩借助ASCII格式解析出时间戳类型
+    fn format_lexical_stamp(&self, out: &mut String, stamp: &Stamp) {
+        match FORMAT_ASCII.parse_stamp_kind(stamp) {
+            Ok(StampKind::Eternal) => out.push_str(STAMP_ETERNAL),
+            Ok(StampKind::Past) => out.push_str(STAMP_PAST),
+            Ok(StampKind::Present) => out.push_str(STAMP_PRESENT),
+            Ok(StampKind::Future) => out.push_str(STAMP_FUTURE),
+            Ok(StampKind::Fixed(t)) => {
+                out.push_str(STAMP_FIXED);
+                out.push_str(&t.to_string());
+            }
+            Err(..) => out.push_str(&quoted(stamp)),
+        }
+    }
+
+    /// 【内部】格式化字符串形式的浮点序列
+    /// * 🚩能解析为浮点数⇒按浮点数格式化；否则⇒带引号的字面量
+    fn _format_lexical_floats(
+        &self,
+        out: &mut String,
+        brackets: (&str, &str),
+        separator: &str,
+        values: &[String],
+    ) {
+        out.push_str(brackets.0);
+        for (i, value) in values.iter().enumerate() {
+            // 分隔符
+            if i != 0 {
+                out.push_str(separator);
+            }
+            match value.parse::<FloatPrecision>() {
+                Ok(f) => out.push_str(&f.to_string()),
+                Err(..) => out.push_str(&quoted(value)),
+            }
+        }
+        out.push_str(brackets.1);
+    }
+
+    /// 【内部】格式化/词法真值
+    /// * 🚩空真值⇒直接为空
+    fn format_lexical_truth(&self, out: &mut String, truth: &Truth) {
+        if !truth.is_empty() {
+            self._format_lexical_floats(out, BRACKETS_TRUTH, SEPARATOR_TRUTH, truth)
+        }
+    }
+
+    /// 【内部】格式化/词法预算值
+    /// * 🚩空预算⇒仅含括弧
+    fn format_lexical_budget(&self, out: &mut String, budget: &Budget) {
+        self._format_lexical_floats(out, BRACKETS_BUDGET, SEPARATOR_BUDGET, budget)
+    }
+}
+
+/// 格式化方法
+impl FormatterTypst {
+    /// 格式化/词法词项
+    pub fn format_lexical_term(&self, term: &Term) -> String {
+        manipulate!(
+            String::new()
+            // 格式化
+            => [self.format_lexical_term_to](_, term)
+            // 后处理
+            => post_process_whitespace
+        )
+    }
+
+    /// 格式化/词法语句
+    /// * 🚩与「枚举Narsese」的格式保持一致
+    pub fn format_lexical_sentence(&self, sentence: &Sentence) -> String {
+        manipulate!(
+            String::new()
+            // 词项 & 标点
+            => [self.format_lexical_term_to](_, &sentence.term)
+            => [self.format_lexical_punctuation](_, &sentence.punctuation)
+            // 时间戳
+            => [self.format_lexical_stamp](_, &sentence.stamp)
+            => .push_str(SEPARATOR_ITEM)
+            // 真值
+            => [self.format_lexical_truth](_, &sentence.truth)
+            // 后处理
+            => post_process_whitespace
+        )
+    }
+
+    /// 格式化/词法任务
+    /// * 🚩与「枚举Narsese」的格式保持一致
+    pub fn format_lexical_task(&self, task: &Task) -> String {
+        let sentence = &task.sentence;
+        manipulate!(
+            String::new()
+            // 预算值
+            => [self.format_lexical_budget](_, &task.budget)
+            => .push_str(SEPARATOR_ITEM)
+            // 词项 & 标点
+            => [self.format_lexical_term_to](_, &sentence.term)
+            => [self.format_lexical_punctuation](_, &sentence.punctuation)
+            => .push_str(SEPARATOR_ITEM)
+            // 时间戳
+            => [self.format_lexical_stamp](_, &sentence.stamp)
+            => .push_str(SEPARATOR_ITEM)
+            // 真值
+            => [self.format_lexical_truth](_, &sentence.truth)
+            // 后处理
+            => post_process_whitespace
+        )
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexical::tests::{_sample_task_ascii, term};
+    use nar_dev_utils::asserts;
+
+    /// 测试/样例
+    #[test]
+    fn test_examples() {
+        let f = FormatterTypst;
+        asserts! {
+            f.format_lexical_term(&term("<A --> B>")) => r#"lr(angle.l "A" arrow.r "B" angle.r)"#,
+            f.format_lexical_term(&term("(/, A, _)")) => r#"lr(( "A" \/ diamond.small "" ))"#,
+            // 方言特有的系词
+            f.format_lexical_term(&term("<A {-] B>")) => r#"lr(angle.l "A" compose#h(-0.05em)arrow.r#h(-0.05em)compose "B" angle.r)"#,
+            // 未知连接符/系词/括弧⇒带引号的字面量
+            f.format_lexical_term(&Term::new_compound("&&&", vec![term("A"), term("B")])) => r#"lr(( "A" "&&&" "B" ))"#,
+            f.format_lexical_term(&Term::new_statement("~~>", term("A"), term("B"))) => r#"lr(angle.l "A" "~~>" "B" angle.r)"#,
+            f.format_lexical_term(&Term::new_set("(|", vec![term("A")], "|)")) => r#"lr(( "(|" "A" "|)" ))"#,
+            f.format_lexical_term(&Term::new_atom("@", "A")) => r#""@" "A""#,
+        }
+    }
+
+    /// 测试/与枚举Narsese一致
+    /// * 🎯对「两种Narsese共有的子集」，两条路径的Typst输出相同
+    ///   * 📌枚举路径：词法折叠⇒枚举Narsese⇒Typst
+    ///   * 📌词法路径：词法Narsese⇒Typst
+    /// * 🚩`{-]`等方言系词会在折叠时展开，故先将折叠结果转回词法Narsese，得到「共有子集」内的值
+    #[test]
+    #[cfg(feature = "enum_narsese")]
+    fn test_consistent_with_enum() {
+        use crate::conversion::{
+            inter_type::lexical_fold::TryFoldInto,
+            string::impl_enum::format_instances::FORMAT_ASCII as ENUM_ASCII,
+        };
+        use crate::{api::GetTerm, enum_narsese::Task as EnumTask};
+
+        let f = FormatterTypst;
+        // 枚举路径
+        let enum_task: EnumTask = _sample_task_ascii().try_fold_into(&ENUM_ASCII).unwrap();
+        let expected = f.format(&enum_task);
+        // 词法路径
+        let lexical_task = enum_task.to_lexical(&ENUM_ASCII);
+        asserts! {
+            f.format_lexical_task(&lexical_task) => expected,
+            f.format_lexical_sentence(&lexical_task.sentence) => f.format(enum_task.get_sentence()),
+            f.format_lexical_term(&lexical_task.sentence.term) => f.format(enum_task.get_term()),
+        }
+        // 原样本中的方言系词被保留，而非折叠展开
+        let formatted = f.format_lexical_task(&_sample_task_ascii());
+        assert!(formatted.contains(COPULA_INSTANCE_PROPERTY.trim()));
+        assert_ne!(formatted, expected);
+    }
+}
@@ -16,7 +16,13 @@ nar_dev_utils::pub_mod_and_pub_use! {
     task
 }
 
+// 测试辅助 | 差异比对
+pub mod testing;
+
 // 统合结构体
 
 /// 集「词项/语句/任务」于一身的「枚举Narsese」（Narsese值）
 pub type Narsese = NarseseValue<Term, Sentence, Task>;
+
+// 实现与「Narsese值」间的`(try_)From/Into`转换
+crate::api::impl_narsese_value_conversions!(Term, Sentence, Task);
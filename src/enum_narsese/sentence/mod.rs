@@ -26,8 +26,11 @@ nar_dev_utils::pub_mod_and_pub_use! {
 // 语句 //
 // * 🚩【2024-03-13 21:27:46】现在直接将内部的`sentence`进行内联，以彻底避免「重复重名路径」麻烦
 //   * 📌即便屏蔽了Clippy的提示，问题在「IDE展示模块路径」以及[`std::any::get_type_id`]中仍然存在
-use crate::api::{GetPunctuation, GetStamp, GetTerm, GetTruth};
+use crate::api::{
+    DroppedSentenceParts, GetPunctuation, GetStamp, GetTerm, GetTruth, SplitSentence,
+};
 use crate::enum_narsese::term::Term;
+use std::{error::Error, fmt::Display};
 
 /// 使用枚举定义的「语句」类型
 ///
@@ -67,6 +70,24 @@ impl Sentence {
         }
     }
 
+    /// 构造函数/从标点构造（严格）
+    /// * 🎯避免「问题」「请求」上的真值被悄然舍去
+    /// * ⚠️「问题」「请求」带有非空真值⇒报错
+    /// * 📌其它情况与[`Self::from_punctuation`]相同
+    pub fn try_from_punctuation(
+        term: Term,
+        punctuation: Punctuation,
+        stamp: Stamp,
+        truth: Truth,
+    ) -> Result<Self, SentenceBuildError> {
+        match (&punctuation, &truth) {
+            (Punctuation::Question | Punctuation::Quest, Truth::Single(..) | Truth::Double(..)) => {
+                Err(SentenceBuildError::TruthNotAllowed { punctuation, truth })
+            }
+            _ => Ok(Self::from_punctuation(term, punctuation, stamp, truth)),
+        }
+    }
+
     /// 构造函数/判断
     pub fn new_judgement(term: Term, truth: Truth, stamp: Stamp) -> Self {
         Judgement(term, truth, stamp)
@@ -88,6 +109,88 @@ impl Sentence {
     }
 }
 
+/// 实现/判型
+impl Sentence {
+    /// 是否为判断
+    pub fn is_judgement(&self) -> bool {
+        matches!(self, Judgement(..))
+    }
+
+    /// 是否为目标
+    pub fn is_goal(&self) -> bool {
+        matches!(self, Goal(..))
+    }
+
+    /// 是否为问题
+    pub fn is_question(&self) -> bool {
+        matches!(self, Question(..))
+    }
+
+    /// 是否为请求
+    pub fn is_quest(&self) -> bool {
+        matches!(self, Quest(..))
+    }
+
+    /// 作为判断，获取其词项、真值、时间戳
+    /// * 🚩非判断⇒[`None`]
+    pub fn as_judgement(&self) -> Option<(&Term, &Truth, &Stamp)> {
+        match self {
+            Judgement(term, truth, stamp) => Some((term, truth, stamp)),
+            _ => None,
+        }
+    }
+
+    /// 作为目标，获取其词项、真值、时间戳
+    /// * 🚩非目标⇒[`None`]
+    pub fn as_goal(&self) -> Option<(&Term, &Truth, &Stamp)> {
+        match self {
+            Goal(term, truth, stamp) => Some((term, truth, stamp)),
+            _ => None,
+        }
+    }
+
+    /// 作为问题，获取其词项、时间戳
+    /// * 🚩非问题⇒[`None`]
+    pub fn as_question(&self) -> Option<(&Term, &Stamp)> {
+        match self {
+            Question(term, stamp) => Some((term, stamp)),
+            _ => None,
+        }
+    }
+
+    /// 作为请求，获取其词项、时间戳
+    /// * 🚩非请求⇒[`None`]
+    pub fn as_quest(&self) -> Option<(&Term, &Stamp)> {
+        match self {
+            Quest(term, stamp) => Some((term, stamp)),
+            _ => None,
+        }
+    }
+}
+
+/// 语句构造错误
+/// * 🎯用于[`Sentence::try_from_punctuation`]、[`Sentence::with_truth`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum SentenceBuildError {
+    /// 无需真值的语句（问题、请求）带有真值
+    TruthNotAllowed {
+        punctuation: Punctuation,
+        truth: Truth,
+    },
+}
+
+impl Display for SentenceBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SentenceBuildError::TruthNotAllowed { punctuation, truth } => {
+                write!(f, "{punctuation:?}语句不能带有真值：{truth:?}")
+            }
+        }
+    }
+}
+
+impl Error for SentenceBuildError {}
+
 // 实现/属性 //
 
 impl GetTerm<Term> for Sentence {
@@ -133,3 +236,235 @@ impl GetStamp<Stamp> for Sentence {
         }
     }
 }
+
+/// 实现/变换时间戳
+impl Sentence {
+    /// 替换时间戳，其余不变
+    fn with_stamp(&self, stamp: Stamp) -> Self {
+        let mut sentence = self.clone();
+        match &mut sentence {
+            Judgement(_, _, s) | Goal(_, _, s) | Question(_, s) | Quest(_, s) => *s = stamp,
+        }
+        sentence
+    }
+
+    /// 变换时间戳，其余不变
+    /// * 📄平移固定时间：`sentence.map_stamp(|stamp| stamp.shifted(delta))`
+    pub fn map_stamp(&self, f: impl Fn(&Stamp) -> Stamp) -> Self {
+        self.with_stamp(f(self.get_stamp()))
+    }
+
+    /// 变换时间戳（可失败），其余不变
+    /// * 📄检查溢出：`sentence.try_map_stamp(|stamp| stamp.checked_shifted(delta).ok_or(..))`
+    pub fn try_map_stamp<E>(&self, f: impl Fn(&Stamp) -> Result<Stamp, E>) -> Result<Self, E> {
+        Ok(self.with_stamp(f(self.get_stamp())?))
+    }
+}
+
+/// 实现/变换词项、真值
+impl Sentence {
+    /// 变换词项，其余不变
+    /// * 📌标点、真值、时间戳均保留
+    pub fn map_term(self, f: impl FnOnce(Term) -> Term) -> Self {
+        match self {
+            Judgement(term, truth, stamp) => Judgement(f(term), truth, stamp),
+            Goal(term, truth, stamp) => Goal(f(term), truth, stamp),
+            Question(term, stamp) => Question(f(term), stamp),
+            Quest(term, stamp) => Quest(f(term), stamp),
+        }
+    }
+
+    /// 替换真值，其余不变
+    /// * ⚠️问题、请求没有真值⇒报错
+    ///   * 📌即便是空真值也报错：替换真值的操作对其无意义
+    pub fn with_truth(self, truth: Truth) -> Result<Self, SentenceBuildError> {
+        match self {
+            Judgement(term, _, stamp) => Ok(Judgement(term, truth, stamp)),
+            Goal(term, _, stamp) => Ok(Goal(term, truth, stamp)),
+            Question(..) | Quest(..) => Err(SentenceBuildError::TruthNotAllowed {
+                punctuation: self.get_punctuation().clone(),
+                truth,
+            }),
+        }
+    }
+}
+
+// 实现/转换 //
+impl SplitSentence<Term, Punctuation, Stamp, Truth> for Sentence {
+    /// 有损转换为词项
+    /// * 📌问题、请求⇒舍去的真值为空真值
+    fn to_term_lossy(self) -> (Term, DroppedSentenceParts<Punctuation, Stamp, Truth>) {
+        let punctuation = self.get_punctuation().clone();
+        let (term, truth, stamp) = match self {
+            Judgement(term, truth, stamp) | Goal(term, truth, stamp) => (term, truth, stamp),
+            Question(term, stamp) | Quest(term, stamp) => (term, Truth::new_empty(), stamp),
+        };
+        let parts = DroppedSentenceParts {
+            punctuation,
+            stamp,
+            truth,
+        };
+        (term, parts)
+    }
+
+    /// 从词项与舍去的部分重新组合
+    /// * 📄参见[`Sentence::from_punctuation`]
+    fn join_sentence(term: Term, parts: DroppedSentenceParts<Punctuation, Stamp, Truth>) -> Self {
+        Self::from_punctuation(term, parts.punctuation, parts.stamp, parts.truth)
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::IntPrecision;
+    use nar_dev_utils::asserts;
+
+    #[test]
+    fn test_try_from_punctuation() {
+        let term = Term::new_word("A");
+        let truth = Truth::new_double(1.0, 0.9);
+        let build = |punctuation, truth| {
+            Sentence::try_from_punctuation(term.clone(), punctuation, Stamp::Eternal, truth)
+        };
+        asserts! {
+            // 判断、目标：真值原样保留
+            build(Punctuation::Judgement, truth.clone()) => Ok(Judgement(term.clone(), truth.clone(), Stamp::Eternal)),
+            build(Punctuation::Goal, truth.clone()) => Ok(Goal(term.clone(), truth.clone(), Stamp::Eternal)),
+            build(Punctuation::Judgement, Truth::new_empty()) => Ok(Judgement(term.clone(), Truth::Empty, Stamp::Eternal)),
+            // 问题、请求：空真值可以
+            build(Punctuation::Question, Truth::new_empty()) => Ok(Question(term.clone(), Stamp::Eternal)),
+            build(Punctuation::Quest, Truth::new_empty()) => Ok(Quest(term.clone(), Stamp::Eternal)),
+            // 问题、请求：非空真值报错
+            build(Punctuation::Question, truth.clone()) => Err(SentenceBuildError::TruthNotAllowed {
+                punctuation: Punctuation::Question,
+                truth: truth.clone(),
+            }),
+            build(Punctuation::Quest, Truth::new_single(1.0)).is_err(),
+            // 宽松版本：舍去真值
+            Sentence::from_punctuation(term.clone(), Punctuation::Question, Stamp::Eternal, truth.clone()) => Question(term.clone(), Stamp::Eternal),
+        }
+    }
+
+    #[test]
+    fn test_map_stamp() {
+        let term = Term::new_word("A");
+        let truth = Truth::new_double(1.0, 0.9);
+        let judgement = Judgement(term.clone(), truth.clone(), Stamp::Fixed(1));
+        let question = Question(term.clone(), Stamp::Present);
+        asserts! {
+            // 平移：仅时间戳改变
+            judgement.map_stamp(|stamp| stamp.shifted(10)) => Judgement(term.clone(), truth.clone(), Stamp::Fixed(11)),
+            judgement.map_stamp(|stamp| stamp.shifted(-10)) => Judgement(term.clone(), truth.clone(), Stamp::Fixed(-9)),
+            question.map_stamp(|stamp| stamp.shifted(10)) => question.clone(),
+            // 任意变换
+            question.map_stamp(|_| Stamp::Eternal) => Question(term.clone(), Stamp::Eternal),
+            // 溢出⇒报错
+            judgement.try_map_stamp(|stamp| stamp.checked_shifted(IntPrecision::MAX).ok_or("溢出")) => Err("溢出"),
+            judgement.try_map_stamp(|stamp| stamp.checked_shifted(1).ok_or("溢出")) => Ok(Judgement(term.clone(), truth.clone(), Stamp::Fixed(2))),
+        }
+    }
+
+    #[test]
+    fn test_punctuation_queries() {
+        let term = Term::new_word("A");
+        let truth = Truth::new_double(1.0, 0.9);
+        let stamp = Stamp::Present;
+        let sentences = [
+            Judgement(term.clone(), truth.clone(), stamp.clone()),
+            Goal(term.clone(), truth.clone(), stamp.clone()),
+            Question(term.clone(), stamp.clone()),
+            Quest(term.clone(), stamp.clone()),
+        ];
+        let kinds = |sentence: &Sentence| {
+            [
+                sentence.is_judgement(),
+                sentence.is_goal(),
+                sentence.is_question(),
+                sentence.is_quest(),
+            ]
+        };
+        asserts! {
+            kinds(&sentences[0]) => [true, false, false, false],
+            kinds(&sentences[1]) => [false, true, false, false],
+            kinds(&sentences[2]) => [false, false, true, false],
+            kinds(&sentences[3]) => [false, false, false, true],
+            // 各自的访问器
+            sentences[0].as_judgement() => Some((&term, &truth, &stamp)),
+            sentences[1].as_goal() => Some((&term, &truth, &stamp)),
+            sentences[2].as_question() => Some((&term, &stamp)),
+            sentences[3].as_quest() => Some((&term, &stamp)),
+            // 类型不符⇒无
+            sentences[0].as_goal() => None,
+            sentences[1].as_judgement() => None,
+            sentences[2].as_quest() => None,
+            sentences[3].as_question() => None,
+        }
+    }
+
+    #[test]
+    fn test_map_term_with_truth() {
+        let term = Term::new_word("A");
+        let negated = Term::new_negation(term.clone());
+        let truth = Truth::new_double(1.0, 0.9);
+        let new_truth = Truth::new_single(0.5);
+        let stamp = Stamp::Fixed(1);
+        let sentences = [
+            Judgement(term.clone(), truth.clone(), stamp.clone()),
+            Goal(term.clone(), truth.clone(), stamp.clone()),
+            Question(term.clone(), stamp.clone()),
+            Quest(term.clone(), stamp.clone()),
+        ];
+        for sentence in sentences.clone() {
+            // 变换词项：标点、真值、时间戳不变
+            let mapped = sentence.clone().map_term(Term::new_negation);
+            asserts! {
+                mapped.get_term() => &negated,
+                mapped.get_punctuation() => sentence.get_punctuation(),
+                mapped.get_truth() => sentence.get_truth(),
+                mapped.get_stamp() => sentence.get_stamp(),
+            }
+        }
+        asserts! {
+            // 替换真值：判断、目标
+            sentences[0].clone().with_truth(new_truth.clone()) => Ok(Judgement(term.clone(), new_truth.clone(), stamp.clone())),
+            sentences[1].clone().with_truth(new_truth.clone()) => Ok(Goal(term.clone(), new_truth.clone(), stamp.clone())),
+            // 问题、请求⇒报错
+            sentences[2].clone().with_truth(new_truth.clone()) => Err(SentenceBuildError::TruthNotAllowed {
+                punctuation: Punctuation::Question,
+                truth: new_truth.clone(),
+            }),
+            sentences[3].clone().with_truth(Truth::new_empty()).is_err(),
+        }
+    }
+
+    /// 语句⇄词项+舍去的部分
+    #[test]
+    fn test_split_sentence() {
+        let term = Term::new_word("A");
+        let truth = Truth::new_double(1.0, 0.9);
+        let stamp = Stamp::Past;
+        let sentences = [
+            Judgement(term.clone(), truth.clone(), stamp.clone()),
+            Goal(term.clone(), Truth::new_empty(), Stamp::Eternal),
+            Question(term.clone(), stamp.clone()),
+            Quest(term.clone(), Stamp::Fixed(-1)),
+        ];
+        for sentence in sentences.clone() {
+            let (split_term, parts) = sentence.clone().to_term_lossy();
+            asserts! {
+                split_term => term,
+                &parts.punctuation => sentence.get_punctuation(),
+                &parts.stamp => sentence.get_stamp(),
+                Sentence::join_sentence(split_term, parts) => sentence,
+            }
+        }
+        let (_, parts) = sentences[0].clone().to_term_lossy();
+        let (_, question_parts) = sentences[2].clone().to_term_lossy();
+        asserts! {
+            parts.truth => truth,
+            question_parts.truth => Truth::Empty,
+        }
+    }
+}
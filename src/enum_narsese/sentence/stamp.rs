@@ -39,3 +39,59 @@ impl Stamp {
         matches!(self, Stamp::Fixed(_))
     }
 }
+
+/// 实现/时间平移
+/// * 🎯对齐不同会话的时间基准：如将录制的会话「平移到现在」
+impl Stamp {
+    /// 平移「固定」时间戳（检查溢出）
+    /// * 🚩固定⇒时间加上`delta`；其它⇒不变
+    /// * 📌溢出⇒[`None`]，而非回绕
+    pub fn checked_shifted(&self, delta: IntPrecision) -> Option<Stamp> {
+        match self {
+            Stamp::Fixed(time) => time.checked_add(delta).map(Stamp::Fixed),
+            other => Some(other.clone()),
+        }
+    }
+
+    /// 平移「固定」时间戳
+    /// * 🚩固定⇒时间加上`delta`；其它⇒不变
+    /// * ⚠️溢出⇒panic；需处理溢出时使用[`Self::checked_shifted`]
+    pub fn shifted(&self, delta: IntPrecision) -> Stamp {
+        self.checked_shifted(delta)
+            .unwrap_or_else(|| panic!("时间戳平移溢出：{self:?} + {delta}"))
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nar_dev_utils::asserts;
+
+    #[test]
+    fn test_shifted() {
+        asserts! {
+            // 正、负平移
+            Stamp::Fixed(1).shifted(10) => Stamp::Fixed(11),
+            Stamp::Fixed(1).shifted(-10) => Stamp::Fixed(-9),
+            Stamp::Fixed(-1).shifted(0) => Stamp::Fixed(-1),
+            // 非固定时间戳不变
+            Stamp::Eternal.shifted(10) => Stamp::Eternal,
+            Stamp::Past.shifted(-10) => Stamp::Past,
+            Stamp::Present.shifted(10) => Stamp::Present,
+            Stamp::Future.shifted(10) => Stamp::Future,
+            // 溢出
+            Stamp::Fixed(IntPrecision::MAX).checked_shifted(1) => None,
+            Stamp::Fixed(IntPrecision::MIN).checked_shifted(-1) => None,
+            Stamp::Fixed(IntPrecision::MAX).checked_shifted(-1) => Some(Stamp::Fixed(IntPrecision::MAX - 1)),
+            // 非固定时间戳不会溢出
+            Stamp::Present.checked_shifted(IntPrecision::MAX) => Some(Stamp::Present),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "时间戳平移溢出")]
+    fn test_shifted_overflow() {
+        let _ = Stamp::Fixed(IntPrecision::MAX).shifted(1);
+    }
+}
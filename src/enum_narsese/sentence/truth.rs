@@ -4,6 +4,7 @@
 //! * 空真值
 //! * 单真值
 //! * 双真值
+//! * 三真值（频率、信度、证据数）
 
 use crate::api::{hyper_parameters::*, EvidentValue, EvidentValueMut};
 use nar_dev_utils::ZeroOneFloat;
@@ -14,6 +15,7 @@ use nar_dev_utils::ZeroOneFloat;
 ///   * 空真值
 ///   * 单真值
 ///   * 双真值
+///   * 三真值：部分实验性NARS变种输出的「k-证据」形式`%f;c;k%`
 ///
 /// ! ❌【2024-03-27 20:54:19】浮点数[`f32`]、[`f64`]不支持[`Hash`]特征
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
@@ -24,6 +26,10 @@ pub enum Truth {
     Single(FloatPrecision),
     /// 双真值
     Double(FloatPrecision, FloatPrecision),
+    /// 三真值：频率、信度、证据数
+    /// * 📌证据数`k`不在`[0, 1]`中：只要求非负
+    /// * ⚠️仅在格式启用`allow_extended_truth`时解析得到
+    Triple(FloatPrecision, FloatPrecision, FloatPrecision),
 }
 
 /// 实现/构造
@@ -49,6 +55,15 @@ impl Truth {
         Truth::Double(*f.validate_01(), *c.validate_01())
     }
 
+    /// 构造「三真值」
+    ///
+    /// # Panics
+    /// ! 若频率、信度不符合范围，或证据数为负，会发生panic
+    pub fn new_triple(f: FloatPrecision, c: FloatPrecision, k: FloatPrecision) -> Self {
+        assert!(k >= 0.0, "证据数不能为负：{k}");
+        Truth::Triple(*f.validate_01(), *c.validate_01(), k)
+    }
+
     /// 尝试从「浮点数迭代器」中提取真值
     /// * 🚩多余的值会被忽略
     /// * 🚩无效的值会被上报（作为字符串提示）
@@ -71,6 +86,60 @@ impl Truth {
     }
 }
 
+/// 实现/属性（不会panic）
+impl Truth {
+    /// 尝试获取「频率」
+    /// * 🚩空真值⇒[`None`]
+    pub fn try_frequency(&self) -> Option<FloatPrecision> {
+        match self {
+            Truth::Single(frequency)
+            | Truth::Double(frequency, _)
+            | Truth::Triple(frequency, _, _) => Some(*frequency),
+            _ => None,
+        }
+    }
+
+    /// 尝试获取「信度」
+    /// * 🚩空真值、单真值⇒[`None`]
+    pub fn try_confidence(&self) -> Option<FloatPrecision> {
+        match self {
+            Truth::Double(_, confidence) | Truth::Triple(_, confidence, _) => Some(*confidence),
+            _ => None,
+        }
+    }
+
+    /// 尝试获取「证据数」
+    /// * 🚩仅三真值⇒[`Some`]
+    pub fn try_count(&self) -> Option<FloatPrecision> {
+        match self {
+            Truth::Triple(_, _, count) => Some(*count),
+            _ => None,
+        }
+    }
+
+    /// 获取「频率」，缺省时使用默认值
+    pub fn frequency_or(&self, default: FloatPrecision) -> FloatPrecision {
+        self.try_frequency().unwrap_or(default)
+    }
+
+    /// 获取「信度」，缺省时使用默认值
+    /// * 📄OpenNARS中，输入的判断缺省信度为`0.9`：`truth.confidence_or(0.9)`
+    pub fn confidence_or(&self, default: FloatPrecision) -> FloatPrecision {
+        self.try_confidence().unwrap_or(default)
+    }
+
+    /// 获取「(频率, 信度)」，缺省时使用默认值
+    /// * 🎯将「空真值/单真值」补全为可参与真值函数计算的「证据值」
+    ///   * 📄`(f, c)`二元组已实现[`EvidentValue`]
+    pub fn frequency_confidence_or(
+        &self,
+        default_f: FloatPrecision,
+        default_c: FloatPrecision,
+    ) -> (FloatPrecision, FloatPrecision) {
+        (self.frequency_or(default_f), self.confidence_or(default_c))
+    }
+}
+
 /// 实现/证据值
 /// * 🚩用于统一「真值」与「欲望值」
 /// * 🎯为「[证据值](EvidenceValue)」作示范
@@ -79,16 +148,14 @@ impl Truth {
 ///
 /// ! ⚠️若读取到「空真值」「单真值的信度」，会导致「尝试获取缺省的值」的panic
 /// * ❗故因此，不建议在具体NARS实现中使用
+/// * 💡不确定真值元数时，可先用[`Truth::frequency_confidence_or`]补全缺省值
 impl EvidentValue<FloatPrecision> for Truth {
     /// 获取「频率」
     ///
     /// # Panics
     /// ! ⚠️若读取到「空真值」会导致「尝试获取缺省的值」的panic
     fn get_frequency(&self) -> FloatPrecision {
-        match self {
-            Truth::Single(frequency) | Truth::Double(frequency, _) => *frequency,
-            _ => panic!("尝试获取缺省的值"),
-        }
+        self.try_frequency().expect("尝试获取缺省的值")
     }
 
     /// 获取「信度」
@@ -96,10 +163,7 @@ impl EvidentValue<FloatPrecision> for Truth {
     /// # Panics
     /// ! ⚠️若读取到「空真值」会导致「尝试获取缺省的值」的panic
     fn get_confidence(&self) -> FloatPrecision {
-        match self {
-            Truth::Double(_, confidence) => *confidence,
-            _ => panic!("尝试获取缺省的值"),
-        }
+        self.try_confidence().expect("尝试获取缺省的值")
     }
 }
 
@@ -107,14 +171,16 @@ impl EvidentValue<FloatPrecision> for Truth {
 impl EvidentValueMut<FloatPrecision> for Truth {
     fn set_frequency(&mut self, new_f: &FloatPrecision) {
         match self {
-            Truth::Single(frequency) | Truth::Double(frequency, _) => *frequency = *new_f,
+            Truth::Single(frequency)
+            | Truth::Double(frequency, _)
+            | Truth::Triple(frequency, _, _) => *frequency = *new_f,
             _ => panic!("尝试获取缺省的值"),
         }
     }
 
     fn set_confidence(&mut self, new_c: &FloatPrecision) {
         match self {
-            Truth::Double(_, confidence) => *confidence = *new_c,
+            Truth::Double(_, confidence) | Truth::Triple(_, confidence, _) => *confidence = *new_c,
             _ => panic!("尝试获取缺省的值"),
         }
     }
@@ -137,7 +203,7 @@ impl Truth {
 #[cfg(test)]
 mod tests_truth {
     use super::*;
-    use nar_dev_utils::fail_tests;
+    use nar_dev_utils::{asserts, fail_tests};
 
     /// 辅助构造示例
     #[inline(always)]
@@ -173,6 +239,49 @@ mod tests_truth {
         assert_eq!(double.c(), c);
     }
 
+    /// try - get | 覆盖所有元数
+    #[test]
+    fn test_try_get() {
+        let (f, c) = (0.25, 0.75);
+        let (empty, single, double) = new_examples(f, c);
+        asserts! {
+            // 空真值
+            empty.try_frequency() => None
+            empty.try_confidence() => None
+            empty.frequency_or(1.0) => 1.0
+            empty.confidence_or(0.9) => 0.9
+            // 单真值
+            single.try_frequency() => Some(f)
+            single.try_confidence() => None
+            single.frequency_or(1.0) => f
+            single.confidence_or(0.9) => 0.9
+            // 双真值
+            double.try_frequency() => Some(f)
+            double.try_confidence() => Some(c)
+            double.frequency_or(1.0) => f
+            double.confidence_or(0.9) => c
+        }
+    }
+
+    /// 证据值 | 在泛型代码中使用真值
+    #[test]
+    fn test_evident_value() {
+        /// 「期望」函数：对任意「证据值」通用
+        fn expectation(v: &impl EvidentValue<FloatPrecision>) -> FloatPrecision {
+            v.confidence() * (v.frequency() - 0.5) + 0.5
+        }
+        let (f, c) = (1.0, 0.5);
+        let (empty, single, double) = new_examples(f, c);
+        asserts! {
+            // 双真值：直接参与计算
+            expectation(&double) => 0.75
+            double.get_frequency_confidence() => (f, c)
+            // 空真值、单真值：补全后参与计算
+            expectation(&single.frequency_confidence_or(1.0, 0.5)) => 0.75
+            expectation(&empty.frequency_confidence_or(1.0, 0.5)) => 0.75
+        }
+    }
+
     // invalid //
     fail_tests! {
         /// invalid - new | f | >1
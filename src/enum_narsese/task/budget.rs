@@ -1,6 +1,6 @@
 //! 统一定义「预算值」
 
-use crate::api::hyper_parameters::*;
+use crate::api::{hyper_parameters::*, BudgetValue, BudgetValueMut, EvidentNumber};
 use nar_dev_utils::*;
 
 /// 使用枚举表示预算值
@@ -77,32 +77,77 @@ impl Budget {
         matches!(self, Budget::Empty)
     }
 
-    /// 获取「优先级」
-    pub fn priority(&self) -> FloatPrecision {
+    /// 尝试获取「优先级」
+    /// * 🚩缺省⇒[`None`]
+    pub fn try_priority(&self) -> Option<FloatPrecision> {
         match self {
             Budget::Single(priority)
             | Budget::Double(priority, _)
-            | Budget::Triple(priority, _, _) => *priority,
-            _ => panic!("尝试获取缺省的值"),
+            | Budget::Triple(priority, _, _) => Some(*priority),
+            _ => None,
         }
     }
 
-    /// 获取「耐久度」
-    pub fn duality(&self) -> FloatPrecision {
+    /// 尝试获取「耐久度」
+    /// * 🚩缺省⇒[`None`]
+    pub fn try_duality(&self) -> Option<FloatPrecision> {
         match self {
-            Budget::Double(_, duality) | Budget::Triple(_, duality, _) => *duality,
-            _ => panic!("尝试获取缺省的值"),
+            Budget::Double(_, duality) | Budget::Triple(_, duality, _) => Some(*duality),
+            _ => None,
         }
     }
 
-    /// 获取「质量」
-    pub fn quality(&self) -> FloatPrecision {
+    /// 尝试获取「质量」
+    /// * 🚩缺省⇒[`None`]
+    pub fn try_quality(&self) -> Option<FloatPrecision> {
         match self {
-            Budget::Triple(_, _, quality) => *quality,
-            _ => panic!("尝试获取缺省的值"),
+            Budget::Triple(_, _, quality) => Some(*quality),
+            _ => None,
         }
     }
 
+    /// 获取「优先级」，缺省时使用默认值
+    pub fn priority_or(&self, default: FloatPrecision) -> FloatPrecision {
+        self.try_priority().unwrap_or(default)
+    }
+
+    /// 获取「耐久度」，缺省时使用默认值
+    pub fn duality_or(&self, default: FloatPrecision) -> FloatPrecision {
+        self.try_duality().unwrap_or(default)
+    }
+
+    /// 获取「质量」，缺省时使用默认值
+    pub fn quality_or(&self, default: FloatPrecision) -> FloatPrecision {
+        self.try_quality().unwrap_or(default)
+    }
+
+    /// 获取「优先级」
+    ///
+    /// # Panics
+    /// ! ⚠️若读取到缺省的值，会导致「尝试获取缺省的值」的panic
+    /// * 💡长期运行的服务中，建议使用[`Self::try_priority`]
+    pub fn priority(&self) -> FloatPrecision {
+        self.try_priority().expect("尝试获取缺省的值")
+    }
+
+    /// 获取「耐久度」
+    ///
+    /// # Panics
+    /// ! ⚠️若读取到缺省的值，会导致「尝试获取缺省的值」的panic
+    /// * 💡长期运行的服务中，建议使用[`Self::try_duality`]
+    pub fn duality(&self) -> FloatPrecision {
+        self.try_duality().expect("尝试获取缺省的值")
+    }
+
+    /// 获取「质量」
+    ///
+    /// # Panics
+    /// ! ⚠️若读取到缺省的值，会导致「尝试获取缺省的值」的panic
+    /// * 💡长期运行的服务中，建议使用[`Self::try_quality`]
+    pub fn quality(&self) -> FloatPrecision {
+        self.try_quality().expect("尝试获取缺省的值")
+    }
+
     /// 【辅助】用`p`快速获取「优先级」
     pub fn p(&self) -> FloatPrecision {
         self.priority()
@@ -119,6 +164,136 @@ impl Budget {
     }
 }
 
+/// 实现/预算值（抽象API）
+///
+/// # Panics
+///
+/// ! ⚠️与「真值」的[「证据值」实现](crate::api::EvidentValue)一致：读写缺省的值会panic
+/// * 💡不确定预算元数时，可使用[`Budget::try_priority`]等方法
+impl BudgetValue<FloatPrecision> for Budget {
+    fn get_priority(&self) -> FloatPrecision {
+        self.priority()
+    }
+
+    fn get_duality(&self) -> FloatPrecision {
+        self.duality()
+    }
+
+    fn get_quality(&self) -> FloatPrecision {
+        self.quality()
+    }
+}
+
+/// 实现/可变预算值（抽象API）
+/// * 🚩设置的值同样需处在 0≤x≤1 的范围
+impl BudgetValueMut<FloatPrecision> for Budget {
+    fn set_priority(&mut self, new_p: &FloatPrecision) {
+        match self {
+            Budget::Single(p) | Budget::Double(p, _) | Budget::Triple(p, _, _) => {
+                *p = *new_p.validate()
+            }
+            _ => panic!("尝试获取缺省的值"),
+        }
+    }
+
+    fn set_duality(&mut self, new_d: &FloatPrecision) {
+        match self {
+            Budget::Double(_, d) | Budget::Triple(_, d, _) => *d = *new_d.validate(),
+            _ => panic!("尝试获取缺省的值"),
+        }
+    }
+
+    fn set_quality(&mut self, new_q: &FloatPrecision) {
+        match self {
+            Budget::Triple(_, _, q) => *q = *new_q.validate(),
+            _ => panic!("尝试获取缺省的值"),
+        }
+    }
+}
+
+/// 实现/运算
+/// * 🎯供调度层合并、衰减、比较预算值
+/// * 📌所有结果均处在 0≤x≤1 的范围（经[「证据数值」](EvidentNumber)校验）
+impl Budget {
+    /// 获取预算值的「元数」
+    /// * 📄空预算⇒0，单预算⇒1，双预算⇒2，三预算⇒3
+    pub fn arity(&self) -> usize {
+        match self {
+            Budget::Empty => 0,
+            Budget::Single(..) => 1,
+            Budget::Double(..) => 2,
+            Budget::Triple(..) => 3,
+        }
+    }
+
+    /// 获取所有（非缺省的）分量
+    /// * 🚩按「优先级、耐久度、质量」的顺序
+    pub fn to_floats(&self) -> Vec<FloatPrecision> {
+        match self {
+            Budget::Empty => vec![],
+            Budget::Single(p) => vec![*p],
+            Budget::Double(p, d) => vec![*p, *d],
+            Budget::Triple(p, d, q) => vec![*p, *d, *q],
+        }
+    }
+
+    /// 工具函数/从「已校验的分量」构造预算值
+    /// * 🚩多余的分量会被忽略
+    fn from_floats_validated(floats: impl IntoIterator<Item = FloatPrecision>) -> Self {
+        Self::try_from_floats(floats.into_iter().map(|v| *v.validate()))
+            .expect("分量已经过校验")
+    }
+
+    /// 合并两个预算值
+    /// * 🎯两个相同任务修正时，合并其预算值
+    /// * 🚩逐分量取最大值（即「或」的语义）
+    /// * 🚩结果的元数取两者中较大者
+    ///   * 📌某一方缺省的分量⇒直接取另一方的值（相当于缺省值为0）
+    ///   * 📄空预算与三预算合并⇒该三预算
+    pub fn merge(&self, other: &Budget) -> Budget {
+        let (a, b) = (self.to_floats(), other.to_floats());
+        let arity = a.len().max(b.len());
+        Self::from_floats_validated((0..arity).map(|i| match (a.get(i), b.get(i)) {
+            (Some(x), Some(y)) => x.max(*y),
+            (Some(v), None) | (None, Some(v)) => *v,
+            (None, None) => unreachable!("索引不会超出较大者的元数"),
+        }))
+    }
+
+    /// 衰减预算值
+    /// * 🎯调度层的「遗忘」：随时间降低优先级
+    /// * 🚩优先级乘以衰减系数，耐久度、质量保持不变
+    /// * 📌元数保持不变：空预算衰减后仍为空预算
+    ///
+    /// # Panics
+    /// ! ⚠️衰减系数须处在 0≤x≤1 的范围，否则panic
+    pub fn decay(&self, factor: FloatPrecision) -> Budget {
+        let factor = *factor.validate();
+        let mut floats = self.to_floats();
+        if let Some(p) = floats.first_mut() {
+            *p *= factor;
+        }
+        Self::from_floats_validated(floats)
+    }
+
+    /// 预算值的「总体评价」
+    /// * 🎯用于调度层比较、排序预算值
+    /// * 🚩所有（非缺省）分量的几何平均值
+    ///   * 📄三预算⇒`(p * d * q)^(1/3)`，同OpenNARS的`summary`
+    /// * 📌空预算⇒0
+    pub fn total(&self) -> FloatPrecision {
+        let floats = self.to_floats();
+        match floats.is_empty() {
+            true => FloatPrecision::zero(),
+            false => floats
+                .iter()
+                .copied()
+                .fold(FloatPrecision::one(), |acc, v| acc * v)
+                .root(floats.len()),
+        }
+    }
+}
+
 /// 单元测试/预算值
 #[cfg(test)]
 mod tests_budget {
@@ -176,6 +351,42 @@ mod tests_budget {
         assert_eq!(triple.q(), q);
     }
 
+    /// try - get | 覆盖所有元数
+    #[test]
+    fn test_try_get() {
+        let (p, d, q) = (0.1, 0.2, 0.3);
+        let (empty, single, double, triple) = new_examples(p, d, q);
+        asserts! {
+            // 空预算
+            empty.try_priority() => None
+            empty.try_duality() => None
+            empty.try_quality() => None
+            // 单预算
+            single.try_priority() => Some(p)
+            single.try_duality() => None
+            single.try_quality() => None
+            // 双预算
+            double.try_priority() => Some(p)
+            double.try_duality() => Some(d)
+            double.try_quality() => None
+            // 三预算
+            triple.try_priority() => Some(p)
+            triple.try_duality() => Some(d)
+            triple.try_quality() => Some(q)
+        }
+        // 带默认值
+        asserts! {
+            empty.priority_or(0.5) => 0.5
+            empty.duality_or(0.5) => 0.5
+            empty.quality_or(0.5) => 0.5
+            single.priority_or(0.5) => p
+            single.duality_or(0.5) => 0.5
+            double.duality_or(0.5) => d
+            double.quality_or(0.5) => 0.5
+            triple.quality_or(0.5) => q
+        }
+    }
+
     /// from_floats
     #[test]
     fn test_from_floats() {
@@ -189,6 +400,76 @@ mod tests_budget {
         }
     }
 
+    /// merge | 覆盖所有元数组合
+    #[test]
+    fn test_merge() {
+        let (a_empty, a_single, a_double, a_triple) = new_examples(0.2, 0.8, 0.4);
+        let (b_empty, b_single, b_double, b_triple) = new_examples(0.6, 0.3, 0.5);
+        let a = [a_empty, a_single, a_double, a_triple];
+        let b = [b_empty, b_single, b_double, b_triple];
+        for x in &a {
+            for y in &b {
+                let merged = x.merge(y);
+                // 元数取较大者，且合并可交换
+                assert_eq!(merged.arity(), x.arity().max(y.arity()));
+                assert_eq!(merged, y.merge(x));
+            }
+        }
+        asserts! {
+            // 空预算⇒取另一方
+            a[0].merge(&b[3]) => b[3].clone()
+            a[3].merge(&b[0]) => a[3].clone()
+            a[0].merge(&b[0]) => Budget::Empty
+            // 逐分量取最大值
+            a[3].merge(&b[3]) => Budget::new_triple(0.6, 0.8, 0.5)
+            a[1].merge(&b[3]) => Budget::new_triple(0.6, 0.3, 0.5)
+            a[2].merge(&b[1]) => Budget::new_double(0.6, 0.8)
+            a[3].merge(&b[2]) => Budget::new_triple(0.6, 0.8, 0.4)
+        }
+    }
+
+    /// decay | 只衰减优先级，元数不变
+    #[test]
+    fn test_decay() {
+        let (empty, single, double, triple) = new_examples(0.8, 0.5, 0.4);
+        asserts! {
+            empty.decay(0.5) => Budget::Empty
+            single.decay(0.5) => Budget::new_single(0.4)
+            double.decay(0.5) => Budget::new_double(0.4, 0.5)
+            triple.decay(0.5) => Budget::new_triple(0.4, 0.5, 0.4)
+            triple.decay(1.0) => triple.clone()
+            triple.decay(0.0) => Budget::new_triple(0.0, 0.5, 0.4)
+        }
+    }
+
+    /// total | 几何平均
+    #[test]
+    fn test_total() {
+        let (empty, single, double, triple) = new_examples(0.5, 0.5, 0.5);
+        asserts! {
+            empty.total() => 0.0,
+            single.total() => 0.5,
+            (Budget::new_double(0.25, 1.0).total() - 0.5).abs() < 1e-9,
+            (double.total() - 0.5).abs() < 1e-9,
+            (triple.total() - 0.5).abs() < 1e-9,
+            Budget::new_triple(0.5, 0.5, 0.0).total() => 0.0,
+        }
+    }
+
+    /// set | 抽象API「可变预算值」
+    #[test]
+    fn test_budget_value_mut() {
+        let mut triple = Budget::new_triple(0.5, 0.5, 0.5);
+        triple.set_pdq(&0.1, &0.2, &0.3);
+        let mut single = Budget::new_single(0.5);
+        single.set_priority(&0.9);
+        asserts! {
+            triple.get_pdq() => (0.1, 0.2, 0.3)
+            triple => Budget::new_triple(0.1, 0.2, 0.3)
+            single.get_priority() => 0.9
+        }
+    }
+
     // invalid //
 
     fail_tests! {
@@ -227,5 +508,14 @@ mod tests_budget {
 
         /// invalid - get | q | double
         test_get_invalid_q_double Budget::new_double(0.5, 0.5).q();
+
+        /// invalid - decay | factor | >1
+        test_decay_invalid_factor Budget::new_single(0.5).decay(1.5);
+
+        /// invalid - set | d | single
+        test_set_invalid_d_single Budget::new_single(0.5).set_duality(&0.5);
+
+        /// invalid - set | p | >1
+        test_set_invalid_p_up Budget::new_single(0.5).set_priority(&1.5);
     }
 }
@@ -16,7 +16,8 @@ pub use budget::*;
 // 任务 //
 
 use crate::api::{
-    CastToTask, GetBudget, GetPunctuation, GetStamp, GetTerm, GetTruth, TryCastToSentence,
+    CastToTask, GetBudget, GetPunctuation, GetStamp, GetTerm, GetTruth, SplitTask,
+    TryCastToSentence,
 };
 use crate::enum_narsese::sentence::{Punctuation, Sentence, Stamp, Truth};
 use crate::enum_narsese::term::Term;
@@ -43,14 +44,37 @@ impl CastToTask<Task> for Sentence {
     }
 }
 
+impl SplitTask<Sentence, Budget> for Task {
+    /// 拆分为语句与预算值
+    fn split_task(self) -> (Sentence, Budget) {
+        (self.0, self.1)
+    }
+
+    /// 从语句与预算值重新组合
+    fn join_task(sentence: Sentence, budget: Budget) -> Self {
+        Task::new(sentence, budget)
+    }
+
+    /// 有损转换为语句
+    fn to_sentence_lossy(self) -> (Sentence, Option<Budget>) {
+        match self.1.is_empty() {
+            // 空预算⇒无损失
+            true => (self.0, None),
+            // 其它⇒舍去预算值
+            false => (self.0, Some(self.1)),
+        }
+    }
+}
+
 impl TryCastToSentence<Sentence> for Task {
     /// 尝试（无损）转换为语句
+    /// * 📄基于[`SplitTask::to_sentence_lossy`]
     fn try_cast_to_sentence(self) -> Result<Sentence, Self> {
-        match self.1.is_empty() {
+        match self.to_sentence_lossy() {
             // 空预算⇒可无损转换
-            true => Ok(self.0),
+            (sentence, None) => Ok(sentence),
             // 其它⇒无法转换
-            false => Err(self),
+            (sentence, Some(budget)) => Err(Task::join_task(sentence, budget)),
         }
     }
 }
@@ -97,3 +121,96 @@ impl GetTruth<Truth> for Task {
         self.get_sentence().get_truth()
     }
 }
+
+/// 实现/变换时间戳
+impl Task {
+    /// 变换时间戳，其余不变
+    /// * 📄参见[`Sentence::map_stamp`]
+    pub fn map_stamp(&self, f: impl Fn(&Stamp) -> Stamp) -> Self {
+        Task(self.0.map_stamp(f), self.1.clone())
+    }
+
+    /// 变换时间戳（可失败），其余不变
+    /// * 📄参见[`Sentence::try_map_stamp`]
+    pub fn try_map_stamp<E>(&self, f: impl Fn(&Stamp) -> Result<Stamp, E>) -> Result<Self, E> {
+        Ok(Task(self.0.try_map_stamp(f)?, self.1.clone()))
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nar_dev_utils::asserts;
+
+    /// 批量平移任务的时间戳
+    #[test]
+    fn test_map_stamp() {
+        let task = |stamp| {
+            Task::new(
+                Sentence::new_goal(Term::new_word("A"), Truth::new_single(1.0), stamp),
+                Budget::new_double(0.5, 0.5),
+            )
+        };
+        let tasks = [task(Stamp::Fixed(-5)), task(Stamp::Present), task(Stamp::Fixed(3))];
+        let shifted = tasks
+            .iter()
+            .map(|task| task.map_stamp(|stamp| stamp.shifted(100)))
+            .collect::<Vec<_>>();
+        asserts! {
+            shifted => vec![task(Stamp::Fixed(95)), task(Stamp::Present), task(Stamp::Fixed(103))],
+            tasks[0].try_map_stamp(|stamp| stamp.checked_shifted(isize::MIN).ok_or(())) => Err(()),
+        }
+    }
+
+    /// 任务⇄语句+预算值
+    #[test]
+    fn test_split_task() {
+        let sentence = Sentence::new_judgement(
+            Term::new_word("A"),
+            Truth::new_double(1.0, 0.9),
+            Stamp::Present,
+        );
+        let task = Task::new(sentence.clone(), Budget::new_triple(0.5, 0.5, 0.5));
+        let empty_budget_task = Task::new(sentence.clone(), Budget::Empty);
+        let (split_sentence, budget) = task.clone().split_task();
+        asserts! {
+            split_sentence => sentence,
+            budget => Budget::new_triple(0.5, 0.5, 0.5),
+            Task::join_task(split_sentence, budget) => task,
+            task.clone().to_sentence_lossy() => (sentence.clone(), Some(task.1.clone())),
+            empty_budget_task.clone().to_sentence_lossy() => (sentence.clone(), None),
+            // 基于「有损转换」的「尝试转换」
+            task.clone().try_cast_to_sentence() => Err(task),
+            empty_budget_task.try_cast_to_sentence() => Ok(sentence),
+        }
+    }
+
+    /// 与「Narsese值」间的相互转换
+    #[test]
+    fn test_narsese_value_conversions() {
+        use crate::{api::NarseseCastError, enum_narsese::Narsese};
+        let term = Term::new_inheritance(Term::new_word("A"), Term::new_word("B"));
+        let sentence = Sentence::new_judgement(term.clone(), Truth::new_empty(), Stamp::Eternal);
+        let task = Task::new(sentence.clone(), Budget::new_single(0.5));
+        let [n_term, n_sentence, n_task]: [Narsese; 3] =
+            [term.clone().into(), sentence.clone().into(), task.clone().into()];
+        asserts! {
+            // 具体类型→Narsese值
+            n_term => Narsese::from_term(term.clone()),
+            n_sentence => Narsese::from_sentence(sentence.clone()),
+            n_task => Narsese::from_task(task.clone()),
+            // Narsese值→具体类型
+            Term::try_from(n_term.clone()) => Ok(term),
+            Sentence::try_from(n_sentence.clone()) => Ok(sentence),
+            Task::try_from(n_task.clone()) => Ok(task),
+            // 类型不匹配
+            Term::try_from(n_task) => Err(NarseseCastError::new("任务", "词项")),
+            Sentence::try_from(n_term) => Err(NarseseCastError::new("词项", "语句")),
+            Task::try_from(n_sentence) => Err(NarseseCastError::new("语句", "任务")),
+            // 兼容原先的`io::Error`
+            std::io::Error::from(NarseseCastError::new("语句", "任务")).kind()
+                => std::io::ErrorKind::InvalidData,
+        }
+    }
+}
@@ -0,0 +1,337 @@
+//! 词项的「变量换名」（α-规范化）
+//! * 🎯对「只有变量名不同」的词项去重
+//!   * 📄`<<$x --> A> ==> <$x --> B>>`与`<<$1 --> A> ==> <$1 --> B>>`
+//! * 🚩按「首次出现」的顺序，将变量依次重命名为`1`、`2`、`3`……
+//!   * 📌独立变量、非独变量、查询变量：各自独立编号
+//!   * 📌不同的变量保持不同，相同的变量保持相同
+//! * 🚩确定性遍历：先序，先自身、再按组分顺序
+//!   * 📌无序容器（集合、交、合取等）：组分按「抹去变量名后的词项」排序
+//!     * 🎯不依赖[`HashSet`](std::collections::HashSet)的遍历顺序，也不依赖原有变量名
+//!   * 📌抹去变量名后相同的组分：按变量的「颜色」进一步排序
+//! * 🚩变量的「颜色」：反复细化，直至稳定
+//!   * 📌初始时各变量同色；每轮按「标记该变量后，其余变量按颜色命名的词项」重新分色
+//!   * 📌仍有同色变量⇒逐个尝试「单独分出其中一个」，取换名结果中[最小](Ord)者
+//!     * 🚩交换两者后词项不变（对称）⇒不必重复尝试
+//!     * 📄`{$a, $b, $c}`：只需尝试一次，而非其所有排列
+
+use super::structs::*;
+use crate::api::GetCategory;
+use std::collections::HashMap;
+
+/// 变量的命名空间及其名称
+/// * 📌三种变量各自一个命名空间
+type Variable<'a> = (usize, &'a str);
+
+/// 变量的编号顺序：各变量按首次出现的先后排列，不重复
+type VariableOrder<'a> = Vec<Variable<'a>>;
+
+/// 变量的颜色：同色的变量尚未区分开
+type Colors<'a> = HashMap<Variable<'a>, usize>;
+
+/// 工具函数：将一组键按大小换算为从`0`开始的排名
+/// * 📌相同的键⇒相同的排名
+fn ranks<K: Ord>(keys: &[K]) -> Vec<usize> {
+    let mut sorted = keys.iter().collect::<Vec<_>>();
+    sorted.sort();
+    sorted.dedup();
+    keys.iter()
+        .map(|key| sorted.binary_search(&key).expect("键必定存在"))
+        .collect()
+}
+
+/// 工具函数：统计颜色的种数
+fn count_colors(colors: &Colors) -> usize {
+    let mut values = colors.values().collect::<Vec<_>>();
+    values.sort();
+    values.dedup();
+    values.len()
+}
+
+/// 实现/变量换名
+impl Term {
+    /// 获取变量所在的命名空间及其名称
+    /// * 📌非变量⇒[`None`]
+    fn variable_namespace(&self) -> Option<Variable<'_>> {
+        match self {
+            VariableIndependent(name) => Some((0, name)),
+            VariableDependent(name) => Some((1, name)),
+            VariableQuery(name) => Some((2, name)),
+            _ => None,
+        }
+    }
+
+    /// 按指定的函数重命名所有变量
+    /// * 📌变量的种类保持不变
+    fn map_variables(&self, f: &mut impl FnMut(Variable) -> String) -> Term {
+        self.clone().map_terms(&mut |term| {
+            let name = match term.variable_namespace() {
+                Some(variable) => f(variable),
+                None => return term,
+            };
+            match term {
+                VariableIndependent(..) => VariableIndependent(name),
+                VariableDependent(..) => VariableDependent(name),
+                _ => VariableQuery(name),
+            }
+        })
+    }
+
+    /// 抹去所有变量名
+    /// * 🎯用作无序容器中组分的排序依据
+    fn anonymize_variables(&self) -> Term {
+        self.map_variables(&mut |_| String::new())
+    }
+
+    /// 按颜色命名所有变量
+    /// * 🎯用作「颜色细化」及无序容器中组分的排序依据
+    /// * 📌被标记的变量⇒命名为`*`，与所有颜色相区分
+    fn color_variables(&self, colors: &Colors, marked: Option<Variable>) -> Term {
+        self.map_variables(&mut |variable| match Some(variable) == marked {
+            true => "*".into(),
+            false => colors[&variable].to_string(),
+        })
+    }
+
+    /// 细化颜色，直至稳定
+    /// * 🚩新颜色：旧颜色+「标记该变量后，按颜色命名的整个词项」
+    /// * 📌只依赖词项结构与旧颜色，与原有变量名无关
+    fn refine_colors<'a>(&self, variables: &[Variable<'a>], mut colors: Colors<'a>) -> Colors<'a> {
+        let mut count = count_colors(&colors);
+        loop {
+            let keys = variables
+                .iter()
+                .map(|variable| (colors[variable], self.color_variables(&colors, Some(*variable))))
+                .collect::<Vec<_>>();
+            colors = variables.iter().copied().zip(ranks(&keys)).collect();
+            let new_count = count_colors(&colors);
+            if new_count == count {
+                return colors;
+            }
+            count = new_count;
+        }
+    }
+
+    /// 判断交换两个变量后词项是否不变
+    /// * 🎯剪枝：对称的变量，单独分出哪个结果都相同
+    fn is_symmetric(&self, a: Variable, b: Variable) -> bool {
+        let swapped = self.map_variables(&mut |variable| match variable {
+            _ if variable == a => b.1.into(),
+            _ if variable == b => a.1.into(),
+            (_, name) => name.into(),
+        });
+        swapped == *self
+    }
+
+    /// 按颜色获取变量的编号顺序
+    /// * 🚩按确定性遍历，收集变量的首次出现
+    /// * 📌无序容器⇒组分按「抹去变量名后的词项」排序，并列者再按颜色排序
+    fn variable_order<'a>(&'a self, colors: &Colors, order: &mut VariableOrder<'a>) {
+        // 变量⇒仅自身
+        if let Some(variable) = self.variable_namespace() {
+            if !order.contains(&variable) {
+                order.push(variable);
+            }
+            return;
+        }
+        // 其它原子词项⇒无变量
+        if self.is_atom() {
+            return;
+        }
+        // 容器⇒逐个组分展开
+        let components = match self {
+            SetExtension(set)
+            | SetIntension(set)
+            | IntersectionExtension(set)
+            | IntersectionIntension(set)
+            | Conjunction(set)
+            | Disjunction(set)
+            | ConjunctionParallel(set) => {
+                let mut terms = set
+                    .iter()
+                    .map(|term| {
+                        let key = (term.anonymize_variables(), term.color_variables(colors, None));
+                        (key, term)
+                    })
+                    .collect::<Vec<_>>();
+                terms.sort_by(|(key1, _), (key2, _)| key1.cmp(key2));
+                terms.into_iter().map(|(_, term)| term).collect()
+            }
+            _ => self.get_components(),
+        };
+        for term in components {
+            term.variable_order(colors, order);
+        }
+    }
+
+    /// 按指定的编号顺序重命名变量
+    fn rename_variables(&self, order: &[Variable]) -> Term {
+        // 各命名空间内，按顺序依次编号
+        let mut numbers = [0; 3];
+        let numbers = order
+            .iter()
+            .map(|&(namespace, name)| {
+                numbers[namespace] += 1;
+                ((namespace, name), numbers[namespace])
+            })
+            .collect::<HashMap<_, _>>();
+        self.map_variables(&mut |variable| numbers[&variable].to_string())
+    }
+
+    /// 搜索最小的换名结果
+    /// * 🚩细化颜色；仍有同色变量⇒逐个单独分出，递归搜索
+    /// * 📌所有变量颜色各异⇒编号顺序唯一，直接换名
+    fn search_normalized<'a>(
+        &'a self,
+        variables: &[Variable<'a>],
+        colors: Colors<'a>,
+        best: &mut Option<Term>,
+    ) {
+        let colors = self.refine_colors(variables, colors);
+        // 找出最小的「同色变量组」
+        let mut cells = HashMap::<usize, Vec<Variable>>::new();
+        for variable in variables {
+            cells.entry(colors[variable]).or_default().push(*variable);
+        }
+        let cell = cells
+            .into_iter()
+            .filter(|(_, cell)| cell.len() > 1)
+            .min_by_key(|(color, _)| *color)
+            .map(|(_, cell)| cell);
+        let Some(cell) = cell else {
+            // 颜色各异⇒直接换名
+            let mut order = vec![];
+            self.variable_order(&colors, &mut order);
+            let normalized = self.rename_variables(&order);
+            if best.as_ref().is_none_or(|best| normalized < *best) {
+                *best = Some(normalized);
+            }
+            return;
+        };
+        // 逐个单独分出，跳过与已尝试者对称的变量
+        let mut tried = vec![];
+        for &chosen in &cell {
+            if tried.iter().any(|&other| self.is_symmetric(other, chosen)) {
+                continue;
+            }
+            tried.push(chosen);
+            let keys = variables
+                .iter()
+                .map(|variable| (colors[variable], *variable != chosen))
+                .collect::<Vec<_>>();
+            let colors = variables.iter().copied().zip(ranks(&keys)).collect();
+            self.search_normalized(variables, colors, best);
+        }
+    }
+
+    /// 变量换名/规范化
+    /// * 🚩先细化变量的颜色，再按颜色确定编号顺序并重命名，取[最小](Ord)者
+    /// * 📌返回新词项，不修改自身
+    /// * 📌结果与原有变量名无关：α-等价的词项，规范化结果相同
+    /// * 📄`<(&&, <$x --> A>, <#y --> B>) ==> <$x --> ?q>>` ⇒ `<(&&, <$1 --> A>, <#1 --> B>) ==> <$1 --> ?1>>`
+    pub fn normalize_variables(&self) -> Term {
+        let mut variables = vec![];
+        for variable in self.variables().into_iter().filter_map(Term::variable_namespace) {
+            if !variables.contains(&variable) {
+                variables.push(variable);
+            }
+        }
+        // 初始：各变量同色
+        let colors = variables.iter().map(|&variable| (variable, 0)).collect();
+        let mut best = None;
+        self.search_normalized(&variables, colors, &mut best);
+        best.expect("至少有一种编号顺序")
+    }
+
+    /// 判断是否「α-等价」
+    /// * 🚩变量换名后判等
+    pub fn alpha_eq(&self, other: &Term) -> bool {
+        self.normalize_variables() == other.normalize_variables()
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use crate::enum_narsese::tests::term;
+    use nar_dev_utils::asserts;
+
+    /// 工具函数：判断α-等价
+    fn alpha_eq(a: &str, b: &str) -> bool {
+        term(a).alpha_eq(&term(b))
+    }
+
+    #[test]
+    fn test_normalize_variables() {
+        asserts! {
+            term("<<$x --> A> ==> <$x --> B>>").normalize_variables()
+                => term("<<$1 --> A> ==> <$1 --> B>>"),
+            // 三种变量各自编号
+            term("<(&/, <$x --> A>, <#y --> B>) ==> <$z --> ?q>>").normalize_variables()
+                => term("<(&/, <$1 --> A>, <#1 --> B>) ==> <$2 --> ?1>>"),
+            term("<$x --> #x>").normalize_variables() => term("<$1 --> #1>"),
+            // 无变量⇒不变
+            term("<A --> B>").normalize_variables() => term("<A --> B>"),
+        }
+    }
+
+    #[test]
+    fn test_alpha_eq() {
+        asserts! {
+            alpha_eq("<<$x --> A> ==> <$x --> B>>", "<<$1 --> A> ==> <$1 --> B>>"),
+            // 不同变量保持不同
+            !alpha_eq("<$x --> $y>", "<$x --> $x>"),
+            alpha_eq("<$x --> $y>", "<$b --> $a>"),
+            // 不同种类的变量不等价
+            !alpha_eq("<$x --> A>", "<#x --> A>"),
+            // 无序容器：与组分顺序、变量名无关
+            alpha_eq("(&&, <$x --> A>, <$y --> B>)", "(&&, <$b --> B>, <$a --> A>)"),
+            alpha_eq("{<$x --> A>, <#y --> B>}", "{<#a --> B>, <$b --> A>}"),
+            !alpha_eq("(&&, <$x --> A>, <$y --> B>)", "(&&, <$x --> B>, <$x --> A>)"),
+            // 抹去变量名后并列的组分：与变量名无关
+            alpha_eq("(&&, <$x --> A>, <$y --> A>, <$x --> B>)", "(&&, <$b --> A>, <$a --> A>, <$b --> B>)"),
+            alpha_eq("(&&, <$x --> A>, <$y --> A>, <$x --> B>)", "(&&, <$a --> A>, <$b --> A>, <$a --> B>)"),
+            !alpha_eq("(&&, <$x --> A>, <$y --> A>, <$x --> B>)", "(&&, <$x --> A>, <$y --> A>, <$z --> B>)"),
+            alpha_eq("<{$a, $b} --> (*, $a, C)>", "<{$q, $p} --> (*, $q, C)>"),
+            alpha_eq("<{$a, $b} --> (*, $a, C)>", "<{$p, $q} --> (*, $q, C)>"),
+            !alpha_eq("<{$a, $b} --> (*, $a, C)>", "<{$p, $q} --> (*, $r, C)>"),
+        }
+        // 无序容器中的编号顺序：按抹去变量名后的组分排序
+        asserts! {
+            term("(&&, <$b --> B>, <$a --> A>)").normalize_variables()
+                => term("(&&, <$1 --> A>, <$2 --> B>)"),
+            term("(&&, <$x --> A>, <$y --> A>, <$x --> B>)").normalize_variables()
+                => term("(&&, <$1 --> A>, <$2 --> A>, <$1 --> B>)"),
+            term("(&&, <$b --> A>, <$a --> A>, <$b --> B>)").normalize_variables()
+                => term("(&&, <$1 --> A>, <$2 --> A>, <$1 --> B>)"),
+        }
+    }
+
+    /// 测试/大量对称变量
+    /// * 🎯对称的变量不必逐一排列：耗时不随变量数阶乘增长
+    #[test]
+    fn test_alpha_eq_symmetric() {
+        use std::time::{Duration, Instant};
+        let start = Instant::now();
+        let names = |prefix: &str| (0..10).map(|i| format!("${prefix}{i}")).collect::<Vec<_>>();
+        let set = |names: &[String]| format!("{{{}}}", names.join(", "));
+        let expected = term(&set(&(1..=10).map(|i| format!("${i}")).collect::<Vec<_>>()));
+        asserts! {
+            term(&set(&names("v"))).normalize_variables() => expected,
+            alpha_eq(&set(&names("v")), &set(&names("w"))),
+            alpha_eq(
+                "(&&, <$v0 --> A>, <$v1 --> A>, <$v2 --> A>, <$v3 --> A>, <$v4 --> A>, <$v5 --> A>, <$v6 --> A>, <$v7 --> A>, <$v8 --> A>, <$v9 --> A>)",
+                "(&&, <$w9 --> A>, <$w8 --> A>, <$w7 --> A>, <$w6 --> A>, <$w5 --> A>, <$w4 --> A>, <$w3 --> A>, <$w2 --> A>, <$w1 --> A>, <$w0 --> A>)"
+            ),
+            // 环状结构：仅轮换对称
+            alpha_eq(
+                "(&&, <$a --> $b>, <$b --> $c>, <$c --> $a>)",
+                "(&&, <$x --> $y>, <$z --> $x>, <$y --> $z>)"
+            ),
+            !alpha_eq(
+                "(&&, <$a --> $b>, <$b --> $c>, <$c --> $a>)",
+                "(&&, <$x --> $y>, <$y --> $z>, <$x --> $z>)"
+            ),
+        }
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,182 @@
+//! 原子词项名称的检验
+//! * 🎯保证「构造出的词项」在格式化后能被原样解析回来
+//!   * 📄`Term::new_word("$x")`格式化为`$x`，重新解析后变成「独立变量」
+//!   * 📄`Term::new_word("A B")`格式化为`A B`，重新解析后变成`AB`或报错
+//! * 🚩合法的原子词项名
+//!   * 非空
+//!   * 仅含「合法词项名字符」：字母、数字、`_`、`-`、常见emoji
+//!     * 📌与内置格式的「原子词项名」字符集一致
+//!   * 不以`--`结尾：作谓词时会与陈述右括弧组成系词
+//!     * 📄`<B --> a-->`
+//!   * 词语：不以占位符前缀`_`开头
+//!     * 📌其它原子词项前缀（`$`、`#`、`?`、`+`、`^`）已被上述字符集排除
+//! * ⚠️仅检验与格式无关的部分：漢文等格式中的「关键字」仍可能与名称冲突
+//!   * 📄漢文格式中，名称含系词`是`的词语
+
+use super::{error::TermEditError, structs::*};
+
+/// 判断字符是否能作为「原子词项名」的一部分
+/// * 🎯用于判断「合法词项名」
+/// * 📌内置的「枚举Narsese格式」均使用此函数
+/// * ⚠️【2024-06-13 19:25:15】[`char::is_alphanumeric`]目前还不是常量函数
+pub fn is_valid_atom_name_char(c: char) -> bool {
+    //  先判断是否为「字母/数字」
+    c.is_alphanumeric()
+    // 特殊：横杠/下划线
+    // //! ↓【2024-02-22 14:46:16】现因需兼顾`<主词-->谓词>`的结构（防止系词中的`-`被消耗），故不再兼容`-`
+    // * 🚩【2024-03-28 14:18:08】现在重新启用对`-`的「原子词项字符兼容」：使用新的「前缀failing匹配」方法
+    || c == '_' || c == '-'
+    // 常见emoji兼容
+    || c > '\u{1f2ff}'
+}
+
+/// 检验「原子词项名」
+/// * 🚩非空，仅含[合法词项名字符](is_valid_atom_name_char)，且不以`--`结尾
+pub fn validate_atom_name(name: &str) -> Result<(), TermEditError> {
+    let is_valid = !name.is_empty()
+        && name.chars().all(is_valid_atom_name_char)
+        && !name.ends_with("--");
+    match is_valid {
+        true => Ok(()),
+        false => Err(TermEditError::InvalidAtomName(name.into())),
+    }
+}
+
+/// 检验「词语名」
+/// * 🚩在[`validate_atom_name`]的基础上，不以占位符前缀`_`开头
+///   * 📄`_x`会被解析为「占位符」
+pub fn validate_word_name(name: &str) -> Result<(), TermEditError> {
+    validate_atom_name(name)?;
+    match name.starts_with('_') {
+        true => Err(TermEditError::InvalidAtomName(name.into())),
+        false => Ok(()),
+    }
+}
+
+/// 实现/可失败的构造函数
+/// * 🚩名称不合法⇒[`TermEditError::InvalidAtomName`]
+impl Term {
+    /// 构造/词语（检验名称）
+    pub fn try_new_word(word: impl Into<String>) -> Result<Self, TermEditError> {
+        let word = word.into();
+        validate_word_name(&word)?;
+        Ok(Word(word))
+    }
+
+    /// 构造/独立变量（检验名称）
+    pub fn try_new_variable_independent(name: impl Into<String>) -> Result<Self, TermEditError> {
+        let name = name.into();
+        validate_atom_name(&name)?;
+        Ok(VariableIndependent(name))
+    }
+
+    /// 构造/非独变量（检验名称）
+    pub fn try_new_variable_dependent(name: impl Into<String>) -> Result<Self, TermEditError> {
+        let name = name.into();
+        validate_atom_name(&name)?;
+        Ok(VariableDependent(name))
+    }
+
+    /// 构造/查询变量（检验名称）
+    pub fn try_new_variable_query(name: impl Into<String>) -> Result<Self, TermEditError> {
+        let name = name.into();
+        validate_atom_name(&name)?;
+        Ok(VariableQuery(name))
+    }
+
+    /// 构造/操作符（检验名称）
+    pub fn try_new_operator(operator: impl Into<String>) -> Result<Self, TermEditError> {
+        let operator = operator.into();
+        validate_atom_name(&operator)?;
+        Ok(Operator(operator))
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{conversion::string::impl_enum::format_instances::*, enum_narsese::Narsese};
+    use nar_dev_utils::asserts;
+
+    /// 对抗性的名称
+    const ADVERSARIAL_NAMES: &[&str] = &[
+        "", " ", "A B", "$x", "#x", "?x", "^op", "+1", "+123", "_", "_x", "x_", "-", "--", "a-",
+        "a--", "-->", "a-->b", "<A", "A>", "(A", "{A}", "[A]", "A,B", "A.", "A!", "A?", "A@",
+        "%1%", ":|:", "A;B", "\n", "a/b", r"a\b", "&&", "*", "x", "X1", "中文", "🚀", "1", "007",
+    ];
+
+    /// 可失败的构造函数
+    #[test]
+    fn test_try_new() {
+        asserts! {
+            Term::try_new_word("A") => Ok(Term::new_word("A")),
+            Term::try_new_word("$x") => Err(TermEditError::InvalidAtomName("$x".into())),
+            Term::try_new_word("_x") => Err(TermEditError::InvalidAtomName("_x".into())),
+            Term::try_new_word("") => Err(TermEditError::InvalidAtomName("".into())),
+            Term::try_new_word("A B") => Err(TermEditError::InvalidAtomName("A B".into())),
+            Term::try_new_word("a--") => Err(TermEditError::InvalidAtomName("a--".into())),
+            Term::try_new_word("a--b") => Ok(Term::new_word("a--b")),
+            // 变量、操作符的名称可以`_`开头：前缀之后不再识别占位符
+            Term::try_new_variable_independent("_x") => Ok(Term::new_variable_independent("_x")),
+            Term::try_new_variable_dependent("x") => Ok(Term::new_variable_dependent("x")),
+            Term::try_new_variable_query("x?") => Err(TermEditError::InvalidAtomName("x?".into())),
+            Term::try_new_operator("^go") => Err(TermEditError::InvalidAtomName("^go".into())),
+            TermEditError::InvalidAtomName("$x".into()).to_string() => "非法的原子词项名：\"$x\"",
+        }
+        // 修改名称
+        let mut word = Term::new_word("A");
+        asserts! {
+            word.set_atom_name("$x") => Err(TermEditError::InvalidAtomName("$x".into())),
+            word => Term::new_word("A"),
+            word.set_atom_name("_x") => Err(TermEditError::InvalidAtomName("_x".into())),
+            word.set_atom_name("B") => Ok(()),
+            word => Term::new_word("B"),
+        }
+    }
+
+    /// 不检验的构造函数：调试模式下panic
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "非法的原子词项名")]
+    fn test_new_word_invalid() {
+        let _ = Term::new_word("$x");
+    }
+
+    /// 往返：能构造⇒格式化后能原样解析回来
+    #[test]
+    fn test_round_trip() {
+        type Constructor = fn(String) -> Result<Term, TermEditError>;
+        let constructors: [Constructor; 5] = [
+            Term::try_new_word,
+            Term::try_new_variable_independent,
+            Term::try_new_variable_dependent,
+            Term::try_new_variable_query,
+            Term::try_new_operator,
+        ];
+        for format in [&FORMAT_ASCII, &FORMAT_LATEX] {
+            for name in ADVERSARIAL_NAMES {
+                for constructor in constructors {
+                    // 构造失败⇒提前报错，不会产生歧义
+                    let Ok(atom) = constructor(name.to_string()) else {
+                        continue;
+                    };
+                    // 单独的原子词项、陈述、复合词项中
+                    let terms = [
+                        atom.clone(),
+                        Term::new_inheritance(atom.clone(), Term::new_word("B")),
+                        Term::new_inheritance(Term::new_word("B"), atom.clone()),
+                        Term::new_product([atom.clone(), Term::new_word("B")]),
+                        Term::new_set_extension([atom.clone()]),
+                    ];
+                    for term in terms {
+                        let formatted = format.format_term(&term);
+                        let parsed = format.parse::<Narsese>(&formatted);
+                        let parsed_term = parsed.clone().ok().and_then(|n| n.try_into_term().ok());
+                        assert_eq!(parsed_term, Some(term), "{formatted:?} => {parsed:?}");
+                    }
+                }
+            }
+        }
+    }
+}
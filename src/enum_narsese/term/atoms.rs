@@ -0,0 +1,113 @@
+//! 词项的「原子词项提取」
+//! * 🎯为推理器预处理词项：收集变量（以便换名分离）、收集词汇表……
+//! * 🚩基于[先序遍历](Term::visit)：所有方法均递归深入组分
+//!   * 📌像：会提取到占位符
+//!   * ⚠️无序容器中的组分，提取顺序不确定
+
+use super::structs::*;
+use crate::api::GetCategory;
+
+/// 实现/原子词项提取
+impl Term {
+    /// 提取所有原子词项
+    /// * 📌按遍历顺序，不去重
+    /// * 📌包括像占位符、间隔、操作符
+    pub fn atoms(&self) -> Vec<&Term> {
+        let mut atoms = vec![];
+        self.visit(&mut |term| {
+            if term.is_atom() {
+                atoms.push(term)
+            }
+        });
+        atoms
+    }
+
+    /// 判断自身是否为变量
+    /// * 📌独立变量、非独变量、查询变量
+    pub fn is_variable(&self) -> bool {
+        matches!(
+            self,
+            VariableIndependent(..) | VariableDependent(..) | VariableQuery(..)
+        )
+    }
+
+    /// 提取所有变量
+    /// * 📌按遍历顺序，不去重
+    pub fn variables(&self) -> Vec<&Term> {
+        self.atoms()
+            .into_iter()
+            .filter(|term| term.is_variable())
+            .collect()
+    }
+
+    /// 提取所有词语的名称
+    /// * 📌按遍历顺序，不去重
+    pub fn words(&self) -> Vec<&str> {
+        self.atoms()
+            .into_iter()
+            .filter_map(|term| match term {
+                Word(name) => Some(name.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// 判断是否含有变量
+    /// * 📌包括自身
+    pub fn has_variable(&self) -> bool {
+        let mut has_variable = false;
+        self.visit(&mut |term| has_variable |= term.is_variable());
+        has_variable
+    }
+
+    /// 判断是否为「封闭词项」
+    /// * 📌不含任何变量
+    pub fn is_closed(&self) -> bool {
+        !self.has_variable()
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{api::GetTerm, conversion::string::impl_enum::tests::_sample_task};
+    use nar_dev_utils::asserts;
+
+    #[test]
+    fn test_atoms() {
+        let task = _sample_task();
+        let term = task.get_term();
+        asserts! {
+            term.atoms() => [
+                &Term::new_word("ball"),
+                &Term::new_word("left"),
+                &Term::new_word("SELF"),
+                &Term::new_variable_independent("any"),
+                &Term::new_variable_dependent("some"),
+                &Term::new_operator("do"),
+                &Term::new_word("SELF"),
+                &Term::new_word("good"),
+            ],
+            term.variables() => [
+                &Term::new_variable_independent("any"),
+                &Term::new_variable_dependent("some"),
+            ],
+            term.words() => ["ball", "left", "SELF", "SELF", "good"],
+            term.has_variable(),
+            !term.is_closed(),
+        }
+        // 原子词项、像
+        let image = Term::new_image_extension(
+            1,
+            vec![Term::new_word("R"), Term::new_variable_query("x")],
+        );
+        asserts! {
+            Term::new_word("A").atoms() => [&Term::new_word("A")],
+            Term::new_word("A").is_closed(),
+            Term::new_variable_query("x").has_variable(),
+            image.atoms().contains(&&Term::Placeholder),
+            image.variables() => [&Term::new_variable_query("x")],
+        }
+    }
+}
@@ -0,0 +1,391 @@
+//! 词项的「增量构造器」
+//! * 🎯程序化生成词项：逐个添加组分，而非书写层层嵌套的构造函数
+//!   * 📄`TermBuilder::compound(Connecter::ConjunctionSequential).push(a).push(b).build()`
+//! * 🚩构造时检验元数：一元/二元须恰有1/2个组分，像须恰有一个占位符，多元须非空
+//! * 🚩占位符只能出现在像与乘积中：同解析器「非像⇒占位符是普通组分」的约定
+//!   * 📌错误类型：[`TermBuildError`]
+//! * 📌与解析器「统一构建复合词项」的思路一致：像的占位符按其所在位置记录
+
+use super::{error::TermBuildError, structs::*};
+use crate::api::{Connecter, Copula, TermCapacity, UIntPrecision};
+
+/// 构造器所构造词项的种类
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TermBuilderKind {
+    /// 复合词项
+    Compound(Connecter),
+    /// 陈述
+    Statement(Copula),
+    /// 外延集
+    SetExtension,
+    /// 内涵集
+    SetIntension,
+}
+
+/// 词项构造器
+/// * 🚩先指定种类，再逐个添加组分，最后调用[`TermBuilder::build`]构造
+/// * ⚠️仅在构造时检验：添加组分的过程总是成功
+#[derive(Debug, Clone, PartialEq)]
+pub struct TermBuilder {
+    /// 所构造词项的种类
+    kind: TermBuilderKind,
+    /// 组分 | 不含像的占位符
+    components: TermVecType,
+    /// 像的占位符所在位置
+    /// * 📌即「添加占位符时已有的组分数」
+    placeholders: Vec<UIntPrecision>,
+}
+
+/// 实现/构造
+impl TermBuilder {
+    /// 内部构造函数
+    fn new(kind: TermBuilderKind) -> Self {
+        Self {
+            kind,
+            components: vec![],
+            placeholders: vec![],
+        }
+    }
+
+    /// 构造复合词项
+    pub fn compound(connecter: Connecter) -> Self {
+        Self::new(TermBuilderKind::Compound(connecter))
+    }
+
+    /// 构造陈述
+    /// * 📌先添加的为主词，后添加的为谓词
+    pub fn statement(copula: Copula) -> Self {
+        Self::new(TermBuilderKind::Statement(copula))
+    }
+
+    /// 构造外延集
+    pub fn set_extension() -> Self {
+        Self::new(TermBuilderKind::SetExtension)
+    }
+
+    /// 构造内涵集
+    pub fn set_intension() -> Self {
+        Self::new(TermBuilderKind::SetIntension)
+    }
+}
+
+/// 实现/添加组分
+impl TermBuilder {
+    /// 是否在构造像
+    fn is_image(&self) -> bool {
+        matches!(self.kind, TermBuilderKind::Compound(connecter) if connecter.is_image())
+    }
+
+    /// 添加组分
+    /// * 📌构造像时，占位符等同于[`Self::push_placeholder`]
+    pub fn push(mut self, term: Term) -> Self {
+        match (self.is_image(), term) {
+            (true, Placeholder) => self.placeholders.push(self.components.len()),
+            (_, term) => self.components.push(term),
+        }
+        self
+    }
+
+    /// 添加占位符
+    /// * 🚩构造像⇒记录占位符位置
+    /// * 🚩其它⇒作为普通组分添加
+    pub fn push_placeholder(self) -> Self {
+        self.push(Term::new_placeholder())
+    }
+
+    /// 添加多个组分
+    /// * 📄逐个调用[`Self::push`]
+    pub fn extend(self, terms: impl IntoIterator<Item = Term>) -> Self {
+        terms.into_iter().fold(self, Self::push)
+    }
+}
+
+/// 工具函数：取出恰好`N`个组分
+fn take_exact<const N: usize>(components: TermVecType) -> Result<[Term; N], TermBuildError> {
+    let found = components.len();
+    components
+        .try_into()
+        .map_err(|_| TermBuildError::ArityMismatch { expected: N, found })
+}
+
+/// 工具函数：检验组分非空
+fn check_non_empty(components: &TermVecType) -> Result<(), TermBuildError> {
+    match components.is_empty() {
+        true => Err(TermBuildError::EmptyComponents),
+        false => Ok(()),
+    }
+}
+
+/// 实现/构造
+impl TermBuilder {
+    /// 构造词项
+    /// * 🚩检验元数，再调用相应的构造函数
+    /// * ⚠️组分不足或过多、像的占位符不为一个、多元复合词项为空⇒返回错误
+    /// * ⚠️占位符出现在像与乘积之外⇒返回错误
+    pub fn build(self) -> Result<Term, TermBuildError> {
+        let Self {
+            kind,
+            components,
+            placeholders,
+        } = self;
+        // 像的占位符不在组分中；乘积中的占位符是普通组分
+        let allows_placeholder = matches!(kind, TermBuilderKind::Compound(Connecter::Product));
+        if !allows_placeholder && components.contains(&Placeholder) {
+            return Err(TermBuildError::UnexpectedPlaceholder);
+        }
+        match kind {
+            TermBuilderKind::Statement(copula) => {
+                let [subject, predicate] = take_exact(components)?;
+                Ok(Self::build_statement(copula, subject, predicate))
+            }
+            TermBuilderKind::SetExtension => {
+                check_non_empty(&components)?;
+                Ok(Term::new_set_extension(components))
+            }
+            TermBuilderKind::SetIntension => {
+                check_non_empty(&components)?;
+                Ok(Term::new_set_intension(components))
+            }
+            TermBuilderKind::Compound(connecter) => {
+                Self::build_compound(connecter, components, &placeholders)
+            }
+        }
+    }
+
+    /// 构造陈述
+    fn build_statement(copula: Copula, subject: Term, predicate: Term) -> Term {
+        match copula {
+            Copula::Inheritance => Term::new_inheritance(subject, predicate),
+            Copula::Similarity => Term::new_similarity(subject, predicate),
+            Copula::Implication => Term::new_implication(subject, predicate),
+            Copula::Equivalence => Term::new_equivalence(subject, predicate),
+            Copula::Instance => Term::new_instance(subject, predicate),
+            Copula::Property => Term::new_property(subject, predicate),
+            Copula::InstanceProperty => Term::new_instance_property(subject, predicate),
+            Copula::ImplicationPredictive => Term::new_implication_predictive(subject, predicate),
+            Copula::ImplicationConcurrent => Term::new_implication_concurrent(subject, predicate),
+            Copula::ImplicationRetrospective => {
+                Term::new_implication_retrospective(subject, predicate)
+            }
+            Copula::EquivalencePredictive => Term::new_equivalence_predictive(subject, predicate),
+            Copula::EquivalenceConcurrent => Term::new_equivalence_concurrent(subject, predicate),
+            Copula::EquivalenceRetrospective => {
+                Term::new_equivalence_retrospective(subject, predicate)
+            }
+        }
+    }
+
+    /// 构造复合词项
+    fn build_compound(
+        connecter: Connecter,
+        components: TermVecType,
+        placeholders: &[UIntPrecision],
+    ) -> Result<Term, TermBuildError> {
+        // 一元、二元
+        match connecter.capacity() {
+            TermCapacity::Unary => {
+                let [term] = take_exact(components)?;
+                return Ok(Term::new_negation(term));
+            }
+            TermCapacity::BinaryVec | TermCapacity::BinarySet => {
+                let [left, right] = take_exact(components)?;
+                return Ok(match connecter {
+                    Connecter::DifferenceIntension => Term::new_difference_intension(left, right),
+                    _ => Term::new_difference_extension(left, right),
+                });
+            }
+            _ => check_non_empty(&components)?,
+        }
+        // 像
+        if connecter.is_image() {
+            let &[index] = placeholders else {
+                return Err(TermBuildError::ImagePlaceholderCount(placeholders.len()));
+            };
+            return match connecter {
+                Connecter::ImageIntension => Term::try_new_image_intension(index, components),
+                _ => Term::try_new_image_extension(index, components),
+            };
+        }
+        // 多元
+        Ok(match connecter {
+            Connecter::IntersectionExtension => Term::new_intersection_extension(components),
+            Connecter::IntersectionIntension => Term::new_intersection_intension(components),
+            Connecter::Product => Term::new_product(components),
+            Connecter::Conjunction => Term::new_conjunction(components),
+            Connecter::Disjunction => Term::new_disjunction(components),
+            Connecter::ConjunctionSequential => Term::new_conjunction_sequential(components),
+            Connecter::ConjunctionParallel => Term::new_conjunction_parallel(components),
+            // ! 一元、二元、像已在上边处理
+            Connecter::Negation
+            | Connecter::DifferenceExtension
+            | Connecter::DifferenceIntension
+            | Connecter::ImageExtension
+            | Connecter::ImageIntension => unreachable!("已处理的连接符：{connecter:?}"),
+        })
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nar_dev_utils::{asserts, show};
+
+    /// 测试/重建样本词项
+    /// * 🎯与构造函数所得词项相等
+    /// * 📄`conversion::string::impl_enum::tests::_sample_task`
+    #[test]
+    fn test_rebuild_sample() -> Result<(), TermBuildError> {
+        // 构造函数版本
+        let ball_left = Term::new_instance_property(Term::new_word("ball"), Term::new_word("left"));
+        let conditional_operation = Term::new_conjunction_sequential(vec![
+            ball_left.clone(),
+            Term::new_inheritance(
+                Term::new_product(vec![
+                    Term::new_set_extension(vec![Term::new_word("SELF")]),
+                    Term::new_variable_independent("any"),
+                    Term::new_variable_dependent("some"),
+                ]),
+                Term::new_operator("do"),
+            ),
+        ]);
+        let self_good = Term::new_instance_property(Term::new_word("SELF"), Term::new_word("good"));
+        let expected = Term::new_implication(conditional_operation, self_good);
+
+        // 构造器版本
+        let instance_property = |subject, predicate| {
+            TermBuilder::statement(Copula::InstanceProperty)
+                .push(Term::new_word(subject))
+                .push(Term::new_word(predicate))
+                .build()
+        };
+        let product = TermBuilder::compound(Connecter::Product)
+            .push(TermBuilder::set_extension().push(Term::new_word("SELF")).build()?)
+            .push(Term::new_variable_independent("any"))
+            .push(Term::new_variable_dependent("some"))
+            .build()?;
+        let operation = TermBuilder::statement(Copula::Inheritance)
+            .push(product)
+            .push(Term::new_operator("do"))
+            .build()?;
+        let conditional_operation = TermBuilder::compound(Connecter::ConjunctionSequential)
+            .push(instance_property("ball", "left")?)
+            .push(operation)
+            .build()?;
+        let term = TermBuilder::statement(Copula::Implication)
+            .push(conditional_operation)
+            .push(instance_property("SELF", "good")?)
+            .build()?;
+        show!(&term);
+        assert_eq!(term, expected);
+        Ok(())
+    }
+
+    /// 测试/像
+    #[test]
+    fn test_image() {
+        let word = Term::new_word;
+        asserts! {
+            // 占位符位置 | 两种添加方式等价
+            TermBuilder::compound(Connecter::ImageExtension)
+                .push(word("open"))
+                .push_placeholder()
+                .push(word("door"))
+                .build()
+                => Ok(Term::new_image_extension(1, vec![word("open"), word("door")])),
+            TermBuilder::compound(Connecter::ImageIntension)
+                .extend([word("A"), word("B"), Term::new_placeholder()])
+                .build()
+                => Ok(Term::new_image_intension(2, vec![word("A"), word("B")])),
+            // 乘积⇒占位符作为普通组分
+            TermBuilder::compound(Connecter::Product)
+                .push_placeholder()
+                .push(word("A"))
+                .build()
+                => Ok(Term::new_product(vec![Term::new_placeholder(), word("A")])),
+        }
+    }
+
+    /// 测试/像与乘积之外的占位符
+    /// * 🎯陈述、词项集、其它复合词项中的占位符⇒错误
+    #[test]
+    fn test_unexpected_placeholder() {
+        let word = Term::new_word;
+        let placeholder = Term::new_placeholder;
+        asserts! {
+            TermBuilder::statement(Copula::Inheritance)
+                .push_placeholder()
+                .push(word("A"))
+                .build()
+                => Err(TermBuildError::UnexpectedPlaceholder),
+            TermBuilder::set_extension().extend([word("A"), placeholder()]).build()
+                => Err(TermBuildError::UnexpectedPlaceholder),
+            TermBuilder::set_intension().push_placeholder().build()
+                => Err(TermBuildError::UnexpectedPlaceholder),
+            TermBuilder::compound(Connecter::Negation).push_placeholder().build()
+                => Err(TermBuildError::UnexpectedPlaceholder),
+            TermBuilder::compound(Connecter::DifferenceExtension)
+                .extend([word("A"), placeholder()])
+                .build()
+                => Err(TermBuildError::UnexpectedPlaceholder),
+            TermBuilder::compound(Connecter::Conjunction)
+                .extend([word("A"), placeholder()])
+                .build()
+                => Err(TermBuildError::UnexpectedPlaceholder),
+            // 先于元数检验
+            TermBuilder::compound(Connecter::Negation)
+                .extend([placeholder(), placeholder()])
+                .build()
+                => Err(TermBuildError::UnexpectedPlaceholder),
+            // 嵌套于乘积中的占位符不受影响
+            TermBuilder::statement(Copula::Inheritance)
+                .push(TermBuilder::compound(Connecter::Product).push_placeholder().build().unwrap())
+                .push(word("A"))
+                .build()
+                .is_ok(),
+            // 错误信息
+            TermBuilder::set_extension().push_placeholder().build().unwrap_err().to_string()
+                => "占位符只能出现在像或乘积中",
+        }
+    }
+
+    /// 测试/元数错误
+    #[test]
+    fn test_arity_errors() {
+        let word = Term::new_word;
+        asserts! {
+            // 陈述：须恰有两个组分
+            TermBuilder::statement(Copula::Inheritance).push(word("A")).build()
+                => Err(TermBuildError::ArityMismatch { expected: 2, found: 1 }),
+            TermBuilder::statement(Copula::Similarity)
+                .extend([word("A"), word("B"), word("C")])
+                .build()
+                => Err(TermBuildError::ArityMismatch { expected: 2, found: 3 }),
+            // 一元、二元复合词项
+            TermBuilder::compound(Connecter::Negation).build()
+                => Err(TermBuildError::ArityMismatch { expected: 1, found: 0 }),
+            TermBuilder::compound(Connecter::Negation).extend([word("A"), word("B")]).build()
+                => Err(TermBuildError::ArityMismatch { expected: 1, found: 2 }),
+            TermBuilder::compound(Connecter::DifferenceExtension).push(word("A")).build()
+                => Err(TermBuildError::ArityMismatch { expected: 2, found: 1 }),
+            // 像：须恰有一个占位符
+            TermBuilder::compound(Connecter::ImageExtension).push(word("A")).build()
+                => Err(TermBuildError::ImagePlaceholderCount(0)),
+            TermBuilder::compound(Connecter::ImageIntension)
+                .push_placeholder()
+                .push(word("A"))
+                .push_placeholder()
+                .build()
+                => Err(TermBuildError::ImagePlaceholderCount(2)),
+            // 词项集、多元复合词项：须非空
+            TermBuilder::set_extension().build() => Err(TermBuildError::EmptyComponents),
+            TermBuilder::set_intension().build() => Err(TermBuildError::EmptyComponents),
+            TermBuilder::compound(Connecter::Conjunction).build() => Err(TermBuildError::EmptyComponents),
+            TermBuilder::compound(Connecter::ImageExtension).push_placeholder().build()
+                => Err(TermBuildError::EmptyComponents),
+            // 错误信息
+            TermBuilder::compound(Connecter::Negation).build().unwrap_err().to_string()
+                => "组分数不匹配：应有1个，实有0个",
+        }
+    }
+}
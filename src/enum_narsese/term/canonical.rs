@@ -0,0 +1,58 @@
+//! 词项的「规范形式」
+//! * 🎯使词项的内部表示尽可能与构造顺序无关
+//!   * 📄`<B <-> A>`与`<A <-> B>`：判等、散列时相同，但内部的主谓词顺序不同
+//! * 🚩对称陈述（相似、等价、并发性等价）：主谓词按[全序](Ord)排列
+//! * ⚠️无序容器基于[`HashSet`](std::collections::HashSet)：无法承载顺序
+//!   * 🚩确定性的格式化输出：开启枚举Narsese格式的`canonical_order`选项
+//!   * 📌路径寻址、α-规范化等亦按全序遍历无序容器，不依赖其遍历顺序
+
+use super::structs::*;
+
+/// 实现/规范化
+impl Term {
+    /// 转换为规范形式
+    /// * 🚩递归处理所有子词项（自底向上）
+    /// * 📌规范形式与原词项相等：[`PartialEq`]、[`Hash`]本就不考虑对称陈述的主谓词顺序
+    /// * 📌返回新词项，不修改自身
+    pub fn to_canonical(&self) -> Term {
+        self.clone().map_terms(&mut |term| match term {
+            Similarity(left, right) if right < left => Similarity(right, left),
+            Equivalence(left, right) if right < left => Equivalence(right, left),
+            EquivalenceConcurrent(left, right) if right < left => {
+                EquivalenceConcurrent(right, left)
+            }
+            other => other,
+        })
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        conversion::string::impl_enum::format_instances::FORMAT_ASCII, enum_narsese::tests::term,
+    };
+    use nar_dev_utils::asserts;
+
+    /// 测试/对称陈述
+    #[test]
+    fn test_to_canonical() {
+        let forward = Term::new_similarity(Term::new_word("A"), Term::new_word("B"));
+        let backward = Term::new_similarity(Term::new_word("B"), Term::new_word("A"));
+        let nested = term("(&&, <B <=> A>, (--, <D <|> C>))");
+        asserts! {
+            // 主谓词顺序统一
+            format!("{:?}", backward.to_canonical()) => format!("{forward:?}"),
+            format!("{:?}", forward.to_canonical()) => format!("{forward:?}"),
+            // 递归 | 与原词项相等
+            nested.to_canonical() => nested,
+            FORMAT_ASCII.format_term(&term("(--, <D <|> C>)").to_canonical())
+                => "(--, <C <|> D>)",
+            // 幂等
+            nested.to_canonical().to_canonical() => nested.to_canonical(),
+            // 非对称陈述不变
+            FORMAT_ASCII.format_term(&term("<B --> A>").to_canonical()) => "<B --> A>",
+        }
+    }
+}
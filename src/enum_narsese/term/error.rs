@@ -0,0 +1,87 @@
+//! 词项修改时的错误
+//! * 🎯替代原先基于[`std::io::Error`]的错误：词项操作与IO无关
+//!   * 📌可按具体的变体分支处理，而无需比对错误消息
+//! * 🚩兼容原先的错误类型：可经[`From`]转换为[`std::io::Error`]
+//!   * 📌返回`io::Result`的调用方可继续使用`?`
+
+use crate::api::UIntPrecision;
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{Error as IoError, ErrorKind as IoErrorKind},
+};
+
+/// 词项修改错误
+/// * 📄[`Term::set_atom_name`](super::Term::set_atom_name)
+/// * 📄[`Term::try_new_word`](super::Term::try_new_word)
+/// * 📄[`Term::push_components`](super::Term::push_components)
+/// * 📄[`Term::try_new_image_extension`](super::Term::try_new_image_extension)
+/// * 📄[`TermBuilder::build`](super::TermBuilder::build)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TermEditError {
+    /// 在间隔中设置了无法解析为数值的名称
+    InvalidInterval(String),
+    /// 不合法的原子词项名：格式化后无法原样解析回来
+    /// * 📄空名称、含空白或括弧、以其它原子词项前缀开头的词语
+    InvalidAtomName(String),
+    /// 在非原子词项中设置词项名
+    NotAtom,
+    /// 为容量固定的词项（原子、一元、二元）添加词项
+    FixedCapacity,
+    /// 未定义的多元复合词项
+    UnknownCompound,
+    /// 像的占位符索引超出范围
+    /// * 📌合法范围：`0..=组分数`（等于组分数⇒占位符在最后）
+    ImagePlaceholderOutOfRange { index: UIntPrecision, len: usize },
+    /// 组分数不符合元数
+    /// * 📄一元复合词项须有1个组分，二元复合词项、陈述须有2个组分
+    ArityMismatch { expected: usize, found: usize },
+    /// 多元复合词项、词项集的组分为空
+    EmptyComponents,
+    /// 像的占位符数目不为1
+    ImagePlaceholderCount(usize),
+    /// 占位符出现在像与乘积之外
+    /// * 📄陈述、词项集、其它复合词项的组分
+    UnexpectedPlaceholder,
+}
+
+/// 词项构造错误
+/// * 🎯用于「可失败的构造函数」：[`Term::try_new_word`](super::Term::try_new_word)、[`Term::try_new_image_extension`](super::Term::try_new_image_extension)等
+/// * 📌与「词项修改错误」共用同一类型：二者的错误情形多有重叠
+pub type TermBuildError = TermEditError;
+
+impl Display for TermEditError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::InvalidInterval(name) => write!(f, "尝试在间隔中设置无效的数值：{name:?}"),
+            Self::InvalidAtomName(name) => write!(f, "非法的原子词项名：{name:?}"),
+            Self::NotAtom => write!(f, "尝试在非原子词项中设置词项名"),
+            Self::FixedCapacity => write!(f, "尝试为容量固定的词项添加词项"),
+            Self::UnknownCompound => write!(f, "未定义的多元复合词项"),
+            Self::ImagePlaceholderOutOfRange { index, len } => {
+                write!(f, "像的占位符超出范围：索引{index}，组分数{len}")
+            }
+            Self::ArityMismatch { expected, found } => {
+                write!(f, "组分数不匹配：应有{expected}个，实有{found}个")
+            }
+            Self::EmptyComponents => write!(f, "复合词项的组分为空"),
+            Self::ImagePlaceholderCount(n) => write!(f, "像须有且仅有一个占位符，实有{n}个"),
+            Self::UnexpectedPlaceholder => write!(f, "占位符只能出现在像或乘积中"),
+        }
+    }
+}
+
+impl std::error::Error for TermEditError {}
+
+/// 兼容原先基于[`std::io::Error`]的错误
+/// * 📌错误种类同原先：间隔数值无效⇒[`IoErrorKind::InvalidInput`]，其它⇒[`IoErrorKind::InvalidData`]
+impl From<TermEditError> for IoError {
+    fn from(error: TermEditError) -> Self {
+        let kind = match error {
+            TermEditError::InvalidInterval(..) | TermEditError::InvalidAtomName(..) => {
+                IoErrorKind::InvalidInput
+            }
+            _ => IoErrorKind::InvalidData,
+        };
+        IoError::new(kind, error)
+    }
+}
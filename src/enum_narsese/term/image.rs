@@ -0,0 +1,158 @@
+//! 词项的「像」与「乘积」之间的转换（NAL-4）
+//! * 🎯实现NAL-4中「关系」的结构变换，不涉及真值
+//!   * 📄`<(*, acid, base) --> reaction>`
+//!   * 📄⇔`<acid --> (/, reaction, _, base)>`
+//!   * 📄⇔`<base --> (/, reaction, acid, _)>`
+//! * 📌关系总在像的第0位：占位符标记「被提取出的组分」所在位置
+//!   * 📌像的占位符索引 = 乘积中的位置 + 1
+//! * 🚩输入不是乘积/像、索引越界⇒[`None`]
+
+use super::structs::*;
+use crate::api::UIntPrecision;
+
+/// 实现/像与乘积
+impl Term {
+    /// 从乘积构造像
+    /// * 🚩提取乘积中`placeholder_position`处的组分，以占位符标记之；关系置于第0位
+    ///   * 📄`(*, acid, base)`、`reaction`、`0`⇒`(/, reaction, _, base)`
+    /// * 📌`extension`：`true`⇒外延像，`false`⇒内涵像
+    ///   * 📄外延：`<(*, a, b) --> R>`⇔`<a --> (/, R, _, b)>`
+    ///   * 📄内涵：`<R --> (*, a, b)>`⇔`<(\, R, _, b) --> a>`
+    /// * ⚠️被提取出的组分不在结果中：由调用方放在陈述的另一侧
+    /// * 📌不是乘积、位置越界⇒[`None`]
+    pub fn image_from_product(
+        product: &Term,
+        relation: &Term,
+        placeholder_position: UIntPrecision,
+        extension: bool,
+    ) -> Option<Term> {
+        let Product(components) = product else {
+            return None;
+        };
+        if placeholder_position >= components.len() {
+            return None;
+        }
+        let terms = std::iter::once(relation.clone()).chain(
+            components
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != placeholder_position)
+                .map(|(_, term)| term.clone()),
+        );
+        // 占位符索引：关系占据第0位
+        let placeholder_index = placeholder_position + 1;
+        Some(match extension {
+            true => Term::new_image_extension(placeholder_index, terms),
+            false => Term::new_image_intension(placeholder_index, terms),
+        })
+    }
+
+    /// 从像还原乘积
+    /// * 🚩取出第0位的关系，写入`relation_out`；其余组分按原位置组成乘积
+    ///   * 📄`(/, reaction, _, base)`⇒`(*, _, base)`，关系为`reaction`
+    /// * ⚠️被提取出的组分不在像中：乘积中的相应位置仍为占位符，由调用方填入
+    ///   * 📄`(*, _, base)`中的`_`即陈述另一侧的`acid`
+    /// * 📌外延像、内涵像均可
+    /// * 📌不是像、没有关系、占位符在关系位置或越界⇒[`None`]，且不修改`relation_out`
+    pub fn product_from_image(image: &Term, relation_out: &mut Option<Term>) -> Option<Term> {
+        let (ImageExtension(placeholder_index, terms) | ImageIntension(placeholder_index, terms)) =
+            image
+        else {
+            return None;
+        };
+        let placeholder_index = *placeholder_index;
+        let (relation, components) = terms.split_first()?;
+        if placeholder_index == 0 || placeholder_index > terms.len() {
+            return None;
+        }
+        // 占位符在乘积中的位置
+        let placeholder_position = placeholder_index - 1;
+        let mut components = components.to_vec();
+        components.insert(placeholder_position, Term::new_placeholder());
+        *relation_out = Some(relation.clone());
+        Some(Term::new_product(components))
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nar_dev_utils::asserts;
+
+    /// 工具函数：词语
+    fn word(name: &str) -> Term {
+        Term::new_word(name)
+    }
+
+    /// 教科书示例：`<(*, acid, base) --> reaction>`
+    #[test]
+    fn test_textbook_extension() {
+        let (acid, base, reaction) = (word("acid"), word("base"), word("reaction"));
+        let product = Term::new_product(vec![acid.clone(), base.clone()]);
+        let statement = Term::new_inheritance(product.clone(), reaction.clone());
+        // 乘积⇒像
+        let image_0 = Term::image_from_product(&product, &reaction, 0, true).unwrap();
+        let image_1 = Term::image_from_product(&product, &reaction, 1, true).unwrap();
+        asserts! {
+            image_0 => Term::new_image_extension(1, vec![reaction.clone(), base.clone()]),
+            image_1 => Term::new_image_extension(2, vec![reaction.clone(), acid.clone()]),
+            image_0.to_string() => "(/, reaction, _, base)",
+            image_1.to_string() => "(/, reaction, acid, _)",
+            Term::new_inheritance(acid.clone(), image_0.clone()).to_string() => "<acid --> (/, reaction, _, base)>",
+            Term::new_inheritance(base.clone(), image_1.clone()).to_string() => "<base --> (/, reaction, acid, _)>",
+        }
+        // 像⇒乘积：占位符由陈述的另一侧填入
+        for (subject, image) in [(&acid, &image_0), (&base, &image_1)] {
+            let mut relation = None;
+            let product = Term::product_from_image(image, &mut relation).unwrap();
+            let restored = Term::new_inheritance(
+                product.replace(&Term::new_placeholder(), subject),
+                relation.unwrap(),
+            );
+            assert_eq!(restored, statement);
+        }
+    }
+
+    /// 教科书示例（内涵）：`<reaction --> (*, acid, base)>`⇔`<(\, reaction, _, base) --> acid>`
+    #[test]
+    fn test_textbook_intension() {
+        let (acid, base, reaction) = (word("acid"), word("base"), word("reaction"));
+        let product = Term::new_product(vec![acid.clone(), base.clone()]);
+        let image = Term::image_from_product(&product, &reaction, 0, false).unwrap();
+        asserts! {
+            Term::new_inheritance(image.clone(), acid.clone()).to_string() => r"<(\, reaction, _, base) --> acid>",
+        }
+        let mut relation = None;
+        let restored = Term::product_from_image(&image, &mut relation).unwrap();
+        asserts! {
+            restored.to_string() => "(*, _, base)",
+            relation => Some(reaction),
+            restored.replace(&Term::new_placeholder(), &acid) => product,
+        }
+    }
+
+    /// 非法输入⇒[`None`]
+    #[test]
+    fn test_invalid() {
+        let (a, b, r) = (word("A"), word("B"), word("R"));
+        let product = Term::new_product(vec![a.clone(), b.clone()]);
+        let mut relation = None;
+        asserts! {
+            // 不是乘积
+            Term::image_from_product(&a, &r, 0, true) => None,
+            Term::image_from_product(&Term::new_set_extension(vec![a.clone()]), &r, 0, true) => None,
+            // 位置越界
+            Term::image_from_product(&product, &r, 2, true) => None,
+            Term::image_from_product(&Term::new_product(vec![]), &r, 0, false) => None,
+            // 不是像
+            Term::product_from_image(&product, &mut relation) => None,
+            // 占位符在关系的位置 | `(/, _, A, B)`
+            Term::product_from_image(&Term::new_image_extension(0, vec![a.clone(), b.clone()]), &mut relation) => None,
+            // 没有关系 | `(/, _)`
+            Term::product_from_image(&Term::new_image_extension(0, vec![]), &mut relation) => None,
+            // 失败时不修改输出
+            relation => None,
+        }
+    }
+}
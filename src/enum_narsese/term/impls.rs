@@ -1,15 +1,19 @@
 //! 统一定义词项实现
 
-use super::structs::*;
+use super::{
+    atom_name::{validate_atom_name, validate_word_name},
+    error::{TermBuildError, TermEditError},
+    structs::*,
+};
 use crate::api::{
-    ExtractTerms, GetCapacity, GetCategory, GetTerm, TermCapacity, TermCategory, UIntPrecision,
+    complexity_of_components, depth_of_components, ExtractTerms, GetCapacity, GetCategory,
+    GetComplexity, GetTerm, TermCapacity, TermCategory, UIntPrecision,
 };
 use nar_dev_utils::ResultBoost;
 use std::{
     any::type_name,
-    error::Error,
+    cmp::Ordering,
     hash::Hash,
-    io::{Error as IoError, ErrorKind as IoErrorKind},
 };
 
 // 实现 //
@@ -61,28 +65,33 @@ fn from_term_settable_to_term_vec(settable: impl IntoIterator<Item = Term>) -> T
     vec
 }
 
-/// 在像中测试像索引
-/// * ⚠️若不合法，则panic
-fn test_term_vec_for_image(placeholder_index: UIntPrecision, vec: &TermVecType) {
-    // 检查 | 判断索引是否越界
-    // * 📌在`placeholder_index == vec.len()`时，相当于「占位符在最后一个」的情况
-    if placeholder_index > vec.len() {
-        panic!("占位符超出范围")
+/// 在像中检验像索引
+/// * 📌在`placeholder_index == vec.len()`时，相当于「占位符在最后一个」的情况
+fn check_term_vec_for_image(
+    placeholder_index: UIntPrecision,
+    vec: &TermVecType,
+) -> Result<(), TermBuildError> {
+    match placeholder_index > vec.len() {
+        true => Err(TermBuildError::ImagePlaceholderOutOfRange {
+            index: placeholder_index,
+            len: vec.len(),
+        }),
+        false => Ok(()),
     }
 }
 
 /// 创造一个合法的像与索引
-fn new_term_vec_for_image(
+/// * ⚠️索引越界⇒返回错误
+fn try_new_term_vec_for_image(
     placeholder_index: UIntPrecision,
     terms: impl IntoIterator<Item = Term>,
-) -> TermVecType {
+) -> Result<TermVecType, TermBuildError> {
     // 创建
     let vec = from_term_settable_to_term_vec(terms);
     // 检查 | 判断索引是否越界
-    // * 📌在`placeholder_index == vec.len()`时，相当于「占位符在最后一个」的情况
-    test_term_vec_for_image(placeholder_index, &vec);
+    check_term_vec_for_image(placeholder_index, &vec)?;
     // 返回
-    vec
+    Ok(vec)
 }
 
 /// 实现/构造函数
@@ -90,8 +99,12 @@ impl Term {
     // 原子词项 //
 
     /// 构造/词语
+    /// * ⚠️不检验名称：调试模式下，不合法的名称会panic
+    ///   * 📌需要检验时，使用[`Self::try_new_word`]
     pub fn new_word(word: impl Into<String>) -> Self {
-        Word(word.into())
+        let word = word.into();
+        debug_assert!(validate_word_name(&word).is_ok(), "非法的原子词项名：{word:?}");
+        Word(word)
     }
 
     /// 构造/占位符
@@ -100,18 +113,30 @@ impl Term {
     }
 
     /// 构造/独立变量
+    /// * ⚠️不检验名称：调试模式下，不合法的名称会panic
+    ///   * 📌需要检验时，使用[`Self::try_new_variable_independent`]
     pub fn new_variable_independent(name: impl Into<String>) -> Self {
-        VariableIndependent(name.into())
+        let name = name.into();
+        debug_assert!(validate_atom_name(&name).is_ok(), "非法的原子词项名：{name:?}");
+        VariableIndependent(name)
     }
 
     /// 构造/非独变量
+    /// * ⚠️不检验名称：调试模式下，不合法的名称会panic
+    ///   * 📌需要检验时，使用[`Self::try_new_variable_dependent`]
     pub fn new_variable_dependent(name: impl Into<String>) -> Self {
-        VariableDependent(name.into())
+        let name = name.into();
+        debug_assert!(validate_atom_name(&name).is_ok(), "非法的原子词项名：{name:?}");
+        VariableDependent(name)
     }
 
     /// 构造/查询变量
+    /// * ⚠️不检验名称：调试模式下，不合法的名称会panic
+    ///   * 📌需要检验时，使用[`Self::try_new_variable_query`]
     pub fn new_variable_query(name: impl Into<String>) -> Self {
-        VariableQuery(name.into())
+        let name = name.into();
+        debug_assert!(validate_atom_name(&name).is_ok(), "非法的原子词项名：{name:?}");
+        VariableQuery(name)
     }
 
     /// 构造/间隔
@@ -120,8 +145,12 @@ impl Term {
     }
 
     /// 构造/操作符
+    /// * ⚠️不检验名称：调试模式下，不合法的名称会panic
+    ///   * 📌需要检验时，使用[`Self::try_new_operator`]
     pub fn new_operator(operator: impl Into<String>) -> Self {
-        Operator(operator.into())
+        let operator = operator.into();
+        debug_assert!(validate_atom_name(&operator).is_ok(), "非法的原子词项名：{operator:?}");
+        Operator(operator)
     }
 
     // 复合词项 //
@@ -162,25 +191,44 @@ impl Term {
     }
 
     /// 构造/外延像
+    /// * ⚠️占位符索引越界⇒panic
+    ///   * 📌需要检验时，使用[`Self::try_new_image_extension`]
     pub fn new_image_extension(
         placeholder_index: UIntPrecision,
         terms: impl IntoIterator<Item = Term>,
     ) -> Self {
-        ImageExtension(
-            placeholder_index,
-            new_term_vec_for_image(placeholder_index, terms),
-        )
+        Self::try_new_image_extension(placeholder_index, terms).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// 构造/外延像（可失败）
+    /// * 🎯从不受信任的索引数据构造像，而无需预先检验
+    /// * 📌占位符索引的合法范围：`0..=组分数`
+    pub fn try_new_image_extension(
+        placeholder_index: UIntPrecision,
+        terms: impl IntoIterator<Item = Term>,
+    ) -> Result<Self, TermBuildError> {
+        let terms = try_new_term_vec_for_image(placeholder_index, terms)?;
+        Ok(ImageExtension(placeholder_index, terms))
     }
 
     /// 构造/内涵像
+    /// * ⚠️占位符索引越界⇒panic
+    ///   * 📌需要检验时，使用[`Self::try_new_image_intension`]
     pub fn new_image_intension(
         placeholder_index: UIntPrecision,
         terms: impl IntoIterator<Item = Term>,
     ) -> Self {
-        ImageIntension(
-            placeholder_index,
-            new_term_vec_for_image(placeholder_index, terms),
-        )
+        Self::try_new_image_intension(placeholder_index, terms).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// 构造/内涵像（可失败）
+    /// * 📄同[`Self::try_new_image_extension`]
+    pub fn try_new_image_intension(
+        placeholder_index: UIntPrecision,
+        terms: impl IntoIterator<Item = Term>,
+    ) -> Result<Self, TermBuildError> {
+        let terms = try_new_term_vec_for_image(placeholder_index, terms)?;
+        Ok(ImageIntension(placeholder_index, terms))
     }
 
     /// 构造/合取
@@ -274,9 +322,16 @@ impl Term {
     }
 
     /// 回顾性等价 | A <\> C
-    /// * ⚠️自动转换成「预测性等价」
-    ///   * 转换后形式：`C <\> A`
+    /// * 📌保留原始形式，不作转换
+    ///   * 🚩需要转换时，使用[`Term::new_equivalence_retrospective_normalized`]
     pub fn new_equivalence_retrospective(antecedent: Term, consequent: Term) -> Self {
+        EquivalenceRetrospective(new_term_ref_type(antecedent), new_term_ref_type(consequent))
+    }
+
+    /// 回顾性等价（规范化） | A <\> C
+    /// * ⚠️自动转换成「预测性等价」
+    ///   * 转换后形式：`C </> A`
+    pub fn new_equivalence_retrospective_normalized(antecedent: Term, consequent: Term) -> Self {
         Term::new_equivalence_predictive(consequent, antecedent)
     }
 
@@ -284,6 +339,8 @@ impl Term {
 
     /// 工具函数/像：伴随占位符的初始化
     /// * 🚩找到并消耗第一个占位符，并将其用作「占位符位置」
+    /// * ⚠️无占位符、多于一个占位符⇒[`None`]
+    ///   * 📌此时`target`中可能已有部分词项
     /// * 📝特征[`IntoIterator`]不直接支持`enumerate`方法
     ///   * 需要先使用[`IntoIterator::into_iter`]进行转换
     ///   * 或使用[`Iterator`]规避所有权问题（若需对自身进行处理）
@@ -300,6 +357,8 @@ impl Term {
                     // 置入（忽略返回值）
                     let _ = placeholder_index.insert(i);
                 }
+                // 多余的占位符⇒失败，而非作为普通组分
+                (Term::Placeholder, Some(..)) => return None,
                 _ => target.push(term),
             }
         }
@@ -309,7 +368,7 @@ impl Term {
     }
 
     /// 从「带有占位符的词项迭代器」中直接构建「外延像」
-    /// * 📌可能失败（无占位符时）
+    /// * 📌可能失败（无占位符、多于一个占位符时）
     ///   * 此时返回[`None`]
     pub fn to_image_extension_with_placeholder(
         terms: impl IntoIterator<Item = Term>,
@@ -322,7 +381,7 @@ impl Term {
     }
 
     /// 从「带有占位符的词项迭代器」中直接构建「内涵像」
-    /// * 📌可能失败（无占位符时）
+    /// * 📌可能失败（无占位符、多于一个占位符时）
     ///   * 此时返回[`None`]
     pub fn to_image_intension_with_placeholder(
         terms: impl IntoIterator<Item = Term>,
@@ -404,7 +463,8 @@ impl GetCategory for Term {
             | ImplicationConcurrent(..)
             | ImplicationRetrospective(..)
             | EquivalencePredictive(..)
-            | EquivalenceConcurrent(..) => Statement,
+            | EquivalenceConcurrent(..)
+            | EquivalenceRetrospective(..) => Statement,
         }
     }
 }
@@ -435,7 +495,8 @@ impl GetCapacity for Term {
             | ImplicationPredictive(..)
             | ImplicationConcurrent(..)
             | ImplicationRetrospective(..)
-            | EquivalencePredictive(..) => BinaryVec,
+            | EquivalencePredictive(..)
+            | EquivalenceRetrospective(..) => BinaryVec,
             // 二元集合
             Similarity(..) | Equivalence(..) | EquivalenceConcurrent(..) => BinarySet,
             // 序列
@@ -454,6 +515,24 @@ impl GetCapacity for Term {
     }
 }
 
+/// 词项复杂度
+/// * 📌像：包括占位符
+impl GetComplexity for Term {
+    fn complexity(&self) -> usize {
+        match self.is_atom() {
+            true => 1,
+            false => complexity_of_components(self.get_components_including_placeholder()),
+        }
+    }
+
+    fn depth(&self) -> usize {
+        match self.is_atom() {
+            true => 1,
+            false => depth_of_components(self.get_components_including_placeholder()),
+        }
+    }
+}
+
 /// 其它类型判断相关
 impl Term {
     // 通用 //
@@ -508,7 +587,21 @@ impl Term {
     /// * ⚠️对其它情况：静默失败
     /// * ⚠️对「占位符」：静默失败
     /// * 📌对「间隔」会自动转换成数值类型
-    pub fn set_atom_name(&mut self, new_name: &str) -> Result<(), impl Error> {
+    /// * 🚩名称不合法⇒[`TermEditError::InvalidAtomName`]，且不修改词项
+    pub fn set_atom_name(&mut self, new_name: &str) -> Result<(), TermEditError> {
+        match self {
+            Word(..) => validate_word_name(new_name)?,
+            VariableIndependent(..) | VariableDependent(..) | VariableQuery(..) | Operator(..) => {
+                validate_atom_name(new_name)?
+            }
+            _ => {}
+        }
+        self.set_atom_name_unchecked(new_name)
+    }
+
+    /// 设置词项作为原子词项的词项名（不检验名称）
+    /// * 🎯供解析器使用：其「合法词项名字符」由格式决定
+    pub(crate) fn set_atom_name_unchecked(&mut self, new_name: &str) -> Result<(), TermEditError> {
         match self {
             // 原子词项
             Word(name)
@@ -528,13 +621,10 @@ impl Term {
                 |new_interval| {
                     *interval = new_interval // * ↓隐式返回Ok(())
                 },
-                |_| IoError::new(IoErrorKind::InvalidInput, "尝试在间隔中设置无效的数值"),
+                |_| TermEditError::InvalidInterval(new_name.into()),
             ),
-            // 其它情况：静默失败
-            _ => Err(IoError::new(
-                IoErrorKind::InvalidData,
-                "尝试在非原子词项中设置词项名",
-            )),
+            // 其它情况：报错
+            _ => Err(TermEditError::NotAtom),
         }
     }
 
@@ -588,7 +678,8 @@ impl Term {
             | ImplicationConcurrent(term1, term2)
             | ImplicationRetrospective(term1, term2)
             | EquivalencePredictive(term1, term2)
-            | EquivalenceConcurrent(term1, term2) => vec![term1, term2],
+            | EquivalenceConcurrent(term1, term2)
+            | EquivalenceRetrospective(term1, term2) => vec![term1, term2],
 
             // 有序容器⇒返回拷贝后的容器
             Product(vec)
@@ -630,7 +721,7 @@ impl Term {
     pub fn push_components(
         &mut self,
         terms: impl IntoIterator<Item = Term>,
-    ) -> Result<(), impl Error> {
+    ) -> Result<(), TermEditError> {
         // 预先使用以简化
         use TermCapacity::*;
         // 模式匹配
@@ -642,10 +733,7 @@ impl Term {
             // 二元序列
             BinaryVec|
             // 二元集合
-            BinarySet=>Err(IoError::new(
-                IoErrorKind::InvalidData,
-                "尝试为容量固定的词项添加词项",
-            )),
+            BinarySet=>Err(TermEditError::FixedCapacity),
             // 多元词项 ⇒ 具体类型具体分析
             _ => match self {
                 // 序列 | 忽略「像」的占位符位置
@@ -666,10 +754,7 @@ impl Term {
                     Ok(())
                 },
                 // 其它⇒未知类型报错
-                _ => Err(IoError::new(
-                    IoErrorKind::InvalidData,
-                    "未定义的多元复合词项",
-                ))
+                _ => Err(TermEditError::UnknownCompound)
             },
         }
     }
@@ -684,14 +769,32 @@ fn test_components() {
 }
 
 /// 散列化「无序不重复词项容器」
-/// * ⚠️潜在假设：集合相同⇒遍历顺序相同⇒散列化顺序相同⇒散列化结果相同
+/// * 🚩按[全序](Ord)排序后逐个散列化
+///   * ⚠️相等的[`HashSet`]未必有相同的遍历顺序（容量、插入历史不同）
 fn hash_term_set<H: std::hash::Hasher>(set: &TermSetType, state: &mut H) {
     // 逐个元素散列化
-    for term in set {
+    for term in sorted_term_set(set) {
         term.hash(state)
     }
 }
 
+/// 将「无序不重复词项容器」按[全序](Ord)排序
+/// * 🎯使「散列化」「比较」不依赖集合的遍历顺序
+fn sorted_term_set(set: &TermSetType) -> Vec<&Term> {
+    let mut terms = set.iter().collect::<Vec<_>>();
+    terms.sort();
+    terms
+}
+
+/// 将「对称陈述」的主谓词按[全序](Ord)排序
+/// * 🎯使「散列化」「比较」与「对称判等」一致
+fn sorted_pair<'a>(t1: &'a Term, t2: &'a Term) -> (&'a Term, &'a Term) {
+    match t1 <= t2 {
+        true => (t1, t2),
+        false => (t2, t1),
+    }
+}
+
 /// 实现/散列化逻辑
 ///
 /// ?【2024-02-21 14:21:10】是否一定要实现
@@ -748,14 +851,18 @@ impl Hash for Term {
             ConjunctionParallel(set) => hash_term_set(set, state),
             // 陈述
             Inheritance(t1, t2)
-            | Similarity(t1, t2)
             | Implication(t1, t2)
-            | Equivalence(t1, t2)
             | ImplicationPredictive(t1, t2)
             | ImplicationConcurrent(t1, t2)
             | ImplicationRetrospective(t1, t2)
             | EquivalencePredictive(t1, t2)
-            | EquivalenceConcurrent(t1, t2) => {
+            | EquivalenceRetrospective(t1, t2) => {
+                t1.hash(state);
+                t2.hash(state);
+            }
+            // 对称陈述：与判等一致，不考虑主谓词顺序
+            Similarity(t1, t2) | Equivalence(t1, t2) | EquivalenceConcurrent(t1, t2) => {
+                let (t1, t2) = sorted_pair(t1, t2);
                 t1.hash(state);
                 t2.hash(state);
             }
@@ -805,7 +912,8 @@ impl PartialEq for Term {
             | (ImplicationPredictive(t1, t2), ImplicationPredictive(u1, u2))
             | (ImplicationConcurrent(t1, t2), ImplicationConcurrent(u1, u2))
             | (ImplicationRetrospective(t1, t2), ImplicationRetrospective(u1, u2))
-            | (EquivalencePredictive(t1, t2), EquivalencePredictive(u1, u2)) => {
+            | (EquivalencePredictive(t1, t2), EquivalencePredictive(u1, u2))
+            | (EquivalenceRetrospective(t1, t2), EquivalenceRetrospective(u1, u2)) => {
                 t1 == u1 && t2 == u2
             }
             // 二元无序`Box`
@@ -823,6 +931,125 @@ impl PartialEq for Term {
 /// 实现全相等
 impl Eq for Term {}
 
+/// 实现/全序
+/// * 📌顺序：先按类别，再按具体类型，最后按内容
+///   * 类别：原子词项 < 复合词项 < 陈述
+///   * 具体类型：按枚举中的声明顺序
+///   * 内容：名称、数值按自身顺序；组分按字典序逐个比较
+/// * 📌与判等逻辑一致
+///   * 无序容器：组分排序后再比较，不依赖[`HashSet`]的遍历顺序
+///   * 对称陈述：主谓词排序后再比较，即`<A <-> B>`与`<B <-> A>`相等
+impl Ord for Term {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // 类别、具体类型
+        let ordering = (self.get_category() as u8, self.variant_index())
+            .cmp(&(other.get_category() as u8, other.variant_index()));
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+        // 内容 | 此时两者必定为同一类型
+        match (self, other) {
+            // 原子词项 //
+            (Placeholder, Placeholder) => Ordering::Equal,
+            (Interval(i1), Interval(i2)) => i1.cmp(i2),
+            (Word(name1), Word(name2))
+            | (VariableIndependent(name1), VariableIndependent(name2))
+            | (VariableDependent(name1), VariableDependent(name2))
+            | (VariableQuery(name1), VariableQuery(name2))
+            | (Operator(name1), Operator(name2)) => name1.cmp(name2),
+            // 复合词项 //
+            // 集合：排序后比较
+            (SetExtension(s1), SetExtension(s2))
+            | (SetIntension(s1), SetIntension(s2))
+            | (IntersectionExtension(s1), IntersectionExtension(s2))
+            | (IntersectionIntension(s1), IntersectionIntension(s2))
+            | (Conjunction(s1), Conjunction(s2))
+            | (Disjunction(s1), Disjunction(s2))
+            | (ConjunctionParallel(s1), ConjunctionParallel(s2)) => {
+                sorted_term_set(s1).cmp(&sorted_term_set(s2))
+            }
+            // 数组+数值
+            (ImageExtension(i1, v1), ImageExtension(i2, v2))
+            | (ImageIntension(i1, v1), ImageIntension(i2, v2)) => (i1, v1).cmp(&(i2, v2)),
+            // 数组
+            (Product(v1), Product(v2)) | (ConjunctionSequential(v1), ConjunctionSequential(v2)) => {
+                v1.cmp(v2)
+            }
+            // 一元`Box`
+            (Negation(t1), Negation(t2)) => t1.cmp(t2),
+            // 二元有序`Box` | 含非对称陈述
+            (DifferenceExtension(t1, t2), DifferenceExtension(u1, u2))
+            | (DifferenceIntension(t1, t2), DifferenceIntension(u1, u2))
+            | (Inheritance(t1, t2), Inheritance(u1, u2))
+            | (Implication(t1, t2), Implication(u1, u2))
+            | (ImplicationPredictive(t1, t2), ImplicationPredictive(u1, u2))
+            | (ImplicationConcurrent(t1, t2), ImplicationConcurrent(u1, u2))
+            | (ImplicationRetrospective(t1, t2), ImplicationRetrospective(u1, u2))
+            | (EquivalencePredictive(t1, t2), EquivalencePredictive(u1, u2))
+            | (EquivalenceRetrospective(t1, t2), EquivalenceRetrospective(u1, u2)) => {
+                (t1, t2).cmp(&(u1, u2))
+            }
+            // 对称陈述：主谓词排序后比较
+            (Similarity(t1, t2), Similarity(u1, u2))
+            | (Equivalence(t1, t2), Equivalence(u1, u2))
+            | (EquivalenceConcurrent(t1, t2), EquivalenceConcurrent(u1, u2)) => {
+                sorted_pair(t1, t2).cmp(&sorted_pair(u1, u2))
+            }
+            // ! 不同类型已在上边返回
+            _ => unreachable!("同类型词项的比较应已穷尽"),
+        }
+    }
+}
+
+/// 实现/偏序 | 直接使用全序
+impl PartialOrd for Term {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// 实现/全序辅助
+impl Term {
+    /// 获取「具体类型」的序号
+    /// * 🎯用于[全序](Ord)中「同类别不同类型」的比较
+    /// * 🚩按枚举中的声明顺序
+    fn variant_index(&self) -> u8 {
+        match self {
+            Word(..) => 0,
+            Placeholder => 1,
+            VariableIndependent(..) => 2,
+            VariableDependent(..) => 3,
+            VariableQuery(..) => 4,
+            Interval(..) => 5,
+            Operator(..) => 6,
+            SetExtension(..) => 7,
+            SetIntension(..) => 8,
+            IntersectionExtension(..) => 9,
+            IntersectionIntension(..) => 10,
+            DifferenceExtension(..) => 11,
+            DifferenceIntension(..) => 12,
+            Product(..) => 13,
+            ImageExtension(..) => 14,
+            ImageIntension(..) => 15,
+            Conjunction(..) => 16,
+            Disjunction(..) => 17,
+            Negation(..) => 18,
+            ConjunctionSequential(..) => 19,
+            ConjunctionParallel(..) => 20,
+            Inheritance(..) => 21,
+            Similarity(..) => 22,
+            Implication(..) => 23,
+            Equivalence(..) => 24,
+            ImplicationPredictive(..) => 25,
+            ImplicationConcurrent(..) => 26,
+            ImplicationRetrospective(..) => 27,
+            EquivalencePredictive(..) => 28,
+            EquivalenceConcurrent(..) => 29,
+            EquivalenceRetrospective(..) => 30,
+        }
+    }
+}
+
 /// 实现/获取词项
 impl GetTerm<Term> for Term {
     fn get_term(&self) -> &Term {
@@ -910,7 +1137,8 @@ impl ExtractTerms for Term {
             | ImplicationConcurrent(term1, term2)
             | ImplicationRetrospective(term1, term2)
             | EquivalencePredictive(term1, term2)
-            | EquivalenceConcurrent(term1, term2) => vec![*term1, *term2].into_iter(),
+            | EquivalenceConcurrent(term1, term2)
+            | EquivalenceRetrospective(term1, term2) => vec![*term1, *term2].into_iter(),
 
             // 有序容器⇒返回拷贝后的容器
             Product(vec)
@@ -1032,7 +1260,7 @@ pub mod tests {
     /// * 复杂度：O(N²)
     #[test]
     fn valid_image() {
-        let x = Term::new_word("");
+        let x = Term::new_word("x");
         // 在一个基础的长度中测试
         const N: UIntPrecision = 10000;
         for len in 1..(N + 1) {
@@ -1045,7 +1273,7 @@ pub mod tests {
             assert_eq!(vec.len(), len);
             // 测试所有位置的占位符
             for i in 0..(len + 1) {
-                test_term_vec_for_image(i, &vec);
+                assert!(check_term_vec_for_image(i, &vec).is_ok());
             }
         }
     }
@@ -1054,14 +1282,44 @@ pub mod tests {
     #[should_panic]
     fn invalid_image_1() {
         // 均超过索引
-        new_term_vec_for_image(1, vec![]);
+        Term::new_image_extension(1, vec![]);
     }
 
     #[test]
     #[should_panic]
     fn invalid_image_2() {
         // 均超过索引
-        new_term_vec_for_image(2, vec![Term::new_word("")]);
+        Term::new_image_intension(2, vec![Term::new_word("x")]);
+    }
+
+    /// 测试/可失败的像构造函数
+    #[test]
+    fn try_new_image() {
+        let x = || Term::new_word("x");
+        asserts! {
+            // 边界：索引等于组分数⇒占位符在最后
+            Term::try_new_image_extension(2, vec![x(), x()]) => Ok(Term::ImageExtension(2, vec![x(), x()])),
+            Term::try_new_image_intension(0, vec![x()]) => Ok(Term::ImageIntension(0, vec![x()])),
+            // 越界
+            Term::try_new_image_extension(3, vec![x(), x()]) => Err(TermBuildError::ImagePlaceholderOutOfRange { index: 3, len: 2 }),
+            Term::try_new_image_intension(2, vec![x()]) => Err(TermBuildError::ImagePlaceholderOutOfRange { index: 2, len: 1 }),
+            // 空数组：仅有占位符
+            Term::try_new_image_extension(0, vec![]) => Ok(Term::ImageExtension(0, vec![])),
+            Term::try_new_image_intension(1, vec![]) => Err(TermBuildError::ImagePlaceholderOutOfRange { index: 1, len: 0 }),
+        }
+    }
+
+    /// 【通用】以ASCII格式解析词项
+    /// * 🎯供各模块的单元测试共用
+    /// * ⚠️解析失败、或解析出的不是词项⇒panic
+    pub fn term(input: &str) -> Term {
+        use crate::{
+            conversion::string::impl_enum::format_instances::FORMAT_ASCII, enum_narsese::Narsese,
+        };
+        match FORMAT_ASCII.parse::<Narsese>(input).unwrap() {
+            Narsese::Term(term) => term,
+            other => panic!("不是词项：{other:?}"),
+        }
     }
 
     /// 【通用】生成一个「词项测试集」
@@ -1173,6 +1431,43 @@ pub mod tests {
         }
     }
 
+    /// 测试/复杂度与深度
+    #[test]
+    fn test_complexity() {
+        // 测试集 | 原子词项7个，然后是复合词项、陈述
+        let expected_complexities = [
+            1, 1, 1, 1, 1, 1, 1, // 原子词项
+            2, 2, 2, 2, 2, 2, 2, // 集合、交、差、积
+            3, 3, // 像：包括占位符
+            2, 2, 1, 2, 2, // 合取、析取、否定、顺序/平行合取
+            2, 2, 2, 2, 2, 2, 2, 2, 2, // 陈述
+        ];
+        let testset = generate_term_testset();
+        assert_eq!(testset.len(), expected_complexities.len());
+        for (term, expected) in testset.iter().zip(expected_complexities) {
+            let expected_depth = match term.is_atom() {
+                true => 1,
+                false => 2,
+            };
+            asserts! {
+                term.complexity() => expected,
+                term.depth() => expected_depth,
+            }
+        }
+        // 嵌套
+        let nested = Term::new_implication(
+            Term::new_conjunction(vec![
+                Term::new_inheritance(Term::new_word("A"), Term::new_word("B")),
+                Term::new_negation(Term::new_word("C")),
+            ]),
+            Term::new_image_extension(1, vec![Term::new_word("R"), Term::new_word("D")]),
+        );
+        asserts! {
+            nested.complexity() => 6,
+            nested.depth() => 4,
+        }
+    }
+
     /// 有效性测试
     #[test]
     fn test_term() {
@@ -1189,6 +1484,123 @@ pub mod tests {
         }
     }
 
+    /// 全序测试
+    /// * 🎯自反、反对称、传递，且与判等一致
+    #[test]
+    fn test_ord() {
+        use std::collections::BTreeSet;
+        // 测试集 + 对称/无序变体
+        let (a, b) = (Term::new_word("A"), Term::new_word("B"));
+        let mut testset = generate_term_testset();
+        testset.extend([
+            Term::new_similarity(b.clone(), a.clone()),
+            Term::new_equivalence(b.clone(), a.clone()),
+            Term::new_equivalence_concurrent(b.clone(), a.clone()),
+            Term::new_inheritance(b.clone(), a.clone()),
+            Term::new_conjunction(vec![b.clone(), a.clone()]),
+            Term::new_set_extension(vec![b.clone()]),
+            Term::new_product(vec![b.clone(), a.clone()]),
+        ]);
+        for x in &testset {
+            // 自反
+            assert_eq!(x.cmp(x), Ordering::Equal);
+            for y in &testset {
+                // 反对称 & 与判等一致
+                asserts! {
+                    x.cmp(y) => y.cmp(x).reverse(),
+                    x.cmp(y) == Ordering::Equal => x == y,
+                    x.partial_cmp(y) => Some(x.cmp(y)),
+                }
+                // 传递
+                for z in &testset {
+                    if x <= y && y <= z {
+                        assert!(x <= z, "{x:?} <= {y:?} <= {z:?}");
+                    }
+                }
+            }
+        }
+        // 类别顺序：原子词项 < 复合词项 < 陈述
+        asserts! {
+            Term::new_word("z") < Term::new_product(vec![]),
+            Term::new_product(vec![]) < Term::new_inheritance(a.clone(), a.clone()),
+            Term::new_word("A") < Term::new_word("B"),
+            // 对称陈述
+            Term::new_similarity(a.clone(), b.clone()).cmp(&Term::new_similarity(b.clone(), a.clone())) => Ordering::Equal,
+            // 非对称陈述
+            Term::new_inheritance(a.clone(), b.clone()) < Term::new_inheritance(b.clone(), a.clone()),
+        }
+        // 可放入有序集合
+        let set = testset.iter().cloned().collect::<BTreeSet<_>>();
+        // 三个对称陈述、一个无序合取被合并
+        assert_eq!(set.len(), testset.len() - 4);
+        assert!(set.iter().zip(set.iter().skip(1)).all(|(x, y)| x < y));
+    }
+
+    /// 散列化与判等一致
+    #[test]
+    fn test_hash_consistent() {
+        use std::{collections::hash_map::DefaultHasher, hash::Hasher};
+        let hash = |term: &Term| {
+            let mut hasher = DefaultHasher::new();
+            term.hash(&mut hasher);
+            hasher.finish()
+        };
+        let (a, b) = (Term::new_word("A"), Term::new_word("B"));
+        // 对称陈述
+        let s1 = Term::new_similarity(a.clone(), b.clone());
+        let s2 = Term::new_similarity(b.clone(), a.clone());
+        // 不同插入顺序、不同容量的集合
+        let words = (0..32)
+            .map(|i| Term::new_word(format!("w{i}")))
+            .collect::<Vec<_>>();
+        let c1 = Term::new_conjunction(words.clone());
+        let c2 = Term::new_conjunction(words.iter().rev().cloned().collect::<Vec<_>>());
+        let e1 = Term::new_set_extension(words.iter().cloned());
+        let e2 = Term::new_set_extension(words.iter().rev().cloned());
+        // 嵌套：对称陈述中的无序集合
+        let n1 = Term::new_equivalence(c1.clone(), Term::new_similarity(e1.clone(), a.clone()));
+        let n2 = Term::new_equivalence(Term::new_similarity(a.clone(), e2.clone()), c2.clone());
+        asserts! {
+            s1 => s2,
+            hash(&s1) => hash(&s2),
+            c1 => c2,
+            hash(&c1) => hash(&c2),
+            e1 => e2,
+            hash(&e1) => hash(&e2),
+            n1 => n2,
+            hash(&n1) => hash(&n2),
+        }
+        // 可在散列集合中去重
+        let set = [s1, s2, c1, c2, e1, e2, n1, n2]
+            .into_iter()
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(set.len(), 4);
+    }
+
+    /// 修改错误：具体的错误变体
+    #[test]
+    fn test_edit_errors() {
+        let mut interval = Term::new_interval(1);
+        let mut statement = Term::new_inheritance(Term::new_word("A"), Term::new_word("B"));
+        let mut product = Term::new_product(vec![]);
+        asserts! {
+            interval.set_atom_name("x") => Err(TermEditError::InvalidInterval("x".into())),
+            interval.set_atom_name("2") => Ok(()),
+            interval => Term::new_interval(2),
+            statement.set_atom_name("C") => Err(TermEditError::NotAtom),
+            statement.push_components([Term::new_word("C")]) => Err(TermEditError::FixedCapacity),
+            product.push_components([Term::new_word("C")]) => Ok(()),
+            product => Term::new_product([Term::new_word("C")]),
+            TermEditError::NotAtom.to_string() => "尝试在非原子词项中设置词项名",
+        }
+        // 兼容原先的`io::Error`
+        let to_io = |error: TermEditError| std::io::Error::from(error).kind();
+        asserts! {
+            to_io(TermEditError::InvalidInterval("x".into())) => std::io::ErrorKind::InvalidInput,
+            to_io(TermEditError::NotAtom) => std::io::ErrorKind::InvalidData,
+        }
+    }
+
     /// 元素提取测试
     #[test]
     fn test_extract_terms() {
@@ -0,0 +1,471 @@
+//! 词项的「驻留池」
+//! * 🎯批量加载大量Narsese时，让结构相同的词项共享同一份内存
+//!   * 📄50万条语句中反复出现的`{SELF}`、`<(*, {SELF}) --> ^do>`等
+//! * 🚩驻留后的词项以[`ArcTerm`]（共享引用）形式存在，克隆只增加引用计数
+//! * 🚩递归驻留：组分亦以[`ArcTerm`]存储
+//!   * 📄`<{SELF} --> A>`与`<(*, {SELF}) --> ^do>`共享同一个`{SELF}`
+//!   * 📌每个结点只存「去除组分的外壳」与「共享的组分」：相同的子词项在池中只有一份
+//! * 📌需要[`Term`]时，经[`ArcTerm::to_term`]重建
+//! * 🚩Narsese值：语句、任务中的词项驻留，其余条目原样保留
+//!   * 📄[`InternedNarsese`]
+
+use super::structs::*;
+use crate::{
+    api::{
+        DroppedSentenceParts, GetCapacity, NarseseValue, SplitSentence, SplitTask, TermCapacity,
+    },
+    enum_narsese::{Budget, Narsese, Punctuation, Sentence, Stamp, Task, Truth},
+};
+use std::{
+    collections::{hash_map::DefaultHasher, HashSet},
+    fmt::{self, Display, Formatter},
+    hash::{Hash, Hasher},
+    mem::discriminant,
+    sync::Arc,
+};
+
+/// 驻留后词项的结点
+#[derive(Debug)]
+struct TermNode {
+    /// 外壳
+    /// * 📌原子词项⇒自身
+    /// * 📌无序容器⇒空容器
+    /// * 📌其它复合词项、陈述⇒组分替换为占位符
+    shell: Term,
+    /// 组分
+    /// * 📌无序容器、对称陈述：按地址排序，使同一驻留池中相等的词项组分顺序一致
+    components: Box<[ArcTerm]>,
+    /// 缓存的散列值
+    /// * 📌与[`Term`]的判等一致：无序容器、对称陈述的散列与组分顺序无关
+    hash: u64,
+}
+
+/// 共享的词项
+/// * 📌判等、散列与[`Term`]一致
+///   * 🚩判等时先比较指针，再比较外壳与组分的指针：同一驻留池中的词项通常无需重建
+/// * ⚠️不再可直接解引用为[`Term`]：组分亦为共享引用
+#[derive(Debug, Clone)]
+pub struct ArcTerm(Arc<TermNode>);
+
+/// 拆分词项：外壳与组分
+fn split_term(term: Term) -> (Term, Vec<Term>) {
+    let set = |terms: TermSetType| terms.into_iter().collect::<Vec<_>>();
+    match term {
+        // 无序容器 | 外壳为空容器
+        SetExtension(terms) => (SetExtension(TermSetType::new()), set(terms)),
+        SetIntension(terms) => (SetIntension(TermSetType::new()), set(terms)),
+        IntersectionExtension(terms) => (IntersectionExtension(TermSetType::new()), set(terms)),
+        IntersectionIntension(terms) => (IntersectionIntension(TermSetType::new()), set(terms)),
+        Conjunction(terms) => (Conjunction(TermSetType::new()), set(terms)),
+        Disjunction(terms) => (Disjunction(TermSetType::new()), set(terms)),
+        ConjunctionParallel(terms) => (ConjunctionParallel(TermSetType::new()), set(terms)),
+        // 其它 | 组分替换为占位符；原子词项无组分
+        term => {
+            let mut components = vec![];
+            let shell = term.map_components(&mut |term| {
+                components.push(term);
+                Placeholder
+            });
+            (shell, components)
+        }
+    }
+}
+
+/// 是否为「与组分顺序无关」的词项
+/// * 📌无序容器、对称陈述
+fn is_unordered(shell: &Term) -> bool {
+    matches!(
+        shell.get_capacity(),
+        TermCapacity::Set | TermCapacity::BinarySet
+    )
+}
+
+impl ArcTerm {
+    /// 构造函数
+    /// * ⚠️不经过驻留池：不会与其它词项共享
+    pub fn new(term: Term) -> Self {
+        let (shell, components) = split_term(term);
+        Self::from_parts(shell, components.into_iter().map(Self::new).collect())
+    }
+
+    /// 从「外壳」与「共享的组分」构造
+    /// * 🚩无序⇒组分按地址排序；计算散列值
+    fn from_parts(shell: Term, mut components: Vec<ArcTerm>) -> Self {
+        let mut hasher = DefaultHasher::new();
+        discriminant(&shell).hash(&mut hasher);
+        shell.hash(&mut hasher);
+        let mut hashes = components.iter().map(|term| term.0.hash).collect::<Vec<_>>();
+        if is_unordered(&shell) {
+            components.sort_by_key(|term| Arc::as_ptr(&term.0));
+            hashes.sort_unstable();
+        }
+        hashes.hash(&mut hasher);
+        Self(Arc::new(TermNode {
+            shell,
+            components: components.into_boxed_slice(),
+            hash: hasher.finish(),
+        }))
+    }
+
+    /// 是否与另一词项共享同一份内存
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// 获取组分
+    /// * 📌原子词项⇒空
+    /// * 📌像：不含占位符
+    /// * ⚠️无序容器、对称陈述：顺序不确定
+    pub fn components(&self) -> &[ArcTerm] {
+        &self.0.components
+    }
+
+    /// 重建词项
+    /// * 🚩无序容器⇒向空容器中添加组分；其它⇒依次替换外壳中的占位符
+    pub fn to_term(&self) -> Term {
+        let mut components = self.0.components.iter().map(ArcTerm::to_term);
+        let mut term = self.0.shell.clone();
+        match self.0.shell.get_capacity() {
+            TermCapacity::Set => {
+                // 外壳为空容器⇒必定成功
+                let _ = term.push_components(components);
+                term
+            }
+            _ => term.map_components(&mut |placeholder| components.next().unwrap_or(placeholder)),
+        }
+    }
+}
+
+/// 📌与[`Term`]的判等一致
+/// * 🚩指针相同⇒相等
+/// * 🚩外壳相同，且组分两两指针相同⇒相等（同一驻留池中的常见情形）
+/// * 🚩否则重建后比较
+impl PartialEq for ArcTerm {
+    fn eq(&self, other: &Self) -> bool {
+        if self.ptr_eq(other) {
+            return true;
+        }
+        if self.0.hash != other.0.hash {
+            return false;
+        }
+        let same_components = self.components().len() == other.components().len()
+            && self
+                .components()
+                .iter()
+                .zip(other.components())
+                .all(|(a, b)| a.ptr_eq(b));
+        (same_components && self.0.shell == other.0.shell) || self.to_term() == other.to_term()
+    }
+}
+
+impl Eq for ArcTerm {}
+
+impl PartialEq<Term> for ArcTerm {
+    fn eq(&self, other: &Term) -> bool {
+        self.to_term() == *other
+    }
+}
+
+impl Hash for ArcTerm {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash.hash(state)
+    }
+}
+
+impl From<Term> for ArcTerm {
+    fn from(term: Term) -> Self {
+        Self::new(term)
+    }
+}
+
+impl From<&ArcTerm> for Term {
+    fn from(term: &ArcTerm) -> Self {
+        term.to_term()
+    }
+}
+
+impl Display for ArcTerm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_term())
+    }
+}
+
+/// 词项已驻留的语句
+/// * 📌标点、时间戳、真值原样保留
+#[derive(Debug, Clone, PartialEq)]
+pub struct InternedSentence {
+    /// 共享的词项
+    pub term: ArcTerm,
+    /// 词项以外的部分
+    pub parts: DroppedSentenceParts<Punctuation, Stamp, Truth>,
+}
+
+impl InternedSentence {
+    /// 重建语句
+    pub fn to_sentence(&self) -> Sentence {
+        Sentence::join_sentence(self.term.to_term(), self.parts.clone())
+    }
+}
+
+/// 词项已驻留的任务
+/// * 📌预算值原样保留
+#[derive(Debug, Clone, PartialEq)]
+pub struct InternedTask {
+    /// 词项已驻留的语句
+    pub sentence: InternedSentence,
+    /// 预算值
+    pub budget: Budget,
+}
+
+impl InternedTask {
+    /// 重建任务
+    pub fn to_task(&self) -> Task {
+        Task::join_task(self.sentence.to_sentence(), self.budget.clone())
+    }
+}
+
+/// 词项已驻留的Narsese值
+/// * 📄[`TermPool::intern_narsese`]
+pub type InternedNarsese = NarseseValue<ArcTerm, InternedSentence, InternedTask>;
+
+impl InternedNarsese {
+    /// 重建Narsese值
+    pub fn to_narsese(&self) -> Narsese {
+        match self {
+            NarseseValue::Term(term) => NarseseValue::Term(term.to_term()),
+            NarseseValue::Sentence(sentence) => NarseseValue::Sentence(sentence.to_sentence()),
+            NarseseValue::Task(task) => NarseseValue::Task(task.to_task()),
+        }
+    }
+}
+
+/// 词项驻留池
+/// * 🚩结构相同的词项只保留一份，之后均返回其共享引用
+///   * 📌递归驻留：所有子词项亦在池中
+/// * 📌判等与[`Term`]一致：无序容器、对称陈述按其「值」去重
+#[derive(Debug, Clone, Default)]
+pub struct TermPool {
+    terms: HashSet<ArcTerm>,
+}
+
+impl TermPool {
+    /// 构造函数
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 驻留词项
+    /// * 🚩自底向上：先驻留各组分，再驻留自身
+    /// * 🚩已有相同词项⇒返回已有的共享引用，并丢弃传入的词项
+    pub fn intern(&mut self, term: Term) -> ArcTerm {
+        let (shell, components) = split_term(term);
+        let components = components
+            .into_iter()
+            .map(|term| self.intern(term))
+            .collect();
+        let candidate = ArcTerm::from_parts(shell, components);
+        if let Some(interned) = self.terms.get(&candidate) {
+            return interned.clone();
+        }
+        self.terms.insert(candidate.clone());
+        candidate
+    }
+
+    /// 驻留Narsese值中的词项
+    /// * 🚩词项⇒自身；语句、任务⇒其中的词项
+    /// * 📌标点、时间戳、真值、预算值原样保留
+    pub fn intern_narsese(&mut self, narsese: Narsese) -> InternedNarsese {
+        let mut intern_sentence = |sentence: Sentence| {
+            let (term, parts) = sentence.to_term_lossy();
+            InternedSentence {
+                term: self.intern(term),
+                parts,
+            }
+        };
+        match narsese {
+            NarseseValue::Term(term) => NarseseValue::Term(self.intern(term)),
+            NarseseValue::Sentence(sentence) => NarseseValue::Sentence(intern_sentence(sentence)),
+            NarseseValue::Task(task) => {
+                let (sentence, budget) = task.split_task();
+                NarseseValue::Task(InternedTask {
+                    sentence: intern_sentence(sentence),
+                    budget,
+                })
+            }
+        }
+    }
+
+    /// 查找已驻留的词项
+    /// * 🚩自底向上：任一组分未驻留⇒[`None`]
+    pub fn get(&self, term: &Term) -> Option<&ArcTerm> {
+        self.get_owned(term.clone())
+    }
+
+    /// 查找已驻留的词项（内部实现）
+    fn get_owned(&self, term: Term) -> Option<&ArcTerm> {
+        let (shell, components) = split_term(term);
+        let components = components
+            .into_iter()
+            .map(|term| self.get_owned(term).cloned())
+            .collect::<Option<Vec<_>>>()?;
+        self.terms.get(&ArcTerm::from_parts(shell, components))
+    }
+
+    /// 是否已驻留某词项
+    pub fn contains(&self, term: &Term) -> bool {
+        self.get(term).is_some()
+    }
+
+    /// 已驻留的（互不相同的）词项数
+    /// * 📌含所有子词项
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// 清空驻留池
+    /// * 📌已返回的[`ArcTerm`]仍然有效
+    pub fn clear(&mut self) {
+        self.terms.clear()
+    }
+
+    /// 遍历已驻留的词项
+    /// * ⚠️顺序不确定
+    pub fn iter(&self) -> impl Iterator<Item = &ArcTerm> {
+        self.terms.iter()
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversion::string::impl_enum::format_instances::FORMAT_ASCII;
+    use nar_dev_utils::{asserts, show};
+
+    /// 驻留：相同⇒共享，不同⇒各自独立
+    #[test]
+    fn test_intern() {
+        let format = &FORMAT_ASCII;
+        let parse = |input| format.parse::<Narsese>(input).unwrap().try_into_term().unwrap();
+        let mut pool = TermPool::new();
+        let a1 = pool.intern(parse("{SELF}"));
+        let a2 = pool.intern(parse("{SELF}"));
+        let b = pool.intern(parse("<A --> B>"));
+        // 对称陈述、无序容器：按「值」去重
+        let c1 = pool.intern(parse("<A <-> B>"));
+        let c2 = pool.intern(parse("<B <-> A>"));
+        let d1 = pool.intern(parse("(&&, A, B)"));
+        let d2 = pool.intern(parse("(&&, B, A)"));
+        asserts! {
+            a1.ptr_eq(&a2),
+            !a1.ptr_eq(&b),
+            c1.ptr_eq(&c2),
+            d1.ptr_eq(&d2),
+            // `SELF`、`{SELF}`、`A`、`B`、三个复合词项
+            pool.len() => 7,
+            pool.contains(&parse("{SELF}")),
+            pool.contains(&parse("SELF")),
+            !pool.contains(&parse("{A}")),
+            // 判等、散列与内部词项一致
+            a1 == parse("{SELF}"),
+            a1 == ArcTerm::new(parse("{SELF}")),
+            a1.to_term() => parse("{SELF}"),
+            a1.to_string() => parse("{SELF}").to_string(),
+            ArcTerm::new(parse("(&&, A, B)")) == ArcTerm::new(parse("(&&, B, A)")),
+            ArcTerm::new(parse("<A <-> B>")) == ArcTerm::new(parse("<B <-> A>")),
+            ArcTerm::new(parse("<A --> B>")) != ArcTerm::new(parse("<B --> A>")),
+        }
+        // 清空后，已返回的引用仍然有效
+        pool.clear();
+        asserts! {
+            pool.is_empty(),
+            a1.to_term() => parse("{SELF}"),
+        }
+    }
+
+    /// 驻留：子词项亦共享
+    #[test]
+    fn test_intern_recursive() {
+        let format = &FORMAT_ASCII;
+        let parse = |input| format.parse::<Narsese>(input).unwrap().try_into_term().unwrap();
+        let mut pool = TermPool::new();
+        let statement = pool.intern(parse("<{SELF} --> [good]>"));
+        let operation = pool.intern(parse("<(*, {SELF}, [good]) --> ^do>"));
+        let image = pool.intern(parse(r"(/, ^do, {SELF}, _)"));
+        let self_ = pool.get(&parse("{SELF}")).unwrap();
+        let product = &operation.components()[0];
+        asserts! {
+            statement.components()[0].ptr_eq(self_),
+            product.components().iter().any(|term| term.ptr_eq(self_)),
+            image.components().iter().any(|term| term.ptr_eq(self_)),
+            operation.components()[1].ptr_eq(pool.get(&parse("^do")).unwrap()),
+            // 往返
+            statement.to_term() => parse("<{SELF} --> [good]>"),
+            operation.to_term() => parse("<(*, {SELF}, [good]) --> ^do>"),
+            image.to_term() => parse(r"(/, ^do, {SELF}, _)"),
+        }
+    }
+
+    /// 驻留批量解析的结果
+    #[test]
+    fn test_intern_batch() {
+        let format = &FORMAT_ASCII;
+        let inputs = [
+            "<(&&, <<$x-->A>==><$x-->B>>, <<$y-->C>==><$y-->D>>) ==> E>.",
+            "<{tim} --> (/,livingIn,_,{graz})>. %0%",
+            "<<(*,$1,sunglasses) --> own> ==> <$1 --> [aggressive]>>.",
+            "<(*,{tom},sunglasses) --> own>.",
+            "<<$1 --> [aggressive]> ==> <$1 --> murder>>.",
+            "<<$1 --> (/,livingIn,_,{graz})> ==> <$1 --> murder>>.",
+            "<{?who} --> murder>?",
+            "$0.5;0.5;0.5$ <{tim} --> (/,livingIn,_,{graz})>.",
+            "<{tim} --> (/,livingIn,_,{graz})>. %0%",
+            "<<(*,$1,sunglasses) --> own> ==> <$1 --> [aggressive]>>.",
+            "<(*,{tom},(&,[black],glasses)) --> own>.",
+            "<<$1 --> [aggressive]> ==> <$1 --> murder>>.",
+            "<<$1 --> (/,livingIn,_,{graz})> ==> <$1 --> murder>>.",
+            "<sunglasses --> (&,[black],glasses)>.",
+            "<{?who} --> murder>?",
+        ];
+        let mut pool = TermPool::new();
+        let interned = inputs
+            .iter()
+            .map(|input| format.parse_interned(input, &mut pool).unwrap())
+            .collect::<Vec<_>>();
+        // 不驻留的解析结果
+        let narseses = inputs
+            .iter()
+            .map(|input| format.parse::<Narsese>(input).unwrap())
+            .collect::<Vec<_>>();
+        let sub_terms = narseses
+            .iter()
+            .flat_map(|narsese| narsese.term().iter_dfs())
+            // 像占位符不单独驻留
+            .filter(|term| !matches!(term, Term::Placeholder))
+            .collect::<std::collections::HashSet<_>>();
+        let term_of = |narsese: &InternedNarsese| match narsese {
+            NarseseValue::Term(term) => term.clone(),
+            NarseseValue::Sentence(sentence) => sentence.term.clone(),
+            NarseseValue::Task(task) => task.sentence.term.clone(),
+        };
+        show!(pool.len(), sub_terms.len());
+        asserts! {
+            // 驻留与不驻留的结果相等：标点、真值、预算值均保留
+            interned.iter().map(InternedNarsese::to_narsese).collect::<Vec<_>>() => narseses,
+            // 重复的语句共享同一词项
+            term_of(&interned[1]).ptr_eq(&term_of(&interned[7])),
+            term_of(&interned[1]).ptr_eq(&term_of(&interned[8])),
+            // 不同语句中的相同子词项亦共享
+            term_of(&interned[5]).components()[0].components()[1]
+                .ptr_eq(&term_of(&interned[1]).components()[1]),
+            // 池中恰为所有互不相同的子词项
+            pool.len() => sub_terms.len(),
+            sub_terms.iter().all(|term| pool.contains(term)),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! 词项中的「间隔」处理
+//! * 🎯时序推理中合并连续的间隔
+//!   * 📄`(&/, A, +3, +5, B)` ⇒ `(&/, A, +8, B)`
+//! * 🚩仅处理「顺序合取」中相邻的间隔；其它复合词项原样保留
+//! * 📌数值相加时饱和，不会溢出
+
+use super::structs::*;
+use crate::api::UIntPrecision;
+
+/// 实现/间隔合并
+impl Term {
+    /// 合并顺序合取中相邻的间隔
+    /// * 🚩递归处理所有子词项（自底向上）
+    /// * 📌全为间隔的顺序合取⇒合并为仅含一个间隔的顺序合取
+    /// * 📌返回新词项，不修改自身
+    pub fn merge_adjacent_intervals(&self) -> Term {
+        self.clone().map_terms(&mut |term| match term {
+            ConjunctionSequential(terms) => ConjunctionSequential(merge_intervals(terms)),
+            other => other,
+        })
+    }
+}
+
+/// 合并序列中相邻的间隔
+fn merge_intervals(terms: TermVecType) -> TermVecType {
+    let mut merged = TermVecType::with_capacity(terms.len());
+    for term in terms {
+        match (merged.last_mut(), term) {
+            (Some(Interval(last)), Interval(interval)) => {
+                *last = UIntPrecision::saturating_add(*last, interval)
+            }
+            (_, term) => merged.push(term),
+        }
+    }
+    merged
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enum_narsese::tests::term;
+    use nar_dev_utils::asserts;
+
+    #[test]
+    fn test_merge_adjacent_intervals() {
+        let merge = |input| term(input).merge_adjacent_intervals();
+        asserts! {
+            // 相邻
+            merge("(&/, A, +3, +5, B)") => term("(&/, A, +8, B)"),
+            merge("(&/, +1, +2, A, +3, +4)") => term("(&/, +3, A, +7)"),
+            // 不相邻
+            merge("(&/, +3, A, +5, B)") => term("(&/, +3, A, +5, B)"),
+            // 全为间隔
+            merge("(&/, +1, +2, +3)") => term("(&/, +6)"),
+            // 嵌套
+            merge("<(&/, +1, +1, <A --> B>) =/> (&/, C, +2, +2)>") => term("<(&/, +2, <A --> B>) =/> (&/, C, +4)>"),
+            // 非顺序合取⇒不变
+            merge("(*, A, +3, +5)") => term("(*, A, +3, +5)"),
+            merge("(&|, +3, +5)") => term("(&|, +3, +5)"),
+            merge("+3") => term("+3"),
+            // 饱和相加
+            Term::new_conjunction_sequential([Term::new_interval(UIntPrecision::MAX), Term::new_interval(1)]).merge_adjacent_intervals()
+                => Term::new_conjunction_sequential([Term::new_interval(UIntPrecision::MAX)]),
+        }
+    }
+}
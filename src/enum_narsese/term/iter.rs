@@ -0,0 +1,158 @@
+//! 词项的「子词项迭代器」
+//! * 🎯对所有子词项（含自身）进行惰性迭代，免去每次手写递归
+//!   * 📄统计任务中的陈述个数、查找首个操作符……
+//! * 🚩使用显式的栈/队列，而非预先收集到数组中
+//!   * 📌可借助[`Iterator::find`]等方法提前终止
+//! * 📌像：会迭代到占位符（基于[`ImageIterator`]）
+//! * ⚠️无序容器（集合、交、合取等）：组分顺序即底层容器的迭代顺序，不确定
+
+use super::structs::*;
+use crate::api::GetCategory;
+use std::collections::VecDeque;
+
+/// 工具函数：获取词项的直接组分（含像占位符）
+/// * 📌原子词项⇒没有组分
+///   * ⚠️[`Term::get_components`]对原子词项会返回自身，需单独处理
+#[inline]
+fn children(term: &Term) -> Vec<&Term> {
+    match term.is_atom() {
+        true => vec![],
+        false => term.get_components_including_placeholder(),
+    }
+}
+
+/// 子词项迭代器/深度优先
+/// * 🚩先序：先自身、再按顺序迭代各组分
+pub struct TermIterDfs<'a> {
+    stack: Vec<&'a Term>,
+}
+
+impl<'a> Iterator for TermIterDfs<'a> {
+    type Item = &'a Term;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let term = self.stack.pop()?;
+        // 逆序入栈，以便按原顺序出栈
+        self.stack.extend(children(term).into_iter().rev());
+        Some(term)
+    }
+}
+
+/// 子词项迭代器/广度优先
+/// * 🚩逐层：先自身、再所有直接组分、再所有组分的组分……
+pub struct TermIterBfs<'a> {
+    queue: VecDeque<&'a Term>,
+}
+
+impl<'a> Iterator for TermIterBfs<'a> {
+    type Item = &'a Term;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let term = self.queue.pop_front()?;
+        self.queue.extend(children(term));
+        Some(term)
+    }
+}
+
+/// 实现/子词项迭代
+impl Term {
+    /// 深度优先迭代所有子词项
+    /// * 📌包括自身
+    /// * 📄参见[`TermIterDfs`]
+    pub fn iter_dfs(&self) -> TermIterDfs<'_> {
+        TermIterDfs { stack: vec![self] }
+    }
+
+    /// 广度优先迭代所有子词项
+    /// * 📌包括自身
+    /// * 📄参见[`TermIterBfs`]
+    pub fn iter_bfs(&self) -> TermIterBfs<'_> {
+        TermIterBfs {
+            queue: VecDeque::from([self]),
+        }
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::GetComplexity,
+        enum_narsese::tests::{generate_term_testset, term},
+    };
+    use nar_dev_utils::asserts;
+
+    /// 工具函数：解析一串词项
+    fn terms(inputs: &[&str]) -> Vec<Term> {
+        inputs.iter().map(|input| term(input)).collect()
+    }
+
+    #[test]
+    fn test_iter_order() {
+        // 只用有序容器，以便比对顺序
+        let statement = term(r"<(*, A, (/, R, _, B)) --> (--, C)>");
+        let dfs = statement.iter_dfs().cloned().collect::<Vec<_>>();
+        let bfs = statement.iter_bfs().cloned().collect::<Vec<_>>();
+        asserts! {
+            dfs => terms(&[
+                r"<(*, A, (/, R, _, B)) --> (--, C)>",
+                r"(*, A, (/, R, _, B))",
+                "A",
+                r"(/, R, _, B)",
+                "R",
+                "_",
+                "B",
+                "(--, C)",
+                "C",
+            ]),
+            bfs => terms(&[
+                r"<(*, A, (/, R, _, B)) --> (--, C)>",
+                r"(*, A, (/, R, _, B))",
+                "(--, C)",
+                "A",
+                r"(/, R, _, B)",
+                "C",
+                "R",
+                "_",
+                "B",
+            ]),
+        }
+    }
+
+    #[test]
+    fn test_iter_atom() {
+        let atom = term("A");
+        asserts! {
+            atom.iter_dfs().collect::<Vec<_>>() => vec![&atom],
+            atom.iter_bfs().collect::<Vec<_>>() => vec![&atom],
+        }
+    }
+
+    /// 提前终止：找到即停
+    #[test]
+    fn test_iter_find() {
+        let statement = term(r"<(&&, <A --> B>, <C --> D>) ==> <(*, SELF) --> ^go>>");
+        asserts! {
+            statement.iter_dfs().find(|t| matches!(t, Operator(..))) => Some(&term("^go")),
+            statement.iter_bfs().filter(|t| t.is_statement()).count() => 4,
+        }
+    }
+
+    /// 与「复杂度」一致：迭代出的原子词项个数即复杂度
+    #[test]
+    fn test_iter_complexity() {
+        for term in generate_term_testset() {
+            let count_atoms = |iter: &mut dyn Iterator<Item = &Term>| iter.filter(|t| t.is_atom()).count();
+            // 空复合词项：没有原子，但复杂度计为1
+            let expected = term.complexity();
+            let dfs = count_atoms(&mut term.iter_dfs()).max(1);
+            let bfs = count_atoms(&mut term.iter_bfs()).max(1);
+            asserts! {
+                dfs => expected,
+                bfs => expected,
+                term.iter_dfs().count() => term.iter_bfs().count(),
+            }
+        }
+    }
+}
@@ -60,4 +60,45 @@ nar_dev_utils::mod_and_pub_use! {
     structs
     // 实现
     impls
+    // 子词项迭代器
+    iter
+    // 修改错误
+    error
+    // 驻留池
+    intern
+    // 原子词项名称检验
+    atom_name
+    // 增量构造器
+    builder
 }
+
+// 遍历与变换 | 直接对「词项」实现方法，无需导出
+mod visit;
+
+// 路径寻址 | 直接对「词项」实现方法，无需导出
+mod path;
+
+// 原子词项提取 | 直接对「词项」实现方法，无需导出
+mod atoms;
+
+// 变量换名 | 直接对「词项」实现方法，无需导出
+mod alpha;
+
+// 间隔合并 | 直接对「词项」实现方法，无需导出
+mod interval;
+
+// 规范形式 | 直接对「词项」实现方法，无需导出
+mod canonical;
+
+// 像与乘积的转换 | 直接对「词项」实现方法，无需导出
+mod image;
+
+// 词项集的值层面运算
+// * 🚩作为单独的子模块导出，而**不导出其内元素**
+//   * 其内如`evaluate_all`等名称较为宽泛
+pub mod set_ops;
+
+// 模式匹配（单向合一）
+// * 🚩作为单独的子模块导出，而**不导出其内元素**
+//   * 其内如`apply`等名称较为宽泛
+pub mod unification;
@@ -0,0 +1,263 @@
+//! 词项的「路径寻址」
+//! * 🎯为词项树中的子词项提供稳定的「地址」
+//!   * 📄词项改写界面：选中、替换某个子词项
+//! * 📌路径中的每个索引，对应当前词项「组分」中的位置
+//!   * 📌像：占位符亦占一个位置
+//!   * 📌无序容器（集合、交、合取等）：按[全序](Ord)排序后的位置
+//!     * 🎯不依赖[`HashSet`](std::collections::HashSet)的遍历顺序：相等的词项总有相同的路径
+//!   * 📌原子词项：没有组分
+
+use super::structs::*;
+use crate::api::{GetCategory, PathError, TermPath, UIntPrecision};
+
+/// 实现/路径寻址
+impl Term {
+    /// 获取「按路径寻址」所用的组分
+    /// * 📌原子词项⇒空
+    /// * 📌像⇒包括占位符
+    /// * 📌无序容器⇒按全序排序
+    pub(crate) fn path_components(&self) -> Vec<&Term> {
+        match self {
+            _ if self.is_atom() => vec![],
+            SetExtension(set)
+            | SetIntension(set)
+            | IntersectionExtension(set)
+            | IntersectionIntension(set)
+            | Conjunction(set)
+            | Disjunction(set)
+            | ConjunctionParallel(set) => {
+                let mut terms = set.iter().collect::<Vec<_>>();
+                terms.sort();
+                terms
+            }
+            _ => self.get_components_including_placeholder(),
+        }
+    }
+
+    /// 按路径获取子词项
+    /// * 📌空路径⇒自身
+    /// * ⚠️路径无效⇒[`None`]
+    pub fn get_at(&self, path: &[UIntPrecision]) -> Option<&Term> {
+        match path.split_first() {
+            None => Some(self),
+            Some((&index, rest)) => self.path_components().get(index)?.get_at(rest),
+        }
+    }
+
+    /// 按路径替换子词项
+    /// * 📌空路径⇒整个替换为`new`
+    /// * 📌返回新词项，不修改自身
+    /// * ⚠️无序容器中的替换：先移除所寻址的组分，再插入新组分
+    ///   * 📌新组分可能与已有组分重复：此时会被合并
+    ///   * ⚠️替换后，该容器中（排序后的）组分位置可能改变：此前得到的路径可能失效
+    /// * ⚠️像：占位符只能被替换为占位符；其它组分不能被替换为占位符
+    /// * ⚠️与[构造器](super::TermBuilder)一致：占位符不能出现在像与乘积之外
+    pub fn replace_at(&self, path: &[UIntPrecision], new: Term) -> Result<Term, PathError> {
+        self.replace_at_depth(path, new, 0)
+    }
+
+    /// 按路径替换子词项/递归
+    /// * 📌`depth`：已走过的路径长度，用于报错
+    fn replace_at_depth(
+        &self,
+        path: &[UIntPrecision],
+        new: Term,
+        depth: UIntPrecision,
+    ) -> Result<Term, PathError> {
+        let Some((&index, rest)) = path.split_first() else {
+            return Ok(new);
+        };
+        let components = self.path_components();
+        if components.is_empty() {
+            return Err(PathError::AtomReached { depth });
+        }
+        let component = *components.get(index).ok_or(PathError::OutOfRange {
+            depth,
+            index,
+            len: components.len(),
+        })?;
+        let replaced = component.replace_at_depth(rest, new, depth + 1)?;
+        // 按组分位置重建容器
+        let rebuild = |index: UIntPrecision, replaced: Term| {
+            let mut replaced = Some(replaced);
+            let mut i = 0;
+            self.clone().map_components(&mut |term| {
+                let term = match i == index {
+                    true => replaced.take().unwrap_or(term),
+                    false => term,
+                };
+                i += 1;
+                term
+            })
+        };
+        match self {
+            // 像 | 占位符不作为组分存储
+            ImageExtension(placeholder_index, _) | ImageIntension(placeholder_index, _) => {
+                let is_placeholder = matches!(replaced, Placeholder);
+                match (index == *placeholder_index, is_placeholder) {
+                    // 占位符⇒占位符：不变
+                    (true, true) => Ok(self.clone()),
+                    // 占位符被移除，或新增占位符
+                    (true, false) | (false, true) => Err(PathError::Placeholder { depth }),
+                    // 跳过占位符计算存储位置
+                    (false, false) => {
+                        let index = match index < *placeholder_index {
+                            true => index,
+                            false => index - 1,
+                        };
+                        Ok(rebuild(index, replaced))
+                    }
+                }
+            }
+            // 乘积 | 占位符是普通组分
+            Product(..) => Ok(rebuild(index, replaced)),
+            // 其它复合词项、陈述 | 不允许占位符
+            _ if matches!(replaced, Placeholder) => Err(PathError::Placeholder { depth }),
+            // 无序容器 | 移除后插入
+            SetExtension(set)
+            | SetIntension(set)
+            | IntersectionExtension(set)
+            | IntersectionIntension(set)
+            | Conjunction(set)
+            | Disjunction(set)
+            | ConjunctionParallel(set) => {
+                let mut set = set.clone();
+                set.remove(component);
+                set.insert(replaced);
+                Ok(match self {
+                    SetExtension(..) => SetExtension(set),
+                    SetIntension(..) => SetIntension(set),
+                    IntersectionExtension(..) => IntersectionExtension(set),
+                    IntersectionIntension(..) => IntersectionIntension(set),
+                    Conjunction(..) => Conjunction(set),
+                    Disjunction(..) => Disjunction(set),
+                    _ => ConjunctionParallel(set),
+                })
+            }
+            // 有序容器、陈述
+            _ => Ok(rebuild(index, replaced)),
+        }
+    }
+
+    /// 查找子词项的所有路径
+    /// * 🚩先序遍历：先自身，再按组分顺序
+    /// * 📌包括自身（空路径）
+    /// * 📌像：占位符亦可被查找
+    pub fn paths_of(&self, target: &Term) -> Vec<TermPath> {
+        let mut paths = vec![];
+        self.collect_paths(target, &mut vec![], &mut paths);
+        paths
+    }
+
+    /// 查找子词项的所有路径/递归
+    fn collect_paths(&self, target: &Term, path: &mut TermPath, paths: &mut Vec<TermPath>) {
+        if self == target {
+            paths.push(path.clone());
+        }
+        for (index, component) in self.path_components().into_iter().enumerate() {
+            path.push(index);
+            component.collect_paths(target, path, paths);
+            path.pop();
+        }
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        conversion::string::impl_enum::format_instances::FORMAT_ASCII, enum_narsese::tests::term,
+    };
+    use nar_dev_utils::asserts;
+
+    /// 三层嵌套的陈述：寻址、替换、格式化往返
+    #[test]
+    fn test_nested_statement() {
+        let statement = term("<<A --> (*, B, C)> ==> D>");
+        let path = vec![0, 1, 1];
+        asserts! {
+            statement.get_at(&[]) => Some(&statement),
+            statement.get_at(&[0]) => Some(&term("<A --> (*, B, C)>")),
+            statement.get_at(&[0, 1]) => Some(&term("(*, B, C)")),
+            statement.get_at(&path) => Some(&term("C")),
+            statement.get_at(&[0, 1, 2]) => None,
+            statement.get_at(&[0, 1, 1, 0]) => None,
+            statement.paths_of(&term("C")) => vec![path.clone()],
+        }
+        // 替换⇒格式化⇒解析：往返一致
+        let replaced = statement.replace_at(&path, term("{E, F}")).unwrap();
+        let formatted = FORMAT_ASCII.format_term(&replaced);
+        asserts! {
+            replaced.get_at(&path) => Some(&term("{E, F}")),
+            term(&formatted) => replaced,
+            replaced => term("<<A --> (*, B, {E, F})> ==> D>"),
+            // 自身不变
+            statement.get_at(&path) => Some(&term("C")),
+        }
+        // 路径错误
+        asserts! {
+            statement.replace_at(&[0, 1, 2], term("E"))
+                => Err(PathError::OutOfRange { depth: 2, index: 2, len: 2 }),
+            statement.replace_at(&[1, 0], term("E")) => Err(PathError::AtomReached { depth: 1 }),
+            statement.replace_at(&[], term("E")) => Ok(term("E")),
+        }
+    }
+
+    /// 像：占位符亦占一个位置
+    #[test]
+    fn test_image() {
+        let image = term(r"(/, R, _, A)");
+        asserts! {
+            image.get_at(&[1]) => Some(&Placeholder),
+            image.get_at(&[2]) => Some(&term("A")),
+            image.paths_of(&Placeholder) => vec![vec![1]],
+            image.replace_at(&[2], term("B")) => Ok(term(r"(/, R, _, B)")),
+            image.replace_at(&[0], term("S")) => Ok(term(r"(/, S, _, A)")),
+            image.replace_at(&[1], Placeholder) => Ok(image.clone()),
+            image.replace_at(&[1], term("B")) => Err(PathError::Placeholder { depth: 0 }),
+            image.replace_at(&[2], Placeholder) => Err(PathError::Placeholder { depth: 0 }),
+        }
+    }
+
+    /// 占位符只能出现在像与乘积中
+    #[test]
+    fn test_placeholder_outside_image() {
+        let statement = term("<(*, A, B) --> {C, D}>");
+        asserts! {
+            // 乘积：占位符是普通组分
+            statement.replace_at(&[0, 1], Placeholder) => Ok(term("<(*, A, _) --> {C, D}>")),
+            // 陈述、集合、有序复合词项
+            statement.replace_at(&[0], Placeholder) => Err(PathError::Placeholder { depth: 0 }),
+            statement.replace_at(&[1, 0], Placeholder) => Err(PathError::Placeholder { depth: 1 }),
+            term("(--, A)").replace_at(&[0], Placeholder) => Err(PathError::Placeholder { depth: 0 }),
+            // 空路径：整个替换
+            statement.replace_at(&[], Placeholder) => Ok(Placeholder),
+        }
+    }
+
+    /// 无序容器：按全序排序寻址，替换即「移除后插入」
+    #[test]
+    fn test_unordered() {
+        let conjunction = term("(&&, <$x --> A>, <(*, B, C) --> D>)");
+        // 路径与集合的遍历顺序无关
+        let paths = conjunction.paths_of(&term("C"));
+        assert_eq!(paths.len(), 1);
+        let path = &paths[0];
+        asserts! {
+            conjunction.get_at(path) => Some(&term("C")),
+            term("(&&, <(*, B, C) --> D>, <$x --> A>)").get_at(path) => Some(&term("C")),
+        }
+        let replaced = conjunction.replace_at(path, term("E")).unwrap();
+        asserts! {
+            replaced => term("(&&, <$x --> A>, <(*, B, E) --> D>)"),
+            term(&FORMAT_ASCII.format_term(&replaced)) => replaced,
+        }
+        // 替换后与已有组分重复⇒合并
+        let set = term("{A, B}");
+        let path_b = &set.paths_of(&term("B"))[0];
+        asserts! {
+            set.replace_at(path_b, term("A")) => Ok(term("{A}")),
+        }
+    }
+}
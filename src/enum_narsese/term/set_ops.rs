@@ -0,0 +1,293 @@
+//! 词项集的「值层面」运算
+//! * 🎯在数据层面计算NAL-3中的集合运算
+//!   * 📌得到的是「求值后的集合」，而非符号化的「外延交/内涵交/差」复合词项
+//!   * 📄`{A, B}`与`{B, C}`的交集⇒`{B}`，而非`(&, {A, B}, {B, C})`
+//! * 📝NAL-3中「词项集」与「交/差」的对应关系
+//!   * 外延交`(&, {..}, {..})` ⇔ 外延集的交集
+//!   * 内涵交`(|, {..}, {..})` ⇔ 外延集的并集
+//!   * 外延交`(&, [..], [..])` ⇔ 内涵集的并集
+//!   * 内涵交`(|, [..], [..])` ⇔ 内涵集的交集
+//!   * 外延差`(-, {..}, {..})` ⇔ 外延集的差集
+//!   * 内涵差`(~, [..], [..])` ⇔ 内涵集的差集
+//! * ⚠️运算结果为空集时不求值：Narsese中没有「空词项集」
+//!   * 📄`{}`、`[]`无法被解析，求值结果须能「格式化→解析」往返
+
+use super::structs::*;
+
+/// 集合的种类
+/// * 🎯统一处理「外延集」「内涵集」
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SetKind {
+    /// 外延集
+    Extension,
+    /// 内涵集
+    Intension,
+}
+
+/// 工具函数/拆解词项集
+/// * 🚩返回「集合种类」与「元素集」的引用
+/// * ⚠️非词项集⇒[`None`]
+fn as_set(term: &Term) -> Option<(SetKind, &TermSetType)> {
+    match term {
+        SetExtension(set) => Some((SetKind::Extension, set)),
+        SetIntension(set) => Some((SetKind::Intension, set)),
+        _ => None,
+    }
+}
+
+/// 工具函数/从「集合种类」与元素构造词项集
+fn new_set(kind: SetKind, set: TermSetType) -> Term {
+    match kind {
+        SetKind::Extension => SetExtension(set),
+        SetKind::Intension => SetIntension(set),
+    }
+}
+
+/// 工具函数/对两个同种词项集进行集合运算
+/// * ⚠️任一不是词项集，或两者种类不同⇒[`None`]
+/// * ⚠️结果为空集⇒[`None`]
+fn evaluate_with(
+    left: &Term,
+    right: &Term,
+    op: impl FnOnce(&TermSetType, &TermSetType) -> TermSetType,
+) -> Option<Term> {
+    let (kind_l, set_l) = as_set(left)?;
+    let (kind_r, set_r) = as_set(right)?;
+    if kind_l != kind_r {
+        return None;
+    }
+    let set = op(set_l, set_r);
+    match set.is_empty() {
+        true => None,
+        false => Some(new_set(kind_l, set)),
+    }
+}
+
+/// 求值/交集
+/// * 🚩两个同种词项集（均为外延集或均为内涵集）⇒同种词项集的交集
+/// * ⚠️任一不是词项集，或两者种类不同⇒[`None`]
+/// * ⚠️交集为空⇒[`None`]
+pub fn evaluate_intersection(left: &Term, right: &Term) -> Option<Term> {
+    evaluate_with(left, right, |l, r| l.intersection(r).cloned().collect())
+}
+
+/// 求值/并集
+/// * 🚩两个同种词项集（均为外延集或均为内涵集）⇒同种词项集的并集
+/// * ⚠️任一不是词项集，或两者种类不同⇒[`None`]
+pub fn evaluate_union(left: &Term, right: &Term) -> Option<Term> {
+    evaluate_with(left, right, |l, r| l.union(r).cloned().collect())
+}
+
+/// 求值/差集
+/// * 🚩两个同种词项集（均为外延集或均为内涵集）⇒同种词项集的差集`left - right`
+/// * ⚠️任一不是词项集，或两者种类不同⇒[`None`]
+/// * ⚠️差集为空⇒[`None`]
+pub fn evaluate_difference(left: &Term, right: &Term) -> Option<Term> {
+    evaluate_with(left, right, |l, r| l.difference(r).cloned().collect())
+}
+
+/// 工具函数/对一组词项逐个折叠集合运算
+/// * 🚩要求所有词项均为`kind`种类的词项集，否则返回[`None`]
+/// * ⚠️空组⇒[`None`]
+fn evaluate_fold<'a>(
+    terms: impl IntoIterator<Item = &'a Term>,
+    kind: SetKind,
+    op: fn(&Term, &Term) -> Option<Term>,
+) -> Option<Term> {
+    let mut terms = terms.into_iter();
+    let mut result = terms.next()?.clone();
+    // 检查首个词项的种类
+    if as_set(&result)?.0 != kind {
+        return None;
+    }
+    for term in terms {
+        result = op(&result, term)?;
+    }
+    Some(result)
+}
+
+/// 工具函数/尝试对「交/差」复合词项求值
+/// * 🚩仅当其所有组分均为「同种词项集」时求值；否则原样返回
+/// * 📌假定组分已经过递归求值
+fn evaluate_compound(term: Term) -> Term {
+    use SetKind::*;
+    let evaluated = match &term {
+        // 外延交：外延集取交，内涵集取并
+        IntersectionExtension(terms) => evaluate_fold(terms, Extension, evaluate_intersection)
+            .or_else(|| evaluate_fold(terms, Intension, evaluate_union)),
+        // 内涵交：外延集取并，内涵集取交
+        IntersectionIntension(terms) => evaluate_fold(terms, Extension, evaluate_union)
+            .or_else(|| evaluate_fold(terms, Intension, evaluate_intersection)),
+        // 外延差：仅外延集
+        DifferenceExtension(left, right) => match as_set(left) {
+            Some((Extension, _)) => evaluate_difference(left, right),
+            _ => None,
+        },
+        // 内涵差：仅内涵集
+        DifferenceIntension(left, right) => match as_set(left) {
+            Some((Intension, _)) => evaluate_difference(left, right),
+            _ => None,
+        },
+        _ => None,
+    };
+    evaluated.unwrap_or(term)
+}
+
+/// 递归求值
+/// * 🎯将「组分均为字面词项集」的「交/差」复合词项替换为求值后的词项集
+/// * 🚩自底向上：先对组分求值，再尝试对自身求值
+///   * 📄`(-, (&, {A, B}, {B, C}), {C})`⇒`(-, {B}, {C})`⇒`{B}`
+/// * 📌无法求值的词项原样保留（组分仍会被递归求值）
+///   * 📄结果为空集：`(&, {A}, {B})`保持不变
+pub fn evaluate_all(term: &Term) -> Term {
+    let set = |terms: &TermSetType| terms.iter().map(evaluate_all).collect::<TermSetType>();
+    let vec = |terms: &TermVecType| terms.iter().map(evaluate_all).collect::<TermVecType>();
+    let boxed = |term: &Term| Box::new(evaluate_all(term));
+    let mapped = match term {
+        // 原子词项
+        Word(..) | Placeholder | VariableIndependent(..) | VariableDependent(..)
+        | VariableQuery(..) | Interval(..) | Operator(..) => return term.clone(),
+        // 复合词项
+        SetExtension(terms) => SetExtension(set(terms)),
+        SetIntension(terms) => SetIntension(set(terms)),
+        IntersectionExtension(terms) => IntersectionExtension(set(terms)),
+        IntersectionIntension(terms) => IntersectionIntension(set(terms)),
+        DifferenceExtension(l, r) => DifferenceExtension(boxed(l), boxed(r)),
+        DifferenceIntension(l, r) => DifferenceIntension(boxed(l), boxed(r)),
+        Product(terms) => Product(vec(terms)),
+        ImageExtension(i, terms) => ImageExtension(*i, vec(terms)),
+        ImageIntension(i, terms) => ImageIntension(*i, vec(terms)),
+        Conjunction(terms) => Conjunction(set(terms)),
+        Disjunction(terms) => Disjunction(set(terms)),
+        Negation(t) => Negation(boxed(t)),
+        ConjunctionSequential(terms) => ConjunctionSequential(vec(terms)),
+        ConjunctionParallel(terms) => ConjunctionParallel(set(terms)),
+        // 陈述
+        Inheritance(l, r) => Inheritance(boxed(l), boxed(r)),
+        Similarity(l, r) => Similarity(boxed(l), boxed(r)),
+        Implication(l, r) => Implication(boxed(l), boxed(r)),
+        Equivalence(l, r) => Equivalence(boxed(l), boxed(r)),
+        ImplicationPredictive(l, r) => ImplicationPredictive(boxed(l), boxed(r)),
+        ImplicationConcurrent(l, r) => ImplicationConcurrent(boxed(l), boxed(r)),
+        ImplicationRetrospective(l, r) => ImplicationRetrospective(boxed(l), boxed(r)),
+        EquivalencePredictive(l, r) => EquivalencePredictive(boxed(l), boxed(r)),
+        EquivalenceConcurrent(l, r) => EquivalenceConcurrent(boxed(l), boxed(r)),
+        EquivalenceRetrospective(l, r) => EquivalenceRetrospective(boxed(l), boxed(r)),
+    };
+    evaluate_compound(mapped)
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nar_dev_utils::asserts;
+
+    /// 快捷构造外延集
+    fn ext(names: &[&str]) -> Term {
+        Term::new_set_extension(names.iter().map(|&name| Term::new_word(name)))
+    }
+
+    /// 快捷构造内涵集
+    fn int(names: &[&str]) -> Term {
+        Term::new_set_intension(names.iter().map(|&name| Term::new_word(name)))
+    }
+
+    #[test]
+    fn test_overlapping() {
+        let (a, b) = (ext(&["A", "B"]), ext(&["B", "C"]));
+        asserts! {
+            evaluate_intersection(&a, &b) => Some(ext(&["B"])),
+            evaluate_union(&a, &b) => Some(ext(&["A", "B", "C"])),
+            evaluate_difference(&a, &b) => Some(ext(&["A"])),
+        }
+        let (a, b) = (int(&["A", "B"]), int(&["B", "C"]));
+        asserts! {
+            evaluate_intersection(&a, &b) => Some(int(&["B"])),
+            evaluate_union(&a, &b) => Some(int(&["A", "B", "C"])),
+            evaluate_difference(&a, &b) => Some(int(&["A"])),
+        }
+    }
+
+    #[test]
+    fn test_disjoint() {
+        let (a, b) = (ext(&["A"]), ext(&["B"]));
+        asserts! {
+            evaluate_intersection(&a, &b) => None,
+            evaluate_union(&a, &b) => Some(ext(&["A", "B"])),
+            evaluate_difference(&a, &b) => Some(a.clone()),
+            evaluate_difference(&a, &a) => None,
+            evaluate_intersection(&int(&["A"]), &int(&["B"])) => None,
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        asserts! {
+            // 种类不同
+            evaluate_intersection(&ext(&["A"]), &int(&["A"])) => None,
+            // 非词项集
+            evaluate_union(&ext(&["A"]), &Term::new_word("A")) => None,
+            evaluate_difference(&Term::new_word("A"), &ext(&["A"])) => None,
+        }
+    }
+
+    #[test]
+    fn test_evaluate_all() {
+        // (-, (&, {A, B}, {B, C}), {C}) ⇒ {B}
+        let term = Term::new_difference_extension(
+            Term::new_intersection_extension([ext(&["A", "B"]), ext(&["B", "C"])]),
+            ext(&["C"]),
+        );
+        assert_eq!(evaluate_all(&term), ext(&["B"]));
+        // (|, {A}, {B}, {C}) ⇒ {A, B, C}
+        let term = Term::new_intersection_intension([ext(&["A"]), ext(&["B"]), ext(&["C"])]);
+        assert_eq!(evaluate_all(&term), ext(&["A", "B", "C"]));
+        // (&, [A, B], [C]) ⇒ [A, B, C]
+        let term = Term::new_intersection_extension([int(&["A", "B"]), int(&["C"])]);
+        assert_eq!(evaluate_all(&term), int(&["A", "B", "C"]));
+        // 嵌套于陈述中：<X --> (~, [A, B], [B])> ⇒ <X --> [A]>
+        let term = Term::new_inheritance(
+            Term::new_word("X"),
+            Term::new_difference_intension(int(&["A", "B"]), int(&["B"])),
+        );
+        assert_eq!(
+            evaluate_all(&term),
+            Term::new_inheritance(Term::new_word("X"), int(&["A"]))
+        );
+        // 无法求值的保留原样
+        let term = Term::new_intersection_extension([ext(&["A"]), Term::new_word("B")]);
+        assert_eq!(evaluate_all(&term), term);
+        let term = Term::new_difference_extension(int(&["A"]), int(&["B"]));
+        assert_eq!(evaluate_all(&term), term);
+        // 结果为空集的保留原样
+        let term = Term::new_intersection_extension([ext(&["A"]), ext(&["B"])]);
+        assert_eq!(evaluate_all(&term), term);
+        let term = Term::new_difference_intension(int(&["A"]), int(&["A", "B"]));
+        assert_eq!(evaluate_all(&term), term);
+    }
+
+    /// 测试/求值结果可「格式化→解析」往返
+    /// * 🎯不产生无法解析的空集`{}`、`[]`
+    #[test]
+    fn test_evaluate_all_round_trip() {
+        use crate::{
+            conversion::string::impl_enum::format_instances::FORMAT_ASCII, enum_narsese::Narsese,
+        };
+        for input in [
+            "(&, {A, B}, {B, C})",
+            "(&, {A}, {B})",
+            "(|, [A], [B])",
+            "(-, {A}, {A, B})",
+            "(~, [A, B], [A, B])",
+            "<(&, {A}, {B}) --> (-, (|, {A}, {B}), {A, B})>",
+            "(-, (&, {A, B}, {B, C}), {C})",
+        ] {
+            let term = FORMAT_ASCII.parse::<Narsese>(input).unwrap().try_into_term().unwrap();
+            let evaluated = evaluate_all(&term);
+            let formatted = FORMAT_ASCII.format_term(&evaluated);
+            let reparsed = FORMAT_ASCII.parse::<Narsese>(&formatted).unwrap().try_into_term().unwrap();
+            assert_eq!(reparsed, evaluated, "{input} => {formatted}");
+        }
+    }
+}
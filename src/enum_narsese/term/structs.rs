@@ -16,6 +16,7 @@ pub type TermVecType = Vec<Term>;
 /// * 自动实现[`Debug`]、[`Clone`]
 ///
 /// ! ❌【2024-03-27 21:03:26】[`HashSet`]不支持[`PartialOrd`]特征
+/// * 🚩[`PartialOrd`]、[`Ord`]改为手动实现：无序容器排序后比较
 #[derive(Debug, Clone)]
 pub enum Term {
     // 原子词项 //
@@ -81,8 +82,11 @@ pub enum Term {
     EquivalencePredictive(TermRefType, TermRefType),
     /// 并发性等价 | 💭目前当作对称 | 不能被解构的派生系词（不像NAL-2的可以有语法等价形式）
     EquivalenceConcurrent(TermRefType, TermRefType),
-    // !回顾性等价 | 可以被等价到「预测性等价」中
-    // EquivalenceRetrospective(TermRefType, TermRefType),
+    /// 回顾性等价 | ⚠️非对称 | 语义上等同于「调换主谓词的预测性等价」
+    /// * 📌保留原始写法，以便格式化时还原输入
+    ///   * 📄`<A <\> B>`不会被格式化为`<B </> A>`
+    /// * 🚩需要统一形式时，使用[`Term::normalize_temporal`]
+    EquivalenceRetrospective(TermRefType, TermRefType),
 }
 // 直接导出内部所有
 pub use Term::*;
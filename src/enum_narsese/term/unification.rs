@@ -0,0 +1,208 @@
+//! 词项的「模式匹配」（单向合一）
+//! * 🎯在完整推理器之外，实现简单的「规则匹配」
+//!   * 📄以`<$x --> B>`匹配`<A --> B>`⇒`{$x ↦ A}`
+//! * 🚩单向：只有「模式」中的变量被视作通配符
+//!   * 📌「目标」中的变量视作普通词项，只能被模式变量绑定、或与之逐字相等
+//!   * 📌同一变量多次出现⇒绑定须一致
+//! * 🚩无序容器（集合、交、合取，以及相似、等价等对称陈述）：逐个尝试组分间的对应关系
+//!   * ⚠️最坏情况下为指数复杂度（`n`元容器需尝试`n!`种对应），应避免匹配较大的无序容器
+
+use super::structs::*;
+use crate::api::{GetCapacity, TermCapacity};
+use std::{collections::HashMap, mem::discriminant};
+
+/// 变量的种类
+/// * 🎯作为[`Bindings`]键的一部分：同名不同种的变量互不相干
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VariableKind {
+    /// 独立变量
+    Independent,
+    /// 非独变量
+    Dependent,
+    /// 查询变量
+    Query,
+}
+
+/// 变量绑定表
+/// * 📌键：变量的「种类+名称」
+/// * 📌值：变量所绑定的词项
+pub type Bindings = HashMap<(VariableKind, String), Term>;
+
+/// 工具函数/获取变量的「种类+名称」
+/// * 📌非变量⇒[`None`]
+fn variable_key(term: &Term) -> Option<(VariableKind, String)> {
+    match term {
+        VariableIndependent(name) => Some((VariableKind::Independent, name.clone())),
+        VariableDependent(name) => Some((VariableKind::Dependent, name.clone())),
+        VariableQuery(name) => Some((VariableKind::Query, name.clone())),
+        _ => None,
+    }
+}
+
+/// 模式匹配
+/// * 🚩以`pattern`中的变量为通配符，尝试匹配`target`
+/// * ⚙️成功⇒返回变量绑定表；失败⇒[`None`]
+/// * 📄`unify(<$x --> B>, <A --> B>)` ⇒ `Some({$x ↦ A})`
+pub fn unify(pattern: &Term, target: &Term) -> Option<Bindings> {
+    unify_with(pattern, target, Bindings::new())
+}
+
+/// 在已有绑定的基础上进行模式匹配
+/// * 🚩按值传递绑定表：失败分支直接丢弃，便于无序容器回溯
+fn unify_with(pattern: &Term, target: &Term, mut bindings: Bindings) -> Option<Bindings> {
+    // 变量⇒绑定，或检查已有绑定的一致性
+    if let Some(key) = variable_key(pattern) {
+        return match bindings.get(&key) {
+            Some(bound) => (bound == target).then_some(bindings),
+            None => {
+                bindings.insert(key, target.clone());
+                Some(bindings)
+            }
+        };
+    }
+    // 具体类型不同⇒失败
+    if discriminant(pattern) != discriminant(target) {
+        return None;
+    }
+    match (pattern, target) {
+        // 像⇒占位符位置须一致
+        (ImageExtension(i1, _), ImageExtension(i2, _))
+        | (ImageIntension(i1, _), ImageIntension(i2, _))
+            if i1 != i2 =>
+        {
+            return None
+        }
+        // 其它原子词项⇒逐字相等
+        _ if pattern.is_capacity_atom() => return (pattern == target).then_some(bindings),
+        _ => {}
+    }
+    // 复合词项⇒按组分匹配
+    let components_p = pattern.get_components();
+    let components_t = target.get_components();
+    // 元数不同⇒失败
+    if components_p.len() != components_t.len() {
+        return None;
+    }
+    match pattern.get_capacity() {
+        // 无序⇒尝试所有对应关系
+        TermCapacity::BinarySet | TermCapacity::Set => {
+            unify_unordered(&components_p, &components_t, bindings)
+        }
+        // 有序⇒逐个匹配
+        _ => components_p
+            .into_iter()
+            .zip(components_t)
+            .try_fold(bindings, |bindings, (p, t)| unify_with(p, t, bindings)),
+    }
+}
+
+/// 无序容器的模式匹配
+/// * 🚩取首个模式组分，依次尝试与每个目标组分匹配，再递归匹配剩余组分
+///   * 📌匹配失败⇒回溯，尝试下一个目标组分
+/// * ⚠️最坏情况为`n!`次尝试
+fn unify_unordered(patterns: &[&Term], targets: &[&Term], bindings: Bindings) -> Option<Bindings> {
+    let Some((pattern, patterns_rest)) = patterns.split_first() else {
+        return Some(bindings);
+    };
+    targets.iter().enumerate().find_map(|(i, target)| {
+        let bindings = unify_with(pattern, target, bindings.clone())?;
+        let mut targets_rest = targets.to_vec();
+        targets_rest.remove(i);
+        unify_unordered(patterns_rest, &targets_rest, bindings)
+    })
+}
+
+/// 代入绑定
+/// * 🚩将词项中已绑定的变量替换为其所绑定的词项
+///   * 📌未绑定的变量保持不变
+/// * 📄`apply({$x ↦ A}, <$x --> B>)` ⇒ `<A --> B>`
+pub fn apply(bindings: &Bindings, term: &Term) -> Term {
+    term.clone().map_terms(&mut |term| {
+        match variable_key(&term).and_then(|key| bindings.get(&key)) {
+            Some(bound) => bound.clone(),
+            None => term,
+        }
+    })
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enum_narsese::tests::term;
+    use nar_dev_utils::asserts;
+
+    /// 工具函数：匹配并取出某变量的绑定
+    fn bound(pattern: &str, target: &str, kind: VariableKind, name: &str) -> Option<Term> {
+        unify(&term(pattern), &term(target))?
+            .remove(&(kind, name.into()))
+    }
+
+    #[test]
+    fn test_unify() {
+        use VariableKind::*;
+        asserts! {
+            // 基本匹配
+            bound("<$x --> B>", "<A --> B>", Independent, "x") => Some(term("A")),
+            bound("<#x --> B>", "<(*, A, C) --> B>", Dependent, "x") => Some(term("(*, A, C)")),
+            bound("<?x --> B>", "<A --> B>", Query, "x") => Some(term("A")),
+            // 无变量⇒须相等
+            unify(&term("<A --> B>"), &term("<A --> B>")) => Some(Bindings::new()),
+            unify(&term("<A --> B>"), &term("<A --> C>")) => None,
+            // 目标中的变量不是通配符
+            unify(&term("<A --> B>"), &term("<$x --> B>")) => None,
+            // 类型不同⇒失败
+            unify(&term("<$x --> B>"), &term("<A <-> B>")) => None,
+        }
+    }
+
+    /// 测试/同一变量的绑定须一致
+    #[test]
+    fn test_unify_repeated_variable() {
+        asserts! {
+            bound("<$x --> $x>", "<A --> A>", VariableKind::Independent, "x") => Some(term("A")),
+            unify(&term("<$x --> $x>"), &term("<A --> B>")) => None,
+            unify(&term("<<$x --> A> ==> <$x --> B>>"), &term("<<C --> A> ==> <D --> B>>")) => None,
+            // 同名不同种⇒互不相干
+            unify(&term("<$x --> #x>"), &term("<A --> B>")).map(|b| b.len()) => Some(2),
+        }
+    }
+
+    /// 测试/元数不同⇒失败
+    #[test]
+    fn test_unify_arity_mismatch() {
+        asserts! {
+            unify(&term("(*, $x, B)"), &term("(*, A, B, C)")) => None,
+            unify(&term("{$x, B}"), &term("{A}")) => None,
+            unify(&term("(/, R, _, $x)"), &term("(/, R, $x, _)")) => None,
+        }
+    }
+
+    /// 测试/无序容器
+    /// * ⚠️保持容器较小：匹配为指数复杂度
+    #[test]
+    fn test_unify_unordered() {
+        let pattern = term("(&&, <$x --> A>, <$x --> B>)");
+        let bindings = unify(&pattern, &term("(&&, <C --> B>, <C --> A>)")).unwrap();
+        asserts! {
+            bindings.get(&(VariableKind::Independent, "x".into())) => Some(&term("C")),
+            // 代入后还原目标
+            apply(&bindings, &pattern) => term("(&&, <C --> A>, <C --> B>)"),
+            // 绑定不一致⇒失败
+            unify(&pattern, &term("(&&, <C --> A>, <D --> B>)")) => None,
+            // 需要回溯的情形
+            unify(&term("{$x, $y, A}"), &term("{A, B, C}")).map(|b| b.len()) => Some(2),
+            // 对称陈述
+            bound("<$x <-> A>", "<A <-> B>", VariableKind::Independent, "x") => Some(term("B")),
+        }
+    }
+
+    #[test]
+    fn test_apply() {
+        let bindings = unify(&term("<$x --> $y>"), &term("<A --> (*, B, C)>")).unwrap();
+        asserts! {
+            apply(&bindings, &term("<$y ==> <$x --> $z>>")) => term("<(*, B, C) ==> <A --> $z>>"),
+            apply(&Bindings::new(), &term("<$x --> A>")) => term("<$x --> A>"),
+        }
+    }
+}
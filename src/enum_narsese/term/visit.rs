@@ -0,0 +1,211 @@
+//! 词项的「遍历」与「变换」
+//! * 🎯避免下游为每种变换都写一遍「覆盖所有词项类型」的`match`
+//!   * 📄重命名变量、替换子词项、统计操作符……
+//! * 🚩变换：自底向上，先变换组分、重建容器，再变换自身
+//!   * 📌像：保留占位符索引
+//!   * 📌无序容器（集合、交、合取等）：重建时自动去重
+//! * 🚩遍历：先序，先自身、再组分
+//!   * 📌像：会遍历到占位符
+//!   * ⚠️无序容器中的组分顺序不确定
+
+use super::structs::*;
+use crate::api::GetCategory;
+
+/// 实现/变换与遍历
+impl Term {
+    /// 变换/仅组分
+    /// * 🚩对每个直接组分应用`f`，然后重建容器
+    /// * 📌原子词项⇒原样返回
+    pub(super) fn map_components(self, f: &mut impl FnMut(Term) -> Term) -> Term {
+        let mut set = |terms: TermSetType| terms.into_iter().map(&mut *f).collect::<TermSetType>();
+        match self {
+            // 原子词项
+            Word(..) | Placeholder | VariableIndependent(..) | VariableDependent(..)
+            | VariableQuery(..) | Interval(..) | Operator(..) => self,
+            // 无序容器 | 重建时自动去重
+            SetExtension(terms) => SetExtension(set(terms)),
+            SetIntension(terms) => SetIntension(set(terms)),
+            IntersectionExtension(terms) => IntersectionExtension(set(terms)),
+            IntersectionIntension(terms) => IntersectionIntension(set(terms)),
+            Conjunction(terms) => Conjunction(set(terms)),
+            Disjunction(terms) => Disjunction(set(terms)),
+            ConjunctionParallel(terms) => ConjunctionParallel(set(terms)),
+            // 有序容器 | 像保留占位符索引
+            Product(terms) => Product(terms.into_iter().map(f).collect()),
+            ImageExtension(i, terms) => ImageExtension(i, terms.into_iter().map(f).collect()),
+            ImageIntension(i, terms) => ImageIntension(i, terms.into_iter().map(f).collect()),
+            ConjunctionSequential(terms) => {
+                ConjunctionSequential(terms.into_iter().map(f).collect())
+            }
+            // 一元、二元容器
+            Negation(t) => Negation(Box::new(f(*t))),
+            DifferenceExtension(l, r) => DifferenceExtension(Box::new(f(*l)), Box::new(f(*r))),
+            DifferenceIntension(l, r) => DifferenceIntension(Box::new(f(*l)), Box::new(f(*r))),
+            // 陈述
+            Inheritance(l, r) => Inheritance(Box::new(f(*l)), Box::new(f(*r))),
+            Similarity(l, r) => Similarity(Box::new(f(*l)), Box::new(f(*r))),
+            Implication(l, r) => Implication(Box::new(f(*l)), Box::new(f(*r))),
+            Equivalence(l, r) => Equivalence(Box::new(f(*l)), Box::new(f(*r))),
+            ImplicationPredictive(l, r) => {
+                ImplicationPredictive(Box::new(f(*l)), Box::new(f(*r)))
+            }
+            ImplicationConcurrent(l, r) => {
+                ImplicationConcurrent(Box::new(f(*l)), Box::new(f(*r)))
+            }
+            ImplicationRetrospective(l, r) => {
+                ImplicationRetrospective(Box::new(f(*l)), Box::new(f(*r)))
+            }
+            EquivalencePredictive(l, r) => {
+                EquivalencePredictive(Box::new(f(*l)), Box::new(f(*r)))
+            }
+            EquivalenceConcurrent(l, r) => {
+                EquivalenceConcurrent(Box::new(f(*l)), Box::new(f(*r)))
+            }
+            EquivalenceRetrospective(l, r) => {
+                EquivalenceRetrospective(Box::new(f(*l)), Box::new(f(*r)))
+            }
+        }
+    }
+
+    /// 变换/递归
+    /// * 🚩自底向上：先变换所有组分并重建容器，再对自身应用`f`
+    ///   * 📌`f`会作用于每个子词项（包括自身）
+    /// * 📌像：占位符不作为组分存储，故不会传入`f`；占位符索引保持不变
+    /// * 📄重命名独立变量：`$x` ⇒ `$y`
+    pub fn map_terms(self, f: &mut impl FnMut(Term) -> Term) -> Term {
+        let mapped = self.map_components(&mut |term| term.map_terms(f));
+        f(mapped)
+    }
+
+    /// 遍历/先序
+    /// * 🚩先访问自身，再依次访问组分
+    /// * 📌像：会访问到占位符
+    /// * ⚠️无序容器中的组分，访问顺序不确定
+    pub fn visit<'a>(&'a self, f: &mut impl FnMut(&'a Term)) {
+        f(self);
+        // 原子词项⇒无组分 | ⚠️[`Term::get_components`]会返回自身
+        if self.is_atom() {
+            return;
+        }
+        for term in self.get_components_including_placeholder() {
+            term.visit(f);
+        }
+    }
+
+    /// 替换子词项
+    /// * 🚩自顶向下：与`from`相等的子词项（包括自身）整个替换为`to`
+    ///   * 📌被替换后不再深入其中
+    /// * 📌返回新词项，不修改自身
+    pub fn replace(&self, from: &Term, to: &Term) -> Term {
+        match self == from {
+            true => to.clone(),
+            false => self
+                .clone()
+                .map_components(&mut |term| term.replace(from, to)),
+        }
+    }
+
+    /// 判断是否包含子词项
+    /// * 📌包括自身
+    /// * 📌像：包括占位符
+    pub fn contains(&self, sub: &Term) -> bool {
+        let mut found = false;
+        self.visit(&mut |term| found = found || term == sub);
+        found
+    }
+
+    /// 规范化/时序系词
+    /// * 🚩将所有「回顾性等价」转换为「调换主谓词的预测性等价」
+    ///   * 📄`<A <\> B>` ⇒ `<B </> A>`
+    /// * 📌递归处理所有子词项；返回新词项，不修改自身
+    pub fn normalize_temporal(&self) -> Term {
+        self.clone().map_terms(&mut |term| match term {
+            EquivalenceRetrospective(l, r) => EquivalencePredictive(r, l),
+            term => term,
+        })
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enum_narsese::tests::term;
+    use nar_dev_utils::asserts;
+
+    /// 变换：重命名独立变量
+    #[test]
+    fn test_map_terms() {
+        let statement = term("<(&&, <<$x-->A>==><$x-->B>>, <<$y-->C>==><$y-->D>>) ==> E>");
+        let renamed = statement.clone().map_terms(&mut |term| match term {
+            VariableIndependent(name) if name == "x" => Term::new_variable_independent("y"),
+            other => other,
+        });
+        asserts! {
+            renamed => term("<(&&, <<$y-->A>==><$y-->B>>, <<$y-->C>==><$y-->D>>) ==> E>"),
+            // 与`replace`一致
+            statement.replace(&term("$x"), &term("$y")) => renamed,
+        }
+        // 像：保留占位符索引
+        let image = term(r"(\, R, A, _, B)");
+        let mapped = image.map_terms(&mut |term| match term {
+            Word(name) => Term::new_word(name.to_lowercase()),
+            other => other,
+        });
+        assert_eq!(mapped, term(r"(\, r, a, _, b)"));
+        // 无序容器：重建时去重
+        let set = term("{A, B}").map_terms(&mut |term| match term {
+            Word(..) => Term::new_word("C"),
+            other => other,
+        });
+        assert_eq!(set, term("{C}"));
+        // 自底向上：自身也会被变换
+        let counted = term("(*, A, (*, B))").map_terms(&mut |term| match term {
+            Product(terms) => Term::new_word(terms.len().to_string()),
+            other => other,
+        });
+        // * 📄`(*, A, (*, B))` ⇒ `(*, A, 1)` ⇒ `2`
+        assert_eq!(counted, term("2"));
+    }
+
+    /// 遍历：先序，统计操作符
+    #[test]
+    fn test_visit() {
+        let statement = term("<(&/, <(*, {SELF}) --> ^left>, +1, <(*, {SELF}) --> ^right>) =/> G>");
+        let mut operators = vec![];
+        statement.visit(&mut |term| {
+            if let Operator(name) = term {
+                operators.push(name.as_str())
+            }
+        });
+        assert_eq!(operators, ["left", "right"]);
+        // 先序：自身最先被访问
+        let mut visited = vec![];
+        term("<A --> (--, B)>").visit(&mut |term| visited.push(term.clone()));
+        asserts! {
+            visited => [term("<A --> (--, B)>"), term("A"), term("(--, B)"), term("B")],
+        }
+    }
+
+    /// 替换与包含
+    #[test]
+    fn test_replace_contains() {
+        let image = term(r"(/, R, _, A)");
+        asserts! {
+            // 像：包括占位符
+            image.contains(&Placeholder),
+            image.contains(&term("R")),
+            image.contains(&term("A")),
+            image.contains(&image),
+            !image.contains(&term("B")),
+            // 替换整个子词项
+            term("<(&&, A, B) --> C>").replace(&term("(&&, B, A)"), &term("D")) => term("<D --> C>"),
+            // 替换后不再深入
+            term("(--, A)").replace(&term("A"), &term("(--, A)")) => term("(--, (--, A))"),
+            // 无匹配⇒原样
+            term("<A --> B>").replace(&term("C"), &term("D")) => term("<A --> B>"),
+            // 像中替换：占位符索引不变
+            image.replace(&term("A"), &term("B")) => term(r"(/, R, _, B)"),
+        }
+    }
+}
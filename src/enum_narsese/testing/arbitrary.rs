@@ -0,0 +1,297 @@
+//! 测试辅助：随机生成枚举Narsese
+//! * 🎯往返性质测试：覆盖手写样例覆盖不到的词项形状
+//!   * 📄深层的像、陈述中的集合、乘积中的陈述……
+//! * 🚩生成的值总满足各类不变量，保证可被「格式化⇒解析」还原
+//!   * 📌原子词项名称：仅含ASCII字母、数字，且以字母开头（不与任何格式的关键字冲突）
+//!   * 📌复合词项非空；像的占位符索引在范围内
+//!   * ⚠️不生成占位符：占位符只作为像的一部分出现
+//!   * ⚠️不生成「回顾性等价」：解析时可能被规范化为「预测性等价」
+//! * 🚩不引入外部依赖：自带一个xorshift伪随机数生成器
+
+use crate::{
+    api::{FloatPrecision, IntPrecision, UIntPrecision},
+    enum_narsese::{Budget, Sentence, Stamp, Task, Term, Truth},
+};
+
+/// 随机数生成器的抽象
+/// * 🎯允许调用方使用自己的随机源
+/// * 📌只需实现[`Rng::next_u64`]，其余方法有默认实现
+pub trait Rng {
+    /// 生成下一个随机数
+    fn next_u64(&mut self) -> u64;
+
+    /// 生成`[0, n)`内的随机整数
+    /// * ⚠️`n`不能为零
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// 生成`[min, max]`内的随机整数
+    fn between(&mut self, min: usize, max: usize) -> usize {
+        min + self.below(max - min + 1)
+    }
+
+    /// 生成`[0, 1]`内的随机数值
+    /// * 🚩取两位小数：格式化后简短易读
+    fn unit(&mut self) -> FloatPrecision {
+        self.below(101) as FloatPrecision / 100.0
+    }
+}
+
+/// xorshift64伪随机数生成器
+/// * 🎯以固定种子复现测试结果
+#[derive(Debug, Clone)]
+pub struct XorShift64 {
+    state: u64,
+}
+
+impl XorShift64 {
+    /// 从种子构造
+    /// * 📌种子为零时以非零常量代替：xorshift的状态不能为零
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: match seed {
+                0 => 0x9e37_79b9_7f4a_7c15,
+                seed => seed,
+            },
+        }
+    }
+}
+
+impl Rng for XorShift64 {
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+}
+
+/// 随机生成原子词项名称
+/// * 🚩以ASCII字母开头，后接至多三个ASCII字母或数字
+fn random_name(rng: &mut impl Rng) -> String {
+    const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    const ALPHANUMERIC: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+    let mut name = String::from(LETTERS[rng.below(LETTERS.len())] as char);
+    for _ in 0..rng.below(4) {
+        name.push(ALPHANUMERIC[rng.below(ALPHANUMERIC.len())] as char);
+    }
+    name
+}
+
+/// 随机生成原子词项
+/// * ⚠️不含占位符
+fn random_atom(rng: &mut impl Rng) -> Term {
+    match rng.below(6) {
+        0 => Term::new_word(random_name(rng)),
+        1 => Term::new_variable_independent(random_name(rng)),
+        2 => Term::new_variable_dependent(random_name(rng)),
+        3 => Term::new_variable_query(random_name(rng)),
+        4 => Term::new_interval(rng.below(100) as UIntPrecision),
+        _ => Term::new_operator(random_name(rng)),
+    }
+}
+
+/// 随机生成若干词项
+fn random_terms(rng: &mut impl Rng, depth: usize, min: usize, max: usize) -> Vec<Term> {
+    (0..rng.between(min, max))
+        .map(|_| random_term(rng, depth))
+        .collect()
+}
+
+/// 随机生成词项
+/// * 🚩`depth`为最大嵌套层数：为零时只生成原子词项
+/// * 📌覆盖除「占位符」「回顾性等价」之外的所有变种
+pub fn random_term(rng: &mut impl Rng, depth: usize) -> Term {
+    // 到达最大深度，或随机选中⇒原子词项
+    if depth == 0 || rng.below(3) == 0 {
+        return random_atom(rng);
+    }
+    let depth = depth - 1;
+    match rng.below(2) {
+        0 => random_compound(rng, depth),
+        _ => random_statement(rng, depth),
+    }
+}
+
+/// 随机生成复合词项
+fn random_compound(rng: &mut impl Rng, depth: usize) -> Term {
+    match rng.below(14) {
+        // 集合
+        0 => Term::new_set_extension(random_terms(rng, depth, 1, 3)),
+        1 => Term::new_set_intension(random_terms(rng, depth, 1, 3)),
+        // 交、差
+        2 => Term::new_intersection_extension(random_terms(rng, depth, 2, 3)),
+        3 => Term::new_intersection_intension(random_terms(rng, depth, 2, 3)),
+        4 => Term::new_difference_extension(random_term(rng, depth), random_term(rng, depth)),
+        5 => Term::new_difference_intension(random_term(rng, depth), random_term(rng, depth)),
+        // 乘积、像 | 占位符索引在`[0, 组分数]`内
+        6 => Term::new_product(random_terms(rng, depth, 1, 3)),
+        7 | 8 => {
+            let terms = random_terms(rng, depth, 1, 3);
+            let index = rng.below(terms.len() + 1);
+            match rng.below(2) {
+                0 => Term::new_image_extension(index, terms),
+                _ => Term::new_image_intension(index, terms),
+            }
+        }
+        // 合取、析取、否定
+        9 => Term::new_conjunction(random_terms(rng, depth, 2, 3)),
+        10 => Term::new_disjunction(random_terms(rng, depth, 2, 3)),
+        11 => Term::new_negation(random_term(rng, depth)),
+        12 => Term::new_conjunction_sequential(random_terms(rng, depth, 2, 3)),
+        _ => Term::new_conjunction_parallel(random_terms(rng, depth, 2, 3)),
+    }
+}
+
+/// 随机生成陈述
+/// * 📌「实例」「属性」「实例属性」作为语法糖，一并生成
+fn random_statement(rng: &mut impl Rng, depth: usize) -> Term {
+    let constructors = [
+        Term::new_inheritance,
+        Term::new_similarity,
+        Term::new_implication,
+        Term::new_equivalence,
+        Term::new_instance,
+        Term::new_property,
+        Term::new_instance_property,
+        Term::new_implication_predictive,
+        Term::new_implication_concurrent,
+        Term::new_implication_retrospective,
+        Term::new_equivalence_predictive,
+        Term::new_equivalence_concurrent,
+    ];
+    let constructor = constructors[rng.below(constructors.len())];
+    constructor(random_term(rng, depth), random_term(rng, depth))
+}
+
+/// 随机生成时间戳
+fn random_stamp(rng: &mut impl Rng) -> Stamp {
+    match rng.below(5) {
+        0 => Stamp::Eternal,
+        1 => Stamp::Past,
+        2 => Stamp::Present,
+        3 => Stamp::Future,
+        _ => Stamp::Fixed(rng.below(201) as IntPrecision - 100),
+    }
+}
+
+/// 随机生成真值
+fn random_truth(rng: &mut impl Rng) -> Truth {
+    match rng.below(3) {
+        0 => Truth::new_empty(),
+        1 => Truth::new_single(rng.unit()),
+        _ => Truth::new_double(rng.unit(), rng.unit()),
+    }
+}
+
+/// 随机生成预算值
+fn random_budget(rng: &mut impl Rng) -> Budget {
+    match rng.below(4) {
+        0 => Budget::new_empty(),
+        1 => Budget::new_single(rng.unit()),
+        2 => Budget::new_double(rng.unit(), rng.unit()),
+        _ => Budget::new_triple(rng.unit(), rng.unit(), rng.unit()),
+    }
+}
+
+/// 随机生成语句
+/// * 🚩判断、目标带真值；问题、请求不带真值
+pub fn random_sentence(rng: &mut impl Rng, depth: usize) -> Sentence {
+    let term = random_term(rng, depth);
+    let stamp = random_stamp(rng);
+    match rng.below(4) {
+        0 => Sentence::new_judgement(term, random_truth(rng), stamp),
+        1 => Sentence::new_goal(term, random_truth(rng), stamp),
+        2 => Sentence::new_question(term, stamp),
+        _ => Sentence::new_quest(term, stamp),
+    }
+}
+
+/// 随机生成任务
+pub fn random_task(rng: &mut impl Rng, depth: usize) -> Task {
+    Task::new(random_sentence(rng, depth), random_budget(rng))
+}
+
+/// 单元测试
+/// * 🎯往返性质：随机任务经「格式化⇒解析」后不变
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::GetCategory, conversion::string::impl_enum::format_instances::*, enum_narsese::Narsese,
+    };
+
+    /// 随机任务的数目
+    const N: usize = 500;
+    /// 随机任务的最大嵌套深度
+    const DEPTH: usize = 4;
+    /// 固定种子：保证可复现
+    const SEED: u64 = 0x4e41_5253;
+
+    /// 生成随机任务
+    fn random_tasks() -> Vec<Task> {
+        let mut rng = XorShift64::new(SEED);
+        (0..N).map(|_| random_task(&mut rng, DEPTH)).collect()
+    }
+
+    /// 测试/生成器：确定性，且满足不变量
+    #[test]
+    fn test_random_term() {
+        let mut rng = XorShift64::new(SEED);
+        let terms = (0..N)
+            .map(|_| random_term(&mut rng, DEPTH))
+            .collect::<Vec<_>>();
+        let mut rng = XorShift64::new(SEED);
+        assert!(terms
+            .iter()
+            .all(|term| *term == random_term(&mut rng, DEPTH)));
+        // 原子词项、复合词项、陈述均有生成
+        assert!(terms.iter().any(Term::is_atom));
+        assert!(terms.iter().any(Term::is_compound));
+        assert!(terms.iter().any(Term::is_statement));
+        // 深度为零⇒只有原子词项
+        assert!((0..N).all(|_| random_term(&mut rng, 0).is_atom()));
+    }
+
+    /// 测试/往返：枚举Narsese
+    #[test]
+    fn test_round_trip_enum() {
+        for format in [&FORMAT_ASCII, &FORMAT_LATEX, &FORMAT_HAN] {
+            for task in random_tasks() {
+                let formatted = format.format_task(&task);
+                let parsed = format.parse::<Narsese>(&formatted);
+                assert_eq!(parsed.ok(), Some(Narsese::Task(task)), "{formatted}");
+            }
+        }
+    }
+
+    /// 测试/往返：词法Narsese
+    /// * 🚩展开⇒格式化⇒解析⇒折叠
+    #[test]
+    #[cfg(feature = "lexical_narsese")]
+    fn test_round_trip_lexical() {
+        use crate::conversion::{
+            inter_type::lexical_fold::{FoldError, TryFoldInto},
+            string::impl_lexical::format_instances as lexical,
+        };
+        let pairs = [
+            (&FORMAT_ASCII, &*lexical::FORMAT_ASCII),
+            (&FORMAT_LATEX, &*lexical::FORMAT_LATEX),
+            (&FORMAT_HAN, &*lexical::FORMAT_HAN),
+        ];
+        for (format, lexical_format) in pairs {
+            for task in random_tasks() {
+                let formatted = lexical_format.format_task(&task.to_lexical(format));
+                let folded: Option<Result<Task, FoldError>> = lexical_format
+                    .parse(&formatted)
+                    .ok()
+                    .and_then(|parsed| parsed.try_into_task().ok())
+                    .map(|parsed| parsed.try_fold_into(format));
+                assert_eq!(folded, Some(Ok(task)), "{formatted}");
+            }
+        }
+    }
+}
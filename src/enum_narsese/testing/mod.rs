@@ -0,0 +1,272 @@
+//! 测试辅助：枚举Narsese的「差异比对」
+//! * 🎯往返测试失败时，只给出「哪里不同」，而非两大段[`Debug`]输出
+//!   * 📄`[0, 1]: Word("B") ≠ Word("C")`
+//! * 🚩词项：逐层比对，每个分支在首个不同的节点处停止深入
+//!   * 📌路径与[`Term::get_at`]一致：无序容器按全序排序后的位置
+//! * 🚩语句、任务：另外比对标点、时间戳、真值、预算值
+//! * 📌公开可用：下游crate的测试亦可使用
+
+// 随机生成 | 用于往返性质测试
+// * 🚩仅在测试或启用`testing`特性时编译
+#[cfg(any(test, feature = "testing"))]
+pub mod arbitrary;
+
+use super::{Budget, Narsese, Punctuation, Stamp, Term, Truth};
+use crate::api::{
+    GetBudget, GetCategory, GetPunctuation, GetStamp, GetTerm, GetTruth, UIntPrecision,
+};
+use std::fmt::{self, Display, Formatter};
+
+/// 词项差异
+/// * 📌记录「不同节点」的路径，以及两侧节点的简述
+///   * 📄原子词项：`Word("A")`
+///   * 📄复合词项、陈述：`Product[2]`（类型与组分数）
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TermDiff {
+    /// 节点所在路径
+    pub path: Vec<UIntPrecision>,
+    /// 左侧节点的简述
+    pub left: String,
+    /// 右侧节点的简述
+    pub right: String,
+}
+
+impl Display for TermDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {} ≠ {}", self.path, self.left, self.right)
+    }
+}
+
+/// Narsese值差异
+#[derive(Debug, Clone, PartialEq)]
+pub enum NarseseDiff {
+    /// 类型不同（词项/语句/任务）
+    Kind {
+        left: &'static str,
+        right: &'static str,
+    },
+    /// 词项不同
+    Term(TermDiff),
+    /// 标点不同
+    Punctuation {
+        left: Punctuation,
+        right: Punctuation,
+    },
+    /// 时间戳不同
+    Stamp { left: Stamp, right: Stamp },
+    /// 真值不同
+    /// * 📌问题、请求⇒[`None`]
+    Truth {
+        left: Option<Truth>,
+        right: Option<Truth>,
+    },
+    /// 预算值不同
+    Budget { left: Budget, right: Budget },
+}
+
+impl Display for NarseseDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            NarseseDiff::Kind { left, right } => write!(f, "类型: {left} ≠ {right}"),
+            NarseseDiff::Term(diff) => write!(f, "词项{diff}"),
+            NarseseDiff::Punctuation { left, right } => write!(f, "标点: {left:?} ≠ {right:?}"),
+            NarseseDiff::Stamp { left, right } => write!(f, "时间戳: {left:?} ≠ {right:?}"),
+            NarseseDiff::Truth { left, right } => write!(f, "真值: {left:?} ≠ {right:?}"),
+            NarseseDiff::Budget { left, right } => write!(f, "预算值: {left:?} ≠ {right:?}"),
+        }
+    }
+}
+
+/// 将一系列差异展示为多行文本
+/// * 🎯用于断言失败时的消息：`assert!(a == b, "{}", display_diffs(&diff_terms(&a, &b)))`
+pub fn display_diffs<D: Display>(diffs: &[D]) -> String {
+    diffs
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 比对两个词项
+/// * 🚩相同⇒空数组
+/// * 🚩节点简述不同（类型、名称、组分数）⇒记录该节点，不再深入
+/// * 🚩否则逐个比对组分
+pub fn diff_terms(a: &Term, b: &Term) -> Vec<TermDiff> {
+    let mut diffs = vec![];
+    diff_terms_at(a, b, &mut vec![], &mut diffs);
+    diffs
+}
+
+/// 比对两个Narsese值
+/// * 🚩类型不同⇒仅记录类型
+/// * 🚩依次比对词项、标点、时间戳、真值、预算值
+pub fn diff_narsese(a: &Narsese, b: &Narsese) -> Vec<NarseseDiff> {
+    use crate::api::NarseseValue::*;
+    let (sentence_a, sentence_b, budgets) = match (a, b) {
+        (Term(a), Term(b)) => return term_diffs(a, b),
+        (Sentence(a), Sentence(b)) => (a, b, None),
+        (Task(a), Task(b)) => (
+            a.get_sentence(),
+            b.get_sentence(),
+            Some((a.get_budget(), b.get_budget())),
+        ),
+        _ => {
+            return vec![NarseseDiff::Kind {
+                left: a.type_name(),
+                right: b.type_name(),
+            }]
+        }
+    };
+    let mut diffs = term_diffs(sentence_a.get_term(), sentence_b.get_term());
+    if sentence_a.get_punctuation() != sentence_b.get_punctuation() {
+        diffs.push(NarseseDiff::Punctuation {
+            left: sentence_a.get_punctuation().clone(),
+            right: sentence_b.get_punctuation().clone(),
+        });
+    }
+    if sentence_a.get_stamp() != sentence_b.get_stamp() {
+        diffs.push(NarseseDiff::Stamp {
+            left: sentence_a.get_stamp().clone(),
+            right: sentence_b.get_stamp().clone(),
+        });
+    }
+    if sentence_a.get_truth() != sentence_b.get_truth() {
+        diffs.push(NarseseDiff::Truth {
+            left: sentence_a.get_truth().cloned(),
+            right: sentence_b.get_truth().cloned(),
+        });
+    }
+    match budgets {
+        Some((left, right)) if left != right => diffs.push(NarseseDiff::Budget {
+            left: left.clone(),
+            right: right.clone(),
+        }),
+        _ => {}
+    }
+    diffs
+}
+
+/// 工具函数/词项差异⇒Narsese值差异
+fn term_diffs(a: &Term, b: &Term) -> Vec<NarseseDiff> {
+    diff_terms(a, b)
+        .into_iter()
+        .map(NarseseDiff::Term)
+        .collect()
+}
+
+/// 递归比对词项
+fn diff_terms_at(a: &Term, b: &Term, path: &mut Vec<UIntPrecision>, diffs: &mut Vec<TermDiff>) {
+    // 完全相同⇒提前返回
+    if a == b {
+        return;
+    }
+    let (components_a, components_b) = (a.path_components(), b.path_components());
+    let (left, right) = (describe(a, &components_a), describe(b, &components_b));
+    // 节点本身不同⇒记录并停止深入
+    if left != right {
+        diffs.push(TermDiff {
+            path: path.clone(),
+            left,
+            right,
+        });
+        return;
+    }
+    // 逐个比对组分 | 节点相同⇒组分数相同
+    for (i, (a, b)) in components_a.into_iter().zip(components_b).enumerate() {
+        path.push(i);
+        diff_terms_at(a, b, path, diffs);
+        path.pop();
+    }
+}
+
+/// 节点简述
+/// * 📌原子词项⇒[`Debug`]形式；其它⇒类型名与组分数
+fn describe(term: &Term, components: &[&Term]) -> String {
+    if term.is_atom() {
+        return format!("{term:?}");
+    }
+    // 取[`Debug`]形式中的变体名 | 组分已单独比对，此处只需类型
+    let debug = format!("{term:?}");
+    let name = debug.split(['(', ' ', '{']).next().unwrap_or_default();
+    format!("{name}[{}]", components.len())
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        conversion::string::impl_enum::format_instances::FORMAT_ASCII, enum_narsese::tests::term,
+    };
+    use nar_dev_utils::{asserts, show};
+
+    /// 工具函数：解析
+    fn parse(input: &str) -> Narsese {
+        FORMAT_ASCII.parse(input).unwrap()
+    }
+
+    /// 工具函数：构造词项差异
+    fn term_diff(path: &[UIntPrecision], left: &str, right: &str) -> TermDiff {
+        TermDiff {
+            path: path.to_vec(),
+            left: left.into(),
+            right: right.into(),
+        }
+    }
+
+    #[test]
+    fn test_diff_terms() {
+        let a = term("<(*, A, <B --> C>) ==> <D --> E>>");
+        let b = term("<(*, A, <B --> X>) ==> <D --> E>>");
+        let diffs = diff_terms(&a, &b);
+        show!(display_diffs(&diffs));
+        asserts! {
+            // 相同⇒空
+            diff_terms(&a, &a.clone()) => vec![],
+            // 无序容器、对称陈述按「值」判等
+            diff_terms(&term("{A, B}"), &term("{B, A}")) => vec![],
+            diff_terms(&term("<A <-> B>"), &term("<B <-> A>")) => vec![],
+            // 嵌套陈述中的一个叶子⇒恰好一条
+            diffs => vec![term_diff(&[0, 1, 1], r#"Word("C")"#, r#"Word("X")"#)],
+            display_diffs(&diffs) => r#"[0, 1, 1]: Word("C") ≠ Word("X")"#,
+            // 类型不同⇒停止深入
+            diff_terms(&a, &term("<(*, A, <B --> X>) =/> <D --> E>>")) => vec![term_diff(&[], "Implication[2]", "ImplicationPredictive[2]")],
+            // 组分数不同⇒停止深入
+            diff_terms(&term("(*, A, B)"), &term("(*, A, C, D)")) => vec![term_diff(&[], "Product[2]", "Product[3]")],
+            // 多个分支⇒各自记录
+            diff_terms(&term("<(*, A, B) --> C>"), &term("<(*, X, B) --> Y>")) => vec![
+                term_diff(&[0, 0], r#"Word("A")"#, r#"Word("X")"#),
+                term_diff(&[1], r#"Word("C")"#, r#"Word("Y")"#),
+            ],
+            // 像：占位符位置
+            diff_terms(&term("(/, R, _, A)"), &term("(/, R, A, _)")) => vec![
+                term_diff(&[1], "Placeholder", r#"Word("A")"#),
+                term_diff(&[2], r#"Word("A")"#, "Placeholder"),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_diff_narsese() {
+        let task = parse("$0.5;0.5;0.5$ <A --> B>. :|: %1.0;0.9%");
+        let diffs = diff_narsese(&task, &parse("$0.5;0.5;0.8$ <A --> C>! :!1: %1.0;0.8%"));
+        show!(display_diffs(&diffs));
+        asserts! {
+            diff_narsese(&task, &task.clone()) => vec![],
+            diffs => vec![
+                NarseseDiff::Term(term_diff(&[1], r#"Word("B")"#, r#"Word("C")"#)),
+                NarseseDiff::Punctuation { left: Punctuation::Judgement, right: Punctuation::Goal },
+                NarseseDiff::Stamp { left: Stamp::Present, right: Stamp::Fixed(1) },
+                NarseseDiff::Truth { left: Some(Truth::new_double(1.0, 0.9)), right: Some(Truth::new_double(1.0, 0.8)) },
+                NarseseDiff::Budget { left: Budget::new_triple(0.5, 0.5, 0.5), right: Budget::new_triple(0.5, 0.5, 0.8) },
+            ],
+            // 问题：无真值
+            diff_narsese(&parse("A."), &parse("A?")) => vec![
+                NarseseDiff::Punctuation { left: Punctuation::Judgement, right: Punctuation::Question },
+                NarseseDiff::Truth { left: Some(Truth::Empty), right: None },
+            ],
+            // 类型不同
+            diff_narsese(&parse("A"), &parse("A.")) => vec![NarseseDiff::Kind { left: "词项", right: "语句" }],
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! 词法词项的「原子词项提取」
+//! * 🎯与「枚举Narsese」的原子词项提取对应：收集变量、收集词汇表……
+//! * 🚩「是否为词语/变量」的信息取自[词法Narsese格式](NarseseFormat)
+//!   * 📌比对原子词项的前缀
+//! * 🚩先序遍历：先自身，再按组分顺序
+//!   * 📌陈述：先主词，后谓词
+
+use super::Term;
+use crate::conversion::string::impl_lexical::NarseseFormat;
+
+/// 实现/原子词项提取
+impl Term {
+    /// 遍历/先序
+    fn visit_atoms<'a>(&'a self, f: &mut impl FnMut(&'a Term)) {
+        match self {
+            Term::Atom { .. } => f(self),
+            Term::Compound { terms, .. } | Term::Set { terms, .. } => {
+                terms.iter().for_each(|term| term.visit_atoms(f))
+            }
+            Term::Statement {
+                subject, predicate, ..
+            } => {
+                subject.visit_atoms(f);
+                predicate.visit_atoms(f);
+            }
+        }
+    }
+
+    /// 提取所有原子词项
+    /// * 📌按遍历顺序，不去重
+    /// * 📌包括像占位符、间隔、操作符
+    pub fn atoms(&self) -> Vec<&Term> {
+        let mut atoms = vec![];
+        self.visit_atoms(&mut |term| atoms.push(term));
+        atoms
+    }
+
+    /// 判断自身是否为变量
+    /// * 🚩原子词项的前缀在格式的「变量前缀」中
+    pub fn is_variable(&self, format: &NarseseFormat) -> bool {
+        match self {
+            Term::Atom { prefix, .. } => format.atom.prefix_variables.contains(prefix),
+            _ => false,
+        }
+    }
+
+    /// 提取所有变量
+    /// * 📌按遍历顺序，不去重
+    pub fn variables(&self, format: &NarseseFormat) -> Vec<&Term> {
+        self.atoms()
+            .into_iter()
+            .filter(|term| term.is_variable(format))
+            .collect()
+    }
+
+    /// 提取所有词语的名称
+    /// * 🚩原子词项的前缀为格式的「词语前缀」
+    /// * 📌按遍历顺序，不去重
+    pub fn words(&self, format: &NarseseFormat) -> Vec<&str> {
+        self.atoms()
+            .into_iter()
+            .filter_map(|term| match term {
+                Term::Atom { prefix, name } if *prefix == format.atom.prefix_word => {
+                    Some(name.as_str())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// 判断是否含有变量
+    /// * 📌包括自身
+    pub fn has_variable(&self, format: &NarseseFormat) -> bool {
+        self.atoms()
+            .into_iter()
+            .any(|term| term.is_variable(format))
+    }
+
+    /// 判断是否为「封闭词项」
+    /// * 📌不含任何变量
+    pub fn is_closed(&self, format: &NarseseFormat) -> bool {
+        !self.has_variable(format)
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::GetTerm,
+        conversion::string::impl_lexical::format_instances::{FORMAT_ASCII, FORMAT_HAN},
+        lexical::tests::_sample_task_ascii,
+        lexical_atom,
+    };
+    use nar_dev_utils::asserts;
+
+    #[test]
+    fn test_atoms() {
+        let format = &FORMAT_ASCII;
+        let task = _sample_task_ascii();
+        let term = task.get_term();
+        asserts! {
+            term.atoms().len() => 8,
+            term.variables(format) => [&lexical_atom!("$" "any"), &lexical_atom!("#" "some")],
+            term.atoms().contains(&&lexical_atom!("^" "go-to")),
+            term.words(format) => ["ball", "left", "SELF", "SELF", "good"],
+            term.has_variable(format),
+            !term.is_closed(format),
+        }
+        // 与枚举Narsese的样本一致：含`^do`操作符
+        let term = format
+            .parse_term("<(&/, <{ball} --> [left]>, <(*, {SELF}, $any, #some) --> ^do>) ==> <{SELF} --> [good]>>")
+            .unwrap();
+        asserts! {
+            term.variables(format) => [&lexical_atom!("$" "any"), &lexical_atom!("#" "some")],
+            term.atoms().contains(&&lexical_atom!("^" "do")),
+        }
+        // 变量的判断依格式而定
+        let term = FORMAT_HAN
+            .parse_term("「（积，任一甲，其一乙）是操作丙」")
+            .unwrap();
+        asserts! {
+            term.variables(&FORMAT_HAN) => [&lexical_atom!("任一" "甲"), &lexical_atom!("其一" "乙")],
+            term.words(&FORMAT_HAN) => [] as [&str; 0],
+            term.variables(format) => [] as [&Term; 0],
+            FORMAT_ASCII.parse_term("<A --> B>").unwrap().is_closed(format),
+        }
+    }
+}
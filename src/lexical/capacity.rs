@@ -0,0 +1,178 @@
+//! 词法词项的「容量」
+//! * 🎯在不引入「枚举Narsese」的前提下，获取词项在语义上的容量
+//!   * 📄`(-, A, B)`⇒二元序列、`(&&, A, B)`⇒集合
+//! * 📌与[`GetCapacity`](crate::api::GetCapacity)不同：后者只反映存储结构（复合词项总是序列）
+//! * 🚩连接符、系词的容量取自[词法Narsese格式](NarseseFormat)中的容量表
+//!   * 📌同一连接符在不同格式中可能有不同含义
+
+use super::Term;
+use crate::{api::TermCapacity, conversion::string::impl_lexical::NarseseFormat};
+use nar_dev_utils::PrefixMatch;
+
+/// 实现/容量
+impl Term {
+    /// 基于格式获取词项容量
+    /// * 🚩原子词项⇒原子
+    /// * 🚩词项集⇒集合（仅限格式中存在的括弧）
+    /// * 🚩复合词项、陈述⇒查询格式中的容量表
+    /// * 📌未知的连接符、系词、括弧⇒[`None`]
+    pub fn capacity_with(&self, format: &NarseseFormat) -> Option<TermCapacity> {
+        match self {
+            Term::Atom { .. } => Some(TermCapacity::Atom),
+            Term::Set {
+                left_bracket,
+                right_bracket,
+                ..
+            } => format
+                .compound
+                .set_brackets
+                .prefix_terms()
+                .any(|(left, right)| left == left_bracket && right == right_bracket)
+                .then_some(TermCapacity::Set),
+            Term::Compound { connecter, .. } => format.connecter_capacity(connecter),
+            Term::Statement { copula, .. } => format.copula_capacity(copula),
+        }
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{GetCategory, TermCategory},
+        conversion::string::impl_lexical::format_instances::*,
+    };
+    use nar_dev_utils::asserts;
+    use TermCapacity::*;
+
+    /// 工具函数：解析词项
+    fn term(format: &NarseseFormat, input: &str) -> Term {
+        format.parse(input).unwrap().try_into_term().unwrap()
+    }
+
+    /// 工具函数：解析词项并获取容量
+    fn capacity(input: &str) -> Option<TermCapacity> {
+        term(&FORMAT_ASCII, input).capacity_with(&FORMAT_ASCII)
+    }
+
+    #[test]
+    fn test_capacity_ascii() {
+        asserts! {
+            // 原子
+            capacity("A") => Some(Atom),
+            capacity("$A") => Some(Atom),
+            capacity("_") => Some(Atom),
+            // 词项集
+            capacity("{A, B}") => Some(Set),
+            capacity("[A, B]") => Some(Set),
+            // 复合词项
+            capacity("(&, A, B)") => Some(Set),
+            capacity("(|, A, B)") => Some(Set),
+            capacity("(-, A, B)") => Some(BinaryVec),
+            capacity("(~, A, B)") => Some(BinaryVec),
+            capacity("(*, A, B)") => Some(Vec),
+            capacity(r"(/, R, _, A)") => Some(Vec),
+            capacity(r"(\, R, _, A)") => Some(Vec),
+            capacity("(&&, A, B)") => Some(Set),
+            capacity("(||, A, B)") => Some(Set),
+            capacity("(--, A)") => Some(Unary),
+            capacity("(&/, A, B)") => Some(Vec),
+            capacity("(&|, A, B)") => Some(Set),
+            // 陈述
+            capacity("<A --> B>") => Some(BinaryVec),
+            capacity("<A <-> B>") => Some(BinarySet),
+            capacity("<A ==> B>") => Some(BinaryVec),
+            capacity("<A <=> B>") => Some(BinarySet),
+            capacity("<A {-- B>") => Some(BinaryVec),
+            capacity("<A --] B>") => Some(BinaryVec),
+            capacity("<A {-] B>") => Some(BinaryVec),
+            capacity("<A =/> B>") => Some(BinaryVec),
+            capacity("<A =|> B>") => Some(BinaryVec),
+            capacity(r"<A =\> B>") => Some(BinaryVec),
+            capacity("<A </> B>") => Some(BinaryVec),
+            capacity("<A <|> B>") => Some(BinarySet),
+            capacity(r"<A <\> B>") => Some(BinaryVec),
+        }
+    }
+
+    /// 未知符号⇒[`None`]
+    #[test]
+    fn test_capacity_unknown() {
+        let a = || crate::lexical_atom!("A");
+        asserts! {
+            crate::lexical_compound!("&&&"; a() a()).capacity_with(&FORMAT_ASCII) => None,
+            crate::lexical_statement!(a() "-?>" a()).capacity_with(&FORMAT_ASCII) => None,
+            crate::lexical_set!("<"; a(); ">").capacity_with(&FORMAT_ASCII) => None,
+            // ASCII连接符在漢文格式中未知
+            term(&FORMAT_ASCII, "(&&, A, B)").capacity_with(&FORMAT_HAN) => None,
+        }
+        // 自定义方言：移除的连接符不再有容量
+        let format = FORMAT_ASCII
+            .builder()
+            .connecters(["&&", "--"])
+            .commutative_connecters(["&&"])
+            .connecter_conjunction_sequential("&&")
+            .connecter_images("&&", "--")
+            .build()
+            .unwrap();
+        asserts! {
+            format.connecter_capacity("&&") => Some(Set),
+            format.connecter_capacity("--") => Some(Unary),
+            format.connecter_capacity("*") => None,
+        }
+    }
+
+    /// 漢文、LaTeX格式的连接符与ASCII一一对应
+    #[test]
+    fn test_capacity_consistent_across_formats() {
+        // ASCII：每个连接符都有容量
+        for connecter in FORMAT_ASCII.compound.connecters.iter_x_fixes() {
+            assert!(FORMAT_ASCII.connecter_capacity(connecter).is_some());
+        }
+        for format in [&*FORMAT_HAN, &*FORMAT_LATEX] {
+            // 连接符、系词按定义顺序一一对应
+            for (table, ascii_table) in [
+                (
+                    &format.compound.connecter_capacities,
+                    &FORMAT_ASCII.compound.connecter_capacities,
+                ),
+                (
+                    &format.statement.copula_capacities,
+                    &FORMAT_ASCII.statement.copula_capacities,
+                ),
+            ] {
+                let capacities = table.iter().map(|(_, capacity)| *capacity);
+                let ascii_capacities = ascii_table.iter().map(|(_, capacity)| *capacity);
+                assert!(capacities.eq(ascii_capacities));
+            }
+            // 表中的符号均为格式中的连接符
+            for (connecter, _) in &format.compound.connecter_capacities {
+                assert!(format.compound.connecters.has(connecter));
+            }
+        }
+        // 漢文词项与对应ASCII词项容量相同、类别相同
+        for (ascii_input, han_input) in [
+            ("(--, A)", "（非，A）"),
+            ("(-, A, B)", "（外差，A，B）"),
+            ("(&&, A, B)", "（与，A，B）"),
+            ("(*, A, B)", "（积，A，B）"),
+            ("<A <-> B>", "「A似B」"),
+            ("<A ==> B>", "「A得B」"),
+        ] {
+            let (ascii_term, han_term) = (
+                term(&FORMAT_ASCII, ascii_input),
+                term(&FORMAT_HAN, han_input),
+            );
+            assert_eq!(
+                ascii_term.capacity_with(&FORMAT_ASCII),
+                han_term.capacity_with(&FORMAT_HAN)
+            );
+            assert_eq!(ascii_term.get_category(), han_term.get_category());
+        }
+        assert_eq!(
+            term(&FORMAT_HAN, "「A是B」").get_category(),
+            TermCategory::Statement
+        );
+    }
+}
@@ -0,0 +1,186 @@
+//! 词法词项的「组分拆分与重建」
+//! * 🎯与[`ExtractTerms`](crate::api::ExtractTerms)对应的借用版本，以及可逆的「拆分⇒重建」
+//!   * 📄对所有组分做变换后，按原结构重建词项
+//! * 📌组分顺序与[`ExtractTerms`](crate::api::ExtractTerms)一致
+//!   * 📌原子词项⇒自身
+//!   * 📌复合词项、集合⇒其中组分
+//!   * 📌陈述⇒先主词、后谓词
+
+use super::Term;
+
+/// 词项的「结构头」
+/// * 🎯拆分词项时，保存「除组分以外」的结构信息
+///   * 📌原子词项：前缀、名称
+///   * 📌复合词项：连接符
+///   * 📌集合：左右括弧
+///   * 📌陈述：系词
+/// * 🚩配合[`Term::into_components`]与[`Term::rebuild`]使用
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TermHead {
+    /// 原子词项：前缀+名称
+    Atom { prefix: String, name: String },
+    /// 复合词项：连接符
+    Compound { connecter: String },
+    /// 集合：左右括弧
+    Set {
+        left_bracket: String,
+        right_bracket: String,
+    },
+    /// 陈述：系词
+    Statement { copula: String },
+}
+
+/// 实现/组分
+impl Term {
+    /// 获取组分（借用）
+    /// * 📌原子词项⇒自身
+    /// * 📄与[`ExtractTerms::extract_terms`](crate::api::ExtractTerms::extract_terms)对应
+    pub fn components(&self) -> Vec<&Term> {
+        match self {
+            Term::Atom { .. } => vec![self],
+            Term::Compound { terms, .. } | Term::Set { terms, .. } => terms.iter().collect(),
+            Term::Statement {
+                subject, predicate, ..
+            } => vec![subject, predicate],
+        }
+    }
+
+    /// 获取组分（可变借用）
+    /// * 🎯原地修改组分
+    /// * 📌原子词项⇒自身
+    pub fn components_mut(&mut self) -> Vec<&mut Term> {
+        match self {
+            Term::Atom { .. } => vec![self],
+            Term::Compound { terms, .. } | Term::Set { terms, .. } => terms.iter_mut().collect(),
+            Term::Statement {
+                subject, predicate, ..
+            } => vec![subject, predicate],
+        }
+    }
+
+    /// 拆分为「结构头」与组分
+    /// * 📌原子词项⇒没有组分：其信息全部在「结构头」中
+    /// * 📄逆操作：[`Term::rebuild`]
+    pub fn into_components(self) -> (TermHead, Vec<Term>) {
+        match self {
+            Term::Atom { prefix, name } => (TermHead::Atom { prefix, name }, vec![]),
+            Term::Compound { connecter, terms } => (TermHead::Compound { connecter }, terms),
+            Term::Set {
+                left_bracket,
+                terms,
+                right_bracket,
+            } => (
+                TermHead::Set {
+                    left_bracket,
+                    right_bracket,
+                },
+                terms,
+            ),
+            Term::Statement {
+                copula,
+                subject,
+                predicate,
+            } => (TermHead::Statement { copula }, vec![*subject, *predicate]),
+        }
+    }
+
+    /// 从「结构头」与组分重建词项
+    /// * 📄[`Term::into_components`]的逆操作
+    /// * ⚠️组分数目与结构不符⇒[`None`]
+    ///   * 📌原子词项：不能有组分
+    ///   * 📌陈述：须恰好两个组分
+    pub fn rebuild(head: TermHead, components: Vec<Term>) -> Option<Term> {
+        let term = match head {
+            TermHead::Atom { prefix, name } => match components.is_empty() {
+                true => Term::Atom { prefix, name },
+                false => return None,
+            },
+            TermHead::Compound { connecter } => Term::Compound {
+                connecter,
+                terms: components,
+            },
+            TermHead::Set {
+                left_bracket,
+                right_bracket,
+            } => Term::Set {
+                left_bracket,
+                terms: components,
+                right_bracket,
+            },
+            TermHead::Statement { copula } => {
+                let [subject, predicate]: [Term; 2] = components.try_into().ok()?;
+                Term::new_statement(copula, subject, predicate)
+            }
+        };
+        Some(term)
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{ExtractTerms, GetTerm},
+        lexical::{shortcuts::*, tests::_sample_task_ascii, Term},
+    };
+    use nar_dev_utils::asserts;
+
+    /// 测试/拆分与重建
+    /// * 🚩样例任务中的所有子词项：拆分⇒重建后不变，且组分与[`ExtractTerms`]一致
+    #[test]
+    fn test_into_components_rebuild() {
+        let task = _sample_task_ascii();
+        for term in task.get_term().iter_dfs() {
+            let components = term.components().into_iter().cloned().collect::<Vec<_>>();
+            let (head, children) = term.clone().into_components();
+            // 借用版本与消耗版本一致 | 原子词项的组分为自身
+            match term {
+                Term::Atom { .. } => assert!(children.is_empty()),
+                _ => assert_eq!(children, components),
+            }
+            assert_eq!(components, term.clone().extract_terms_to_vec());
+            // 重建
+            assert_eq!(Term::rebuild(head, children).as_ref(), Some(term));
+        }
+    }
+
+    /// 测试/变换组分后重建
+    #[test]
+    fn test_rebuild_transformed() {
+        let term = statement!(compound!("&&", atom!("A"), atom!("B")) "-->" set!("{"; "C"; "}"));
+        let (head, children) = term.into_components();
+        // 每个组分只保留其首个组分
+        let children = children
+            .into_iter()
+            .map(|child| {
+                let (head, mut terms) = child.into_components();
+                terms.truncate(1);
+                Term::rebuild(head, terms).unwrap()
+            })
+            .collect();
+        asserts! {
+            Term::rebuild(head, children) => Some(statement!(compound!("&&", atom!("A")) "-->" set!("{"; "C"; "}"))),
+            // 组分数目不符
+            Term::rebuild(TermHead::Statement { copula: "-->".into() }, vec![atom!("A")]) => None,
+            Term::rebuild(TermHead::Atom { prefix: "".into(), name: "A".into() }, vec![atom!("A")]) => None,
+        }
+    }
+
+    /// 测试/原地修改组分
+    #[test]
+    fn test_components_mut() {
+        let mut term = compound!("*", atom!("A"), atom!("B"));
+        for component in term.components_mut() {
+            *component = set!("{"; component.clone(); "}");
+        }
+        let mut atom = atom!("A");
+        for component in atom.components_mut() {
+            *component = atom!("$" "A");
+        }
+        asserts! {
+            term => compound!("*", set!("{"; atom!("A"); "}"), set!("{"; atom!("B"); "}")),
+            atom => atom!("$" "A"),
+        }
+    }
+}
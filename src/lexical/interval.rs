@@ -0,0 +1,142 @@
+//! 词法词项中的「间隔」处理
+//! * 🎯时序推理中合并连续的间隔
+//!   * 📄`(&/, A, +3, +5, B)` ⇒ `(&/, A, +8, B)`
+//! * 🚩「间隔前缀」「顺序合取连接符」的信息取自[词法Narsese格式](NarseseFormat)
+//! * 🚩仅处理「顺序合取」中相邻的间隔；其它复合词项原样保留
+//!   * 📌名称不是数值的间隔不参与合并
+//!   * 📌数值相加时饱和，不会溢出
+
+use super::Term;
+use crate::{api::UIntPrecision, conversion::string::impl_lexical::NarseseFormat};
+
+/// 实现/间隔
+impl Term {
+    /// 作为间隔解析出数值
+    /// * 🚩原子词项的前缀为格式的「间隔前缀」，且名称可解析为数值
+    /// * 📌其它情况⇒[`None`]
+    pub fn as_interval(&self, format: &NarseseFormat) -> Option<UIntPrecision> {
+        match self {
+            Term::Atom { prefix, name } if *prefix == format.atom.prefix_interval => {
+                name.parse().ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// 合并顺序合取中相邻的间隔
+    /// * 🚩递归处理所有子词项
+    /// * 📌全为间隔的顺序合取⇒合并为仅含一个间隔的顺序合取
+    /// * 📌返回新词项，不修改自身
+    pub fn merge_adjacent_intervals(&self, format: &NarseseFormat) -> Term {
+        let merge_all = |terms: &[Term]| {
+            terms
+                .iter()
+                .map(|term| term.merge_adjacent_intervals(format))
+                .collect::<Vec<_>>()
+        };
+        match self {
+            Term::Atom { .. } => self.clone(),
+            Term::Compound { connecter, terms } => {
+                let terms = merge_all(terms);
+                Term::Compound {
+                    connecter: connecter.clone(),
+                    terms: match *connecter == format.compound.connecter_conjunction_sequential {
+                        true => merge_intervals(terms, format),
+                        false => terms,
+                    },
+                }
+            }
+            Term::Set {
+                left_bracket,
+                terms,
+                right_bracket,
+            } => Term::Set {
+                left_bracket: left_bracket.clone(),
+                terms: merge_all(terms),
+                right_bracket: right_bracket.clone(),
+            },
+            Term::Statement {
+                copula,
+                subject,
+                predicate,
+            } => Term::new_statement(
+                copula,
+                subject.merge_adjacent_intervals(format),
+                predicate.merge_adjacent_intervals(format),
+            ),
+        }
+    }
+}
+
+/// 合并序列中相邻的间隔
+fn merge_intervals(terms: Vec<Term>, format: &NarseseFormat) -> Vec<Term> {
+    let mut merged: Vec<Term> = Vec::with_capacity(terms.len());
+    for term in terms {
+        let last = merged.last().and_then(|last| last.as_interval(format));
+        match (last, term.as_interval(format)) {
+            (Some(last), Some(interval)) => {
+                let sum = last.saturating_add(interval);
+                *merged.last_mut().unwrap() =
+                    Term::new_atom(&format.atom.prefix_interval, sum.to_string());
+            }
+            _ => merged.push(term),
+        }
+    }
+    merged
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        conversion::string::impl_lexical::format_instances::{FORMAT_ASCII, FORMAT_HAN},
+        lexical_atom,
+    };
+    use nar_dev_utils::asserts;
+
+    #[test]
+    fn test_as_interval() {
+        let format = &FORMAT_ASCII;
+        asserts! {
+            lexical_atom!("+" "3").as_interval(format) => Some(3),
+            lexical_atom!("+" "x").as_interval(format) => None,
+            lexical_atom!("" "3").as_interval(format) => None,
+            format.parse_term("(&/, +3)").unwrap().as_interval(format) => None,
+            // 前缀依格式而定
+            lexical_atom!("间隔" "3").as_interval(&FORMAT_HAN) => Some(3),
+            lexical_atom!("间隔" "3").as_interval(format) => None,
+        }
+    }
+
+    #[test]
+    fn test_merge_adjacent_intervals() {
+        let format = &FORMAT_ASCII;
+        let term = |input| format.parse_term(input).unwrap();
+        let merge = |input| term(input).merge_adjacent_intervals(format);
+        asserts! {
+            // 相邻
+            merge("(&/, A, +3, +5, B)") => term("(&/, A, +8, B)"),
+            merge("(&/, +1, +2, A, +3, +4)") => term("(&/, +3, A, +7)"),
+            // 不相邻
+            merge("(&/, +3, A, +5, B)") => term("(&/, +3, A, +5, B)"),
+            // 全为间隔
+            merge("(&/, +1, +2, +3)") => term("(&/, +6)"),
+            // 嵌套
+            merge("<(&/, +1, +1, <A --> B>) =/> {(&/, C, +2, +2)}>") => term("<(&/, +2, <A --> B>) =/> {(&/, C, +4)}>"),
+            // 非顺序合取⇒不变
+            merge("(*, A, +3, +5)") => term("(*, A, +3, +5)"),
+            merge("(&|, +3, +5)") => term("(&|, +3, +5)"),
+            merge("+3") => term("+3"),
+            // 非数值的间隔不参与合并
+            Term::new_compound("&/", vec![lexical_atom!("+" "x"), lexical_atom!("+" "1")]).merge_adjacent_intervals(format)
+                => Term::new_compound("&/", vec![lexical_atom!("+" "x"), lexical_atom!("+" "1")]),
+        }
+        // 连接符依格式而定
+        let han = |input| FORMAT_HAN.parse_term(input).unwrap();
+        asserts! {
+            han("（接连，甲，间隔1，间隔2）").merge_adjacent_intervals(&FORMAT_HAN) => han("（接连，甲，间隔3）"),
+            term("(&/, +1, +2)").merge_adjacent_intervals(&FORMAT_HAN) => term("(&/, +1, +2)"),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! 词法词项的「子词项迭代器」
+//! * 🎯与「枚举Narsese」的子词项迭代器对应，惰性迭代所有子词项（含自身）
+//! * 🚩使用显式的栈/队列，可借助[`Iterator::find`]等方法提前终止
+//! * 📌组分顺序：按原样顺序（词法上不区分有序无序）
+//!   * 📌陈述：先主词、后谓词
+//!   * 📌像占位符在词法上即原子词项，自然会被迭代到
+
+use super::Term;
+use std::collections::VecDeque;
+
+/// 工具函数：获取词项的直接组分
+/// * 📌原子词项⇒没有组分
+#[inline]
+fn children(term: &Term) -> Vec<&Term> {
+    match term {
+        Term::Atom { .. } => vec![],
+        Term::Compound { terms, .. } | Term::Set { terms, .. } => terms.iter().collect(),
+        Term::Statement {
+            subject, predicate, ..
+        } => vec![subject, predicate],
+    }
+}
+
+/// 子词项迭代器/深度优先
+/// * 🚩先序：先自身、再按顺序迭代各组分
+pub struct TermIterDfs<'a> {
+    stack: Vec<&'a Term>,
+}
+
+impl<'a> Iterator for TermIterDfs<'a> {
+    type Item = &'a Term;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let term = self.stack.pop()?;
+        // 逆序入栈，以便按原顺序出栈
+        self.stack.extend(children(term).into_iter().rev());
+        Some(term)
+    }
+}
+
+/// 子词项迭代器/广度优先
+/// * 🚩逐层：先自身、再所有直接组分、再所有组分的组分……
+pub struct TermIterBfs<'a> {
+    queue: VecDeque<&'a Term>,
+}
+
+impl<'a> Iterator for TermIterBfs<'a> {
+    type Item = &'a Term;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let term = self.queue.pop_front()?;
+        self.queue.extend(children(term));
+        Some(term)
+    }
+}
+
+/// 实现/子词项迭代
+impl Term {
+    /// 深度优先迭代所有子词项
+    /// * 📌包括自身
+    pub fn iter_dfs(&self) -> TermIterDfs<'_> {
+        TermIterDfs { stack: vec![self] }
+    }
+
+    /// 广度优先迭代所有子词项
+    /// * 📌包括自身
+    pub fn iter_bfs(&self) -> TermIterBfs<'_> {
+        TermIterBfs {
+            queue: VecDeque::from([self]),
+        }
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{GetCategory, GetComplexity},
+        conversion::string::impl_lexical::format_instances::FORMAT_ASCII,
+        lexical::term::tests::generate_term_testset,
+    };
+    use nar_dev_utils::asserts;
+
+    /// 工具函数：以ASCII格式解析一串词项
+    fn terms(inputs: &[&str]) -> Vec<Term> {
+        inputs
+            .iter()
+            .map(|input| FORMAT_ASCII.parse_term(input).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_iter_order() {
+        let statement = FORMAT_ASCII
+            .parse_term(r"<(*, A, {B, C}) --> [D]>")
+            .unwrap();
+        let dfs = statement.iter_dfs().cloned().collect::<Vec<_>>();
+        let bfs = statement.iter_bfs().cloned().collect::<Vec<_>>();
+        asserts! {
+            dfs => terms(&[r"<(*, A, {B, C}) --> [D]>", "(*, A, {B, C})", "A", "{B, C}", "B", "C", "[D]", "D"]),
+            bfs => terms(&[r"<(*, A, {B, C}) --> [D]>", "(*, A, {B, C})", "[D]", "A", "{B, C}", "D", "B", "C"]),
+        }
+    }
+
+    /// 与「复杂度」一致：迭代出的原子词项个数即复杂度
+    #[test]
+    fn test_iter_complexity() {
+        for term in generate_term_testset() {
+            let atoms = term.iter_dfs().filter(|t| t.is_atom()).count();
+            asserts! {
+                // 空复合词项：没有原子，但复杂度计为1
+                atoms.max(1) => term.complexity(),
+                term.iter_bfs().filter(|t| t.is_atom()).count() => atoms,
+                term.iter_dfs().count() => term.iter_bfs().count(),
+            }
+        }
+    }
+}
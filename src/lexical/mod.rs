@@ -26,12 +26,46 @@ pub use sentence::*;
 mod task;
 pub use task::*;
 
+// 时间戳的结构化视图
+mod stamp_kind;
+pub use stamp_kind::*;
+
+// 规范化 | 直接对「词项」实现方法，无需导出
+mod normalize;
+
+// 基于格式的词项容量 | 直接对「词项」实现方法，无需导出
+mod capacity;
+
+// 路径寻址 | 直接对「词项」实现方法，无需导出
+mod path;
+
+// 原子词项提取 | 直接对「词项」实现方法，无需导出
+mod atoms;
+
+// 间隔识别与合并 | 直接对「词项」实现方法，无需导出
+mod interval;
+
+// 子词项迭代器
+mod iter;
+pub use iter::*;
+
+// 组分拆分与重建
+mod components;
+pub use components::*;
+
+// 数值化的词法Narsese
+mod numeric;
+pub use numeric::*;
+
 // 统合部分
 
 /// 用于归并表示「词法上的Narsese」
 /// * 🚩现在使用更抽象的「Narsese值」取代
 pub type Narsese = NarseseValue<Term, Sentence, Task>;
 
+// 实现与「Narsese值」间的`(try_)From/Into`转换
+crate::api::impl_narsese_value_conversions!(Term, Sentence, Task);
+
 /// 快捷方式：用于快速构建「词法Narsese」
 /// * ⚠️不建议直接导出其中的符号，而是通过`lexical::shortcuts`引入
 pub mod shortcuts {
@@ -59,6 +93,14 @@ pub(crate) mod tests {
     use crate::lexical::shortcuts::*;
     use nar_dev_utils::*;
 
+    /// （通用）以ASCII格式解析词项
+    /// * 🎯供各模块的单元测试共用
+    /// * ⚠️解析失败⇒panic
+    pub(crate) fn term(input: &str) -> Term {
+        use crate::conversion::string::impl_lexical::format_instances::FORMAT_ASCII;
+        FORMAT_ASCII.parse_term(input).unwrap()
+    }
+
     /// （通用）构造一个格式化样本（ASCII字面量版本）
     /// * 基本涵盖其所属模块的全部内容
     /// * 📌格式稳定版：基本所有其它格式以此为参照
@@ -131,4 +173,32 @@ pub(crate) mod tests {
         let task = _sample_task_ascii_0();
         show!(task);
     }
+
+    /// 测试/与「Narsese值」间的相互转换
+    #[test]
+    fn test_narsese_value_conversions() {
+        use crate::api::NarseseCastError;
+        let task = _sample_task_ascii();
+        let sentence = task.sentence.clone();
+        let term = sentence.term.clone();
+        let [n_term, n_sentence, n_task]: [Narsese; 3] = [
+            term.clone().into(),
+            sentence.clone().into(),
+            task.clone().into(),
+        ];
+        asserts! {
+            // 具体类型→Narsese值
+            n_term => Narsese::from_term(term.clone()),
+            n_sentence => Narsese::from_sentence(sentence.clone()),
+            n_task => Narsese::from_task(task.clone()),
+            // Narsese值→具体类型
+            Term::try_from(n_term.clone()) => Ok(term),
+            Sentence::try_from(n_sentence.clone()) => Ok(sentence),
+            Task::try_from(n_task.clone()) => Ok(task),
+            // 类型不匹配
+            Term::try_from(n_sentence) => Err(NarseseCastError::new("语句", "词项")),
+            Sentence::try_from(n_task) => Err(NarseseCastError::new("任务", "语句")),
+            Task::try_from(n_term) => Err(NarseseCastError::new("词项", "任务")),
+        }
+    }
 }
@@ -0,0 +1,140 @@
+//! 词法词项的「规范化」
+//! * 🎯在不引入「枚举Narsese」的前提下，比对「语义上相同」的词法词项
+//!   * 📄`(&&, A, B)`与`(&&, B, A)`
+//!   * 📄`<A <-> B>`与`<B <-> A>`
+//! * 🚩「是否可交换/对称」的信息取自[词法Narsese格式](NarseseFormat)
+//!   * 📌词法Narsese本身不考虑语义：同一连接符在不同格式中可能有不同含义
+//! * 🚩排序依据：组分按该格式格式化后的字符串
+//! * ⚠️有序复合词项（乘积、顺序合取、像等）原样保留
+
+use super::Term;
+use crate::conversion::string::impl_lexical::NarseseFormat;
+
+/// 实现/规范化
+impl Term {
+    /// 规范化词项
+    /// * 🚩递归处理所有组分
+    /// * 🚩满足交换律的复合词项、词项集：组分排序并去重
+    /// * 🚩对称陈述：主词、谓词排序
+    /// * 📌返回新词项，不修改自身
+    pub fn normalize(&self, format: &NarseseFormat) -> Term {
+        match self {
+            Term::Atom { .. } => self.clone(),
+            Term::Compound { connecter, terms } => {
+                let terms = normalize_terms(terms, format);
+                Term::Compound {
+                    connecter: connecter.clone(),
+                    terms: match format.is_commutative_connecter(connecter) {
+                        true => sort_dedup(terms, format),
+                        false => terms,
+                    },
+                }
+            }
+            // 词项集本身即无序不重复
+            Term::Set {
+                left_bracket,
+                terms,
+                right_bracket,
+            } => Term::Set {
+                left_bracket: left_bracket.clone(),
+                terms: sort_dedup(normalize_terms(terms, format), format),
+                right_bracket: right_bracket.clone(),
+            },
+            Term::Statement {
+                copula,
+                subject,
+                predicate,
+            } => {
+                let mut subject = subject.normalize(format);
+                let mut predicate = predicate.normalize(format);
+                if format.is_symmetric_copula(copula)
+                    && format.format_term(&subject) > format.format_term(&predicate)
+                {
+                    std::mem::swap(&mut subject, &mut predicate);
+                }
+                Term::new_statement(copula, subject, predicate)
+            }
+        }
+    }
+
+    /// 判断两个词项「语义上相同」
+    /// * 🚩双方规范化后比较
+    pub fn eq_semantic(&self, other: &Term, format: &NarseseFormat) -> bool {
+        self.normalize(format) == other.normalize(format)
+    }
+}
+
+/// 规范化所有组分
+fn normalize_terms(terms: &[Term], format: &NarseseFormat) -> Vec<Term> {
+    terms.iter().map(|term| term.normalize(format)).collect()
+}
+
+/// 按「格式化后的字符串」排序，并去除完全相同的组分
+fn sort_dedup(terms: Vec<Term>, format: &NarseseFormat) -> Vec<Term> {
+    let mut keyed = terms
+        .into_iter()
+        .map(|term| (format.format_term(&term), term))
+        .collect::<Vec<_>>();
+    keyed.sort_by(|(a, _), (b, _)| a.cmp(b));
+    keyed.dedup_by(|(a, _), (b, _)| a == b);
+    keyed.into_iter().map(|(_, term)| term).collect()
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use crate::{
+        conversion::string::impl_lexical::format_instances::{FORMAT_ASCII, FORMAT_HAN},
+        lexical::tests::term,
+    };
+    use nar_dev_utils::asserts;
+
+    /// 工具函数：以ASCII格式判断语义相等
+    fn eq(a: &str, b: &str) -> bool {
+        term(a).eq_semantic(&term(b), &FORMAT_ASCII)
+    }
+
+    #[test]
+    fn test_eq_semantic() {
+        asserts! {
+            // 可交换⇒相等
+            eq("(&&, A, B)", "(&&, B, A)"),
+            eq("(||, A, B, C)", "(||, C, A, B)"),
+            eq("(&|, A, B)", "(&|, B, A)"),
+            eq("(&, A, B)", "(&, B, A)"),
+            eq("{A, B}", "{B, A}"),
+            // 有序⇒不等
+            !eq("(*, A, B)", "(*, B, A)"),
+            !eq("(&/, A, B)", "(&/, B, A)"),
+            !eq(r"(/, R, _, A)", r"(/, R, A, _)"),
+            !eq("(-, A, B)", "(-, B, A)"),
+            // 对称⇒相等
+            eq("<A <-> B>", "<B <-> A>"),
+            eq("<A <=> B>", "<B <=> A>"),
+            // 不对称⇒不等
+            !eq("<A --> B>", "<B --> A>"),
+            !eq("<A ==> B>", "<B ==> A>"),
+            // 递归
+            eq("<(&&, A, B) ==> {C, D}>", "<(&&, B, A) ==> {D, C}>"),
+            eq("(*, <A <-> B>)", "(*, <B <-> A>)"),
+        }
+    }
+
+    #[test]
+    fn test_normalize() {
+        asserts! {
+            // 去重
+            term("(&&, B, A, B)").normalize(&FORMAT_ASCII) => term("(&&, A, B)"),
+            term("{A, A}").normalize(&FORMAT_ASCII) => term("{A}"),
+            // 有序复合词项原样保留（含重复）
+            term("(*, B, A, B)").normalize(&FORMAT_ASCII) => term("(*, B, A, B)"),
+            // 幂等
+            term("(&&, C, (||, B, A))").normalize(&FORMAT_ASCII).normalize(&FORMAT_ASCII)
+                => term("(&&, C, (||, B, A))").normalize(&FORMAT_ASCII),
+        }
+        // 其它格式
+        let han = |input| FORMAT_HAN.parse_term(input).unwrap();
+        assert!(han("（与，甲，乙）").eq_semantic(&han("（与，乙，甲）"), &FORMAT_HAN));
+        assert!(!han("（积，甲，乙）").eq_semantic(&han("（积，乙，甲）"), &FORMAT_HAN));
+    }
+}
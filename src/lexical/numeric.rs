@@ -0,0 +1,77 @@
+//! 「数值化」的词法Narsese
+//! * 🎯下游无需再自行解析真值、预算值、时间戳中的数值
+//!   * 📄`%1.0;0.9%` ⇒ `[1.0, 0.9]`
+//! * 📌词项、标点仍为词法形式：不涉及语义
+//! * 🚩由[词法Narsese格式](crate::conversion::string::impl_lexical::NarseseFormat::parse_numeric)解析得到
+
+use super::{Punctuation, StampKind, Term};
+use crate::api::{FloatPrecision, GetPunctuation, GetStamp, GetTerm, NarseseValue};
+
+/// 数值化的「语句」
+/// * 📌真值：已检验的数值数组
+///   * 📌空数组⇒无真值
+/// * 📌时间戳：[结构化视图](StampKind)
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumericSentence {
+    /// 词法词项
+    pub term: Term,
+    /// 标点（字符串）
+    pub punctuation: Punctuation,
+    /// 时间戳（结构化）
+    pub stamp: StampKind,
+    /// 真值（数值）
+    pub truth: Vec<FloatPrecision>,
+}
+
+/// 数值化的「任务」
+/// * 📌预算值：已检验的数值数组
+///   * 📌空数组⇒无预算
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumericTask {
+    /// 预算值（数值）
+    pub budget: Vec<FloatPrecision>,
+    /// 数值化的语句
+    pub sentence: NumericSentence,
+}
+
+/// 数值化的「词法Narsese」
+/// * 📌与[`Narsese`](super::Narsese)形状相同
+pub type NumericNarsese = NarseseValue<Term, NumericSentence, NumericTask>;
+
+// 实现/属性 //
+
+impl GetTerm<Term> for NumericSentence {
+    fn get_term(&self) -> &Term {
+        &self.term
+    }
+}
+
+impl GetPunctuation<Punctuation> for NumericSentence {
+    fn get_punctuation(&self) -> &Punctuation {
+        &self.punctuation
+    }
+}
+
+impl GetStamp<StampKind> for NumericSentence {
+    fn get_stamp(&self) -> &StampKind {
+        &self.stamp
+    }
+}
+
+impl GetTerm<Term> for NumericTask {
+    fn get_term(&self) -> &Term {
+        &self.sentence.term
+    }
+}
+
+impl GetPunctuation<Punctuation> for NumericTask {
+    fn get_punctuation(&self) -> &Punctuation {
+        &self.sentence.punctuation
+    }
+}
+
+impl GetStamp<StampKind> for NumericTask {
+    fn get_stamp(&self) -> &StampKind {
+        &self.sentence.stamp
+    }
+}
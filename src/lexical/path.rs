@@ -0,0 +1,55 @@
+//! 词法词项的「路径寻址」
+//! * 🎯与「枚举Narsese」的路径寻址对应，为词法词项树中的子词项提供「地址」
+//! * 📌路径中的每个索引，对应当前词项「组分」中的位置
+//!   * 📌复合词项、词项集：按原样顺序（词法上不区分有序无序）
+//!   * 📌陈述：主词为`0`，谓词为`1`
+//!   * 📌原子词项：没有组分
+
+use super::Term;
+use crate::api::UIntPrecision;
+
+/// 实现/路径寻址
+impl Term {
+    /// 按路径获取子词项
+    /// * 📌空路径⇒自身
+    /// * ⚠️路径无效⇒[`None`]
+    pub fn get_at(&self, path: &[UIntPrecision]) -> Option<&Term> {
+        let Some((&index, rest)) = path.split_first() else {
+            return Some(self);
+        };
+        let component = match self {
+            Term::Atom { .. } => None,
+            Term::Compound { terms, .. } | Term::Set { terms, .. } => terms.get(index),
+            Term::Statement {
+                subject, predicate, ..
+            } => match index {
+                0 => Some(subject.as_ref()),
+                1 => Some(predicate.as_ref()),
+                _ => None,
+            },
+        }?;
+        component.get_at(rest)
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use crate::lexical::tests::term;
+    use nar_dev_utils::asserts;
+
+    #[test]
+    fn test_get_at() {
+        let statement = term("<<A --> (*, B, {C})> ==> D>");
+        asserts! {
+            statement.get_at(&[]) => Some(&statement),
+            statement.get_at(&[0, 1]) => Some(&term("(*, B, {C})")),
+            statement.get_at(&[0, 1, 1, 0]) => Some(&term("C")),
+            statement.get_at(&[1]) => Some(&term("D")),
+            statement.get_at(&[2]) => None,
+            statement.get_at(&[1, 0]) => None,
+            // 像占位符在词法上是原子词项
+            term(r"(/, R, _, A)").get_at(&[1]) => Some(&term("_")),
+        }
+    }
+}
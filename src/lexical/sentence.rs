@@ -1,14 +1,21 @@
 use super::Term;
-use crate::api::{GetPunctuation, GetStamp, GetTerm, GetTruth};
+use crate::{
+    api::{DroppedSentenceParts, GetPunctuation, GetStamp, GetTerm, GetTruth, SplitSentence},
+    conversion::string::impl_lexical::NarseseFormat,
+};
 
 /// 独立出来的「真值」类型
 /// * 🚩实际上是「字符串数组」的别名
 /// * ✅对「作为数据结构的真值」的最大适配
 ///   * 📄空真值、单真值、双真值…
+/// * 📌空真值（[`Vec::is_empty`]）⇒无真值
+///   * 🚩[`Default`]即为空真值
 pub type Truth = Vec<String>;
 
 /// 独立出来的「时间戳」类型
 /// * 🚩实际上是「字符串」的别名
+/// * 📌空时间戳（[`String::is_empty`]）⇒永恒
+///   * 🚩[`Default`]即为空时间戳
 pub type Stamp = String;
 
 /// 独立出来的「标点」类型
@@ -36,8 +43,14 @@ pub struct Sentence {
 
 /// 自身方法
 impl Sentence {
+    /// 从词项、标点构造语句
+    /// * 🚩时间戳、真值均为空：永恒、无真值
+    pub fn new(term: Term, punctuation: impl Into<Punctuation>) -> Self {
+        Self::with_stamp_truth(term, punctuation, Stamp::new(), Truth::new())
+    }
+
     /// 从位置参数构造语句
-    pub fn new(
+    pub fn with_stamp_truth(
         term: Term,
         punctuation: impl Into<Punctuation>,
         stamp: impl Into<Stamp>,
@@ -50,6 +63,42 @@ impl Sentence {
             truth: truth.into(),
         }
     }
+
+    /// 时间戳是否为空
+    /// * 📌空时间戳⇒永恒
+    pub fn is_stamp_empty(&self) -> bool {
+        self.stamp.is_empty()
+    }
+
+    /// 真值是否为空
+    /// * 📌空真值⇒无真值，同[`GetTruth::get_truth`]返回[`None`]
+    pub fn is_truth_empty(&self) -> bool {
+        self.truth.is_empty()
+    }
+}
+
+/// 实现/标点语义
+/// * 🚩词法语句只存储标点字符串：与格式中各类标点比对
+impl Sentence {
+    /// 是否为判断
+    pub fn is_judgement_with(&self, format: &NarseseFormat) -> bool {
+        self.punctuation == format.sentence.punctuation_judgement
+    }
+
+    /// 是否为目标
+    pub fn is_goal_with(&self, format: &NarseseFormat) -> bool {
+        self.punctuation == format.sentence.punctuation_goal
+    }
+
+    /// 是否为问题
+    pub fn is_question_with(&self, format: &NarseseFormat) -> bool {
+        self.punctuation == format.sentence.punctuation_question
+    }
+
+    /// 是否为请求
+    pub fn is_quest_with(&self, format: &NarseseFormat) -> bool {
+        self.punctuation == format.sentence.punctuation_quest
+    }
 }
 
 /// 快捷构造宏
@@ -72,7 +121,7 @@ macro_rules! lexical_sentence {
     // ! 使用内部括号包裹，以防「函数调用」歧义
     [@NEW $( ($arg:expr) )*] => {
         // * 📝引入`$crate::lexical`作为绝对路径
-        $crate::lexical::Sentence::new($($arg),*)
+        $crate::lexical::Sentence::with_stamp_truth($($arg),*)
     };
 }
 
@@ -134,10 +183,31 @@ impl GetStamp<Stamp> for Sentence {
 
 impl GetTruth<Truth> for Sentence {
     /// ! 缩减[`Option`]失败：参见[`GetTruth`]的描述
+    /// * 🚩空真值（空数组）⇒[`None`]
+    ///   * 📌与枚举Narsese中「空真值⇒无真值」的语义保持一致
     fn get_truth(&self) -> Option<&Truth> {
-        // 此处一定返回有值（数组）
-        // * 🚩没真值的当空真值对待
-        Some(&self.truth)
+        match self.truth.is_empty() {
+            true => None,
+            false => Some(&self.truth),
+        }
+    }
+}
+
+// 实现/转换 //
+impl SplitSentence<Term, Punctuation, Stamp, Truth> for Sentence {
+    /// 有损转换为词项
+    fn to_term_lossy(self) -> (Term, DroppedSentenceParts<Punctuation, Stamp, Truth>) {
+        let parts = DroppedSentenceParts {
+            punctuation: self.punctuation,
+            stamp: self.stamp,
+            truth: self.truth,
+        };
+        (self.term, parts)
+    }
+
+    /// 从词项与舍去的部分重新组合
+    fn join_sentence(term: Term, parts: DroppedSentenceParts<Punctuation, Stamp, Truth>) -> Self {
+        Self::with_stamp_truth(term, parts.punctuation, parts.stamp, parts.truth)
     }
 }
 
@@ -171,7 +241,7 @@ mod tests {
         show!(&sentence);
         asserts! {
             sentence.get_stamp() => "", // 无时间戳
-            sentence.get_truth().unwrap().is_empty(), // 空真值
+            sentence.get_truth() => None, // 空真值
         }
 
         // 缺省形式：只有词项、标点和时间戳
@@ -179,7 +249,59 @@ mod tests {
         show!(&sentence);
         asserts! {
             sentence.get_stamp() => ":|:", // 有时间戳
-            sentence.get_truth().unwrap().is_empty(), // 空真值
+            sentence.get_truth() => None, // 空真值
+        }
+    }
+
+    /// 测试/标点语义
+    #[test]
+    fn test_is_punctuation_with() {
+        use crate::conversion::string::impl_lexical::format_instances::{FORMAT_ASCII, FORMAT_HAN};
+        let term = lexical_atom!("A");
+        for (format, [judgement, goal, question, quest]) in [
+            (&*FORMAT_ASCII, [".", "!", "?", "@"]),
+            (&*FORMAT_HAN, ["。", "！", "？", "；"]),
+        ] {
+            let kinds = |punctuation| {
+                let sentence = Sentence::new(term.clone(), punctuation);
+                [
+                    sentence.is_judgement_with(format),
+                    sentence.is_goal_with(format),
+                    sentence.is_question_with(format),
+                    sentence.is_quest_with(format),
+                ]
+            };
+            asserts! {
+                kinds(judgement) => [true, false, false, false],
+                kinds(goal) => [false, true, false, false],
+                kinds(question) => [false, false, true, false],
+                kinds(quest) => [false, false, false, true],
+                // 格式之外的标点
+                kinds("~") => [false; 4],
+            }
+        }
+    }
+
+    /// 语句⇄词项+舍去的部分
+    #[test]
+    fn test_split_sentence() {
+        let term = lexical_atom!("A");
+        let sentence = lexical_sentence![term.clone() "." ":|:" lexical_truth!["1.0" "0.9"]];
+        let (split_term, parts) = sentence.clone().to_term_lossy();
+        asserts! {
+            split_term => term,
+            parts => DroppedSentenceParts {
+                punctuation: ".".into(),
+                stamp: ":|:".into(),
+                truth: lexical_truth!["1.0" "0.9"],
+            },
+            Sentence::join_sentence(split_term, parts) => sentence,
+        }
+        // 缺省形式
+        let sentence = lexical_sentence![term.clone(), "?"];
+        let (split_term, parts) = sentence.clone().to_term_lossy();
+        asserts! {
+            Sentence::join_sentence(split_term, parts) => sentence,
         }
     }
 }
@@ -0,0 +1,19 @@
+/// 时间戳的「结构化视图」
+/// * 🎯避免下游代码各自解析「时间戳字符串」
+///   * 📄`:!-1:` ⇒ [`StampKind::Fixed(-1)`](StampKind::Fixed)
+/// * 📌词法语句仍存储原始字符串，以保证无损往返
+/// * 🚩由[词法Narsese格式](crate::conversion::string::impl_lexical::NarseseFormat::parse_stamp_kind)解析得到
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StampKind {
+    /// 永恒 | 空时间戳
+    Eternal,
+    /// 过去
+    Past,
+    /// 现在
+    Present,
+    /// 将来
+    Future,
+    /// 固定
+    /// * 📌可带正负号：`:!+1:`、`:!-1:`
+    Fixed(i64),
+}
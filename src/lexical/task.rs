@@ -1,12 +1,15 @@
 use super::{Punctuation, Sentence, Stamp, Term, Truth};
 use crate::api::{
-    CastToTask, GetBudget, GetPunctuation, GetStamp, GetTerm, GetTruth, TryCastToSentence,
+    CastToTask, GetBudget, GetPunctuation, GetStamp, GetTerm, GetTruth, SplitTask,
+    TryCastToSentence,
 };
 
 /// 独立出来的「预算值」类型
 /// * 🚩实际上是「字符串数组」的别名
 /// * ✅对「作为数据结构的真值」的最大适配
 ///   * 📄空预算、单预算、双预算、三预算…
+/// * 📌空预算（[`Vec::is_empty`]）⇒无预算：任务可无损转换为语句
+///   * 🚩[`Default`]即为空预算
 pub type Budget = Vec<String>;
 
 /// 词法上的「任务」：预算值+语句
@@ -18,27 +21,34 @@ pub type Budget = Vec<String>;
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Task {
     /// 预算值（数值字串）
-    pub budget: Vec<String>,
+    pub budget: Budget,
     /// 词法语句
     pub sentence: Sentence,
 }
 
 /// 自身方法
 impl Task {
-    /// 从位置参数构造语句 | 对语句[`LexicalSentence`]部分进行展开
-    pub fn new(
-        budget: impl Into<Budget>,
-        term: Term,
-        punctuation: impl Into<Punctuation>,
-        stamp: impl Into<Stamp>,
-        truth: impl Into<Truth>,
-    ) -> Self {
+    /// 从语句、预算值构造任务
+    /// * 📌参数顺序同「枚举Narsese」的任务构造函数
+    pub fn new(sentence: Sentence, budget: impl Into<Budget>) -> Self {
         Self {
             budget: budget.into(),
-            sentence: Sentence::new(term, punctuation, stamp, truth),
+            sentence,
         }
     }
 
+    /// 从词项、标点构造任务
+    /// * 🚩时间戳、真值、预算值均为空
+    pub fn from_term(term: Term, punctuation: impl Into<Punctuation>) -> Self {
+        Self::new(Sentence::new(term, punctuation), Budget::new())
+    }
+
+    /// 预算值是否为空
+    /// * 📌空预算⇒可无损转换为语句，参见[`TryCastToSentence`]
+    pub fn is_budget_empty(&self) -> bool {
+        self.budget.is_empty()
+    }
+
     // 获取内部语句
     pub fn get_sentence(&self) -> &Sentence {
         &self.sentence
@@ -98,21 +108,41 @@ macro_rules! lexical_budget {
 impl CastToTask<Task> for Sentence {
     /// 转换：默认加上空预算
     fn cast_to_task(self) -> Task {
-        Task {
-            budget: lexical_budget![],
-            sentence: self,
+        Task::new(self, Budget::new())
+    }
+}
+
+impl SplitTask<Sentence, Budget> for Task {
+    /// 拆分为语句与预算值
+    fn split_task(self) -> (Sentence, Budget) {
+        (self.sentence, self.budget)
+    }
+
+    /// 从语句与预算值重新组合
+    fn join_task(sentence: Sentence, budget: Budget) -> Self {
+        Task::new(sentence, budget)
+    }
+
+    /// 有损转换为语句
+    fn to_sentence_lossy(self) -> (Sentence, Option<Budget>) {
+        match self.is_budget_empty() {
+            // 空预算⇒无损失
+            true => (self.sentence, None),
+            // 其它⇒舍去预算值
+            false => (self.sentence, Some(self.budget)),
         }
     }
 }
 
 impl TryCastToSentence<Sentence> for Task {
     /// 尝试（无损）转换为语句
+    /// * 📄基于[`SplitTask::to_sentence_lossy`]
     fn try_cast_to_sentence(self) -> Result<Sentence, Self> {
-        match self.budget.is_empty() {
+        match self.to_sentence_lossy() {
             // 空预算⇒可无损转换
-            true => Ok(self.sentence),
+            (sentence, None) => Ok(sentence),
             // 其它⇒无法转换
-            false => Err(self),
+            (sentence, Some(budget)) => Err(Task::join_task(sentence, budget)),
         }
     }
 }
@@ -158,7 +188,7 @@ impl GetTruth<Truth> for Task {
 #[allow(unused)]
 mod tests {
     use super::*;
-    use crate::{lexical_atom, lexical_truth};
+    use crate::{lexical_atom, lexical_sentence, lexical_truth};
     use nar_dev_utils::*;
 
     #[test]
@@ -191,7 +221,118 @@ mod tests {
             task.get_term() => &term, // 词项
             task.get_punctuation() => ".", // 标点
             task.get_stamp() => "", // 时间戳（空）
-            task.get_truth().unwrap().is_empty(), // 真值（空）
+            task.get_truth() => None, // 真值（空）
+        }
+    }
+
+    /// 构造函数
+    #[test]
+    fn test_new() {
+        let term = lexical_atom!("A");
+        let sentence = Sentence::new(term.clone(), "?");
+        asserts! {
+            sentence => lexical_sentence![term.clone(), "?"],
+            sentence.is_stamp_empty(),
+            sentence.is_truth_empty(),
+            Sentence::with_stamp_truth(term.clone(), ".", ":|:", lexical_truth!["1.0"])
+                => lexical_sentence![term.clone() "." ":|:" lexical_truth!["1.0"]],
+            Task::new(sentence.clone(), lexical_budget!["0.5"])
+                => lexical_task![lexical_budget!["0.5"] term.clone() "?"],
+            Task::from_term(term.clone(), "?") => Task::new(sentence, Budget::new()),
+            Task::from_term(term, "?").is_budget_empty(),
+        }
+    }
+
+    /// 语句与任务的相互转换
+    /// * 🚩空预算⇒可无损转换为语句
+    #[test]
+    fn test_cast() {
+        let sentence =
+            Sentence::with_stamp_truth(lexical_atom!("A"), ".", "", lexical_truth!["1.0"]);
+        let task = sentence.clone().cast_to_task();
+        asserts! {
+            task.is_budget_empty(),
+            task.clone().try_cast_to_sentence() => Ok(sentence.clone()),
+        }
+        let task = Task::new(sentence, lexical_budget!["0.5" "0.5"]);
+        asserts! {
+            task.clone().try_cast_to_sentence() => Err(task),
+        }
+    }
+
+    /// 任务⇄语句+预算值
+    #[test]
+    fn test_split_task() {
+        let sentence =
+            Sentence::with_stamp_truth(lexical_atom!("A"), "!", ":|:", lexical_truth!["1.0"]);
+        let task = Task::new(sentence.clone(), lexical_budget!["0.5" "0.5"]);
+        let (split_sentence, budget) = task.clone().split_task();
+        asserts! {
+            split_sentence => sentence,
+            budget => lexical_budget!["0.5" "0.5"],
+            Task::join_task(split_sentence, budget) => task,
+            task.clone().to_sentence_lossy() => (sentence.clone(), Some(lexical_budget!["0.5" "0.5"])),
+            sentence.clone().cast_to_task().to_sentence_lossy() => (sentence, None),
+        }
+    }
+
+    /// 仅依赖API特征的泛型函数
+    /// * 🎯验证「词法Narsese」与「枚举Narsese」可共用同一套泛型代码
+    fn describe<T, Tm, P>(t: &T) -> (Tm, P)
+    where
+        T: GetTerm<Tm> + GetPunctuation<P>,
+        Tm: Clone,
+        P: Clone,
+    {
+        (t.get_term().clone(), t.get_punctuation().clone())
+    }
+
+    /// 仅依赖API特征的泛型函数（预算、时间戳、真值）
+    fn attributes<T, B, S, Tr>(t: &T) -> (&B, &S, Option<&Tr>)
+    where
+        T: GetBudget<B> + GetStamp<S> + GetTruth<Tr>,
+    {
+        (t.get_budget(), t.get_stamp(), t.get_truth())
+    }
+
+    #[test]
+    fn test_generic_api() {
+        let term = lexical_atom!("A");
+        let task = lexical_task![
+            lexical_budget!["0.5" "0.5" "0.5"]
+            term.clone() "." ":|:" lexical_truth!["1.0" "0.9"]
+        ];
+        let sentence = task.get_sentence().clone();
+        asserts! {
+            describe(&task) => (term.clone(), ".".to_string()),
+            describe(&sentence) => (term.clone(), ".".to_string()),
+            attributes(&task) => (
+                &lexical_budget!["0.5" "0.5" "0.5"],
+                &":|:".to_string(),
+                Some(&lexical_truth!["1.0" "0.9"])
+            ),
+        }
+        // 无真值
+        let task = lexical_task![lexical_budget![] term.clone() "?"];
+        asserts! {
+            describe(&task) => (term, "?".to_string()),
+            attributes(&task) => (&vec![], &String::new(), None),
+        }
+        // 枚举Narsese也可使用同一泛型函数
+        #[cfg(feature = "enum_narsese")]
+        {
+            use crate::enum_narsese::{
+                Punctuation as EnumPunctuation, Sentence as EnumSentence, Stamp as EnumStamp,
+                Term as EnumTerm, Truth as EnumTruth,
+            };
+            let sentence = EnumSentence::new_judgement(
+                EnumTerm::new_word("A"),
+                EnumTruth::Empty,
+                EnumStamp::Eternal,
+            );
+            asserts! {
+                describe(&sentence) => (EnumTerm::new_word("A"), EnumPunctuation::Judgement),
+            }
         }
     }
 }
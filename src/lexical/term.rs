@@ -1,6 +1,9 @@
 //! 词法Narsese的「词项」数据结构
 
-use crate::api::{ExtractTerms, GetCapacity, GetCategory, TermCapacity, TermCategory};
+use crate::api::{
+    complexity_of_components, depth_of_components, ExtractTerms, GetCapacity, GetCategory,
+    GetComplexity, TermCapacity, TermCategory,
+};
 
 /// 词法上的「词项」
 /// * 📌只在词法（字符串语法）上表征词项
@@ -166,6 +169,31 @@ impl GetCapacity for Term {
     }
 }
 
+/// 词项复杂度
+/// * 📌像：占位符在词法上即原子词项，自然计入
+/// * 📌没有组分的复合词项、词项集：计为1，不会出错
+impl GetComplexity for Term {
+    fn complexity(&self) -> usize {
+        match self {
+            Atom { .. } => 1,
+            Compound { terms, .. } | Set { terms, .. } => complexity_of_components(terms),
+            Statement {
+                subject, predicate, ..
+            } => complexity_of_components([subject.as_ref(), predicate.as_ref()]),
+        }
+    }
+
+    fn depth(&self) -> usize {
+        match self {
+            Atom { .. } => 1,
+            Compound { terms, .. } | Set { terms, .. } => depth_of_components(terms),
+            Statement {
+                subject, predicate, ..
+            } => depth_of_components([subject.as_ref(), predicate.as_ref()]),
+        }
+    }
+}
+
 /// 实现/提取内部元素
 impl ExtractTerms for Term {
     type Term = Term;
@@ -291,6 +319,40 @@ pub(crate) mod tests {
         }
     }
 
+    /// 测试/复杂度与深度
+    #[test]
+    fn test_complexity() {
+        // 测试集 | 原子词项7个，然后是复合词项、陈述
+        let expected_complexities = [
+            1, 1, 1, 1, 1, 1, 1, // 原子词项
+            2, 2, 2, 2, 2, 2, 2, // 集合、交、差、积
+            3, 3, // 像：占位符即原子词项
+            2, 2, 1, 1,
+            1, // 合取、析取、否定、顺序/平行合取（仅一个组分）
+            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, // 陈述
+        ];
+        let testset = generate_term_testset();
+        assert_eq!(testset.len(), expected_complexities.len());
+        for (term, expected) in testset.iter().zip(expected_complexities) {
+            let expected_depth = match term {
+                Term::Atom { .. } => 1,
+                _ => 2,
+            };
+            asserts! {
+                term.complexity() => expected,
+                term.depth() => expected_depth,
+            }
+        }
+        // 没有组分的复合词项、词项集：不会出错
+        asserts! {
+            lexical_compound!("&&";).complexity() => 1,
+            lexical_compound!("&&";).depth() => 1,
+            lexical_set!("{";;"}").complexity() => 1,
+            lexical_statement!(lexical_compound!("*";) "-->" lexical_atom!("A")).complexity() => 2,
+            lexical_statement!(lexical_compound!("*";) "-->" lexical_atom!("A")).depth() => 2,
+        }
+    }
+
     /// 测试/元素类别
     #[test]
     fn test_category() {